@@ -3,7 +3,9 @@ use std::fs::create_dir_all;
 
 use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
 
-use asset_classification_smart_contract::core::msg::{ExecuteMsg, InitMsg, MigrateMsg, QueryMsg};
+use asset_classification_smart_contract::core::msg::{
+    ExecuteMsg, InitMsg, MigrateMsg, QueryMsg, SudoMsg,
+};
 use asset_classification_smart_contract::core::types::asset_definition::{
     AssetDefinitionInputV3, AssetDefinitionV3,
 };
@@ -24,6 +26,7 @@ fn main() {
     export_schema(&schema_for!(InitMsg), &out_dir);
     export_schema(&schema_for!(QueryMsg), &out_dir);
     export_schema(&schema_for!(MigrateMsg), &out_dir);
+    export_schema(&schema_for!(SudoMsg), &out_dir);
     export_schema(&schema_for!(AssetScopeAttribute), &out_dir);
     export_schema(&schema_for!(AssetDefinitionV3), &out_dir);
     export_schema(&schema_for!(AssetDefinitionInputV3), &out_dir);
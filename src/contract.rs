@@ -1,24 +1,91 @@
-use crate::core::msg::{ExecuteMsg, InitMsg, MigrateMsg, QueryMsg};
+use crate::core::msg::{ExecuteMsg, InitMsg, MigrateMsg, QueryMsg, SudoMsg};
+use crate::core::state::STATE_V2;
 use crate::execute::add_asset_definition::{add_asset_definition, AddAssetDefinitionV1};
 use crate::execute::add_asset_verifier::{add_asset_verifier, AddAssetVerifierV1};
+use crate::execute::appeal_verification::{appeal_verification, AppealVerificationV1};
+use crate::execute::batch_onboard_asset::{batch_onboard_asset, BatchOnboardAssetV1};
+use crate::execute::batch_verify_asset::{batch_verify_asset, BatchVerifyAssetV1};
+use crate::execute::bulk_delete_asset_definitions::{
+    bulk_delete_asset_definitions, BulkDeleteAssetDefinitionsV1,
+};
+use crate::execute::cancel_pending_onboarding::{
+    cancel_pending_onboarding, CancelPendingOnboardingV1,
+};
+use crate::execute::clone_asset_definition::{clone_asset_definition, CloneAssetDefinitionV1};
 use crate::execute::delete_asset_definition::{delete_asset_definition, DeleteAssetDefinitionV1};
+use crate::execute::deprecate_asset_definition::{
+    deprecate_asset_definition, DeprecateAssetDefinitionV1,
+};
+use crate::execute::expire_stale_verification::{
+    expire_stale_verification, ExpireStaleVerificationV1,
+};
+use crate::execute::freeze_asset_definition::{freeze_asset_definition, FreezeAssetDefinitionV1};
 use crate::execute::onboard_asset::{onboard_asset, OnboardAssetV1};
+use crate::execute::purge_fee_payment_detail::{purge_fee_payment_detail, PurgeFeePaymentDetailV1};
+use crate::execute::purge_stale_fee_detail::{
+    purge_stale_fee_detail, set_fee_detail_max_age, PurgeStaleFeeDetailV1,
+};
+use crate::execute::relay_os_gateway_access::{relay_os_gateway_access, RelayOsGatewayAccessV1};
+use crate::execute::resolve_appeal::{resolve_appeal, ResolveAppealV1};
+use crate::execute::rotate_verifier_address::{rotate_verifier_address, RotateVerifierAddressV1};
 use crate::execute::toggle_asset_definition::{toggle_asset_definition, ToggleAssetDefinitionV1};
-use crate::execute::update_access_routes::{update_access_routes, UpdateAccessRoutesV1};
+use crate::execute::toggle_asset_verifier::{toggle_asset_verifier, ToggleAssetVerifierV1};
+use crate::execute::transfer_admin::{accept_admin_transfer, transfer_admin, TransferAdminV1};
+use crate::execute::update_access_routes::{
+    set_max_access_routes_per_owner, update_access_routes, UpdateAccessRoutesV1,
+};
+use crate::execute::update_allowed_requestors::{
+    update_allowed_requestors, UpdateAllowedRequestorsV1,
+};
 use crate::execute::update_asset_definition::{update_asset_definition, UpdateAssetDefinitionV1};
+use crate::execute::update_asset_definition_description::{
+    update_asset_definition_description, UpdateAssetDefinitionDescriptionV1,
+};
 use crate::execute::update_asset_verifier::{update_asset_verifier, UpdateAssetVerifierV1};
+use crate::execute::update_os_gateway_grant_prefix::update_os_gateway_grant_prefix;
+use crate::execute::update_verifier_entity_detail::{
+    set_require_entity_detail, update_verifier_entity_detail, UpdateVerifierEntityDetailV1,
+};
 use crate::execute::verify_asset::{verify_asset, VerifyAssetV1};
 use crate::instantiate::init_contract::init_contract;
 use crate::migrate::migrate_contract::migrate_contract;
+use crate::query::query_access_definitions::query_access_definitions_for_scope;
+use crate::query::query_access_routes_by_owner::query_access_routes_by_owner;
+use crate::query::query_all::query_all;
 use crate::query::query_asset_definition::query_asset_definition;
+use crate::query::query_asset_definition_count::query_asset_definition_count;
 use crate::query::query_asset_definitions::query_asset_definitions;
+use crate::query::query_asset_definitions_by_label::query_asset_definitions_by_label;
+use crate::query::query_asset_definitions_page::query_asset_definitions_page;
 use crate::query::query_asset_scope_attribute::query_asset_scope_attribute;
 use crate::query::query_asset_scope_attribute_by_asset_type::query_asset_scope_attribute_by_asset_type;
+use crate::query::query_asset_scope_attributes_batch::query_asset_scope_attributes_batch;
+use crate::query::query_assets_by_requestor::query_assets_by_requestor;
+use crate::query::query_contract_metrics::query_contract_metrics;
+use crate::query::query_contract_statistics::query_contract_statistics;
+use crate::query::query_deprecated_definitions::query_deprecated_definitions;
+use crate::query::query_effective_fee::query_effective_fee;
 use crate::query::query_fee_payments::query_fee_payments;
+use crate::query::query_latest_verification_result::query_latest_verification_result;
+use crate::query::query_onboarding_fee_preview::query_onboarding_fee_preview;
+use crate::query::query_pending_verifications::query_pending_verifications;
+use crate::query::query_recently_modified_scopes::query_recently_modified_scopes;
+use crate::query::query_scope_attribute_count::query_scope_attribute_count;
+use crate::query::query_scope_spec_binding::query_scope_spec_binding;
 use crate::query::query_state::query_state;
+use crate::query::query_storage_key_count::query_storage_key_count;
+use crate::query::query_verifier_count::query_verifier_count;
+use crate::query::query_verifier_pending_count::query_verifier_pending_count;
+use crate::query::query_verifier_statistics::{
+    query_all_verifier_statistics, query_verifier_statistics,
+};
 use crate::query::query_version::query_version;
 use crate::service::asset_meta_service::AssetMetaService;
+use crate::sudo::force_delete_asset_definition::force_delete_asset_definition;
+use crate::sudo::override_admin::override_admin;
+use crate::sudo::pause_contract::pause_contract;
 use crate::util::aliases::{AssetResult, EntryPointResponse};
+use crate::util::contract_helpers::check_contract_not_paused;
 use crate::validation::validate_execute_msg::validate_execute_msg;
 use crate::validation::validate_init_msg::validate_init_msg;
 use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo};
@@ -58,10 +125,34 @@ pub fn instantiate(deps: DepsMut, env: Env, info: MessageInfo, msg: InitMsg) ->
 /// * `msg` A custom query message enum defined by this contract to allow multiple different results
 /// to be determined for this route.
 #[entry_point]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> AssetResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> AssetResult<Binary> {
     match msg {
+        QueryMsg::QueryAccessDefinitionsForScope { scope_address } => {
+            query_access_definitions_for_scope(&deps, scope_address)
+        }
+        QueryMsg::QueryAccessRoutesByOwner {
+            owner_address,
+            start_after,
+            limit,
+        } => query_access_routes_by_owner(&deps, &owner_address, start_after, limit),
+        QueryMsg::QueryAll {} => query_all(&deps),
+        QueryMsg::QueryAllVerifierStatistics {
+            asset_type,
+            start_after,
+            limit,
+        } => query_all_verifier_statistics(&deps, asset_type.as_deref(), start_after, limit),
         QueryMsg::QueryAssetDefinition { asset_type } => query_asset_definition(&deps, &asset_type),
         QueryMsg::QueryAssetDefinitions {} => query_asset_definitions(&deps),
+        QueryMsg::QueryAssetDefinitionCount {} => query_asset_definition_count(&deps),
+        QueryMsg::QueryAssetDefinitionsPage {
+            page_size,
+            page_token,
+        } => query_asset_definitions_page(&deps, page_size, page_token),
+        QueryMsg::QueryAssetDefinitionsByLabel {
+            label,
+            start_after,
+            limit,
+        } => query_asset_definitions_by_label(&deps, &label, start_after, limit),
         QueryMsg::QueryAssetScopeAttributes { identifier } => {
             query_asset_scope_attribute(&deps, identifier.to_asset_identifier()?)
         }
@@ -73,11 +164,76 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> AssetResult<Binary> {
             identifier.to_asset_identifier()?,
             asset_type,
         ),
+        QueryMsg::QueryAssetScopeAttributesBatch { requests } => {
+            query_asset_scope_attributes_batch(&deps, requests)
+        }
+        QueryMsg::QueryAssetsByRequestor {
+            requestor_address,
+            asset_type,
+            start_after,
+            limit,
+        } => query_assets_by_requestor(
+            &deps,
+            &requestor_address,
+            asset_type.as_deref(),
+            start_after,
+            limit,
+        ),
+        QueryMsg::QueryContractMetrics {} => query_contract_metrics(&deps),
+        QueryMsg::QueryContractStatistics {} => query_contract_statistics(&deps),
+        QueryMsg::QueryDeprecatedDefinitions {} => query_deprecated_definitions(&deps),
+        QueryMsg::QueryEffectiveFee {
+            scope_address,
+            asset_type,
+            verifier_address,
+        } => query_effective_fee(&deps, &env, &scope_address, &asset_type, &verifier_address),
         QueryMsg::QueryFeePayments {
             identifier,
             asset_type,
         } => query_fee_payments(&deps, identifier.to_asset_identifier()?, &asset_type),
+        QueryMsg::QueryLatestVerificationResult {
+            identifier,
+            asset_type,
+        } => query_latest_verification_result(&deps, identifier.to_asset_identifier()?, asset_type),
+        QueryMsg::QueryOnboardingFeePreview {
+            scope_address,
+            asset_type,
+            verifier_address,
+        } => query_onboarding_fee_preview(&deps, &scope_address, &asset_type, &verifier_address),
+        QueryMsg::QueryPendingVerificationsByVerifier {
+            verifier_address,
+            asset_type,
+            start_after,
+            limit,
+        } => query_pending_verifications(
+            &deps,
+            &verifier_address,
+            asset_type.as_deref(),
+            start_after,
+            limit,
+        ),
+        QueryMsg::QueryRecentlyModifiedScopes {
+            after_block,
+            asset_type,
+        } => query_recently_modified_scopes(&deps, after_block, asset_type.as_deref()),
         QueryMsg::QueryState {} => query_state(&deps),
+        QueryMsg::QueryScopeAttributeCount {
+            scope_address,
+            asset_type,
+        } => query_scope_attribute_count(&deps, scope_address, asset_type.as_deref()),
+        QueryMsg::QueryScopeSpecificationBinding { scope_spec_address } => {
+            query_scope_spec_binding(&deps, &scope_spec_address)
+        }
+        QueryMsg::QueryStorageKeyCount {} => query_storage_key_count(&deps),
+        QueryMsg::QueryVerifierCount { asset_type } => query_verifier_count(&deps, &asset_type),
+        QueryMsg::QueryVerifierPendingCount {
+            verifier_address,
+            asset_type,
+        } => query_verifier_pending_count(&deps, &verifier_address, asset_type.as_deref()),
+        QueryMsg::QueryVerifierStatistics {
+            asset_type,
+            verifier_address,
+        } => query_verifier_statistics(&deps, &asset_type, &verifier_address),
         QueryMsg::QueryVersion {} => query_version(&deps),
     }
 }
@@ -101,46 +257,143 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> AssetResult<Binary> {
 #[entry_point]
 pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> EntryPointResponse {
     // Ensure the execute message is properly formatted before doing anything
-    validate_execute_msg(&msg)?;
+    let state = STATE_V2.load(deps.storage)?;
+    validate_execute_msg(
+        &msg,
+        state.allow_all_bech32_prefixes,
+        state.require_entity_detail_for_verifiers,
+    )?;
+    // Reject all execution routes while governance has the contract paused
+    check_contract_not_paused(&deps.as_ref())?;
     match msg {
         ExecuteMsg::OnboardAsset { .. } => onboard_asset(
             AssetMetaService::new(deps),
             env,
             info,
-            OnboardAssetV1::from_execute_msg(msg)?,
+            OnboardAssetV1::try_from(msg)?,
         ),
+        ExecuteMsg::BatchOnboardAsset { .. } => {
+            batch_onboard_asset(deps, env, info, BatchOnboardAssetV1::try_from(msg)?)
+        }
         ExecuteMsg::VerifyAsset { .. } => verify_asset(
             &env,
             AssetMetaService::new(deps),
             info,
-            VerifyAssetV1::from_execute_msg(msg)?,
+            VerifyAssetV1::try_from(msg)?,
         ),
-        ExecuteMsg::AddAssetDefinition { .. } => add_asset_definition(
+        ExecuteMsg::BatchVerifyAsset { .. } => {
+            batch_verify_asset(deps, env, info, BatchVerifyAssetV1::try_from(msg)?)
+        }
+        ExecuteMsg::AddAssetDefinition { .. } => {
+            add_asset_definition(deps, env, info, AddAssetDefinitionV1::try_from(msg)?)
+        }
+        ExecuteMsg::CloneAssetDefinition { .. } => {
+            clone_asset_definition(deps, env, info, CloneAssetDefinitionV1::try_from(msg)?)
+        }
+        ExecuteMsg::UpdateAssetDefinition { .. } => {
+            update_asset_definition(deps, info, UpdateAssetDefinitionV1::try_from(msg)?)
+        }
+        ExecuteMsg::UpdateAssetDefinitionDescription { .. } => update_asset_definition_description(
             deps,
-            env,
             info,
-            AddAssetDefinitionV1::from_execute_msg(msg)?,
+            UpdateAssetDefinitionDescriptionV1::try_from(msg)?,
         ),
-        ExecuteMsg::UpdateAssetDefinition { .. } => {
-            update_asset_definition(deps, info, UpdateAssetDefinitionV1::from_execute_msg(msg)?)
-        }
         ExecuteMsg::ToggleAssetDefinition { .. } => {
-            toggle_asset_definition(deps, info, ToggleAssetDefinitionV1::from_execute_msg(msg)?)
+            toggle_asset_definition(deps, info, ToggleAssetDefinitionV1::try_from(msg)?)
         }
         ExecuteMsg::AddAssetVerifier { .. } => {
-            add_asset_verifier(deps, info, AddAssetVerifierV1::from_execute_msg(msg)?)
+            add_asset_verifier(deps, info, AddAssetVerifierV1::try_from(msg)?)
         }
         ExecuteMsg::UpdateAssetVerifier { .. } => {
-            update_asset_verifier(deps, info, UpdateAssetVerifierV1::from_execute_msg(msg)?)
+            update_asset_verifier(deps, info, UpdateAssetVerifierV1::try_from(msg)?)
+        }
+        ExecuteMsg::ToggleAssetVerifier { .. } => {
+            toggle_asset_verifier(deps, info, ToggleAssetVerifierV1::try_from(msg)?)
+        }
+        ExecuteMsg::UpdateVerifierEntityDetail { .. } => {
+            update_verifier_entity_detail(deps, info, UpdateVerifierEntityDetailV1::try_from(msg)?)
+        }
+        ExecuteMsg::RotateVerifierAddress { .. } => rotate_verifier_address(
+            &env,
+            AssetMetaService::new(deps),
+            info,
+            RotateVerifierAddressV1::try_from(msg)?,
+        ),
+        ExecuteMsg::UpdateAllowedRequestors { .. } => {
+            update_allowed_requestors(deps, info, UpdateAllowedRequestorsV1::try_from(msg)?)
         }
         ExecuteMsg::UpdateAccessRoutes { .. } => update_access_routes(
             &env,
             AssetMetaService::new(deps),
             info,
-            UpdateAccessRoutesV1::from_execute_msg(msg)?,
+            UpdateAccessRoutesV1::try_from(msg)?,
         ),
         ExecuteMsg::DeleteAssetDefinition { .. } => {
-            delete_asset_definition(deps, info, DeleteAssetDefinitionV1::from_execute_msg(msg)?)
+            delete_asset_definition(deps, info, DeleteAssetDefinitionV1::try_from(msg)?)
+        }
+        ExecuteMsg::BulkDeleteAssetDefinitions { .. } => {
+            bulk_delete_asset_definitions(deps, info, BulkDeleteAssetDefinitionsV1::try_from(msg)?)
+        }
+        ExecuteMsg::FreezeAssetDefinition { .. } => {
+            freeze_asset_definition(deps, info, FreezeAssetDefinitionV1::try_from(msg)?)
+        }
+        ExecuteMsg::DeprecateAssetDefinition { .. } => {
+            deprecate_asset_definition(deps, info, DeprecateAssetDefinitionV1::try_from(msg)?)
+        }
+        ExecuteMsg::PurgeFeePaymentDetail { .. } => purge_fee_payment_detail(
+            &env,
+            AssetMetaService::new(deps),
+            info,
+            PurgeFeePaymentDetailV1::try_from(msg)?,
+        ),
+        ExecuteMsg::PurgeStaleFeeDetail { .. } => purge_stale_fee_detail(
+            &env,
+            AssetMetaService::new(deps),
+            info,
+            PurgeStaleFeeDetailV1::try_from(msg)?,
+        ),
+        ExecuteMsg::SetFeeDetailMaxAge { max_age_blocks } => {
+            set_fee_detail_max_age(deps, info, max_age_blocks)
+        }
+        ExecuteMsg::SetMaxAccessRoutesPerOwner { limit } => {
+            set_max_access_routes_per_owner(deps, info, limit)
+        }
+        ExecuteMsg::SetRequireEntityDetail { required } => {
+            set_require_entity_detail(deps, info, required)
+        }
+        ExecuteMsg::UpdateOsGatewayGrantPrefix { prefix } => {
+            update_os_gateway_grant_prefix(deps, info, prefix)
+        }
+        ExecuteMsg::ExpireStaleVerification { .. } => expire_stale_verification(
+            &env,
+            AssetMetaService::new(deps),
+            info,
+            ExpireStaleVerificationV1::try_from(msg)?,
+        ),
+        ExecuteMsg::CancelPendingOnboarding { .. } => cancel_pending_onboarding(
+            &env,
+            AssetMetaService::new(deps),
+            info,
+            CancelPendingOnboardingV1::try_from(msg)?,
+        ),
+        ExecuteMsg::AppealVerification { .. } => appeal_verification(
+            &env,
+            AssetMetaService::new(deps),
+            info,
+            AppealVerificationV1::try_from(msg)?,
+        ),
+        ExecuteMsg::ResolveAppeal { .. } => resolve_appeal(
+            &env,
+            AssetMetaService::new(deps),
+            info,
+            ResolveAppealV1::try_from(msg)?,
+        ),
+        ExecuteMsg::TransferAdmin { .. } => {
+            transfer_admin(deps, info, TransferAdminV1::try_from(msg)?)
+        }
+        ExecuteMsg::AcceptAdminTransfer {} => accept_admin_transfer(deps, info),
+        ExecuteMsg::RelayOsGatewayAccess { .. } => {
+            relay_os_gateway_access(&env, info, RelayOsGatewayAccessV1::try_from(msg)?)
         }
     }
 }
@@ -153,14 +406,48 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> E
 ///
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
 /// resources like contract internal storage and a querier to retrieve blockchain objects.
-/// * `_env` An environment object provided by the cosmwasm framework.  Describes the contract's
-/// details, as well as blockchain information at the time of the transaction.  Unused by this
-/// function, but required by cosmwasm for successful migration entrypoint.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
 /// * msg` A custom migrate message enum defined by this contract to allow multiple different
 /// results of invoking the migrate endpoint.
 #[entry_point]
-pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> EntryPointResponse {
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> EntryPointResponse {
     match msg {
-        MigrateMsg::ContractUpgrade { options } => migrate_contract(deps, options),
+        MigrateMsg::ContractUpgrade {
+            options,
+            populate_scope_specs,
+            backfill_verifier_status_index,
+        } => migrate_contract(
+            deps,
+            &env,
+            options,
+            populate_scope_specs,
+            backfill_verifier_status_index,
+        ),
+    }
+}
+
+/// The entry point used when the Provenance Blockchain's governance module invokes the contract.
+/// This route cannot be invoked by external accounts, and allows for emergency actions to be taken
+/// against the contract, like pausing all execution routes or overriding the admin address.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `_env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.  Unused by this
+/// function, but required by cosmwasm for successful sudo entrypoint.
+/// * `msg` A custom sudo message enum defined by this contract to allow multiple different
+/// governance actions to be taken.
+#[entry_point]
+pub fn sudo(deps: DepsMut, _env: Env, msg: SudoMsg) -> EntryPointResponse {
+    match msg {
+        SudoMsg::Pause {} => pause_contract(deps, true),
+        SudoMsg::Unpause {} => pause_contract(deps, false),
+        SudoMsg::ForceDeleteAssetDefinition { asset_type } => {
+            force_delete_asset_definition(deps, asset_type)
+        }
+        SudoMsg::OverrideAdmin { new_admin } => override_admin(deps, new_admin),
     }
 }
@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
 use thiserror::Error;
 
 use super::types::asset_onboarding_status::AssetOnboardingStatus;
@@ -33,6 +33,17 @@ pub enum ContractError {
     // CUSTOM TYPES //
     //              //
     //////////////////
+    /// An error emitted when an [AccessDefinition](super::types::access_definition::AccessDefinition)
+    /// would hold more access routes than the contract's [max_access_routes_per_owner](super::state::StateV2::max_access_routes_per_owner)
+    /// allows.
+    #[error("Owner [{owner}] cannot have more than {limit} access routes")]
+    AccessRouteLimitExceeded {
+        /// The bech32 address of the owner of the access definition.
+        owner: String,
+        /// The maximum number of access routes allowed per owner.
+        limit: u32,
+    },
+
     /// This error is encountered when an asset is attempted in the onboarding process, but it has
     /// already been onboarded and classified.
     #[error("Asset {scope_address} has already been fully onboarded as asset type [{asset_type}]")]
@@ -57,6 +68,24 @@ pub enum ContractError {
         status: AssetOnboardingStatus,
     },
 
+    /// This error is encountered when the onboarding process is attempted for an asset whose denial
+    /// has been appealed and is awaiting resolution by the contract admin.
+    #[error("Asset [{scope_address}] has a pending appeal for asset type [{asset_type}]")]
+    AssetAppealPending {
+        /// The bech32 scope address of the asset with the pending appeal.
+        scope_address: String,
+        /// The asset type for which the denial was appealed.
+        asset_type: String,
+    },
+
+    /// This error is encountered when a modification is attempted on an [AssetDefinitionV3](super::types::asset_definition::AssetDefinitionV3)
+    /// that has been [frozen](super::types::asset_definition::AssetDefinitionV3::frozen).
+    #[error("Asset definition for asset type [{asset_type}] is frozen and cannot be modified")]
+    AssetDefinitionFrozen {
+        /// The asset type of the frozen definition.
+        asset_type: String,
+    },
+
     /// This error is encountered when the onboarding process cannot locate the scope specified by
     /// the requestor.
     #[error("Asset {scope_address} not found")]
@@ -88,6 +117,28 @@ pub enum ContractError {
         asset_type: String,
     },
 
+    /// This error is encountered when an [AssetScopeAttribute](super::types::asset_scope_attribute::AssetScopeAttribute)
+    /// cannot be converted to or from the raw json bytes stored in a Provenance Blockchain Attribute
+    /// Module attribute.
+    #[error("Failed to serialize or deserialize attribute [{attribute_name}]: {cause}")]
+    AttributeSerializationError {
+        /// The name of the Provenance Blockchain Attribute Module attribute that holds the value.
+        attribute_name: String,
+        /// A description of the underlying serialization failure.
+        cause: String,
+    },
+
+    /// This error is encountered during instantiation when the contract's base name is requested to
+    /// be bound, but the Provenance Blockchain Name Module reports that the name is already owned
+    /// by a different address.
+    #[error("Base name [{name}] is already bound to address [{owner}]")]
+    BaseNameAlreadyBound {
+        /// The base contract name that was requested to be bound.
+        name: String,
+        /// The bech32 address that currently owns the name.
+        owner: String,
+    },
+
     /// Denotes that an existing [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2)
     /// has the same [address](super::types::verifier_detail::VerifierDetailV2::address) property
     /// as the provided [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2) to be
@@ -95,6 +146,20 @@ pub enum ContractError {
     #[error("duplicate/existing verifier address provided as input")]
     DuplicateVerifierProvided,
 
+    /// An error emitted when a verifier attempts to verify an asset whose [FeePaymentDetail](super::types::fee_payment_detail::FeePaymentDetail)
+    /// is older than [MAX_FEE_PAYMENT_DETAIL_SECONDS](crate::util::constants::MAX_FEE_PAYMENT_DETAIL_SECONDS).
+    /// Expired details must be removed with the [PurgeFeePaymentDetail](super::msg::ExecuteMsg::PurgeFeePaymentDetail)
+    /// route.
+    #[error("Fee payment detail for asset [{scope_address}] with asset type [{asset_type}] created at [{created_at}] has expired")]
+    FeePaymentDetailExpired {
+        /// The bech32 address of the scope to which the expired fee payment detail belongs.
+        scope_address: String,
+        /// The asset type for which the expired fee payment detail was created.
+        asset_type: String,
+        /// The block time, in seconds, at which the expired fee payment detail was created.
+        created_at: u64,
+    },
+
     /// An error that can be used in a circumstance where a named error is not necessary to be
     /// created.
     #[error("{msg}")]
@@ -103,6 +168,14 @@ pub enum ContractError {
         msg: String,
     },
 
+    /// Indicates that the contract reached an unexpected internal state, such as attempting to use
+    /// a resource that is already in use.
+    #[error("Internal error: {message}")]
+    InternalError {
+        /// A free-form text description of the internal failure.
+        message: String,
+    },
+
     /// Indicates that a bech32 address was provided that does not meet proper specifications for the
     /// given scenario.
     #[error("Invalid address provided [{address}]: {explanation}")]
@@ -162,6 +235,19 @@ pub enum ContractError {
         expected_message_type: String,
     },
 
+    /// This error is encountered when an [AssetDefinitionV3](super::types::asset_definition::AssetDefinitionV3)
+    /// is deprecated in favor of a replacement asset type that does not refer to an existing,
+    /// enabled asset definition.
+    #[error("Replacement asset type [{replacement_asset_type}] for asset type [{asset_type}] is invalid: {explanation}")]
+    InvalidReplacementAssetType {
+        /// The asset type being deprecated.
+        asset_type: String,
+        /// The invalid replacement asset type.
+        replacement_asset_type: String,
+        /// A message further explaining the issue.
+        explanation: String,
+    },
+
     /// An error that indicates that a scope inspected during the onboarding process is missing
     /// internal values and is not valid for onboarding, like an internal Provenance Blockchain Metadata
     /// Record.
@@ -171,6 +257,61 @@ pub enum ContractError {
         explanation: String,
     },
 
+    /// An error that can occur during a migration when existing contract data cannot be safely
+    /// transformed.  For instance, when two [AssetDefinitionV3s](super::types::asset_definition::AssetDefinitionV3)
+    /// have asset types that only differ by case, they cannot both be normalized to lowercase.
+    #[error("Migration conflict encountered for values: {conflicting_types:?}")]
+    MigrationConflict {
+        /// All values that conflict with one another.
+        conflicting_types: Vec<String>,
+    },
+
+    /// An error emitted when an asset is onboarded again after it has already been retried the
+    /// maximum number of times allowed by the selected verifier's [max_retries](super::types::verifier_detail::VerifierDetailV2::max_retries).
+    #[error("Scope at address [{scope_address}] has reached the maximum of {max_retries} retries for asset type [{asset_type}]")]
+    MaxRetriesExceeded {
+        /// The bech32 address of the scope that was onboarded.
+        scope_address: String,
+        /// The asset type that the scope was onboarded as.
+        asset_type: String,
+        /// The maximum number of retries allowed by the verifier.
+        max_retries: u32,
+    },
+
+    /// An error emitted when a verifier has already received the maximum number of onboarding
+    /// requests for an asset type in the current block, as defined by its [max_classifications_per_block](super::types::verifier_detail::VerifierDetailV2::max_classifications_per_block).
+    #[error("Verifier [{verifier_address}] has reached its limit of {block_limit} onboarding requests per block for asset type [{asset_type}]")]
+    VerifierRateLimitExceeded {
+        /// The asset type of the rejected onboarding request.
+        asset_type: String,
+        /// The bech32 address of the rate-limited verifier.
+        verifier_address: String,
+        /// The maximum number of onboarding requests the verifier accepts per block.
+        block_limit: u32,
+    },
+
+    /// An error emitted when a verifier addition would cause an asset definition to hold more
+    /// verifiers than its [max_verifiers](super::types::asset_definition::AssetDefinitionV3::max_verifiers)
+    /// allows.
+    #[error("Asset type [{asset_type}] cannot have more than {limit} verifiers")]
+    MaxVerifiersExceeded {
+        /// The asset type of the definition to which verifiers were being added.
+        asset_type: String,
+        /// The maximum number of verifiers allowed by the definition.
+        limit: u32,
+    },
+
+    /// An aggregation of multiple errors, potentially of differing types, that were collected
+    /// before failing.  Constructed via [multi](self::ContractError::multi).
+    #[error(
+        "Multiple errors occurred: [{}]",
+        errors.iter().map(|e| e.to_string()).collect::<Vec<String>>().join("; ")
+    )]
+    MultipleErrors {
+        /// Each error that was encountered.
+        errors: Vec<ContractError>,
+    },
+
     /// An error that occurs when a lookup is attempted for a contract resource but the resource
     /// does not exist.  For instance, when an [AssetDefinitionV3](super::types::asset_definition::AssetDefinitionV3)
     /// does not contain a [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2) with a
@@ -182,6 +323,25 @@ pub enum ContractError {
         explanation: String,
     },
 
+    /// An error emitted when an asset that was denied by a verifier is onboarded again before the
+    /// verifier's [cooldown_seconds](super::types::verifier_detail::VerifierDetailV2::cooldown_seconds)
+    /// have elapsed since the denial.
+    #[error("Onboarding cooldown is active. Retry in {seconds_remaining} seconds")]
+    OnboardingCooldownActive {
+        /// The number of seconds that must still elapse before the asset can be onboarded again.
+        seconds_remaining: u64,
+    },
+
+    /// An error emitted when an onboarding request specifies an expected fee that does not exactly
+    /// match the fee that would be charged.
+    #[error("Expected an onboarding fee of {expected}, but the actual fee is {actual}")]
+    FeeSlippage {
+        /// The fee total provided by the requestor.
+        expected: Uint128,
+        /// The fee total that would be charged for the onboarding.
+        actual: Uint128,
+    },
+
     /// An error that occurs when a unique key is violated during an attempt to add new data to the
     /// contract's internal storage.  Reference: [state](super::state).
     #[error("Existing record found: {explanation}")]
@@ -198,6 +358,15 @@ pub enum ContractError {
         explanation: String,
     },
 
+    /// An error that occurs when a Provenance Blockchain Metadata Scope is looked up by its address
+    /// but does not exist.  This is distinct from a [NotFound](self::ContractError::NotFound) error,
+    /// which is emitted when the scope exists but does not contain the requested data.
+    #[error("Scope not found at address [{scope_address}]")]
+    ScopeNotFound {
+        /// The bech32 address of the scope that could not be found.
+        scope_address: String,
+    },
+
     /// A generic error that occurs when an address attempts to perform an operation in the contract
     /// that it does not have the permission to.
     #[error("Unauthorized: {explanation}")]
@@ -220,6 +389,18 @@ pub enum ContractError {
         expected_verifier_address: String,
     },
 
+    /// An error that occurs when a subtraction would produce a negative value, which cannot be
+    /// represented by the unsigned integers used for fee calculations.
+    #[error("Underflow encountered during {context}: {minuend} - {subtrahend}")]
+    Underflow {
+        /// A free-form text description of the calculation that was being performed.
+        context: String,
+        /// The value being subtracted from.
+        minuend: u128,
+        /// The value that exceeded the minuend.
+        subtrahend: u128,
+    },
+
     /// This error occurs when a [SerializedEnum](super::types::serialized_enum::SerializedEnum) is
     /// received from a caller that cannot be properly converted to its expected underlying type.
     #[error("Unexpected enum value received. Got type [{received_type}]. {explanation}")]
@@ -254,6 +435,17 @@ pub enum ContractError {
         asset_type: String,
     },
 
+    /// This error is encountered when an asset is onboarded with a [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2)
+    /// that has been [disabled](super::types::verifier_detail::VerifierDetailV2::enabled).
+    #[error("Verifier [{verifier_address}] is disabled for asset type [{asset_type}]")]
+    VerifierDisabled {
+        /// The [asset_type](super::types::asset_definition::AssetDefinitionV3::asset_type) selected
+        /// during onboarding.
+        asset_type: String,
+        /// The bech32 address of the disabled verifier.
+        verifier_address: String,
+    },
+
     /// This error can occur when a target [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2)
     /// does not exist in an [AssetDefinitionV3](super::types::asset_definition::AssetDefinitionV3) during
     /// the onboarding process.
@@ -276,4 +468,290 @@ impl ContractError {
     pub fn generic<S: Into<String>>(msg: S) -> ContractError {
         ContractError::GenericError { msg: msg.into() }
     }
+
+    /// Constructs an instance of the [MultipleErrors](self::ContractError::MultipleErrors) variant,
+    /// wrapping all provided errors.
+    ///
+    /// # Parameters
+    ///
+    /// * `errors` All errors that were encountered.
+    pub fn multi(errors: Vec<ContractError>) -> ContractError {
+        ContractError::MultipleErrors { errors }
+    }
+
+    /// Extracts every [InvalidMessageFields](self::ContractError::InvalidMessageFields) error from
+    /// the provided errors, including those nested within [MultipleErrors](self::ContractError::MultipleErrors),
+    /// and combines their invalid fields into a single error.  If all extracted errors share a
+    /// message type, it is retained.  Otherwise, the distinct message types are joined together.
+    /// Any errors of other types are preserved alongside the combined error in a [MultipleErrors](self::ContractError::MultipleErrors)
+    /// result.
+    ///
+    /// # Parameters
+    ///
+    /// * `errors` The errors from which to extract invalid message fields.
+    pub fn flatten_to_message_fields(errors: Vec<ContractError>) -> ContractError {
+        let mut message_types: Vec<String> = vec![];
+        let mut invalid_fields: Vec<String> = vec![];
+        let mut other_errors: Vec<ContractError> = vec![];
+        let mut pending_errors = errors;
+        pending_errors.reverse();
+        while let Some(error) = pending_errors.pop() {
+            match error {
+                ContractError::InvalidMessageFields {
+                    message_type,
+                    invalid_fields: mut fields,
+                } => {
+                    if !message_types.contains(&message_type) {
+                        message_types.push(message_type);
+                    }
+                    invalid_fields.append(&mut fields);
+                }
+                ContractError::MultipleErrors { errors } => {
+                    pending_errors.extend(errors.into_iter().rev());
+                }
+                other => other_errors.push(other),
+            }
+        }
+        if message_types.is_empty() {
+            return ContractError::multi(other_errors);
+        }
+        let message_fields_error = ContractError::InvalidMessageFields {
+            message_type: message_types.join(", "),
+            invalid_fields,
+        };
+        if other_errors.is_empty() {
+            message_fields_error
+        } else {
+            other_errors.insert(0, message_fields_error);
+            ContractError::multi(other_errors)
+        }
+    }
+
+    /// Determines if this error indicates that a requested resource does not exist.  Matches the
+    /// [NotFound](self::ContractError::NotFound), [AssetNotFound](self::ContractError::AssetNotFound),
+    /// [RecordNotFound](self::ContractError::RecordNotFound) and [ScopeNotFound](self::ContractError::ScopeNotFound)
+    /// variants, as well as an intercepted [StdError::NotFound](cosmwasm_std::StdError::NotFound).
+    pub fn is_not_found(&self) -> bool {
+        matches!(
+            self,
+            ContractError::NotFound { .. }
+                | ContractError::AssetNotFound { .. }
+                | ContractError::RecordNotFound { .. }
+                | ContractError::ScopeNotFound { .. }
+                | ContractError::Std(StdError::NotFound { .. })
+        )
+    }
+
+    /// Determines if this error indicates that the sender was not permitted to perform an action.
+    /// Matches the [Unauthorized](self::ContractError::Unauthorized) and [UnauthorizedAssetVerifier](self::ContractError::UnauthorizedAssetVerifier)
+    /// variants.
+    pub fn is_unauthorized(&self) -> bool {
+        matches!(
+            self,
+            ContractError::Unauthorized { .. } | ContractError::UnauthorizedAssetVerifier { .. }
+        )
+    }
+
+    /// Determines if this error is an [InvalidMessageFields](self::ContractError::InvalidMessageFields)
+    /// variant.
+    pub fn is_invalid_message_fields(&self) -> bool {
+        matches!(self, ContractError::InvalidMessageFields { .. })
+    }
+
+    /// Determines if this error is a [GenericError](self::ContractError::GenericError) variant or an
+    /// intercepted [StdError::GenericErr](cosmwasm_std::StdError::GenericErr).
+    pub fn is_generic_error(&self) -> bool {
+        matches!(
+            self,
+            ContractError::GenericError { .. } | ContractError::Std(StdError::GenericErr { .. })
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::StdError;
+
+    use crate::core::error::ContractError;
+
+    #[test]
+    fn test_is_not_found() {
+        let not_found_errors = vec![
+            ContractError::NotFound {
+                explanation: "verifier".to_string(),
+            },
+            ContractError::AssetNotFound {
+                scope_address: "scope".to_string(),
+            },
+            ContractError::RecordNotFound {
+                explanation: "definition".to_string(),
+            },
+            ContractError::ScopeNotFound {
+                scope_address: "scope".to_string(),
+            },
+            ContractError::Std(StdError::not_found("FeePaymentDetail")),
+        ];
+        for error in not_found_errors {
+            assert!(
+                error.is_not_found(),
+                "expected not found but got: {:?}",
+                error,
+            );
+        }
+        assert!(
+            !ContractError::generic("oops").is_not_found(),
+            "a generic error should not be considered a not found error",
+        );
+    }
+
+    #[test]
+    fn test_is_unauthorized() {
+        assert!(
+            ContractError::Unauthorized {
+                explanation: "admin only".to_string(),
+            }
+            .is_unauthorized(),
+            "the unauthorized variant should be considered unauthorized",
+        );
+        assert!(
+            ContractError::UnauthorizedAssetVerifier {
+                scope_address: "scope".to_string(),
+                asset_type: "heloc".to_string(),
+                verifier_address: "verifier".to_string(),
+                expected_verifier_address: "other".to_string(),
+            }
+            .is_unauthorized(),
+            "the unauthorized asset verifier variant should be considered unauthorized",
+        );
+        assert!(
+            !ContractError::Unimplemented.is_unauthorized(),
+            "an unrelated error should not be considered unauthorized",
+        );
+    }
+
+    #[test]
+    fn test_is_invalid_message_fields() {
+        assert!(
+            ContractError::InvalidMessageFields {
+                message_type: "ExecuteMsg::OnboardAsset".to_string(),
+                invalid_fields: vec![],
+            }
+            .is_invalid_message_fields(),
+            "the invalid message fields variant should be detected",
+        );
+        assert!(
+            !ContractError::Unimplemented.is_invalid_message_fields(),
+            "an unrelated error should not be considered an invalid message fields error",
+        );
+    }
+
+    #[test]
+    fn test_is_generic_error() {
+        assert!(
+            ContractError::generic("oops").is_generic_error(),
+            "the generic error variant should be detected",
+        );
+        assert!(
+            ContractError::Std(StdError::generic_err("oops")).is_generic_error(),
+            "an intercepted generic std error should be detected",
+        );
+        assert!(
+            !ContractError::Unimplemented.is_generic_error(),
+            "an unrelated error should not be considered a generic error",
+        );
+    }
+
+    #[test]
+    fn test_multi_wraps_errors() {
+        let error = ContractError::multi(vec![
+            ContractError::generic("first"),
+            ContractError::Unimplemented,
+        ]);
+        match error {
+            ContractError::MultipleErrors { errors } => {
+                assert_eq!(2, errors.len(), "both errors should be wrapped");
+                assert!(
+                    errors[0].is_generic_error(),
+                    "the first error should be the generic error",
+                );
+                assert!(
+                    matches!(errors[1], ContractError::Unimplemented),
+                    "the second error should be the unimplemented error",
+                );
+            }
+            e => panic!("unexpected error encountered: {:?}", e),
+        };
+    }
+
+    #[test]
+    fn test_multi_error_message_includes_each_error() {
+        let error = ContractError::multi(vec![
+            ContractError::generic("first problem"),
+            ContractError::NotFound {
+                explanation: "second problem".to_string(),
+            },
+        ]);
+        assert_eq!(
+            "Multiple errors occurred: [first problem; Resource not found: second problem]",
+            error.to_string(),
+            "the error message should include the message of each wrapped error",
+        );
+    }
+
+    #[test]
+    fn test_flatten_to_message_fields_combines_fields() {
+        let error = ContractError::flatten_to_message_fields(vec![
+            invalid_fields("InitMsg", vec!["first"]),
+            ContractError::multi(vec![
+                invalid_fields("InitMsg", vec!["second"]),
+                invalid_fields("AssetDefinition", vec!["third"]),
+            ]),
+        ]);
+        match error {
+            ContractError::InvalidMessageFields {
+                message_type,
+                invalid_fields,
+            } => {
+                assert_eq!(
+                    "InitMsg, AssetDefinition", message_type,
+                    "the distinct message types should be joined in the order they were encountered",
+                );
+                assert_eq!(
+                    vec!["first", "second", "third"],
+                    invalid_fields,
+                    "all invalid fields should be combined in order, including nested fields",
+                );
+            }
+            e => panic!("unexpected error encountered: {:?}", e),
+        };
+    }
+
+    #[test]
+    fn test_flatten_to_message_fields_preserves_other_errors() {
+        let error = ContractError::flatten_to_message_fields(vec![
+            ContractError::generic("unrelated"),
+            invalid_fields("InitMsg", vec!["first"]),
+        ]);
+        match error {
+            ContractError::MultipleErrors { errors } => {
+                assert_eq!(2, errors.len(), "both errors should be present");
+                assert!(
+                    errors[0].is_invalid_message_fields(),
+                    "the combined invalid message fields error should come first",
+                );
+                assert!(
+                    errors[1].is_generic_error(),
+                    "the unrelated error should be preserved",
+                );
+            }
+            e => panic!("unexpected error encountered: {:?}", e),
+        };
+    }
+
+    fn invalid_fields(message_type: &str, fields: Vec<&str>) -> ContractError {
+        ContractError::InvalidMessageFields {
+            message_type: message_type.to_string(),
+            invalid_fields: fields.into_iter().map(|f| f.to_string()).collect(),
+        }
+    }
 }
@@ -1,11 +1,36 @@
 use crate::core::state::StateV2;
+use crate::core::types::access_definitions_response::AccessDefinitionsResponse;
+use crate::core::types::access_route_owner_entry::AccessRouteOwnerEntry;
 use crate::core::types::asset_definition::{AssetDefinitionInputV3, AssetDefinitionV3};
+use crate::core::types::asset_definition_count_response::AssetDefinitionCountResponse;
+use crate::core::types::asset_definitions_page_response::AssetDefinitionsPageResponse;
 use crate::core::types::asset_scope_attribute::AssetScopeAttribute;
+use crate::core::types::assets_by_requestor_response::AssetsByRequestorResponse;
+use crate::core::types::batch_onboard_asset::BatchOnboardAssetEntry;
+use crate::core::types::batch_scope_attribute::{
+    BatchScopeAttributeRequest, BatchScopeAttributeResponse,
+};
+use crate::core::types::batch_verify_asset::BatchVerifyAssetEntry;
+use crate::core::types::contract_metrics_response::ContractMetricsResponse;
+use crate::core::types::contract_statistics_response::ContractStatisticsResponse;
+use crate::core::types::count_response::CountResponse;
+use crate::core::types::deprecated_definition_entry::DeprecatedDefinitionEntry;
+use crate::core::types::entity_detail::EntityDetail;
 use crate::core::types::fee_payment_detail::FeePaymentDetail;
+use crate::core::types::fee_preview_response::FeePreviewResponse;
+use crate::core::types::latest_verification_result_response::LatestVerificationResultResponse;
+use crate::core::types::os_gateway_access::OsGatewayAccess;
+use crate::core::types::query_all_response::QueryAllResponse;
+use crate::core::types::scope_spec_binding_response::ScopeSpecBindingResponse;
 use crate::core::types::serialized_enum::SerializedEnum;
+use crate::core::types::storage_key_count_response::StorageKeyCountResponse;
+use crate::core::types::verifier_count_response::VerifierCountResponse;
 use crate::core::types::verifier_detail::VerifierDetailV2;
+use crate::core::types::verifier_pending_count_response::VerifierPendingCountResponse;
+use crate::core::types::verifier_stats::VerifierStats;
 use crate::migrate::version_info::VersionInfoV1;
 use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
 
 use super::types::access_route::AccessRoute;
 
@@ -29,6 +54,18 @@ pub struct InitMsg {
     /// onboarding process to determine if onboarded scopes include underlying record values.  This
     /// should never be set to true in a mainnet environment.
     pub is_test: Option<bool>,
+    /// The maximum number of [AccessRoutes](super::types::access_route::AccessRoute) that a single
+    /// owner may hold on an asset.  Must be greater than zero when provided, and defaults to 10
+    /// when omitted.  It can later be changed via [SetMaxAccessRoutesPerOwner](self::ExecuteMsg::SetMaxAccessRoutesPerOwner).
+    pub max_access_routes_per_owner: Option<u32>,
+    /// When true, verifier and fee destination addresses are not required to use one of the
+    /// [VALID_VERIFIER_ADDRESS_PREFIXES](crate::util::constants::VALID_VERIFIER_ADDRESS_PREFIXES).
+    /// Intended for testing.  Defaults to false when omitted.
+    pub allow_all_bech32_prefixes: Option<bool>,
+    /// A value prepended to every Object Store Gateway grant id generated by the contract, allowing
+    /// multiple contract instances to share a single gateway without grant id collisions.  Must
+    /// not be blank when provided.  It can later be changed via [UpdateOsGatewayGrantPrefix](self::ExecuteMsg::UpdateOsGatewayGrantPrefix).
+    pub os_gateway_grant_prefix: Option<String>,
 }
 
 /// Defines all routes in which the contract can be queried.  These are all handled directly in
@@ -36,6 +73,52 @@ pub struct InitMsg {
 #[cw_serde]
 #[derive(cosmwasm_schema::QueryResponses)]
 pub enum QueryMsg {
+    /// This route can be used to retrieve every [AccessDefinition](super::types::access_definition::AccessDefinition)
+    /// attached to a scope by this contract, across all of its asset types, without needing to know which asset types the
+    /// scope was classified as.  Each definition is tagged with the asset type in which it was found, and duplicate access
+    /// routes are omitted.  It responds with an [AccessDefinitionsResponse](super::types::access_definitions_response::AccessDefinitionsResponse).
+    #[returns(AccessDefinitionsResponse)]
+    QueryAccessDefinitionsForScope {
+        /// The bech32 address of the scope for which to retrieve access definitions.
+        scope_address: String,
+    },
+    /// This route can be used to retrieve a single page of every [AccessRoute](super::types::access_route::AccessRoute)
+    /// owned by an address, across all scopes and asset types, sorted in ascending order by scope address.  Each
+    /// [AccessRouteOwnerEntry](super::types::access_route_owner_entry::AccessRouteOwnerEntry) contains the owned routes for
+    /// a single scope and asset type.  The scope address of the final entry in a page can be provided as the start after
+    /// value to retrieve the following page.
+    #[returns(Vec<AccessRouteOwnerEntry>)]
+    QueryAccessRoutesByOwner {
+        /// The bech32 address of the account that owns the access routes.
+        owner_address: String,
+        /// The scope address of the final entry in a previous page.  When omitted, the first page is returned.
+        start_after: Option<String>,
+        /// The maximum number of scopes to include in the page.  Must be greater than zero.  Values greater than
+        /// [MAX_ACCESS_ROUTE_OWNER_SCOPES_PER_PAGE](crate::util::constants::MAX_ACCESS_ROUTE_OWNER_SCOPES_PER_PAGE) are
+        /// reduced to that limit, which is also used when this value is omitted.
+        limit: Option<u32>,
+    },
+    /// This route is a debugging tool that returns the contract's [state](super::state::StateV2),
+    /// [version info](crate::migrate::version_info::VersionInfoV1) and all stored [AssetDefinitionV3s](super::types::asset_definition::AssetDefinitionV3)
+    /// in a single response.  It is only available when the contract was instantiated in test mode,
+    /// and will otherwise be rejected with an unauthorized error.
+    #[returns(QueryAllResponse)]
+    QueryAll {},
+    /// This route can be used to retrieve a single page of [VerifierStats](super::types::verifier_stats::VerifierStats),
+    /// sorted in ascending order by asset type and then by verifier address.  The asset type and verifier address of the
+    /// final statistics in a page can be provided as the start after value to retrieve the following page.
+    #[returns(Vec<VerifierStats>)]
+    QueryAllVerifierStatistics {
+        /// An optional asset type filter.  If provided, only statistics for this asset type are returned.
+        asset_type: Option<String>,
+        /// The asset type and verifier address of the final statistics in a previous page.  When omitted, the first page
+        /// is returned.
+        start_after: Option<(String, String)>,
+        /// The maximum number of statistics to include in the page.  Must be greater than zero.  Values greater than
+        /// [MAX_VERIFIER_STATISTICS_PER_PAGE](crate::util::constants::MAX_VERIFIER_STATISTICS_PER_PAGE) are reduced to
+        /// that limit, which is also used when this value is omitted.
+        limit: Option<u32>,
+    },
     /// This route can be used to retrieve a specific [AssetDefinitionV3](super::types::asset_definition::AssetDefinitionV3) from the contract's
     /// internal storage for inspection of its verifies and other properties.  If the requested value is not found, a null
     /// response will be returned.
@@ -47,8 +130,44 @@ pub enum QueryMsg {
     /// This route can be used to retrieve all [AssetDefinitionV3s](super::types::asset_definition::AssetDefinitionV3) stored in the contract.  This response payload can be quite
     /// large if many complex definitions are stored, so it should only used in circumstances where all asset definitions need
     /// to be inspected or displayed.  The query asset definition route is much more efficient.
+    ///
+    /// Soft-deprecated: this route is retained for backwards compatibility, but the [QueryAssetDefinitionsPage](self::QueryMsg::QueryAssetDefinitionsPage)
+    /// route should be used instead, as it is not bounded by the number of stored definitions.
     #[returns(Vec<AssetDefinitionV3>)]
     QueryAssetDefinitions {},
+    /// This route can be used to retrieve the total number of [AssetDefinitionV3s](super::types::asset_definition::AssetDefinitionV3)
+    /// stored in the contract.  The value is read from a cached count, making this a cheap alternative to the query asset
+    /// definitions route for health checks.  It responds with an [AssetDefinitionCountResponse](super::types::asset_definition_count_response::AssetDefinitionCountResponse).
+    #[returns(AssetDefinitionCountResponse)]
+    QueryAssetDefinitionCount {},
+    /// This route can be used to retrieve a single page of [AssetDefinitionV3s](super::types::asset_definition::AssetDefinitionV3)
+    /// stored in the contract, sorted in ascending order by asset type.  The response includes the total number of stored
+    /// definitions, as well as a page token that can be provided in a subsequent query to retrieve the following page.  This
+    /// route should be preferred over the query asset definitions route when many definitions are stored.
+    #[returns(AssetDefinitionsPageResponse)]
+    QueryAssetDefinitionsPage {
+        /// The maximum number of asset definitions to include in the page.  Must be greater than zero.  Values
+        /// greater than [MAX_DEFINITIONS_PER_PAGE](crate::util::constants::MAX_DEFINITIONS_PER_PAGE) are reduced to
+        /// that limit, which is also used when this value is omitted.
+        page_size: Option<u32>,
+        /// The next page token returned in a previous page's response.  When omitted, the first page is returned.
+        page_token: Option<String>,
+    },
+    /// This route can be used to retrieve a single page of the [AssetDefinitionV3s](super::types::asset_definition::AssetDefinitionV3)
+    /// that carry a specific [label](super::types::asset_definition::AssetDefinitionV3::labels), sorted in ascending order by
+    /// asset type.  The asset type of the final definition in a page can be provided as the start after value to retrieve the
+    /// following page.
+    #[returns(Vec<AssetDefinitionV3>)]
+    QueryAssetDefinitionsByLabel {
+        /// The label that every returned asset definition must carry.
+        label: String,
+        /// The asset type of the final definition in a previous page.  When omitted, the first page is returned.
+        start_after: Option<String>,
+        /// The maximum number of asset definitions to include in the page.  Must be greater than zero.  Values greater than
+        /// [MAX_DEFINITIONS_PER_PAGE](crate::util::constants::MAX_DEFINITIONS_PER_PAGE) are reduced to that limit, which is
+        /// also used when this value is omitted.
+        limit: Option<u32>,
+    },
     /// This route can be used to retrieve a list of existing [AssetScopeAttribute](super::types::asset_scope_attribute::AssetScopeAttribute)s that have
     /// been added to a [Provenance Metadata Scope](https://docs.provenance.io/modules/metadata-module#metadata-scope) by this
     /// contract.  This route will return a null (empty option) if the scope has never had a scope attribute added to it by the contract.
@@ -73,6 +192,65 @@ pub enum QueryMsg {
         /// The asset type to query for
         asset_type: String,
     },
+    /// This route can be used to resolve the [AssetScopeAttribute](super::types::asset_scope_attribute::AssetScopeAttribute)s for
+    /// many scope and asset type pairs in a single call.  Each request is resolved independently, so a failure for one pair
+    /// is reported in its result without failing the entire query.  At most [MAX_SCOPE_ATTRIBUTE_BATCH_SIZE](crate::util::constants::MAX_SCOPE_ATTRIBUTE_BATCH_SIZE)
+    /// requests may be provided.  It responds with a [BatchScopeAttributeResponse](super::types::batch_scope_attribute::BatchScopeAttributeResponse).
+    #[returns(BatchScopeAttributeResponse)]
+    QueryAssetScopeAttributesBatch {
+        /// The scope and asset type pairs to resolve.
+        requests: Vec<BatchScopeAttributeRequest>,
+    },
+    /// This route can be used to retrieve the [AssetScopeAttribute](super::types::asset_scope_attribute::AssetScopeAttribute)s
+    /// of all scopes onboarded by a specific requestor, sorted in ascending order by scope address.  Scopes are located via an
+    /// index maintained by the contract as assets are onboarded, so only scopes onboarded after the index was introduced are
+    /// included.  The response includes a cursor that can be provided in a subsequent query to retrieve the following page.
+    /// It responds with an [AssetsByRequestorResponse](super::types::assets_by_requestor_response::AssetsByRequestorResponse).
+    #[returns(AssetsByRequestorResponse)]
+    QueryAssetsByRequestor {
+        /// The bech32 address of the account that onboarded the scopes.
+        requestor_address: String,
+        /// An optional asset type filter.  If provided, only scope attributes of this asset type are returned.
+        asset_type: Option<String>,
+        /// The next start after value returned in a previous page's response.  When omitted, the first page is returned.
+        start_after: Option<String>,
+        /// The maximum number of scopes to include in the page.  Must be greater than zero.  Values greater than
+        /// [MAX_REQUESTOR_ASSETS_PER_PAGE](crate::util::constants::MAX_REQUESTOR_ASSETS_PER_PAGE) are reduced to that
+        /// limit, which is also used when this value is omitted.
+        limit: Option<u32>,
+    },
+    /// This route can be used to retrieve the operational metrics tracked by the contract, including the total number of
+    /// onboards and verifications, the fees collected, and the onboard count for each asset type.  It responds with a
+    /// [ContractMetricsResponse](super::types::contract_metrics_response::ContractMetricsResponse).
+    #[returns(ContractMetricsResponse)]
+    QueryContractMetrics {},
+    /// This route can be used to retrieve aggregate counts and totals describing the contract's contents, including the
+    /// number of asset definitions, the number of assets in each onboarding status, the number of stored fee payment
+    /// details, and the number of distinct assets onboarded and verified over the contract's lifetime.  It responds with a
+    /// [ContractStatisticsResponse](super::types::contract_statistics_response::ContractStatisticsResponse).
+    #[returns(ContractStatisticsResponse)]
+    QueryContractStatistics {},
+    /// This route can be used to retrieve every [AssetDefinitionV3](super::types::asset_definition::AssetDefinitionV3) that
+    /// has been [deprecated](super::types::asset_definition::AssetDefinitionV3::deprecated), along with the asset type that
+    /// requestors should migrate to, if any.  Definitions are sorted in ascending order by asset type.  It responds with a
+    /// vector of [DeprecatedDefinitionEntry](super::types::deprecated_definition_entry::DeprecatedDefinitionEntry) values.
+    #[returns(Vec<DeprecatedDefinitionEntry>)]
+    QueryDeprecatedDefinitions {},
+    /// This route can be used to calculate the exact [FeePaymentDetail](super::types::fee_payment_detail::FeePaymentDetail)
+    /// that would be stored if a scope were onboarded via the [OnboardAsset](self::ExecuteMsg::OnboardAsset) route with a
+    /// specific verifier in the current block.  The scope's existing [AssetScopeAttribute](super::types::asset_scope_attribute::AssetScopeAttribute)s
+    /// are inspected to detect retries and subsequent classification discounts in the same manner as onboarding, including
+    /// withholding retry costs when a different verifier than the one that denied the scope is selected.  The request is
+    /// rejected with the same errors as [QueryOnboardingFeePreview](self::QueryMsg::QueryOnboardingFeePreview).
+    #[returns(FeePaymentDetail)]
+    QueryEffectiveFee {
+        /// The bech32 address of the scope to onboard.
+        scope_address: String,
+        /// The asset type to onboard the scope as.
+        asset_type: String,
+        /// The bech32 address of the verifier that would be selected for onboarding.
+        verifier_address: String,
+    },
     /// This route can be used to retrieve an existing [FeePaymentDetail](super::types::fee_payment_detail::FeePaymentDetail)
     /// that has been stored from a [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2)
     /// during the [OnboardAsset](self::ExecuteMsg::OnboardAsset) execution route's processes.  This
@@ -86,11 +264,120 @@ pub enum QueryMsg {
         /// The asset type to query for pending verification fee payment details
         asset_type: String,
     },
+    /// This route can be used to retrieve only the verification status of an existing [AssetScopeAttribute](super::types::asset_scope_attribute::AssetScopeAttribute)
+    /// for a specific asset type.  It is a lighter-weight alternative to the [QueryAssetScopeAttributeForAssetType](self::QueryMsg::QueryAssetScopeAttributeForAssetType)
+    /// route for consumers that poll for verification results and do not need the full attribute payload.  This route will return
+    /// a null (empty option) if the scope has never had a scope attribute added to it by the contract for the asset type.
+    #[returns(Option<LatestVerificationResultResponse>)]
+    QueryLatestVerificationResult {
+        /// Expects an [AssetIdentifier](super::types::asset_identifier::AssetIdentifier)-compatible
+        /// [SerializedEnum](super::types::serialized_enum::SerializedEnum).
+        identifier: SerializedEnum,
+        /// The asset type to query for
+        asset_type: String,
+    },
+    /// This route can be used to calculate the fees that would be charged if a scope were onboarded via the [OnboardAsset](self::ExecuteMsg::OnboardAsset)
+    /// route with a specific verifier, allowing a requestor to review the cost before submitting the onboarding transaction.
+    /// Discounts for subsequent classifications and retry costs are applied based on the scope's existing
+    /// [AssetScopeAttribute](super::types::asset_scope_attribute::AssetScopeAttribute)s.  The request is rejected with the same
+    /// errors as onboarding if the asset type is unsupported or disabled, or if the verifier is not registered to the asset type.
+    /// It responds with a [FeePreviewResponse](super::types::fee_preview_response::FeePreviewResponse).
+    #[returns(FeePreviewResponse)]
+    QueryOnboardingFeePreview {
+        /// The bech32 address of the scope to onboard.
+        scope_address: String,
+        /// The asset type to onboard the scope as.
+        asset_type: String,
+        /// The bech32 address of the verifier that would be selected for onboarding.
+        verifier_address: String,
+    },
+    /// This route can be used to retrieve a single page of the [AssetScopeAttributes](super::types::asset_scope_attribute::AssetScopeAttribute)
+    /// currently awaiting verification by a specific verifier, sorted in ascending order by scope address.  Scopes that have
+    /// been approved or denied are not included.  Each page includes every pending asset type of the scopes it contains, and
+    /// the scope address of the final attribute in a page can be provided as the start after value to retrieve the following
+    /// page.
+    #[returns(Vec<AssetScopeAttribute>)]
+    QueryPendingVerificationsByVerifier {
+        /// The bech32 address of the verifier for which to list pending scopes.
+        verifier_address: String,
+        /// An optional asset type filter.  If provided, only scope attributes of this asset type are returned.
+        asset_type: Option<String>,
+        /// The scope address of the final attribute in a previous page.  When omitted, the first page is returned.
+        start_after: Option<String>,
+        /// The maximum number of scopes to include in the page.  Must be greater than zero.  Values greater than
+        /// [MAX_PENDING_VERIFICATIONS_PER_PAGE](crate::util::constants::MAX_PENDING_VERIFICATIONS_PER_PAGE) are reduced to
+        /// that limit, which is also used when this value is omitted.
+        limit: Option<u32>,
+    },
+    /// This route can be used to retrieve the scope addresses of all assets whose [AssetScopeAttributes](super::types::asset_scope_attribute::AssetScopeAttribute)
+    /// were modified by the contract after a specific block height, sorted in ascending order by the block of their most recent
+    /// modification.  Each scope address is included only once, regardless of how many of its asset types were modified.
+    #[returns(Vec<String>)]
+    QueryRecentlyModifiedScopes {
+        /// The block height after which scopes must have been modified to be included.
+        after_block: u64,
+        /// An optional asset type filter.  If provided, only scopes modified as this asset type are returned.
+        asset_type: Option<String>,
+    },
     /// This route can be used to retrieve the internal contract state values.  These are core configurations that denote how
     /// the contract behaves.  They reflect the values created at instantiation and potentially modified during migration.  It
     /// responds with a [StateV2](super::state::StateV2) struct value.
     #[returns(StateV2)]
     QueryState {},
+    /// This route can be used to count the [AssetScopeAttributes](super::types::asset_scope_attribute::AssetScopeAttribute)
+    /// attached to a Provenance Blockchain Metadata Scope by this contract.  Attributes are matched by their generated attribute
+    /// names and are never deserialized, making this a cheap alternative to the query asset scope attribute routes for
+    /// dashboards.  If an asset type is provided and no asset definition exists for it, the request will be rejected.  It
+    /// responds with a [CountResponse](super::types::count_response::CountResponse).
+    #[returns(CountResponse)]
+    QueryScopeAttributeCount {
+        /// The bech32 address of the scope for which to count attributes.
+        scope_address: String,
+        /// An optional asset type to restrict the count to.  If omitted, attributes for every asset type are counted.
+        asset_type: Option<String>,
+    },
+    /// This route can be used to determine which [AssetDefinitionV3](super::types::asset_definition::AssetDefinitionV3)
+    /// is bound to a scope specification via its [scope_spec_address](super::types::asset_definition::AssetDefinitionV3::scope_spec_address).
+    /// It responds with a [ScopeSpecBindingResponse](super::types::scope_spec_binding_response::ScopeSpecBindingResponse),
+    /// which contains no asset type if no definition is bound to the scope specification.
+    #[returns(ScopeSpecBindingResponse)]
+    QueryScopeSpecificationBinding {
+        /// The bech32 address of the scope specification to look up.
+        scope_spec_address: String,
+    },
+    /// This route is a debugging tool that counts the keys held in each of the contract's storage maps, allowing storage
+    /// usage to be audited.  It is only available when the contract was instantiated in test mode, and will otherwise be
+    /// rejected with an unauthorized error.  It responds with a [StorageKeyCountResponse](super::types::storage_key_count_response::StorageKeyCountResponse).
+    #[returns(StorageKeyCountResponse)]
+    QueryStorageKeyCount {},
+    /// This route can be used to retrieve the number of [VerifierDetailV2s](super::types::verifier_detail::VerifierDetailV2)
+    /// registered to a specific [AssetDefinitionV3](super::types::asset_definition::AssetDefinitionV3).  If no asset definition
+    /// exists for the given asset type, the request will be rejected.  It responds with a [VerifierCountResponse](super::types::verifier_count_response::VerifierCountResponse).
+    #[returns(VerifierCountResponse)]
+    QueryVerifierCount {
+        /// The asset type of the asset definition for which to count verifiers.
+        asset_type: String,
+    },
+    /// This route can be used to retrieve the number of assets currently awaiting verification by a specific verifier.  It is
+    /// useful for determining a verifier's current workload, and responds with a [VerifierPendingCountResponse](super::types::verifier_pending_count_response::VerifierPendingCountResponse).
+    #[returns(VerifierPendingCountResponse)]
+    QueryVerifierPendingCount {
+        /// The bech32 address of the verifier for which to count pending assets.
+        verifier_address: String,
+        /// An optional asset type filter.  If omitted, pending assets of all types are counted.
+        asset_type: Option<String>,
+    },
+    /// This route can be used to retrieve the [VerifierStats](super::types::verifier_stats::VerifierStats) of a specific
+    /// verifier for a specific asset type, which count the assets it has approved and denied, as well as those currently
+    /// awaiting its verification.  A verifier that has never been assigned an onboarding of the asset type responds with
+    /// zeroed statistics.
+    #[returns(VerifierStats)]
+    QueryVerifierStatistics {
+        /// The asset type for which to fetch the verifier's statistics.
+        asset_type: String,
+        /// The bech32 address of the verifier for which to fetch statistics.
+        verifier_address: String,
+    },
     /// This route can be used to retrieve the internal contract version information.  It elucidates the current version of the
     /// contract that was derived through instantiation or the most recent code migration.  It responds with a [VersionInfoV1](crate::migrate::version_info::VersionInfoV1)
     /// struct value.
@@ -130,6 +417,8 @@ pub enum ExecuteMsg {
         /// they can always be added by using the [UpdateAccessRoutes](self::ExecuteMsg::UpdateAccessRoutes) execution route.
         /// Note: Access routes can specify a [name](super::types::access_route::AccessRoute::name)
         /// parameter, as well, to indicate the reason for the route, but this is entirely optional.
+        /// The request is rejected if more routes are provided than the contract's
+        /// [max_access_routes_per_owner](super::state::StateV2::max_access_routes_per_owner).
         access_routes: Option<Vec<AccessRoute>>,
         /// An optional parameter that will cause the emitted events to include values that signal
         /// to any [Object Store Gateway](https://github.com/FigureTechnologies/object-store-gateway)
@@ -141,6 +430,29 @@ pub enum ExecuteMsg {
         ///
         /// This behavior defaults to TRUE.
         add_os_gateway_permission: Option<bool>,
+        /// An optional parameter that protects the requestor from fee changes made after the fee was
+        /// previewed with the [QueryOnboardingFeePreview](self::QueryMsg::QueryOnboardingFeePreview) route.
+        /// When provided, the request is rejected unless the total fee charged for the onboarding
+        /// exactly matches this amount.
+        expected_fee: Option<Uint128>,
+        /// An optional free-form note from the requestor that is emitted with the onboarding event
+        /// under the `asset_onboarding_memo` key.  Blank memos are not emitted, and memos may not
+        /// exceed 256 characters.
+        memo: Option<String>,
+        /// An optional free-form string, such as serialized json, that is stored unmodified in the
+        /// [requestor_metadata](super::types::asset_scope_attribute::AssetScopeAttribute::requestor_metadata)
+        /// of the scope attribute.  The contract never interprets this value, and it may not exceed
+        /// 1024 bytes.
+        metadata: Option<String>,
+    },
+    /// This route performs the same process as the [OnboardAsset](self::ExecuteMsg::OnboardAsset) route for many scopes in a
+    /// single transaction, combining the messages and events produced for each entry into a single response.  If any entry fails
+    /// to onboard, the entire batch is rejected and no scopes are onboarded.  Between one and [MAX_BATCH_ONBOARD_SIZE](crate::util::constants::MAX_BATCH_ONBOARD_SIZE)
+    /// entries must be provided, and no two entries may target the same scope and asset type.  Each entry's object store
+    /// gateway access grant is emitted via [RelayOsGatewayAccess](self::ExecuteMsg::RelayOsGatewayAccess).
+    BatchOnboardAsset {
+        /// Each scope to onboard, processed in the order provided.
+        entries: Vec<BatchOnboardAssetEntry>,
     },
     /// This route is specifically designed to allow a Verifier specified in the [AssetScopeAttribute](super::types::asset_scope_attribute::AssetScopeAttribute)
     /// of a [Provenance Metadata Scope](https://docs.provenance.io/modules/metadata-module#scope-data-structures) to indicate to
@@ -173,6 +485,16 @@ pub enum ExecuteMsg {
         /// interaction.
         access_routes: Option<Vec<AccessRoute>>,
     },
+    /// This route performs the same process as the [VerifyAsset](self::ExecuteMsg::VerifyAsset) route for many scopes in a
+    /// single transaction, combining the messages and events produced for each entry into a single response.  If any entry fails
+    /// to verify, including when its scope is no longer pending verification, the entire batch is rejected and no scopes are
+    /// verified.  Between one and [MAX_BATCH_VERIFY_SIZE](crate::util::constants::MAX_BATCH_VERIFY_SIZE) entries must be provided,
+    /// and no two entries may target the same scope and asset type.  Each entry's object store gateway access revocation is
+    /// emitted via [RelayOsGatewayAccess](self::ExecuteMsg::RelayOsGatewayAccess).
+    BatchVerifyAsset {
+        /// Each scope to verify, processed in the order provided.
+        entries: Vec<BatchVerifyAssetEntry>,
+    },
     /// __This route is only accessible to the contract's admin address.__  This route allows a new [AssetDefinitionV3](super::types::asset_definition::AssetDefinitionV3)
     /// value to be added to the contract's internal storage.  These asset definitions dictate which asset types are allowed to
     /// be onboarded, as well as which verifiers are tied to each asset type.  Each added asset definition must be unique in
@@ -183,6 +505,23 @@ pub enum ExecuteMsg {
         /// values.  The execution route converts the incoming value to an asset definition.
         asset_definition: AssetDefinitionInputV3,
     },
+    /// __This route is only accessible to the contract's admin address.__  This route adds a new [AssetDefinitionV3](super::types::asset_definition::AssetDefinitionV3)
+    /// to the contract's internal storage, copying the [verifiers](super::types::asset_definition::AssetDefinitionV3::verifiers)
+    /// of an existing asset definition.  The new asset definition is always enabled, and no other values are copied from the
+    /// source.  The new [asset_type](super::types::asset_definition::AssetDefinitionV3::asset_type) must not yet be
+    /// registered in a different asset definition.
+    CloneAssetDefinition {
+        /// The type of the existing asset definition from which the verifiers will be copied.
+        source_asset_type: String,
+        /// The unique type of the new asset definition.
+        new_asset_type: String,
+        /// An optional human-readable name for the new asset type.
+        new_display_name: Option<String>,
+        /// An optional parameter.  If omitted or provided as `true`, the contract will attempt to
+        /// bind a name branched off of its [base_contract_name](super::state::StateV2::base_contract_name)
+        /// with the new asset type, exactly as is done when an asset definition is added.
+        bind_name: Option<bool>,
+    },
     /// __This route is only accessible to the contract's admin address.__ This route allows an existing [AssetDefinitionV3](super::types::asset_definition::AssetDefinitionV3)
     /// value to be updated.  It works by matching the input's [asset_type](super::types::asset_definition::AssetDefinitionV3::asset_type) to an existing asset definition and overwriting the
     /// existing values.  If no asset definition exists for the given type, the request will be rejected.
@@ -190,6 +529,21 @@ pub enum ExecuteMsg {
         /// An asset definition input value defining all of the updated [AssetDefinitionV3](super::types::asset_definition::AssetDefinitionV3)'s
         /// values.  The execution route converts the incoming value to an asset definition.
         asset_definition: AssetDefinitionInputV3,
+        /// Whether or not to perform a partial update.  When set to `true`, only the values provided
+        /// in the `asset_definition` overwrite the existing definition's values.  An omitted `display_name`
+        /// or `enabled` value retains the existing value, and an empty `verifiers` vector retains the
+        /// existing verifiers.  Defaults to `false`, which replaces the entire definition.
+        partial: Option<bool>,
+    },
+    /// __This route is only accessible to the contract's admin address.__ This route replaces the [display_name](super::types::asset_definition::AssetDefinitionV3::display_name)
+    /// of an existing [AssetDefinitionV3](super::types::asset_definition::AssetDefinitionV3) without the bulky requirements of the
+    /// [UpdateAssetDefinition](self::ExecuteMsg::UpdateAssetDefinition) execution route.  All other values, including the
+    /// definition's verifiers, are left untouched.  If no asset definition exists for the given type, the request will be rejected.
+    UpdateAssetDefinitionDescription {
+        /// The type of asset for which the definition's display name will be replaced.
+        asset_type: String,
+        /// The new human-readable name for the asset type.  If omitted, the existing display name is cleared.
+        display_name: Option<String>,
     },
     /// __This route is only accessible to the contract's admin address.__ This route toggles an existing [AssetDefinitionV3](super::types::asset_definition::AssetDefinitionV3)
     /// from enabled to disabled, or disabled to enabled.  When disabled, an asset definition will no longer allow new assets to
@@ -236,7 +590,69 @@ pub enum ExecuteMsg {
         /// value, or the request will be rejected.
         verifier: VerifierDetailV2,
     },
-    /// __This route is only accessible to the contract's admin address OR to the owner of the access routes being updated.__
+    /// __This route is only accessible to the contract's admin address.__ This route toggles a single
+    /// [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2) on an existing
+    /// [AssetDefinitionV3](super::types::asset_definition::AssetDefinitionV3) from enabled to
+    /// disabled, or disabled to enabled.  When disabled, the verifier can no longer be selected for
+    /// new onboarding requests, but assets already awaiting its verification can still be verified.
+    /// Other verifiers on the same asset definition are unaffected.
+    ToggleAssetVerifier {
+        /// The type of asset for which the verifier will be toggled.
+        asset_type: String,
+        /// The bech32 address of the verifier to toggle.
+        verifier_address: String,
+        /// The value of [enabled](super::types::verifier_detail::VerifierDetailV2::enabled) after the
+        /// toggle takes place.  This value is required to ensure that multiple toggles executed in
+        /// succession will only be honored if the verifier is in the intended state during the
+        /// execution of the route.
+        expected_result: bool,
+    },
+    /// __This route is only accessible to the contract's admin address.__ This route replaces the
+    /// [entity_detail](super::types::verifier_detail::VerifierDetailV2::entity_detail) of a single
+    /// [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2) on an existing
+    /// [AssetDefinitionV3](super::types::asset_definition::AssetDefinitionV3).  Unlike [UpdateAssetVerifier](self::ExecuteMsg::UpdateAssetVerifier),
+    /// this route does not require the full verifier detail, and all other verifier values,
+    /// including its fee configuration, are left untouched.
+    UpdateVerifierEntityDetail {
+        /// The type of asset for which the verifier's entity detail will be updated.
+        asset_type: String,
+        /// The bech32 address of the verifier to update.
+        verifier_address: String,
+        /// The new entity detail for the verifier.  Omitting this value removes the verifier's
+        /// existing entity detail.
+        entity_detail: Option<EntityDetail>,
+    },
+    /// __This route is only accessible to the contract's admin address.__ This route replaces the
+    /// [address](super::types::verifier_detail::VerifierDetailV2::address) of a verifier on an existing
+    /// [AssetDefinitionV3](super::types::asset_definition::AssetDefinitionV3), allowing a verifier to rotate its signing
+    /// key.  Every pending [AssetScopeAttribute](super::types::asset_scope_attribute::AssetScopeAttribute) of the asset type
+    /// awaiting the old address is moved to the new address, along with any pending fee payments to the old address.
+    /// Object store gateway access granted to the old address for those attributes is revoked and granted to the new
+    /// address via [RelayOsGatewayAccess](self::ExecuteMsg::RelayOsGatewayAccess).  Approved and denied attributes are
+    /// not modified.
+    RotateVerifierAddress {
+        /// The type of asset for which the verifier's address will be rotated.
+        asset_type: String,
+        /// The bech32 address currently used by the verifier.
+        old_address: String,
+        /// The bech32 address that will replace the verifier's current address.  Must not already
+        /// be used by another verifier of the asset type.
+        new_address: String,
+    },
+    /// __This route is only accessible to the contract's admin address.__ This route replaces the
+    /// [allowed_requestors](super::types::asset_definition::AssetDefinitionV3::allowed_requestors) of an existing
+    /// [AssetDefinitionV3](super::types::asset_definition::AssetDefinitionV3), restricting which requestors may onboard
+    /// assets of its type.  All other definition values are left untouched.
+    UpdateAllowedRequestors {
+        /// The type of asset for which the allowed requestors will be updated.
+        asset_type: String,
+        /// The bech32 addresses of the only requestors permitted to onboard the asset type.  Each
+        /// value must be a unique, valid bech32 address.  Omitting this value, or providing an
+        /// empty list, allows any requestor to onboard the asset type.
+        allowed_requestors: Option<Vec<String>>,
+    },
+    /// __This route is only accessible to the contract's admin address, to the owner of the access routes being updated, OR to
+    /// the verifier of the scope attribute.  Verifiers may only update access routes in verifier access definitions.__
     /// This route will swap all existing access routes for a specific owner for a specific scope to the provided values. These
     /// access routes either correspond to those created during the onboarding process, or those created during the verification
     /// process.
@@ -264,6 +680,182 @@ pub enum ExecuteMsg {
         /// The asset type to delete the definition for
         asset_type: String,
     },
+    /// __This route is only accessible to the contract's admin address.__ This route performs the same process as the
+    /// [DeleteAssetDefinition](self::ExecuteMsg::DeleteAssetDefinition) route for many asset types in a single transaction.
+    /// If any asset type does not refer to an existing, unfrozen definition, the entire request is rejected and no definitions
+    /// are deleted.  Between one and [MAX_BULK_DELETE_SIZE](crate::util::constants::MAX_BULK_DELETE_SIZE) unique asset types
+    /// must be provided.
+    BulkDeleteAssetDefinitions {
+        /// The asset types to delete the definitions for, processed in the order provided.
+        asset_types: Vec<String>,
+    },
+    /// __This route is only accessible to the contract's admin address.__ This route permanently
+    /// locks an [AssetDefinitionV3](super::types::asset_definition::AssetDefinitionV3) against
+    /// further modification by setting its [frozen](super::types::asset_definition::AssetDefinitionV3::frozen)
+    /// flag.  Once frozen, every route that modifies the definition or its verifiers is rejected,
+    /// including deletion and toggling.  Assets can still be onboarded as the asset type while it
+    /// is enabled.  IMPORTANT: There is no route to unfreeze a definition.
+    FreezeAssetDefinition {
+        /// The asset type of the definition to freeze.
+        asset_type: String,
+    },
+    /// __This route is only accessible to the contract's admin address.__ This route marks an
+    /// [AssetDefinitionV3](super::types::asset_definition::AssetDefinitionV3) as [deprecated](super::types::asset_definition::AssetDefinitionV3::deprecated),
+    /// signaling that requestors should migrate to a different asset type.  Deprecated definitions
+    /// still accept onboarding for backwards compatibility, but each onboarding emits a
+    /// [DeprecatedTypeUsed](crate::util::event_attributes::EventType::DeprecatedTypeUsed) event.
+    /// Deprecating an already-deprecated definition replaces its replacement asset type.
+    DeprecateAssetDefinition {
+        /// The asset type of the definition to deprecate.
+        asset_type: String,
+        /// The asset type that requestors should migrate to, if any.  Must refer to an existing,
+        /// enabled asset definition other than the deprecated one.
+        replacement_asset_type: Option<String>,
+    },
+    /// __This route is only accessible to the contract's admin address.__ This route removes a
+    /// [FeePaymentDetail](super::types::fee_payment_detail::FeePaymentDetail) that has expired, and
+    /// can therefore no longer be used to verify its asset.  The fees collected during onboarding are
+    /// refunded to the [requestor](super::types::asset_scope_attribute::AssetScopeAttribute::requestor_address)
+    /// of the asset.  The request will be rejected if the detail has not yet expired.
+    PurgeFeePaymentDetail {
+        /// Expects an [AssetIdentifier](super::types::asset_identifier::AssetIdentifier)-compatible
+        /// [SerializedEnum](super::types::serialized_enum::SerializedEnum).
+        identifier: SerializedEnum,
+        /// The asset type for which the expired fee payment detail was created.
+        asset_type: String,
+    },
+    /// __This route is accessible to any address.__ This route removes the [FeePaymentDetail](super::types::fee_payment_detail::FeePaymentDetail)
+    /// of a [Denied](super::types::asset_onboarding_status::AssetOnboardingStatus::Denied) asset once it
+    /// has been stored for more than the contract's [fee_detail_max_age_blocks](super::state::StateV2::fee_detail_max_age_blocks).
+    /// No funds are moved.  The request will be rejected if no max age is configured, if the asset
+    /// has not been denied, or if the detail has not yet exceeded the max age.
+    PurgeStaleFeeDetail {
+        /// Expects an [AssetIdentifier](super::types::asset_identifier::AssetIdentifier)-compatible
+        /// [SerializedEnum](super::types::serialized_enum::SerializedEnum).
+        identifier: SerializedEnum,
+        /// The asset type for which the stale fee payment detail was created.
+        asset_type: String,
+    },
+    /// __This route is only accessible to the contract's admin address.__ This route sets the
+    /// contract's [fee_detail_max_age_blocks](super::state::StateV2::fee_detail_max_age_blocks),
+    /// which controls when [PurgeStaleFeeDetail](self::ExecuteMsg::PurgeStaleFeeDetail) may remove
+    /// a fee payment detail.
+    SetFeeDetailMaxAge {
+        /// The number of blocks a fee payment detail must be stored for before it can be purged.
+        /// Must be greater than zero when provided.  Omitting this value prevents all purges.
+        max_age_blocks: Option<u64>,
+    },
+    /// __This route is only accessible to the contract's admin address.__ This route sets the
+    /// contract's [os_gateway_grant_prefix](super::state::StateV2::os_gateway_grant_prefix), which
+    /// is prepended to the grant ids of all subsequently-emitted Object Store Gateway access grants
+    /// and revocations.
+    UpdateOsGatewayGrantPrefix {
+        /// The new grant id prefix.  Must not be blank when provided.  Omitting this value restores
+        /// the default grant id format.
+        prefix: Option<String>,
+    },
+    /// __This route is only accessible to the contract's admin address.__ This route sets the
+    /// contract's [max_access_routes_per_owner](super::state::StateV2::max_access_routes_per_owner),
+    /// which limits the number of access routes a single owner may hold on an asset.  Lowering the
+    /// limit does not remove existing routes, but owners that exceed it can no longer add routes.
+    SetMaxAccessRoutesPerOwner {
+        /// The new maximum number of access routes per owner.  Must be greater than zero.
+        limit: u32,
+    },
+    /// __This route is only accessible to the contract's admin address.__ This route sets the
+    /// contract's [require_entity_detail_for_verifiers](super::state::StateV2::require_entity_detail_for_verifiers)
+    /// value.  While enabled, verifiers can only be added or updated when they include an
+    /// [EntityDetail](super::types::entity_detail::EntityDetail) with a name.  Existing verifiers
+    /// are not affected until they are next updated.
+    SetRequireEntityDetail {
+        /// Whether or not verifiers must include an entity detail with a name.
+        required: bool,
+    },
+    /// __This route is accessible to any address.__ This route denies a [Pending](super::types::asset_onboarding_status::AssetOnboardingStatus::Pending)
+    /// verification once the block height has passed the scope attribute's [pending_expiry_block](super::types::asset_scope_attribute::AssetScopeAttribute::pending_expiry_block),
+    /// allowing the asset to be onboarded again.  No fees are paid to the verifier.  Instead, the
+    /// fees collected during onboarding are refunded to the [requestor](super::types::asset_scope_attribute::AssetScopeAttribute::requestor_address)
+    /// and the stored [FeePaymentDetail](super::types::fee_payment_detail::FeePaymentDetail) is
+    /// removed.  The request will be rejected if the verification has not yet expired.
+    ExpireStaleVerification {
+        /// Expects an [AssetIdentifier](super::types::asset_identifier::AssetIdentifier)-compatible
+        /// [SerializedEnum](super::types::serialized_enum::SerializedEnum).
+        identifier: SerializedEnum,
+        /// The asset type of the pending verification.
+        asset_type: String,
+    },
+    /// __This route is only accessible to the [requestor](super::types::asset_scope_attribute::AssetScopeAttribute::requestor_address)
+    /// of the asset.__ This route aborts a [Pending](super::types::asset_onboarding_status::AssetOnboardingStatus::Pending)
+    /// onboarding, moving the asset to the [Denied](super::types::asset_onboarding_status::AssetOnboardingStatus::Denied)
+    /// status so that it can be onboarded again.  The stored [FeePaymentDetail](super::types::fee_payment_detail::FeePaymentDetail)
+    /// is removed.  No verification occurs, so no fees are paid to the verifier.  Instead, the fees
+    /// collected during onboarding are refunded to the requestor, and any object store gateway
+    /// access granted to the verifier is revoked.
+    CancelPendingOnboarding {
+        /// Expects an [AssetIdentifier](super::types::asset_identifier::AssetIdentifier)-compatible
+        /// [SerializedEnum](super::types::serialized_enum::SerializedEnum).
+        identifier: SerializedEnum,
+        /// The asset type of the pending onboarding.
+        asset_type: String,
+    },
+    /// __This route is only accessible to the [requestor](super::types::asset_scope_attribute::AssetScopeAttribute::requestor_address)
+    /// of the asset.__ This route disputes a verifier's denial, moving a [Denied](super::types::asset_onboarding_status::AssetOnboardingStatus::Denied)
+    /// asset to the [Appealing](super::types::asset_onboarding_status::AssetOnboardingStatus::Appealing)
+    /// status until the contract admin resolves it via [ResolveAppeal](self::ExecuteMsg::ResolveAppeal).
+    /// The request will be rejected once the block height has passed the scope attribute's
+    /// [appeal_deadline_block](super::types::asset_scope_attribute::AssetScopeAttribute::appeal_deadline_block),
+    /// or if the denying verifier has no [appeal_window_blocks](super::types::verifier_detail::VerifierDetailV2::appeal_window_blocks).
+    /// A denial can only be appealed once.
+    AppealVerification {
+        /// Expects an [AssetIdentifier](super::types::asset_identifier::AssetIdentifier)-compatible
+        /// [SerializedEnum](super::types::serialized_enum::SerializedEnum).
+        identifier: SerializedEnum,
+        /// The asset type of the denied verification.
+        asset_type: String,
+        /// The requestor's explanation of why the denial should be overturned.  Emitted in the
+        /// route's event attributes.
+        appeal_reason: String,
+    },
+    /// __This route is only accessible to the contract's admin address.__ This route resolves an
+    /// appeal filed via [AppealVerification](self::ExecuteMsg::AppealVerification), moving the asset
+    /// to the [Approved](super::types::asset_onboarding_status::AssetOnboardingStatus::Approved)
+    /// status when the appeal is upheld, or back to the [Denied](super::types::asset_onboarding_status::AssetOnboardingStatus::Denied)
+    /// status when it is rejected.  The verifier is paid when it denies an asset, so no funds are
+    /// moved by this route.
+    ResolveAppeal {
+        /// Expects an [AssetIdentifier](super::types::asset_identifier::AssetIdentifier)-compatible
+        /// [SerializedEnum](super::types::serialized_enum::SerializedEnum).
+        identifier: SerializedEnum,
+        /// The asset type of the appealed verification.
+        asset_type: String,
+        /// Whether or not the appeal overturns the verifier's denial.
+        approved: bool,
+        /// The admin's explanation of the resolution, which replaces the asset's [latest_verification_result](super::types::asset_scope_attribute::AssetScopeAttribute::latest_verification_result).
+        resolution_message: String,
+    },
+    /// __This route is only accessible to the contract's admin address.__ This route nominates a
+    /// new account to become the contract's [admin](super::state::StateV2::admin).  The admin is not
+    /// changed until the nominated account executes [AcceptAdminTransfer](self::ExecuteMsg::AcceptAdminTransfer),
+    /// which prevents an incorrect address from locking the contract out of its administrative
+    /// routes.  Executing this route again replaces any previously nominated account.
+    TransferAdmin {
+        /// The bech32 address of the account that will be allowed to accept the admin role.
+        new_admin_address: String,
+    },
+    /// __This route is only accessible to the [pending admin](super::state::StateV2::pending_admin)
+    /// nominated by [TransferAdmin](self::ExecuteMsg::TransferAdmin).__ Completes the admin transfer,
+    /// making the sender the contract's new [admin](super::state::StateV2::admin).
+    AcceptAdminTransfer {},
+    /// __This route is only accessible to the contract itself.__ This route emits a single
+    /// [Object Store Gateway](https://github.com/FigureTechnologies/object-store-gateway) access
+    /// grant or revocation in the attributes of its own `wasm` event.  The gateway only reads one
+    /// grant or revocation from each event, so routes that change gateway access for more than one
+    /// scope or account, like [RotateVerifierAddress](self::ExecuteMsg::RotateVerifierAddress),
+    /// execute this route once for each change.
+    RelayOsGatewayAccess {
+        /// The grant or revocation to emit.
+        access: OsGatewayAccess,
+    },
 }
 
 /// The struct used to migrate the contract from one code instance to another.  Utilized in the core
@@ -276,6 +868,49 @@ pub enum MigrateMsg {
         /// Various optional values that dictate additional behavior that can occur during a contract
         /// upgrade.
         options: Option<MigrationOptions>,
+        /// When true, every [AssetScopeAttribute](super::types::asset_scope_attribute::AssetScopeAttribute)
+        /// held by an account on the Provenance Blockchain, as reported by the Attribute Module,
+        /// that does not yet have a [scope_spec_address](super::types::asset_scope_attribute::AssetScopeAttribute::scope_spec_address)
+        /// will be updated with the value retrieved from the Provenance Blockchain Metadata Module.
+        /// This requires a query and an attribute update for each asset, so it should only be
+        /// enabled once.  Defaults to false if omitted.
+        #[serde(default)]
+        populate_scope_specs: bool,
+        /// When true, every account holding an attribute for one of the contract's asset definitions
+        /// is visited, and its [AssetScopeAttribute](super::types::asset_scope_attribute::AssetScopeAttribute)
+        /// is recorded in each of the contract's internal scope attribute indexes: the verifier and
+        /// onboarding status index, the requestor index, the access route owner index and the last
+        /// modified block index.  Attributes written before the indexes existed are otherwise missed
+        /// by routes that rely on them, like [QueryVerifierPendingCount](self::QueryMsg::QueryVerifierPendingCount),
+        /// [QueryAssetsByRequestor](self::QueryMsg::QueryAssetsByRequestor), [QueryAccessRoutesByOwner](self::QueryMsg::QueryAccessRoutesByOwner)
+        /// and [QueryRecentlyModifiedScopes](self::QueryMsg::QueryRecentlyModifiedScopes).  This
+        /// requires a query for each asset, so it should only be enabled once.  Defaults to false if
+        /// omitted.
+        #[serde(default)]
+        backfill_verifier_status_index: bool,
+    },
+}
+
+/// Defines all routes that can be invoked by the chain's governance module via the
+/// [sudo](crate::contract::sudo) entrypoint.  These routes cannot be invoked by external accounts.
+#[cw_serde]
+pub enum SudoMsg {
+    /// Pauses the contract, causing all [execute](crate::contract::execute) routes to be rejected
+    /// until the contract is unpaused.  Queries are unaffected.
+    Pause {},
+    /// Unpauses a previously-paused contract, allowing [execute](crate::contract::execute) routes
+    /// to be processed again.
+    Unpause {},
+    /// Removes an [AssetDefinitionV3](super::types::asset_definition::AssetDefinitionV3) from the
+    /// contract's internal storage without requiring the contract's admin address.
+    ForceDeleteAssetDefinition {
+        /// The asset type of the definition to delete.
+        asset_type: String,
+    },
+    /// Replaces the contract's admin address without requiring a code migration.
+    OverrideAdmin {
+        /// The bech32 address of the account that will become the contract's new admin.
+        new_admin: String,
     },
 }
 
@@ -285,10 +920,18 @@ pub struct MigrationOptions {
     /// Sets the contract admin to a new address when populated.  Must be a valid Provenance
     /// Blockchain bech32 address.
     pub new_admin_address: Option<String>,
+    /// Sets the contract's [verification timeout](super::state::StateV2::verification_timeout_seconds)
+    /// when populated.  A value of zero removes the timeout entirely.
+    pub verification_timeout_seconds: Option<u64>,
+    /// Sets whether or not the contract [allows all bech32 prefixes](super::state::StateV2::allow_all_bech32_prefixes)
+    /// for verifier and fee destination addresses when populated.
+    pub allow_all_bech32_prefixes: Option<bool>,
 }
 impl MigrationOptions {
     /// Notes whether or not any options have been specified.
     pub fn has_changes(&self) -> bool {
         self.new_admin_address.is_some()
+            || self.verification_timeout_seconds.is_some()
+            || self.allow_all_bech32_prefixes.is_some()
     }
 }
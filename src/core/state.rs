@@ -1,8 +1,16 @@
 use crate::core::types::asset_definition::AssetDefinitionV3;
+use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+use crate::core::types::asset_scope_attribute::AssetScopeAttribute;
+use crate::core::types::contract_metrics::ContractMetrics;
+use crate::core::types::contract_statistics_response::ContractStatisticsResponse;
 use crate::core::types::fee_payment_detail::FeePaymentDetail;
+use crate::core::types::storage_key_count_response::StorageKeyCountResponse;
+use crate::core::types::verifier_stats::VerifierStats;
+use crate::util::constants::DEFAULT_MAX_ACCESS_ROUTES_PER_OWNER;
+use crate::util::traits::{IntoContractError, SafeDisplay};
 use crate::{core::msg::InitMsg, util::aliases::AssetResult};
-use cosmwasm_std::{Addr, Storage};
-use cw_storage_plus::{Item, Map};
+use cosmwasm_std::{Addr, StdResult, Storage};
+use cw_storage_plus::{Bound, Item, Map, PrefixBound};
 use result_extensions::ResultExtensions;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -12,10 +20,79 @@ use super::error::ContractError;
 const STATE_V2_KEY: &str = "\x00\x08state_v2"; // Special characters added to allow seamless upgrade from cosmwasm-storage to cw-storage-plus
 pub const STATE_V2: Item<StateV2> = Item::new(STATE_V2_KEY);
 
+const CONTRACT_PAUSED_KEY: &str = "contract_paused";
+/// Denotes whether or not the contract has been paused via the [sudo](crate::contract::sudo)
+/// entrypoint.  Private access to ensure only helper functions below are used.
+const CONTRACT_PAUSED: Item<bool> = Item::new(CONTRACT_PAUSED_KEY);
+
 const FEE_PAYMENT_DETAIL_NAMESPACE: &str = "fee_payment_detail";
 const FEE_PAYMENT_DETAILS: Map<(Addr, String), FeePaymentDetail> =
     Map::new(FEE_PAYMENT_DETAIL_NAMESPACE);
 
+const VERIFIER_STATUS_INDEX_NAMESPACE: &str = "verifier_status_index";
+/// A secondary index over all onboarded assets, keyed by verifier address and onboarding status,
+/// followed by the scope address and asset type of each asset.  The asset type is also stored as
+/// the value to allow filtering without deserializing each key.  Attributes written before the index
+/// existed are added by [backfill_scope_attribute_indexes](crate::migrate::migrate_contract::backfill_scope_attribute_indexes).
+/// Private access to ensure only helper functions below are used.
+const VERIFIER_STATUS_INDEX: Map<(String, String, (String, String)), String> =
+    Map::new(VERIFIER_STATUS_INDEX_NAMESPACE);
+
+const REQUESTOR_INDEX_NAMESPACE: &str = "requestor_index";
+/// A secondary index over all onboarded assets, keyed by the bech32 address of the requestor that
+/// onboarded each asset, followed by its scope address.  The value holds every asset type the
+/// requestor has onboarded the scope as.  Attributes written before the index existed are added by
+/// [backfill_scope_attribute_indexes](crate::migrate::migrate_contract::backfill_scope_attribute_indexes).
+/// Private access to ensure only helper functions below are used.
+const REQUESTOR_INDEX: Map<(String, String), Vec<String>> = Map::new(REQUESTOR_INDEX_NAMESPACE);
+
+const ACCESS_ROUTE_OWNER_INDEX_NAMESPACE: &str = "access_route_owner_index";
+/// A secondary index over the [AccessDefinitions](super::types::access_definition::AccessDefinition)
+/// of all onboarded assets, keyed by the bech32 address of each definition's owner, followed by
+/// the scope address.  The value holds every asset type for which the owner holds access routes
+/// on the scope.  Attributes written before the index existed are added by
+/// [backfill_scope_attribute_indexes](crate::migrate::migrate_contract::backfill_scope_attribute_indexes).
+/// Private access to ensure only helper functions below are used.
+const ACCESS_ROUTE_OWNER_INDEX: Map<(String, String), Vec<String>> =
+    Map::new(ACCESS_ROUTE_OWNER_INDEX_NAMESPACE);
+
+const LAST_MODIFIED_BLOCK_INDEX_NAMESPACE: &str = "last_modified_block_index";
+/// A secondary index over all onboarded assets, keyed by the [last_modified_block](super::types::asset_scope_attribute::AssetScopeAttribute::last_modified_block)
+/// of each asset's attribute, followed by its scope address and asset type.  The asset type is
+/// also stored as the value to allow filtering without deserializing each key.  Attributes written
+/// before the index existed are added by [backfill_scope_attribute_indexes](crate::migrate::migrate_contract::backfill_scope_attribute_indexes).
+/// Private access to ensure only helper functions below are used.
+const LAST_MODIFIED_BLOCK_INDEX: Map<(u64, (String, String)), String> =
+    Map::new(LAST_MODIFIED_BLOCK_INDEX_NAMESPACE);
+
+const DENIAL_TIMESTAMPS_NAMESPACE: &str = "denial_timestamps";
+/// Records the block time, in seconds, at which a verifier most recently denied an asset, keyed on
+/// the scope address and asset type.  Used to enforce a verifier's [cooldown_seconds](super::types::verifier_detail::VerifierDetailV2::cooldown_seconds).
+/// Private access to ensure only helper functions below are used.
+const DENIAL_TIMESTAMPS: Map<(String, String), u64> = Map::new(DENIAL_TIMESTAMPS_NAMESPACE);
+
+const ASSET_TYPE_ONBOARD_COUNTS_NAMESPACE: &str = "asset_type_onboard_counts";
+/// The number of onboarding requests processed for each asset type, used alongside the
+/// [metrics](self::StateV2::metrics) in the contract's state.  Private access to ensure only helper
+/// functions below are used.
+const ASSET_TYPE_ONBOARD_COUNTS: Map<String, u64> = Map::new(ASSET_TYPE_ONBOARD_COUNTS_NAMESPACE);
+
+const VERIFIER_BLOCK_CLASSIFICATIONS_NAMESPACE: &str = "verifier_block_classifications";
+/// The number of onboarding requests received by a verifier within a single block, keyed on the
+/// asset type, the verifier's bech32 address, and the block height.  Used to enforce a verifier's
+/// [max_classifications_per_block](super::types::verifier_detail::VerifierDetailV2::max_classifications_per_block).
+/// Entries for past blocks are never read again, so they are not removed.  Private access to
+/// ensure only helper functions below are used.
+const VERIFIER_BLOCK_CLASSIFICATIONS: Map<(String, String, u64), u32> =
+    Map::new(VERIFIER_BLOCK_CLASSIFICATIONS_NAMESPACE);
+
+const VERIFIER_STATISTICS_NAMESPACE: &str = "verifier_statistics";
+/// The [VerifierStats](super::types::verifier_stats::VerifierStats) of each verifier, keyed on the
+/// asset type followed by the verifier's bech32 address.  Private access to ensure only helper
+/// functions below are used.
+const VERIFIER_STATISTICS: Map<(String, String), VerifierStats> =
+    Map::new(VERIFIER_STATISTICS_NAMESPACE);
+
 /// Stores the main configurations for the contract internally.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct StateV2 {
@@ -25,13 +102,67 @@ pub struct StateV2 {
     /// The Provenance Blockchain bech32 address that maintains primary control over the contract.
     /// This address is derived from the sender of the initial contract instantiation, and is the
     /// only address that can access administrative execution routes in the contract.  It can be
-    /// changed during migrations.
+    /// changed during migrations, or via the [TransferAdmin](super::msg::ExecuteMsg::TransferAdmin)
+    /// and [AcceptAdminTransfer](super::msg::ExecuteMsg::AcceptAdminTransfer) routes.
     pub admin: Addr,
     /// A boolean value allowing for less restrictions to be placed on certain functionalities
     /// across the contract's execution processes.  Notably, this disables a check during the
     /// onboarding process to determine if onboarded scopes include underlying record values.  This
     /// should never be set to true in a mainnet environment.
     pub is_test: bool,
+    /// A cached count of all [AssetDefinitionV3s](super::types::asset_definition::AssetDefinitionV3)
+    /// currently held in contract storage.  Maintained by the asset definition storage helpers to
+    /// avoid iterating over every definition when only the total is needed.
+    #[serde(default)]
+    pub asset_definition_count: u64,
+    /// Enables the [QueryAll](super::msg::QueryMsg::QueryAll) debugging route, which exposes all
+    /// of the contract's queryable configuration in a single response.  Only ever set when the
+    /// contract is instantiated in test mode.
+    #[serde(default)]
+    pub debug_query_enabled: bool,
+    /// The number of seconds a verifier has to respond to a pending onboarding request.  When an
+    /// asset's verification has been pending for longer than this value, a subsequent onboarding
+    /// attempt will automatically deny the stale verification and proceed as a retry.  Pending
+    /// verifications never time out when this value is not set.
+    #[serde(default)]
+    pub verification_timeout_seconds: Option<u64>,
+    /// Operational metrics tracked over the lifetime of the contract.  Contracts that were
+    /// instantiated before metrics existed begin tracking from zero.
+    #[serde(default)]
+    pub metrics: ContractMetrics,
+    /// The Provenance Blockchain bech32 address nominated by the current admin via [TransferAdmin](super::msg::ExecuteMsg::TransferAdmin).
+    /// The nominated account does not become the [admin](self::StateV2::admin) until it executes
+    /// [AcceptAdminTransfer](super::msg::ExecuteMsg::AcceptAdminTransfer).
+    #[serde(default)]
+    pub pending_admin: Option<Addr>,
+    /// The number of blocks a [FeePaymentDetail](super::types::fee_payment_detail::FeePaymentDetail)
+    /// must be stored for before it can be removed via [PurgeStaleFeeDetail](super::msg::ExecuteMsg::PurgeStaleFeeDetail).
+    /// Set via [SetFeeDetailMaxAge](super::msg::ExecuteMsg::SetFeeDetailMaxAge).  Stale details can
+    /// never be purged by that route when this value is not set.
+    #[serde(default)]
+    pub fee_detail_max_age_blocks: Option<u64>,
+    /// The maximum number of [AccessRoutes](super::types::access_route::AccessRoute) that a single
+    /// owner's [AccessDefinition](super::types::access_definition::AccessDefinition) may hold on an
+    /// asset.  Set at instantiation or via [SetMaxAccessRoutesPerOwner](super::msg::ExecuteMsg::SetMaxAccessRoutesPerOwner).
+    /// Contracts that were instantiated before this limit existed use [DEFAULT_MAX_ACCESS_ROUTES_PER_OWNER](crate::util::constants::DEFAULT_MAX_ACCESS_ROUTES_PER_OWNER).
+    #[serde(default = "default_max_access_routes_per_owner")]
+    pub max_access_routes_per_owner: u32,
+    /// Disables the requirement that verifier and fee destination addresses use one of the
+    /// [VALID_VERIFIER_ADDRESS_PREFIXES](crate::util::constants::VALID_VERIFIER_ADDRESS_PREFIXES).
+    /// When enabled, any bech32 address accepted elsewhere by the contract is allowed.  Intended for
+    /// testing, and set at instantiation or during a migration.
+    #[serde(default)]
+    pub allow_all_bech32_prefixes: bool,
+    /// Requires every verifier that is added or updated to include an [EntityDetail](super::types::entity_detail::EntityDetail)
+    /// with a name.  Verifiers stored before the requirement was enabled remain valid until they
+    /// are next updated.  Set via [SetRequireEntityDetail](super::msg::ExecuteMsg::SetRequireEntityDetail).
+    #[serde(default)]
+    pub require_entity_detail_for_verifiers: bool,
+    /// A value prepended to every Object Store Gateway grant id generated by [generate_os_gateway_grant_id](crate::util::functions::generate_os_gateway_grant_id),
+    /// allowing multiple contract instances to share a single gateway.  Grant ids use the default
+    /// format when this value is not set.  Set at instantiation or via [UpdateOsGatewayGrantPrefix](super::msg::ExecuteMsg::UpdateOsGatewayGrantPrefix).
+    #[serde(default)]
+    pub os_gateway_grant_prefix: Option<String>,
 }
 impl StateV2 {
     /// Constructs a new instance of this struct for the instantiation process.
@@ -47,8 +178,74 @@ impl StateV2 {
             base_contract_name: msg.base_contract_name,
             admin,
             is_test: msg.is_test.unwrap_or(false),
+            // All provided definitions are inserted before the state is first saved, so the count
+            // must be derived directly from the message
+            asset_definition_count: msg.asset_definitions.len() as u64,
+            debug_query_enabled: msg.is_test.unwrap_or(false),
+            verification_timeout_seconds: None,
+            metrics: ContractMetrics::default(),
+            pending_admin: None,
+            fee_detail_max_age_blocks: None,
+            max_access_routes_per_owner: msg
+                .max_access_routes_per_owner
+                .unwrap_or(DEFAULT_MAX_ACCESS_ROUTES_PER_OWNER),
+            allow_all_bech32_prefixes: msg.allow_all_bech32_prefixes.unwrap_or(false),
+            require_entity_detail_for_verifiers: false,
+            os_gateway_grant_prefix: msg.os_gateway_grant_prefix,
         }
     }
+
+    /// Formats this state for human-readable logging via its [SafeDisplay](crate::util::traits::SafeDisplay)
+    /// implementation.
+    pub fn to_safe_display(&self) -> String {
+        self.safe_display()
+    }
+}
+impl SafeDisplay for StateV2 {
+    fn safe_display(&self) -> String {
+        format!(
+            "StateV2 {{ base_contract_name: {}, admin: {}, is_test: {}, asset_definition_count: {}, debug_query_enabled: {}, verification_timeout_seconds: {:?}, total_onboards: {}, total_verifications: {}, total_fees_collected_nhash: {}, pending_admin: {:?}, fee_detail_max_age_blocks: {:?}, max_access_routes_per_owner: {}, allow_all_bech32_prefixes: {}, require_entity_detail_for_verifiers: {}, os_gateway_grant_prefix: {:?} }}",
+            self.base_contract_name,
+            self.admin,
+            self.is_test,
+            self.asset_definition_count,
+            self.debug_query_enabled,
+            self.verification_timeout_seconds,
+            self.metrics.total_onboards,
+            self.metrics.total_verifications,
+            self.metrics.total_fees_collected_nhash,
+            self.pending_admin.as_ref().map(|addr| addr.as_str()),
+            self.fee_detail_max_age_blocks,
+            self.max_access_routes_per_owner,
+            self.allow_all_bech32_prefixes,
+            self.require_entity_detail_for_verifiers,
+            self.os_gateway_grant_prefix,
+        )
+    }
+}
+
+fn default_max_access_routes_per_owner() -> u32 {
+    DEFAULT_MAX_ACCESS_ROUTES_PER_OWNER
+}
+
+/// Determines whether or not the contract is currently paused.  A contract that has never been
+/// paused has no stored value, which is treated as unpaused.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+pub fn is_contract_paused(storage: &dyn Storage) -> AssetResult<bool> {
+    CONTRACT_PAUSED.may_load(storage)?.unwrap_or(false).to_ok()
+}
+
+/// Sets the paused status of the contract.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `paused` The new paused status of the contract.
+pub fn set_contract_paused(storage: &mut dyn Storage, paused: bool) -> AssetResult<()> {
+    CONTRACT_PAUSED.save(storage, &paused)?.to_ok()
 }
 
 /// Value is currently 'asset_definitions_v2' due to a structural change of data (removing an existing field, scope_spec_address) and switching from
@@ -60,6 +257,21 @@ const ASSET_DEFINITIONS_NAMESPACE: &str = "asset_definitions_v2";
 /// functionality. Private access to ensure only helper functions below are used.
 const ASSET_DEFINITIONS_V3: Map<String, AssetDefinitionV3> = Map::new(ASSET_DEFINITIONS_NAMESPACE);
 
+const SCOPE_SPEC_TO_ASSET_TYPE_NAMESPACE: &str = "scope_spec_to_asset_type";
+/// A secondary index over all asset definitions with a [scope_spec_address](super::types::asset_definition::AssetDefinitionV3::scope_spec_address),
+/// keyed by the scope spec address and storing the asset type of the definition to which it is
+/// bound.  Maintained by the asset definition storage helpers.  Private access to ensure only
+/// helper functions below are used.
+const SCOPE_SPEC_TO_ASSET_TYPE: Map<String, String> = Map::new(SCOPE_SPEC_TO_ASSET_TYPE_NAMESPACE);
+
+const ASSET_DEFINITION_LABEL_INDEX_NAMESPACE: &str = "asset_definition_label_index";
+/// A secondary index over all asset definitions with [labels](super::types::asset_definition::AssetDefinitionV3::labels),
+/// keyed by each label followed by the storage key of a definition that carries it, and storing
+/// the asset type of that definition.  Maintained by the asset definition storage helpers.
+/// Private access to ensure only helper functions below are used.
+const ASSET_DEFINITION_LABEL_INDEX: Map<(String, String), String> =
+    Map::new(ASSET_DEFINITION_LABEL_INDEX_NAMESPACE);
+
 pub fn list_asset_definitions_v3(storage: &dyn Storage) -> Vec<AssetDefinitionV3> {
     ASSET_DEFINITIONS_V3
         .range(storage, None, None, cosmwasm_std::Order::Descending)
@@ -68,6 +280,89 @@ pub fn list_asset_definitions_v3(storage: &dyn Storage) -> Vec<AssetDefinitionV3
         .collect::<Vec<AssetDefinitionV3>>()
 }
 
+/// Lazily iterates over every asset definition in storage, sorted in ascending order by storage
+/// key.  Definitions are only deserialized as the iterator advances, so this should be preferred
+/// over [list_asset_definitions_v3](self::list_asset_definitions_v3) when the full set of
+/// definitions does not need to be held in memory.  A definition that cannot be read from storage
+/// produces an error for its item without halting iteration.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+pub fn iter_asset_definitions_v3(
+    storage: &dyn Storage,
+) -> impl Iterator<Item = AssetResult<AssetDefinitionV3>> + '_ {
+    ASSET_DEFINITIONS_V3
+        .range(storage, None, None, cosmwasm_std::Order::Ascending)
+        .map(|result| {
+            result
+                .map(|(_, definition)| definition)
+                .map_err(|e| e.into_contract_error())
+        })
+}
+
+/// Fetches a page of asset definitions, sorted in ascending order by their storage key (the
+/// lowercased [asset_type](super::types::asset_definition::AssetDefinitionV3::asset_type)).
+/// Returns the definitions in the page, as well as a boolean indicating whether or not more
+/// definitions exist after the final definition in the page.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+/// * `page_size` The maximum number of definitions to include in the page.
+/// * `page_token` The storage key of the final definition of the previous page.  All returned
+/// definitions will be sorted after this value.  When omitted, the first page is returned.
+pub fn list_asset_definitions_page_v3(
+    storage: &dyn Storage,
+    page_size: u32,
+    page_token: Option<String>,
+) -> AssetResult<(Vec<AssetDefinitionV3>, bool)> {
+    let mut asset_definitions = ASSET_DEFINITIONS_V3
+        .range(
+            storage,
+            page_token.map(Bound::exclusive),
+            None,
+            cosmwasm_std::Order::Ascending,
+        )
+        // Take a single extra definition to determine if another page exists after this one
+        .take(page_size as usize + 1)
+        .map(|result| result.map(|(_, definition)| definition))
+        .collect::<Result<Vec<AssetDefinitionV3>, _>>()?;
+    let has_next_page = asset_definitions.len() > page_size as usize;
+    asset_definitions.truncate(page_size as usize);
+    (asset_definitions, has_next_page).to_ok()
+}
+
+/// Fetches the cached total count of all asset definitions in storage from the contract's
+/// [StateV2](self::StateV2).
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+pub fn load_asset_definition_count(storage: &dyn Storage) -> AssetResult<u64> {
+    STATE_V2.load(storage)?.asset_definition_count.to_ok()
+}
+
+/// Adjusts the cached asset definition count in the contract's [StateV2](self::StateV2) after an
+/// insert or delete.  During instantiation, definitions are inserted before the state exists, so a
+/// missing state is skipped; [StateV2::new](self::StateV2::new) derives the initial count instead.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `increment` If true, the count is increased by one.  Otherwise, it is decreased by one.
+fn adjust_asset_definition_count(storage: &mut dyn Storage, increment: bool) -> AssetResult<()> {
+    if let Some(mut state) = STATE_V2.may_load(storage)? {
+        state.asset_definition_count = if increment {
+            state.asset_definition_count.saturating_add(1)
+        } else {
+            state.asset_definition_count.saturating_sub(1)
+        };
+        STATE_V2.save(storage, &state)?;
+    }
+    Ok(())
+}
+
 /// Inserts a new asset definition into storage. If a value already exists, an error will be returned.
 /// Note: Asset definitions must contain a unique [asset_type](super::types::asset_definition::AssetDefinitionV3::asset_type)
 /// value. An error will be returned if this unique constraint is violated.
@@ -91,11 +386,15 @@ pub fn insert_asset_definition_v3(
         }
         .to_err()
     } else {
+        check_scope_spec_binding_available(storage, definition)?;
         // At this point, we know there is no old data available, so we can safely call the replace function and
         // specify None for the old_data param.
         state
             .save(storage, key, definition)
-            .map_err(ContractError::Std)
+            .map_err(|e| e.into_contract_error())?;
+        update_scope_spec_binding(storage, None, Some(definition))?;
+        update_label_index(storage, None, Some(definition))?;
+        adjust_asset_definition_count(storage, true)
     }
 }
 
@@ -117,10 +416,13 @@ pub fn replace_asset_definition_v3(
 ) -> AssetResult<()> {
     let state = ASSET_DEFINITIONS_V3;
     let key = definition.storage_key();
-    if let Ok(Some(_)) = state.may_load(storage, key.to_string()) {
+    if let Ok(Some(existing_def)) = state.may_load(storage, key.to_string()) {
+        check_scope_spec_binding_available(storage, definition)?;
         state
             .save(storage, key, definition)
-            .map_err(ContractError::Std)
+            .map_err(|e| e.into_contract_error())?;
+        update_scope_spec_binding(storage, Some(&existing_def), Some(definition))?;
+        update_label_index(storage, Some(&existing_def), Some(definition))
     } else {
         ContractError::RecordNotFound {
             explanation: format!(
@@ -132,6 +434,54 @@ pub fn replace_asset_definition_v3(
     }
 }
 
+/// Lists every asset definition in storage alongside the raw key under which it is stored.  This
+/// should only be used by migrations that need to inspect or repair storage keys, as standard
+/// functionality should always rely on [storage_key](super::types::asset_definition::AssetDefinitionV3::storage_key).
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+pub fn list_asset_definition_entries_v3(
+    storage: &dyn Storage,
+) -> AssetResult<Vec<(String, AssetDefinitionV3)>> {
+    ASSET_DEFINITIONS_V3
+        .range(storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<Result<Vec<(String, AssetDefinitionV3)>, _>>()
+        .map_err(|e| e.into_contract_error())
+}
+
+/// Moves an asset definition stored under a raw key to the key derived from its [storage_key](super::types::asset_definition::AssetDefinitionV3::storage_key),
+/// saving the provided definition in place of the existing value.  The definition's scope spec
+/// binding and label index entries are moved to the new key and asset type as well.  The cached
+/// asset definition count is unaffected, as the number of stored definitions does not change.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `existing_key` The raw key under which the definition is currently stored.
+/// * `definition` The definition to store under its derived storage key.
+pub fn rekey_asset_definition_v3(
+    storage: &mut dyn Storage,
+    existing_key: &str,
+    definition: &AssetDefinitionV3,
+) -> AssetResult<()> {
+    let existing_def = ASSET_DEFINITIONS_V3.may_load(storage, existing_key.to_string())?;
+    ASSET_DEFINITIONS_V3.remove(storage, existing_key.to_string());
+    ASSET_DEFINITIONS_V3
+        .save(storage, definition.storage_key(), definition)
+        .map_err(|e| e.into_contract_error())?;
+    update_scope_spec_binding(storage, existing_def.as_ref(), Some(definition))?;
+    // Label index entries are keyed by the raw key of the existing definition, which may differ
+    // from its derived storage key, so they must be removed directly
+    if let Some(existing_def) = &existing_def {
+        for label in &existing_def.labels {
+            ASSET_DEFINITION_LABEL_INDEX
+                .remove(storage, (label.to_owned(), existing_key.to_string()));
+        }
+    }
+    update_label_index(storage, None, Some(definition))
+}
+
 /// Finds an existing asset definition in state by checking against the provided asset type,
 /// returning an Option that reflects whether or not the definition exists.
 ///
@@ -147,7 +497,7 @@ pub fn may_load_asset_definition_by_type_v3<S: Into<String>>(
     ASSET_DEFINITIONS_V3
         // Coerce to lowercase to match how stored values are keyed
         .may_load(storage, asset_type.into().to_lowercase())
-        .map_err(ContractError::Std)
+        .map_err(|e| e.into_contract_error())
 }
 
 /// Finds an existing asset definition by asset type, or returns an error if no definition is found.
@@ -172,142 +522,1172 @@ pub fn load_asset_definition_by_type_v3<S: Into<String>>(
     }
 }
 
-/// Attempts to delete an existing asset definition by asset type.  Returns an error if the
-/// definition does not exist or if the deletion fails.  Returns the asset type of the deleted
-/// definition on a successful deletion.
+/// Attempts to delete an existing asset definition by asset type.  Returns an error if the
+/// definition does not exist or if the deletion fails.  Returns the asset type of the deleted
+/// definition on a successful deletion.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type to delete.
+pub fn delete_asset_definition_by_asset_type_v3(
+    storage: &mut dyn Storage,
+    asset_type: &str,
+) -> AssetResult<String> {
+    let existing_def = load_asset_definition_by_type_v3(storage, asset_type)?;
+    ASSET_DEFINITIONS_V3.remove(storage, existing_def.storage_key());
+    update_scope_spec_binding(storage, Some(&existing_def), None)?;
+    update_label_index(storage, Some(&existing_def), None)?;
+    adjust_asset_definition_count(storage, false)?;
+    Ok(existing_def.asset_type)
+}
+
+/// Finds the asset type of the asset definition to which a scope specification is bound via its
+/// [scope_spec_address](super::types::asset_definition::AssetDefinitionV3::scope_spec_address),
+/// returning a None variant if no definition is bound to the scope specification.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+/// * `scope_spec_address` The bech32 address of the scope specification to look up.
+pub fn may_load_asset_type_by_scope_spec_address(
+    storage: &dyn Storage,
+    scope_spec_address: &str,
+) -> AssetResult<Option<String>> {
+    SCOPE_SPEC_TO_ASSET_TYPE
+        .may_load(storage, scope_spec_address.to_string())?
+        .to_ok()
+}
+
+/// Ensures that the scope specification referenced by a definition, if any, is not already bound
+/// to a different asset type.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+/// * `definition` The definition that is about to be stored.
+fn check_scope_spec_binding_available(
+    storage: &dyn Storage,
+    definition: &AssetDefinitionV3,
+) -> AssetResult<()> {
+    if let Some(scope_spec_address) = &definition.scope_spec_address {
+        if let Some(bound_asset_type) =
+            may_load_asset_type_by_scope_spec_address(storage, scope_spec_address)?
+        {
+            if bound_asset_type.to_lowercase() != definition.storage_key() {
+                return ContractError::RecordAlreadyExists {
+                    explanation: format!(
+                        "unique constraints violated! scope spec address [{}] is already bound to asset type [{}]",
+                        scope_spec_address, bound_asset_type,
+                    ),
+                }
+                .to_err();
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Moves the scope specification binding of a definition from its previous value to its current
+/// value.  Either value may be omitted when a definition is being added or removed.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `previous_definition` The definition as it existed before the change, if any.
+/// * `current_definition` The definition as it exists after the change, if any.
+fn update_scope_spec_binding(
+    storage: &mut dyn Storage,
+    previous_definition: Option<&AssetDefinitionV3>,
+    current_definition: Option<&AssetDefinitionV3>,
+) -> AssetResult<()> {
+    if let Some(previous_scope_spec_address) =
+        previous_definition.and_then(|definition| definition.scope_spec_address.as_ref())
+    {
+        SCOPE_SPEC_TO_ASSET_TYPE.remove(storage, previous_scope_spec_address.to_owned());
+    }
+    if let Some(definition) = current_definition {
+        if let Some(scope_spec_address) = &definition.scope_spec_address {
+            SCOPE_SPEC_TO_ASSET_TYPE.save(
+                storage,
+                scope_spec_address.to_owned(),
+                &definition.asset_type,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Moves the label index entries of a definition from its previous labels to its current labels.
+/// Either value may be omitted when a definition is being added or removed.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `previous_definition` The definition as it existed before the change, if any.
+/// * `current_definition` The definition as it exists after the change, if any.
+fn update_label_index(
+    storage: &mut dyn Storage,
+    previous_definition: Option<&AssetDefinitionV3>,
+    current_definition: Option<&AssetDefinitionV3>,
+) -> AssetResult<()> {
+    if let Some(definition) = previous_definition {
+        for label in &definition.labels {
+            ASSET_DEFINITION_LABEL_INDEX
+                .remove(storage, (label.to_owned(), definition.storage_key()));
+        }
+    }
+    if let Some(definition) = current_definition {
+        for label in &definition.labels {
+            ASSET_DEFINITION_LABEL_INDEX.save(
+                storage,
+                (label.to_owned(), definition.storage_key()),
+                &definition.asset_type,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Fetches a page of the asset definitions that carry a specific label, sorted in ascending order
+/// by their storage key (the lowercased [asset_type](super::types::asset_definition::AssetDefinitionV3::asset_type)).
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+/// * `label` The label that every returned definition must carry.
+/// * `start_after` The asset type of the final definition in a previous page.  When omitted, the
+/// first page is returned.
+/// * `limit` The maximum number of definitions to include in the page.
+pub fn list_asset_definitions_by_label_page(
+    storage: &dyn Storage,
+    label: &str,
+    start_after: Option<String>,
+    limit: u32,
+) -> AssetResult<Vec<AssetDefinitionV3>> {
+    let mut asset_definitions = vec![];
+    for entry in ASSET_DEFINITION_LABEL_INDEX
+        .prefix(label.to_string())
+        .range(
+            storage,
+            // Coerce to lowercase to match how stored values are keyed
+            start_after.map(|asset_type| Bound::exclusive(asset_type.to_lowercase())),
+            None,
+            cosmwasm_std::Order::Ascending,
+        )
+        .take(limit as usize)
+    {
+        let (_, asset_type) = entry?;
+        asset_definitions.push(load_asset_definition_by_type_v3(storage, asset_type)?);
+    }
+    asset_definitions.to_ok()
+}
+
+/// Inserts a new payment detail into storage.  If a value already exists, an error will be returned.
+/// Note: Each payment detail must contain a unique [scope_address](super::types::fee_payment_detail::FeePaymentDetail::scope_address)
+/// value, or the insert will be rejected with an error.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `fee_payment_detail` The detail to insert into storage and derive the unique scope address key.
+pub fn insert_fee_payment_detail(
+    storage: &mut dyn Storage,
+    fee_payment_detail: &FeePaymentDetail,
+    asset_type: &str,
+) -> AssetResult<()> {
+    if load_fee_payment_detail(storage, &fee_payment_detail.scope_address, asset_type).is_ok() {
+        return ContractError::RecordAlreadyExists {
+            explanation: format!(
+                "cannot insert payment detail for scope [{}] and asset type [{}] because a record already exists with that address and asset type",
+                &fee_payment_detail.scope_address,
+                &asset_type
+            )
+        }.to_err();
+    }
+    FEE_PAYMENT_DETAILS
+        .save(
+            storage,
+            (
+                Addr::unchecked(&fee_payment_detail.scope_address),
+                asset_type.into(),
+            ),
+            fee_payment_detail,
+        )?
+        .to_ok()
+}
+
+/// Finds an existing fee payment detail by scope address, or returns an error if no detail is found.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+/// * `scope_address` The unique key [scope_address](super::types::fee_payment_detail::FeePaymentDetail::scope_address)
+/// for the requested payment detail.
+pub fn load_fee_payment_detail<S1: Into<String>, S2: Into<String>>(
+    storage: &dyn Storage,
+    scope_address: S1,
+    asset_type: S2,
+) -> AssetResult<FeePaymentDetail> {
+    FEE_PAYMENT_DETAILS
+        .load(storage, (Addr::unchecked(scope_address), asset_type.into()))?
+        .to_ok()
+}
+
+/// Attempts to find an existing fee payment detail by scope address, or returns a None variant if
+/// an error occurs or no detail is found.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+/// * `scope_address` The unique key [scope_address](super::types::fee_payment_detail::FeePaymentDetail::scope_address)
+/// for the requested payment detail.
+pub fn may_load_fee_payment_detail<S1: Into<String>, S2: Into<String>>(
+    storage: &dyn Storage,
+    scope_address: S1,
+    asset_type: S2,
+) -> Option<FeePaymentDetail> {
+    FEE_PAYMENT_DETAILS
+        .may_load(storage, (Addr::unchecked(scope_address), asset_type.into()))
+        .unwrap_or(None)
+}
+
+/// Attempts to delete an existing payment detail by scope address.  Returns an error if the detail
+/// does not exist or if deletion fails.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `scope_address` The unique key [scope_address](super::types::fee_payment_detail::FeePaymentDetail::scope_address)
+/// of the detail to delete.
+pub fn delete_fee_payment_detail<S1: Into<String>, S2: Into<String>>(
+    storage: &mut dyn Storage,
+    scope_address: S1,
+    asset_type: S2,
+) -> AssetResult<()> {
+    let scope_address = scope_address.into();
+    let asset_type = asset_type.into();
+    // Verify the detail exists before allowing its deletion.  The standard "remove" function will
+    // not produce an error if no target value exists, but it is very informative of bad code to
+    // reveal when unnecessary operations occur.
+    load_fee_payment_detail(storage, &scope_address, &asset_type)?;
+    FEE_PAYMENT_DETAILS.remove(
+        storage,
+        (
+            Addr::unchecked(scope_address.to_owned()),
+            asset_type.to_owned(),
+        ),
+    );
+    ().to_ok()
+}
+
+/// Stamps every stored payment detail that predates creation tracking with the provided block time
+/// and height, so that it is only considered [expired](super::types::fee_payment_detail::FeePaymentDetail::is_expired)
+/// once it has been stored for the full window after the backfill.  Returns the number of details
+/// that were updated.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `created_at` The block time, in seconds, to record on each legacy detail.
+/// * `created_at_block` The block height to record on each legacy detail.
+pub fn backfill_fee_payment_detail_creation(
+    storage: &mut dyn Storage,
+    created_at: u64,
+    created_at_block: u64,
+) -> AssetResult<u64> {
+    let legacy_details = FEE_PAYMENT_DETAILS
+        .range(storage, None, None, cosmwasm_std::Order::Ascending)
+        .filter(|entry| {
+            entry
+                .as_ref()
+                .map(|(_, detail)| detail.created_at == 0)
+                .unwrap_or(true)
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    let updated_count = legacy_details.len() as u64;
+    for (key, mut detail) in legacy_details {
+        detail.created_at = created_at;
+        detail.created_at_block = created_at_block;
+        FEE_PAYMENT_DETAILS.save(storage, key, &detail)?;
+    }
+    updated_count.to_ok()
+}
+
+/// Records the time at which an asset was denied by a verifier, overwriting any previous denial
+/// time for the same scope address and asset type.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `scope_address` The bech32 address of the scope that was denied.
+/// * `asset_type` The asset type for which the scope was denied.
+/// * `denied_at_seconds` The block time, in seconds, at which the denial occurred.
+pub fn set_denial_timestamp(
+    storage: &mut dyn Storage,
+    scope_address: &str,
+    asset_type: &str,
+    denied_at_seconds: u64,
+) -> AssetResult<()> {
+    DENIAL_TIMESTAMPS
+        .save(
+            storage,
+            (scope_address.to_string(), asset_type.to_string()),
+            &denied_at_seconds,
+        )
+        .map_err(|e| e.into_contract_error())
+}
+
+/// Fetches the time, in seconds, at which an asset was most recently denied by a verifier.  Returns
+/// a None variant if the asset has not been denied since it was last approved.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+/// * `scope_address` The bech32 address of the scope to check.
+/// * `asset_type` The asset type to check.
+pub fn may_load_denial_timestamp(
+    storage: &dyn Storage,
+    scope_address: &str,
+    asset_type: &str,
+) -> AssetResult<Option<u64>> {
+    DENIAL_TIMESTAMPS
+        .may_load(storage, (scope_address.to_string(), asset_type.to_string()))
+        .map_err(|e| e.into_contract_error())
+}
+
+/// Removes the recorded denial time for an asset, if one exists.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `scope_address` The bech32 address of the scope.
+/// * `asset_type` The asset type for which the denial time should be removed.
+pub fn delete_denial_timestamp(storage: &mut dyn Storage, scope_address: &str, asset_type: &str) {
+    DENIAL_TIMESTAMPS.remove(storage, (scope_address.to_string(), asset_type.to_string()));
+}
+
+/// Fetches the number of onboarding requests a verifier has received for an asset type within a
+/// single block.  Returns zero if none have been recorded.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+/// * `asset_type` The asset type of the onboarding requests.
+/// * `verifier_address` The bech32 address of the verifier.
+/// * `block_height` The height of the block in which the requests were received.
+pub fn load_verifier_block_classification_count(
+    storage: &dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+    block_height: u64,
+) -> AssetResult<u32> {
+    VERIFIER_BLOCK_CLASSIFICATIONS
+        .may_load(
+            storage,
+            (
+                asset_type.to_string(),
+                verifier_address.to_string(),
+                block_height,
+            ),
+        )
+        .map(|count| count.unwrap_or_default())
+        .map_err(|e| e.into_contract_error())
+}
+
+/// Records an onboarding request received by a verifier for an asset type within a single block,
+/// returning the updated number of requests for that block.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type of the onboarding request.
+/// * `verifier_address` The bech32 address of the verifier.
+/// * `block_height` The height of the block in which the request was received.
+pub fn increment_verifier_block_classification_count(
+    storage: &mut dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+    block_height: u64,
+) -> AssetResult<u32> {
+    VERIFIER_BLOCK_CLASSIFICATIONS
+        .update(
+            storage,
+            (
+                asset_type.to_string(),
+                verifier_address.to_string(),
+                block_height,
+            ),
+            |count| -> StdResult<u32> { Ok(count.unwrap_or_default().saturating_add(1)) },
+        )
+        .map_err(|e| e.into_contract_error())
+}
+
+/// Records an onboarding request in the contract's [metrics](self::StateV2::metrics), incrementing
+/// the total onboard count, the onboard count for the asset type, and the total fees collected.
+/// The count of distinct onboarded assets is only incremented when the request is not a retry.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type that was onboarded.
+/// * `fees_collected_nhash` The amount of fees, in nhash, assessed from the requestor.
+/// * `is_retry` Whether or not the asset was previously onboarded as the same asset type.
+pub fn record_onboard_metrics(
+    storage: &mut dyn Storage,
+    asset_type: &str,
+    fees_collected_nhash: u128,
+    is_retry: bool,
+) -> AssetResult<()> {
+    let mut state = STATE_V2.load(storage)?;
+    state.metrics.total_onboards = state.metrics.total_onboards.saturating_add(1);
+    if !is_retry {
+        state.metrics.total_onboarded_ever = state.metrics.total_onboarded_ever.saturating_add(1);
+    }
+    state.metrics.total_fees_collected_nhash = state
+        .metrics
+        .total_fees_collected_nhash
+        .saturating_add(fees_collected_nhash.into());
+    STATE_V2.save(storage, &state)?;
+    ASSET_TYPE_ONBOARD_COUNTS.update(
+        storage,
+        asset_type.to_string(),
+        |count| -> StdResult<u64> { Ok(count.unwrap_or_default().saturating_add(1)) },
+    )?;
+    ().to_ok()
+}
+
+/// Records a verification in the contract's [metrics](self::StateV2::metrics).  The count of
+/// verified assets is only incremented when the verification approved the asset.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `success` Whether or not the verifier approved the asset.
+pub fn record_verification_metrics(storage: &mut dyn Storage, success: bool) -> AssetResult<()> {
+    let mut state = STATE_V2.load(storage)?;
+    state.metrics.total_verifications = state.metrics.total_verifications.saturating_add(1);
+    if success {
+        state.metrics.total_verified_ever = state.metrics.total_verified_ever.saturating_add(1);
+    }
+    STATE_V2.save(storage, &state)?.to_ok()
+}
+
+/// Fetches the number of onboarding requests processed for each asset type that has been
+/// onboarded at least once, in ascending order of asset type.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+pub fn list_asset_type_onboard_counts(storage: &dyn Storage) -> AssetResult<Vec<(String, u64)>> {
+    ASSET_TYPE_ONBOARD_COUNTS
+        .range(storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<StdResult<Vec<(String, u64)>>>()
+        .map_err(|e| e.into_contract_error())
+}
+
+/// Applies a change to the [VerifierStats](super::types::verifier_stats::VerifierStats) of a
+/// verifier for an asset type, creating a zeroed entry if none exists yet.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type of the onboarding that caused the change.
+/// * `verifier_address` The bech32 address of the verifier assigned to the onboarding.
+/// * `update` A function that modifies the verifier's statistics.
+fn update_verifier_stats<F: FnOnce(&mut VerifierStats)>(
+    storage: &mut dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+    update: F,
+) -> AssetResult<()> {
+    let key = (asset_type.to_string(), verifier_address.to_string());
+    let mut stats = VERIFIER_STATISTICS
+        .may_load(storage, key.clone())?
+        .unwrap_or_else(|| VerifierStats::new(verifier_address, asset_type));
+    update(&mut stats);
+    VERIFIER_STATISTICS.save(storage, key, &stats)?.to_ok()
+}
+
+/// Records a new pending onboarding in a verifier's [statistics](super::types::verifier_stats::VerifierStats).
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type of the onboarded asset.
+/// * `verifier_address` The bech32 address of the verifier assigned to the onboarding.
+pub fn increment_verifier_pending(
+    storage: &mut dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+) -> AssetResult<()> {
+    update_verifier_stats(storage, asset_type, verifier_address, |stats| {
+        stats.total_pending = stats.total_pending.saturating_add(1);
+    })
+}
+
+/// Removes a pending onboarding from a verifier's [statistics](super::types::verifier_stats::VerifierStats)
+/// without recording a verification, such as when the onboarding is cancelled, expires, or is
+/// moved to another verifier address.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type of the onboarded asset.
+/// * `verifier_address` The bech32 address of the verifier assigned to the onboarding.
+pub fn decrement_verifier_pending(
+    storage: &mut dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+) -> AssetResult<()> {
+    update_verifier_stats(storage, asset_type, verifier_address, |stats| {
+        stats.total_pending = stats.total_pending.saturating_sub(1);
+    })
+}
+
+/// Records a verification in a verifier's [statistics](super::types::verifier_stats::VerifierStats),
+/// moving the onboarding from the pending count to the approved or denied count.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type of the verified asset.
+/// * `verifier_address` The bech32 address of the verifier that made the decision.
+/// * `success` Whether or not the verifier approved the asset.
+pub fn record_verifier_decision(
+    storage: &mut dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+    success: bool,
+) -> AssetResult<()> {
+    update_verifier_stats(storage, asset_type, verifier_address, |stats| {
+        stats.total_pending = stats.total_pending.saturating_sub(1);
+        if success {
+            stats.total_approved = stats.total_approved.saturating_add(1);
+        } else {
+            stats.total_denied = stats.total_denied.saturating_add(1);
+        }
+    })
+}
+
+/// Records that an upheld appeal approved an asset that its verifier had denied.  The onboarding
+/// is moved from the verifier's denied count to its approved count in its [statistics](super::types::verifier_stats::VerifierStats),
+/// and the asset is counted in the contract's [total_verified_ever](super::types::contract_metrics::ContractMetrics::total_verified_ever)
+/// metric.  The total number of verifications is left unchanged, as the verifier's denial was
+/// already counted.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type of the approved asset.
+/// * `verifier_address` The bech32 address of the verifier that denied the asset.
+pub fn record_overturned_denial(
+    storage: &mut dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+) -> AssetResult<()> {
+    let mut state = STATE_V2.load(storage)?;
+    state.metrics.total_verified_ever = state.metrics.total_verified_ever.saturating_add(1);
+    STATE_V2.save(storage, &state)?;
+    update_verifier_stats(storage, asset_type, verifier_address, |stats| {
+        stats.total_denied = stats.total_denied.saturating_sub(1);
+        stats.total_approved = stats.total_approved.saturating_add(1);
+    })
+}
+
+/// Fetches the [statistics](super::types::verifier_stats::VerifierStats) of a verifier for an
+/// asset type, returning zeroed statistics if the verifier has never been assigned an onboarding
+/// of the asset type.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+/// * `asset_type` The asset type for which to fetch statistics.
+/// * `verifier_address` The bech32 address of the verifier for which to fetch statistics.
+pub fn load_verifier_stats(
+    storage: &dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+) -> AssetResult<VerifierStats> {
+    VERIFIER_STATISTICS
+        .may_load(
+            storage,
+            (asset_type.to_string(), verifier_address.to_string()),
+        )?
+        .unwrap_or_else(|| VerifierStats::new(verifier_address, asset_type))
+        .to_ok()
+}
+
+/// Fetches a single page of [VerifierStats](super::types::verifier_stats::VerifierStats), sorted in
+/// ascending order by asset type and then by verifier address.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+/// * `asset_type` An optional asset type filter.  If omitted, statistics for all asset types are
+/// returned.
+/// * `start_after` The asset type and verifier address of the final statistics in a previous page.
+/// When omitted, the first page is returned.
+/// * `limit` The maximum number of statistics to include in the page.
+pub fn list_verifier_stats_page(
+    storage: &dyn Storage,
+    asset_type: Option<&str>,
+    start_after: Option<(String, String)>,
+    limit: u32,
+) -> AssetResult<Vec<VerifierStats>> {
+    match asset_type {
+        Some(asset_type) => {
+            let min = match start_after {
+                Some((start_asset_type, verifier_address)) if start_asset_type == asset_type => {
+                    Some(Bound::exclusive(verifier_address))
+                }
+                // Every entry for the filtered asset type sorts before the provided key
+                Some((start_asset_type, _)) if start_asset_type.as_str() > asset_type => {
+                    return vec![].to_ok();
+                }
+                _ => None,
+            };
+            VERIFIER_STATISTICS
+                .prefix(asset_type.to_string())
+                .range(storage, min, None, cosmwasm_std::Order::Ascending)
+                .take(limit as usize)
+                .map(|entry| entry.map(|(_, stats)| stats))
+                .collect::<StdResult<Vec<VerifierStats>>>()
+        }
+        None => VERIFIER_STATISTICS
+            .range(
+                storage,
+                start_after.map(Bound::exclusive),
+                None,
+                cosmwasm_std::Order::Ascending,
+            )
+            .take(limit as usize)
+            .map(|entry| entry.map(|(_, stats)| stats))
+            .collect::<StdResult<Vec<VerifierStats>>>(),
+    }
+    .map_err(|e| e.into_contract_error())
+}
+
+/// Counts the keys held in each of the contract's storage maps.  Raw keys are counted to avoid
+/// deserializing any stored values.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+pub fn count_storage_keys(storage: &dyn Storage) -> StorageKeyCountResponse {
+    let count = |keys: Box<dyn Iterator<Item = Vec<u8>> + '_>| keys.count() as u64;
+    StorageKeyCountResponse::new(
+        count(ASSET_DEFINITIONS_V3.keys_raw(storage, None, None, cosmwasm_std::Order::Ascending)),
+        count(FEE_PAYMENT_DETAILS.keys_raw(storage, None, None, cosmwasm_std::Order::Ascending)),
+        count(VERIFIER_STATUS_INDEX.keys_raw(storage, None, None, cosmwasm_std::Order::Ascending))
+            + count(SCOPE_SPEC_TO_ASSET_TYPE.keys_raw(
+                storage,
+                None,
+                None,
+                cosmwasm_std::Order::Ascending,
+            ))
+            + count(ASSET_DEFINITION_LABEL_INDEX.keys_raw(
+                storage,
+                None,
+                None,
+                cosmwasm_std::Order::Ascending,
+            ))
+            + count(REQUESTOR_INDEX.keys_raw(storage, None, None, cosmwasm_std::Order::Ascending))
+            + count(ACCESS_ROUTE_OWNER_INDEX.keys_raw(
+                storage,
+                None,
+                None,
+                cosmwasm_std::Order::Ascending,
+            ))
+            + count(LAST_MODIFIED_BLOCK_INDEX.keys_raw(
+                storage,
+                None,
+                None,
+                cosmwasm_std::Order::Ascending,
+            )),
+        count(DENIAL_TIMESTAMPS.keys_raw(storage, None, None, cosmwasm_std::Order::Ascending)),
+        count(ASSET_TYPE_ONBOARD_COUNTS.keys_raw(
+            storage,
+            None,
+            None,
+            cosmwasm_std::Order::Ascending,
+        )) + count(VERIFIER_STATISTICS.keys_raw(
+            storage,
+            None,
+            None,
+            cosmwasm_std::Order::Ascending,
+        )) + count(VERIFIER_BLOCK_CLASSIFICATIONS.keys_raw(
+            storage,
+            None,
+            None,
+            cosmwasm_std::Order::Ascending,
+        )),
+    )
+}
+
+/// Builds the contract's current [statistics](super::types::contract_statistics_response::ContractStatisticsResponse).
+/// Onboarding status counts are derived from the verifier status index, which holds a single entry
+/// for each asset and asset type combination, so retried assets are only counted once.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+pub fn build_contract_statistics(storage: &dyn Storage) -> AssetResult<ContractStatisticsResponse> {
+    let metrics = STATE_V2.load(storage)?.metrics;
+    let mut pending = 0u64;
+    let mut approved = 0u64;
+    let mut denied = 0u64;
+    let mut appealing = 0u64;
+    for key in VERIFIER_STATUS_INDEX.keys(storage, None, None, cosmwasm_std::Order::Ascending) {
+        let (_, status, _) = key.map_err(|e| e.into_contract_error())?;
+        if status == AssetOnboardingStatus::Pending.to_string() {
+            pending += 1;
+        } else if status == AssetOnboardingStatus::Approved.to_string() {
+            approved += 1;
+        } else if status == AssetOnboardingStatus::Denied.to_string() {
+            denied += 1;
+        } else if status == AssetOnboardingStatus::Appealing.to_string() {
+            appealing += 1;
+        }
+    }
+    ContractStatisticsResponse {
+        total_asset_definitions: ASSET_DEFINITIONS_V3
+            .keys_raw(storage, None, None, cosmwasm_std::Order::Ascending)
+            .count() as u64,
+        total_pending_onboardings: pending,
+        total_approved_onboardings: approved,
+        total_denied_onboardings: denied,
+        total_appealing_onboardings: appealing,
+        total_fee_payment_details_stored: FEE_PAYMENT_DETAILS
+            .keys_raw(storage, None, None, cosmwasm_std::Order::Ascending)
+            .count() as u64,
+        total_onboarded_ever: metrics.total_onboarded_ever,
+        total_verified_ever: metrics.total_verified_ever,
+    }
+    .to_ok()
+}
+
+/// Moves an asset's entry in the verifier and onboarding status index from its previous state to
+/// its current state.  This should be invoked any time an [AssetScopeAttribute](super::types::asset_scope_attribute::AssetScopeAttribute)
+/// is created or has its verifier or onboarding status changed.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `previous_attribute` The attribute as it existed before the change, if any.  Its index entry
+/// will be removed.
+/// * `current_attribute` The attribute after the change.  An index entry will be added for it.
+pub fn update_verifier_status_index(
+    storage: &mut dyn Storage,
+    previous_attribute: Option<&AssetScopeAttribute>,
+    current_attribute: &AssetScopeAttribute,
+) -> AssetResult<()> {
+    if let Some(previous) = previous_attribute {
+        VERIFIER_STATUS_INDEX.remove(storage, verifier_status_index_key(previous));
+    }
+    VERIFIER_STATUS_INDEX
+        .save(
+            storage,
+            verifier_status_index_key(current_attribute),
+            &current_attribute.asset_type,
+        )?
+        .to_ok()
+}
+
+/// Counts all assets in the verifier and onboarding status index for a verifier with a specific
+/// onboarding status.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+/// * `verifier_address` The bech32 address of the verifier for which to count assets.
+/// * `status` The onboarding status of the assets to count.
+/// * `asset_type` An optional asset type filter.  If omitted, assets of all types are counted.
+pub fn count_assets_by_verifier_and_status(
+    storage: &dyn Storage,
+    verifier_address: &str,
+    status: &AssetOnboardingStatus,
+    asset_type: Option<&str>,
+) -> AssetResult<u64> {
+    let mut count = 0;
+    for entry in VERIFIER_STATUS_INDEX
+        .prefix((verifier_address.to_string(), status.to_string()))
+        .range_raw(storage, None, None, cosmwasm_std::Order::Ascending)
+    {
+        let (_, stored_asset_type) = entry?;
+        if asset_type.is_none_or(|asset_type| asset_type == stored_asset_type) {
+            count += 1;
+        }
+    }
+    count.to_ok()
+}
+
+/// Fetches a single page of the scope addresses and asset types of the assets currently awaiting
+/// verification by a specific verifier, sorted in ascending order by scope address.  A page never
+/// splits the asset types of a single scope, so the limit counts scopes rather than asset types.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+/// * `verifier_address` The bech32 address of the verifier for which to list pending assets.
+/// * `asset_type` An optional asset type filter.  If omitted, pending assets of all types are
+/// listed.
+/// * `start_after` The scope address after which to begin the page.  When omitted, the page
+/// begins with the first pending scope.
+/// * `limit` The maximum number of scopes to include in the page.
+pub fn list_pending_verifier_status_index_page(
+    storage: &dyn Storage,
+    verifier_address: &str,
+    asset_type: Option<&str>,
+    start_after: Option<String>,
+    limit: u32,
+) -> AssetResult<Vec<(String, String)>> {
+    let mut scope_count = 0;
+    let mut pending_assets: Vec<(String, String)> = vec![];
+    for entry in VERIFIER_STATUS_INDEX
+        .prefix((
+            verifier_address.to_string(),
+            AssetOnboardingStatus::Pending.to_string(),
+        ))
+        .range(
+            storage,
+            // Every entry for the start_after scope sorts after its empty asset type, so they are
+            // skipped below rather than by the bound
+            start_after
+                .to_owned()
+                .map(|scope_address| Bound::exclusive((scope_address, String::new()))),
+            None,
+            cosmwasm_std::Order::Ascending,
+        )
+    {
+        let ((scope_address, _), stored_asset_type) = entry?;
+        if start_after.as_ref() == Some(&scope_address)
+            || asset_type.is_some_and(|asset_type| asset_type != stored_asset_type)
+        {
+            continue;
+        }
+        let is_new_scope = pending_assets
+            .last()
+            .is_none_or(|(last_scope_address, _)| last_scope_address != &scope_address);
+        if is_new_scope {
+            if scope_count == limit {
+                break;
+            }
+            scope_count += 1;
+        }
+        pending_assets.push((scope_address, stored_asset_type));
+    }
+    pending_assets.to_ok()
+}
+
+/// Moves an asset's entry in the requestor index from the requestor of its previous state to the
+/// requestor of its current state.  This should be invoked any time an [AssetScopeAttribute](super::types::asset_scope_attribute::AssetScopeAttribute)
+/// is created or has its requestor changed.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `previous_attribute` The attribute as it existed before the change, if any.  Its asset type
+/// will be removed from its requestor's index entry.
+/// * `current_attribute` The attribute after the change.  Its asset type will be added to its
+/// requestor's index entry.
+pub fn update_requestor_index(
+    storage: &mut dyn Storage,
+    previous_attribute: Option<&AssetScopeAttribute>,
+    current_attribute: &AssetScopeAttribute,
+) -> AssetResult<()> {
+    if let Some(previous) = previous_attribute {
+        let previous_key = requestor_index_key(previous);
+        if let Some(mut asset_types) = REQUESTOR_INDEX.may_load(storage, previous_key.clone())? {
+            asset_types.retain(|asset_type| asset_type != &previous.asset_type);
+            if asset_types.is_empty() {
+                REQUESTOR_INDEX.remove(storage, previous_key);
+            } else {
+                REQUESTOR_INDEX.save(storage, previous_key, &asset_types)?;
+            }
+        }
+    }
+    let current_key = requestor_index_key(current_attribute);
+    let mut asset_types = REQUESTOR_INDEX
+        .may_load(storage, current_key.clone())?
+        .unwrap_or_default();
+    if !asset_types.contains(&current_attribute.asset_type) {
+        asset_types.push(current_attribute.asset_type.to_owned());
+    }
+    REQUESTOR_INDEX
+        .save(storage, current_key, &asset_types)?
+        .to_ok()
+}
+
+/// A scope address from the requestor index, alongside the asset types it was onboarded as.
+pub type RequestorIndexEntry = (String, Vec<String>);
+
+/// Fetches a single page of scopes onboarded by a requestor from the requestor index, sorted in
+/// ascending order by scope address.  Each scope is returned alongside the asset types it was
+/// onboarded as.  The returned boolean indicates whether or not more matching scopes exist after
+/// the final scope in the page.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+/// * `requestor_address` The bech32 address of the requestor for which to list scopes.
+/// * `asset_type` An optional asset type filter.  If provided, only scopes onboarded as this type
+/// are returned, and only this type is included in each scope's asset types.
+/// * `start_after` The scope address of the final scope of the previous page.  All returned scopes
+/// will be sorted after this value.  When omitted, the first page is returned.
+/// * `limit` The maximum number of scopes to include in the page.
+pub fn list_requestor_index_page(
+    storage: &dyn Storage,
+    requestor_address: &str,
+    asset_type: Option<&str>,
+    start_after: Option<String>,
+    limit: u32,
+) -> AssetResult<(Vec<RequestorIndexEntry>, bool)> {
+    let mut scopes = vec![];
+    for entry in REQUESTOR_INDEX.prefix(requestor_address.to_string()).range(
+        storage,
+        start_after.map(Bound::exclusive),
+        None,
+        cosmwasm_std::Order::Ascending,
+    ) {
+        let (scope_address, mut asset_types) = entry?;
+        if let Some(asset_type) = asset_type {
+            asset_types.retain(|stored_asset_type| stored_asset_type == asset_type);
+        }
+        if asset_types.is_empty() {
+            continue;
+        }
+        // Take a single extra scope to determine if another page exists after this one
+        if scopes.len() == limit as usize {
+            return (scopes, true).to_ok();
+        }
+        scopes.push((scope_address, asset_types));
+    }
+    (scopes, false).to_ok()
+}
+
+/// Moves an asset's entries in the access route owner index from the owners of the access routes
+/// in its previous state to the owners of the access routes in its current state.  This should be
+/// invoked any time an [AssetScopeAttribute](super::types::asset_scope_attribute::AssetScopeAttribute)
+/// is created or has its access definitions changed.  Owners whose access definitions contain no
+/// routes are not indexed.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `previous_attribute` The attribute as it existed before the change, if any.  Its asset type
+/// will be removed from the index entry of each of its access route owners.
+/// * `current_attribute` The attribute after the change.  Its asset type will be added to the
+/// index entry of each of its access route owners.
+pub fn update_access_route_owner_index(
+    storage: &mut dyn Storage,
+    previous_attribute: Option<&AssetScopeAttribute>,
+    current_attribute: &AssetScopeAttribute,
+) -> AssetResult<()> {
+    if let Some(previous) = previous_attribute {
+        for owner_address in access_route_owners(previous) {
+            let previous_key = (owner_address, previous.scope_address.to_owned());
+            if let Some(mut asset_types) =
+                ACCESS_ROUTE_OWNER_INDEX.may_load(storage, previous_key.clone())?
+            {
+                asset_types.retain(|asset_type| asset_type != &previous.asset_type);
+                if asset_types.is_empty() {
+                    ACCESS_ROUTE_OWNER_INDEX.remove(storage, previous_key);
+                } else {
+                    ACCESS_ROUTE_OWNER_INDEX.save(storage, previous_key, &asset_types)?;
+                }
+            }
+        }
+    }
+    for owner_address in access_route_owners(current_attribute) {
+        let current_key = (owner_address, current_attribute.scope_address.to_owned());
+        let mut asset_types = ACCESS_ROUTE_OWNER_INDEX
+            .may_load(storage, current_key.clone())?
+            .unwrap_or_default();
+        if !asset_types.contains(&current_attribute.asset_type) {
+            asset_types.push(current_attribute.asset_type.to_owned());
+        }
+        ACCESS_ROUTE_OWNER_INDEX.save(storage, current_key, &asset_types)?;
+    }
+    Ok(())
+}
+
+/// Fetches a single page of scopes on which an address owns access routes from the access route
+/// owner index, sorted in ascending order by scope address.  Each scope is returned alongside the
+/// asset types for which the address owns access routes on it.
 ///
 /// # Parameters
 ///
-/// * `storage` A mutable reference to the contract's internal storage.
-/// * `asset_type` The asset type to delete.
-pub fn delete_asset_definition_by_asset_type_v3(
-    storage: &mut dyn Storage,
-    asset_type: &str,
-) -> AssetResult<String> {
-    let existing_asset_type = load_asset_definition_by_type_v3(storage, asset_type)?.asset_type;
-    ASSET_DEFINITIONS_V3.remove(storage, existing_asset_type.to_lowercase());
-    Ok(existing_asset_type)
+/// * `storage` A reference to the contract's internal storage.
+/// * `owner_address` The bech32 address of the access route owner for which to list scopes.
+/// * `start_after` The scope address of the final scope of the previous page.  All returned scopes
+/// will be sorted after this value.  When omitted, the first page is returned.
+/// * `limit` The maximum number of scopes to include in the page.
+pub fn list_access_route_owner_index_page(
+    storage: &dyn Storage,
+    owner_address: &str,
+    start_after: Option<String>,
+    limit: u32,
+) -> AssetResult<Vec<(String, Vec<String>)>> {
+    ACCESS_ROUTE_OWNER_INDEX
+        .prefix(owner_address.to_string())
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            cosmwasm_std::Order::Ascending,
+        )
+        .take(limit as usize)
+        .map(|entry| entry.map_err(ContractError::Std))
+        .collect()
 }
 
-/// Inserts a new payment detail into storage.  If a value already exists, an error will be returned.
-/// Note: Each payment detail must contain a unique [scope_address](super::types::fee_payment_detail::FeePaymentDetail::scope_address)
-/// value, or the insert will be rejected with an error.
+/// Moves an asset's entry in the last modified block index from the block at which its previous
+/// state was modified to the block at which its current state was modified.  This should be
+/// invoked any time an [AssetScopeAttribute](super::types::asset_scope_attribute::AssetScopeAttribute)
+/// is created or updated.
 ///
 /// # Parameters
 ///
 /// * `storage` A mutable reference to the contract's internal storage.
-/// * `fee_payment_detail` The detail to insert into storage and derive the unique scope address key.
-pub fn insert_fee_payment_detail(
+/// * `previous_attribute` The attribute as it existed before the change, if any.  Its index entry
+/// will be removed.
+/// * `current_attribute` The attribute after the change.  An index entry will be added for it.
+pub fn update_last_modified_block_index(
     storage: &mut dyn Storage,
-    fee_payment_detail: &FeePaymentDetail,
-    asset_type: &str,
+    previous_attribute: Option<&AssetScopeAttribute>,
+    current_attribute: &AssetScopeAttribute,
 ) -> AssetResult<()> {
-    if load_fee_payment_detail(storage, &fee_payment_detail.scope_address, asset_type).is_ok() {
-        return ContractError::RecordAlreadyExists {
-            explanation: format!(
-                "cannot insert payment detail for scope [{}] and asset type [{}] because a record already exists with that address and asset type",
-                &fee_payment_detail.scope_address,
-                &asset_type
-            )
-        }.to_err();
+    if let Some(previous) = previous_attribute {
+        LAST_MODIFIED_BLOCK_INDEX.remove(storage, last_modified_block_index_key(previous));
     }
-    FEE_PAYMENT_DETAILS
+    LAST_MODIFIED_BLOCK_INDEX
         .save(
             storage,
-            (
-                Addr::unchecked(&fee_payment_detail.scope_address),
-                asset_type.into(),
-            ),
-            fee_payment_detail,
+            last_modified_block_index_key(current_attribute),
+            &current_attribute.asset_type,
         )?
         .to_ok()
 }
 
-/// Finds an existing fee payment detail by scope address, or returns an error if no detail is found.
+/// Lists the scope addresses of all assets in the last modified block index that were modified
+/// after a specific block height, sorted in ascending order by the block at which each was most
+/// recently modified.  Each scope address is only included once, at the position of its earliest
+/// matching modification.
 ///
 /// # Parameters
 ///
 /// * `storage` A reference to the contract's internal storage.
-/// * `scope_address` The unique key [scope_address](super::types::fee_payment_detail::FeePaymentDetail::scope_address)
-/// for the requested payment detail.
-pub fn load_fee_payment_detail<S1: Into<String>, S2: Into<String>>(
+/// * `after_block` The block height after which assets must have been modified to be included.
+/// * `asset_type` An optional asset type filter.  If omitted, assets of all types are included.
+pub fn list_scopes_modified_after_block(
     storage: &dyn Storage,
-    scope_address: S1,
-    asset_type: S2,
-) -> AssetResult<FeePaymentDetail> {
-    FEE_PAYMENT_DETAILS
-        .load(storage, (Addr::unchecked(scope_address), asset_type.into()))?
-        .to_ok()
+    after_block: u64,
+    asset_type: Option<&str>,
+) -> AssetResult<Vec<String>> {
+    let mut scope_addresses: Vec<String> = vec![];
+    for entry in LAST_MODIFIED_BLOCK_INDEX.prefix_range(
+        storage,
+        Some(PrefixBound::exclusive(after_block)),
+        None,
+        cosmwasm_std::Order::Ascending,
+    ) {
+        let ((_, (scope_address, _)), stored_asset_type) = entry?;
+        if asset_type.is_some_and(|asset_type| asset_type != stored_asset_type)
+            || scope_addresses.contains(&scope_address)
+        {
+            continue;
+        }
+        scope_addresses.push(scope_address);
+    }
+    scope_addresses.to_ok()
 }
 
-/// Attempts to find an existing fee payment detail by scope address, or returns a None variant if
-/// an error occurs or no detail is found.
-///
-/// # Parameters
-///
-/// * `storage` A reference to the contract's internal storage.
-/// * `scope_address` The unique key [scope_address](super::types::fee_payment_detail::FeePaymentDetail::scope_address)
-/// for the requested payment detail.
-pub fn may_load_fee_payment_detail<S1: Into<String>, S2: Into<String>>(
-    storage: &dyn Storage,
-    scope_address: S1,
-    asset_type: S2,
-) -> Option<FeePaymentDetail> {
-    FEE_PAYMENT_DETAILS
-        .may_load(storage, (Addr::unchecked(scope_address), asset_type.into()))
-        .unwrap_or(None)
+fn access_route_owners(attribute: &AssetScopeAttribute) -> Vec<String> {
+    let mut owners = attribute
+        .access_definitions
+        .iter()
+        .filter(|definition| !definition.access_routes.is_empty())
+        .map(|definition| definition.owner_address.to_owned())
+        .collect::<Vec<String>>();
+    owners.sort();
+    owners.dedup();
+    owners
 }
 
-/// Attempts to delete an existing payment detail by scope address.  Returns an error if the detail
-/// does not exist or if deletion fails.
-///
-/// # Parameters
-///
-/// * `storage` A mutable reference to the contract's internal storage.
-/// * `scope_address` The unique key [scope_address](super::types::fee_payment_detail::FeePaymentDetail::scope_address)
-/// of the detail to delete.
-pub fn delete_fee_payment_detail<S1: Into<String>, S2: Into<String>>(
-    storage: &mut dyn Storage,
-    scope_address: S1,
-    asset_type: S2,
-) -> AssetResult<()> {
-    let scope_address = scope_address.into();
-    let asset_type = asset_type.into();
-    // Verify the detail exists before allowing its deletion.  The standard "remove" function will
-    // not produce an error if no target value exists, but it is very informative of bad code to
-    // reveal when unnecessary operations occur.
-    load_fee_payment_detail(storage, &scope_address, &asset_type)?;
-    FEE_PAYMENT_DETAILS.remove(
-        storage,
+fn requestor_index_key(attribute: &AssetScopeAttribute) -> (String, String) {
+    (
+        attribute.requestor_address.to_string(),
+        attribute.scope_address.to_owned(),
+    )
+}
+
+fn last_modified_block_index_key(attribute: &AssetScopeAttribute) -> (u64, (String, String)) {
+    (
+        attribute.last_modified_block,
         (
-            Addr::unchecked(scope_address.to_owned()),
-            asset_type.to_owned(),
+            attribute.scope_address.to_owned(),
+            attribute.asset_type.to_owned(),
         ),
-    );
-    ().to_ok()
+    )
+}
+
+fn verifier_status_index_key(
+    attribute: &AssetScopeAttribute,
+) -> (String, String, (String, String)) {
+    (
+        attribute.verifier_address.to_string(),
+        attribute.onboarding_status.to_string(),
+        (
+            attribute.scope_address.to_owned(),
+            attribute.asset_type.to_owned(),
+        ),
+    )
 }
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::StdError;
+    use cosmwasm_std::{Addr, Order, StdResult, Uint128};
     use provwasm_mocks::mock_provenance_dependencies;
 
     use crate::core::error::ContractError;
     use crate::core::state::{
-        delete_asset_definition_by_asset_type_v3, delete_fee_payment_detail,
-        insert_asset_definition_v3, insert_fee_payment_detail, load_asset_definition_by_type_v3,
-        load_fee_payment_detail, may_load_asset_definition_by_type_v3, may_load_fee_payment_detail,
-        replace_asset_definition_v3,
+        count_assets_by_verifier_and_status, delete_asset_definition_by_asset_type_v3,
+        delete_fee_payment_detail, insert_asset_definition_v3, insert_fee_payment_detail,
+        iter_asset_definitions_v3, list_access_route_owner_index_page,
+        list_pending_verifier_status_index_page, list_requestor_index_page,
+        load_asset_definition_by_type_v3, load_asset_definition_count, load_fee_payment_detail,
+        may_load_asset_definition_by_type_v3, may_load_asset_type_by_scope_spec_address,
+        may_load_fee_payment_detail, rekey_asset_definition_v3, replace_asset_definition_v3,
+        update_access_route_owner_index, update_requestor_index, update_verifier_status_index,
+        RequestorIndexEntry, StateV2, ASSET_DEFINITIONS_V3, ASSET_DEFINITION_LABEL_INDEX,
+        SCOPE_SPEC_TO_ASSET_TYPE,
     };
+    use crate::core::types::access_definition::{AccessDefinition, AccessDefinitionType};
+    use crate::core::types::access_route::AccessRoute;
     use crate::core::types::asset_definition::AssetDefinitionV3;
-    use crate::testutil::test_constants::{DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS};
-    use crate::testutil::test_utilities::get_duped_fee_payment_detail;
+    use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+    use crate::core::types::asset_scope_attribute::AssetScopeAttribute;
+    use crate::core::types::contract_metrics::ContractMetrics;
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS, DEFAULT_SCOPE_SPEC_ADDRESS,
+        DEFAULT_SECONDARY_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        get_default_asset_scope_attribute, get_default_verifier_detail,
+        get_duped_fee_payment_detail, test_instantiate_success, InstArgs, MockOwnedDeps,
+    };
     use crate::util::traits::OptionExtensions;
 
     #[test]
     fn test_insert_asset_definition() {
         let mut deps = mock_provenance_dependencies();
-        let def = AssetDefinitionV3::new("heloc", "Home Equity Line of Credit".to_some(), vec![]);
+        let def = AssetDefinitionV3::builder()
+            .asset_type("heloc")
+            .display_name("Home Equity Line of Credit".to_some())
+            .add_verifier(get_default_verifier_detail())
+            .build()
+            .expect("the heloc asset definition should be built");
         insert_asset_definition_v3(deps.as_mut().storage, &def)
             .expect("insert should work correctly");
         let error = insert_asset_definition_v3(deps.as_mut().storage, &def).unwrap_err();
@@ -330,11 +1710,110 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_asset_definition_count_tracks_inserts_and_deletes() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        assert_eq!(
+            1,
+            load_asset_definition_count(deps.as_ref().storage)
+                .expect("the count should load after instantiation"),
+            "the count should include the definition provided during instantiation",
+        );
+        let def = AssetDefinitionV3::builder()
+            .asset_type("heloc")
+            .display_name("Home Equity Line of Credit".to_some())
+            .add_verifier(get_default_verifier_detail())
+            .build()
+            .expect("the heloc asset definition should be built");
+        insert_asset_definition_v3(deps.as_mut().storage, &def)
+            .expect("insert should work correctly");
+        insert_asset_definition_v3(deps.as_mut().storage, &def)
+            .expect_err("a duplicate insert should fail");
+        assert_eq!(
+            2,
+            load_asset_definition_count(deps.as_ref().storage)
+                .expect("the count should load after an insert"),
+            "the count should be incremented only by the successful insert",
+        );
+        delete_asset_definition_by_asset_type_v3(deps.as_mut().storage, &def.asset_type)
+            .expect("delete should work correctly");
+        assert_eq!(
+            1,
+            load_asset_definition_count(deps.as_ref().storage)
+                .expect("the count should load after a delete"),
+            "the count should be decremented by the delete",
+        );
+    }
+
+    #[test]
+    fn test_iter_asset_definitions_yields_all_definitions() {
+        let mut deps = mock_provenance_dependencies();
+        let definitions = ["mortgage", "heloc", "auto"]
+            .into_iter()
+            .map(|asset_type| AssetDefinitionV3::new(asset_type, None::<String>, vec![]))
+            .collect::<Vec<AssetDefinitionV3>>();
+        for definition in definitions.iter() {
+            insert_asset_definition_v3(deps.as_mut().storage, definition)
+                .expect("insert should work correctly");
+        }
+        let iterated_asset_types = iter_asset_definitions_v3(deps.as_ref().storage)
+            .map(|result| {
+                result
+                    .expect("each definition should be read successfully")
+                    .asset_type
+            })
+            .collect::<Vec<String>>();
+        assert_eq!(
+            vec!["auto", "heloc", "mortgage"],
+            iterated_asset_types,
+            "every definition should be yielded in ascending order by asset type",
+        );
+    }
+
+    #[test]
+    fn test_iter_asset_definitions_yields_errors_for_unreadable_definitions() {
+        let mut deps = mock_provenance_dependencies();
+        insert_asset_definition_v3(
+            deps.as_mut().storage,
+            &AssetDefinitionV3::new("heloc", None::<String>, vec![]),
+        )
+        .expect("insert should work correctly");
+        // Directly write a value that cannot be deserialized, keyed the same way as the asset
+        // definitions map: a length-prefixed namespace followed by the asset type
+        deps.as_mut().storage.set(
+            &[b"\x00\x14asset_definitions_v2".as_slice(), b"auto"].concat(),
+            b"not json",
+        );
+        let results = iter_asset_definitions_v3(deps.as_ref().storage).collect::<Vec<_>>();
+        assert_eq!(
+            2,
+            results.len(),
+            "an unreadable definition should not halt iteration",
+        );
+        assert!(
+            results[0].is_err(),
+            "the unreadable definition should produce an error",
+        );
+        assert_eq!(
+            "heloc",
+            results[1]
+                .as_ref()
+                .expect("the valid definition should be read successfully")
+                .asset_type,
+            "the valid definition should still be yielded after the error",
+        );
+    }
+
     #[test]
     fn test_replace_asset_definition() {
         let mut deps = mock_provenance_dependencies();
-        let mut def =
-            AssetDefinitionV3::new("heloc", "Home Equity Line of Credit".to_some(), vec![]);
+        let mut def = AssetDefinitionV3::builder()
+            .asset_type("heloc")
+            .display_name("Home Equity Line of Credit".to_some())
+            .add_verifier(get_default_verifier_detail())
+            .build()
+            .expect("the heloc asset definition should be built");
         let error = replace_asset_definition_v3(deps.as_mut().storage, &def).unwrap_err();
         match error {
             ContractError::RecordNotFound { explanation } => {
@@ -362,7 +1841,12 @@ mod tests {
     #[test]
     fn test_may_load_asset_definition_by_type() {
         let mut deps = mock_provenance_dependencies();
-        let heloc = AssetDefinitionV3::new("heloc", "Home Equity Line of Credit".to_some(), vec![]);
+        let heloc = AssetDefinitionV3::builder()
+            .asset_type("heloc")
+            .display_name("Home Equity Line of Credit".to_some())
+            .add_verifier(get_default_verifier_detail())
+            .build()
+            .expect("the heloc asset definition should be built");
         insert_asset_definition_v3(deps.as_mut().storage, &heloc)
             .expect("the heloc definition should insert without error");
         assert!(
@@ -383,8 +1867,18 @@ mod tests {
     #[test]
     fn test_load_asset_definition_by_type() {
         let mut deps = mock_provenance_dependencies();
-        let heloc = AssetDefinitionV3::new("heloc", "Home Equity Line of Credit".to_some(), vec![]);
-        let mortgage = AssetDefinitionV3::new("mortgage", "DEATH PLEDGE".to_some(), vec![]);
+        let heloc = AssetDefinitionV3::builder()
+            .asset_type("heloc")
+            .display_name("Home Equity Line of Credit".to_some())
+            .add_verifier(get_default_verifier_detail())
+            .build()
+            .expect("the heloc asset definition should be built");
+        let mortgage = AssetDefinitionV3::builder()
+            .asset_type("mortgage")
+            .display_name("DEATH PLEDGE".to_some())
+            .add_verifier(get_default_verifier_detail())
+            .build()
+            .expect("the mortgage asset definition should be built");
         insert_asset_definition_v3(deps.as_mut().storage, &heloc)
             .expect("the heloc definition should insert appropriately");
         insert_asset_definition_v3(deps.as_mut().storage, &mortgage)
@@ -405,10 +1899,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_rekey_asset_definition_moves_secondary_indexes() {
+        let mut deps = mock_provenance_dependencies();
+        let legacy_heloc = AssetDefinitionV3::builder()
+            .asset_type("HELOC")
+            .add_verifier(get_default_verifier_detail())
+            .scope_spec_address(DEFAULT_SCOPE_SPEC_ADDRESS.to_some())
+            .add_label("consumer")
+            .build()
+            .expect("the legacy heloc asset definition should be built");
+        // Simulate a definition and its indexes stored under a mixed-case key by an older version
+        let storage = deps.as_mut().storage;
+        ASSET_DEFINITIONS_V3
+            .save(storage, "HELOC".to_string(), &legacy_heloc)
+            .expect("the legacy definition should be saved");
+        SCOPE_SPEC_TO_ASSET_TYPE
+            .save(
+                storage,
+                DEFAULT_SCOPE_SPEC_ADDRESS.to_string(),
+                &"HELOC".to_string(),
+            )
+            .expect("the legacy scope spec binding should be saved");
+        ASSET_DEFINITION_LABEL_INDEX
+            .save(
+                storage,
+                ("consumer".to_string(), "HELOC".to_string()),
+                &"HELOC".to_string(),
+            )
+            .expect("the legacy label index entry should be saved");
+        let heloc = AssetDefinitionV3 {
+            asset_type: "heloc".to_string(),
+            ..legacy_heloc
+        };
+        rekey_asset_definition_v3(deps.as_mut().storage, "HELOC", &heloc)
+            .expect("the definition should be rekeyed without error");
+        assert_eq!(
+            "heloc",
+            may_load_asset_type_by_scope_spec_address(
+                deps.as_ref().storage,
+                DEFAULT_SCOPE_SPEC_ADDRESS,
+            )
+            .expect("the scope spec binding should load without error")
+            .expect("the scope spec binding should still exist"),
+            "the scope spec binding should reference the rekeyed asset type",
+        );
+        assert_eq!(
+            vec![(
+                ("consumer".to_string(), "heloc".to_string()),
+                "heloc".to_string()
+            )],
+            ASSET_DEFINITION_LABEL_INDEX
+                .range(deps.as_ref().storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()
+                .expect("the label index should be readable"),
+            "the label index should only contain an entry for the rekeyed definition",
+        );
+    }
+
     #[test]
     fn test_delete_asset_definition_by_type() {
         let mut deps = mock_provenance_dependencies();
-        let def = AssetDefinitionV3::new("heloc", "Home Equity Line of Credit".to_some(), vec![]);
+        let def = AssetDefinitionV3::builder()
+            .asset_type("heloc")
+            .display_name("Home Equity Line of Credit".to_some())
+            .add_verifier(get_default_verifier_detail())
+            .build()
+            .expect("the heloc asset definition should be built");
         insert_asset_definition_v3(deps.as_mut().storage, &def)
             .expect("expected the asset definition to be stored without error");
         assert_eq!(
@@ -464,7 +2021,7 @@ mod tests {
             "an error should occur when trying to load a payment detail that does not exist",
         );
         assert!(
-            matches!(err, ContractError::Std(StdError::NotFound { .. })),
+            err.is_not_found(),
             "a not found error should occur when the payment detail is not found, but got: {:?}",
             err,
         );
@@ -528,7 +2085,7 @@ mod tests {
             "an error should occur when attempting to delete a fee payment detail that does not exist"
         );
         assert!(
-            matches!(err, ContractError::Std(StdError::NotFound { .. })),
+            err.is_not_found(),
             "a not found error should occur when the payment detail is not found, but got: {:?}",
             err,
         );
@@ -559,12 +2116,379 @@ mod tests {
             "an error should occur when trying to load a payment detail after it has been deleted",
         );
         assert!(
-            matches!(err, ContractError::Std(StdError::NotFound { .. })),
+            err.is_not_found(),
             "a not found error should occur when the payment detail is loaded after deletion, but got: {:?}",
             err,
         );
     }
 
+    #[test]
+    fn test_update_and_count_verifier_status_index() {
+        let mut deps = mock_provenance_dependencies();
+        let pending_count = |deps: &MockOwnedDeps, asset_type: Option<&str>| {
+            count_assets_by_verifier_and_status(
+                deps.as_ref().storage,
+                DEFAULT_VERIFIER_ADDRESS,
+                &AssetOnboardingStatus::Pending,
+                asset_type,
+            )
+            .expect("counting assets should succeed")
+        };
+        assert_eq!(
+            0,
+            pending_count(&deps, None),
+            "no assets should be counted before any are indexed",
+        );
+        let first_attribute = get_default_asset_scope_attribute();
+        let second_attribute = AssetScopeAttribute {
+            asset_type: DEFAULT_SECONDARY_ASSET_TYPE.to_string(),
+            ..get_default_asset_scope_attribute()
+        };
+        update_verifier_status_index(deps.as_mut().storage, None, &first_attribute)
+            .expect("indexing the first attribute should succeed");
+        update_verifier_status_index(deps.as_mut().storage, None, &second_attribute)
+            .expect("indexing the second attribute should succeed");
+        assert_eq!(
+            2,
+            pending_count(&deps, None),
+            "both pending assets should be counted when no asset type filter is provided",
+        );
+        assert_eq!(
+            1,
+            pending_count(&deps, Some(DEFAULT_ASSET_TYPE)),
+            "only the matching asset should be counted when an asset type filter is provided",
+        );
+        let approved_attribute = AssetScopeAttribute {
+            onboarding_status: AssetOnboardingStatus::Approved,
+            ..first_attribute.clone()
+        };
+        update_verifier_status_index(
+            deps.as_mut().storage,
+            Some(&first_attribute),
+            &approved_attribute,
+        )
+        .expect("moving the first attribute to approved should succeed");
+        assert_eq!(
+            1,
+            pending_count(&deps, None),
+            "the approved asset should no longer be counted as pending",
+        );
+        assert_eq!(
+            1,
+            count_assets_by_verifier_and_status(
+                deps.as_ref().storage,
+                DEFAULT_VERIFIER_ADDRESS,
+                &AssetOnboardingStatus::Approved,
+                None,
+            )
+            .expect("counting approved assets should succeed"),
+            "the approved asset should be counted under its new status",
+        );
+        assert_eq!(
+            0,
+            count_assets_by_verifier_and_status(
+                deps.as_ref().storage,
+                DEFAULT_SCOPE_ADDRESS,
+                &AssetOnboardingStatus::Pending,
+                None,
+            )
+            .expect("counting assets for an unknown verifier should succeed"),
+            "no assets should be counted for an address that is not a verifier",
+        );
+    }
+
+    #[test]
+    fn test_requestor_index_pages_scopes_by_requestor() {
+        let mut deps = mock_provenance_dependencies();
+        let attribute_for =
+            |requestor: &str, scope_address: &str, asset_type: &str| AssetScopeAttribute {
+                requestor_address: Addr::unchecked(requestor),
+                scope_address: scope_address.to_string(),
+                asset_type: asset_type.to_string(),
+                ..get_default_asset_scope_attribute()
+            };
+        for attribute in [
+            attribute_for("requestor_a", "scope_1", DEFAULT_ASSET_TYPE),
+            attribute_for("requestor_a", "scope_2", DEFAULT_ASSET_TYPE),
+            attribute_for("requestor_a", "scope_2", DEFAULT_SECONDARY_ASSET_TYPE),
+            attribute_for("requestor_a", "scope_3", DEFAULT_SECONDARY_ASSET_TYPE),
+            attribute_for("requestor_b", "scope_4", DEFAULT_ASSET_TYPE),
+        ] {
+            update_requestor_index(deps.as_mut().storage, None, &attribute)
+                .expect("indexing the attribute should succeed");
+        }
+        let page = |deps: &MockOwnedDeps,
+                    requestor: &str,
+                    asset_type: Option<&str>,
+                    start_after: Option<&str>,
+                    limit: u32| {
+            list_requestor_index_page(
+                deps.as_ref().storage,
+                requestor,
+                asset_type,
+                start_after.map(|value| value.to_string()),
+                limit,
+            )
+            .expect("listing the requestor index should succeed")
+        };
+        let scopes = |entries: Vec<RequestorIndexEntry>| {
+            entries
+                .into_iter()
+                .map(|(scope_address, _)| scope_address)
+                .collect::<Vec<String>>()
+        };
+        let (first_page, has_next_page) = page(&deps, "requestor_a", None, None, 2);
+        assert_eq!(
+            vec![
+                ("scope_1".to_string(), vec![DEFAULT_ASSET_TYPE.to_string()]),
+                (
+                    "scope_2".to_string(),
+                    vec![
+                        DEFAULT_ASSET_TYPE.to_string(),
+                        DEFAULT_SECONDARY_ASSET_TYPE.to_string()
+                    ]
+                ),
+            ],
+            first_page,
+            "the first page should contain the first two scopes and all of their asset types",
+        );
+        assert!(has_next_page, "another page should exist after the first");
+        let (second_page, has_next_page) = page(&deps, "requestor_a", None, Some("scope_2"), 2);
+        assert_eq!(
+            vec!["scope_3".to_string()],
+            scopes(second_page),
+            "the second page should start after the cursor",
+        );
+        assert!(!has_next_page, "no page should exist after the final scope");
+        let (filtered_page, has_next_page) =
+            page(&deps, "requestor_a", Some(DEFAULT_ASSET_TYPE), None, 2);
+        assert_eq!(
+            vec!["scope_1".to_string(), "scope_2".to_string()],
+            scopes(filtered_page),
+            "only scopes onboarded as the filtered asset type should be returned",
+        );
+        assert!(
+            !has_next_page,
+            "scopes excluded by the filter should not signal another page",
+        );
+        assert_eq!(
+            vec!["scope_4".to_string()],
+            scopes(page(&deps, "requestor_b", None, None, 10).0),
+            "only scopes onboarded by the requested requestor should be returned",
+        );
+        assert!(
+            page(&deps, "requestor_c", None, None, 10).0.is_empty(),
+            "no scopes should be returned for an unknown requestor",
+        );
+    }
+
+    #[test]
+    fn test_access_route_owner_index_tracks_access_definition_changes() {
+        let mut deps = mock_provenance_dependencies();
+        let definition_for = |owner: &str, routes: Vec<AccessRoute>| AccessDefinition {
+            owner_address: owner.to_string(),
+            access_routes: routes,
+            definition_type: AccessDefinitionType::Requestor,
+        };
+        let original_attribute = AssetScopeAttribute {
+            access_definitions: vec![
+                definition_for("owner_a", vec![AccessRoute::route_only("route")]),
+                definition_for("owner_b", vec![]),
+            ],
+            ..get_default_asset_scope_attribute()
+        };
+        update_access_route_owner_index(deps.as_mut().storage, None, &original_attribute)
+            .expect("indexing the original attribute should succeed");
+        let page = |deps: &MockOwnedDeps, owner: &str| {
+            list_access_route_owner_index_page(deps.as_ref().storage, owner, None, 10)
+                .expect("listing the access route owner index should succeed")
+        };
+        assert_eq!(
+            vec![(
+                DEFAULT_SCOPE_ADDRESS.to_string(),
+                vec![DEFAULT_ASSET_TYPE.to_string()]
+            )],
+            page(&deps, "owner_a"),
+            "the owner of the access routes should be indexed",
+        );
+        assert!(
+            page(&deps, "owner_b").is_empty(),
+            "an owner with no access routes should not be indexed",
+        );
+        let updated_attribute = AssetScopeAttribute {
+            access_definitions: vec![
+                definition_for("owner_a", vec![]),
+                definition_for("owner_b", vec![AccessRoute::route_only("route")]),
+            ],
+            ..original_attribute.clone()
+        };
+        update_access_route_owner_index(
+            deps.as_mut().storage,
+            Some(&original_attribute),
+            &updated_attribute,
+        )
+        .expect("indexing the updated attribute should succeed");
+        assert!(
+            page(&deps, "owner_a").is_empty(),
+            "an owner whose access routes were removed should no longer be indexed",
+        );
+        assert_eq!(
+            vec![(
+                DEFAULT_SCOPE_ADDRESS.to_string(),
+                vec![DEFAULT_ASSET_TYPE.to_string()]
+            )],
+            page(&deps, "owner_b"),
+            "an owner whose access routes were added should be indexed",
+        );
+    }
+
+    #[test]
+    fn test_pending_verifier_status_index_pages_scopes_by_verifier() {
+        let mut deps = mock_provenance_dependencies();
+        let attribute_for = |verifier: &str,
+                             scope_address: &str,
+                             asset_type: &str,
+                             onboarding_status: AssetOnboardingStatus| {
+            AssetScopeAttribute {
+                verifier_address: Addr::unchecked(verifier),
+                scope_address: scope_address.to_string(),
+                asset_type: asset_type.to_string(),
+                onboarding_status,
+                ..get_default_asset_scope_attribute()
+            }
+        };
+        for attribute in [
+            attribute_for(
+                "verifier_a",
+                "scope_1",
+                DEFAULT_ASSET_TYPE,
+                AssetOnboardingStatus::Pending,
+            ),
+            attribute_for(
+                "verifier_a",
+                "scope_2",
+                DEFAULT_ASSET_TYPE,
+                AssetOnboardingStatus::Pending,
+            ),
+            attribute_for(
+                "verifier_a",
+                "scope_2",
+                DEFAULT_SECONDARY_ASSET_TYPE,
+                AssetOnboardingStatus::Pending,
+            ),
+            attribute_for(
+                "verifier_a",
+                "scope_3",
+                DEFAULT_SECONDARY_ASSET_TYPE,
+                AssetOnboardingStatus::Pending,
+            ),
+            attribute_for(
+                "verifier_a",
+                "scope_4",
+                DEFAULT_ASSET_TYPE,
+                AssetOnboardingStatus::Approved,
+            ),
+            attribute_for(
+                "verifier_b",
+                "scope_5",
+                DEFAULT_ASSET_TYPE,
+                AssetOnboardingStatus::Pending,
+            ),
+        ] {
+            update_verifier_status_index(deps.as_mut().storage, None, &attribute)
+                .expect("indexing the attribute should succeed");
+        }
+        let page = |deps: &MockOwnedDeps,
+                    verifier: &str,
+                    asset_type: Option<&str>,
+                    start_after: Option<&str>,
+                    limit: u32| {
+            list_pending_verifier_status_index_page(
+                deps.as_ref().storage,
+                verifier,
+                asset_type,
+                start_after.map(|value| value.to_string()),
+                limit,
+            )
+            .expect("listing the pending verifier status index should succeed")
+        };
+        let entry = |scope_address: &str, asset_type: &str| {
+            (scope_address.to_string(), asset_type.to_string())
+        };
+        assert_eq!(
+            vec![
+                entry("scope_1", DEFAULT_ASSET_TYPE),
+                entry("scope_2", DEFAULT_ASSET_TYPE),
+                entry("scope_2", DEFAULT_SECONDARY_ASSET_TYPE),
+            ],
+            page(&deps, "verifier_a", None, None, 2),
+            "the first page should contain the first two scopes and all of their asset types",
+        );
+        assert_eq!(
+            vec![entry("scope_3", DEFAULT_SECONDARY_ASSET_TYPE)],
+            page(&deps, "verifier_a", None, Some("scope_2"), 2),
+            "the second page should start after every entry for the cursor's scope",
+        );
+        assert_eq!(
+            vec![
+                entry("scope_1", DEFAULT_ASSET_TYPE),
+                entry("scope_2", DEFAULT_ASSET_TYPE),
+            ],
+            page(&deps, "verifier_a", Some(DEFAULT_ASSET_TYPE), None, 10),
+            "only pending scopes of the filtered asset type should be returned",
+        );
+        assert_eq!(
+            vec![entry("scope_5", DEFAULT_ASSET_TYPE)],
+            page(&deps, "verifier_b", None, None, 10),
+            "only scopes pending for the requested verifier should be returned",
+        );
+        assert!(
+            page(&deps, "verifier_c", None, None, 10).is_empty(),
+            "no scopes should be returned for an unknown verifier",
+        );
+    }
+
+    #[test]
+    fn test_update_requestor_index_moves_asset_type_to_new_requestor() {
+        let mut deps = mock_provenance_dependencies();
+        let original_attribute = get_default_asset_scope_attribute();
+        update_requestor_index(deps.as_mut().storage, None, &original_attribute)
+            .expect("indexing the original attribute should succeed");
+        let retried_attribute = AssetScopeAttribute {
+            requestor_address: Addr::unchecked("new_requestor"),
+            ..original_attribute.clone()
+        };
+        update_requestor_index(
+            deps.as_mut().storage,
+            Some(&original_attribute),
+            &retried_attribute,
+        )
+        .expect("moving the attribute to a new requestor should succeed");
+        assert!(
+            list_requestor_index_page(
+                deps.as_ref().storage,
+                original_attribute.requestor_address.as_str(),
+                None,
+                None,
+                10,
+            )
+            .expect("listing the original requestor's scopes should succeed")
+            .0
+            .is_empty(),
+            "the original requestor should no longer have the scope indexed",
+        );
+        assert_eq!(
+            vec![(
+                original_attribute.scope_address.to_owned(),
+                vec![original_attribute.asset_type.to_owned()],
+            )],
+            list_requestor_index_page(deps.as_ref().storage, "new_requestor", None, None, 10)
+                .expect("listing the new requestor's scopes should succeed")
+                .0,
+            "the new requestor should have the scope indexed",
+        );
+    }
+
     #[test]
     fn test_basic_state_key_is_expected_value() {
         assert_eq!(
@@ -574,4 +2498,34 @@ mod tests {
                 .to_lowercase()
         );
     }
+
+    #[test]
+    fn test_to_safe_display() {
+        let state = StateV2 {
+            base_contract_name: "asset".to_string(),
+            admin: Addr::unchecked("admin-address"),
+            is_test: true,
+            asset_definition_count: 3,
+            debug_query_enabled: false,
+            verification_timeout_seconds: 600.to_some(),
+            metrics: ContractMetrics {
+                total_onboards: 5,
+                total_verifications: 4,
+                total_fees_collected_nhash: Uint128::new(1000),
+                total_onboarded_ever: 4,
+                total_verified_ever: 3,
+            },
+            pending_admin: None,
+            fee_detail_max_age_blocks: 100.to_some(),
+            max_access_routes_per_owner: 5,
+            allow_all_bech32_prefixes: false,
+            require_entity_detail_for_verifiers: false,
+            os_gateway_grant_prefix: "asset".to_string().to_some(),
+        };
+        assert_eq!(
+            "StateV2 { base_contract_name: asset, admin: admin-address, is_test: true, asset_definition_count: 3, debug_query_enabled: false, verification_timeout_seconds: Some(600), total_onboards: 5, total_verifications: 4, total_fees_collected_nhash: 1000, pending_admin: None, fee_detail_max_age_blocks: Some(100), max_access_routes_per_owner: 5, allow_all_bech32_prefixes: false, require_entity_detail_for_verifiers: false, os_gateway_grant_prefix: Some(\"asset\") }",
+            state.to_safe_display(),
+            "the state should be displayed in the expected format",
+        );
+    }
 }
@@ -0,0 +1,52 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    access_definition::{AccessDefinition, AccessDefinitionType},
+    access_route::AccessRoute,
+};
+
+/// An [AccessDefinition](super::access_definition::AccessDefinition) that is tagged with the asset
+/// type of the [AssetScopeAttribute](super::asset_scope_attribute::AssetScopeAttribute) in which it
+/// was found.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AccessDefinitionWithType {
+    /// The asset type of the scope attribute that contains this access definition.
+    pub asset_type: String,
+    /// The bech32 address of the account that created the underlying [AccessRoutes](super::access_route::AccessRoute).
+    pub owner_address: String,
+    /// A collection of [AccessRoute](super::access_route::AccessRoute) structs that define methods of
+    /// obtaining the underlying data for a scope.
+    pub access_routes: Vec<AccessRoute>,
+    /// Defines the source that created this definition.
+    pub definition_type: AccessDefinitionType,
+}
+impl AccessDefinitionWithType {
+    /// Constructs a new instance of this struct by tagging an existing access definition with an
+    /// asset type.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_type` The asset type of the scope attribute that contains the access definition.
+    /// * `definition` The access definition to tag.
+    pub fn new<S: Into<String>>(asset_type: S, definition: AccessDefinition) -> Self {
+        Self {
+            asset_type: asset_type.into(),
+            owner_address: definition.owner_address,
+            access_routes: definition.access_routes,
+            definition_type: definition.definition_type,
+        }
+    }
+}
+
+/// A directory of every [AccessDefinition](super::access_definition::AccessDefinition) attached to
+/// a scope, across all of its asset types.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AccessDefinitionsResponse {
+    /// The bech32 address of the Provenance Blockchain Metadata Scope that was queried.
+    pub scope_address: String,
+    /// Each access definition found on the scope, tagged with its asset type.
+    pub definitions: Vec<AccessDefinitionWithType>,
+}
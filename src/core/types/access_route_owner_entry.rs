@@ -0,0 +1,39 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::access_route::AccessRoute;
+
+/// A single entry in the response to a [QueryAccessRoutesByOwner](crate::core::msg::QueryMsg::QueryAccessRoutesByOwner)
+/// query, containing the access routes an address owns on a scope for a single asset type.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AccessRouteOwnerEntry {
+    /// The bech32 address of the scope on which the access routes are held.
+    pub scope_address: String,
+    /// The asset type of the [AssetScopeAttribute](super::asset_scope_attribute::AssetScopeAttribute)
+    /// that contains the access routes.
+    pub asset_type: String,
+    /// Every [AccessRoute](super::access_route::AccessRoute) owned by the address for the scope
+    /// and asset type.
+    pub access_routes: Vec<AccessRoute>,
+}
+impl AccessRouteOwnerEntry {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `scope_address` The bech32 address of the scope on which the access routes are held.
+    /// * `asset_type` The asset type of the attribute that contains the access routes.
+    /// * `access_routes` Every access route owned by the address for the scope and asset type.
+    pub fn new<S1: Into<String>, S2: Into<String>>(
+        scope_address: S1,
+        asset_type: S2,
+        access_routes: Vec<AccessRoute>,
+    ) -> Self {
+        Self {
+            scope_address: scope_address.into(),
+            asset_type: asset_type.into(),
+            access_routes,
+        }
+    }
+}
@@ -6,7 +6,9 @@ use serde::{Deserialize, Serialize};
 use crate::core::error::ContractError;
 use crate::core::state::{StateV2, STATE_V2};
 use crate::core::types::verifier_detail::VerifierDetailV2;
+use crate::util::traits::SafeDisplay;
 use crate::util::{aliases::AssetResult, functions::generate_asset_attribute_name};
+use crate::validation::validate_init_msg::validate_asset_definition;
 
 /// Defines a specific asset type associated with the contract.  Allows its specified type to be
 /// onboarded and verified.
@@ -22,6 +24,45 @@ pub struct AssetDefinitionV3 {
     /// Indicates whether or not the asset definition is enabled for use in the contract.  If disabled,
     /// requests to onboard assets of this type will be rejected.
     pub enabled: bool,
+    /// The bech32 address with a prefix of "scopespec" of the Provenance Blockchain Metadata Scope
+    /// Specification that this asset type manages, if any.  A scope specification can only be bound
+    /// to a single asset type.  When set, only scopes created from this specification can be
+    /// onboarded as the asset type.
+    #[serde(default)]
+    pub scope_spec_address: Option<String>,
+    /// Free-form labels used to group related asset types.  Each label may only appear once per
+    /// definition, and definitions can be listed by label with the
+    /// [QueryAssetDefinitionsByLabel](crate::core::msg::QueryMsg::QueryAssetDefinitionsByLabel) query.
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// The bech32 addresses of the only requestors permitted to onboard assets of this type.  When
+    /// omitted or empty, any scope owner may onboard assets of this type.
+    #[serde(default)]
+    pub allowed_requestors: Option<Vec<String>>,
+    /// The maximum number of verifiers that may be added to this definition.  When omitted, any
+    /// number of verifiers may be added.
+    #[serde(default)]
+    pub max_verifiers: Option<u32>,
+    /// Indicates whether or not the asset definition has been permanently locked against further
+    /// modification via the [FreezeAssetDefinition](crate::core::msg::ExecuteMsg::FreezeAssetDefinition)
+    /// route.  A frozen definition can still be used to onboard assets while it is enabled.
+    #[serde(default)]
+    pub frozen: bool,
+    /// Indicates whether or not the asset type has been superseded via the [DeprecateAssetDefinition](crate::core::msg::ExecuteMsg::DeprecateAssetDefinition)
+    /// route.  Deprecated definitions can still be used to onboard assets for backwards
+    /// compatibility, but each onboarding emits a [DeprecatedTypeUsed](crate::util::event_attributes::EventType::DeprecatedTypeUsed)
+    /// event.
+    #[serde(default)]
+    pub deprecated: bool,
+    /// The asset type that requestors should migrate to from this deprecated asset type, if any.
+    #[serde(default)]
+    pub replacement_asset_type: Option<String>,
+    /// Indicates whether or not assets of this type may only be onboarded by their scope's primary
+    /// owner.  When `true`, the requestor must be the first owner listed on the scope, and any
+    /// value owner set on the scope must also be the requestor, which prevents proxy onboarding
+    /// by secondary owners.
+    #[serde(default)]
+    pub scope_owner_must_match_requestor: bool,
 }
 impl AssetDefinitionV3 {
     /// Constructs a new instance of AssetDefinitionV3, setting enabled to `true` by default.
@@ -40,9 +81,24 @@ impl AssetDefinitionV3 {
             display_name: display_name.map(|n| n.into()),
             verifiers,
             enabled: true,
+            scope_spec_address: None,
+            labels: vec![],
+            allowed_requestors: None,
+            max_verifiers: None,
+            frozen: false,
+            deprecated: false,
+            replacement_asset_type: None,
+            scope_owner_must_match_requestor: false,
         }
     }
 
+    /// Creates an empty [AssetDefinitionV3Builder](self::AssetDefinitionV3Builder), allowing a
+    /// definition to be constructed one field at a time.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn builder() -> AssetDefinitionV3Builder {
+        AssetDefinitionV3Builder::default()
+    }
+
     /// Converts the asset_type value to lowercase and serializes it as bytes,
     /// then uplifts the value to a vector to allow it to be returned.
     pub fn storage_key(&self) -> String {
@@ -82,11 +138,7 @@ impl AssetDefinitionV3 {
         verifier_address: S,
     ) -> Result<VerifierDetailV2, ContractError> {
         let verifier_address = verifier_address.into();
-        match self
-            .verifiers
-            .iter()
-            .find(|verifier| verifier.address == verifier_address)
-        {
+        match self.get_verifier_detail_opt(&verifier_address) {
             Some(verifier) => verifier.to_owned().to_ok(),
             None => ContractError::UnsupportedVerifier {
                 asset_type: self.asset_type.to_owned(),
@@ -95,6 +147,221 @@ impl AssetDefinitionV3 {
             .to_err(),
         }
     }
+
+    /// Helper functionality to retrieve a reference to a verifier detail from the self-contained
+    /// vector of verifiers by matching on the given address.  Returns a None variant if no
+    /// verifier matches the address.
+    ///
+    /// # Parameters
+    ///
+    /// * `verifier_address` The bech32 address of the verifier to locate within the verifiers
+    /// vector.
+    pub fn get_verifier_detail_opt(&self, verifier_address: &str) -> Option<&VerifierDetailV2> {
+        self.verifiers
+            .iter()
+            .find(|verifier| verifier.address == verifier_address)
+    }
+
+    /// Helper functionality to retrieve a reference to the first verifier detail in the
+    /// self-contained vector of verifiers.  This is a convenience for definitions that only
+    /// contain a single verifier.  Returns a None variant if the definition has no verifiers.
+    pub fn get_default_verifier(&self) -> Option<&VerifierDetailV2> {
+        self.verifiers.first()
+    }
+
+    /// Helper functionality to retrieve a human-readable name for this asset type.  Returns the
+    /// [display_name](self::AssetDefinitionV3::display_name) when it is set to a non-empty value,
+    /// and otherwise falls back to the [asset_type](self::AssetDefinitionV3::asset_type).
+    pub fn display_name_or_type(&self) -> &str {
+        match self.display_name.as_deref() {
+            Some(display_name) if !display_name.is_empty() => display_name,
+            _ => &self.asset_type,
+        }
+    }
+
+    /// Verifies that the definition may hold the given number of verifiers without exceeding its
+    /// [max_verifiers](self::AssetDefinitionV3::max_verifiers), returning a [MaxVerifiersExceeded](crate::core::error::ContractError::MaxVerifiersExceeded)
+    /// error if it cannot.  Always succeeds when no maximum is set.
+    ///
+    /// # Parameters
+    ///
+    /// * `verifier_count` The total number of verifiers that the definition would hold.
+    pub fn check_verifier_count(&self, verifier_count: usize) -> AssetResult<()> {
+        match self.max_verifiers {
+            Some(limit) if verifier_count > limit as usize => ContractError::MaxVerifiersExceeded {
+                asset_type: self.asset_type.clone(),
+                limit,
+            }
+            .to_err(),
+            _ => ().to_ok(),
+        }
+    }
+
+    /// Verifies that the definition has not been [frozen](self::AssetDefinitionV3::frozen),
+    /// returning an [AssetDefinitionFrozen](crate::core::error::ContractError::AssetDefinitionFrozen)
+    /// error if it has.
+    pub fn check_not_frozen(&self) -> AssetResult<()> {
+        if self.frozen {
+            ContractError::AssetDefinitionFrozen {
+                asset_type: self.asset_type.clone(),
+            }
+            .to_err()
+        } else {
+            ().to_ok()
+        }
+    }
+
+    /// Validates that this asset definition is properly formed, ensuring that all fields are
+    /// properly set and that each contained verifier is valid.  Delegates to
+    /// [validate_asset_definition](crate::validation::validate_init_msg::validate_asset_definition).
+    ///
+    /// # Parameters
+    ///
+    /// * `allow_all_bech32_prefixes` Whether or not verifier and fee destination addresses may use
+    /// prefixes other than the [VALID_VERIFIER_ADDRESS_PREFIXES](crate::util::constants::VALID_VERIFIER_ADDRESS_PREFIXES).
+    pub fn validate(&self, allow_all_bech32_prefixes: bool) -> AssetResult<()> {
+        validate_asset_definition(self, allow_all_bech32_prefixes, false)
+    }
+}
+impl SafeDisplay for AssetDefinitionV3 {
+    fn safe_display(&self) -> String {
+        format!(
+            "AssetDefinitionV3 {{ asset_type: {}, display_name: {:?}, enabled: {}, scope_spec_address: {:?}, labels: {:?}, allowed_requestors: {:?}, max_verifiers: {:?}, frozen: {}, deprecated: {}, replacement_asset_type: {:?}, scope_owner_must_match_requestor: {}, verifiers: [{}] }}",
+            self.asset_type,
+            self.display_name,
+            self.enabled,
+            self.scope_spec_address,
+            self.labels,
+            self.allowed_requestors,
+            self.max_verifiers,
+            self.frozen,
+            self.deprecated,
+            self.replacement_asset_type,
+            self.scope_owner_must_match_requestor,
+            self.verifiers
+                .iter()
+                .map(|verifier| verifier.safe_display())
+                .collect::<Vec<String>>()
+                .join(", "),
+        )
+    }
+}
+
+/// A fluent builder for [AssetDefinitionV3](self::AssetDefinitionV3) values, created via
+/// [AssetDefinitionV3::builder](self::AssetDefinitionV3::builder).  Unset values fall back to the
+/// same defaults as [AssetDefinitionV3::new](self::AssetDefinitionV3::new).
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, Debug, Default)]
+pub struct AssetDefinitionV3Builder {
+    asset_type: String,
+    display_name: Option<String>,
+    verifiers: Vec<VerifierDetailV2>,
+    enabled: Option<bool>,
+    scope_spec_address: Option<String>,
+    labels: Vec<String>,
+    allowed_requestors: Option<Vec<String>>,
+    max_verifiers: Option<u32>,
+    frozen: bool,
+    scope_owner_must_match_requestor: bool,
+}
+#[cfg(not(target_arch = "wasm32"))]
+impl AssetDefinitionV3Builder {
+    /// Sets the unique name of the asset associated with the definition.
+    pub fn asset_type<S: Into<String>>(mut self, asset_type: S) -> Self {
+        self.asset_type = asset_type.into();
+        self
+    }
+
+    /// Sets the human-readable name for the asset type.
+    pub fn display_name<S: Into<String>>(mut self, display_name: Option<S>) -> Self {
+        self.display_name = display_name.map(|n| n.into());
+        self
+    }
+
+    /// Appends a verifier to the definition's verifiers, retaining any previously-added values.
+    pub fn add_verifier(mut self, verifier: VerifierDetailV2) -> Self {
+        self.verifiers.push(verifier);
+        self
+    }
+
+    /// Sets whether or not the definition is enabled.  Defaults to `true` when not set.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Sets the bech32 scope specification address bound to the asset type.
+    pub fn scope_spec_address<S: Into<String>>(mut self, scope_spec_address: Option<S>) -> Self {
+        self.scope_spec_address = scope_spec_address.map(|a| a.into());
+        self
+    }
+
+    /// Appends a label to the definition's labels, retaining any previously-added values.
+    pub fn add_label<S: Into<String>>(mut self, label: S) -> Self {
+        self.labels.push(label.into());
+        self
+    }
+
+    /// Sets the bech32 addresses of the only requestors permitted to onboard the asset type.
+    pub fn allowed_requestors(mut self, allowed_requestors: Option<Vec<String>>) -> Self {
+        self.allowed_requestors = allowed_requestors;
+        self
+    }
+
+    /// Sets the maximum number of verifiers that may be added to the definition.
+    pub fn max_verifiers(mut self, max_verifiers: Option<u32>) -> Self {
+        self.max_verifiers = max_verifiers;
+        self
+    }
+
+    /// Sets whether or not the definition is frozen.  Defaults to `false` when not set.
+    pub fn frozen(mut self, frozen: bool) -> Self {
+        self.frozen = frozen;
+        self
+    }
+
+    /// Sets whether or not only a scope's primary owner may onboard the asset type.  Defaults to
+    /// `false` when not set.
+    pub fn scope_owner_must_match_requestor(mut self, required: bool) -> Self {
+        self.scope_owner_must_match_requestor = required;
+        self
+    }
+
+    /// Produces the [AssetDefinitionV3](self::AssetDefinitionV3), rejecting a blank asset type or
+    /// an empty verifiers vector with an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields)
+    /// error.  No other validation is performed; use [AssetDefinitionV3::validate](self::AssetDefinitionV3::validate)
+    /// for a full check.
+    pub fn build(self) -> AssetResult<AssetDefinitionV3> {
+        let mut invalid_fields: Vec<String> = vec![];
+        if self.asset_type.trim().is_empty() {
+            invalid_fields.push("asset_type: must not be blank".to_string());
+        }
+        if self.verifiers.is_empty() {
+            invalid_fields.push("verifiers: at least one verifier must be added".to_string());
+        }
+        if !invalid_fields.is_empty() {
+            return ContractError::InvalidMessageFields {
+                message_type: "AssetDefinitionV3Builder".to_string(),
+                invalid_fields,
+            }
+            .to_err();
+        }
+        AssetDefinitionV3 {
+            asset_type: self.asset_type,
+            display_name: self.display_name,
+            verifiers: self.verifiers,
+            enabled: self.enabled.unwrap_or(true),
+            scope_spec_address: self.scope_spec_address,
+            labels: self.labels,
+            allowed_requestors: self.allowed_requestors,
+            max_verifiers: self.max_verifiers,
+            frozen: self.frozen,
+            deprecated: false,
+            replacement_asset_type: None,
+            scope_owner_must_match_requestor: self.scope_owner_must_match_requestor,
+        }
+        .to_ok()
+    }
 }
 
 /// Allows the user to optionally specify the enabled flag on an asset definition, versus forcing
@@ -120,6 +387,29 @@ pub struct AssetDefinitionInputV3 {
     /// will be bound to the contract.  For example, if the base name is "pb" and the asset type is
     /// "myasset," the resulting bound name would be "myasset.pb".
     pub bind_name: Option<bool>,
+    /// The bech32 address with a prefix of "scopespec" of the Provenance Blockchain Metadata Scope
+    /// Specification that this asset type manages.  A scope specification can only be bound to a
+    /// single asset type, and only scopes created from it can be onboarded as the asset type.
+    #[serde(default)]
+    pub scope_spec_address: Option<String>,
+    /// Free-form labels used to group related asset types.  Each label may only appear once per
+    /// definition, or requests to add or update will be rejected.
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// The bech32 addresses of the only requestors permitted to onboard assets of this type.  Each
+    /// value must be a unique, valid bech32 address.  When omitted or empty, any scope owner may
+    /// onboard assets of this type.
+    #[serde(default)]
+    pub allowed_requestors: Option<Vec<String>>,
+    /// The maximum number of verifiers that may be added to the definition.  Must not be less than
+    /// the number of provided verifiers.  When omitted, any number of verifiers may be added.
+    #[serde(default)]
+    pub max_verifiers: Option<u32>,
+    /// Whether or not assets of this type may only be onboarded by their scope's primary owner.
+    /// When omitted from a new definition, proxy onboarding is allowed.  When omitted from an
+    /// update, the existing definition's value is retained.
+    #[serde(default)]
+    pub scope_owner_must_match_requestor: Option<bool>,
 }
 impl AssetDefinitionInputV3 {
     /// Constructs a new instance of this struct.
@@ -147,6 +437,11 @@ impl AssetDefinitionInputV3 {
             verifiers,
             enabled,
             bind_name,
+            scope_spec_address: None,
+            labels: vec![],
+            allowed_requestors: None,
+            max_verifiers: None,
+            scope_owner_must_match_requestor: None,
         }
     }
 
@@ -157,6 +452,61 @@ impl AssetDefinitionInputV3 {
             display_name: self.display_name,
             verifiers: self.verifiers,
             enabled: self.enabled.unwrap_or(true),
+            scope_spec_address: self.scope_spec_address,
+            labels: self.labels,
+            allowed_requestors: self.allowed_requestors,
+            max_verifiers: self.max_verifiers,
+            frozen: false,
+            deprecated: false,
+            replacement_asset_type: None,
+            scope_owner_must_match_requestor: self
+                .scope_owner_must_match_requestor
+                .unwrap_or(false),
+        }
+    }
+
+    /// Overlays the values contained within this struct onto an existing [AssetDefinitionV3](self::AssetDefinitionV3),
+    /// producing a new definition.  Only provided values overwrite existing values: an omitted
+    /// `display_name`, `enabled`, `scope_spec_address`, `allowed_requestors`, `max_verifiers` or
+    /// `scope_owner_must_match_requestor` retains the existing value, and an empty
+    /// `verifiers` or `labels` vector retains the existing verifiers or labels.
+    ///
+    /// # Parameters
+    ///
+    /// * `existing` The currently-stored asset definition onto which this input is overlaid.
+    pub fn merge_into(&self, existing: &AssetDefinitionV3) -> AssetDefinitionV3 {
+        AssetDefinitionV3 {
+            asset_type: existing.asset_type.clone(),
+            display_name: self
+                .display_name
+                .clone()
+                .or_else(|| existing.display_name.clone()),
+            verifiers: if self.verifiers.is_empty() {
+                existing.verifiers.clone()
+            } else {
+                self.verifiers.clone()
+            },
+            enabled: self.enabled.unwrap_or(existing.enabled),
+            scope_spec_address: self
+                .scope_spec_address
+                .clone()
+                .or_else(|| existing.scope_spec_address.clone()),
+            labels: if self.labels.is_empty() {
+                existing.labels.clone()
+            } else {
+                self.labels.clone()
+            },
+            allowed_requestors: self
+                .allowed_requestors
+                .clone()
+                .or_else(|| existing.allowed_requestors.clone()),
+            max_verifiers: self.max_verifiers.or(existing.max_verifiers),
+            frozen: existing.frozen,
+            deprecated: existing.deprecated,
+            replacement_asset_type: existing.replacement_asset_type.clone(),
+            scope_owner_must_match_requestor: self
+                .scope_owner_must_match_requestor
+                .unwrap_or(existing.scope_owner_must_match_requestor),
         }
     }
 
@@ -168,6 +518,327 @@ impl AssetDefinitionInputV3 {
             display_name: self.display_name.clone(),
             verifiers: self.verifiers.clone(),
             enabled: self.enabled.unwrap_or(true),
+            scope_spec_address: self.scope_spec_address.clone(),
+            labels: self.labels.clone(),
+            allowed_requestors: self.allowed_requestors.clone(),
+            max_verifiers: self.max_verifiers,
+            frozen: false,
+            deprecated: false,
+            replacement_asset_type: None,
+            scope_owner_must_match_requestor: self
+                .scope_owner_must_match_requestor
+                .unwrap_or(false),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::types::asset_definition::{AssetDefinitionInputV3, AssetDefinitionV3};
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_SPEC_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        get_default_asset_definition, get_default_verifier_detail,
+    };
+    use crate::util::traits::{OptionExtensions, SafeDisplay};
+
+    #[test]
+    fn test_get_verifier_detail_opt_found() {
+        let definition = get_default_asset_definition();
+        assert_eq!(
+            &get_default_verifier_detail(),
+            definition
+                .get_verifier_detail_opt(DEFAULT_VERIFIER_ADDRESS)
+                .expect("the default verifier should be found"),
+            "the matching verifier should be returned",
+        );
+    }
+
+    #[test]
+    fn test_get_verifier_detail_opt_not_found() {
+        let definition = get_default_asset_definition();
+        assert!(
+            definition
+                .get_verifier_detail_opt("unknown-address")
+                .is_none(),
+            "no verifier should be returned for an address that is not present",
+        );
+    }
+
+    #[test]
+    fn test_get_verifier_detail_opt_empty_verifiers() {
+        let definition = AssetDefinitionV3::new(DEFAULT_ASSET_TYPE, None::<String>, vec![]);
+        assert!(
+            definition
+                .get_verifier_detail_opt(DEFAULT_VERIFIER_ADDRESS)
+                .is_none(),
+            "no verifier should be returned when the definition has no verifiers",
+        );
+    }
+
+    #[test]
+    fn test_get_default_verifier_found() {
+        let mut second_verifier = get_default_verifier_detail();
+        second_verifier.address = "second-verifier".to_string();
+        let definition = AssetDefinitionV3::builder()
+            .asset_type(DEFAULT_ASSET_TYPE)
+            .add_verifier(get_default_verifier_detail())
+            .add_verifier(second_verifier)
+            .build()
+            .expect("the definition should be built");
+        assert_eq!(
+            &get_default_verifier_detail(),
+            definition
+                .get_default_verifier()
+                .expect("a default verifier should be found"),
+            "the first verifier should be returned as the default",
+        );
+    }
+
+    #[test]
+    fn test_get_default_verifier_empty_verifiers() {
+        let definition = AssetDefinitionV3::new(DEFAULT_ASSET_TYPE, None::<String>, vec![]);
+        assert!(
+            definition.get_default_verifier().is_none(),
+            "no default verifier should be returned when the definition has no verifiers",
+        );
+    }
+
+    #[test]
+    fn test_merge_into_with_no_provided_values_retains_existing_definition() {
+        let existing = get_default_asset_definition();
+        let input =
+            AssetDefinitionInputV3::new(DEFAULT_ASSET_TYPE, None::<String>, vec![], None, None);
+        assert_eq!(
+            existing,
+            input.merge_into(&existing),
+            "the existing definition should be unchanged when no values are provided",
+        );
+    }
+
+    #[test]
+    fn test_merge_into_overwrites_only_provided_values() {
+        let existing = get_default_asset_definition();
+        let mut verifier = get_default_verifier_detail();
+        verifier.address = "other-verifier".to_string();
+        let input = AssetDefinitionInputV3::new(
+            DEFAULT_ASSET_TYPE,
+            "New Name".to_some(),
+            vec![verifier.clone()],
+            false.to_some(),
+            None,
+        );
+        let merged = input.merge_into(&existing);
+        assert_eq!(
+            "New Name",
+            merged.display_name.expect("the display name should be set"),
+            "the provided display name should overwrite the existing value",
+        );
+        assert_eq!(
+            vec![verifier],
+            merged.verifiers,
+            "the provided verifiers should overwrite the existing verifiers",
+        );
+        assert!(
+            !merged.enabled,
+            "the provided enabled value should overwrite the existing value",
+        );
+    }
+
+    #[test]
+    fn test_merge_into_labels() {
+        let mut existing = get_default_asset_definition();
+        existing.labels = vec!["mortgage".to_string()];
+        let mut input =
+            AssetDefinitionInputV3::new(DEFAULT_ASSET_TYPE, None::<String>, vec![], None, None);
+        assert_eq!(
+            vec!["mortgage".to_string()],
+            input.merge_into(&existing).labels,
+            "the existing labels should be retained when no labels are provided",
+        );
+        input.labels = vec!["heloc".to_string(), "residential".to_string()];
+        assert_eq!(
+            vec!["heloc".to_string(), "residential".to_string()],
+            input.merge_into(&existing).labels,
+            "the provided labels should overwrite the existing labels",
+        );
+    }
+
+    #[test]
+    fn test_display_name_or_type_uses_display_name() {
+        let definition = AssetDefinitionV3::new(DEFAULT_ASSET_TYPE, "Test Asset".to_some(), vec![]);
+        assert_eq!(
+            "Test Asset",
+            definition.display_name_or_type(),
+            "the display name should be used when it is set",
+        );
+    }
+
+    #[test]
+    fn test_display_name_or_type_falls_back_when_missing() {
+        let definition = AssetDefinitionV3::new(DEFAULT_ASSET_TYPE, None::<String>, vec![]);
+        assert_eq!(
+            DEFAULT_ASSET_TYPE,
+            definition.display_name_or_type(),
+            "the asset type should be used when no display name is set",
+        );
+    }
+
+    #[test]
+    fn test_display_name_or_type_falls_back_when_empty() {
+        let definition = AssetDefinitionV3::new(DEFAULT_ASSET_TYPE, "".to_some(), vec![]);
+        assert_eq!(
+            DEFAULT_ASSET_TYPE,
+            definition.display_name_or_type(),
+            "the asset type should be used when the display name is empty",
+        );
+    }
+
+    #[test]
+    fn test_check_not_frozen() {
+        let mut definition = get_default_asset_definition();
+        definition
+            .check_not_frozen()
+            .expect("a definition that is not frozen should pass the check");
+        definition.frozen = true;
+        let error = definition.check_not_frozen().unwrap_err();
+        assert!(
+            matches!(error, ContractError::AssetDefinitionFrozen { ref asset_type } if asset_type == DEFAULT_ASSET_TYPE),
+            "expected the frozen error to be returned for a frozen definition, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_validate_valid_asset_definition() {
+        get_default_asset_definition()
+            .validate(false)
+            .expect("the default asset definition should pass validation");
+    }
+
+    #[test]
+    fn test_validate_invalid_asset_definition() {
+        let definition = AssetDefinitionV3::new(DEFAULT_ASSET_TYPE, None::<String>, vec![]);
+        match definition
+            .validate(false)
+            .expect_err("an asset definition without verifiers should fail validation")
+        {
+            ContractError::InvalidMessageFields {
+                message_type,
+                invalid_fields,
+            } => {
+                assert_eq!(
+                    "AssetDefinition", message_type,
+                    "the error should reference the asset definition message type",
+                );
+                assert!(
+                    invalid_fields.contains(
+                        &"asset_definition:verifiers: at least one verifier must be supplied per asset type".to_string()
+                    ),
+                    "the missing verifiers should be reported as invalid, but got: {:?}",
+                    invalid_fields,
+                );
+            }
+            e => panic!("unexpected error encountered: {:?}", e),
+        };
+    }
+
+    #[test]
+    fn test_builder_matches_constructor() {
+        let mut second_verifier = get_default_verifier_detail();
+        second_verifier.address = "second-verifier".to_string();
+        let built = AssetDefinitionV3::builder()
+            .asset_type(DEFAULT_ASSET_TYPE)
+            .display_name("Test Asset".to_some())
+            .add_verifier(get_default_verifier_detail())
+            .add_verifier(second_verifier.clone())
+            .build()
+            .expect("a builder with an asset type and verifiers should succeed");
+        assert_eq!(
+            AssetDefinitionV3::new(
+                DEFAULT_ASSET_TYPE,
+                "Test Asset".to_some(),
+                vec![get_default_verifier_detail(), second_verifier],
+            ),
+            built,
+            "the builder should produce the same definition as the constructor",
+        );
+    }
+
+    #[test]
+    fn test_builder_sets_optional_values() {
+        let built = AssetDefinitionV3::builder()
+            .asset_type(DEFAULT_ASSET_TYPE)
+            .add_verifier(get_default_verifier_detail())
+            .enabled(false)
+            .scope_spec_address(DEFAULT_SCOPE_SPEC_ADDRESS.to_some())
+            .add_label("mortgage")
+            .add_label("residential")
+            .frozen(true)
+            .scope_owner_must_match_requestor(true)
+            .build()
+            .expect("a builder with an asset type and verifiers should succeed");
+        assert_eq!(
+            AssetDefinitionV3 {
+                asset_type: DEFAULT_ASSET_TYPE.to_string(),
+                display_name: None,
+                verifiers: vec![get_default_verifier_detail()],
+                enabled: false,
+                scope_spec_address: DEFAULT_SCOPE_SPEC_ADDRESS.to_string().to_some(),
+                labels: vec!["mortgage".to_string(), "residential".to_string()],
+                allowed_requestors: None,
+                max_verifiers: None,
+                frozen: true,
+                deprecated: false,
+                replacement_asset_type: None,
+                scope_owner_must_match_requestor: true,
+            },
+            built,
+            "the builder should apply every provided value",
+        );
+    }
+
+    #[test]
+    fn test_builder_rejects_missing_required_values() {
+        match AssetDefinitionV3::builder()
+            .asset_type("  ")
+            .build()
+            .expect_err("a builder without an asset type or verifiers should fail")
+        {
+            ContractError::InvalidMessageFields {
+                message_type,
+                invalid_fields,
+            } => {
+                assert_eq!(
+                    "AssetDefinitionV3Builder", message_type,
+                    "the error should reference the builder",
+                );
+                assert_eq!(
+                    vec![
+                        "asset_type: must not be blank".to_string(),
+                        "verifiers: at least one verifier must be added".to_string(),
+                    ],
+                    invalid_fields,
+                    "both missing values should be reported",
+                );
+            }
+            e => panic!("unexpected error encountered: {:?}", e),
+        };
+    }
+
+    #[test]
+    fn test_safe_display_includes_verifier_displays() {
+        let definition = get_default_asset_definition();
+        assert_eq!(
+            format!(
+                "AssetDefinitionV3 {{ asset_type: {}, display_name: Some(\"Your Favorite Asset\"), enabled: true, scope_spec_address: None, labels: [], allowed_requestors: None, max_verifiers: None, frozen: false, deprecated: false, replacement_asset_type: None, scope_owner_must_match_requestor: false, verifiers: [{}] }}",
+                DEFAULT_ASSET_TYPE,
+                get_default_verifier_detail().safe_display(),
+            ),
+            definition.safe_display(),
+            "the asset definition should be displayed in the expected format",
+        );
+    }
+}
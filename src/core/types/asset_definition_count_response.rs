@@ -0,0 +1,22 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The response to a [QueryAssetDefinitionCount](crate::core::msg::QueryMsg::QueryAssetDefinitionCount)
+/// query, containing the total number of asset definitions stored in the contract.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AssetDefinitionCountResponse {
+    /// The number of [AssetDefinitionV3s](super::asset_definition::AssetDefinitionV3) stored in
+    /// the contract, regardless of whether or not they are enabled.
+    pub count: u64,
+}
+impl AssetDefinitionCountResponse {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `count` The number of stored asset definitions.
+    pub fn new(count: u64) -> Self {
+        Self { count }
+    }
+}
@@ -0,0 +1,50 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::asset_definition::AssetDefinitionV3;
+
+/// The response to a [QueryAssetDefinitionsPage](crate::core::msg::QueryMsg::QueryAssetDefinitionsPage)
+/// query, containing a single page of [AssetDefinitionV3s](super::asset_definition::AssetDefinitionV3)
+/// sorted by asset type.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AssetDefinitionsPageResponse {
+    /// The asset definitions included in this page, in ascending order by asset type.
+    pub asset_definitions: Vec<AssetDefinitionV3>,
+    /// The total number of asset definitions currently stored in the contract.
+    pub total_definitions: u64,
+    /// Whether or not more asset definitions exist after this page.
+    pub has_next_page: bool,
+    /// The value to provide as the page token in the next query to retrieve the following page.
+    /// Only populated when [has_next_page](self::AssetDefinitionsPageResponse::has_next_page) is true.
+    pub next_page_token: Option<String>,
+}
+impl AssetDefinitionsPageResponse {
+    /// Constructs a new instance of this struct, deriving the next page token from the final
+    /// asset definition in the page.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_definitions` The asset definitions included in this page.
+    /// * `total_definitions` The total number of asset definitions stored in the contract.
+    /// * `has_next_page` Whether or not more asset definitions exist after this page.
+    pub fn new(
+        asset_definitions: Vec<AssetDefinitionV3>,
+        total_definitions: u64,
+        has_next_page: bool,
+    ) -> Self {
+        let next_page_token = if has_next_page {
+            asset_definitions
+                .last()
+                .map(|definition| definition.storage_key())
+        } else {
+            None
+        };
+        Self {
+            asset_definitions,
+            total_definitions,
+            has_next_page,
+            next_page_token,
+        }
+    }
+}
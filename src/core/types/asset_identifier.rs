@@ -11,6 +11,7 @@ use crate::util::{
 
 const ASSET_UUID_NAME: &str = "asset_uuid";
 const SCOPE_ADDRESS_NAME: &str = "scope_address";
+const SCOPE_UUID_NAME: &str = "scope_uuid";
 
 /// An enum containing interchangeable values that can be used to define an asset (uuid or address).
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -20,6 +21,10 @@ pub enum AssetIdentifier {
     AssetUuid(String),
     /// A bech32 Provenance Blockchain address that begins with "scope".
     ScopeAddress(String),
+    /// The uuid v4 of a Provenance Blockchain Metadata Scope, represented by a string.  By
+    /// convention, this value matches the asset uuid, and it is converted to a scope address in the
+    /// same manner.
+    ScopeUuid(String),
 }
 impl AssetIdentifier {
     /// Converts a [SerializedEnum](super::serialized_enum::SerializedEnum) instance to one of the
@@ -33,9 +38,10 @@ impl AssetIdentifier {
         match e.r#type.as_str() {
             ASSET_UUID_NAME => Self::asset_uuid(&e.value).to_ok(),
             SCOPE_ADDRESS_NAME => Self::scope_address(&e.value).to_ok(),
+            SCOPE_UUID_NAME => Self::scope_uuid(&e.value).to_ok(),
             _ => ContractError::UnexpectedSerializedEnum {
                 received_type: e.r#type.clone(),
-                explanation: format!("Invalid AssetIdentifier. Expected one of [{ASSET_UUID_NAME}, {SCOPE_ADDRESS_NAME}]"),
+                explanation: format!("Invalid AssetIdentifier. Expected one of [{ASSET_UUID_NAME}, {SCOPE_ADDRESS_NAME}, {SCOPE_UUID_NAME}]"),
             }
             .to_err(),
         }
@@ -46,6 +52,7 @@ impl AssetIdentifier {
         match self {
             Self::AssetUuid(uuid) => SerializedEnum::new(ASSET_UUID_NAME, uuid),
             Self::ScopeAddress(address) => SerializedEnum::new(SCOPE_ADDRESS_NAME, address),
+            Self::ScopeUuid(uuid) => SerializedEnum::new(SCOPE_UUID_NAME, uuid),
         }
     }
 
@@ -68,24 +75,34 @@ impl AssetIdentifier {
         Self::ScopeAddress(scope_address.into())
     }
 
-    /// Fetches the asset uuid value from this enum.  The [AssetUuid](self::AssetIdentifier::AssetUuid) variant
-    /// can directly provide the value, but the [ScopeAddress](self::AssetIdentifier::ScopeAddress) variant
+    /// Creates a new instance of this enum as the [ScopeUuid](self::AssetIdentifier::ScopeUuid) variant.
+    ///
+    /// # Parameters
+    ///
+    /// * `scope_uuid` A uuid v4 string instance.
+    pub fn scope_uuid<S: Into<String>>(scope_uuid: S) -> Self {
+        Self::ScopeUuid(scope_uuid.into())
+    }
+
+    /// Fetches the asset uuid value from this enum.  The [AssetUuid](self::AssetIdentifier::AssetUuid) and
+    /// [ScopeUuid](self::AssetIdentifier::ScopeUuid) variants can directly provide the value, but the
+    /// [ScopeAddress](self::AssetIdentifier::ScopeAddress) variant
     /// needs to utilize the [scope_address_to_asset_uuid](crate::util::scope_address_utils::scope_address_to_asset_uuid) function
     /// to derive the value.
     pub fn get_asset_uuid(&self) -> AssetResult<String> {
         match self {
-            Self::AssetUuid(asset_uuid) => (*asset_uuid).clone().to_ok(),
+            Self::AssetUuid(uuid) | Self::ScopeUuid(uuid) => (*uuid).clone().to_ok(),
             Self::ScopeAddress(scope_address) => scope_address_to_asset_uuid(scope_address),
         }
     }
 
     /// Fetches the scope address value from this enum.  The [ScopeAddress](self::AssetIdentifier::ScopeAddress) variant
-    /// can directly provide the value, but the [AssetUuid](self::AssetIdentifier::AssetUuid) variant
-    /// needs to utilize the [asset_uuid_to_scope_address](crate::util::scope_address_utils::asset_uuid_to_scope_address) function
+    /// can directly provide the value, but the [AssetUuid](self::AssetIdentifier::AssetUuid) and
+    /// [ScopeUuid](self::AssetIdentifier::ScopeUuid) variants need to utilize the [asset_uuid_to_scope_address](crate::util::scope_address_utils::asset_uuid_to_scope_address) function
     /// to derive the value.
     pub fn get_scope_address(&self) -> AssetResult<String> {
         match self {
-            Self::AssetUuid(asset_uuid) => asset_uuid_to_scope_address(asset_uuid),
+            Self::AssetUuid(uuid) | Self::ScopeUuid(uuid) => asset_uuid_to_scope_address(uuid),
             Self::ScopeAddress(scope_address) => (*scope_address).clone().to_ok(),
         }
     }
@@ -124,7 +141,7 @@ impl AssetIdentifiers {
 mod tests {
     use crate::core::error::ContractError;
     use crate::core::types::asset_identifier::{
-        AssetIdentifier, ASSET_UUID_NAME, SCOPE_ADDRESS_NAME,
+        AssetIdentifier, ASSET_UUID_NAME, SCOPE_ADDRESS_NAME, SCOPE_UUID_NAME,
     };
     use crate::core::types::serialized_enum::SerializedEnum;
     use uuid::Uuid;
@@ -171,6 +188,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_asset_identifier_parse_for_scope_uuid() {
+        // The uuid was generated randomly and the scope address was derived via provenance's MetadataAddress util
+        let scope_uuid = "0c39efb6-9fef-11ec-ab21-6bf5c9fb3f83";
+        let expected_scope_address = "scope1qqxrnmaknlh3rm9ty94ltj0m87psnapt5l";
+        let identifier = AssetIdentifier::scope_uuid(scope_uuid);
+        let result_identifiers = identifier
+            .to_identifiers()
+            .expect("parsing identifiers should succeed");
+        assert_eq!(
+            expected_scope_address,
+            result_identifiers.scope_address.as_str(),
+            "expected the scope address to be derived correctly",
+        );
+        assert_eq!(
+            scope_uuid,
+            result_identifiers.asset_uuid.as_str(),
+            "expected the scope uuid to be used as the asset uuid",
+        );
+    }
+
     #[test]
     fn test_asset_identifier_to_functions_from_asset_uuid() {
         let initial_uuid = "5134f836-a15c-11ec-abb6-a733aad66af8";
@@ -246,6 +284,23 @@ mod tests {
         };
     }
 
+    #[test]
+    fn test_scope_uuid_serialized_enum_round_trip() {
+        let uuid = Uuid::new_v4().to_string();
+        let ser_enum = AssetIdentifier::scope_uuid(&uuid).to_serialized_enum();
+        assert_eq!(
+            SerializedEnum::new(SCOPE_UUID_NAME, &uuid),
+            ser_enum,
+            "expected the scope uuid variant to serialize with the scope uuid type",
+        );
+        assert_eq!(
+            AssetIdentifier::scope_uuid(&uuid),
+            AssetIdentifier::from_serialized_enum(&ser_enum)
+                .expect("expected serialized enum to identifier to succeed"),
+            "expected the serialized enum to convert back to the scope uuid variant",
+        );
+    }
+
     #[test]
     fn test_from_serialized_enum_wrong_type_error() {
         let ser_enum = SerializedEnum::new("bad_type", "some_value");
@@ -261,7 +316,7 @@ mod tests {
                     "expected the unexpected type to be provided in the error message",
                 );
                 assert_eq!(
-                    format!("Invalid AssetIdentifier. Expected one of [{ASSET_UUID_NAME}, {SCOPE_ADDRESS_NAME}]"),
+                    format!("Invalid AssetIdentifier. Expected one of [{ASSET_UUID_NAME}, {SCOPE_ADDRESS_NAME}, {SCOPE_UUID_NAME}]"),
                     explanation,
                     "expected the explanation to list the type of the enum and the expected values",
                 );
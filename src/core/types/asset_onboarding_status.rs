@@ -5,17 +5,26 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// An enum that denotes the various states that an [AssetScopeAttribute](super::asset_scope_attribute::AssetScopeAttribute) can have.
+/// Values are always serialized in snake_case, but PascalCase values are also accepted during
+/// deserialization.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum AssetOnboardingStatus {
     /// Indicates that the asset has been onboarded but has yet to be verified.
+    #[serde(alias = "Pending")]
     Pending,
     /// Indicates that the asset has been verified and is determined to be unfit to be classified as
     /// its designated asset type.
+    #[serde(alias = "Denied")]
     Denied,
     /// Indicates that the asset has been verified and has been successfully classified as its
     /// designated asset type.
+    #[serde(alias = "Approved")]
     Approved,
+    /// Indicates that the asset was denied by its verifier, and that the requestor has disputed the
+    /// denial.  The appeal is awaiting resolution by the contract admin.
+    #[serde(alias = "Appealing")]
+    Appealing,
 }
 impl Display for AssetOnboardingStatus {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
@@ -28,7 +37,53 @@ impl AssetOnboardingStatus {
             Self::Pending => "pending",
             Self::Denied => "denied",
             Self::Approved => "approved",
+            Self::Appealing => "appealing",
         }
         .to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, to_json_string};
+
+    use super::AssetOnboardingStatus;
+
+    #[test]
+    fn test_serialization_produces_snake_case() {
+        for (status, expected) in [
+            (AssetOnboardingStatus::Pending, "\"pending\""),
+            (AssetOnboardingStatus::Denied, "\"denied\""),
+            (AssetOnboardingStatus::Approved, "\"approved\""),
+            (AssetOnboardingStatus::Appealing, "\"appealing\""),
+        ] {
+            assert_eq!(
+                expected,
+                to_json_string(&status).expect("the status should serialize successfully"),
+                "the status should serialize to a snake_case string",
+            );
+        }
+    }
+
+    #[test]
+    fn test_deserialization_accepts_snake_case_and_pascal_case() {
+        for (json, expected) in [
+            ("\"pending\"", AssetOnboardingStatus::Pending),
+            ("\"Pending\"", AssetOnboardingStatus::Pending),
+            ("\"denied\"", AssetOnboardingStatus::Denied),
+            ("\"Denied\"", AssetOnboardingStatus::Denied),
+            ("\"approved\"", AssetOnboardingStatus::Approved),
+            ("\"Approved\"", AssetOnboardingStatus::Approved),
+            ("\"appealing\"", AssetOnboardingStatus::Appealing),
+            ("\"Appealing\"", AssetOnboardingStatus::Appealing),
+        ] {
+            assert_eq!(
+                expected,
+                from_json::<AssetOnboardingStatus>(json.as_bytes())
+                    .expect("the status should deserialize successfully"),
+                "the value {} should deserialize to the expected status",
+                json,
+            );
+        }
+    }
+}
@@ -1,12 +1,13 @@
-use cosmwasm_std::Addr;
+use cosmwasm_std::{from_json, to_json_binary, Addr, Binary};
 use result_extensions::ResultExtensions;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    core::types::access_definition::AccessDefinitionType,
+    core::{error::ContractError, types::access_definition::AccessDefinitionType},
     util::{
-        aliases::AssetResult, functions::filter_valid_access_routes,
+        aliases::AssetResult,
+        functions::{filter_valid_access_routes, generate_os_gateway_grant_id},
         scope_address_utils::bech32_string_to_addr,
     },
 };
@@ -42,6 +43,68 @@ pub struct AssetScopeAttribute {
     /// All provided access definitions are stored in the attribute for external consumers, and can
     /// be externally manipulated by admin routes or verification tasks.
     pub access_definitions: Vec<AccessDefinition>,
+    /// The bech32 address with a prefix of "scopespec" of the scope specification that the scope
+    /// was created with.  Attributes created before this value was tracked will not have it
+    /// populated until a migration is run with [populate_scope_specs](crate::core::msg::MigrateMsg::ContractUpgrade::populate_scope_specs)
+    /// enabled.  Omitted from the serialized json when absent, ensuring that the stored value of an
+    /// older attribute can be reproduced exactly when it is updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope_spec_address: Option<String>,
+    /// The number of times the scope has been onboarded again as this asset type after its initial
+    /// onboarding, either after a denial or a verification timeout.  Omitted from the serialized
+    /// json when zero, for the same reason as the [scope_spec_address](self::AssetScopeAttribute::scope_spec_address).
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub retry_count: u32,
+    /// The block height after which a [Pending](super::asset_onboarding_status::AssetOnboardingStatus::Pending)
+    /// verification can be expired by anyone via [ExpireStaleVerification](crate::core::msg::ExecuteMsg::ExpireStaleVerification).
+    /// Only populated when the selected verifier has a [verification_timeout_blocks](super::verifier_detail::VerifierDetailV2::verification_timeout_blocks)
+    /// value, and cleared once the verification completes.  Omitted from the serialized json when
+    /// absent, for the same reason as the [scope_spec_address](self::AssetScopeAttribute::scope_spec_address).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_expiry_block: Option<u64>,
+    /// The final block height at which a [Denied](super::asset_onboarding_status::AssetOnboardingStatus::Denied)
+    /// verification can be disputed by the requestor via [AppealVerification](crate::core::msg::ExecuteMsg::AppealVerification).
+    /// Only populated when the denying verifier has an [appeal_window_blocks](super::verifier_detail::VerifierDetailV2::appeal_window_blocks)
+    /// value, and cleared once an appeal is filed.  Omitted from the serialized json when absent,
+    /// for the same reason as the [scope_spec_address](self::AssetScopeAttribute::scope_spec_address).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub appeal_deadline_block: Option<u64>,
+    /// Whether or not the verifier was granted object store gateway access to the scope when it was
+    /// onboarded.  When set, the grant is revoked once verification completes.  Attributes created
+    /// before this value was tracked are assumed to have been granted access, matching the default
+    /// of the [OnboardAsset](crate::core::msg::ExecuteMsg::OnboardAsset) route.  Omitted from the
+    /// serialized json when true, for the same reason as the [scope_spec_address](self::AssetScopeAttribute::scope_spec_address).
+    #[serde(
+        default = "default_os_gateway_permission_granted",
+        skip_serializing_if = "is_true"
+    )]
+    pub os_gateway_permission_granted: bool,
+    /// The grant id under which the verifier was given object store gateway access to the scope
+    /// when it was onboarded.  Retained so that the access is revoked with the same id, even if the
+    /// contract's [os_gateway_grant_prefix](crate::core::state::StateV2::os_gateway_grant_prefix)
+    /// changes in the meantime.  Omitted from the serialized json when absent, for the same reason
+    /// as the [scope_spec_address](self::AssetScopeAttribute::scope_spec_address).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub os_gateway_grant_id: Option<String>,
+    /// The block height at which the scope was first onboarded as this asset type.  Retained
+    /// across retries, allowing external consumers to determine how long the classification
+    /// process has been underway.  Attributes created before this value was tracked will have a
+    /// value of zero, which is omitted from the serialized json for the same reason as the
+    /// [scope_spec_address](self::AssetScopeAttribute::scope_spec_address).
+    #[serde(default, skip_serializing_if = "is_zero_block")]
+    pub classification_initiated_at_block: u64,
+    /// The block height at which this attribute was most recently created or updated by the
+    /// contract.  Attributes that have not been modified since this value was tracked will have a
+    /// value of zero, which is omitted from the serialized json for the same reason as the
+    /// [scope_spec_address](self::AssetScopeAttribute::scope_spec_address).
+    #[serde(default, skip_serializing_if = "is_zero_block")]
+    pub last_modified_block: u64,
+    /// Optional free-form data provided by the requestor when onboarding the scope, such as
+    /// workflow identifiers from an external system.  The contract never interprets or clears this
+    /// value, and retains it across verifications and retries.  Omitted from the serialized json
+    /// when absent, for the same reason as the [scope_spec_address](self::AssetScopeAttribute::scope_spec_address).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requestor_metadata: Option<String>,
 }
 impl AssetScopeAttribute {
     /// Constructs a new instance of AssetScopeAttribute from the input params
@@ -63,6 +126,8 @@ impl AssetScopeAttribute {
     /// currently is.  If omitted, this value is populated as [Pending](super::asset_onboarding_status::AssetOnboardingStatus::Pending).
     /// * `access_routes` The initial access routes for the scope attribute.  These values are
     /// implicitly assumed to be from the requestor, and are wrapped in an initial [AccessDefinition](super::access_definition::AccessDefinition).
+    /// * `block_height` The current block height, used as both the [classification_initiated_at_block](self::AssetScopeAttribute::classification_initiated_at_block)
+    /// and the [last_modified_block](self::AssetScopeAttribute::last_modified_block).
     pub fn new<S1: Into<String>, S2: Into<String>, S3: Into<String>>(
         identifier: &AssetIdentifier,
         asset_type: S1,
@@ -70,6 +135,7 @@ impl AssetScopeAttribute {
         verifier_address: S3,
         onboarding_status: Option<AssetOnboardingStatus>,
         access_routes: Vec<AccessRoute>,
+        block_height: u64,
     ) -> AssetResult<Self> {
         let identifiers = identifier.to_identifiers()?;
         let req_addr = bech32_string_to_addr(requestor_address)?;
@@ -95,14 +161,90 @@ impl AssetScopeAttribute {
             onboarding_status: onboarding_status.unwrap_or(AssetOnboardingStatus::Pending),
             latest_verification_result: None,
             access_definitions,
+            scope_spec_address: None,
+            retry_count: 0,
+            pending_expiry_block: None,
+            appeal_deadline_block: None,
+            os_gateway_permission_granted: true,
+            os_gateway_grant_id: None,
+            classification_initiated_at_block: block_height,
+            last_modified_block: block_height,
+            requestor_metadata: None,
         }
         .to_ok()
     }
+
+    /// Determines the grant id under which the verifier was given object store gateway access to
+    /// the scope.  The [recorded grant id](self::AssetScopeAttribute::os_gateway_grant_id) is
+    /// preferred.  Attributes onboarded before it was recorded derive the id from the provided
+    /// prefix instead.
+    ///
+    /// # Parameters
+    ///
+    /// * `fallback_prefix` The contract's current [os_gateway_grant_prefix](crate::core::state::StateV2::os_gateway_grant_prefix),
+    /// used only when no grant id was recorded.
+    pub fn get_os_gateway_grant_id(&self, fallback_prefix: Option<&str>) -> String {
+        match &self.os_gateway_grant_id {
+            Some(grant_id) => grant_id.to_owned(),
+            None => {
+                generate_os_gateway_grant_id(fallback_prefix, &self.asset_type, &self.scope_address)
+            }
+        }
+    }
+
+    /// Deserializes an instance of this struct from the raw json bytes stored in a Provenance
+    /// Blockchain Attribute Module attribute.
+    ///
+    /// # Parameters
+    ///
+    /// * `attribute_name` The name of the attribute from which the value was retrieved.  Used to
+    /// provide context in the error produced when deserialization fails.
+    /// * `value` The raw json bytes of the attribute.
+    pub fn from_binary<S: Into<String>, B: AsRef<[u8]>>(
+        attribute_name: S,
+        value: B,
+    ) -> AssetResult<Self> {
+        from_json::<Self>(value).map_err(|e| ContractError::AttributeSerializationError {
+            attribute_name: attribute_name.into(),
+            cause: e.to_string(),
+        })
+    }
+
+    /// Serializes this struct into json bytes for storage in a Provenance Blockchain Attribute
+    /// Module attribute.
+    ///
+    /// # Parameters
+    ///
+    /// * `attribute_name` The name of the attribute to which the value will be written.  Used to
+    /// provide context in the error produced when serialization fails.
+    pub fn to_binary<S: Into<String>>(&self, attribute_name: S) -> AssetResult<Binary> {
+        to_json_binary(self).map_err(|e| ContractError::AttributeSerializationError {
+            attribute_name: attribute_name.into(),
+            cause: e.to_string(),
+        })
+    }
+}
+
+fn is_zero(value: &u32) -> bool {
+    *value == 0
+}
+
+fn is_zero_block(value: &u64) -> bool {
+    *value == 0
+}
+
+fn is_true(value: &bool) -> bool {
+    *value
+}
+
+fn default_os_gateway_permission_granted() -> bool {
+    true
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
+        core::error::ContractError,
         core::types::{
             access_route::AccessRoute, asset_identifier::AssetIdentifier,
             asset_onboarding_status::AssetOnboardingStatus,
@@ -112,7 +254,7 @@ mod tests {
             test_constants::{
                 DEFAULT_ASSET_UUID, DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
             },
-            test_utilities::assert_single_item,
+            test_utilities::{assert_single_item, get_default_asset_scope_attribute},
         },
         util::traits::OptionExtensions,
     };
@@ -131,6 +273,7 @@ mod tests {
                 AccessRoute::route_only(""),
                 AccessRoute::route_only("good route"),
             ],
+            0,
         )
         .expect("validation should succeed for a properly-formatted asset scope attribute");
         let access_definition = assert_single_item(
@@ -160,6 +303,7 @@ mod tests {
                 AccessRoute::route_only("  "),
                 AccessRoute::route_only(""),
             ],
+            0,
         )
         .expect("validation should succeed for a properly-formatted asset scope attribute");
         assert!(
@@ -177,6 +321,7 @@ mod tests {
             DEFAULT_VERIFIER_ADDRESS,
             AssetOnboardingStatus::Pending.to_some(),
             vec![],
+            0,
         )
         .expect("validation should succeed for a properly-formatted asset scope attribute");
         assert!(
@@ -197,6 +342,7 @@ mod tests {
                 "   test-route   ",
                 "my cool name                 ",
             )],
+            0,
         )
         .expect("validation should succeed for a properly-formatted asset scope attribute");
         let access_definition = assert_single_item(
@@ -232,6 +378,7 @@ mod tests {
                 AccessRoute::route_and_name("test-route", "name1"),
                 AccessRoute::route_and_name("test-route", "name2"),
             ],
+            0,
         )
         .expect("validation should succeed for a properly-formatted asset scope attribute");
         assert_eq!(
@@ -278,6 +425,7 @@ mod tests {
                 AccessRoute::route_and_name("test-route", "hey look at my name right here"),
                 AccessRoute::route_only("test-route"),
             ],
+            0,
         )
         .expect("validation should succeed for a properly-formatted asset scope attribute");
         assert_eq!(
@@ -324,6 +472,7 @@ mod tests {
                 AccessRoute::route_and_name("test-route     ", "myname"),
                 AccessRoute::route_and_name("test-route", "myname    "),
             ],
+            0,
         )
         .expect("validation should succeed for a properly-formatted asset scope attribute");
         let access_definition = assert_single_item(
@@ -344,4 +493,111 @@ mod tests {
             "the trimmed name should be produced correctly",
         );
     }
+
+    #[test]
+    fn test_binary_round_trip() {
+        let attribute = get_default_asset_scope_attribute();
+        let binary = attribute
+            .to_binary("test.attribute")
+            .expect("serialization should succeed for a valid attribute");
+        let deserialized = AssetScopeAttribute::from_binary("test.attribute", &binary)
+            .expect("deserialization should succeed for a serialized attribute");
+        assert_eq!(
+            attribute, deserialized,
+            "the attribute should be unchanged after a serialization round trip",
+        );
+    }
+
+    #[test]
+    fn test_retry_count_is_only_serialized_when_nonzero() {
+        let attribute = get_default_asset_scope_attribute();
+        let binary = attribute
+            .to_binary("test.attribute")
+            .expect("serialization should succeed for a valid attribute");
+        assert!(
+            !String::from_utf8(binary.to_vec())
+                .unwrap()
+                .contains("retry_count"),
+            "a zero retry count should be omitted from the serialized attribute",
+        );
+        let retried_attribute = AssetScopeAttribute {
+            retry_count: 3,
+            ..attribute
+        };
+        let binary = retried_attribute
+            .to_binary("test.attribute")
+            .expect("serialization should succeed for a retried attribute");
+        assert_eq!(
+            retried_attribute,
+            AssetScopeAttribute::from_binary("test.attribute", &binary)
+                .expect("deserialization should succeed for a retried attribute"),
+            "a nonzero retry count should survive a serialization round trip",
+        );
+    }
+
+    #[test]
+    fn test_os_gateway_permission_granted_is_only_serialized_when_false() {
+        let attribute = get_default_asset_scope_attribute();
+        let binary = attribute
+            .to_binary("test.attribute")
+            .expect("serialization should succeed for a valid attribute");
+        assert!(
+            !String::from_utf8(binary.to_vec())
+                .unwrap()
+                .contains("os_gateway_permission_granted"),
+            "a granted permission should be omitted from the serialized attribute",
+        );
+        assert!(
+            AssetScopeAttribute::from_binary("test.attribute", &binary)
+                .expect("deserialization should succeed for a valid attribute")
+                .os_gateway_permission_granted,
+            "an attribute without the value should be assumed to have been granted permission",
+        );
+        let ungranted_attribute = AssetScopeAttribute {
+            os_gateway_permission_granted: false,
+            ..attribute
+        };
+        let binary = ungranted_attribute
+            .to_binary("test.attribute")
+            .expect("serialization should succeed for an ungranted attribute");
+        assert_eq!(
+            ungranted_attribute,
+            AssetScopeAttribute::from_binary("test.attribute", &binary)
+                .expect("deserialization should succeed for an ungranted attribute"),
+            "an ungranted permission should survive a serialization round trip",
+        );
+    }
+
+    #[test]
+    fn test_from_malformed_binary_produces_typed_error() {
+        let error = AssetScopeAttribute::from_binary("test.attribute", b"not json")
+            .expect_err("deserialization should fail for malformed json");
+        match error {
+            ContractError::AttributeSerializationError {
+                attribute_name,
+                cause,
+            } => {
+                assert_eq!(
+                    "test.attribute", attribute_name,
+                    "the attribute name should be included in the error",
+                );
+                assert!(
+                    !cause.is_empty(),
+                    "the underlying cause should be included in the error",
+                );
+            }
+            _ => panic!("unexpected error encountered: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn test_from_binary_with_wrong_shape_produces_typed_error() {
+        let error = AssetScopeAttribute::from_binary("test.attribute", br#"{"asset_uuid":1}"#)
+            .expect_err("deserialization should fail for json that is not a scope attribute");
+        assert!(
+            matches!(error, ContractError::AttributeSerializationError { .. }),
+            "expected an attribute serialization error, but got: {:?}",
+            error,
+        );
+    }
 }
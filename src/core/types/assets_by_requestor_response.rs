@@ -0,0 +1,36 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::asset_scope_attribute::AssetScopeAttribute;
+
+/// The response to a [QueryAssetsByRequestor](crate::core::msg::QueryMsg::QueryAssetsByRequestor)
+/// query, containing a single page of [AssetScopeAttributes](super::asset_scope_attribute::AssetScopeAttribute)
+/// onboarded by a requestor, sorted by scope address.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AssetsByRequestorResponse {
+    /// The scope attributes included in this page, in ascending order by scope address.  A scope
+    /// onboarded as multiple asset types will have an attribute for each type.
+    pub scope_attributes: Vec<AssetScopeAttribute>,
+    /// The value to provide as the start after value in the next query to retrieve the following
+    /// page.  Only populated when more scopes exist after this page.
+    pub next_start_after: Option<String>,
+}
+impl AssetsByRequestorResponse {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `scope_attributes` The scope attributes included in this page.
+    /// * `next_start_after` The scope address from which the next page should start, if more
+    /// scopes exist.
+    pub fn new(
+        scope_attributes: Vec<AssetScopeAttribute>,
+        next_start_after: Option<String>,
+    ) -> Self {
+        Self {
+            scope_attributes,
+            next_start_after,
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use cosmwasm_std::Uint128;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{access_route::AccessRoute, serialized_enum::SerializedEnum};
+
+/// A single scope to onboard in a [BatchOnboardAsset](crate::core::msg::ExecuteMsg::BatchOnboardAsset)
+/// request.  Each field behaves identically to its counterpart in the [OnboardAsset](crate::core::msg::ExecuteMsg::OnboardAsset)
+/// route.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct BatchOnboardAssetEntry {
+    /// Expects an [AssetIdentifier](super::asset_identifier::AssetIdentifier)-compatible
+    /// [SerializedEnum](super::serialized_enum::SerializedEnum).
+    pub identifier: SerializedEnum,
+    /// The asset type to onboard the scope as.
+    pub asset_type: String,
+    /// The bech32 address of the verifier that should verify the scope.
+    pub verifier_address: String,
+    /// The access routes to add to the requestor's access definition, if any.
+    pub access_routes: Option<Vec<AccessRoute>>,
+    /// Whether or not to grant the verifier object store gateway permissions.  Defaults to TRUE.
+    pub add_os_gateway_permission: Option<bool>,
+    /// The exact total fee that onboarding the scope is expected to charge, if any.
+    pub expected_fee: Option<Uint128>,
+    /// An optional note from the requestor that is emitted with the entry's onboarding event.
+    pub memo: Option<String>,
+    /// Optional free-form data from the requestor that is stored on the scope attribute.
+    pub metadata: Option<String>,
+}
+impl BatchOnboardAssetEntry {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `identifier` An [AssetIdentifier](super::asset_identifier::AssetIdentifier)-compatible
+    /// serialized enum.
+    /// * `asset_type` The asset type to onboard the scope as.
+    /// * `verifier_address` The bech32 address of the verifier that should verify the scope.
+    /// * `access_routes` The access routes to add to the requestor's access definition, if any.
+    /// * `add_os_gateway_permission` Whether or not to grant the verifier object store gateway
+    /// permissions.
+    ///
+    /// The optional `expected_fee`, `memo` and `metadata` values are omitted, and can be populated
+    /// directly on the constructed instance.
+    pub fn new<S1: Into<String>, S2: Into<String>>(
+        identifier: SerializedEnum,
+        asset_type: S1,
+        verifier_address: S2,
+        access_routes: Option<Vec<AccessRoute>>,
+        add_os_gateway_permission: Option<bool>,
+    ) -> Self {
+        Self {
+            identifier,
+            asset_type: asset_type.into(),
+            verifier_address: verifier_address.into(),
+            access_routes,
+            add_os_gateway_permission,
+            expected_fee: None,
+            memo: None,
+            metadata: None,
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{asset_scope_attribute::AssetScopeAttribute, serialized_enum::SerializedEnum};
+
+/// A single scope and asset type pair to resolve in a [QueryAssetScopeAttributesBatch](crate::core::msg::QueryMsg::QueryAssetScopeAttributesBatch)
+/// query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct BatchScopeAttributeRequest {
+    /// Expects an [AssetIdentifier](super::asset_identifier::AssetIdentifier)-compatible
+    /// [SerializedEnum](super::serialized_enum::SerializedEnum).
+    pub identifier: SerializedEnum,
+    /// The asset type to query for.
+    pub asset_type: String,
+}
+impl BatchScopeAttributeRequest {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `identifier` An [AssetIdentifier](super::asset_identifier::AssetIdentifier)-compatible
+    /// serialized enum.
+    /// * `asset_type` The asset type to query for.
+    pub fn new<S: Into<String>>(identifier: SerializedEnum, asset_type: S) -> Self {
+        Self {
+            identifier,
+            asset_type: asset_type.into(),
+        }
+    }
+}
+
+/// The outcome of resolving a single [BatchScopeAttributeRequest](self::BatchScopeAttributeRequest).
+/// At most one of `attribute` and `error` will be populated.  When both are empty, the scope
+/// exists but has no attribute for the asset type.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct BatchScopeAttributeResult {
+    /// The identifier from the originating request.
+    pub identifier: SerializedEnum,
+    /// The asset type from the originating request.
+    pub asset_type: String,
+    /// The scope attribute found for the request, if any.
+    pub attribute: Option<AssetScopeAttribute>,
+    /// A description of the error encountered while resolving the request, if any.
+    pub error: Option<String>,
+}
+
+/// The response to a [QueryAssetScopeAttributesBatch](crate::core::msg::QueryMsg::QueryAssetScopeAttributesBatch)
+/// query, containing one result per request in the same order as the requests were provided.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct BatchScopeAttributeResponse {
+    /// The result of each request, in request order.
+    pub results: Vec<BatchScopeAttributeResult>,
+}
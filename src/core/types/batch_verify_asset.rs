@@ -0,0 +1,50 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{access_route::AccessRoute, serialized_enum::SerializedEnum};
+
+/// A single scope to verify in a [BatchVerifyAsset](crate::core::msg::ExecuteMsg::BatchVerifyAsset)
+/// request.  Each field behaves identically to its counterpart in the [VerifyAsset](crate::core::msg::ExecuteMsg::VerifyAsset)
+/// route.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct BatchVerifyAssetEntry {
+    /// Expects an [AssetIdentifier](super::asset_identifier::AssetIdentifier)-compatible
+    /// [SerializedEnum](super::serialized_enum::SerializedEnum).
+    pub identifier: SerializedEnum,
+    /// The asset type this verification result is for.
+    pub asset_type: String,
+    /// A boolean indicating whether or not verification was successful.
+    pub success: bool,
+    /// An optional string describing the result of the verification process.
+    pub message: Option<String>,
+    /// The access routes to add to the verifier's access definition, if any.
+    pub access_routes: Option<Vec<AccessRoute>>,
+}
+impl BatchVerifyAssetEntry {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `identifier` An [AssetIdentifier](super::asset_identifier::AssetIdentifier)-compatible
+    /// serialized enum.
+    /// * `asset_type` The asset type this verification result is for.
+    /// * `success` A boolean indicating whether or not verification was successful.
+    /// * `message` An optional string describing the result of the verification process.
+    /// * `access_routes` The access routes to add to the verifier's access definition, if any.
+    pub fn new<S: Into<String>>(
+        identifier: SerializedEnum,
+        asset_type: S,
+        success: bool,
+        message: Option<String>,
+        access_routes: Option<Vec<AccessRoute>>,
+    ) -> Self {
+        Self {
+            identifier,
+            asset_type: asset_type.into(),
+            success,
+            message,
+            access_routes,
+        }
+    }
+}
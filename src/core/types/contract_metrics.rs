@@ -0,0 +1,28 @@
+use cosmwasm_std::Uint128;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Operational metrics tracked by the contract over its entire lifetime.  Stored within the
+/// contract's [StateV2](crate::core::state::StateV2) and maintained by the [AssetMetaService](crate::service::asset_meta_service::AssetMetaService)
+/// as assets are onboarded and verified.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ContractMetrics {
+    /// The total number of onboarding requests processed by the contract, including retries.
+    pub total_onboards: u64,
+    /// The total number of verifications processed by the contract, whether successful or not.
+    pub total_verifications: u64,
+    /// The total amount of fees, in nhash, assessed from requestors during onboarding.  Fees
+    /// charged in any other denomination are not included.
+    pub total_fees_collected_nhash: Uint128,
+    /// The total number of distinct assets onboarded by the contract.  Unlike [total_onboards](self::ContractMetrics::total_onboards),
+    /// retries are not counted.  Contracts that were tracking metrics before this field existed
+    /// begin counting from zero.
+    #[serde(default)]
+    pub total_onboarded_ever: u64,
+    /// The total number of assets approved by their verifiers.  An asset can only be approved
+    /// once, so denied verifications and retries are never counted.  Contracts that were tracking
+    /// metrics before this field existed begin counting from zero.
+    #[serde(default)]
+    pub total_verified_ever: u64,
+}
@@ -0,0 +1,57 @@
+use cosmwasm_std::Uint128;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::contract_metrics::ContractMetrics;
+
+/// The response to a [QueryContractMetrics](crate::core::msg::QueryMsg::QueryContractMetrics)
+/// query, containing the operational metrics tracked by the contract.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ContractMetricsResponse {
+    /// The total number of onboarding requests processed by the contract, including retries.
+    pub total_onboards: u64,
+    /// The total number of verifications processed by the contract, whether successful or not.
+    pub total_verifications: u64,
+    /// The total amount of fees, in nhash, assessed from requestors during onboarding.
+    pub total_fees_collected_nhash: Uint128,
+    /// The average amount of fees, in nhash, assessed per onboarding request.  Zero if no assets
+    /// have been onboarded.
+    pub average_fee_paid_nhash: Uint128,
+    /// The number of onboarding requests processed for each asset type.
+    pub asset_type_onboard_counts: BTreeMap<String, u64>,
+    /// The asset type with the most onboarding requests, if any assets have been onboarded.  Ties
+    /// are resolved in favor of the alphabetically-first asset type.
+    pub most_popular_asset_type: Option<String>,
+}
+impl ContractMetricsResponse {
+    /// Constructs a new instance of this struct, deriving the average fee and most popular asset
+    /// type from the provided values.
+    ///
+    /// # Parameters
+    ///
+    /// * `metrics` The contract's stored metrics.
+    /// * `asset_type_onboard_counts` The number of onboarding requests processed for each asset type.
+    pub fn new(metrics: ContractMetrics, asset_type_onboard_counts: BTreeMap<String, u64>) -> Self {
+        let average_fee_paid_nhash = metrics
+            .total_fees_collected_nhash
+            .checked_div(Uint128::from(metrics.total_onboards))
+            .unwrap_or_default();
+        // Ties are broken by preferring the asset type that sorts first
+        let most_popular_asset_type = asset_type_onboard_counts
+            .iter()
+            .max_by(|(type_a, count_a), (type_b, count_b)| {
+                count_a.cmp(count_b).then_with(|| type_b.cmp(type_a))
+            })
+            .map(|(asset_type, _)| asset_type.to_owned());
+        Self {
+            total_onboards: metrics.total_onboards,
+            total_verifications: metrics.total_verifications,
+            total_fees_collected_nhash: metrics.total_fees_collected_nhash,
+            average_fee_paid_nhash,
+            asset_type_onboard_counts,
+            most_popular_asset_type,
+        }
+    }
+}
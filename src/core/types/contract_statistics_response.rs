@@ -0,0 +1,29 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Aggregate counts and totals describing the contract's contents, used to observe the overall
+/// health of the contract.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ContractStatisticsResponse {
+    /// The number of stored [AssetDefinitionV3s](super::asset_definition::AssetDefinitionV3).
+    pub total_asset_definitions: u64,
+    /// The number of assets currently awaiting verification.
+    pub total_pending_onboardings: u64,
+    /// The number of assets currently approved by their verifiers.
+    pub total_approved_onboardings: u64,
+    /// The number of assets currently denied by their verifiers.  Denied assets that have been
+    /// retried are counted as pending instead.
+    pub total_denied_onboardings: u64,
+    /// The number of denied assets currently awaiting the resolution of an appeal.  These assets
+    /// are not counted as denied until the appeal is rejected.
+    pub total_appealing_onboardings: u64,
+    /// The number of stored [FeePaymentDetails](super::fee_payment_detail::FeePaymentDetail).
+    pub total_fee_payment_details_stored: u64,
+    /// The total number of distinct assets onboarded over the lifetime of the contract, excluding
+    /// retries.  See [total_onboarded_ever](super::contract_metrics::ContractMetrics::total_onboarded_ever).
+    pub total_onboarded_ever: u64,
+    /// The total number of assets approved over the lifetime of the contract.  See
+    /// [total_verified_ever](super::contract_metrics::ContractMetrics::total_verified_ever).
+    pub total_verified_ever: u64,
+}
@@ -0,0 +1,22 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A generic response containing a single count, used by queries that only need to report how many
+/// values exist without returning the values themselves, like the [QueryScopeAttributeCount](crate::core::msg::QueryMsg::QueryScopeAttributeCount)
+/// query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct CountResponse {
+    /// The number of values that matched the query.
+    pub count: u64,
+}
+impl CountResponse {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `count` The number of values that matched the query.
+    pub fn new(count: u64) -> Self {
+        Self { count }
+    }
+}
@@ -0,0 +1,28 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A single entry in the response to a [QueryDeprecatedDefinitions](crate::core::msg::QueryMsg::QueryDeprecatedDefinitions)
+/// query, containing a deprecated asset type and the asset type that should be used in its place.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct DeprecatedDefinitionEntry {
+    /// The asset type of the deprecated [AssetDefinitionV3](super::asset_definition::AssetDefinitionV3).
+    pub asset_type: String,
+    /// The asset type that requestors should migrate to, if one was provided when the definition
+    /// was deprecated.
+    pub replacement_asset_type: Option<String>,
+}
+impl DeprecatedDefinitionEntry {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_type` The asset type of the deprecated definition.
+    /// * `replacement_asset_type` The asset type that requestors should migrate to, if any.
+    pub fn new<S: Into<String>>(asset_type: S, replacement_asset_type: Option<String>) -> Self {
+        Self {
+            asset_type: asset_type.into(),
+            replacement_asset_type,
+        }
+    }
+}
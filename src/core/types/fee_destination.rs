@@ -1,6 +1,6 @@
 use crate::core::types::entity_detail::EntityDetail;
 use crate::util::traits::OptionExtensions;
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Decimal, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -13,10 +13,19 @@ pub struct FeeDestinationV2 {
     /// The amount to be distributed to this account from the designated total [onboarding_cost](super::verifier_detail::VerifierDetailV2::onboarding_cost) of the
     /// containing [VerifierDetailV2](super::verifier_detail::VerifierDetailV2).  This number should
     /// always sum with the other fee destinations to be less than or at most equal to the total
-    /// onboarding cost.
+    /// onboarding cost.  Mutually exclusive with [fee_percentage](self::FeeDestinationV2::fee_percentage).
+    /// A value of zero indicates that no fixed amount is set.
+    #[serde(default)]
     pub fee_amount: Uint128,
+    /// A percentage of the designated total [onboarding_cost](super::verifier_detail::VerifierDetailV2::onboarding_cost)
+    /// to be distributed to this account, expressed as a decimal between zero and one.  Mutually
+    /// exclusive with [fee_amount](self::FeeDestinationV2::fee_amount).
+    pub fee_percentage: Option<Decimal>,
     /// An optional set of fields that define the fee destination, including its name and home URL location.
     pub entity_detail: Option<EntityDetail>,
+    /// An optional short label for the account, used when naming the fees it receives.  Takes
+    /// precedence over the name in the [entity_detail](self::FeeDestinationV2::entity_detail).
+    pub display_name: Option<String>,
 }
 impl FeeDestinationV2 {
     /// Constructs a new instance of this struct.
@@ -30,7 +39,28 @@ impl FeeDestinationV2 {
         Self {
             address: address.into(),
             fee_amount: Uint128::new(fee_amount),
+            fee_percentage: None,
             entity_detail: None,
+            display_name: None,
+        }
+    }
+
+    /// Constructs a new instance of this struct that receives a percentage of the total onboarding
+    /// cost rather than a fixed amount.
+    ///
+    /// # Parameters
+    ///
+    /// * `address` The Provenance Blockchain bech32 address belonging to the account.
+    /// * `fee_percentage` The percentage of the total [onboarding_cost](super::verifier_detail::VerifierDetailV2::onboarding_cost)
+    /// of the containing [VerifierDetailV2](super::verifier_detail::VerifierDetailV2) to be
+    /// distributed to this account.
+    pub fn new_percentage<S: Into<String>>(address: S, fee_percentage: Decimal) -> Self {
+        Self {
+            address: address.into(),
+            fee_amount: Uint128::zero(),
+            fee_percentage: fee_percentage.to_some(),
+            entity_detail: None,
+            display_name: None,
         }
     }
 
@@ -51,7 +81,102 @@ impl FeeDestinationV2 {
         Self {
             address: address.into(),
             fee_amount,
+            fee_percentage: None,
             entity_detail: entity_detail.to_some(),
+            display_name: None,
+        }
+    }
+
+    /// Calculates the portion of a total onboarding cost designated by this destination's
+    /// [fee_percentage](self::FeeDestinationV2::fee_percentage).  Fractional amounts are rounded
+    /// down.  Returns zero if no percentage is set.
+    ///
+    /// # Parameters
+    ///
+    /// * `total_onboarding_cost` The total onboarding cost from which the percentage is taken.
+    pub fn proportional_amount(&self, total_onboarding_cost: u128) -> u128 {
+        self.fee_percentage
+            .map(|percentage| Uint128::new(total_onboarding_cost).mul_floor(percentage))
+            .unwrap_or_default()
+            .u128()
+    }
+
+    /// Determines the amount to be distributed to this destination from a total onboarding cost.
+    /// If a fixed [fee_amount](self::FeeDestinationV2::fee_amount) is set, it is used directly.
+    /// Otherwise, the [proportional_amount](self::FeeDestinationV2::proportional_amount) is used.
+    ///
+    /// # Parameters
+    ///
+    /// * `total` The total onboarding cost from which this destination's fee is derived.
+    pub fn effective_amount(&self, total: u128) -> u128 {
+        if self.fee_amount.is_zero() {
+            self.proportional_amount(total)
+        } else {
+            self.fee_amount.u128()
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Decimal, Uint128};
+
+    use crate::core::types::fee_destination::FeeDestinationV2;
+
+    #[test]
+    fn test_effective_amount_uses_fixed_amount() {
+        let destination = FeeDestinationV2::new("address", 150);
+        assert_eq!(
+            150,
+            destination.effective_amount(1000),
+            "the fixed fee amount should be used regardless of the total",
+        );
+        assert_eq!(
+            0,
+            destination.proportional_amount(1000),
+            "no proportional amount should be calculated when no percentage is set",
+        );
+    }
+
+    #[test]
+    fn test_effective_amount_uses_percentage() {
+        let destination = FeeDestinationV2::new_percentage("address", Decimal::percent(25));
+        assert_eq!(
+            250,
+            destination.effective_amount(1000),
+            "the percentage of the total should be used when no fixed amount is set",
+        );
+        assert_eq!(
+            0,
+            destination.effective_amount(0),
+            "a percentage of a zero total should be zero",
+        );
+    }
+
+    #[test]
+    fn test_proportional_amount_rounds_down() {
+        let destination = FeeDestinationV2::new_percentage("address", Decimal::percent(33));
+        assert_eq!(
+            33,
+            destination.proportional_amount(101),
+            "fractional proportional amounts should be rounded down",
+        );
+    }
+
+    #[test]
+    fn test_deserialize_without_fee_percentage() {
+        let destination = cosmwasm_std::from_json::<FeeDestinationV2>(
+            r#"{"address":"address","fee_amount":"100","entity_detail":null}"#,
+        )
+        .expect("a destination without a fee percentage should deserialize");
+        assert_eq!(
+            Uint128::new(100),
+            destination.fee_amount,
+            "the fixed fee amount should be retained",
+        );
+        assert!(
+            destination.fee_percentage.is_none(),
+            "the fee percentage should default to None",
+        );
+    }
+}
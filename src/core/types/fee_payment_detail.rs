@@ -2,7 +2,10 @@ use crate::core::error::ContractError;
 use crate::core::types::fee_destination::FeeDestinationV2;
 use crate::core::types::verifier_detail::VerifierDetailV2;
 use crate::util::aliases::AssetResult;
-use crate::util::functions::bank_send;
+use crate::util::constants::MAX_FEE_PAYMENT_DETAIL_SECONDS;
+use crate::util::functions::{
+    bank_send, bank_send_multi, generate_fee_name, safe_sub, split_by_denom,
+};
 
 use crate::core::types::asset_scope_attribute::AssetScopeAttribute;
 use crate::core::types::onboarding_cost::OnboardingCost;
@@ -23,6 +26,16 @@ pub struct FeePaymentDetail {
     /// The breakdown of each fee charge.  This vector will always at least contain a single charge,
     /// which will be to send a payment to the verifier.
     pub payments: Vec<FeePayment>,
+    /// The block time, in seconds, at which the detail was created during onboarding.  Details
+    /// stored before this value was tracked are stamped with the block time of the contract
+    /// migration by [backfill_fee_payment_detail_creation](crate::core::state::backfill_fee_payment_detail_creation).
+    #[serde(default)]
+    pub created_at: u64,
+    /// The block height at which the detail was created during onboarding.  Details stored before
+    /// this value was tracked are stamped with the block height of the contract migration by
+    /// [backfill_fee_payment_detail_creation](crate::core::state::backfill_fee_payment_detail_creation).
+    #[serde(default)]
+    pub created_at_block: u64,
 }
 impl FeePaymentDetail {
     /// Constructs a new instance of this struct by deriving all required fees from the associated
@@ -41,12 +54,16 @@ impl FeePaymentDetail {
     /// * `existing_scope_attributes` Any current scope attributes that have already been placed
     /// onto the asset being classified.  Helps determine if the subsequent run with this verifier
     /// is applicable for using subsequent fee amounts.
+    /// * `created_at` The block time, in seconds, at which the detail is being created.
+    /// * `created_at_block` The block height at which the detail is being created.
     pub fn new<S1: Into<String>, S2: Into<String>>(
         scope_address: S1,
         verifier: &VerifierDetailV2,
         is_retry: bool,
         asset_type: S2,
         existing_scope_attributes: &[AssetScopeAttribute],
+        created_at: u64,
+        created_at_block: u64,
     ) -> AssetResult<Self> {
         let mut payments = vec![];
         let mut fee_total: u128 = 0;
@@ -54,14 +71,15 @@ impl FeePaymentDetail {
             calc_onboarding_cost_source(verifier, is_retry, asset_type, existing_scope_attributes);
         // Append a message for each destination
         for destination in onboarding_cost.fee_destinations.iter() {
+            let fee_amount = destination.effective_amount(onboarding_cost.cost.u128());
             payments.push(FeePayment {
-                amount: coin(destination.fee_amount.u128(), &verifier.onboarding_denom),
+                amount: coin(fee_amount, &verifier.onboarding_denom),
                 name: generate_fee_destination_fee_name(destination),
                 // All FeeDestination addresses are verified as valid bech32 addresses when they are
                 // added to the contract, so this conversion is inherently fine to do
                 recipient: Addr::unchecked(&destination.address),
             });
-            fee_total += destination.fee_amount.u128();
+            fee_total += fee_amount;
         }
         // Fee distribution can, at most, be equal to the onboarding cost.  The onboarding cost should
         // always reflect the exact total that is taken from the requestor address when onboarding a new
@@ -77,7 +95,11 @@ impl FeePaymentDetail {
             ).to_err();
         }
         // The total funds disbursed to the verifier itself is the remainder from subtracting the fee cost from the onboarding cost
-        let verifier_cost = onboarding_cost.cost.u128() - fee_total;
+        let verifier_cost = safe_sub(
+            onboarding_cost.cost.u128(),
+            fee_total,
+            "verifier cost calculation",
+        )?;
         // Only append payment info for the verifier if it actually has a cost
         if verifier_cost > 0 {
             payments.push(FeePayment {
@@ -89,24 +111,58 @@ impl FeePaymentDetail {
         FeePaymentDetail {
             scope_address: scope_address.into(),
             payments,
+            created_at,
+            created_at_block,
         }
         .to_ok()
     }
 
+    /// Determines if this detail is older than [MAX_FEE_PAYMENT_DETAIL_SECONDS](crate::util::constants::MAX_FEE_PAYMENT_DETAIL_SECONDS)
+    /// and can no longer be used to verify its asset.
+    ///
+    /// # Parameters
+    ///
+    /// * `current_time_seconds` The current block time, in seconds.
+    pub fn is_expired(&self, current_time_seconds: u64) -> bool {
+        current_time_seconds.saturating_sub(self.created_at) > MAX_FEE_PAYMENT_DETAIL_SECONDS
+    }
+
     /// Converts all the [payments](self::FeePaymentDetail::payments) into Provenance Blockchain
-    /// bank send messages in order to charge them to their respective recipients.
+    /// bank send messages in order to charge them to their respective recipients.  All payments to
+    /// the same recipient are combined into a single message, with one coin per denomination.
     pub fn to_bank_send_msgs(&self) -> AssetResult<Vec<CosmosMsg>> {
+        let mut coins_by_recipient: Vec<(&Addr, Vec<Coin>)> = vec![];
+        for payment in self.payments.iter() {
+            match coins_by_recipient
+                .iter_mut()
+                .find(|(recipient, _)| **recipient == payment.recipient)
+            {
+                Some((_, coins)) => coins.push(payment.amount.to_owned()),
+                None => {
+                    coins_by_recipient.push((&payment.recipient, vec![payment.amount.to_owned()]))
+                }
+            }
+        }
+        coins_by_recipient
+            .into_iter()
+            .map(|(recipient, coins)| bank_send_multi(recipient, split_by_denom(&coins)))
+            .collect()
+    }
+
+    /// Creates a message that returns the aggregate amount paid via all payments to the requestor,
+    /// for use when the fees were collected during onboarding but never disbursed.  All payments
+    /// share the verifier's onboarding denom, so the total is refunded in a single send.  No
+    /// message is created when there is nothing to refund.
+    ///
+    /// # Parameters
+    ///
+    /// * `requestor_address` The bech32 address of the account that paid the fees.
+    pub fn to_refund_msg<S: Into<String>>(&self, requestor_address: S) -> Option<CosmosMsg> {
+        let refund_amount = self.sum_costs();
         self.payments
-            .iter()
-            .map(
-                |FeePayment {
-                     amount: Coin { denom, amount },
-                     recipient,
-                     ..
-                 }| { bank_send(recipient, amount.u128(), denom) },
-            )
-            .collect::<Vec<_>>()
-            .to_ok()
+            .first()
+            .filter(|_| refund_amount > 0)
+            .map(|payment| bank_send(requestor_address, refund_amount, &payment.amount.denom))
     }
 
     /// Determines the aggregate amount paid via all payments.
@@ -135,23 +191,14 @@ pub struct FeePayment {
 }
 
 fn generate_fee_destination_fee_name(destination: &FeeDestinationV2) -> String {
-    format!(
-        "Fee for {}",
-        destination
-            .entity_detail
-            .to_owned()
-            .and_then(|detail| detail.name)
-            .unwrap_or_else(|| destination.address.to_owned()),
-    )
+    match destination.display_name.as_deref() {
+        Some(display_name) => format!("Fee for {}", display_name),
+        None => generate_fee_name(destination.entity_detail.as_ref(), &destination.address, ""),
+    }
 }
 
 fn generate_verifier_fee_name(verifier: &VerifierDetailV2) -> String {
-    verifier
-        .entity_detail
-        .to_owned()
-        .and_then(|detail| detail.name)
-        .map(|detail_name| format!("{} Verifier Fee", detail_name))
-        .unwrap_or_else(|| "Verifier Fee".to_string())
+    generate_fee_name(verifier.entity_detail.as_ref(), "", " Verifier Fee")
 }
 
 fn calc_onboarding_cost_source<S: Into<String>>(
@@ -207,7 +254,7 @@ mod tests {
     use crate::core::types::entity_detail::EntityDetail;
     use crate::core::types::fee_destination::FeeDestinationV2;
     use crate::core::types::fee_payment_detail::{
-        generate_fee_destination_fee_name, generate_verifier_fee_name, FeePaymentDetail,
+        generate_fee_destination_fee_name, generate_verifier_fee_name, FeePayment, FeePaymentDetail,
     };
     use crate::core::types::onboarding_cost::OnboardingCost;
     use crate::core::types::subsequent_classification_detail::SubsequentClassificationDetail;
@@ -217,16 +264,18 @@ mod tests {
         DEFAULT_VERIFIER_ADDRESS,
     };
     use crate::testutil::test_utilities::get_default_entity_detail;
-    use crate::util::constants::NHASH;
+    use crate::util::constants::{format_nhash, MAX_FEE_PAYMENT_DETAIL_SECONDS, NHASH};
     use crate::util::traits::OptionExtensions;
-    use cosmwasm_std::{BankMsg, CosmosMsg, Uint128};
+    use cosmwasm_std::{coin, Addr, BankMsg, CosmosMsg, Decimal, Uint128};
 
     #[test]
     fn test_generate_fee_destination_fee_name() {
         let mut fee_destination = FeeDestinationV2 {
             address: "someaddress".to_string(),
             fee_amount: Uint128::new(150),
+            fee_percentage: None,
             entity_detail: Some(EntityDetail::new("selling fake doors", "", "", "")),
+            display_name: None,
         };
         assert_eq!(
             "Fee for selling fake doors".to_string(),
@@ -249,6 +298,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_fee_destination_fee_name_prefers_display_name() {
+        let mut fee_destination = FeeDestinationV2 {
+            display_name: "Door Co".to_string().to_some(),
+            ..FeeDestinationV2::new_with_detail(
+                "someaddress",
+                Uint128::new(150),
+                EntityDetail::new("selling fake doors", "", "", ""),
+            )
+        };
+        assert_eq!(
+            "Fee for Door Co".to_string(),
+            generate_fee_destination_fee_name(&fee_destination),
+            "the display name should be preferred over the entity detail name",
+        );
+        fee_destination.entity_detail = None;
+        assert_eq!(
+            "Fee for Door Co".to_string(),
+            generate_fee_destination_fee_name(&fee_destination),
+            "the display name should be preferred over the destination address",
+        );
+    }
+
     #[test]
     fn test_generate_verifier_fee_name() {
         let mut verifier = VerifierDetailV2::new(
@@ -286,6 +358,125 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_bank_send_msgs_group_payments_by_recipient() {
+        let detail = FeePaymentDetail {
+            scope_address: DEFAULT_SCOPE_ADDRESS.to_string(),
+            payments: vec![
+                FeePayment {
+                    amount: coin(100, NHASH),
+                    name: "Verifier Fee".to_string(),
+                    recipient: Addr::unchecked("verifier"),
+                },
+                FeePayment {
+                    amount: coin(50, "othercoin"),
+                    name: "Other Verifier Fee".to_string(),
+                    recipient: Addr::unchecked("verifier"),
+                },
+                FeePayment {
+                    amount: coin(25, NHASH),
+                    name: "Fee Destination Fee".to_string(),
+                    recipient: Addr::unchecked("destination"),
+                },
+                FeePayment {
+                    amount: coin(10, NHASH),
+                    name: "Additional Verifier Fee".to_string(),
+                    recipient: Addr::unchecked("verifier"),
+                },
+            ],
+            created_at: 0,
+            created_at_block: 0,
+        };
+        let messages = detail
+            .to_bank_send_msgs()
+            .expect("fee messages should generate without error");
+        assert_eq!(
+            vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "verifier".to_string(),
+                    amount: vec![coin(110, NHASH), coin(50, "othercoin")],
+                }),
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "destination".to_string(),
+                    amount: vec![coin(25, NHASH)],
+                }),
+            ],
+            messages,
+            "a single message should be sent to each recipient, with one coin per denom",
+        );
+    }
+
+    #[test]
+    fn test_to_refund_msg_sends_total_to_requestor() {
+        let detail = FeePaymentDetail {
+            scope_address: DEFAULT_SCOPE_ADDRESS.to_string(),
+            payments: vec![
+                FeePayment {
+                    amount: coin(100, NHASH),
+                    name: "Verifier Fee".to_string(),
+                    recipient: Addr::unchecked("verifier"),
+                },
+                FeePayment {
+                    amount: coin(25, NHASH),
+                    name: "Fee Destination Fee".to_string(),
+                    recipient: Addr::unchecked("destination"),
+                },
+            ],
+            created_at: 0,
+            created_at_block: 0,
+        };
+        assert_eq!(
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: DEFAULT_SENDER_ADDRESS.to_string(),
+                amount: vec![coin(125, NHASH)],
+            })
+            .to_some(),
+            detail.to_refund_msg(DEFAULT_SENDER_ADDRESS),
+            "the requestor should be refunded the total of all payments in a single send",
+        );
+    }
+
+    #[test]
+    fn test_to_refund_msg_is_empty_without_costs() {
+        let detail = FeePaymentDetail {
+            scope_address: DEFAULT_SCOPE_ADDRESS.to_string(),
+            payments: vec![],
+            created_at: 0,
+            created_at_block: 0,
+        };
+        assert_eq!(
+            None,
+            detail.to_refund_msg(DEFAULT_SENDER_ADDRESS),
+            "no refund should be created when no fees were collected",
+        );
+    }
+
+    #[test]
+    fn test_is_expired() {
+        let detail = FeePaymentDetail {
+            scope_address: DEFAULT_SCOPE_ADDRESS.to_string(),
+            payments: vec![],
+            created_at: 1000,
+            created_at_block: 0,
+        };
+        assert!(
+            !detail.is_expired(1000),
+            "a detail should not be expired at the time of its creation",
+        );
+        assert!(
+            !detail.is_expired(1000 + MAX_FEE_PAYMENT_DETAIL_SECONDS),
+            "a detail should not be expired when it is exactly at the max age",
+        );
+        assert!(
+            detail.is_expired(1000 + MAX_FEE_PAYMENT_DETAIL_SECONDS + 1),
+            "a detail should be expired when it is older than the max age",
+        );
+        assert!(
+            !detail.is_expired(0),
+            "a detail should not be expired when the current time precedes its creation",
+        );
+    }
+
     #[test]
     fn test_invalid_verifier_greater_fee_than_onboarding_cost() {
         // This verifier tries to send 150% of the fee to the fee destination. NO BUENO!
@@ -304,6 +495,8 @@ mod tests {
             false,
             DEFAULT_ASSET_TYPE,
             &[],
+            0,
+            0,
         )
         .unwrap_err();
         match error {
@@ -426,35 +619,50 @@ mod tests {
             "first",
             40,
             NHASH,
-            "expected 20 nhash of the fee to be sent to the first fee destination",
+            format!(
+                "expected {} of the fee to be sent to the first fee destination",
+                format_nhash(40)
+            ),
         );
         test_messages_contains_fee_for_address(
             &messages,
             "second",
             40,
             NHASH,
-            "expected 20 nhash of the fee to be sent to the second fee destination",
+            format!(
+                "expected {} of the fee to be sent to the second fee destination",
+                format_nhash(40)
+            ),
         );
         test_messages_contains_fee_for_address(
             &messages,
             "third",
             80,
             NHASH,
-            "expected 40 nhash of the fee to be sent to the third fee destination",
+            format!(
+                "expected {} of the fee to be sent to the third fee destination",
+                format_nhash(80)
+            ),
         );
         test_messages_contains_fee_for_address(
             &messages,
             "fourth",
             10,
             NHASH,
-            "expected 5 nhash of the fee to be sent to the fourth fee destination",
+            format!(
+                "expected {} of the fee to be sent to the fourth fee destination",
+                format_nhash(10)
+            ),
         );
         test_messages_contains_fee_for_address(
             &messages,
             "fifth",
             30,
             NHASH,
-            "expected 15 nhash of the fee to be sent to the fifth fee destination",
+            format!(
+                "expected {} of the fee to be sent to the fifth fee destination",
+                format_nhash(30)
+            ),
         );
         test_messages_contains_fee_for_address(
             &messages,
@@ -465,6 +673,84 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_percentage_fee_destinations_and_remainder_to_verifier() {
+        let verifier = VerifierDetailV2::new(
+            "verifier",
+            Uint128::new(400),
+            NHASH,
+            vec![
+                FeeDestinationV2::new_percentage("first", Decimal::percent(25)),
+                FeeDestinationV2::new_percentage("second", Decimal::percent(10)),
+            ],
+            None,
+            None,
+            None,
+        );
+        let messages = test_get_messages(&verifier);
+        assert_eq!(3, messages.len(), "expected three messages to be sent");
+        test_messages_contains_fee_for_address(
+            &messages,
+            "first",
+            100,
+            NHASH,
+            "expected 25 percent of the onboarding cost to be sent to the first fee destination",
+        );
+        test_messages_contains_fee_for_address(
+            &messages,
+            "second",
+            40,
+            NHASH,
+            "expected 10 percent of the onboarding cost to be sent to the second fee destination",
+        );
+        test_messages_contains_fee_for_address(
+            &messages,
+            "verifier",
+            260,
+            NHASH,
+            "expected all remaining funds to be sent to the verifier",
+        );
+    }
+
+    #[test]
+    fn test_mixed_fixed_and_percentage_fee_destinations() {
+        let verifier = VerifierDetailV2::new(
+            "verifier",
+            Uint128::new(1000),
+            NHASH,
+            vec![
+                FeeDestinationV2::new("fixed", 150),
+                FeeDestinationV2::new_percentage("percentage", Decimal::percent(50)),
+            ],
+            None,
+            None,
+            None,
+        );
+        let messages = test_get_messages(&verifier);
+        assert_eq!(3, messages.len(), "expected three messages to be sent");
+        test_messages_contains_fee_for_address(
+            &messages,
+            "fixed",
+            150,
+            NHASH,
+            "expected the fixed fee amount to be sent to the fixed fee destination",
+        );
+        test_messages_contains_fee_for_address(
+            &messages,
+            "percentage",
+            500,
+            NHASH,
+            "expected half of the onboarding cost to be sent to the percentage fee destination",
+        );
+        test_messages_contains_fee_for_address(
+            &messages,
+            "verifier",
+            350,
+            NHASH,
+            "expected all remaining funds to be sent to the verifier",
+        );
+    }
+
     #[test]
     fn test_retry_fees_are_used_when_applicable() {
         let verifier = VerifierDetailV2::new(
@@ -483,7 +769,10 @@ mod tests {
             "verifier",
             180,
             NHASH,
-            "expected the funds to be sent to the verifier, minus the 20 for fee",
+            format!(
+                "expected the funds to be sent to the verifier, minus the {} for fee",
+                format_nhash(20)
+            ),
         );
         test_messages_contains_fee_for_address(
             &messages,
@@ -516,6 +805,7 @@ mod tests {
             DEFAULT_VERIFIER_ADDRESS,
             AssetOnboardingStatus::Approved.to_some(),
             vec![],
+            0,
         )
         .expect("scope attribute should be generated without issue");
         let messages = test_get_messages_provided(&verifier, true, &[existing_scope_attribute]);
@@ -524,7 +814,10 @@ mod tests {
             DEFAULT_VERIFIER_ADDRESS,
             400,
             NHASH,
-            "the verifier should receive the correct amount of nhash: 600 - 200fee = 400",
+            format!(
+                "the verifier should receive the correct amount of nhash: 600 - 200fee = {}",
+                format_nhash(400)
+            ),
         );
         test_messages_contains_fee_for_address(
             &messages,
@@ -552,7 +845,10 @@ mod tests {
             "verifier",
             140,
             NHASH,
-            "the correct amount of nhash should be sent to the verifier: 150 - 10fee = 140",
+            format!(
+                "the correct amount of nhash should be sent to the verifier: 150 - 10fee = {}",
+                format_nhash(140)
+            ),
         );
         test_messages_contains_fee_for_address(
             &messages,
@@ -585,6 +881,7 @@ mod tests {
             DEFAULT_VERIFIER_ADDRESS,
             AssetOnboardingStatus::Approved.to_some(),
             vec![],
+            0,
         )
         .expect("scope attribute should be generated without issue");
         let messages = test_get_messages_provided(&verifier, false, &[existing_scope_attribute]);
@@ -593,14 +890,20 @@ mod tests {
             DEFAULT_VERIFIER_ADDRESS,
             400,
             NHASH,
-            "expected 400 nhash to go to the verifier: 500 - 100fee = 400",
+            format!(
+                "expected {} to go to the verifier: 500 - 100fee = 400",
+                format_nhash(400)
+            ),
         );
         test_messages_contains_fee_for_address(
             &messages,
             "second",
             100,
             NHASH,
-            "expected 100 nhash to go to the fee destination to meet its total value",
+            format!(
+                "expected {} to go to the fee destination to meet its total value",
+                format_nhash(100)
+            ),
         );
     }
 
@@ -622,6 +925,7 @@ mod tests {
             DEFAULT_VERIFIER_ADDRESS,
             AssetOnboardingStatus::Approved.to_some(),
             vec![],
+            0,
         )
         .expect("scope attribute should be generated without issue");
         let messages = test_get_messages_provided(&verifier, false, &[existing_scope_attribute]);
@@ -630,7 +934,10 @@ mod tests {
             DEFAULT_VERIFIER_ADDRESS,
             150,
             NHASH,
-            "the verifier should receive the correct amount of nhash: 200 - 50fee = 150",
+            format!(
+                "the verifier should receive the correct amount of nhash: 200 - 50fee = {}",
+                format_nhash(150)
+            ),
         );
         test_messages_contains_fee_for_address(
             &messages,
@@ -663,6 +970,7 @@ mod tests {
             DEFAULT_VERIFIER_ADDRESS,
             AssetOnboardingStatus::Approved.to_some(),
             vec![],
+            0,
         )
         .expect("scope attribute should be generated without issue");
         let messages = test_get_messages_provided(&verifier, true, &[existing_scope_attribute]);
@@ -671,7 +979,10 @@ mod tests {
             DEFAULT_VERIFIER_ADDRESS,
             990,
             NHASH,
-            "the verifier should receive the correct amount of nhash: 1000 - 10fee = 990",
+            format!(
+                "the verifier should receive the correct amount of nhash: 1000 - 10fee = {}",
+                format_nhash(990)
+            ),
         );
         test_messages_contains_fee_for_address(
             &messages,
@@ -706,6 +1017,7 @@ mod tests {
             "tp1jcegrfy3fzfr8ejwnlqqnr5snlrt46v9mg4882",
             AssetOnboardingStatus::Approved.to_some(),
             vec![],
+            0,
         )
         .expect("scope attribute should be generated without issue");
         // Run as non-retry for a different verifier, which IS a subsequent classification, but not
@@ -716,7 +1028,10 @@ mod tests {
             DEFAULT_VERIFIER_ADDRESS,
             850,
             NHASH,
-            "the verifier should receive the correct amount of nhash: 900 - 50fee = 850",
+            format!(
+                "the verifier should receive the correct amount of nhash: 900 - 50fee = {}",
+                format_nhash(850)
+            ),
         );
         test_messages_contains_fee_for_address(
             &messages,
@@ -751,6 +1066,7 @@ mod tests {
             DEFAULT_VERIFIER_ADDRESS,
             AssetOnboardingStatus::Approved.to_some(),
             vec![],
+            0,
         )
         .expect("scope attribute should be generated without issue");
         let messages = test_get_messages_provided(&verifier, false, &[existing_scope_attribute]);
@@ -759,7 +1075,10 @@ mod tests {
             DEFAULT_VERIFIER_ADDRESS,
             850,
             NHASH,
-            "the verifier should receive the correct amount of nhash: 900 - 50fee = 850",
+            format!(
+                "the verifier should receive the correct amount of nhash: 900 - 50fee = {}",
+                format_nhash(850)
+            ),
         );
         test_messages_contains_fee_for_address(
             &messages,
@@ -785,6 +1104,8 @@ mod tests {
             is_retry,
             DEFAULT_ASSET_TYPE,
             existing_scope_attributes,
+            0,
+            0,
         )
         .expect("fee payment detail should generate without error")
         .to_bank_send_msgs()
@@ -818,8 +1139,9 @@ mod tests {
                         assert_eq!(
                             expected_amount,
                             amount.first().unwrap().amount.u128(),
-                            "{}: the fee amount should always equal the specified number",
+                            "{}: the fee amount should always equal {}",
                             err_msg,
+                            format_nhash(expected_amount),
                         );
                         assert_eq!(
                             target_denom,
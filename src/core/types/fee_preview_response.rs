@@ -0,0 +1,37 @@
+use cosmwasm_std::Uint128;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::fee_payment_detail::FeePayment;
+
+/// The response to a [QueryOnboardingFeePreview](crate::core::msg::QueryMsg::QueryOnboardingFeePreview)
+/// query, containing the fees that would be charged if the scope were onboarded with the selected
+/// verifier.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct FeePreviewResponse {
+    /// Each fee that would be charged during onboarding and paid out after verification.
+    pub payments: Vec<FeePayment>,
+    /// The sum of all payment amounts.  This is the total amount that would be charged to the
+    /// requestor during onboarding.
+    pub total_cost: Uint128,
+    /// The denomination in which all fees would be charged, derived from the verifier's
+    /// [onboarding_denom](super::verifier_detail::VerifierDetailV2::onboarding_denom).
+    pub denom: String,
+}
+impl FeePreviewResponse {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `payments` Each fee that would be charged during onboarding.
+    /// * `total_cost` The sum of all payment amounts.
+    /// * `denom` The denomination in which all fees would be charged.
+    pub fn new<S: Into<String>>(payments: Vec<FeePayment>, total_cost: Uint128, denom: S) -> Self {
+        Self {
+            payments,
+            total_cost,
+            denom: denom.into(),
+        }
+    }
+}
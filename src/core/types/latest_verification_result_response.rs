@@ -0,0 +1,40 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    asset_onboarding_status::AssetOnboardingStatus, asset_scope_attribute::AssetScopeAttribute,
+    asset_verification_result::AssetVerificationResult,
+};
+
+/// A condensed view of an [AssetScopeAttribute](super::asset_scope_attribute::AssetScopeAttribute)
+/// that only includes the values needed to determine the current verification status of an asset
+/// for a specific asset type.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct LatestVerificationResultResponse {
+    /// The bech32 address of the Provenance Blockchain Metadata Scope that was onboarded.
+    pub scope_address: String,
+    /// The asset type for which the scope was onboarded.
+    pub asset_type: String,
+    /// The current onboarding status of the asset for its asset type.
+    pub onboarding_status: AssetOnboardingStatus,
+    /// The most recent verification result produced by the verifier.  This value will be empty
+    /// when the asset is still awaiting verification.
+    pub result: Option<AssetVerificationResult>,
+}
+impl LatestVerificationResultResponse {
+    /// Constructs a new instance of this struct by extracting the relevant values from an existing
+    /// [AssetScopeAttribute](super::asset_scope_attribute::AssetScopeAttribute).
+    ///
+    /// # Parameters
+    ///
+    /// * `attribute` The scope attribute from which to derive the response values.
+    pub fn from_scope_attribute(attribute: AssetScopeAttribute) -> Self {
+        Self {
+            scope_address: attribute.scope_address,
+            asset_type: attribute.asset_type,
+            onboarding_status: attribute.onboarding_status,
+            result: attribute.latest_verification_result,
+        }
+    }
+}
@@ -2,10 +2,20 @@
 
 /// Defines a collection of [AccessRoute](self::access_route::AccessRoute) for a specific address.
 pub mod access_definition;
+/// The response to a query for all [AccessDefinitions](self::access_definition::AccessDefinition)
+/// on a scope, across every asset type.
+pub mod access_definitions_response;
 /// Defines a method of obtaining underlying asset data for a scope.
 pub mod access_route;
+/// The access routes an address owns on a single scope for a single asset type.
+pub mod access_route_owner_entry;
 /// Defines a specific asset type associated with the contract.  Allows its specified type to be onboarded and verified.
 pub mod asset_definition;
+/// The total number of [AssetDefinitionV3s](self::asset_definition::AssetDefinitionV3) stored in the contract.
+pub mod asset_definition_count_response;
+/// A single page of [AssetDefinitionV3s](self::asset_definition::AssetDefinitionV3), along with
+/// the information required to fetch the next page.
+pub mod asset_definitions_page_response;
 /// An enum containing interchangeable values that can be used to define an asset (uuid or address).
 pub mod asset_identifier;
 /// An enum that denotes the various states that an [AssetScopeAttribute](self::asset_scope_attribute::AssetScopeAttribute) can have.
@@ -15,6 +25,28 @@ pub mod asset_onboarding_status;
 pub mod asset_scope_attribute;
 /// A simple wrapper for the result of a verification for a scope.
 pub mod asset_verification_result;
+/// A single page of [AssetScopeAttributes](self::asset_scope_attribute::AssetScopeAttribute) onboarded
+/// by a requestor, along with the cursor required to fetch the next page.
+pub mod assets_by_requestor_response;
+/// The values used to onboard many scopes in a single [BatchOnboardAsset](crate::core::msg::ExecuteMsg::BatchOnboardAsset)
+/// request.
+pub mod batch_onboard_asset;
+/// Request and response values for resolving many [AssetScopeAttributes](self::asset_scope_attribute::AssetScopeAttribute)
+/// in a single query.
+pub mod batch_scope_attribute;
+/// The values used to verify many scopes in a single [BatchVerifyAsset](crate::core::msg::ExecuteMsg::BatchVerifyAsset)
+/// request.
+pub mod batch_verify_asset;
+/// Operational metrics tracked by the contract as assets are onboarded and verified.
+pub mod contract_metrics;
+/// The response to a query for the contract's operational metrics.
+pub mod contract_metrics_response;
+/// The response to a query for aggregate counts and totals describing the contract's contents.
+pub mod contract_statistics_response;
+/// A generic response containing a single count of values.
+pub mod count_response;
+/// A deprecated asset type and the asset type that should be used in its place.
+pub mod deprecated_definition_entry;
 /// Various fields describing an entity, which could be an organization, account, etc.
 pub mod entity_detail;
 /// Defines an external account designated as a recipient of funds during the verification process.
@@ -22,13 +54,32 @@ pub mod fee_destination;
 /// Defines a stored set of values for charging fees to the onboarding account during the asset
 /// classification process.
 pub mod fee_payment_detail;
+/// The fees that would be charged for onboarding a scope, calculated without onboarding it.
+pub mod fee_preview_response;
+/// A condensed view of the verification status of an [AssetScopeAttribute](self::asset_scope_attribute::AssetScopeAttribute).
+pub mod latest_verification_result_response;
 /// A node that defines how much onboarding should cost and any specific fees that should be paid.
 pub mod onboarding_cost;
+/// A single Object Store Gateway access grant or revocation, which can be relayed through the
+/// contract to emit it in its own event.
+pub mod os_gateway_access;
+/// A debugging response containing all of the contract's queryable configuration.
+pub mod query_all_response;
+/// The response to a query for the asset type bound to a scope specification.
+pub mod scope_spec_binding_response;
 /// A simple struct that allows a type and value to be translated to some of the optional enums in the contract:
 /// [AssetIdentifier](self::asset_identifier::AssetIdentifier)
 pub mod serialized_enum;
+/// The number of keys held in each of the contract's storage maps.
+pub mod storage_key_count_response;
 /// Defines fees and values that can be used when classification is being done on an asset for a
 /// new type beyond the first.
 pub mod subsequent_classification_detail;
+/// The number of verifiers registered to a single [AssetDefinitionV3](self::asset_definition::AssetDefinitionV3).
+pub mod verifier_count_response;
 /// Defines the fees and addresses for a single verifier account for an [AssetDefinitionV3](self::asset_definition::AssetDefinitionV3).
 pub mod verifier_detail;
+/// The number of assets currently awaiting verification by a single verifier.
+pub mod verifier_pending_count_response;
+/// The approved, denied and pending onboarding counts of a single verifier for a single asset type.
+pub mod verifier_stats;
@@ -1,5 +1,8 @@
+use crate::core::error::ContractError;
 use crate::core::types::fee_destination::FeeDestinationV2;
-use cosmwasm_std::Uint128;
+use crate::util::aliases::AssetResult;
+use cosmwasm_std::{Decimal, Uint128};
+use result_extensions::ResultExtensions;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -27,11 +30,168 @@ impl OnboardingCost {
         }
     }
 
-    /// Sums all the fee amounts held within the individual fee destinations in this struct.
+    /// Constructs a new instance of this struct, deriving each fee destination's amount from a
+    /// percentage of the base cost.  Because the Provenance Blockchain halves the collected fee,
+    /// the percentages must not sum to more than 50 percent of the base cost, and the base cost
+    /// must be evenly divisible by two.
+    ///
+    /// # Parameters
+    ///
+    /// * `base_cost` The amount of coin to be paid when an asset is sent to the [onboard_asset execute function](crate::execute::onboard_asset::onboard_asset).
+    /// * `destinations` Pairs of bech32 fee destination addresses and the percentage of the base
+    /// cost that each should receive, expressed as a value between 0.0 and 100.0.  Percentages are
+    /// honored to six decimal places, and resulting amounts are rounded down.
+    pub fn new_proportional(base_cost: u128, destinations: &[(&str, f64)]) -> AssetResult<Self> {
+        let mut invalid_fields: Vec<String> = vec![];
+        if !base_cost.is_multiple_of(2) {
+            invalid_fields.push(format!(
+                "onboarding_cost:cost: must be an even number, but was [{}]",
+                base_cost
+            ));
+        }
+        let mut fee_destinations = Vec::with_capacity(destinations.len());
+        for (address, percentage) in destinations {
+            if !percentage.is_finite() || *percentage <= 0.0 || *percentage > 100.0 {
+                invalid_fields.push(format!(
+                    "onboarding_cost:fee_destinations[{}]: percentage must be greater than 0 and at most 100, but was [{}]",
+                    address, percentage,
+                ));
+                continue;
+            }
+            // Convert the percentage to a ratio with six decimal places of percentage precision
+            let ratio =
+                Decimal::from_ratio((percentage * 1_000_000.0).round() as u128, 100_000_000u128);
+            let fee_amount = Uint128::new(base_cost).mul_floor(ratio).u128();
+            if fee_amount == 0 {
+                invalid_fields.push(format!(
+                    "onboarding_cost:fee_destinations[{}]: percentage [{}] of cost [{}] produces a zero fee amount",
+                    address, percentage, base_cost,
+                ));
+                continue;
+            }
+            fee_destinations.push(FeeDestinationV2::new(*address, fee_amount));
+        }
+        let percentage_total = destinations
+            .iter()
+            .map(|(_, percentage)| percentage)
+            .sum::<f64>();
+        if percentage_total > 50.0 {
+            invalid_fields.push(format!(
+                "onboarding_cost:fee_destinations: percentages must sum to at most 50, but summed to [{}]",
+                percentage_total
+            ));
+        }
+        if !invalid_fields.is_empty() {
+            return ContractError::InvalidMessageFields {
+                message_type: "OnboardingCost::new_proportional".to_string(),
+                invalid_fields,
+            }
+            .to_err();
+        }
+        Self::new(base_cost, &fee_destinations).to_ok()
+    }
+
+    /// Sums all the fee amounts held within the individual fee destinations in this struct, using
+    /// each destination's [effective_amount](super::fee_destination::FeeDestinationV2::effective_amount).
     pub fn get_fee_total(&self) -> u128 {
         self.fee_destinations
             .iter()
-            .map(|d| d.fee_amount.u128())
+            .map(|d| d.effective_amount(self.cost.u128()))
             .sum::<u128>()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::types::fee_destination::FeeDestinationV2;
+    use crate::core::types::onboarding_cost::OnboardingCost;
+
+    #[test]
+    fn test_new_proportional_calculates_amounts() {
+        let cost =
+            OnboardingCost::new_proportional(1000, &[("address-a", 10.0), ("address-b", 5.0)])
+                .expect("valid percentages should produce an onboarding cost");
+        assert_eq!(
+            OnboardingCost::new(
+                1000,
+                &[
+                    FeeDestinationV2::new("address-a", 100),
+                    FeeDestinationV2::new("address-b", 50),
+                ],
+            ),
+            cost,
+            "each fee destination should receive its percentage of the base cost",
+        );
+    }
+
+    #[test]
+    fn test_new_proportional_rounds_fractional_amounts_down() {
+        let cost = OnboardingCost::new_proportional(1000, &[("address-a", 12.55)])
+            .expect("valid percentages should produce an onboarding cost");
+        assert_eq!(
+            125,
+            cost.fee_destinations.first().unwrap().fee_amount.u128(),
+            "a fractional fee amount should be rounded down",
+        );
+    }
+
+    #[test]
+    fn test_new_proportional_allows_exactly_fifty_percent() {
+        let cost =
+            OnboardingCost::new_proportional(1000, &[("address-a", 25.0), ("address-b", 25.0)])
+                .expect("percentages summing to exactly 50 should be accepted");
+        assert_eq!(
+            500,
+            cost.get_fee_total(),
+            "the fee total should be half of the base cost",
+        );
+    }
+
+    #[test]
+    fn test_new_proportional_rejects_sum_exceeding_limit() {
+        let error =
+            OnboardingCost::new_proportional(1000, &[("address-a", 30.0), ("address-b", 25.0)])
+                .expect_err("percentages summing to more than 50 should be rejected");
+        assert_invalid_fields(error, "percentages must sum to at most 50");
+    }
+
+    #[test]
+    fn test_new_proportional_rejects_odd_base_cost() {
+        let error = OnboardingCost::new_proportional(1001, &[("address-a", 10.0)])
+            .expect_err("an odd base cost should be rejected");
+        assert_invalid_fields(error, "must be an even number");
+    }
+
+    #[test]
+    fn test_new_proportional_rejects_out_of_range_percentages() {
+        for percentage in [0.0, -1.0, 100.1, f64::NAN] {
+            let error = OnboardingCost::new_proportional(1000, &[("address-a", percentage)])
+                .expect_err("an out of range percentage should be rejected");
+            assert_invalid_fields(error, "percentage must be greater than 0 and at most 100");
+        }
+    }
+
+    #[test]
+    fn test_new_proportional_rejects_zero_fee_amount() {
+        let error = OnboardingCost::new_proportional(10, &[("address-a", 1.0)])
+            .expect_err("a percentage that produces a zero amount should be rejected");
+        assert_invalid_fields(error, "produces a zero fee amount");
+    }
+
+    fn assert_invalid_fields(error: ContractError, expected_message: &str) {
+        match error {
+            ContractError::InvalidMessageFields { invalid_fields, .. } => {
+                assert!(
+                    invalid_fields
+                        .iter()
+                        .any(|field| field.contains(expected_message)),
+                    "expected an invalid field containing [{}], but got: {:?}",
+                    expected_message,
+                    invalid_fields,
+                );
+            }
+            _ => panic!("unexpected error encountered: {:?}", error),
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use cosmwasm_std::{to_json_binary, Attribute, CosmosMsg, Env, WasmMsg};
+use os_gateway_contract_attributes::{
+    OsGatewayAttributeGenerator, OS_GATEWAY_EVENT_TYPES, OS_GATEWAY_KEYS,
+};
+use result_extensions::ResultExtensions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::msg::ExecuteMsg;
+use crate::util::aliases::AssetResult;
+use crate::util::traits::OptionExtensions;
+
+/// A single [Object Store Gateway](https://github.com/FigureTechnologies/object-store-gateway)
+/// access grant or revocation.  The gateway only reads one of these from the attributes of each
+/// `wasm` event emitted by the contract, so routes that need to emit more than one relay each of
+/// them through the [RelayOsGatewayAccess](crate::core::msg::ExecuteMsg::RelayOsGatewayAccess)
+/// route, which emits its own `wasm` event.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct OsGatewayAccess {
+    /// The bech32 address of the scope to which access is granted or revoked.
+    pub scope_address: String,
+    /// The bech32 address of the account that receives or loses access to the scope.
+    pub target_account_address: String,
+    /// The id of the grant, which allows a revocation to target the exact grant that was issued.
+    pub access_grant_id: String,
+    /// If true, access is revoked.  Otherwise, access is granted.
+    pub revoke: bool,
+}
+impl OsGatewayAccess {
+    /// Constructs a new instance of this struct that grants access to a scope.
+    ///
+    /// # Parameters
+    ///
+    /// * `scope_address` The bech32 address of the scope to which access is granted.
+    /// * `target_account_address` The bech32 address of the account that receives access.
+    /// * `access_grant_id` The id of the grant.
+    pub fn grant<S1: Into<String>, S2: Into<String>, S3: Into<String>>(
+        scope_address: S1,
+        target_account_address: S2,
+        access_grant_id: S3,
+    ) -> Self {
+        Self {
+            scope_address: scope_address.into(),
+            target_account_address: target_account_address.into(),
+            access_grant_id: access_grant_id.into(),
+            revoke: false,
+        }
+    }
+
+    /// Constructs a new instance of this struct that revokes access to a scope.
+    ///
+    /// # Parameters
+    ///
+    /// * `scope_address` The bech32 address of the scope to which access is revoked.
+    /// * `target_account_address` The bech32 address of the account that loses access.
+    /// * `access_grant_id` The id of the grant to revoke.
+    pub fn revoke<S1: Into<String>, S2: Into<String>, S3: Into<String>>(
+        scope_address: S1,
+        target_account_address: S2,
+        access_grant_id: S3,
+    ) -> Self {
+        Self {
+            revoke: true,
+            ..Self::grant(scope_address, target_account_address, access_grant_id)
+        }
+    }
+
+    /// Locates the access grant or revocation within a set of response attributes produced by
+    /// [to_attributes](self::OsGatewayAccess::to_attributes), returning `None` if the attributes
+    /// do not contain a complete set of gateway values.
+    ///
+    /// # Parameters
+    ///
+    /// * `attributes` The attributes of a response that may include gateway values.
+    pub fn from_attributes(attributes: &[Attribute]) -> Option<Self> {
+        let find_value = |key: &str| {
+            attributes
+                .iter()
+                .find(|attribute| attribute.key == key)
+                .map(|attribute| attribute.value.to_owned())
+        };
+        let revoke = match find_value(OS_GATEWAY_KEYS.event_type)? {
+            event_type if event_type == OS_GATEWAY_EVENT_TYPES.access_revoke => true,
+            event_type if event_type == OS_GATEWAY_EVENT_TYPES.access_grant => false,
+            _ => return None,
+        };
+        Self {
+            scope_address: find_value(OS_GATEWAY_KEYS.scope_address)?,
+            target_account_address: find_value(OS_GATEWAY_KEYS.target_account)?,
+            access_grant_id: find_value(OS_GATEWAY_KEYS.access_grant_id)?,
+            revoke,
+        }
+        .to_some()
+    }
+
+    /// Determines if the provided attribute key is one of the keys read by the gateway.
+    ///
+    /// # Parameters
+    ///
+    /// * `key` The key of a response attribute.
+    pub fn is_gateway_key(key: &str) -> bool {
+        [
+            OS_GATEWAY_KEYS.event_type,
+            OS_GATEWAY_KEYS.scope_address,
+            OS_GATEWAY_KEYS.target_account,
+            OS_GATEWAY_KEYS.access_grant_id,
+        ]
+        .contains(&key)
+    }
+
+    /// Produces the response attributes that the gateway reads to process this grant or
+    /// revocation.
+    pub fn to_attributes(&self) -> Vec<Attribute> {
+        if self.revoke {
+            OsGatewayAttributeGenerator::access_revoke(
+                &self.scope_address,
+                &self.target_account_address,
+            )
+        } else {
+            OsGatewayAttributeGenerator::access_grant(
+                &self.scope_address,
+                &self.target_account_address,
+            )
+        }
+        .with_access_grant_id(&self.access_grant_id)
+        .into_iter()
+        .map(|(key, value)| Attribute::new(key, value))
+        .collect()
+    }
+
+    /// Produces a message that executes the contract's [RelayOsGatewayAccess](crate::core::msg::ExecuteMsg::RelayOsGatewayAccess)
+    /// route, emitting this grant or revocation in its own `wasm` event.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+    /// details, as well as blockchain information at the time of the transaction.
+    pub fn to_relay_msg(&self, env: &Env) -> AssetResult<CosmosMsg> {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_json_binary(&ExecuteMsg::RelayOsGatewayAccess {
+                access: self.to_owned(),
+            })?,
+            funds: vec![],
+        })
+        .to_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_json, Attribute, CosmosMsg, WasmMsg};
+    use os_gateway_contract_attributes::{OS_GATEWAY_EVENT_TYPES, OS_GATEWAY_KEYS};
+
+    use crate::core::msg::ExecuteMsg;
+    use crate::testutil::test_constants::{DEFAULT_SCOPE_ADDRESS, DEFAULT_VERIFIER_ADDRESS};
+
+    use super::OsGatewayAccess;
+
+    #[test]
+    fn test_to_attributes_round_trips_through_from_attributes() {
+        for access in [
+            OsGatewayAccess::grant(DEFAULT_SCOPE_ADDRESS, DEFAULT_VERIFIER_ADDRESS, "grant-id"),
+            OsGatewayAccess::revoke(DEFAULT_SCOPE_ADDRESS, DEFAULT_VERIFIER_ADDRESS, "grant-id"),
+        ] {
+            let attributes = access.to_attributes();
+            assert!(
+                attributes
+                    .iter()
+                    .all(|attribute| OsGatewayAccess::is_gateway_key(&attribute.key)),
+                "every produced attribute should use a gateway key",
+            );
+            assert_eq!(
+                access,
+                OsGatewayAccess::from_attributes(&attributes)
+                    .expect("the produced attributes should be parsed back into the access"),
+                "the parsed access should match the original access",
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_attributes_uses_expected_event_types() {
+        let expect_event_type = |access: OsGatewayAccess, expected_event_type: &str| {
+            assert!(
+                access.to_attributes().contains(&Attribute::new(
+                    OS_GATEWAY_KEYS.event_type,
+                    expected_event_type
+                )),
+                "expected the event type to be [{expected_event_type}]",
+            );
+        };
+        expect_event_type(
+            OsGatewayAccess::grant(DEFAULT_SCOPE_ADDRESS, DEFAULT_VERIFIER_ADDRESS, "grant-id"),
+            OS_GATEWAY_EVENT_TYPES.access_grant,
+        );
+        expect_event_type(
+            OsGatewayAccess::revoke(DEFAULT_SCOPE_ADDRESS, DEFAULT_VERIFIER_ADDRESS, "grant-id"),
+            OS_GATEWAY_EVENT_TYPES.access_revoke,
+        );
+    }
+
+    #[test]
+    fn test_from_attributes_requires_every_gateway_value() {
+        assert!(
+            OsGatewayAccess::from_attributes(&[]).is_none(),
+            "no access should be found in empty attributes",
+        );
+        let incomplete_attributes =
+            OsGatewayAccess::grant(DEFAULT_SCOPE_ADDRESS, DEFAULT_VERIFIER_ADDRESS, "grant-id")
+                .to_attributes()
+                .into_iter()
+                .filter(|attribute| attribute.key != OS_GATEWAY_KEYS.access_grant_id)
+                .collect::<Vec<Attribute>>();
+        assert!(
+            OsGatewayAccess::from_attributes(&incomplete_attributes).is_none(),
+            "no access should be found when the grant id is missing",
+        );
+    }
+
+    #[test]
+    fn test_to_relay_msg_executes_the_contract() {
+        let env = mock_env();
+        let access =
+            OsGatewayAccess::revoke(DEFAULT_SCOPE_ADDRESS, DEFAULT_VERIFIER_ADDRESS, "grant-id");
+        match access
+            .to_relay_msg(&env)
+            .expect("the relay msg should be produced")
+        {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr,
+                msg,
+                funds,
+            }) => {
+                assert_eq!(
+                    env.contract.address.as_str(),
+                    contract_addr,
+                    "the relay msg should target the contract itself",
+                );
+                assert!(funds.is_empty(), "the relay msg should not send funds");
+                assert_eq!(
+                    ExecuteMsg::RelayOsGatewayAccess { access },
+                    from_json::<ExecuteMsg>(&msg).expect("the relay msg should deserialize"),
+                    "the relay msg should contain the access",
+                );
+            }
+            msg => panic!("unexpected relay msg: {msg:?}"),
+        }
+    }
+}
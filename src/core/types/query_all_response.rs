@@ -0,0 +1,43 @@
+use crate::core::state::StateV2;
+use crate::core::types::asset_definition::AssetDefinitionV3;
+use crate::migrate::version_info::VersionInfoV1;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The response to a [QueryAll](crate::core::msg::QueryMsg::QueryAll) query, containing a
+/// snapshot of all of the contract's queryable configuration for debugging purposes.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QueryAllResponse {
+    /// The contract's stored [StateV2](crate::core::state::StateV2) value.
+    pub state: StateV2,
+    /// The contract's stored [VersionInfoV1](crate::migrate::version_info::VersionInfoV1) value.
+    pub version: VersionInfoV1,
+    /// All [AssetDefinitionV3s](super::asset_definition::AssetDefinitionV3) stored in the contract.
+    pub asset_definitions: Vec<AssetDefinitionV3>,
+    /// The cached count of all asset definitions stored in the contract.
+    pub asset_definition_count: u64,
+}
+impl QueryAllResponse {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `state` The contract's stored state.
+    /// * `version` The contract's stored version info.
+    /// * `asset_definitions` All asset definitions stored in the contract.
+    /// * `asset_definition_count` The cached count of all stored asset definitions.
+    pub fn new(
+        state: StateV2,
+        version: VersionInfoV1,
+        asset_definitions: Vec<AssetDefinitionV3>,
+        asset_definition_count: u64,
+    ) -> Self {
+        Self {
+            state,
+            version,
+            asset_definitions,
+            asset_definition_count,
+        }
+    }
+}
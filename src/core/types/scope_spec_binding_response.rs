@@ -0,0 +1,28 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The response to a [QueryScopeSpecificationBinding](crate::core::msg::QueryMsg::QueryScopeSpecificationBinding)
+/// query, containing the asset type to which a scope specification is bound.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ScopeSpecBindingResponse {
+    /// The bech32 address of the scope specification that was queried.
+    pub scope_spec_address: String,
+    /// The asset type of the [AssetDefinitionV3](super::asset_definition::AssetDefinitionV3) bound
+    /// to the scope specification, or None if no definition is bound to it.
+    pub asset_type: Option<String>,
+}
+impl ScopeSpecBindingResponse {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `scope_spec_address` The bech32 address of the scope specification that was queried.
+    /// * `asset_type` The asset type bound to the scope specification, if any.
+    pub fn new<S: Into<String>>(scope_spec_address: S, asset_type: Option<String>) -> Self {
+        Self {
+            scope_spec_address: scope_spec_address.into(),
+            asset_type,
+        }
+    }
+}
@@ -95,7 +95,7 @@ mod tests {
                     "expected the unexpected type to be provided in the error message",
                 );
                 assert_eq!(
-                    format!("Invalid AssetIdentifier. Expected one of [asset_uuid, scope_address]"),
+                    format!("Invalid AssetIdentifier. Expected one of [asset_uuid, scope_address, scope_uuid]"),
                     explanation,
                     "expected the explanation to list the type of the enum and the expected values",
                 );
@@ -0,0 +1,49 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The number of keys held in each of the contract's storage maps, used to audit storage usage.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct StorageKeyCountResponse {
+    /// The number of stored [AssetDefinitionV3s](super::asset_definition::AssetDefinitionV3).
+    pub definitions: u64,
+    /// The number of stored [FeePaymentDetails](super::fee_payment_detail::FeePaymentDetail).
+    pub fee_payment_details: u64,
+    /// The number of entries across all secondary indexes, including the verifier status index, the
+    /// scope spec to asset type index, and the requestor index.
+    pub indexes: u64,
+    /// The number of stored asset denial timestamps, used to enforce verifier cooldowns.
+    pub denial_timestamps: u64,
+    /// The number of stored statistics entries, such as the onboard count for each asset type, the
+    /// statistics of each verifier, and the per-block onboarding counts of rate-limited verifiers.
+    pub stats: u64,
+    /// The sum of all other counts in this response.
+    pub total: u64,
+}
+impl StorageKeyCountResponse {
+    /// Constructs a new instance of this struct, calculating the total from the provided counts.
+    ///
+    /// # Parameters
+    ///
+    /// * `definitions` The number of stored asset definitions.
+    /// * `fee_payment_details` The number of stored fee payment details.
+    /// * `indexes` The number of entries across all secondary indexes.
+    /// * `denial_timestamps` The number of stored asset denial timestamps.
+    /// * `stats` The number of stored statistics entries.
+    pub fn new(
+        definitions: u64,
+        fee_payment_details: u64,
+        indexes: u64,
+        denial_timestamps: u64,
+        stats: u64,
+    ) -> Self {
+        Self {
+            definitions,
+            fee_payment_details,
+            indexes,
+            denial_timestamps,
+            stats,
+            total: definitions + fee_payment_details + indexes + denial_timestamps + stats,
+        }
+    }
+}
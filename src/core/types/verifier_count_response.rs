@@ -0,0 +1,29 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The response to a [QueryVerifierCount](crate::core::msg::QueryMsg::QueryVerifierCount) query,
+/// containing the number of verifiers registered to an asset definition.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct VerifierCountResponse {
+    /// The asset type of the [AssetDefinitionV3](super::asset_definition::AssetDefinitionV3) for
+    /// which verifiers were counted.
+    pub asset_type: String,
+    /// The number of [VerifierDetailV2s](super::verifier_detail::VerifierDetailV2) registered to
+    /// the asset definition.
+    pub count: u32,
+}
+impl VerifierCountResponse {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_type` The asset type for which verifiers were counted.
+    /// * `count` The number of verifiers registered to the asset type.
+    pub fn new<S: Into<String>>(asset_type: S, count: u32) -> Self {
+        Self {
+            asset_type: asset_type.into(),
+            count,
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use crate::core::types::fee_destination::FeeDestinationV2;
 use crate::core::types::onboarding_cost::OnboardingCost;
 use crate::core::types::subsequent_classification_detail::SubsequentClassificationDetail;
+use crate::util::aliases::AssetResult;
+use crate::util::traits::SafeDisplay;
+use crate::validation::validate_init_msg::validate_verifier;
 use cosmwasm_std::Uint128;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -9,30 +12,59 @@ use super::entity_detail::EntityDetail;
 
 /// Defines the fees and addresses for a single verifier account for an [AssetDefinitionV3](super::asset_definition::AssetDefinitionV3).
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
-#[serde(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case", from = "VerifierDetailV2Compat")]
 pub struct VerifierDetailV2 {
     /// The Provenance Blockchain bech32 address of the verifier account.
     pub address: String,
-    /// The total amount charged to use the onboarding process of this verifier.
-    pub onboarding_cost: Uint128,
-    /// The coin denomination used for this onboarding process.
-    pub onboarding_denom: String,
-    /// Each account that should receive fees when onboarding a new scope to the contract.
-    /// All of these destinations' individual [fee_amount](super::fee_destination::FeeDestinationV2::fee_amount) properties
-    /// should sum to an amount less than or equal to the [onboarding_cost](super::verifier_detail::VerifierDetailV2::onboarding_cost).
+    /// The total amount charged to use the onboarding process of this verifier, as well as each
+    /// account that should receive fees when onboarding a new scope to the contract.  All of the
+    /// [fee_destinations](super::onboarding_cost::OnboardingCost::fee_destinations)' individual
+    /// [fee_amount](super::fee_destination::FeeDestinationV2::fee_amount) properties should sum to
+    /// an amount less than or equal to the [cost](super::onboarding_cost::OnboardingCost::cost).
     /// Amounts not precisely equal in sum will cause this verifier detail to be considered invalid
     /// and rejected in requests that include it.
-    pub fee_destinations: Vec<FeeDestinationV2>,
+    pub onboarding_cost: OnboardingCost,
+    /// The coin denomination used for this onboarding process.
+    pub onboarding_denom: String,
     /// An optional set of fields that define the verifier, including its name and home URL location.
     pub entity_detail: Option<EntityDetail>,
-    /// Defines the cost to use in place of the root [onboarding_cost](self::VerifierDetailV2::onboarding_cost) and
-    /// [fee_destinations](self::VerifierDetailV2::fee_destinations) when retrying classification for a failed
-    /// verification.  If not present, the original values used for the first verification will be
-    /// used.
+    /// Defines the cost to use in place of the root [onboarding_cost](self::VerifierDetailV2::onboarding_cost)
+    /// when retrying classification for a failed verification.  If not present, the original values
+    /// used for the first verification will be used.
     pub retry_cost: Option<OnboardingCost>,
     /// An optional set of fields that define behaviors when classification is being run for an
     /// asset that is already classified as a different type.
     pub subsequent_classification_detail: Option<SubsequentClassificationDetail>,
+    /// An optional number of seconds that must elapse after this verifier denies an asset before
+    /// the asset can be onboarded again as the same asset type.  If not present, denied assets can
+    /// be retried immediately.
+    pub cooldown_seconds: Option<u64>,
+    /// An optional maximum number of times an asset can be onboarded again as the same asset type
+    /// with this verifier after its initial onboarding.  Once an asset's [retry_count](super::asset_scope_attribute::AssetScopeAttribute::retry_count)
+    /// reaches this value, further retries are rejected.  If not present, retries are unlimited.
+    pub max_retries: Option<u32>,
+    /// An optional number of blocks this verifier has to respond to an onboarding request.  Once
+    /// the block height passes the resulting [pending_expiry_block](super::asset_scope_attribute::AssetScopeAttribute::pending_expiry_block),
+    /// anyone may expire the verification via [ExpireStaleVerification](crate::core::msg::ExecuteMsg::ExpireStaleVerification).
+    /// If not present, verifications with this verifier never expire by block height.
+    pub verification_timeout_blocks: Option<u64>,
+    /// An optional number of blocks after this verifier denies an asset during which the asset's
+    /// requestor may dispute the denial via [AppealVerification](crate::core::msg::ExecuteMsg::AppealVerification).
+    /// The resulting deadline is stored in the denied attribute's [appeal_deadline_block](super::asset_scope_attribute::AssetScopeAttribute::appeal_deadline_block).
+    /// If not present, denials by this verifier cannot be appealed.
+    pub appeal_window_blocks: Option<u64>,
+    /// An optional maximum number of onboarding requests this verifier accepts for its asset type
+    /// within a single block.  Once the limit is reached, further requests are rejected until the
+    /// next block.  If not present, the number of requests per block is unlimited.
+    pub max_classifications_per_block: Option<u32>,
+    /// Whether or not this verifier must provide a non-blank message when denying an asset's
+    /// classification through [VerifyAsset](crate::core::msg::ExecuteMsg::VerifyAsset).  Approvals
+    /// never require a message.  Verifiers stored before this field existed do not require one.
+    pub denial_reason_required: bool,
+    /// Whether or not this verifier currently accepts new onboarding requests.  Disabled verifiers
+    /// can still verify assets that were onboarded before they were disabled.  Verifiers stored
+    /// before this field existed are considered enabled.
+    pub enabled: bool,
 }
 impl VerifierDetailV2 {
     /// Constructs a new instance of this struct.
@@ -42,12 +74,11 @@ impl VerifierDetailV2 {
     /// * `address` The Provenance Blockchain bech32 address of the verifier account.
     /// * `onboarding_cost` The total amount charged to use the onboarding process this this verifier.
     /// * `onboarding_denom` The coin denomination used for this onboarding process.
-    /// * `fee_destinations` Each account that should receive some (or all) of the amount specified in [onboarding_cost](self::VerifierDetailV2::onboarding_cost).
+    /// * `fee_destinations` Each account that should receive some (or all) of the amount specified in `onboarding_cost`.
     /// * `entity_detail` An optional set of fields that define the verifier, including its name and home URL location.
     /// * `retry_cost` Defines the cost to use in place of the root [onboarding_cost](self::VerifierDetailV2::onboarding_cost)
-    /// and [fee_destinations](self::VerifierDetailV2::fee_destinations) when retrying classification for a failed
-    /// verification.  If not present, the original values used for the first verification will be
-    /// used.
+    /// when retrying classification for a failed verification.  If not present, the original values
+    /// used for the first verification will be used.
     /// * `subsequent_classification_detail` An optional set of fields that define behaviors when
     /// classification is being run for an asset that is already classified as a different type.
     pub fn new<S1: Into<String>, S2: Into<String>>(
@@ -61,28 +92,31 @@ impl VerifierDetailV2 {
     ) -> Self {
         VerifierDetailV2 {
             address: address.into(),
-            onboarding_cost,
+            onboarding_cost: OnboardingCost::new(onboarding_cost.u128(), &fee_destinations),
             onboarding_denom: onboarding_denom.into(),
-            fee_destinations,
             entity_detail,
             retry_cost,
             subsequent_classification_detail,
+            cooldown_seconds: None,
+            max_retries: None,
+            verification_timeout_blocks: None,
+            appeal_window_blocks: None,
+            max_classifications_per_block: None,
+            denial_reason_required: false,
+            enabled: true,
         }
     }
 
-    /// Calculates a sum of all held [fee_destinations](self::VerifierDetailV2::fee_destinations)
-    /// respective [fee_amount](super::fee_destination::FeeDestinationV2::fee_amount) fields.
+    /// Calculates a sum of all held [fee_destinations](super::onboarding_cost::OnboardingCost::fee_destinations)
+    /// respective [fee_amount](super::fee_destination::FeeDestinationV2::fee_amount) fields in the
+    /// root [onboarding_cost](self::VerifierDetailV2::onboarding_cost).
     pub fn get_fee_total(&self) -> u128 {
-        self.fee_destinations
-            .iter()
-            .map(|d| d.fee_amount.u128())
-            .sum::<u128>()
+        self.onboarding_cost.get_fee_total()
     }
 
-    /// Packs the root-level onboarding_cost and fee_destinations fields into an OnboardingCost
-    /// struct.
+    /// Returns the root [onboarding_cost](self::VerifierDetailV2::onboarding_cost) value.
     pub fn get_default_cost(&self) -> OnboardingCost {
-        OnboardingCost::new(self.onboarding_cost.u128(), &self.fee_destinations)
+        self.onboarding_cost.clone()
     }
 
     /// Determines the values to use for retrying classification on an asset that has been rejected
@@ -109,17 +143,120 @@ impl VerifierDetailV2 {
         }
         self.get_default_cost()
     }
+
+    /// Validates that this verifier detail is properly formed, ensuring that all fields are
+    /// properly set and fees are established correctly.  Delegates to
+    /// [validate_verifier](crate::validation::validate_init_msg::validate_verifier).
+    ///
+    /// # Parameters
+    ///
+    /// * `allow_all_bech32_prefixes` Whether or not verifier and fee destination addresses may use
+    /// prefixes other than the [VALID_VERIFIER_ADDRESS_PREFIXES](crate::util::constants::VALID_VERIFIER_ADDRESS_PREFIXES).
+    pub fn validate(&self, allow_all_bech32_prefixes: bool) -> AssetResult<()> {
+        validate_verifier(self, allow_all_bech32_prefixes, false)
+    }
+}
+/// Displays the verifier's address and the addresses of its fee destinations, but omits all fee
+/// amounts.
+impl SafeDisplay for VerifierDetailV2 {
+    fn safe_display(&self) -> String {
+        format!(
+            "VerifierDetailV2 {{ address: {}, onboarding_denom: {}, fee_destinations: [{}], cooldown_seconds: {:?}, max_retries: {:?}, verification_timeout_blocks: {:?}, appeal_window_blocks: {:?}, max_classifications_per_block: {:?}, denial_reason_required: {}, enabled: {} }}",
+            self.address,
+            self.onboarding_denom,
+            self.onboarding_cost
+                .fee_destinations
+                .iter()
+                .map(|destination| destination.address.as_str())
+                .collect::<Vec<&str>>()
+                .join(", "),
+            self.cooldown_seconds,
+            self.max_retries,
+            self.verification_timeout_blocks,
+            self.appeal_window_blocks,
+            self.max_classifications_per_block,
+            self.denial_reason_required,
+            self.enabled,
+        )
+    }
+}
+
+/// The deserialization source for a [VerifierDetailV2](self::VerifierDetailV2).  Verifier details
+/// were originally stored with a bare [Uint128](cosmwasm_std::Uint128) onboarding cost and a
+/// sibling `fee_destinations` field.  This struct accepts both that legacy format and the current
+/// format, ensuring that stored values and requests using the old layout are still readable.
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+struct VerifierDetailV2Compat {
+    address: String,
+    onboarding_cost: OnboardingCostCompat,
+    onboarding_denom: String,
+    fee_destinations: Option<Vec<FeeDestinationV2>>,
+    entity_detail: Option<EntityDetail>,
+    retry_cost: Option<OnboardingCost>,
+    subsequent_classification_detail: Option<SubsequentClassificationDetail>,
+    cooldown_seconds: Option<u64>,
+    max_retries: Option<u32>,
+    verification_timeout_blocks: Option<u64>,
+    appeal_window_blocks: Option<u64>,
+    max_classifications_per_block: Option<u32>,
+    #[serde(default)]
+    denial_reason_required: bool,
+    #[serde(default = "default_enabled")]
+    enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// Allows the onboarding cost of a [VerifierDetailV2Compat](self::VerifierDetailV2Compat) to be
+/// parsed as either a full [OnboardingCost](super::onboarding_cost::OnboardingCost) or a legacy
+/// bare amount.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OnboardingCostCompat {
+    Current(OnboardingCost),
+    Legacy(Uint128),
+}
+
+impl From<VerifierDetailV2Compat> for VerifierDetailV2 {
+    fn from(compat: VerifierDetailV2Compat) -> Self {
+        let onboarding_cost = match compat.onboarding_cost {
+            OnboardingCostCompat::Current(cost) => cost,
+            OnboardingCostCompat::Legacy(cost) => {
+                OnboardingCost::new(cost.u128(), &compat.fee_destinations.unwrap_or_default())
+            }
+        };
+        VerifierDetailV2 {
+            address: compat.address,
+            onboarding_cost,
+            onboarding_denom: compat.onboarding_denom,
+            entity_detail: compat.entity_detail,
+            retry_cost: compat.retry_cost,
+            subsequent_classification_detail: compat.subsequent_classification_detail,
+            cooldown_seconds: compat.cooldown_seconds,
+            max_retries: compat.max_retries,
+            verification_timeout_blocks: compat.verification_timeout_blocks,
+            appeal_window_blocks: compat.appeal_window_blocks,
+            max_classifications_per_block: compat.max_classifications_per_block,
+            denial_reason_required: compat.denial_reason_required,
+            enabled: compat.enabled,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::core::error::ContractError;
     use crate::core::types::fee_destination::FeeDestinationV2;
     use crate::core::types::onboarding_cost::OnboardingCost;
     use crate::core::types::subsequent_classification_detail::SubsequentClassificationDetail;
     use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::testutil::test_utilities::get_default_verifier_detail;
     use crate::util::constants::NHASH;
-    use crate::util::traits::OptionExtensions;
-    use cosmwasm_std::Uint128;
+    use crate::util::traits::{OptionExtensions, SafeDisplay};
+    use cosmwasm_std::{from_json, to_json_vec, Uint128};
 
     #[test]
     fn test_no_fee_destinations_fee_total() {
@@ -193,13 +330,9 @@ mod tests {
         );
         let onboarding_cost = verifier.get_default_cost();
         assert_eq!(
-            verifier.onboarding_cost, onboarding_cost.cost,
-            "the cost of the onboarding cost should equate to the value specified in the root of the verifier",
-        );
-        assert_eq!(
-            verifier.fee_destinations,
-            onboarding_cost.fee_destinations,
-            "the fee destinations of the onboarding cost should equate to the value specified in the root of the verifier",
+            OnboardingCost::new(100, &[FeeDestinationV2::new("fee", 1)]),
+            onboarding_cost,
+            "the default cost should equate to the values specified in the root of the verifier",
         );
     }
 
@@ -302,4 +435,133 @@ mod tests {
             "the default subsequent classification cost should be used when no asset type targets match",
         );
     }
+
+    #[test]
+    fn test_deserialize_legacy_format() {
+        let verifier = from_json::<VerifierDetailV2>(
+            r#"{"address":"address","onboarding_cost":"100","onboarding_denom":"nhash","fee_destinations":[{"address":"fee","fee_amount":"1","entity_detail":null}],"entity_detail":null,"retry_cost":null,"subsequent_classification_detail":null}"#,
+        )
+        .expect("the legacy verifier format should deserialize successfully");
+        assert_eq!(
+            VerifierDetailV2::new(
+                "address",
+                Uint128::new(100),
+                NHASH,
+                vec![FeeDestinationV2::new("fee", 1)],
+                None,
+                None,
+                None,
+            ),
+            verifier,
+            "the legacy root cost and fee destinations should be packed into the onboarding cost",
+        );
+    }
+
+    #[test]
+    fn test_deserialize_legacy_format_without_fee_destinations() {
+        let verifier = from_json::<VerifierDetailV2>(
+            r#"{"address":"address","onboarding_cost":"100","onboarding_denom":"nhash"}"#,
+        )
+        .expect(
+            "the legacy verifier format should deserialize successfully without fee destinations",
+        );
+        assert_eq!(
+            OnboardingCost::new(100, &[]),
+            verifier.onboarding_cost,
+            "the onboarding cost should contain no fee destinations when none were provided",
+        );
+        assert!(
+            verifier.enabled,
+            "the verifier should be enabled when the enabled field is not provided",
+        );
+        assert!(
+            !verifier.denial_reason_required,
+            "the verifier should not require denial reasons when the field is not provided",
+        );
+    }
+
+    #[test]
+    fn test_serialization_round_trip() {
+        let verifier = VerifierDetailV2::new(
+            "address",
+            Uint128::new(100),
+            NHASH,
+            vec![FeeDestinationV2::new("fee", 1)],
+            None,
+            OnboardingCost::new(150, &[FeeDestinationV2::new("fee-2", 5)]).to_some(),
+            None,
+        );
+        let deserialized = from_json::<VerifierDetailV2>(
+            to_json_vec(&verifier).expect("the verifier should serialize successfully"),
+        )
+        .expect("the current verifier format should deserialize successfully");
+        assert_eq!(
+            verifier, deserialized,
+            "the verifier should be unchanged after a serialization round trip",
+        );
+    }
+
+    #[test]
+    fn test_validate_valid_verifier() {
+        get_default_verifier_detail()
+            .validate(false)
+            .expect("the default verifier should pass validation");
+    }
+
+    #[test]
+    fn test_validate_invalid_verifier() {
+        let verifier =
+            VerifierDetailV2::new("", Uint128::new(100), NHASH, vec![], None, None, None);
+        match verifier
+            .validate(false)
+            .expect_err("a verifier with a blank address should fail validation")
+        {
+            ContractError::InvalidMessageFields {
+                message_type,
+                invalid_fields,
+            } => {
+                assert_eq!(
+                    "VerifierDetail", message_type,
+                    "the error should reference the verifier detail message type",
+                );
+                assert!(
+                    invalid_fields
+                        .contains(&"verifier:address: must be a valid address".to_string()),
+                    "the blank address should be reported as invalid, but got: {:?}",
+                    invalid_fields,
+                );
+            }
+            e => panic!("unexpected error encountered: {:?}", e),
+        };
+    }
+
+    #[test]
+    fn test_safe_display_omits_fee_amounts() {
+        let verifier = VerifierDetailV2::new(
+            "verifier-address",
+            Uint128::new(12345),
+            NHASH,
+            vec![
+                FeeDestinationV2::new("first-fee-address", 4321),
+                FeeDestinationV2::new("second-fee-address", 5678),
+            ],
+            None,
+            None,
+            None,
+        );
+        let display = verifier.safe_display();
+        assert_eq!(
+            "VerifierDetailV2 { address: verifier-address, onboarding_denom: nhash, fee_destinations: [first-fee-address, second-fee-address], cooldown_seconds: None, max_retries: None, verification_timeout_blocks: None, appeal_window_blocks: None, max_classifications_per_block: None, denial_reason_required: false, enabled: true }",
+            display,
+            "the verifier should display its addresses in the expected format",
+        );
+        for amount in ["12345", "4321", "5678"] {
+            assert!(
+                !display.contains(amount),
+                "the fee amount [{}] should not be displayed, but got: {}",
+                amount,
+                display,
+            );
+        }
+    }
 }
@@ -0,0 +1,22 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The response to a [QueryVerifierPendingCount](crate::core::msg::QueryMsg::QueryVerifierPendingCount)
+/// query, containing the number of assets currently awaiting verification by a verifier.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct VerifierPendingCountResponse {
+    /// The number of assets with a [Pending](super::asset_onboarding_status::AssetOnboardingStatus::Pending)
+    /// onboarding status that are assigned to the verifier.
+    pub count: u64,
+}
+impl VerifierPendingCountResponse {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `count` The number of assets awaiting verification.
+    pub fn new(count: u64) -> Self {
+        Self { count }
+    }
+}
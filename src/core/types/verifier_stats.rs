@@ -0,0 +1,40 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Tracks the outcome of every onboarding assigned to a single verifier for a single asset type.
+/// Maintained by the [AssetMetaService](crate::service::asset_meta_service::AssetMetaService) as
+/// assets are onboarded and verified.  Contracts that were processing onboardings before these
+/// statistics existed begin counting from zero.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct VerifierStats {
+    /// The bech32 address of the verifier to which these statistics belong.
+    pub verifier_address: String,
+    /// The asset type of the onboardings assigned to the verifier.
+    pub asset_type: String,
+    /// The total number of assets approved by the verifier.
+    pub total_approved: u64,
+    /// The total number of assets denied by the verifier.
+    pub total_denied: u64,
+    /// The number of assets currently awaiting verification by the verifier.  This value
+    /// decreases when a pending onboarding is verified, cancelled, expired, purged, or moved to a
+    /// new verifier address.
+    pub total_pending: u64,
+}
+impl VerifierStats {
+    /// Constructs a new instance of this struct with all counts set to zero.
+    ///
+    /// # Parameters
+    ///
+    /// * `verifier_address` The bech32 address of the verifier.
+    /// * `asset_type` The asset type of the onboardings assigned to the verifier.
+    pub fn new<S1: Into<String>, S2: Into<String>>(verifier_address: S1, asset_type: S2) -> Self {
+        Self {
+            verifier_address: verifier_address.into(),
+            asset_type: asset_type.into(),
+            total_approved: 0,
+            total_denied: 0,
+            total_pending: 0,
+        }
+    }
+}
@@ -48,6 +48,13 @@ impl AddAssetDefinitionV1 {
         }
     }
 }
+impl TryFrom<ExecuteMsg> for AddAssetDefinitionV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        AddAssetDefinitionV1::from_execute_msg(msg)
+    }
+}
 
 /// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::AddAssetDefinition](crate::core::msg::ExecuteMsg::AddAssetDefinition)
 /// message is provided.  Attempts to add a new [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
@@ -72,6 +79,8 @@ pub fn add_asset_definition(
     // Verify that the admin is making this call and no funds are provided
     check_admin_only(&deps.as_ref(), &info)?;
     check_funds_are_empty(&info)?;
+    msg.asset_definition
+        .check_verifier_count(msg.asset_definition.verifiers.len())?;
     // The insert function includes its own checking to verify that the asset definition does not yet exist, and an error
     // will be returned if a duplicate is attempted
     insert_asset_definition_v3(deps.storage, &msg.asset_definition)?;
@@ -100,21 +109,20 @@ pub fn add_asset_definition(
 
 #[cfg(test)]
 mod tests {
-    use crate::contract::execute;
     use crate::core::error::ContractError;
-    use crate::core::msg::ExecuteMsg;
     use crate::core::state::load_asset_definition_by_type_v3;
     use crate::core::types::asset_definition::{AssetDefinitionInputV3, AssetDefinitionV3};
     use crate::core::types::fee_destination::FeeDestinationV2;
     use crate::core::types::verifier_detail::VerifierDetailV2;
     use crate::execute::add_asset_definition::{add_asset_definition, AddAssetDefinitionV1};
+    use crate::testutil::execute_utilities::execute_add_asset_definition;
     use crate::testutil::msg_utilities::test_message_is_name_bind;
     use crate::testutil::test_constants::{
         DEFAULT_ADMIN_ADDRESS, DEFAULT_FEE_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
     };
     use crate::testutil::test_utilities::{
-        empty_mock_info, get_default_entity_detail, single_attribute_for_key,
-        test_instantiate_success, InstArgs,
+        assert_response_attributes_contain, assert_response_messages_count, empty_mock_info,
+        get_default_entity_detail, test_instantiate_success, InstArgs,
     };
     use crate::util::constants::{ASSET_EVENT_TYPE_KEY, ASSET_TYPE_KEY, NHASH};
     use crate::util::event_attributes::EventType;
@@ -132,18 +140,16 @@ mod tests {
         let mut deps = mock_provenance_dependencies();
         test_instantiate_success(deps.as_mut(), &InstArgs::default());
         let asset_definition = get_valid_asset_definition();
-        let response = execute(
+        let response = execute_add_asset_definition(
             deps.as_mut(),
             mock_env(),
             empty_mock_info(DEFAULT_ADMIN_ADDRESS),
-            ExecuteMsg::AddAssetDefinition {
-                asset_definition: asset_definition.clone(),
-            },
+            asset_definition.clone(),
         )
         .expect("expected the add asset checks to work correctly");
-        assert_eq!(
+        assert_response_messages_count(
+            &response,
             1,
-            response.messages.len(),
             "the proper number of messages should be added",
         );
         test_message_is_name_bind(&response.messages, &asset_definition.asset_type);
@@ -152,16 +158,12 @@ mod tests {
             response.attributes.len(),
             "adding an asset definition should produce the correct number of attributes",
         );
-        assert_eq!(
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
             EventType::AddAssetDefinition.event_name().as_str(),
-            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
-            "the proper event type should be emitted",
-        );
-        assert_eq!(
-            TEST_ASSET_TYPE,
-            single_attribute_for_key(&response, ASSET_TYPE_KEY),
-            "the value on the attribute should be the loan type of the added definition",
         );
+        assert_response_attributes_contain(&response, ASSET_TYPE_KEY, TEST_ASSET_TYPE);
         test_asset_definition_was_added_for_input(&asset_definition, &deps.as_ref());
     }
 
@@ -188,13 +190,11 @@ mod tests {
         test_instantiate_success(deps.as_mut(), &InstArgs::default());
         let mut asset_definition = get_valid_asset_definition();
         asset_definition.bind_name = false.to_some();
-        let response = execute(
+        let response = execute_add_asset_definition(
             deps.as_mut(),
             mock_env(),
             empty_mock_info(DEFAULT_ADMIN_ADDRESS),
-            ExecuteMsg::AddAssetDefinition {
-                asset_definition: asset_definition.clone(),
-            },
+            asset_definition.clone(),
         )
         .expect("expected the add asset definition function to return properly");
         assert!(
@@ -228,24 +228,15 @@ mod tests {
     fn test_invalid_add_asset_definition_for_invalid_msg() {
         let mut deps = mock_provenance_dependencies();
         test_instantiate_success(deps.as_mut(), &InstArgs::default());
-        let msg = ExecuteMsg::AddAssetDefinition {
-            asset_definition: AssetDefinitionInputV3::new(
-                "",
-                None::<String>,
-                vec![],
-                true.to_some(),
-                true.to_some(),
-            ),
-        };
-        let error = execute(
+        let error = execute_add_asset_definition(
             deps.as_mut(),
             mock_env(),
             empty_mock_info(DEFAULT_ADMIN_ADDRESS),
-            msg,
+            AssetDefinitionInputV3::new("", None::<String>, vec![], true.to_some(), true.to_some()),
         )
         .unwrap_err();
         assert!(
-            matches!(error, ContractError::InvalidMessageFields { .. }),
+            error.is_invalid_message_fields(),
             "expected an invalid asset definition to cause an InvalidMessageFields error, but got: {:?}",
             error,
         );
@@ -264,7 +255,7 @@ mod tests {
         )
         .unwrap_err();
         assert!(
-            matches!(error, ContractError::Unauthorized { .. }),
+            error.is_unauthorized(),
             "expected the unauthorized response to be returned when a different address than the admin is the sender, but got: {:?}",
             error,
         );
@@ -330,6 +321,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_invalid_add_asset_definition_for_exceeded_max_verifiers() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = add_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            AddAssetDefinitionV1 {
+                asset_definition: AssetDefinitionV3 {
+                    max_verifiers: 0.to_some(),
+                    ..get_valid_asset_definition().into_asset_definition()
+                },
+                bind_name: None,
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::MaxVerifiersExceeded { limit: 0, .. }),
+            "expected the max verifiers exceeded error to be returned, but got: {:?}",
+            error,
+        );
+        assert!(
+            load_asset_definition_by_type_v3(deps.as_ref().storage, TEST_ASSET_TYPE).is_err(),
+            "the asset definition should not be stored when it exceeds its maximum verifiers",
+        );
+    }
+
     fn get_valid_asset_definition() -> AssetDefinitionInputV3 {
         let def = AssetDefinitionInputV3::new(
             TEST_ASSET_TYPE,
@@ -348,7 +367,8 @@ mod tests {
             None,
             None,
         );
-        validate_asset_definition_input(&def).expect("expected the asset definition to be valid");
+        validate_asset_definition_input(&def, false)
+            .expect("expected the asset definition to be valid");
         def
     }
 
@@ -62,6 +62,13 @@ impl AddAssetVerifierV1 {
         }
     }
 }
+impl TryFrom<ExecuteMsg> for AddAssetVerifierV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        AddAssetVerifierV1::from_execute_msg(msg)
+    }
+}
 
 /// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::AddAssetVerifier](crate::core::msg::ExecuteMsg::AddAssetVerifier)
 /// message is provided.  Attempts to add a new [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2)
@@ -84,15 +91,16 @@ pub fn add_asset_verifier(
     check_admin_only(&deps.as_ref(), &info)?;
     check_funds_are_empty(&info)?;
     let mut asset_definition = load_asset_definition_by_type_v3(deps.storage, &msg.asset_type)?;
+    asset_definition.check_not_frozen()?;
     // If the asset definition has any verifiers on it (only ever should be 1 max) with a matching
     // address to the new verifier, this request should be an update, not an add
     if asset_definition
-        .verifiers
-        .iter()
-        .any(|verifier| verifier.address == msg.verifier.address)
+        .get_verifier_detail_opt(&msg.verifier.address)
+        .is_some()
     {
         return ContractError::DuplicateVerifierProvided.to_err();
     }
+    asset_definition.check_verifier_count(asset_definition.verifiers.len() + 1)?;
     // Declare all attributes before values are moved
     let attributes = EventAttributes::new(EventType::AddAssetVerifier)
         .set_asset_type(&asset_definition.asset_type)
@@ -106,18 +114,20 @@ pub fn add_asset_verifier(
 
 #[cfg(test)]
 mod tests {
-    use crate::contract::execute;
     use crate::core::error::ContractError;
-    use crate::core::msg::ExecuteMsg;
-    use crate::core::state::load_asset_definition_by_type_v3;
+    use crate::core::state::{load_asset_definition_by_type_v3, replace_asset_definition_v3};
     use crate::core::types::fee_destination::FeeDestinationV2;
     use crate::core::types::verifier_detail::VerifierDetailV2;
     use crate::execute::add_asset_verifier::{add_asset_verifier, AddAssetVerifierV1};
+    use crate::testutil::execute_utilities::{
+        execute_add_asset_verifier, execute_freeze_asset_definition,
+    };
     use crate::testutil::test_constants::{
         DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS,
     };
     use crate::testutil::test_utilities::{
-        get_default_entity_detail, single_attribute_for_key, test_instantiate_success, InstArgs,
+        assert_response_attributes_contain, empty_mock_info, get_default_entity_detail,
+        get_default_verifier_detail, test_instantiate_success, InstArgs, MockOwnedDeps,
     };
     use crate::util::constants::{
         ASSET_EVENT_TYPE_KEY, ASSET_TYPE_KEY, NHASH, VERIFIER_ADDRESS_KEY,
@@ -138,14 +148,12 @@ mod tests {
         let mut deps = mock_provenance_dependencies();
         test_instantiate_success(deps.as_mut(), &InstArgs::default());
         let verifier = get_valid_new_verifier();
-        let response = execute(
+        let response = execute_add_asset_verifier(
             deps.as_mut(),
             mock_env(),
             message_info(&Addr::unchecked(DEFAULT_ADMIN_ADDRESS), &[]),
-            ExecuteMsg::AddAssetVerifier {
-                asset_type: DEFAULT_ASSET_TYPE.to_string(),
-                verifier: verifier.clone(),
-            },
+            DEFAULT_ASSET_TYPE,
+            verifier.clone(),
         )
         .expect("expected the add verifier function to execute properly");
         assert!(
@@ -157,21 +165,13 @@ mod tests {
             response.attributes.len(),
             "adding an asset verifier should produce the correct number of attributes",
         );
-        assert_eq!(
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
             EventType::AddAssetVerifier.event_name().as_str(),
-            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
-            "expected the correct event type to be emitted",
-        );
-        assert_eq!(
-            DEFAULT_ASSET_TYPE,
-            single_attribute_for_key(&response, ASSET_TYPE_KEY),
-            "expected the default asset type to be used for the main add key",
-        );
-        assert_eq!(
-            &verifier.address,
-            single_attribute_for_key(&response, VERIFIER_ADDRESS_KEY),
-            "expected the new verifier's address to be emitted as an attribute",
         );
+        assert_response_attributes_contain(&response, ASSET_TYPE_KEY, DEFAULT_ASSET_TYPE);
+        assert_response_attributes_contain(&response, VERIFIER_ADDRESS_KEY, &verifier.address);
         test_default_verifier_was_added(&verifier, &deps.as_ref());
     }
 
@@ -193,19 +193,17 @@ mod tests {
     fn test_invalid_add_asset_verifier_for_invalid_asset_type() {
         let mut deps = mock_provenance_dependencies();
         test_instantiate_success(deps.as_mut(), &InstArgs::default());
-        let error = execute(
+        let error = execute_add_asset_verifier(
             deps.as_mut(),
             mock_env(),
             message_info(&Addr::unchecked(DEFAULT_ADMIN_ADDRESS), &[]),
-            ExecuteMsg::AddAssetVerifier {
-                // Invalid because the asset type is missing
-                asset_type: String::new(),
-                verifier: get_valid_new_verifier(),
-            },
+            // Invalid because the asset type is missing
+            String::new(),
+            get_valid_new_verifier(),
         )
         .unwrap_err();
         assert!(
-            matches!(error, ContractError::InvalidMessageFields { .. }),
+            error.is_invalid_message_fields(),
             "when an invalid asset type is provided to execute, the invalid message fields error should be returned, but got: {:?}",
             error,
         );
@@ -215,27 +213,17 @@ mod tests {
     fn test_invalid_add_asset_verifier_for_invalid_msg() {
         let mut deps = mock_provenance_dependencies();
         test_instantiate_success(deps.as_mut(), &InstArgs::default());
-        let error = execute(
+        let error = execute_add_asset_verifier(
             deps.as_mut(),
             mock_env(),
             message_info(&Addr::unchecked(DEFAULT_ADMIN_ADDRESS), &[]),
-            ExecuteMsg::AddAssetVerifier {
-                asset_type: DEFAULT_ASSET_TYPE.to_string(),
-                // Invalid because the address is blank
-                verifier: VerifierDetailV2::new(
-                    "",
-                    Uint128::zero(),
-                    NHASH,
-                    vec![],
-                    None,
-                    None,
-                    None,
-                ),
-            },
+            DEFAULT_ASSET_TYPE,
+            // Invalid because the address is blank
+            VerifierDetailV2::new("", Uint128::zero(), NHASH, vec![], None, None, None),
         )
         .unwrap_err();
         assert!(
-            matches!(error, ContractError::InvalidMessageFields { .. }),
+            error.is_invalid_message_fields(),
             "when an invalid verifier is provided to execute, the invalid message fields error should be returned, but got: {:?}",
             error,
         );
@@ -252,7 +240,7 @@ mod tests {
         )
         .unwrap_err();
         assert!(
-            matches!(error, ContractError::Unauthorized { .. }),
+            error.is_unauthorized(),
             "expected the unauthorized response to be returned when a different address than the admin is the sender, but got: {:?}",
             error,
         );
@@ -306,6 +294,114 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_add_asset_verifier_respects_max_verifiers() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        set_max_verifiers(&mut deps, 2.to_some());
+        add_asset_verifier(
+            deps.as_mut(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN_ADDRESS), &[]),
+            get_add_verifier(),
+        )
+        .expect("expected a verifier to be added while the definition is below its maximum");
+        let error = add_asset_verifier(
+            deps.as_mut(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN_ADDRESS), &[]),
+            get_third_verifier(),
+        )
+        .unwrap_err();
+        match error {
+            ContractError::MaxVerifiersExceeded { asset_type, limit } => {
+                assert_eq!(
+                    DEFAULT_ASSET_TYPE, asset_type,
+                    "the error should reference the asset type",
+                );
+                assert_eq!(2, limit, "the error should reference the maximum");
+            }
+            e => panic!("unexpected error encountered: {:?}", e),
+        };
+        assert_eq!(
+            2,
+            load_asset_definition_by_type_v3(deps.as_ref().storage, DEFAULT_ASSET_TYPE)
+                .expect("expected the default asset type to be stored in the state")
+                .verifiers
+                .len(),
+            "the rejected verifier should not be stored",
+        );
+    }
+
+    #[test]
+    fn test_add_asset_verifier_without_max_verifiers_is_unlimited() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        set_max_verifiers(&mut deps, None);
+        for msg in [get_add_verifier(), get_third_verifier()] {
+            add_asset_verifier(
+                deps.as_mut(),
+                message_info(&Addr::unchecked(DEFAULT_ADMIN_ADDRESS), &[]),
+                msg,
+            )
+            .expect("expected verifiers to be added when no maximum is set");
+        }
+        assert_eq!(
+            3,
+            load_asset_definition_by_type_v3(deps.as_ref().storage, DEFAULT_ASSET_TYPE)
+                .expect("expected the default asset type to be stored in the state")
+                .verifiers
+                .len(),
+            "every added verifier should be stored",
+        );
+    }
+
+    #[test]
+    fn test_invalid_add_asset_verifier_for_frozen_definition() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        execute_freeze_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+        )
+        .expect("expected the asset definition to be frozen");
+        let error = execute_add_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            VerifierDetailV2 {
+                address: TEST_VERIFIER_ADDRESS.to_string(),
+                ..get_default_verifier_detail()
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::AssetDefinitionFrozen { ref asset_type } if asset_type == DEFAULT_ASSET_TYPE),
+            "expected the frozen error to be returned when adding a verifier to a frozen definition, but got: {:?}",
+            error,
+        );
+    }
+
+    fn set_max_verifiers(deps: &mut MockOwnedDeps, max_verifiers: Option<u32>) {
+        let mut definition =
+            load_asset_definition_by_type_v3(deps.as_ref().storage, DEFAULT_ASSET_TYPE)
+                .expect("expected the default asset type to be stored in the state");
+        definition.max_verifiers = max_verifiers;
+        replace_asset_definition_v3(deps.as_mut().storage, &definition)
+            .expect("expected the default asset definition to be replaced");
+    }
+
+    fn get_third_verifier() -> AddAssetVerifierV1 {
+        AddAssetVerifierV1::new(
+            DEFAULT_ASSET_TYPE,
+            VerifierDetailV2 {
+                address: TEST_FEE_ADDRESS.to_string(),
+                ..get_valid_new_verifier()
+            },
+        )
+    }
+
     // Checks that the verifier passed in was added to the default asset type's definition
     fn test_default_verifier_was_added(verifier: &VerifierDetailV2, deps: &Deps) {
         let state_def = load_asset_definition_by_type_v3(deps.storage, DEFAULT_ASSET_TYPE)
@@ -328,7 +424,8 @@ mod tests {
             None,
             None,
         );
-        validate_verifier(&verifier).expect("expected the new verifier to pass validation");
+        validate_verifier(&verifier, false, false)
+            .expect("expected the new verifier to pass validation");
         verifier
     }
 
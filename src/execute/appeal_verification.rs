@@ -0,0 +1,434 @@
+use crate::core::error::ContractError;
+use crate::core::msg::ExecuteMsg;
+use crate::core::state::update_verifier_status_index;
+use crate::core::types::asset_identifier::AssetIdentifier;
+use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+use crate::service::asset_meta_repository::AssetMetaRepository;
+use crate::service::deps_manager::DepsManager;
+use crate::service::message_gathering_service::MessageGatheringService;
+use crate::util::aliases::{AssetResult, EntryPointResponse};
+use crate::util::contract_helpers::check_funds_are_empty;
+use crate::util::event_attributes::{EventAttributes, EventType};
+
+use cosmwasm_std::{Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// A transformation of [ExecuteMsg::AppealVerification](crate::core::msg::ExecuteMsg::AppealVerification)
+/// for ease of use in the underlying [appeal_verification](self::appeal_verification) function.
+///
+/// # Parameters
+///
+/// * `identifier` An instance of the asset identifier enum that helps the contract identify which
+/// scope was denied.
+/// * `asset_type` The asset type of the denied verification.
+/// * `appeal_reason` The requestor's explanation of why the denial should be overturned.
+#[derive(Clone, PartialEq, Eq)]
+pub struct AppealVerificationV1 {
+    pub identifier: AssetIdentifier,
+    pub asset_type: String,
+    pub appeal_reason: String,
+}
+impl AppealVerificationV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `identifier` An instance of the asset identifier enum that helps the contract identify which
+    /// scope was denied.
+    /// * `asset_type` The asset type of the denied verification.
+    /// * `appeal_reason` The requestor's explanation of why the denial should be overturned.
+    pub fn new<S1: Into<String>, S2: Into<String>>(
+        identifier: AssetIdentifier,
+        asset_type: S1,
+        appeal_reason: S2,
+    ) -> Self {
+        AppealVerificationV1 {
+            identifier,
+            asset_type: asset_type.into(),
+            appeal_reason: appeal_reason.into(),
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [AppealVerification](crate::core::msg::ExecuteMsg::AppealVerification)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<AppealVerificationV1> {
+        match msg {
+            ExecuteMsg::AppealVerification {
+                identifier,
+                asset_type,
+                appeal_reason,
+            } => Self::new(identifier.to_asset_identifier()?, asset_type, appeal_reason).to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::AppealVerification".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+impl TryFrom<ExecuteMsg> for AppealVerificationV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        AppealVerificationV1::from_execute_msg(msg)
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::AppealVerification](crate::core::msg::ExecuteMsg::AppealVerification)
+/// message is provided.  Moves a [Denied](crate::core::types::asset_onboarding_status::AssetOnboardingStatus::Denied)
+/// asset to the [Appealing](crate::core::types::asset_onboarding_status::AssetOnboardingStatus::Appealing)
+/// status, as long as the block height has not passed its [appeal_deadline_block](crate::core::types::asset_scope_attribute::AssetScopeAttribute::appeal_deadline_block).
+/// The appeal is then resolved by the contract admin via [ResolveAppeal](crate::core::msg::ExecuteMsg::ResolveAppeal).
+///
+/// # Parameters
+///
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `repository` A helper collection of traits that allows complex lookups of scope values and
+/// emits messages to construct the process of appealing as a collection of messages to produce in
+/// the function's result.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the appeal verification v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn appeal_verification<'a, T>(
+    env: &Env,
+    repository: T,
+    info: MessageInfo,
+    msg: AppealVerificationV1,
+) -> EntryPointResponse
+where
+    T: AssetMetaRepository + MessageGatheringService + DepsManager<'a>,
+{
+    check_funds_are_empty(&info)?;
+    let scope_address = msg.identifier.get_scope_address()?;
+    let scope_attribute = repository.get_asset_by_asset_type(&scope_address, &msg.asset_type)?;
+    if info.sender != scope_attribute.requestor_address {
+        return ContractError::Unauthorized {
+            explanation: format!(
+                "only the requestor [{}] may appeal the verification of asset [{}] with asset type [{}]",
+                scope_attribute.requestor_address, scope_address, msg.asset_type,
+            ),
+        }
+        .to_err();
+    }
+    if scope_attribute.onboarding_status != AssetOnboardingStatus::Denied {
+        return ContractError::UnexpectedState {
+            explanation: format!(
+                "asset [{}] with asset type [{}] has status [{}], but only denied assets can be appealed",
+                scope_address, msg.asset_type, scope_attribute.onboarding_status,
+            ),
+        }
+        .to_err();
+    }
+    match scope_attribute.appeal_deadline_block {
+        Some(deadline_block) if env.block.height <= deadline_block => {}
+        Some(deadline_block) => {
+            return ContractError::UnexpectedState {
+                explanation: format!(
+                    "the appeal window for asset [{}] with asset type [{}] closed after block [{}]",
+                    scope_address, msg.asset_type, deadline_block,
+                ),
+            }
+            .to_err();
+        }
+        None => {
+            return ContractError::UnexpectedState {
+                explanation: format!(
+                    "the denial of asset [{}] with asset type [{}] cannot be appealed",
+                    scope_address, msg.asset_type,
+                ),
+            }
+            .to_err();
+        }
+    }
+    let mut appealing_attribute = scope_attribute.clone();
+    appealing_attribute.onboarding_status = AssetOnboardingStatus::Appealing;
+    // A denial can only be appealed once
+    appealing_attribute.appeal_deadline_block = None;
+    repository.update_attribute(env, &appealing_attribute)?;
+    repository.use_deps(|deps| {
+        update_verifier_status_index(deps.storage, Some(&scope_attribute), &appealing_attribute)
+    })?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::for_asset_event(
+                EventType::VerificationAppealed,
+                &msg.asset_type,
+                &scope_address,
+            )
+            .set_verifier(&scope_attribute.verifier_address)
+            .set_new_asset_onboarding_status(&appealing_attribute.onboarding_status)
+            .set_new_value(&msg.appeal_reason),
+        )
+        .add_messages(repository.get_messages())
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::Env;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::types::asset_identifier::AssetIdentifier;
+    use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+    use crate::core::types::asset_scope_attribute::AssetScopeAttribute;
+    use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::service::asset_meta_repository::AssetMetaRepository;
+    use crate::service::asset_meta_service::AssetMetaService;
+    use crate::testutil::execute_utilities::execute_appeal_verification;
+    use crate::testutil::msg_utilities::test_no_money_moved_in_response;
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS, DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, empty_mock_info, get_default_verifier_detail,
+        intercept_add_or_update_attribute, setup_no_attribute_response, setup_test_suite, InstArgs,
+        MockOwnedDeps,
+    };
+    use crate::testutil::verify_asset_helpers::{test_verify_asset, TestVerifyAsset};
+    use crate::util::aliases::EntryPointResponse;
+    use crate::util::constants::{
+        ASSET_EVENT_TYPE_KEY, ASSET_SCOPE_ADDRESS_KEY, ASSET_TYPE_KEY,
+        NEW_ASSET_ONBOARDING_STATUS_KEY, NEW_VALUE_KEY, VERIFIER_ADDRESS_KEY,
+    };
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    const APPEAL_WINDOW_BLOCKS: u64 = 50;
+    const APPEAL_REASON: &str = "the verifier reviewed the wrong records";
+
+    #[test]
+    fn test_denial_populates_appeal_deadline_block() {
+        let mut deps = mock_provenance_dependencies();
+        setup_denied_asset(&mut deps, APPEAL_WINDOW_BLOCKS.to_some());
+        assert_eq!(
+            (mock_env().block.height + APPEAL_WINDOW_BLOCKS).to_some(),
+            get_default_attribute(&mut deps).appeal_deadline_block,
+            "the appeal deadline should be derived from the denial block height and the verifier's window",
+        );
+    }
+
+    #[test]
+    fn test_approval_does_not_populate_appeal_deadline_block() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps, APPEAL_WINDOW_BLOCKS.to_some());
+        test_verify_asset(
+            &mut deps,
+            &mock_env(),
+            TestVerifyAsset::default_with_success(true),
+        )
+        .expect("approving the asset should succeed");
+        assert_eq!(
+            None,
+            get_default_attribute(&mut deps).appeal_deadline_block,
+            "approved assets should have no appeal deadline",
+        );
+    }
+
+    #[test]
+    fn test_appeal_within_window_moves_asset_to_appealing() {
+        let mut deps = mock_provenance_dependencies();
+        setup_denied_asset(&mut deps, APPEAL_WINDOW_BLOCKS.to_some());
+        let response = appeal(
+            &mut deps,
+            &env_at_offset(APPEAL_WINDOW_BLOCKS),
+            DEFAULT_SENDER_ADDRESS,
+        )
+        .expect("appealing at the deadline block should succeed");
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::VerificationAppealed.event_name(),
+        );
+        assert_response_attributes_contain(&response, ASSET_TYPE_KEY, DEFAULT_ASSET_TYPE);
+        assert_response_attributes_contain(
+            &response,
+            ASSET_SCOPE_ADDRESS_KEY,
+            DEFAULT_SCOPE_ADDRESS,
+        );
+        assert_response_attributes_contain(
+            &response,
+            VERIFIER_ADDRESS_KEY,
+            DEFAULT_VERIFIER_ADDRESS,
+        );
+        assert_response_attributes_contain(
+            &response,
+            NEW_ASSET_ONBOARDING_STATUS_KEY,
+            AssetOnboardingStatus::Appealing.to_string().as_str(),
+        );
+        assert_response_attributes_contain(&response, NEW_VALUE_KEY, APPEAL_REASON);
+        test_no_money_moved_in_response(&response, "appealing should not move any funds");
+        intercept_add_or_update_attribute(
+            &mut deps,
+            response,
+            "the appeal should update the attribute",
+        )
+        .expect("the attribute update should be intercepted");
+        let attribute = get_default_attribute(&mut deps);
+        assert_eq!(
+            AssetOnboardingStatus::Appealing,
+            attribute.onboarding_status,
+            "the asset should be appealing after the appeal is filed",
+        );
+        assert_eq!(
+            None, attribute.appeal_deadline_block,
+            "the appeal deadline should be cleared once the appeal is filed",
+        );
+        let err = test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect_err("an appealing asset should not be retried");
+        assert!(
+            matches!(err, ContractError::AssetAppealPending { .. }),
+            "expected an asset appeal pending error, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_appeal_after_window_fails() {
+        let mut deps = mock_provenance_dependencies();
+        setup_denied_asset(&mut deps, APPEAL_WINDOW_BLOCKS.to_some());
+        let err = appeal(
+            &mut deps,
+            &env_at_offset(APPEAL_WINDOW_BLOCKS + 1),
+            DEFAULT_SENDER_ADDRESS,
+        )
+        .expect_err("appealing after the deadline block should fail");
+        match err {
+            ContractError::UnexpectedState { explanation } => {
+                assert_eq!(
+                    format!(
+                        "the appeal window for asset [{}] with asset type [{}] closed after block [{}]",
+                        DEFAULT_SCOPE_ADDRESS,
+                        DEFAULT_ASSET_TYPE,
+                        mock_env().block.height + APPEAL_WINDOW_BLOCKS,
+                    ),
+                    explanation,
+                    "the error should describe the appeal deadline",
+                );
+            }
+            _ => panic!("unexpected error encountered: {:?}", err),
+        };
+        assert_asset_still_denied(&mut deps);
+    }
+
+    #[test]
+    fn test_appeal_without_verifier_window_fails() {
+        let mut deps = mock_provenance_dependencies();
+        setup_denied_asset(&mut deps, None);
+        let err = appeal(&mut deps, &mock_env(), DEFAULT_SENDER_ADDRESS)
+            .expect_err("appealing a denial from a verifier without an appeal window should fail");
+        assert!(
+            matches!(err, ContractError::UnexpectedState { .. }),
+            "expected an unexpected state error, but got: {:?}",
+            err,
+        );
+        assert_asset_still_denied(&mut deps);
+    }
+
+    #[test]
+    fn test_appeal_by_non_requestor_fails() {
+        for sender in [
+            DEFAULT_VERIFIER_ADDRESS,
+            "tp1hsqtppgy7mgd64q0uvk7q39qa7h8hp30urqs6n",
+        ] {
+            let mut deps = mock_provenance_dependencies();
+            setup_denied_asset(&mut deps, APPEAL_WINDOW_BLOCKS.to_some());
+            let err = appeal(&mut deps, &mock_env(), sender)
+                .expect_err("only the requestor should be able to appeal a denial");
+            match err {
+                ContractError::Unauthorized { explanation } => {
+                    assert_eq!(
+                        format!(
+                            "only the requestor [{}] may appeal the verification of asset [{}] with asset type [{}]",
+                            DEFAULT_SENDER_ADDRESS, DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE,
+                        ),
+                        explanation,
+                        "the error should describe the required requestor",
+                    );
+                }
+                _ => panic!("unexpected error when [{}] appeals: {:?}", sender, err),
+            };
+            assert_asset_still_denied(&mut deps);
+        }
+    }
+
+    #[test]
+    fn test_appeal_of_pending_asset_fails() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps, APPEAL_WINDOW_BLOCKS.to_some());
+        let err = appeal(&mut deps, &mock_env(), DEFAULT_SENDER_ADDRESS)
+            .expect_err("appealing an asset that has not been denied should fail");
+        assert!(
+            matches!(err, ContractError::UnexpectedState { .. }),
+            "expected an unexpected state error, but got: {:?}",
+            err,
+        );
+        assert_eq!(
+            AssetOnboardingStatus::Pending,
+            get_default_attribute(&mut deps).onboarding_status,
+            "the asset should remain pending",
+        );
+    }
+
+    fn setup_onboarded_asset(deps: &mut MockOwnedDeps, appeal_window_blocks: Option<u64>) {
+        setup_test_suite(
+            deps,
+            &InstArgs::with_single_verifier(VerifierDetailV2 {
+                appeal_window_blocks,
+                ..get_default_verifier_detail()
+            }),
+        );
+        setup_no_attribute_response(deps, None);
+        test_onboard_asset(deps, TestOnboardAsset::default()).expect("onboarding should succeed");
+    }
+
+    fn setup_denied_asset(deps: &mut MockOwnedDeps, appeal_window_blocks: Option<u64>) {
+        setup_onboarded_asset(deps, appeal_window_blocks);
+        test_verify_asset(
+            deps,
+            &mock_env(),
+            TestVerifyAsset::default_with_success(false),
+        )
+        .expect("denying the asset should succeed");
+    }
+
+    fn env_at_offset(blocks: u64) -> Env {
+        let mut env = mock_env();
+        env.block.height += blocks;
+        env
+    }
+
+    fn appeal(deps: &mut MockOwnedDeps, env: &Env, sender: &str) -> EntryPointResponse {
+        execute_appeal_verification(
+            deps.as_mut(),
+            env.to_owned(),
+            empty_mock_info(sender),
+            AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            APPEAL_REASON,
+        )
+    }
+
+    fn get_default_attribute(deps: &mut MockOwnedDeps) -> AssetScopeAttribute {
+        AssetMetaService::new(deps.as_mut())
+            .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+            .expect("the scope attribute should exist")
+    }
+
+    fn assert_asset_still_denied(deps: &mut MockOwnedDeps) {
+        assert_eq!(
+            AssetOnboardingStatus::Denied,
+            get_default_attribute(deps).onboarding_status,
+            "the asset should remain denied",
+        );
+    }
+}
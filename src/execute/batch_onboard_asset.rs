@@ -0,0 +1,515 @@
+use crate::core::error::ContractError;
+use crate::core::msg::ExecuteMsg;
+use crate::execute::onboard_asset::{onboard_asset, OnboardAssetV1};
+use crate::service::asset_meta_service::AssetMetaService;
+use crate::util::aliases::{AssetResult, EntryPointResponse};
+use crate::util::contract_helpers::relay_os_gateway_attributes;
+use crate::util::event_attributes::{EventAttributes, EventType};
+use cosmwasm_std::{DepsMut, Env, Event, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// A transformation of [ExecuteMsg::BatchOnboardAsset](crate::core::msg::ExecuteMsg::BatchOnboardAsset)
+/// for ease of use in the underlying [batch_onboard_asset](self::batch_onboard_asset) function.
+///
+/// # Parameters
+///
+/// * `entries` Each scope to onboard, converted to the same [OnboardAssetV1](crate::execute::onboard_asset::OnboardAssetV1)
+/// struct used by the single-scope onboarding route.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BatchOnboardAssetV1 {
+    pub entries: Vec<OnboardAssetV1>,
+}
+impl BatchOnboardAssetV1 {
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [BatchOnboardAsset](crate::core::msg::ExecuteMsg::BatchOnboardAsset)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<BatchOnboardAssetV1> {
+        match msg {
+            ExecuteMsg::BatchOnboardAsset { entries } => BatchOnboardAssetV1 {
+                entries: entries
+                    .into_iter()
+                    .map(|entry| {
+                        OnboardAssetV1 {
+                            identifier: entry.identifier.to_asset_identifier()?,
+                            asset_type: entry.asset_type,
+                            verifier_address: entry.verifier_address,
+                            access_routes: entry.access_routes.unwrap_or_default(),
+                            add_os_gateway_permission: entry
+                                .add_os_gateway_permission
+                                .unwrap_or(true),
+                            expected_fee: entry.expected_fee,
+                            memo: entry.memo,
+                            metadata: entry.metadata,
+                        }
+                        .to_ok()
+                    })
+                    .collect::<AssetResult<Vec<OnboardAssetV1>>>()?,
+            }
+            .to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::BatchOnboardAsset".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+impl TryFrom<ExecuteMsg> for BatchOnboardAssetV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        BatchOnboardAssetV1::from_execute_msg(msg)
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::BatchOnboardAsset](crate::core::msg::ExecuteMsg::BatchOnboardAsset)
+/// message is provided.  Runs each entry through the [onboard_asset](crate::execute::onboard_asset::onboard_asset)
+/// function in the order provided, combining all produced messages into a single response.  The
+/// attributes produced for each entry are emitted in their own [onboard_asset](crate::util::event_attributes::EventType::OnboardAsset)
+/// event, except for any object store gateway access grant, which is relayed via the
+/// [RelayOsGatewayAccess](crate::core::msg::ExecuteMsg::RelayOsGatewayAccess) route so that the
+/// gateway can read it from its own event.  If any entry fails to onboard, its error is returned, which causes the entire
+/// transaction, including the storage changes made for previous entries, to be reverted.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the batch onboard asset v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn batch_onboard_asset(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: BatchOnboardAssetV1,
+) -> EntryPointResponse {
+    let entry_count = msg.entries.len();
+    let mut response = Response::new().add_attributes(
+        EventAttributes::new(EventType::BatchOnboardAsset).set_new_value(entry_count),
+    );
+    for entry in msg.entries {
+        let entry_response = onboard_asset(
+            AssetMetaService::new(deps.branch()),
+            env.clone(),
+            info.clone(),
+            entry,
+        )?;
+        let (entry_attributes, access_relay_msg) =
+            relay_os_gateway_attributes(&env, entry_response.attributes)?;
+        response = response
+            .add_submessages(entry_response.messages)
+            .add_messages(access_relay_msg)
+            .add_event(
+                Event::new(EventType::OnboardAsset.event_name()).add_attributes(entry_attributes),
+            )
+            .add_events(entry_response.events);
+    }
+    response.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::Uint128;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::load_fee_payment_detail;
+    use crate::core::types::access_route::AccessRoute;
+    use crate::core::types::asset_identifier::AssetIdentifier;
+    use crate::core::types::batch_onboard_asset::BatchOnboardAssetEntry;
+    use crate::core::types::os_gateway_access::OsGatewayAccess;
+    use crate::testutil::execute_utilities::execute_batch_onboard_asset;
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS, DEFAULT_SECONDARY_ASSET_TYPE,
+        DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, empty_mock_info, get_relayed_os_gateway_access,
+        setup_no_attribute_response, setup_test_suite, InstArgs,
+    };
+    use crate::util::constants::{
+        ASSET_EVENT_TYPE_KEY, MAX_BATCH_ONBOARD_SIZE, MAX_ONBOARDING_MEMO_LENGTH, NEW_VALUE_KEY,
+        ONBOARDING_MEMO_KEY,
+    };
+    use crate::util::event_attributes::EventType;
+    use crate::util::functions::{generate_os_gateway_grant_id, try_into_add_attribute_request};
+    use crate::util::traits::OptionExtensions;
+
+    use super::BatchOnboardAssetV1;
+
+    fn default_entry(asset_type: &str) -> BatchOnboardAssetEntry {
+        BatchOnboardAssetEntry::new(
+            AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS).to_serialized_enum(),
+            asset_type,
+            DEFAULT_VERIFIER_ADDRESS,
+            None,
+            Some(false),
+        )
+    }
+
+    #[test]
+    fn test_from_execute_msg_applies_entry_defaults() {
+        let msg = BatchOnboardAssetV1::from_execute_msg(ExecuteMsg::BatchOnboardAsset {
+            entries: vec![BatchOnboardAssetEntry::new(
+                AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS).to_serialized_enum(),
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS,
+                None,
+                None,
+            )],
+        })
+        .expect("conversion should succeed for a batch onboard message");
+        let entry = msg
+            .entries
+            .first()
+            .expect("a single entry should be converted");
+        assert!(
+            entry.access_routes.is_empty(),
+            "missing access routes should default to an empty vector",
+        );
+        assert!(
+            entry.add_os_gateway_permission,
+            "missing os gateway permission flag should default to true",
+        );
+    }
+
+    #[test]
+    fn test_from_execute_msg_preserves_optional_entry_values() {
+        let msg = BatchOnboardAssetV1::from_execute_msg(ExecuteMsg::BatchOnboardAsset {
+            entries: vec![BatchOnboardAssetEntry {
+                expected_fee: Uint128::new(100).to_some(),
+                memo: "batch memo".to_string().to_some(),
+                metadata: "{\"loan\": 1}".to_string().to_some(),
+                ..default_entry(DEFAULT_ASSET_TYPE)
+            }],
+        })
+        .expect("conversion should succeed for a batch onboard message");
+        let entry = msg
+            .entries
+            .first()
+            .expect("a single entry should be converted");
+        assert_eq!(
+            Some(Uint128::new(100)),
+            entry.expected_fee,
+            "the entry's expected fee should be preserved",
+        );
+        assert_eq!(
+            Some("batch memo"),
+            entry.memo.as_deref(),
+            "the entry's memo should be preserved",
+        );
+        assert_eq!(
+            Some("{\"loan\": 1}"),
+            entry.metadata.as_deref(),
+            "the entry's metadata should be preserved",
+        );
+    }
+
+    #[test]
+    fn test_batch_onboard_asset_success() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(
+            &mut deps,
+            &InstArgs::default_with_additional_asset_types(vec![DEFAULT_SECONDARY_ASSET_TYPE]),
+        );
+        setup_no_attribute_response(&mut deps, None);
+        let response = execute_batch_onboard_asset(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+            vec![
+                default_entry(DEFAULT_ASSET_TYPE),
+                default_entry(DEFAULT_SECONDARY_ASSET_TYPE),
+            ],
+        )
+        .expect("batch onboarding should succeed for valid entries");
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::BatchOnboardAsset.event_name(),
+        );
+        assert_response_attributes_contain(&response, NEW_VALUE_KEY, "2");
+        assert_eq!(
+            2,
+            response
+                .messages
+                .iter()
+                .filter(|msg| try_into_add_attribute_request(&msg.msg).is_some())
+                .count(),
+            "an attribute should be added for each entry in the batch",
+        );
+        assert_eq!(
+            2,
+            response
+                .events
+                .iter()
+                .filter(|event| event.ty == EventType::OnboardAsset.event_name())
+                .count(),
+            "an onboard asset event should be emitted for each entry in the batch",
+        );
+        for asset_type in [DEFAULT_ASSET_TYPE, DEFAULT_SECONDARY_ASSET_TYPE] {
+            load_fee_payment_detail(deps.as_ref().storage, DEFAULT_SCOPE_ADDRESS, asset_type)
+                .expect("a fee payment detail should be stored for each entry");
+        }
+    }
+
+    #[test]
+    fn test_batch_onboard_asset_relays_gateway_grants() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(
+            &mut deps,
+            &InstArgs::default_with_additional_asset_types(vec![DEFAULT_SECONDARY_ASSET_TYPE]),
+        );
+        setup_no_attribute_response(&mut deps, None);
+        let response = execute_batch_onboard_asset(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+            vec![
+                BatchOnboardAssetEntry {
+                    add_os_gateway_permission: None,
+                    ..default_entry(DEFAULT_ASSET_TYPE)
+                },
+                default_entry(DEFAULT_SECONDARY_ASSET_TYPE),
+            ],
+        )
+        .expect("batch onboarding should succeed for valid entries");
+        assert_eq!(
+            vec![OsGatewayAccess::grant(
+                DEFAULT_SCOPE_ADDRESS,
+                DEFAULT_VERIFIER_ADDRESS,
+                generate_os_gateway_grant_id(None, DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS),
+            )],
+            get_relayed_os_gateway_access(&response),
+            "only the entry that requested gateway permission should have its grant relayed",
+        );
+        assert!(
+            response
+                .events
+                .iter()
+                .flat_map(|event| event.attributes.iter())
+                .all(|attribute| !OsGatewayAccess::is_gateway_key(&attribute.key)),
+            "gateway attributes should not be emitted in events that the gateway does not read",
+        );
+    }
+
+    #[test]
+    fn test_batch_onboard_asset_applies_entry_memo_and_expected_fee() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        let err = execute_batch_onboard_asset(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+            vec![BatchOnboardAssetEntry {
+                expected_fee: Uint128::new(1).to_some(),
+                ..default_entry(DEFAULT_ASSET_TYPE)
+            }],
+        )
+        .expect_err("an entry whose expected fee does not match should be rejected");
+        assert!(
+            matches!(err, ContractError::FeeSlippage { .. }),
+            "the entry's expected fee should be checked, but got: {:?}",
+            err,
+        );
+        let response = execute_batch_onboard_asset(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+            vec![BatchOnboardAssetEntry {
+                memo: "batch memo".to_string().to_some(),
+                ..default_entry(DEFAULT_ASSET_TYPE)
+            }],
+        )
+        .expect("batch onboarding should succeed with a memo");
+        assert!(
+            response
+                .events
+                .iter()
+                .filter(|event| event.ty == EventType::OnboardAsset.event_name())
+                .flat_map(|event| event.attributes.iter())
+                .any(|attribute| attribute.key == ONBOARDING_MEMO_KEY
+                    && attribute.value == "batch memo"),
+            "the entry's memo should be emitted in its onboard asset event",
+        );
+    }
+
+    #[test]
+    fn test_batch_onboard_asset_rejects_oversized_entry_memo() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        let err = execute_batch_onboard_asset(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+            vec![BatchOnboardAssetEntry {
+                memo: "a".repeat(MAX_ONBOARDING_MEMO_LENGTH + 1).to_some(),
+                ..default_entry(DEFAULT_ASSET_TYPE)
+            }],
+        )
+        .expect_err("an entry with an oversized memo should be rejected");
+        match err {
+            ContractError::InvalidMessageFields { invalid_fields, .. } => assert_eq!(
+                vec![format!(
+                    "entries[0]:memo: must not exceed {} characters",
+                    MAX_ONBOARDING_MEMO_LENGTH,
+                )],
+                invalid_fields,
+                "only the entry's memo should be reported as invalid",
+            ),
+            _ => panic!("unexpected error for an oversized memo: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn test_batch_onboard_asset_rejects_entry_access_routes_exceeding_limit() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(
+            &mut deps,
+            &InstArgs {
+                max_access_routes_per_owner: 1.to_some(),
+                ..InstArgs::default_with_additional_asset_types(vec![DEFAULT_SECONDARY_ASSET_TYPE])
+            },
+        );
+        setup_no_attribute_response(&mut deps, None);
+        let err = execute_batch_onboard_asset(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+            vec![
+                default_entry(DEFAULT_ASSET_TYPE),
+                BatchOnboardAssetEntry {
+                    access_routes: vec![
+                        AccessRoute::route_only("grpcs://route0.fake:1234"),
+                        AccessRoute::route_only("grpcs://route1.fake:1234"),
+                    ]
+                    .to_some(),
+                    ..default_entry(DEFAULT_SECONDARY_ASSET_TYPE)
+                },
+            ],
+        )
+        .expect_err("an entry with more access routes than the limit should be rejected");
+        assert!(
+            matches!(
+                err,
+                ContractError::AccessRouteLimitExceeded { ref owner, limit: 1 } if owner == DEFAULT_SENDER_ADDRESS,
+            ),
+            "the entry's access routes should be checked against the limit, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_batch_onboard_asset_fails_when_any_entry_fails() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        let err = execute_batch_onboard_asset(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+            vec![default_entry(DEFAULT_ASSET_TYPE), default_entry("bogus")],
+        )
+        .expect_err("batch onboarding should fail when any entry fails");
+        assert!(
+            matches!(err, ContractError::UnsupportedAssetType { ref asset_type } if asset_type == "bogus"),
+            "the failing entry's error should be returned, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_batch_onboard_asset_rejects_empty_batch() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        let err = execute_batch_onboard_asset(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+            vec![],
+        )
+        .expect_err("an empty batch should be rejected");
+        assert!(
+            matches!(err, ContractError::InvalidMessageFields { .. }),
+            "an empty batch should fail validation, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_batch_onboard_asset_rejects_oversized_batch() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        let entries = (0..=MAX_BATCH_ONBOARD_SIZE)
+            .map(|index| {
+                BatchOnboardAssetEntry::new(
+                    AssetIdentifier::asset_uuid(format!(
+                        "{}",
+                        uuid::Uuid::from_u128(index as u128)
+                    ))
+                    .to_serialized_enum(),
+                    DEFAULT_ASSET_TYPE,
+                    DEFAULT_VERIFIER_ADDRESS,
+                    None,
+                    None,
+                )
+            })
+            .collect();
+        let err = execute_batch_onboard_asset(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+            entries,
+        )
+        .expect_err("a batch larger than the maximum size should be rejected");
+        match err {
+            ContractError::InvalidMessageFields { invalid_fields, .. } => assert_eq!(
+                vec![format!(
+                    "entries: no more than {} entries may be provided",
+                    MAX_BATCH_ONBOARD_SIZE
+                )],
+                invalid_fields,
+                "only the batch size should be reported as invalid",
+            ),
+            _ => panic!("unexpected error for an oversized batch: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn test_batch_onboard_asset_rejects_duplicate_entries() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        let err = execute_batch_onboard_asset(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+            vec![
+                default_entry(DEFAULT_ASSET_TYPE),
+                default_entry(DEFAULT_ASSET_TYPE),
+            ],
+        )
+        .expect_err("duplicate entries should be rejected");
+        match err {
+            ContractError::InvalidMessageFields { invalid_fields, .. } => assert_eq!(
+                vec![format!(
+                    "entries[1]: scope [{}] is already onboarded as asset type [{}] by a previous entry",
+                    DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE,
+                )],
+                invalid_fields,
+                "only the duplicate entry should be reported as invalid",
+            ),
+            _ => panic!("unexpected error for duplicate entries: {:?}", err),
+        }
+    }
+}
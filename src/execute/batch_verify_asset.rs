@@ -0,0 +1,482 @@
+use crate::core::error::ContractError;
+use crate::core::msg::ExecuteMsg;
+use crate::execute::verify_asset::{verify_asset, VerifyAssetV1};
+use crate::service::asset_meta_service::AssetMetaService;
+use crate::util::aliases::{AssetResult, EntryPointResponse};
+use crate::util::contract_helpers::relay_os_gateway_attributes;
+use crate::util::event_attributes::{EventAttributes, EventType};
+use cosmwasm_std::{DepsMut, Env, Event, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// A transformation of [ExecuteMsg::BatchVerifyAsset](crate::core::msg::ExecuteMsg::BatchVerifyAsset)
+/// for ease of use in the underlying [batch_verify_asset](self::batch_verify_asset) function.
+///
+/// # Parameters
+///
+/// * `entries` Each scope to verify, converted to the same [VerifyAssetV1](crate::execute::verify_asset::VerifyAssetV1)
+/// struct used by the single-scope verification route.
+#[derive(Clone, PartialEq, Eq)]
+pub struct BatchVerifyAssetV1 {
+    pub entries: Vec<VerifyAssetV1>,
+}
+impl BatchVerifyAssetV1 {
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [BatchVerifyAsset](crate::core::msg::ExecuteMsg::BatchVerifyAsset)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<BatchVerifyAssetV1> {
+        match msg {
+            ExecuteMsg::BatchVerifyAsset { entries } => BatchVerifyAssetV1 {
+                entries: entries
+                    .into_iter()
+                    .map(|entry| {
+                        VerifyAssetV1 {
+                            identifier: entry.identifier.to_asset_identifier()?,
+                            asset_type: entry.asset_type,
+                            success: entry.success,
+                            message: entry.message,
+                            access_routes: entry.access_routes.unwrap_or_default(),
+                        }
+                        .to_ok()
+                    })
+                    .collect::<AssetResult<Vec<VerifyAssetV1>>>()?,
+            }
+            .to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::BatchVerifyAsset".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+impl TryFrom<ExecuteMsg> for BatchVerifyAssetV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        BatchVerifyAssetV1::from_execute_msg(msg)
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::BatchVerifyAsset](crate::core::msg::ExecuteMsg::BatchVerifyAsset)
+/// message is provided.  Runs each entry through the [verify_asset](crate::execute::verify_asset::verify_asset)
+/// function in the order provided, combining all produced messages, including fee payments, into a
+/// single response.  The attributes produced for each entry are emitted in their own [verify_asset](crate::util::event_attributes::EventType::VerifyAsset)
+/// event, except for any object store gateway access revocation, which is relayed via the
+/// [RelayOsGatewayAccess](crate::core::msg::ExecuteMsg::RelayOsGatewayAccess) route so that the
+/// gateway can read it from its own event.  If any entry fails to verify, such as when its scope is no longer pending verification,
+/// its error is returned, which causes the entire transaction, including the storage changes made
+/// for previous entries, to be reverted.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the batch verify asset v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn batch_verify_asset(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: BatchVerifyAssetV1,
+) -> EntryPointResponse {
+    let entry_count = msg.entries.len();
+    let mut response = Response::new().add_attributes(
+        EventAttributes::new(EventType::BatchVerifyAsset).set_new_value(entry_count),
+    );
+    for entry in msg.entries {
+        let entry_response = verify_asset(
+            &env,
+            AssetMetaService::new(deps.branch()),
+            info.clone(),
+            entry,
+        )?;
+        let (entry_attributes, access_relay_msg) =
+            relay_os_gateway_attributes(&env, entry_response.attributes)?;
+        response = response
+            .add_submessages(entry_response.messages)
+            .add_messages(access_relay_msg)
+            .add_event(
+                Event::new(EventType::VerifyAsset.event_name()).add_attributes(entry_attributes),
+            )
+            .add_events(entry_response.events);
+    }
+    response.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{BankMsg, Coin, CosmosMsg, Response};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::state::{load_fee_payment_detail, may_load_fee_payment_detail};
+    use crate::core::types::asset_identifier::AssetIdentifier;
+    use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+    use crate::core::types::asset_scope_attribute::AssetScopeAttribute;
+    use crate::core::types::batch_verify_asset::BatchVerifyAssetEntry;
+    use crate::core::types::os_gateway_access::OsGatewayAccess;
+    use crate::execute::onboard_asset::OnboardAssetV1;
+    use crate::service::asset_meta_repository::AssetMetaRepository;
+    use crate::service::asset_meta_service::AssetMetaService;
+    use crate::testutil::attribute_utilities::{
+        mock_named_attribute_response_for_scope, mock_named_attribute_responses_by_name,
+    };
+    use crate::testutil::execute_utilities::execute_batch_verify_asset;
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS, DEFAULT_SECONDARY_ASSET_TYPE,
+        DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, build_attribute, empty_mock_info,
+        get_relayed_os_gateway_access, setup_no_attribute_response, setup_test_suite, InstArgs,
+        MockOwnedDeps,
+    };
+    use crate::util::constants::{ASSET_EVENT_TYPE_KEY, MAX_BATCH_VERIFY_SIZE, NEW_VALUE_KEY};
+    use crate::util::event_attributes::EventType;
+    use crate::util::functions::{generate_os_gateway_grant_id, try_into_update_attribute_request};
+
+    fn default_entry(asset_type: &str, success: bool) -> BatchVerifyAssetEntry {
+        BatchVerifyAssetEntry::new(
+            AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS).to_serialized_enum(),
+            asset_type,
+            success,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_batch_verify_asset_success() {
+        let mut deps = setup_two_pending_asset_types();
+        let response = execute_batch_verify_asset(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            vec![
+                default_entry(DEFAULT_ASSET_TYPE, true),
+                default_entry(DEFAULT_SECONDARY_ASSET_TYPE, false),
+            ],
+        )
+        .expect("batch verification should succeed for pending entries");
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::BatchVerifyAsset.event_name(),
+        );
+        assert_response_attributes_contain(&response, NEW_VALUE_KEY, "2");
+        assert_eq!(
+            2,
+            response
+                .events
+                .iter()
+                .filter(|event| event.ty == EventType::VerifyAsset.event_name())
+                .count(),
+            "a verify asset event should be emitted for each entry in the batch",
+        );
+        let updated_statuses = response
+            .messages
+            .iter()
+            .filter_map(|msg| try_into_update_attribute_request(&msg.msg))
+            .map(|request| {
+                let attribute = AssetScopeAttribute::from_binary(
+                    &request.name,
+                    request.update_value.as_slice(),
+                )
+                .expect("the updated attribute should deserialize");
+                (attribute.asset_type, attribute.onboarding_status)
+            })
+            .collect::<Vec<(String, AssetOnboardingStatus)>>();
+        assert_eq!(
+            vec![
+                (
+                    DEFAULT_ASSET_TYPE.to_string(),
+                    AssetOnboardingStatus::Approved
+                ),
+                (
+                    DEFAULT_SECONDARY_ASSET_TYPE.to_string(),
+                    AssetOnboardingStatus::Denied
+                ),
+            ],
+            updated_statuses,
+            "each entry's attribute should be updated with its own verification result",
+        );
+        for asset_type in [DEFAULT_ASSET_TYPE, DEFAULT_SECONDARY_ASSET_TYPE] {
+            assert_eq!(
+                None,
+                may_load_fee_payment_detail(
+                    deps.as_ref().storage,
+                    DEFAULT_SCOPE_ADDRESS,
+                    asset_type
+                ),
+                "the fee payment detail for each entry should be removed after verification",
+            );
+        }
+    }
+
+    #[test]
+    fn test_batch_verify_asset_relays_gateway_revocations() {
+        let mut deps = setup_two_pending_asset_types();
+        let response = execute_batch_verify_asset(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            vec![
+                default_entry(DEFAULT_ASSET_TYPE, true),
+                default_entry(DEFAULT_SECONDARY_ASSET_TYPE, false),
+            ],
+        )
+        .expect("batch verification should succeed for pending entries");
+        assert_eq!(
+            [DEFAULT_ASSET_TYPE, DEFAULT_SECONDARY_ASSET_TYPE]
+                .into_iter()
+                .map(|asset_type| OsGatewayAccess::revoke(
+                    DEFAULT_SCOPE_ADDRESS,
+                    DEFAULT_VERIFIER_ADDRESS,
+                    generate_os_gateway_grant_id(None, asset_type, DEFAULT_SCOPE_ADDRESS),
+                ))
+                .collect::<Vec<OsGatewayAccess>>(),
+            get_relayed_os_gateway_access(&response),
+            "the gateway access for every entry should be revoked, in entry order",
+        );
+        assert!(
+            response
+                .events
+                .iter()
+                .flat_map(|event| event.attributes.iter())
+                .all(|attribute| !OsGatewayAccess::is_gateway_key(&attribute.key)),
+            "gateway attributes should not be emitted in events that the gateway does not read",
+        );
+    }
+
+    #[test]
+    fn test_batch_verify_asset_aggregates_fee_payments() {
+        let mut deps = setup_two_pending_asset_types();
+        let expected_payments = [DEFAULT_ASSET_TYPE, DEFAULT_SECONDARY_ASSET_TYPE]
+            .into_iter()
+            .flat_map(|asset_type| {
+                load_fee_payment_detail(deps.as_ref().storage, DEFAULT_SCOPE_ADDRESS, asset_type)
+                    .expect("a fee payment detail should exist for each pending entry")
+                    .payments
+            })
+            .map(|payment| (payment.recipient.to_string(), vec![payment.amount]))
+            .collect::<Vec<(String, Vec<Coin>)>>();
+        assert!(
+            !expected_payments.is_empty(),
+            "the default verifier should charge fees for this test to be meaningful",
+        );
+        let response = execute_batch_verify_asset(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            vec![
+                default_entry(DEFAULT_ASSET_TYPE, true),
+                default_entry(DEFAULT_SECONDARY_ASSET_TYPE, true),
+            ],
+        )
+        .expect("batch verification should succeed for pending entries");
+        assert_eq!(
+            expected_payments,
+            get_bank_sends(&response),
+            "the fee payments for every entry should be sent, in entry order",
+        );
+    }
+
+    #[test]
+    fn test_batch_verify_asset_fails_when_any_entry_is_not_pending() {
+        let mut deps = setup_two_pending_asset_types();
+        let default_attribute = get_attribute(&mut deps, DEFAULT_ASSET_TYPE);
+        let secondary_attribute = get_attribute(&mut deps, DEFAULT_SECONDARY_ASSET_TYPE);
+        mock_named_attribute_responses_by_name(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![
+                build_attribute(DEFAULT_SCOPE_ADDRESS, &default_attribute),
+                build_attribute(
+                    DEFAULT_SCOPE_ADDRESS,
+                    &AssetScopeAttribute {
+                        onboarding_status: AssetOnboardingStatus::Approved,
+                        ..secondary_attribute
+                    },
+                ),
+            ],
+        );
+        let err = execute_batch_verify_asset(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            vec![
+                default_entry(DEFAULT_ASSET_TYPE, true),
+                default_entry(DEFAULT_SECONDARY_ASSET_TYPE, true),
+            ],
+        )
+        .expect_err("batch verification should fail when any entry is not pending");
+        match err {
+            ContractError::AssetAlreadyVerified {
+                asset_type, status, ..
+            } => {
+                assert_eq!(
+                    DEFAULT_SECONDARY_ASSET_TYPE, asset_type,
+                    "the error should reference the entry that was not pending",
+                );
+                assert_eq!(
+                    AssetOnboardingStatus::Approved,
+                    status,
+                    "the error should include the entry's current status",
+                );
+            }
+            _ => panic!(
+                "unexpected error for a batch with a non-pending entry: {:?}",
+                err
+            ),
+        }
+    }
+
+    #[test]
+    fn test_batch_verify_asset_rejects_empty_batch() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        let err = execute_batch_verify_asset(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            vec![],
+        )
+        .expect_err("an empty batch should be rejected");
+        assert!(
+            matches!(err, ContractError::InvalidMessageFields { .. }),
+            "an empty batch should fail validation, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_batch_verify_asset_rejects_oversized_batch() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        let entries = (0..=MAX_BATCH_VERIFY_SIZE)
+            .map(|index| {
+                BatchVerifyAssetEntry::new(
+                    AssetIdentifier::asset_uuid(format!(
+                        "{}",
+                        uuid::Uuid::from_u128(index as u128)
+                    ))
+                    .to_serialized_enum(),
+                    DEFAULT_ASSET_TYPE,
+                    true,
+                    None,
+                    None,
+                )
+            })
+            .collect();
+        let err = execute_batch_verify_asset(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            entries,
+        )
+        .expect_err("a batch larger than the maximum size should be rejected");
+        match err {
+            ContractError::InvalidMessageFields { invalid_fields, .. } => assert_eq!(
+                vec![format!(
+                    "entries: no more than {} entries may be provided",
+                    MAX_BATCH_VERIFY_SIZE
+                )],
+                invalid_fields,
+                "only the batch size should be reported as invalid",
+            ),
+            _ => panic!("unexpected error for an oversized batch: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn test_batch_verify_asset_rejects_duplicate_entries() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        let err = execute_batch_verify_asset(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            vec![
+                default_entry(DEFAULT_ASSET_TYPE, true),
+                default_entry(DEFAULT_ASSET_TYPE, false),
+            ],
+        )
+        .expect_err("duplicate entries should be rejected");
+        match err {
+            ContractError::InvalidMessageFields { invalid_fields, .. } => assert_eq!(
+                vec![format!(
+                    "entries[1]: scope [{}] is already verified as asset type [{}] by a previous entry",
+                    DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE,
+                )],
+                invalid_fields,
+                "only the duplicate entry should be reported as invalid",
+            ),
+            _ => panic!("unexpected error for duplicate entries: {:?}", err),
+        }
+    }
+
+    // Onboards the default scope as both the default and secondary asset types, then mocks the
+    // attribute lookups so that each asset type resolves to its own pending attribute
+    fn setup_two_pending_asset_types() -> MockOwnedDeps {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(
+            &mut deps,
+            &InstArgs::default_with_additional_asset_types(vec![DEFAULT_SECONDARY_ASSET_TYPE]),
+        );
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding the default asset type should succeed");
+        let default_attribute = get_attribute(&mut deps, DEFAULT_ASSET_TYPE);
+        mock_named_attribute_response_for_scope(&mut deps, DEFAULT_SCOPE_ADDRESS, vec![]);
+        test_onboard_asset(
+            &mut deps,
+            TestOnboardAsset {
+                onboard_asset: OnboardAssetV1 {
+                    asset_type: DEFAULT_SECONDARY_ASSET_TYPE.into(),
+                    ..TestOnboardAsset::default_onboard_asset()
+                },
+                ..TestOnboardAsset::default()
+            },
+        )
+        .expect("onboarding the secondary asset type should succeed");
+        let secondary_attribute = get_attribute(&mut deps, DEFAULT_SECONDARY_ASSET_TYPE);
+        mock_named_attribute_responses_by_name(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![
+                build_attribute(DEFAULT_SCOPE_ADDRESS, &default_attribute),
+                build_attribute(DEFAULT_SCOPE_ADDRESS, &secondary_attribute),
+            ],
+        );
+        deps
+    }
+
+    fn get_attribute(deps: &mut MockOwnedDeps, asset_type: &str) -> AssetScopeAttribute {
+        AssetMetaService::new(deps.as_mut())
+            .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, asset_type)
+            .expect("the scope attribute should be present after onboarding")
+    }
+
+    fn get_bank_sends(response: &Response) -> Vec<(String, Vec<Coin>)> {
+        response
+            .messages
+            .iter()
+            .filter_map(|msg| match &msg.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                    Some((to_address.to_owned(), amount.to_owned()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
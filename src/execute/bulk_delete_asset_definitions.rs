@@ -0,0 +1,343 @@
+use crate::core::error::ContractError;
+use crate::core::msg::ExecuteMsg;
+use crate::core::state::load_asset_definition_by_type_v3;
+use crate::execute::delete_asset_definition::{delete_asset_definition, DeleteAssetDefinitionV1};
+use crate::util::aliases::{AssetResult, EntryPointResponse};
+use crate::util::contract_helpers::{check_admin_only, check_funds_are_empty};
+use crate::util::event_attributes::{EventAttributes, EventType};
+
+use cosmwasm_std::{DepsMut, Event, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// A transformation of [ExecuteMsg::BulkDeleteAssetDefinitions](crate::core::msg::ExecuteMsg::BulkDeleteAssetDefinitions)
+/// for ease of use in the underlying [bulk_delete_asset_definitions](self::bulk_delete_asset_definitions) function.
+///
+/// # Parameters
+///
+/// * `entries` Each asset definition to delete, converted to the same [DeleteAssetDefinitionV1](crate::execute::delete_asset_definition::DeleteAssetDefinitionV1)
+/// struct used by the single-definition deletion route.
+pub struct BulkDeleteAssetDefinitionsV1 {
+    pub entries: Vec<DeleteAssetDefinitionV1>,
+}
+impl BulkDeleteAssetDefinitionsV1 {
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [BulkDeleteAssetDefinitions](crate::core::msg::ExecuteMsg::BulkDeleteAssetDefinitions)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<BulkDeleteAssetDefinitionsV1> {
+        match msg {
+            ExecuteMsg::BulkDeleteAssetDefinitions { asset_types } => {
+                BulkDeleteAssetDefinitionsV1 {
+                    entries: asset_types
+                        .iter()
+                        .map(|asset_type| DeleteAssetDefinitionV1::new(asset_type))
+                        .collect(),
+                }
+                .to_ok()
+            }
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::BulkDeleteAssetDefinitions".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+impl TryFrom<ExecuteMsg> for BulkDeleteAssetDefinitionsV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        BulkDeleteAssetDefinitionsV1::from_execute_msg(msg)
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::BulkDeleteAssetDefinitions](crate::core::msg::ExecuteMsg::BulkDeleteAssetDefinitions)
+/// message is provided.  Runs each entry through the [delete_asset_definition](crate::execute::delete_asset_definition::delete_asset_definition)
+/// function in the order provided.  The attributes produced for each entry are emitted in their own
+/// [delete_asset_definition](crate::util::event_attributes::EventType::DeleteAssetDefinition) event.
+/// Every entry is checked before any deletion occurs, so if any definition does not exist or is
+/// frozen, its error is returned and no definitions are deleted.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the bulk delete asset definitions v1 struct, provided by conversion from
+/// an [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn bulk_delete_asset_definitions(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    msg: BulkDeleteAssetDefinitionsV1,
+) -> EntryPointResponse {
+    check_admin_only(&deps.as_ref(), &info)?;
+    check_funds_are_empty(&info)?;
+    for entry in msg.entries.iter() {
+        load_asset_definition_by_type_v3(deps.storage, &entry.asset_type)?.check_not_frozen()?;
+    }
+    let mut response = Response::new().add_attributes(
+        EventAttributes::new(EventType::BulkDeleteAssetDefinitions)
+            .set_new_value(msg.entries.len()),
+    );
+    for entry in msg.entries {
+        let entry_response = delete_asset_definition(deps.branch(), info.clone(), entry)?;
+        response = response.add_event(
+            Event::new(EventType::DeleteAssetDefinition.event_name())
+                .add_attributes(entry_response.attributes),
+        );
+    }
+    response.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{coin, Deps};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::state::load_asset_definition_by_type_v3;
+    use crate::core::types::asset_definition::AssetDefinitionInputV3;
+    use crate::testutil::execute_utilities::{
+        execute_add_asset_definition, execute_bulk_delete_asset_definitions,
+        execute_freeze_asset_definition,
+    };
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_SENDER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, empty_mock_info, get_default_asset_definition_input,
+        mock_info_with_funds, test_instantiate_success, InstArgs, MockOwnedDeps,
+    };
+    use crate::util::constants::{
+        ASSET_EVENT_TYPE_KEY, ASSET_TYPE_KEY, MAX_BULK_DELETE_SIZE, NEW_VALUE_KEY, NHASH,
+    };
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    const SECOND_ASSET_TYPE: &str = "heloc";
+
+    #[test]
+    fn test_bulk_delete_asset_definitions_success() {
+        let mut deps = mock_provenance_dependencies();
+        setup_second_asset_type(&mut deps);
+        let response = execute_bulk_delete_asset_definitions(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            vec![DEFAULT_ASSET_TYPE, SECOND_ASSET_TYPE],
+        )
+        .expect("expected the bulk deletion to succeed");
+        assert_eq!(
+            2,
+            response.attributes.len(),
+            "the correct number of top-level attributes should be emitted",
+        );
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::BulkDeleteAssetDefinitions.event_name(),
+        );
+        assert_response_attributes_contain(&response, NEW_VALUE_KEY, "2");
+        assert_eq!(
+            2,
+            response.events.len(),
+            "each deleted asset type should emit its own event",
+        );
+        for (event, asset_type) in response
+            .events
+            .iter()
+            .zip([DEFAULT_ASSET_TYPE, SECOND_ASSET_TYPE])
+        {
+            assert_eq!(
+                EventType::DeleteAssetDefinition.event_name(),
+                event.ty,
+                "each event should be a delete asset definition event",
+            );
+            assert!(
+                event
+                    .attributes
+                    .iter()
+                    .any(|attr| attr.key == ASSET_TYPE_KEY && attr.value == asset_type),
+                "the event for [{}] should include its asset type, but got: {:?}",
+                asset_type,
+                event.attributes,
+            );
+            assert_definition_exists(&deps.as_ref(), asset_type, false);
+        }
+    }
+
+    #[test]
+    fn test_bulk_delete_asset_definitions_rolls_back_on_missing_definition() {
+        let mut deps = mock_provenance_dependencies();
+        setup_second_asset_type(&mut deps);
+        let error = execute_bulk_delete_asset_definitions(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            vec![DEFAULT_ASSET_TYPE, "not-a-real-type", SECOND_ASSET_TYPE],
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::RecordNotFound { .. }),
+            "expected the missing definition to produce a record not found error, but got: {:?}",
+            error,
+        );
+        assert_definition_exists(&deps.as_ref(), DEFAULT_ASSET_TYPE, true);
+        assert_definition_exists(&deps.as_ref(), SECOND_ASSET_TYPE, true);
+    }
+
+    #[test]
+    fn test_bulk_delete_asset_definitions_rejects_invalid_entries() {
+        let mut deps = mock_provenance_dependencies();
+        setup_second_asset_type(&mut deps);
+        let error = execute_bulk_delete_asset_definitions(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            vec![DEFAULT_ASSET_TYPE, "", DEFAULT_ASSET_TYPE],
+        )
+        .unwrap_err();
+        assert_invalid_field(&error, "asset_types[1]: must not be blank");
+        assert_invalid_field(
+            &error,
+            &format!(
+                "asset_types[2]: asset type [{}] was already provided",
+                DEFAULT_ASSET_TYPE,
+            ),
+        );
+        assert_definition_exists(&deps.as_ref(), DEFAULT_ASSET_TYPE, true);
+    }
+
+    #[test]
+    fn test_bulk_delete_asset_definitions_enforces_size_cap() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let asset_types = (0..=MAX_BULK_DELETE_SIZE)
+            .map(|index| format!("type-{}", index))
+            .collect::<Vec<String>>();
+        let error = execute_bulk_delete_asset_definitions(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            asset_types,
+        )
+        .unwrap_err();
+        assert_invalid_field(
+            &error,
+            &format!(
+                "asset_types: no more than {} asset types may be provided",
+                MAX_BULK_DELETE_SIZE,
+            ),
+        );
+        let error = execute_bulk_delete_asset_definitions(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            Vec::<String>::new(),
+        )
+        .unwrap_err();
+        assert_invalid_field(
+            &error,
+            "asset_types: at least one asset type must be provided",
+        );
+    }
+
+    #[test]
+    fn test_bulk_delete_asset_definitions_is_admin_only() {
+        let mut deps = mock_provenance_dependencies();
+        setup_second_asset_type(&mut deps);
+        let error = execute_bulk_delete_asset_definitions(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+            vec![DEFAULT_ASSET_TYPE, SECOND_ASSET_TYPE],
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected an unauthorized error, but got: {:?}",
+            error,
+        );
+        let error = execute_bulk_delete_asset_definitions(
+            deps.as_mut(),
+            mock_env(),
+            mock_info_with_funds(DEFAULT_ADMIN_ADDRESS, &[coin(150, NHASH)]),
+            vec![DEFAULT_ASSET_TYPE],
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected an invalid funds error, but got: {:?}",
+            error,
+        );
+        assert_definition_exists(&deps.as_ref(), DEFAULT_ASSET_TYPE, true);
+        assert_definition_exists(&deps.as_ref(), SECOND_ASSET_TYPE, true);
+    }
+
+    #[test]
+    fn test_bulk_delete_asset_definitions_rolls_back_on_frozen_definition() {
+        let mut deps = mock_provenance_dependencies();
+        setup_second_asset_type(&mut deps);
+        execute_freeze_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            SECOND_ASSET_TYPE,
+        )
+        .expect("expected the second asset definition to be frozen");
+        let error = execute_bulk_delete_asset_definitions(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            vec![DEFAULT_ASSET_TYPE, SECOND_ASSET_TYPE],
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::AssetDefinitionFrozen { ref asset_type } if asset_type == SECOND_ASSET_TYPE),
+            "expected the frozen error to be returned, but got: {:?}",
+            error,
+        );
+        assert_definition_exists(&deps.as_ref(), DEFAULT_ASSET_TYPE, true);
+    }
+
+    fn setup_second_asset_type(deps: &mut MockOwnedDeps) {
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        execute_add_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            AssetDefinitionInputV3 {
+                asset_type: SECOND_ASSET_TYPE.to_string(),
+                bind_name: false.to_some(),
+                ..get_default_asset_definition_input()
+            },
+        )
+        .expect("adding the second asset definition should succeed");
+    }
+
+    fn assert_definition_exists(deps: &Deps, asset_type: &str, expected: bool) {
+        assert_eq!(
+            expected,
+            load_asset_definition_by_type_v3(deps.storage, asset_type).is_ok(),
+            "unexpected existence of the [{}] asset definition",
+            asset_type,
+        );
+    }
+
+    fn assert_invalid_field(error: &ContractError, expected_field: &str) {
+        match error {
+            ContractError::InvalidMessageFields { invalid_fields, .. } => assert!(
+                invalid_fields.contains(&expected_field.to_string()),
+                "expected the invalid fields to contain [{}], but got: {:?}",
+                expected_field,
+                invalid_fields,
+            ),
+            _ => panic!("unexpected error encountered: {:?}", error),
+        }
+    }
+}
@@ -0,0 +1,431 @@
+use crate::core::error::ContractError;
+use crate::core::msg::ExecuteMsg;
+use crate::core::state::{
+    decrement_verifier_pending, delete_fee_payment_detail, load_fee_payment_detail,
+    update_verifier_status_index,
+};
+use crate::core::types::asset_identifier::AssetIdentifier;
+use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+use crate::core::types::asset_verification_result::AssetVerificationResult;
+use crate::service::asset_meta_repository::AssetMetaRepository;
+use crate::service::deps_manager::DepsManager;
+use crate::service::message_gathering_service::MessageGatheringService;
+use crate::util::aliases::{AssetResult, EntryPointResponse};
+use crate::util::contract_helpers::{check_funds_are_empty, generate_os_gateway_access_revoke};
+use crate::util::event_attributes::{EventAttributes, EventType};
+
+use cosmwasm_std::{Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// A transformation of [ExecuteMsg::CancelPendingOnboarding](crate::core::msg::ExecuteMsg::CancelPendingOnboarding)
+/// for ease of use in the underlying [cancel_pending_onboarding](self::cancel_pending_onboarding) function.
+///
+/// # Parameters
+///
+/// * `identifier` An instance of the asset identifier enum that helps the contract identify which
+/// scope is awaiting verification.
+/// * `asset_type` The asset type of the pending verification.
+#[derive(Clone, PartialEq, Eq)]
+pub struct CancelPendingOnboardingV1 {
+    pub identifier: AssetIdentifier,
+    pub asset_type: String,
+}
+impl CancelPendingOnboardingV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `identifier` An instance of the asset identifier enum that helps the contract identify which
+    /// scope is awaiting verification.
+    /// * `asset_type` The asset type of the pending verification.
+    pub fn new<S: Into<String>>(identifier: AssetIdentifier, asset_type: S) -> Self {
+        CancelPendingOnboardingV1 {
+            identifier,
+            asset_type: asset_type.into(),
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [CancelPendingOnboarding](crate::core::msg::ExecuteMsg::CancelPendingOnboarding)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<CancelPendingOnboardingV1> {
+        match msg {
+            ExecuteMsg::CancelPendingOnboarding {
+                identifier,
+                asset_type,
+            } => Self::new(identifier.to_asset_identifier()?, asset_type).to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::CancelPendingOnboarding".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+impl TryFrom<ExecuteMsg> for CancelPendingOnboardingV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        CancelPendingOnboardingV1::from_execute_msg(msg)
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::CancelPendingOnboarding](crate::core::msg::ExecuteMsg::CancelPendingOnboarding)
+/// message is provided.  Allows the [requestor](crate::core::types::asset_scope_attribute::AssetScopeAttribute::requestor_address)
+/// of a pending onboarding to abort it.  The scope attribute is moved to the denied status so that
+/// the asset can be onboarded again, and the [FeePaymentDetail](crate::core::types::fee_payment_detail::FeePaymentDetail)
+/// is removed.  No verification occurred, so no fees are disbursed to the verifier.  Instead, the
+/// fees collected during onboarding are refunded to the requestor, and any object store gateway
+/// access granted to the verifier is revoked.
+///
+/// # Parameters
+///
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `repository` A helper collection of traits that allows complex lookups of scope values and
+/// emits messages to construct the process of cancellation as a collection of messages to produce
+/// in the function's result.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the cancel pending onboarding v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn cancel_pending_onboarding<'a, T>(
+    env: &Env,
+    repository: T,
+    info: MessageInfo,
+    msg: CancelPendingOnboardingV1,
+) -> EntryPointResponse
+where
+    T: AssetMetaRepository + MessageGatheringService + DepsManager<'a>,
+{
+    check_funds_are_empty(&info)?;
+    let scope_address = msg.identifier.get_scope_address()?;
+    let scope_attribute = repository.get_asset_by_asset_type(&scope_address, &msg.asset_type)?;
+    if info.sender != scope_attribute.requestor_address {
+        return ContractError::Unauthorized {
+            explanation: format!(
+                "only the requestor [{}] may cancel the onboarding of asset [{}] with asset type [{}]",
+                scope_attribute.requestor_address, scope_address, msg.asset_type,
+            ),
+        }
+        .to_err();
+    }
+    if scope_attribute.onboarding_status != AssetOnboardingStatus::Pending {
+        return ContractError::AssetAlreadyVerified {
+            scope_address,
+            asset_type: msg.asset_type,
+            status: scope_attribute.onboarding_status,
+        }
+        .to_err();
+    }
+    let mut cancelled_attribute = scope_attribute.clone();
+    cancelled_attribute.onboarding_status = AssetOnboardingStatus::Denied;
+    cancelled_attribute.latest_verification_result = AssetVerificationResult {
+        message: "onboarding cancelled by requestor".to_string(),
+        success: false,
+    }
+    .into();
+    cancelled_attribute.pending_expiry_block = None;
+    repository.update_attribute(env, &cancelled_attribute)?;
+    repository.use_deps(|deps| {
+        update_verifier_status_index(deps.storage, Some(&scope_attribute), &cancelled_attribute)
+    })?;
+    repository.use_deps(|deps| {
+        decrement_verifier_pending(
+            deps.storage,
+            &msg.asset_type,
+            scope_attribute.verifier_address.as_str(),
+        )
+    })?;
+    // The contract holds the fees collected during onboarding until verification, so they are
+    // returned to the requestor rather than being stranded when the detail is removed
+    let fee_payment_detail = repository
+        .use_deps(|deps| load_fee_payment_detail(deps.storage, &scope_address, &msg.asset_type))?;
+    repository.use_deps(|deps| {
+        delete_fee_payment_detail(deps.storage, &scope_address, &msg.asset_type)
+    })?;
+    if let Some(refund_msg) = fee_payment_detail.to_refund_msg(&scope_attribute.requestor_address) {
+        repository.add_message(refund_msg);
+    }
+    let access_revoke = repository
+        .use_deps(|deps| generate_os_gateway_access_revoke(deps.storage, &scope_attribute))?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::for_asset_event(
+                EventType::CancelledOnboarding,
+                &msg.asset_type,
+                &scope_address,
+            )
+            .set_verifier(&scope_attribute.verifier_address)
+            .set_new_asset_onboarding_status(&cancelled_attribute.onboarding_status),
+        )
+        .add_attributes(access_revoke)
+        .add_messages(repository.get_messages())
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{coin, BankMsg, CosmosMsg};
+    use os_gateway_contract_attributes::{OS_GATEWAY_EVENT_TYPES, OS_GATEWAY_KEYS};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::state::{load_fee_payment_detail, may_load_fee_payment_detail};
+    use crate::core::types::asset_identifier::AssetIdentifier;
+    use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+    use crate::core::types::asset_scope_attribute::AssetScopeAttribute;
+    use crate::service::asset_meta_repository::AssetMetaRepository;
+    use crate::service::asset_meta_service::AssetMetaService;
+    use crate::testutil::execute_utilities::execute_cancel_pending_onboarding;
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS, DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, assert_single_item_by, empty_mock_info,
+        intercept_add_or_update_attribute, mock_info_with_nhash, setup_no_attribute_response,
+        setup_test_suite, InstArgs, MockOwnedDeps,
+    };
+    use crate::testutil::verify_asset_helpers::{test_verify_asset, TestVerifyAsset};
+    use crate::util::aliases::EntryPointResponse;
+    use crate::util::constants::{
+        ASSET_EVENT_TYPE_KEY, ASSET_SCOPE_ADDRESS_KEY, ASSET_TYPE_KEY,
+        NEW_ASSET_ONBOARDING_STATUS_KEY, VERIFIER_ADDRESS_KEY,
+    };
+    use crate::util::event_attributes::EventType;
+    use crate::util::functions::generate_os_gateway_grant_id;
+
+    use super::{cancel_pending_onboarding, CancelPendingOnboardingV1};
+
+    #[test]
+    fn test_cancel_pending_onboarding_by_non_requestor_fails() {
+        for sender in [
+            DEFAULT_VERIFIER_ADDRESS,
+            "tp1hsqtppgy7mgd64q0uvk7q39qa7h8hp30urqs6n",
+        ] {
+            let mut deps = mock_provenance_dependencies();
+            setup_onboarded_asset(&mut deps);
+            let err = cancel(&mut deps, sender)
+                .expect_err("only the requestor should be able to cancel an onboarding");
+            match err {
+                ContractError::Unauthorized { explanation } => {
+                    assert_eq!(
+                        format!(
+                            "only the requestor [{}] may cancel the onboarding of asset [{}] with asset type [{}]",
+                            DEFAULT_SENDER_ADDRESS, DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE,
+                        ),
+                        explanation,
+                        "the error should describe the required requestor",
+                    );
+                }
+                _ => panic!(
+                    "unexpected error when [{}] cancels onboarding: {:?}",
+                    sender, err
+                ),
+            };
+            assert_onboarding_still_pending(&mut deps);
+        }
+    }
+
+    #[test]
+    fn test_cancel_pending_onboarding_fails_for_verified_asset() {
+        for success in [true, false] {
+            let mut deps = mock_provenance_dependencies();
+            setup_onboarded_asset(&mut deps);
+            test_verify_asset(
+                &mut deps,
+                &mock_env(),
+                TestVerifyAsset::default_with_success(success),
+            )
+            .expect("verification should succeed");
+            let err = cancel(&mut deps, DEFAULT_SENDER_ADDRESS)
+                .expect_err("a completed verification should not be cancellable");
+            assert!(
+                matches!(err, ContractError::AssetAlreadyVerified { .. }),
+                "expected an asset already verified error (success = {}), but got: {:?}",
+                success,
+                err,
+            );
+        }
+    }
+
+    #[test]
+    fn test_cancel_pending_onboarding_denies_and_allows_retry() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps);
+        let response = cancel(&mut deps, DEFAULT_SENDER_ADDRESS)
+            .expect("the requestor should be able to cancel a pending onboarding");
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::CancelledOnboarding.event_name(),
+        );
+        assert_response_attributes_contain(&response, ASSET_TYPE_KEY, DEFAULT_ASSET_TYPE);
+        assert_response_attributes_contain(
+            &response,
+            ASSET_SCOPE_ADDRESS_KEY,
+            DEFAULT_SCOPE_ADDRESS,
+        );
+        assert_response_attributes_contain(
+            &response,
+            VERIFIER_ADDRESS_KEY,
+            DEFAULT_VERIFIER_ADDRESS,
+        );
+        assert_response_attributes_contain(
+            &response,
+            NEW_ASSET_ONBOARDING_STATUS_KEY,
+            AssetOnboardingStatus::Denied.to_string().as_str(),
+        );
+        assert!(
+            may_load_fee_payment_detail(&deps.storage, DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+                .is_none(),
+            "the fee payment detail should be removed after the onboarding is cancelled",
+        );
+        intercept_add_or_update_attribute(
+            &mut deps,
+            response,
+            "cancellation should update the attribute",
+        )
+        .expect("the attribute update should be intercepted");
+        let attribute = get_default_attribute(&mut deps);
+        assert_eq!(
+            AssetOnboardingStatus::Denied,
+            attribute.onboarding_status,
+            "the cancelled onboarding should be denied",
+        );
+        let verification_result = attribute
+            .latest_verification_result
+            .expect("a verification result should be recorded for the cancellation");
+        assert!(
+            !verification_result.success,
+            "the cancellation should be recorded as an unsuccessful verification",
+        );
+        // The asset is free to be onboarded again as a retry
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding should succeed after the onboarding is cancelled");
+        let attribute = get_default_attribute(&mut deps);
+        assert_eq!(
+            AssetOnboardingStatus::Pending,
+            attribute.onboarding_status,
+            "the asset should be pending again after it is re-onboarded",
+        );
+        assert_eq!(
+            1, attribute.retry_count,
+            "onboarding after a cancellation should be counted as a retry",
+        );
+    }
+
+    #[test]
+    fn test_cancel_pending_onboarding_refunds_requestor() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps);
+        let detail =
+            load_fee_payment_detail(&deps.storage, DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+                .expect("the fee payment detail should be stored after onboarding");
+        let response = cancel(&mut deps, DEFAULT_SENDER_ADDRESS)
+            .expect("the requestor should be able to cancel a pending onboarding");
+        let refund_msg = &assert_single_item_by(
+            &response.messages,
+            "a single bank message should be emitted",
+            |msg| matches!(msg.msg, CosmosMsg::Bank(..)),
+        )
+        .msg;
+        assert_eq!(
+            &CosmosMsg::Bank(BankMsg::Send {
+                to_address: DEFAULT_SENDER_ADDRESS.to_string(),
+                amount: vec![coin(
+                    detail.sum_costs(),
+                    &detail.payments.first().unwrap().amount.denom,
+                )],
+            }),
+            refund_msg,
+            "the requestor should be refunded the full amount collected during onboarding, and the verifier should not be paid",
+        );
+    }
+
+    #[test]
+    fn test_cancel_pending_onboarding_revokes_gateway_access() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps);
+        let response = cancel(&mut deps, DEFAULT_SENDER_ADDRESS)
+            .expect("the requestor should be able to cancel a pending onboarding");
+        assert_response_attributes_contain(
+            &response,
+            OS_GATEWAY_KEYS.event_type,
+            OS_GATEWAY_EVENT_TYPES.access_revoke,
+        );
+        assert_response_attributes_contain(
+            &response,
+            OS_GATEWAY_KEYS.target_account,
+            DEFAULT_VERIFIER_ADDRESS,
+        );
+        assert_response_attributes_contain(
+            &response,
+            OS_GATEWAY_KEYS.access_grant_id,
+            &generate_os_gateway_grant_id(None, DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS),
+        );
+    }
+
+    #[test]
+    fn test_cancel_pending_onboarding_fails_for_provided_funds() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps);
+        let err = cancel_pending_onboarding(
+            &mock_env(),
+            AssetMetaService::new(deps.as_mut()),
+            mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, 150),
+            CancelPendingOnboardingV1::new(
+                AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
+                DEFAULT_ASSET_TYPE,
+            ),
+        )
+        .expect_err("cancelling an onboarding with funds should fail");
+        assert!(
+            matches!(err, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when the sender provides funds, but got: {:?}",
+            err,
+        );
+        assert_onboarding_still_pending(&mut deps);
+    }
+
+    fn setup_onboarded_asset(deps: &mut MockOwnedDeps) {
+        setup_test_suite(deps, &InstArgs::default());
+        setup_no_attribute_response(deps, None);
+        test_onboard_asset(deps, TestOnboardAsset::default()).expect("onboarding should succeed");
+    }
+
+    fn cancel(deps: &mut MockOwnedDeps, sender: &str) -> EntryPointResponse {
+        execute_cancel_pending_onboarding(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(sender),
+            AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+        )
+    }
+
+    fn get_default_attribute(deps: &mut MockOwnedDeps) -> AssetScopeAttribute {
+        AssetMetaService::new(deps.as_mut())
+            .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+            .expect("the scope attribute should exist")
+    }
+
+    fn assert_onboarding_still_pending(deps: &mut MockOwnedDeps) {
+        assert_eq!(
+            AssetOnboardingStatus::Pending,
+            get_default_attribute(deps).onboarding_status,
+            "the onboarding should remain pending",
+        );
+        assert!(
+            may_load_fee_payment_detail(&deps.storage, DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+                .is_some(),
+            "the fee payment detail should remain stored",
+        );
+    }
+}
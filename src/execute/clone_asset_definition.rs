@@ -0,0 +1,380 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+use crate::core::error::ContractError;
+use crate::core::msg::ExecuteMsg;
+use crate::core::state::{insert_asset_definition_v3, load_asset_definition_by_type_v3, STATE_V2};
+use crate::core::types::asset_definition::AssetDefinitionV3;
+use crate::util::aliases::{AssetResult, EntryPointResponse};
+use crate::util::contract_helpers::{check_admin_only, check_funds_are_empty};
+use crate::util::event_attributes::{EventAttributes, EventType};
+use crate::util::functions::{generate_asset_attribute_name, msg_bind_name};
+use crate::validation::validate_init_msg::validate_asset_definition;
+
+/// A transformation of [ExecuteMsg::CloneAssetDefinition](crate::core::msg::ExecuteMsg::CloneAssetDefinition)
+/// for ease of use in the underlying [clone_asset_definition](self::clone_asset_definition) function.
+///
+/// # Parameters
+///
+/// * `source_asset_type` The type of the existing [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
+/// from which the verifiers will be copied.
+/// * `new_asset_type` The unique type of the new asset definition.
+/// * `new_display_name` An optional human-readable name for the new asset type.
+/// * `bind_name` An optional parameter.  If omitted or provided as `true`, the contract will attempt
+/// to bind a name branched off of its [base_contract_name](crate::core::state::StateV2::base_contract_name)
+/// with the new asset type.
+#[derive(Clone, PartialEq, Eq)]
+pub struct CloneAssetDefinitionV1 {
+    pub source_asset_type: String,
+    pub new_asset_type: String,
+    pub new_display_name: Option<String>,
+    pub bind_name: Option<bool>,
+}
+impl CloneAssetDefinitionV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `source_asset_type` The type of the existing asset definition from which the verifiers
+    /// will be copied.
+    /// * `new_asset_type` The unique type of the new asset definition.
+    /// * `new_display_name` An optional human-readable name for the new asset type.
+    /// * `bind_name` Whether or not to bind a name for the new asset type.  Defaults to `true`.
+    pub fn new<S1: Into<String>, S2: Into<String>>(
+        source_asset_type: S1,
+        new_asset_type: S2,
+        new_display_name: Option<String>,
+        bind_name: Option<bool>,
+    ) -> Self {
+        CloneAssetDefinitionV1 {
+            source_asset_type: source_asset_type.into(),
+            new_asset_type: new_asset_type.into(),
+            new_display_name,
+            bind_name,
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [CloneAssetDefinition](crate::core::msg::ExecuteMsg::CloneAssetDefinition)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<CloneAssetDefinitionV1> {
+        match msg {
+            ExecuteMsg::CloneAssetDefinition {
+                source_asset_type,
+                new_asset_type,
+                new_display_name,
+                bind_name,
+            } => CloneAssetDefinitionV1::new(
+                source_asset_type,
+                new_asset_type,
+                new_display_name,
+                bind_name,
+            )
+            .to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::CloneAssetDefinition".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+impl TryFrom<ExecuteMsg> for CloneAssetDefinitionV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        CloneAssetDefinitionV1::from_execute_msg(msg)
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::CloneAssetDefinition](crate::core::msg::ExecuteMsg::CloneAssetDefinition)
+/// message is provided.  Adds a new, enabled [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
+/// to the contract's internal storage with a copy of the verifiers of an existing definition.  The
+/// copied verifiers are stored independently, so later changes to either definition's verifiers do
+/// not affect the other.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the clone asset definition v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn clone_asset_definition(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: CloneAssetDefinitionV1,
+) -> EntryPointResponse {
+    check_admin_only(&deps.as_ref(), &info)?;
+    check_funds_are_empty(&info)?;
+    let source_definition = load_asset_definition_by_type_v3(deps.storage, &msg.source_asset_type)?;
+    let asset_definition = AssetDefinitionV3::new(
+        &msg.new_asset_type,
+        msg.new_display_name,
+        source_definition.verifiers,
+    );
+    let state = STATE_V2.load(deps.storage)?;
+    // Every copied verifier is new to the cloned definition, so each must include an entity detail
+    // when they are required
+    validate_asset_definition(
+        &asset_definition,
+        state.allow_all_bech32_prefixes,
+        state.require_entity_detail_for_verifiers,
+    )?;
+    // The insert function rejects the new definition if its asset type is already in use
+    insert_asset_definition_v3(deps.storage, &asset_definition)?;
+    let mut messages = vec![];
+    if msg.bind_name.unwrap_or(true) {
+        messages.push(msg_bind_name(
+            generate_asset_attribute_name(&asset_definition.asset_type, state.base_contract_name),
+            env.contract.address,
+            true,
+        )?);
+    }
+    Response::new()
+        .add_messages(messages)
+        .add_attributes(
+            EventAttributes::new(EventType::CloneAssetDefinition)
+                .set_asset_type(&asset_definition.asset_type),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{message_info, mock_env};
+    use cosmwasm_std::{Addr, Deps};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::state::load_asset_definition_by_type_v3;
+    use crate::core::types::asset_definition::AssetDefinitionV3;
+    use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::execute::toggle_asset_definition::{
+        toggle_asset_definition, ToggleAssetDefinitionV1,
+    };
+    use crate::execute::update_asset_verifier::{update_asset_verifier, UpdateAssetVerifierV1};
+    use crate::testutil::execute_utilities::execute_clone_asset_definition;
+    use crate::testutil::msg_utilities::test_message_is_name_bind;
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_SENDER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, assert_response_messages_count, empty_mock_info,
+        get_default_asset_definition, get_default_verifier_detail, mock_info_with_nhash,
+        test_instantiate_success, InstArgs, MockOwnedDeps,
+    };
+    use crate::util::constants::{ASSET_EVENT_TYPE_KEY, ASSET_TYPE_KEY};
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{clone_asset_definition, CloneAssetDefinitionV1};
+
+    // These tests clone the default asset type, so they need a value other than the default to work with
+    const TEST_ASSET_TYPE: &str = "clone_asset_type";
+
+    #[test]
+    fn test_valid_clone_asset_definition_via_execute() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let response = execute_clone_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            CloneAssetDefinitionV1::new(
+                DEFAULT_ASSET_TYPE,
+                TEST_ASSET_TYPE,
+                "Cloned".to_string().to_some(),
+                None,
+            ),
+        )
+        .expect("expected the clone to work correctly");
+        assert_response_messages_count(&response, 1, "the clone should bind a name");
+        test_message_is_name_bind(&response.messages, TEST_ASSET_TYPE);
+        assert_eq!(
+            2,
+            response.attributes.len(),
+            "cloning an asset definition should produce the correct number of attributes",
+        );
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::CloneAssetDefinition.event_name(),
+        );
+        assert_response_attributes_contain(&response, ASSET_TYPE_KEY, TEST_ASSET_TYPE);
+        assert_eq!(
+            AssetDefinitionV3::new(
+                TEST_ASSET_TYPE,
+                "Cloned".to_some(),
+                get_default_asset_definition().verifiers,
+            ),
+            load_definition(&deps.as_ref(), TEST_ASSET_TYPE),
+            "the clone should contain the source's verifiers and the provided values",
+        );
+        assert_eq!(
+            get_default_asset_definition(),
+            load_definition(&deps.as_ref(), DEFAULT_ASSET_TYPE),
+            "the source asset definition should be unchanged by the clone",
+        );
+    }
+
+    #[test]
+    fn test_clone_asset_definition_without_name_binding() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let response = clone_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            CloneAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, TEST_ASSET_TYPE, None, false.to_some()),
+        )
+        .expect("expected the clone to work correctly");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted when name binding is disabled",
+        );
+    }
+
+    #[test]
+    fn test_clone_of_disabled_asset_definition_is_enabled() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        toggle_asset_definition(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ToggleAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, false),
+        )
+        .expect("expected the source asset definition to be disabled");
+        clone(&mut deps);
+        assert!(
+            load_definition(&deps.as_ref(), TEST_ASSET_TYPE).enabled,
+            "the clone should be enabled regardless of the source's enabled value",
+        );
+    }
+
+    #[test]
+    fn test_clone_verifier_updates_do_not_affect_source() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        clone(&mut deps);
+        let updated_verifier = VerifierDetailV2 {
+            entity_detail: None,
+            ..get_default_verifier_detail()
+        };
+        update_asset_verifier(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateAssetVerifierV1::new(TEST_ASSET_TYPE, updated_verifier.clone()),
+        )
+        .expect("expected the clone's verifier to be updated");
+        assert_eq!(
+            vec![updated_verifier],
+            load_definition(&deps.as_ref(), TEST_ASSET_TYPE).verifiers,
+            "the clone's verifier should reflect the update",
+        );
+        assert_eq!(
+            vec![get_default_verifier_detail()],
+            load_definition(&deps.as_ref(), DEFAULT_ASSET_TYPE).verifiers,
+            "the source's verifier should be unaffected by the update to the clone",
+        );
+    }
+
+    #[test]
+    fn test_invalid_clone_asset_definition_for_duplicate_type() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        clone(&mut deps);
+        for new_asset_type in [TEST_ASSET_TYPE, DEFAULT_ASSET_TYPE] {
+            let error = clone_asset_definition(
+                deps.as_mut(),
+                mock_env(),
+                empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+                CloneAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, new_asset_type, None, None),
+            )
+            .unwrap_err();
+            assert!(
+                matches!(error, ContractError::RecordAlreadyExists { .. }),
+                "expected the record already exists error to be returned when cloning to [{}], but got: {:?}",
+                new_asset_type,
+                error,
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_clone_asset_definition_for_missing_source() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = clone_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            CloneAssetDefinitionV1::new("not-a-type", TEST_ASSET_TYPE, None, None),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::RecordNotFound { .. }),
+            "expected the record not found error to be returned, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_clone_asset_definition_for_invalid_sender() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = clone_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_SENDER_ADDRESS), &[]),
+            CloneAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, TEST_ASSET_TYPE, None, None),
+        )
+        .unwrap_err();
+        assert!(
+            error.is_unauthorized(),
+            "expected the unauthorized error to be returned when the sender is not the admin, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_clone_asset_definition_for_provided_funds() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = clone_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            CloneAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, TEST_ASSET_TYPE, None, None),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when the sender provides funds, but got: {:?}",
+            error,
+        );
+    }
+
+    fn clone(deps: &mut MockOwnedDeps) {
+        clone_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            CloneAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, TEST_ASSET_TYPE, None, None),
+        )
+        .expect("expected the clone to work correctly");
+    }
+
+    fn load_definition(deps: &Deps, asset_type: &str) -> AssetDefinitionV3 {
+        load_asset_definition_by_type_v3(deps.storage, asset_type)
+            .expect("the asset definition should exist in storage")
+    }
+}
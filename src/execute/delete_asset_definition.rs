@@ -1,6 +1,8 @@
 use crate::core::error::ContractError;
 use crate::core::msg::ExecuteMsg;
-use crate::core::state::delete_asset_definition_by_asset_type_v3;
+use crate::core::state::{
+    delete_asset_definition_by_asset_type_v3, load_asset_definition_by_type_v3,
+};
 use crate::util::aliases::{AssetResult, EntryPointResponse};
 use crate::util::contract_helpers::{check_admin_only, check_funds_are_empty};
 use crate::util::event_attributes::{EventAttributes, EventType};
@@ -49,6 +51,13 @@ impl DeleteAssetDefinitionV1 {
         }
     }
 }
+impl TryFrom<ExecuteMsg> for DeleteAssetDefinitionV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        DeleteAssetDefinitionV1::from_execute_msg(msg)
+    }
+}
 
 /// Route implementation for [ExecuteMsg::DeleteAssetDefinition](crate::core::msg::ExecuteMsg::DeleteAssetDefinition).
 /// This function allows for the admin address to completely remove an asset definition.  This is
@@ -71,6 +80,7 @@ pub fn delete_asset_definition(
 ) -> EntryPointResponse {
     check_admin_only(&deps.as_ref(), &info)?;
     check_funds_are_empty(&info)?;
+    load_asset_definition_by_type_v3(deps.storage, &msg.asset_type)?.check_not_frozen()?;
     let deleted_asset_type =
         delete_asset_definition_by_asset_type_v3(deps.storage, &msg.asset_type)?;
     Response::new()
@@ -83,17 +93,18 @@ pub fn delete_asset_definition(
 
 #[cfg(test)]
 mod tests {
-    use crate::contract::execute;
     use crate::core::error::ContractError;
-    use crate::core::msg::ExecuteMsg;
     use crate::core::state::load_asset_definition_by_type_v3;
     use crate::execute::delete_asset_definition::{
         delete_asset_definition, DeleteAssetDefinitionV1,
     };
+    use crate::testutil::execute_utilities::{
+        execute_delete_asset_definition, execute_freeze_asset_definition,
+    };
     use crate::testutil::test_constants::{DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE};
     use crate::testutil::test_utilities::{
-        empty_mock_info, mock_info_with_funds, single_attribute_for_key, test_instantiate_success,
-        InstArgs,
+        assert_response_attributes_contain, empty_mock_info, mock_info_with_funds,
+        test_instantiate_success, InstArgs,
     };
     use crate::util::constants::{ASSET_EVENT_TYPE_KEY, ASSET_TYPE_KEY};
     use crate::util::event_attributes::EventType;
@@ -123,16 +134,12 @@ mod tests {
             response.attributes.len(),
             "expected the correct number of attributes to be emitted",
         );
-        assert_eq!(
-            EventType::DeleteAssetDefinition.event_name(),
-            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
-            "expected the event type attribute to be set correctly",
-        );
-        assert_eq!(
-            DEFAULT_ASSET_TYPE,
-            single_attribute_for_key(&response, ASSET_TYPE_KEY),
-            "expected the asset type attribute to be set correctly",
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::DeleteAssetDefinition.event_name(),
         );
+        assert_response_attributes_contain(&response, ASSET_TYPE_KEY, DEFAULT_ASSET_TYPE);
         let err = load_asset_definition_by_type_v3(deps.as_ref().storage, DEFAULT_ASSET_TYPE)
             .expect_err("expected an error to occur when loading the default asset definition");
         assert!(
@@ -146,13 +153,11 @@ mod tests {
     fn test_delete_asset_definition_success_from_execute_route() {
         let mut deps = mock_provenance_dependencies();
         test_instantiate_success(deps.as_mut(), &InstArgs::default());
-        execute(
+        execute_delete_asset_definition(
             deps.as_mut(),
             mock_env(),
             empty_mock_info(DEFAULT_ADMIN_ADDRESS),
-            ExecuteMsg::DeleteAssetDefinition {
-                asset_type: DEFAULT_ASSET_TYPE.to_string(),
-            },
+            DEFAULT_ASSET_TYPE,
         )
         .expect("expected the deletion to be successful");
         let err = load_asset_definition_by_type_v3(deps.as_ref().storage, DEFAULT_ASSET_TYPE)
@@ -177,7 +182,7 @@ mod tests {
             "expected an error to occur when a non-admin user attempts to access the route",
         );
         assert!(
-            matches!(err, ContractError::Unauthorized { .. }),
+            err.is_unauthorized(),
             "expected an unauthorized error to be emitted, but got: {:?}",
             err,
         );
@@ -216,4 +221,29 @@ mod tests {
             err,
         );
     }
+
+    #[test]
+    fn test_delete_asset_definition_failure_for_frozen_definition() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        execute_freeze_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+        )
+        .expect("expected the asset definition to be frozen");
+        let error = execute_delete_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::AssetDefinitionFrozen { ref asset_type } if asset_type == DEFAULT_ASSET_TYPE),
+            "expected the frozen error to be returned when deleting a frozen definition, but got: {:?}",
+            error,
+        );
+    }
 }
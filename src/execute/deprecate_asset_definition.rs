@@ -0,0 +1,391 @@
+use crate::core::error::ContractError;
+use crate::core::msg::ExecuteMsg;
+use crate::core::state::{
+    load_asset_definition_by_type_v3, may_load_asset_definition_by_type_v3,
+    replace_asset_definition_v3,
+};
+use crate::util::aliases::{AssetResult, EntryPointResponse};
+use crate::util::contract_helpers::{check_admin_only, check_funds_are_empty};
+use crate::util::event_attributes::{EventAttributes, EventType};
+use crate::util::traits::OptionExtensions;
+
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// A transformation of [ExecuteMsg::DeprecateAssetDefinition](crate::core::msg::ExecuteMsg::DeprecateAssetDefinition)
+/// for ease of use in the underlying [deprecate_asset_definition](self::deprecate_asset_definition) function.
+///
+/// # Parameters
+///
+/// * `asset_type` The asset type to deprecate.
+/// * `replacement_asset_type` The asset type that requestors should migrate to, if any.
+pub struct DeprecateAssetDefinitionV1 {
+    pub asset_type: String,
+    pub replacement_asset_type: Option<String>,
+}
+impl DeprecateAssetDefinitionV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_type` The asset type to deprecate.
+    /// * `replacement_asset_type` The asset type that requestors should migrate to, if any.
+    pub fn new<S: Into<String>>(asset_type: S, replacement_asset_type: Option<String>) -> Self {
+        Self {
+            asset_type: asset_type.into(),
+            replacement_asset_type,
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [DeprecateAssetDefinition](crate::core::msg::ExecuteMsg::DeprecateAssetDefinition)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<DeprecateAssetDefinitionV1> {
+        match msg {
+            ExecuteMsg::DeprecateAssetDefinition {
+                asset_type,
+                replacement_asset_type,
+            } => DeprecateAssetDefinitionV1::new(asset_type, replacement_asset_type).to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::DeprecateAssetDefinition".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+impl TryFrom<ExecuteMsg> for DeprecateAssetDefinitionV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        DeprecateAssetDefinitionV1::from_execute_msg(msg)
+    }
+}
+
+/// Route implementation for [ExecuteMsg::DeprecateAssetDefinition](crate::core::msg::ExecuteMsg::DeprecateAssetDefinition).
+/// This function allows the admin address to mark an asset definition as [deprecated](crate::core::types::asset_definition::AssetDefinitionV3::deprecated),
+/// optionally pointing requestors to a replacement asset type.  The replacement must refer to an
+/// existing, enabled asset definition.  Deprecated definitions still accept onboarding, so this
+/// route never breaks existing integrations.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the deprecate asset definition v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn deprecate_asset_definition(
+    deps: DepsMut,
+    info: MessageInfo,
+    msg: DeprecateAssetDefinitionV1,
+) -> EntryPointResponse {
+    check_admin_only(&deps.as_ref(), &info)?;
+    check_funds_are_empty(&info)?;
+    let mut asset_definition = load_asset_definition_by_type_v3(deps.storage, &msg.asset_type)?;
+    asset_definition.check_not_frozen()?;
+    let replacement_asset_type = if let Some(replacement_asset_type) = msg.replacement_asset_type {
+        match may_load_asset_definition_by_type_v3(deps.storage, &replacement_asset_type)? {
+            Some(replacement) if replacement.enabled => replacement.asset_type.to_some(),
+            Some(_) => {
+                return ContractError::InvalidReplacementAssetType {
+                    asset_type: asset_definition.asset_type,
+                    replacement_asset_type,
+                    explanation: "the replacement asset definition is disabled".to_string(),
+                }
+                .to_err();
+            }
+            None => {
+                return ContractError::InvalidReplacementAssetType {
+                    asset_type: asset_definition.asset_type,
+                    replacement_asset_type,
+                    explanation: "no asset definition exists for the replacement asset type"
+                        .to_string(),
+                }
+                .to_err();
+            }
+        }
+    } else {
+        None
+    };
+    asset_definition.deprecated = true;
+    asset_definition.replacement_asset_type = replacement_asset_type;
+    replace_asset_definition_v3(deps.storage, &asset_definition)?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::DeprecateAssetDefinition)
+                .set_asset_type(&asset_definition.asset_type)
+                .set_replacement_asset_type(asset_definition.replacement_asset_type.as_deref()),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{message_info, mock_env};
+    use cosmwasm_std::Addr;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::state::load_asset_definition_by_type_v3;
+    use crate::core::types::asset_definition::AssetDefinitionInputV3;
+    use crate::testutil::execute_utilities::{
+        execute_add_asset_definition, execute_deprecate_asset_definition,
+        execute_freeze_asset_definition,
+    };
+    use crate::testutil::test_constants::{DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE};
+    use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, empty_mock_info, get_default_asset_definition_input,
+        mock_info_with_nhash, test_instantiate_success, InstArgs, MockOwnedDeps,
+    };
+    use crate::util::constants::{
+        ASSET_EVENT_TYPE_KEY, ASSET_TYPE_KEY, REPLACEMENT_ASSET_TYPE_KEY,
+    };
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{deprecate_asset_definition, DeprecateAssetDefinitionV1};
+
+    #[test]
+    fn test_valid_deprecate_asset_definition_via_execute() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        add_replacement_definition(&mut deps, "heloc", true);
+        let response = execute_deprecate_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            "HELOC".to_some(),
+        )
+        .expect("the deprecation should work correctly");
+        assert!(
+            response.messages.is_empty(),
+            "deprecating an asset definition should not require messages",
+        );
+        assert_eq!(
+            3,
+            response.attributes.len(),
+            "deprecating an asset definition should produce the correct number of attributes",
+        );
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            EventType::DeprecateAssetDefinition.event_name().as_str(),
+        );
+        assert_response_attributes_contain(&response, ASSET_TYPE_KEY, DEFAULT_ASSET_TYPE);
+        assert_response_attributes_contain(&response, REPLACEMENT_ASSET_TYPE_KEY, "heloc");
+        let asset_definition =
+            load_asset_definition_by_type_v3(deps.as_ref().storage, DEFAULT_ASSET_TYPE)
+                .expect("the default asset definition should exist in storage");
+        assert!(
+            asset_definition.deprecated,
+            "the asset definition should be deprecated after the route executes",
+        );
+        assert_eq!(
+            "heloc",
+            asset_definition
+                .replacement_asset_type
+                .expect("the replacement asset type should be set"),
+            "the replacement should be stored using the asset type of the replacement definition",
+        );
+        assert!(
+            asset_definition.enabled,
+            "deprecating an asset definition should not disable it",
+        );
+    }
+
+    #[test]
+    fn test_valid_deprecate_asset_definition_without_replacement() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let response = deprecate_asset_definition(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DeprecateAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, None),
+        )
+        .expect("a deprecation without a replacement should succeed");
+        assert_eq!(
+            2,
+            response.attributes.len(),
+            "the replacement attribute should be omitted when no replacement is provided",
+        );
+        let asset_definition =
+            load_asset_definition_by_type_v3(deps.as_ref().storage, DEFAULT_ASSET_TYPE)
+                .expect("the default asset definition should exist in storage");
+        assert!(
+            asset_definition.deprecated,
+            "the asset definition should be deprecated after the route executes",
+        );
+        assert!(
+            asset_definition.replacement_asset_type.is_none(),
+            "no replacement asset type should be stored",
+        );
+    }
+
+    #[test]
+    fn test_invalid_deprecate_asset_definition_for_invalid_msg() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = execute_deprecate_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            DEFAULT_ASSET_TYPE.to_some(),
+        )
+        .unwrap_err();
+        assert!(
+            error.is_invalid_message_fields(),
+            "expected the invalid message fields error to be returned when the message is malformatted, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_deprecate_asset_definition_for_invalid_sender() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = deprecate_asset_definition(
+            deps.as_mut(),
+            message_info(&Addr::unchecked("not-the-admin"), &[]),
+            DeprecateAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, None),
+        )
+        .unwrap_err();
+        assert!(
+            error.is_unauthorized(),
+            "expected the unauthorized error to be returned when the sender is not the admin, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_deprecate_asset_definition_for_provided_funds() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = deprecate_asset_definition(
+            deps.as_mut(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            DeprecateAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, None),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when the sender provides funds, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_deprecate_asset_definition_for_missing_asset_type() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = deprecate_asset_definition(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DeprecateAssetDefinitionV1::new("no-u", None),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::RecordNotFound { .. }),
+            "expected the not found error to be returned, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_deprecate_asset_definition_for_missing_replacement() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = deprecate_asset_definition(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DeprecateAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, "heloc".to_string().to_some()),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(
+                error,
+                ContractError::InvalidReplacementAssetType { ref replacement_asset_type, .. } if replacement_asset_type == "heloc",
+            ),
+            "expected the invalid replacement error to be returned for an unknown replacement, but got: {:?}",
+            error,
+        );
+        assert_not_deprecated(&deps);
+    }
+
+    #[test]
+    fn test_invalid_deprecate_asset_definition_for_disabled_replacement() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        add_replacement_definition(&mut deps, "heloc", false);
+        let error = deprecate_asset_definition(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DeprecateAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, "heloc".to_string().to_some()),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(
+                error,
+                ContractError::InvalidReplacementAssetType { ref replacement_asset_type, .. } if replacement_asset_type == "heloc",
+            ),
+            "expected the invalid replacement error to be returned for a disabled replacement, but got: {:?}",
+            error,
+        );
+        assert_not_deprecated(&deps);
+    }
+
+    #[test]
+    fn test_invalid_deprecate_asset_definition_for_frozen_definition() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        execute_freeze_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+        )
+        .expect("the freeze should succeed");
+        let error = deprecate_asset_definition(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DeprecateAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, None),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::AssetDefinitionFrozen { ref asset_type } if asset_type == DEFAULT_ASSET_TYPE),
+            "expected the frozen error to be returned when deprecating a frozen definition, but got: {:?}",
+            error,
+        );
+    }
+
+    fn add_replacement_definition(deps: &mut MockOwnedDeps, asset_type: &str, enabled: bool) {
+        execute_add_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            AssetDefinitionInputV3 {
+                asset_type: asset_type.to_string(),
+                enabled: enabled.to_some(),
+                bind_name: false.to_some(),
+                ..get_default_asset_definition_input()
+            },
+        )
+        .expect("adding the replacement asset definition should succeed");
+    }
+
+    fn assert_not_deprecated(deps: &MockOwnedDeps) {
+        assert!(
+            !load_asset_definition_by_type_v3(deps.as_ref().storage, DEFAULT_ASSET_TYPE)
+                .expect("the default asset definition should exist in storage")
+                .deprecated,
+            "the asset definition should not be deprecated after a rejected request",
+        );
+    }
+}
@@ -0,0 +1,489 @@
+use crate::core::error::ContractError;
+use crate::core::msg::ExecuteMsg;
+use crate::core::state::{
+    decrement_verifier_pending, delete_fee_payment_detail, may_load_fee_payment_detail,
+    update_verifier_status_index,
+};
+use crate::core::types::asset_identifier::AssetIdentifier;
+use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+use crate::core::types::asset_verification_result::AssetVerificationResult;
+use crate::service::asset_meta_repository::AssetMetaRepository;
+use crate::service::deps_manager::DepsManager;
+use crate::service::message_gathering_service::MessageGatheringService;
+use crate::util::aliases::{AssetResult, EntryPointResponse};
+use crate::util::contract_helpers::{check_funds_are_empty, generate_os_gateway_access_revoke};
+use crate::util::event_attributes::{EventAttributes, EventType};
+
+use cosmwasm_std::{Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// A transformation of [ExecuteMsg::ExpireStaleVerification](crate::core::msg::ExecuteMsg::ExpireStaleVerification)
+/// for ease of use in the underlying [expire_stale_verification](self::expire_stale_verification) function.
+///
+/// # Parameters
+///
+/// * `identifier` An instance of the asset identifier enum that helps the contract identify which
+/// scope is awaiting verification.
+/// * `asset_type` The asset type of the pending verification.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ExpireStaleVerificationV1 {
+    pub identifier: AssetIdentifier,
+    pub asset_type: String,
+}
+impl ExpireStaleVerificationV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `identifier` An instance of the asset identifier enum that helps the contract identify which
+    /// scope is awaiting verification.
+    /// * `asset_type` The asset type of the pending verification.
+    pub fn new<S: Into<String>>(identifier: AssetIdentifier, asset_type: S) -> Self {
+        ExpireStaleVerificationV1 {
+            identifier,
+            asset_type: asset_type.into(),
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [ExpireStaleVerification](crate::core::msg::ExecuteMsg::ExpireStaleVerification)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<ExpireStaleVerificationV1> {
+        match msg {
+            ExecuteMsg::ExpireStaleVerification {
+                identifier,
+                asset_type,
+            } => Self::new(identifier.to_asset_identifier()?, asset_type).to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::ExpireStaleVerification".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+impl TryFrom<ExecuteMsg> for ExpireStaleVerificationV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        ExpireStaleVerificationV1::from_execute_msg(msg)
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::ExpireStaleVerification](crate::core::msg::ExecuteMsg::ExpireStaleVerification)
+/// message is provided.  Denies a pending verification once the block height has passed its
+/// [pending_expiry_block](crate::core::types::asset_scope_attribute::AssetScopeAttribute::pending_expiry_block).
+/// The verifier is not paid.  The fees collected during onboarding are refunded to the requestor
+/// and the [FeePaymentDetail](crate::core::types::fee_payment_detail::FeePaymentDetail) is removed.
+/// Any object store gateway access granted to the verifier during onboarding is revoked.
+///
+/// # Parameters
+///
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `repository` A helper collection of traits that allows complex lookups of scope values and
+/// emits messages to construct the process of expiration as a collection of messages to produce in
+/// the function's result.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the expire stale verification v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn expire_stale_verification<'a, T>(
+    env: &Env,
+    repository: T,
+    info: MessageInfo,
+    msg: ExpireStaleVerificationV1,
+) -> EntryPointResponse
+where
+    T: AssetMetaRepository + MessageGatheringService + DepsManager<'a>,
+{
+    check_funds_are_empty(&info)?;
+    let scope_address = msg.identifier.get_scope_address()?;
+    let scope_attribute = repository.get_asset_by_asset_type(&scope_address, &msg.asset_type)?;
+    if scope_attribute.onboarding_status != AssetOnboardingStatus::Pending {
+        return ContractError::AssetAlreadyVerified {
+            scope_address,
+            asset_type: msg.asset_type,
+            status: scope_attribute.onboarding_status,
+        }
+        .to_err();
+    }
+    match scope_attribute.pending_expiry_block {
+        Some(expiry_block) if env.block.height > expiry_block => {}
+        Some(expiry_block) => {
+            return ContractError::UnexpectedState {
+                explanation: format!(
+                    "verification for asset [{}] with asset type [{}] does not expire until after block [{}]",
+                    scope_address, msg.asset_type, expiry_block,
+                ),
+            }
+            .to_err();
+        }
+        None => {
+            return ContractError::UnexpectedState {
+                explanation: format!(
+                    "verification for asset [{}] with asset type [{}] has no expiry block and cannot be expired",
+                    scope_address, msg.asset_type,
+                ),
+            }
+            .to_err();
+        }
+    }
+    let mut expired_attribute = scope_attribute.clone();
+    expired_attribute.onboarding_status = AssetOnboardingStatus::Denied;
+    expired_attribute.latest_verification_result = AssetVerificationResult {
+        message: "verification expired".to_string(),
+        success: false,
+    }
+    .into();
+    expired_attribute.pending_expiry_block = None;
+    repository.update_attribute(env, &expired_attribute)?;
+    repository.use_deps(|deps| {
+        update_verifier_status_index(deps.storage, Some(&scope_attribute), &expired_attribute)
+    })?;
+    repository.use_deps(|deps| {
+        decrement_verifier_pending(
+            deps.storage,
+            &msg.asset_type,
+            scope_attribute.verifier_address.as_str(),
+        )
+    })?;
+    // The verifier never received the fees, so they are refunded to the requestor
+    if let Some(fee_payment_detail) = repository
+        .use_deps(|deps| may_load_fee_payment_detail(deps.storage, &scope_address, &msg.asset_type))
+    {
+        repository.use_deps(|deps| {
+            delete_fee_payment_detail(deps.storage, &scope_address, &msg.asset_type)
+        })?;
+        if let Some(refund_msg) =
+            fee_payment_detail.to_refund_msg(&scope_attribute.requestor_address)
+        {
+            repository.add_message(refund_msg);
+        }
+    }
+    let access_revoke = repository
+        .use_deps(|deps| generate_os_gateway_access_revoke(deps.storage, &scope_attribute))?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::for_asset_event(
+                EventType::VerificationExpired,
+                &msg.asset_type,
+                &scope_address,
+            )
+            .set_verifier(&scope_attribute.verifier_address)
+            .set_new_asset_onboarding_status(&expired_attribute.onboarding_status),
+        )
+        .add_attributes(access_revoke)
+        .add_messages(repository.get_messages())
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{coin, BankMsg, CosmosMsg, Env};
+    use os_gateway_contract_attributes::{OS_GATEWAY_EVENT_TYPES, OS_GATEWAY_KEYS};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::state::{load_fee_payment_detail, may_load_fee_payment_detail};
+    use crate::core::types::asset_identifier::AssetIdentifier;
+    use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+    use crate::core::types::asset_scope_attribute::AssetScopeAttribute;
+    use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::service::asset_meta_repository::AssetMetaRepository;
+    use crate::service::asset_meta_service::AssetMetaService;
+    use crate::testutil::execute_utilities::execute_expire_stale_verification;
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS, DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, assert_single_item_by, empty_mock_info,
+        get_default_verifier_detail, intercept_add_or_update_attribute, mock_info_with_nhash,
+        setup_no_attribute_response, setup_test_suite, InstArgs, MockOwnedDeps,
+    };
+    use crate::testutil::verify_asset_helpers::{test_verify_asset, TestVerifyAsset};
+    use crate::util::aliases::EntryPointResponse;
+    use crate::util::constants::{
+        ASSET_EVENT_TYPE_KEY, ASSET_SCOPE_ADDRESS_KEY, ASSET_TYPE_KEY,
+        NEW_ASSET_ONBOARDING_STATUS_KEY, VERIFIER_ADDRESS_KEY,
+    };
+    use crate::util::event_attributes::EventType;
+    use crate::util::functions::generate_os_gateway_grant_id;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{expire_stale_verification, ExpireStaleVerificationV1};
+
+    const TIMEOUT_BLOCKS: u64 = 100;
+    // Anyone may expire a stale verification, so an account unrelated to the asset is used
+    const UNRELATED_SENDER_ADDRESS: &str = "tp1hsqtppgy7mgd64q0uvk7q39qa7h8hp30urqs6n";
+
+    #[test]
+    fn test_onboarding_populates_pending_expiry_block() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps);
+        assert_eq!(
+            (mock_env().block.height + TIMEOUT_BLOCKS).to_some(),
+            get_default_attribute(&mut deps).pending_expiry_block,
+            "the expiry block should be derived from the onboarding block height and the verifier's timeout",
+        );
+    }
+
+    #[test]
+    fn test_expire_stale_verification_before_deadline_fails() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps);
+        let err = expire(&mut deps, &env_at_offset(TIMEOUT_BLOCKS - 1))
+            .expect_err("expiring a verification before its deadline should fail");
+        assert!(
+            matches!(err, ContractError::UnexpectedState { .. }),
+            "expected an unexpected state error, but got: {:?}",
+            err,
+        );
+        assert_verification_still_pending(&mut deps);
+    }
+
+    #[test]
+    fn test_expire_stale_verification_at_deadline_fails() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps);
+        let err = expire(&mut deps, &env_at_offset(TIMEOUT_BLOCKS))
+            .expect_err("expiring a verification at its deadline block should fail");
+        match err {
+            ContractError::UnexpectedState { explanation } => {
+                assert_eq!(
+                    format!(
+                        "verification for asset [{}] with asset type [{}] does not expire until after block [{}]",
+                        DEFAULT_SCOPE_ADDRESS,
+                        DEFAULT_ASSET_TYPE,
+                        mock_env().block.height + TIMEOUT_BLOCKS,
+                    ),
+                    explanation,
+                    "the error should describe the expiry block",
+                );
+            }
+            _ => panic!("unexpected error encountered: {:?}", err),
+        };
+        assert_verification_still_pending(&mut deps);
+    }
+
+    #[test]
+    fn test_expire_stale_verification_after_deadline_denies_and_refunds() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps);
+        let detail =
+            load_fee_payment_detail(&deps.storage, DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+                .expect("the fee payment detail should be stored after onboarding");
+        let response = expire(&mut deps, &env_at_offset(TIMEOUT_BLOCKS + 1))
+            .expect("expiring a verification after its deadline should succeed");
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::VerificationExpired.event_name(),
+        );
+        assert_response_attributes_contain(&response, ASSET_TYPE_KEY, DEFAULT_ASSET_TYPE);
+        assert_response_attributes_contain(
+            &response,
+            ASSET_SCOPE_ADDRESS_KEY,
+            DEFAULT_SCOPE_ADDRESS,
+        );
+        assert_response_attributes_contain(
+            &response,
+            VERIFIER_ADDRESS_KEY,
+            DEFAULT_VERIFIER_ADDRESS,
+        );
+        assert_response_attributes_contain(
+            &response,
+            NEW_ASSET_ONBOARDING_STATUS_KEY,
+            AssetOnboardingStatus::Denied.to_string().as_str(),
+        );
+        assert_response_attributes_contain(
+            &response,
+            OS_GATEWAY_KEYS.event_type,
+            OS_GATEWAY_EVENT_TYPES.access_revoke,
+        );
+        assert_response_attributes_contain(
+            &response,
+            OS_GATEWAY_KEYS.target_account,
+            DEFAULT_VERIFIER_ADDRESS,
+        );
+        assert_response_attributes_contain(
+            &response,
+            OS_GATEWAY_KEYS.access_grant_id,
+            &generate_os_gateway_grant_id(None, DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS),
+        );
+        assert_eq!(
+            2,
+            response.messages.len(),
+            "an attribute update and a refund should be emitted",
+        );
+        let refund_msg = &assert_single_item_by(
+            &response.messages,
+            "a single refund message should be emitted",
+            |msg| matches!(msg.msg, CosmosMsg::Bank(..)),
+        )
+        .msg;
+        if let CosmosMsg::Bank(BankMsg::Send { to_address, amount }) = refund_msg {
+            assert_eq!(
+                DEFAULT_SENDER_ADDRESS, to_address,
+                "the refund should be sent to the requestor of the asset",
+            );
+            assert_eq!(
+                &vec![coin(
+                    detail.sum_costs(),
+                    &detail.payments.first().unwrap().amount.denom
+                )],
+                amount,
+                "the requestor should be refunded the full amount collected during onboarding",
+            );
+        } else {
+            panic!("unexpected refund message: {:?}", refund_msg);
+        }
+        assert!(
+            may_load_fee_payment_detail(&deps.storage, DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+                .is_none(),
+            "the fee payment detail should be removed after the verification expires",
+        );
+        intercept_add_or_update_attribute(
+            &mut deps,
+            response,
+            "expiration should update the attribute",
+        )
+        .expect("the attribute update should be intercepted");
+        let attribute = get_default_attribute(&mut deps);
+        assert_eq!(
+            AssetOnboardingStatus::Denied,
+            attribute.onboarding_status,
+            "the expired verification should be denied",
+        );
+        assert_eq!(
+            None, attribute.pending_expiry_block,
+            "the expiry block should be cleared once the verification is no longer pending",
+        );
+        let verification_result = attribute
+            .latest_verification_result
+            .expect("a verification result should be recorded for the expiration");
+        assert!(
+            !verification_result.success,
+            "the expiration should be recorded as an unsuccessful verification",
+        );
+        // The asset is free to be onboarded again as a retry
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding should succeed after the verification expires");
+        assert_eq!(
+            1,
+            get_default_attribute(&mut deps).retry_count,
+            "onboarding after an expiration should be counted as a retry",
+        );
+    }
+
+    #[test]
+    fn test_expire_stale_verification_fails_without_expiry_block() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding should succeed");
+        let err = expire(&mut deps, &env_at_offset(u32::MAX as u64))
+            .expect_err("a verification without an expiry block should never expire");
+        assert!(
+            matches!(err, ContractError::UnexpectedState { .. }),
+            "expected an unexpected state error, but got: {:?}",
+            err,
+        );
+        assert_verification_still_pending(&mut deps);
+    }
+
+    #[test]
+    fn test_expire_stale_verification_fails_for_verified_asset() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps);
+        test_verify_asset(&mut deps, &mock_env(), TestVerifyAsset::default())
+            .expect("verification should succeed");
+        assert_eq!(
+            None,
+            get_default_attribute(&mut deps).pending_expiry_block,
+            "the expiry block should be cleared once the asset is verified",
+        );
+        let err = expire(&mut deps, &env_at_offset(TIMEOUT_BLOCKS + 1))
+            .expect_err("a completed verification should not be expired");
+        assert!(
+            matches!(err, ContractError::AssetAlreadyVerified { .. }),
+            "expected an asset already verified error, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_expire_stale_verification_fails_for_provided_funds() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps);
+        let err = expire_stale_verification(
+            &env_at_offset(TIMEOUT_BLOCKS + 1),
+            AssetMetaService::new(deps.as_mut()),
+            mock_info_with_nhash(UNRELATED_SENDER_ADDRESS, 150),
+            ExpireStaleVerificationV1::new(
+                AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
+                DEFAULT_ASSET_TYPE,
+            ),
+        )
+        .expect_err("expiring a verification with funds should fail");
+        assert!(
+            matches!(err, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when the sender provides funds, but got: {:?}",
+            err,
+        );
+    }
+
+    fn setup_onboarded_asset(deps: &mut MockOwnedDeps) {
+        setup_test_suite(
+            deps,
+            &InstArgs::with_single_verifier(VerifierDetailV2 {
+                verification_timeout_blocks: TIMEOUT_BLOCKS.to_some(),
+                ..get_default_verifier_detail()
+            }),
+        );
+        setup_no_attribute_response(deps, None);
+        test_onboard_asset(deps, TestOnboardAsset::default()).expect("onboarding should succeed");
+    }
+
+    fn env_at_offset(blocks: u64) -> Env {
+        let mut env = mock_env();
+        env.block.height += blocks;
+        env
+    }
+
+    fn expire(deps: &mut MockOwnedDeps, env: &Env) -> EntryPointResponse {
+        execute_expire_stale_verification(
+            deps.as_mut(),
+            env.to_owned(),
+            empty_mock_info(UNRELATED_SENDER_ADDRESS),
+            AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+        )
+    }
+
+    fn get_default_attribute(deps: &mut MockOwnedDeps) -> AssetScopeAttribute {
+        AssetMetaService::new(deps.as_mut())
+            .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+            .expect("the scope attribute should exist")
+    }
+
+    fn assert_verification_still_pending(deps: &mut MockOwnedDeps) {
+        assert_eq!(
+            AssetOnboardingStatus::Pending,
+            get_default_attribute(deps).onboarding_status,
+            "the verification should remain pending",
+        );
+        assert!(
+            may_load_fee_payment_detail(&deps.storage, DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+                .is_some(),
+            "the fee payment detail should remain stored",
+        );
+    }
+}
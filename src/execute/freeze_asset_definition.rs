@@ -0,0 +1,258 @@
+use crate::core::error::ContractError;
+use crate::core::msg::ExecuteMsg;
+use crate::core::state::{load_asset_definition_by_type_v3, replace_asset_definition_v3};
+use crate::util::aliases::{AssetResult, EntryPointResponse};
+use crate::util::contract_helpers::{check_admin_only, check_funds_are_empty};
+use crate::util::event_attributes::{EventAttributes, EventType};
+
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// A transformation of [ExecuteMsg::FreezeAssetDefinition](crate::core::msg::ExecuteMsg::FreezeAssetDefinition)
+/// for ease of use in the underlying [freeze_asset_definition](self::freeze_asset_definition) function.
+///
+/// # Parameters
+///
+/// * `asset_type` The asset type to freeze.
+pub struct FreezeAssetDefinitionV1 {
+    pub asset_type: String,
+}
+impl FreezeAssetDefinitionV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_type` The asset type to freeze.
+    pub fn new(asset_type: &str) -> Self {
+        Self {
+            asset_type: asset_type.to_string(),
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [FreezeAssetDefinition](crate::core::msg::ExecuteMsg::FreezeAssetDefinition)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<FreezeAssetDefinitionV1> {
+        match msg {
+            ExecuteMsg::FreezeAssetDefinition { asset_type } => {
+                FreezeAssetDefinitionV1::new(&asset_type).to_ok()
+            }
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::FreezeAssetDefinition".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+impl TryFrom<ExecuteMsg> for FreezeAssetDefinitionV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        FreezeAssetDefinitionV1::from_execute_msg(msg)
+    }
+}
+
+/// Route implementation for [ExecuteMsg::FreezeAssetDefinition](crate::core::msg::ExecuteMsg::FreezeAssetDefinition).
+/// This function allows the admin address to permanently lock an asset definition against further
+/// modification by setting its [frozen](crate::core::types::asset_definition::AssetDefinitionV3::frozen)
+/// flag.  Assets can still be onboarded as a frozen asset type while it is enabled.  There is no
+/// route to unfreeze a definition, so freezing an already-frozen definition is rejected.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the freeze asset definition v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn freeze_asset_definition(
+    deps: DepsMut,
+    info: MessageInfo,
+    msg: FreezeAssetDefinitionV1,
+) -> EntryPointResponse {
+    check_admin_only(&deps.as_ref(), &info)?;
+    check_funds_are_empty(&info)?;
+    let mut asset_definition = load_asset_definition_by_type_v3(deps.storage, &msg.asset_type)?;
+    asset_definition.check_not_frozen()?;
+    asset_definition.frozen = true;
+    replace_asset_definition_v3(deps.storage, &asset_definition)?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::FreezeAssetDefinition)
+                .set_asset_type(&asset_definition.asset_type),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{message_info, mock_env};
+    use cosmwasm_std::Addr;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::state::load_asset_definition_by_type_v3;
+    use crate::testutil::execute_utilities::execute_freeze_asset_definition;
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE};
+    use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, empty_mock_info, mock_info_with_nhash,
+        setup_no_attribute_response, setup_test_suite, test_instantiate_success, InstArgs,
+    };
+    use crate::util::constants::{ASSET_EVENT_TYPE_KEY, ASSET_TYPE_KEY};
+    use crate::util::event_attributes::EventType;
+
+    use super::{freeze_asset_definition, FreezeAssetDefinitionV1};
+
+    #[test]
+    fn test_valid_freeze_asset_definition_via_execute() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let response = execute_freeze_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+        )
+        .expect("the freeze should work correctly");
+        assert!(
+            response.messages.is_empty(),
+            "freezing an asset definition should not require messages",
+        );
+        assert_eq!(
+            2,
+            response.attributes.len(),
+            "freezing an asset definition should produce the correct number of attributes",
+        );
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            EventType::FreezeAssetDefinition.event_name().as_str(),
+        );
+        assert_response_attributes_contain(&response, ASSET_TYPE_KEY, DEFAULT_ASSET_TYPE);
+        let asset_definition =
+            load_asset_definition_by_type_v3(deps.as_ref().storage, DEFAULT_ASSET_TYPE)
+                .expect("the default asset definition should exist in storage");
+        assert!(
+            asset_definition.frozen,
+            "the asset definition should be frozen after the route executes",
+        );
+        assert!(
+            asset_definition.enabled,
+            "freezing an asset definition should not disable it",
+        );
+    }
+
+    #[test]
+    fn test_frozen_asset_definition_can_still_be_onboarded() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        freeze_asset_definition(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            FreezeAssetDefinitionV1::new(DEFAULT_ASSET_TYPE),
+        )
+        .expect("the freeze should succeed");
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding a frozen but enabled asset type should succeed");
+    }
+
+    #[test]
+    fn test_invalid_freeze_asset_definition_for_invalid_msg() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = execute_freeze_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            String::new(),
+        )
+        .unwrap_err();
+        assert!(
+            error.is_invalid_message_fields(),
+            "expected the invalid message fields error to be returned when the message is malformatted, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_freeze_asset_definition_for_invalid_sender() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = freeze_asset_definition(
+            deps.as_mut(),
+            message_info(&Addr::unchecked("not-the-admin"), &[]),
+            FreezeAssetDefinitionV1::new(DEFAULT_ASSET_TYPE),
+        )
+        .unwrap_err();
+        assert!(
+            error.is_unauthorized(),
+            "expected the unauthorized error to be returned when the sender is not the admin, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_freeze_asset_definition_for_provided_funds() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = freeze_asset_definition(
+            deps.as_mut(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            FreezeAssetDefinitionV1::new(DEFAULT_ASSET_TYPE),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when the sender provides funds, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_freeze_asset_definition_for_missing_asset_type() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = freeze_asset_definition(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            FreezeAssetDefinitionV1::new("no-u"),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::RecordNotFound { .. }),
+            "expected the not found error to be returned, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_freeze_asset_definition_for_already_frozen_definition() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        freeze_asset_definition(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            FreezeAssetDefinitionV1::new(DEFAULT_ASSET_TYPE),
+        )
+        .expect("the first freeze should succeed");
+        let error = freeze_asset_definition(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            FreezeAssetDefinitionV1::new(DEFAULT_ASSET_TYPE),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::AssetDefinitionFrozen { ref asset_type } if asset_type == DEFAULT_ASSET_TYPE),
+            "expected the frozen error to be returned when freezing a frozen definition, but got: {:?}",
+            error,
+        );
+    }
+}
@@ -8,30 +8,110 @@ pub mod add_asset_definition;
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
 /// function.
 pub mod add_asset_verifier;
+/// Contains the functionality used by the [AppealVerification](crate::core::msg::ExecuteMsg::AppealVerification)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod appeal_verification;
+/// Contains the functionality used by the [BatchOnboardAsset](crate::core::msg::ExecuteMsg::BatchOnboardAsset)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod batch_onboard_asset;
+/// Contains the functionality used by the [BatchVerifyAsset](crate::core::msg::ExecuteMsg::BatchVerifyAsset)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod batch_verify_asset;
+/// Contains the functionality used by the [BulkDeleteAssetDefinitions](crate::core::msg::ExecuteMsg::BulkDeleteAssetDefinitions)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod bulk_delete_asset_definitions;
+/// Contains the functionality used by the [CancelPendingOnboarding](crate::core::msg::ExecuteMsg::CancelPendingOnboarding)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod cancel_pending_onboarding;
+/// Contains the functionality used by the [CloneAssetDefinition](crate::core::msg::ExecuteMsg::CloneAssetDefinition)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod clone_asset_definition;
 /// Contains the functionality used by the [DeleteAssetDefinition](crate::core::msg::ExecuteMsg::DeleteAssetDefinition)
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
 /// function.
 pub mod delete_asset_definition;
+/// Contains the functionality used by the [DeprecateAssetDefinition](crate::core::msg::ExecuteMsg::DeprecateAssetDefinition)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod deprecate_asset_definition;
+/// Contains the functionality used by the [ExpireStaleVerification](crate::core::msg::ExecuteMsg::ExpireStaleVerification)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod expire_stale_verification;
+/// Contains the functionality used by the [FreezeAssetDefinition](crate::core::msg::ExecuteMsg::FreezeAssetDefinition)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod freeze_asset_definition;
 /// Contains the functionality used by the [OnboardAsset](crate::core::msg::ExecuteMsg::OnboardAsset)
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
 /// function.
 pub mod onboard_asset;
+/// Contains the functionality used by the [PurgeFeePaymentDetail](crate::core::msg::ExecuteMsg::PurgeFeePaymentDetail)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod purge_fee_payment_detail;
+/// Contains the functionality used by the [PurgeStaleFeeDetail](crate::core::msg::ExecuteMsg::PurgeStaleFeeDetail)
+/// and [SetFeeDetailMaxAge](crate::core::msg::ExecuteMsg::SetFeeDetailMaxAge) [ExecuteMsg](crate::core::msg::ExecuteMsg)
+/// variants when invoked via the [execute](crate::contract::execute) function.
+pub mod purge_stale_fee_detail;
+/// Contains the functionality used by the [RelayOsGatewayAccess](crate::core::msg::ExecuteMsg::RelayOsGatewayAccess)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod relay_os_gateway_access;
+/// Contains the functionality used by the [ResolveAppeal](crate::core::msg::ExecuteMsg::ResolveAppeal)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod resolve_appeal;
+/// Contains the functionality used by the [RotateVerifierAddress](crate::core::msg::ExecuteMsg::RotateVerifierAddress)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod rotate_verifier_address;
 /// Contains the functionality used by the [ToggleAssetDefinition](crate::core::msg::ExecuteMsg::ToggleAssetDefinition)
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
 /// function.
 pub mod toggle_asset_definition;
-/// Contains the functionality used by the [UpdateAccessRoutes](crate::core::msg::ExecuteMsg::UpdateAccessRoutes)
+/// Contains the functionality used by the [ToggleAssetVerifier](crate::core::msg::ExecuteMsg::ToggleAssetVerifier)
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
 /// function.
+pub mod toggle_asset_verifier;
+/// Contains the functionality used by the [TransferAdmin](crate::core::msg::ExecuteMsg::TransferAdmin)
+/// and [AcceptAdminTransfer](crate::core::msg::ExecuteMsg::AcceptAdminTransfer) [ExecuteMsg](crate::core::msg::ExecuteMsg)
+/// variants when invoked via the [execute](crate::contract::execute) function.
+pub mod transfer_admin;
+/// Contains the functionality used by the [UpdateAccessRoutes](crate::core::msg::ExecuteMsg::UpdateAccessRoutes)
+/// and [SetMaxAccessRoutesPerOwner](crate::core::msg::ExecuteMsg::SetMaxAccessRoutesPerOwner) [ExecuteMsg](crate::core::msg::ExecuteMsg)
+/// variants when invoked via the [execute](crate::contract::execute) function.
 pub mod update_access_routes;
+/// Contains the functionality used by the [UpdateAllowedRequestors](crate::core::msg::ExecuteMsg::UpdateAllowedRequestors)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod update_allowed_requestors;
 /// Contains the functionality used by the [UpdateAssetDefinition](crate::core::msg::ExecuteMsg::UpdateAssetDefinition)
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
 /// function.
 pub mod update_asset_definition;
+/// Contains the functionality used by the [UpdateAssetDefinitionDescription](crate::core::msg::ExecuteMsg::UpdateAssetDefinitionDescription)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod update_asset_definition_description;
 /// Contains the functionality used by the [UpdateAssetVerifier](crate::core::msg::ExecuteMsg::UpdateAssetVerifier)
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
 /// function.
 pub mod update_asset_verifier;
+/// Contains the functionality used by the [UpdateOsGatewayGrantPrefix](crate::core::msg::ExecuteMsg::UpdateOsGatewayGrantPrefix)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod update_os_gateway_grant_prefix;
+/// Contains the functionality used by the [UpdateVerifierEntityDetail](crate::core::msg::ExecuteMsg::UpdateVerifierEntityDetail)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod update_verifier_entity_detail;
 /// Contains the functionality used by the [VerifyAsset](crate::core::msg::ExecuteMsg::VerifyAsset)
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
 /// function.
@@ -1,19 +1,25 @@
 use crate::core::error::ContractError;
 use crate::core::msg::ExecuteMsg;
-use crate::core::state::{load_asset_definition_by_type_v3, STATE_V2};
+use crate::core::state::{
+    decrement_verifier_pending, delete_fee_payment_detail,
+    increment_verifier_block_classification_count, load_asset_definition_by_type_v3,
+    load_verifier_block_classification_count, may_load_denial_timestamp,
+    may_load_fee_payment_detail, STATE_V2,
+};
 use crate::core::types::access_route::AccessRoute;
 use crate::core::types::asset_identifier::AssetIdentifier;
 use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
 use crate::core::types::asset_scope_attribute::AssetScopeAttribute;
+use crate::core::types::fee_payment_detail::FeePaymentDetail;
 use crate::service::asset_meta_repository::AssetMetaRepository;
 use crate::service::deps_manager::DepsManager;
 use crate::service::message_gathering_service::MessageGatheringService;
 use crate::util::aliases::{AssetResult, EntryPointResponse};
-use crate::util::contract_helpers::check_funds_are_empty;
+use crate::util::contract_helpers::{check_funds_are_empty, generate_os_gateway_access_revoke};
 use crate::util::event_attributes::{EventAttributes, EventType};
 use crate::util::functions::generate_os_gateway_grant_id;
 use crate::util::traits::OptionExtensions;
-use cosmwasm_std::{Env, MessageInfo, Response};
+use cosmwasm_std::{Env, Event, MessageInfo, Response, Uint128};
 use os_gateway_contract_attributes::OsGatewayAttributeGenerator;
 use provwasm_std::types::provenance::metadata::v1::MetadataQuerier;
 use result_extensions::ResultExtensions;
@@ -40,6 +46,13 @@ use result_extensions::ResultExtensions;
 /// include values that signal to any [Object Store Gateway](https://github.com/FigureTechnologies/object-store-gateway)
 /// watching the events that the selected verifier has permission to inspect the identified scope's
 /// records via fetch routes.  This behavior defaults to TRUE.
+/// * `expected_fee` An optional parameter that, when provided, causes the request to be rejected
+/// unless the total fee charged for the onboarding exactly matches it.
+/// * `memo` An optional note from the requestor that is emitted in the onboarding event when it is
+/// not blank.
+/// * `metadata` Optional free-form data from the requestor that is stored unmodified in the
+/// [requestor_metadata](crate::core::types::asset_scope_attribute::AssetScopeAttribute::requestor_metadata)
+/// of the generated scope attribute.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct OnboardAssetV1 {
     pub identifier: AssetIdentifier,
@@ -47,6 +60,9 @@ pub struct OnboardAssetV1 {
     pub verifier_address: String,
     pub access_routes: Vec<AccessRoute>,
     pub add_os_gateway_permission: bool,
+    pub expected_fee: Option<Uint128>,
+    pub memo: Option<String>,
+    pub metadata: Option<String>,
 }
 impl OnboardAssetV1 {
     /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
@@ -65,12 +81,18 @@ impl OnboardAssetV1 {
                 verifier_address,
                 access_routes,
                 add_os_gateway_permission,
+                expected_fee,
+                memo,
+                metadata,
             } => OnboardAssetV1 {
                 identifier: identifier.to_asset_identifier()?,
                 asset_type,
                 verifier_address,
                 access_routes: access_routes.unwrap_or_default(),
                 add_os_gateway_permission: add_os_gateway_permission.unwrap_or(true),
+                expected_fee,
+                memo,
+                metadata,
             }
             .to_ok(),
             _ => ContractError::InvalidMessageType {
@@ -80,6 +102,13 @@ impl OnboardAssetV1 {
         }
     }
 }
+impl TryFrom<ExecuteMsg> for OnboardAssetV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        OnboardAssetV1::from_execute_msg(msg)
+    }
+}
 
 /// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::OnboardAsset](crate::core::msg::ExecuteMsg::OnboardAsset)
 /// message is provided.  Attempts to verify that a provided Provenance Blockchain Metadata Scope is
@@ -129,6 +158,29 @@ where
     // verify prescribed verifier is present as a verifier in asset definition
     let verifier_config = asset_definition.get_verifier_detail(&msg.verifier_address)?;
 
+    // verify the verifier has not been individually disabled
+    if !verifier_config.enabled {
+        return ContractError::VerifierDisabled {
+            asset_type: msg.asset_type,
+            verifier_address: msg.verifier_address,
+        }
+        .to_err();
+    }
+
+    // verify the sender is permitted to onboard the asset type, if the definition restricts requestors
+    if let Some(allowed_requestors) = &asset_definition.allowed_requestors {
+        if !allowed_requestors.is_empty()
+            && !allowed_requestors
+                .iter()
+                .any(|requestor| requestor == info.sender.as_str())
+        {
+            return ContractError::Unauthorized {
+                explanation: "requestor not in allowed list".to_string(),
+            }
+            .to_err();
+        }
+    }
+
     // verify no funds are sent, as msg fee handles fees
     check_funds_are_empty(&info)?;
 
@@ -137,7 +189,7 @@ where
         scope_address: asset_identifiers.scope_address.to_owned(),
     }
     .to_err();
-    let scope = match repository.use_deps(|d| {
+    let (scope, scope_spec_id_info) = match repository.use_deps(|d| {
         MetadataQuerier::new(&d.querier).scope(
             asset_identifiers.scope_address.to_owned(),
             String::from(""),
@@ -151,13 +203,32 @@ where
         Err(..) => return error_response,
         Ok(scope_response) => match scope_response.scope {
             Some(scope_wrapper) => match scope_wrapper.scope {
-                Some(scope) => scope,
+                Some(scope) => (scope, scope_wrapper.scope_spec_id_info),
                 None => return error_response,
             },
             None => return error_response,
         },
     };
 
+    // verify the scope conforms to the scope specification bound to the asset type, if any
+    if let Some(expected_scope_spec_address) = &asset_definition.scope_spec_address {
+        let scope_spec_address = scope_spec_id_info
+            .as_ref()
+            .map(|id_info| id_info.scope_spec_addr.as_str());
+        if scope_spec_address != Some(expected_scope_spec_address.as_str()) {
+            return ContractError::InvalidScope {
+                explanation: format!(
+                    "cannot onboard scope [{}] as asset type [{}]. expected scope specification [{}], but the scope uses [{}]",
+                    asset_identifiers.scope_address,
+                    msg.asset_type,
+                    expected_scope_spec_address,
+                    scope_spec_address.unwrap_or("none"),
+                ),
+            }
+            .to_err();
+        }
+    }
+
     let state = repository.use_deps(|deps| STATE_V2.load(deps.storage))?;
 
     // verify that the sender of this message is a scope owner
@@ -172,6 +243,18 @@ where
         .to_err();
     }
 
+    // verify that the sender is the primary scope owner, if the definition prohibits proxy onboarding
+    if asset_definition.scope_owner_must_match_requestor
+        && (scope.owners.first().map(|owner| owner.address.as_str()) != Some(info.sender.as_str())
+            || (!scope.value_owner_address.is_empty()
+                && scope.value_owner_address != info.sender.as_str()))
+    {
+        return ContractError::Unauthorized {
+            explanation: "requestor must be the scope owner".to_string(),
+        }
+        .to_err();
+    }
+
     // no need to verify records during a test run - this check makes testing the contract a pretty lengthy process
     if !state.is_test {
         // pull scope records for validation - if no records exist on the scope, the querier will produce an error here
@@ -208,19 +291,62 @@ where
         }
     }
 
-    let new_asset_attribute = AssetScopeAttribute::new(
+    let mut new_asset_attribute = AssetScopeAttribute::new(
         &msg.identifier,
         &msg.asset_type,
         &info.sender,
         &msg.verifier_address,
         AssetOnboardingStatus::Pending.to_some(),
         msg.access_routes,
+        env.block.height,
     )?;
+    // The requestor's routes are subject to the same limit that updating access routes enforces
+    if new_asset_attribute
+        .access_definitions
+        .iter()
+        .any(|definition| {
+            definition.access_routes.len() > state.max_access_routes_per_owner as usize
+        })
+    {
+        return ContractError::AccessRouteLimitExceeded {
+            owner: info.sender.to_string(),
+            limit: state.max_access_routes_per_owner,
+        }
+        .to_err();
+    }
+    new_asset_attribute.scope_spec_address =
+        scope_spec_id_info.map(|id_info| id_info.scope_spec_addr);
+    // Verifiers with a block timeout allow anyone to expire the verification once it has passed
+    new_asset_attribute.pending_expiry_block = verifier_config
+        .verification_timeout_blocks
+        .map(|timeout_blocks| env.block.height.saturating_add(timeout_blocks));
+    new_asset_attribute.os_gateway_permission_granted = msg.add_os_gateway_permission;
+    // The grant id is recorded so that the access is revoked with the same id when verification
+    // completes, regardless of any later changes to the contract's grant prefix
+    let os_gateway_grant_id = if msg.add_os_gateway_permission {
+        generate_os_gateway_grant_id(
+            state.os_gateway_grant_prefix.as_deref(),
+            &msg.asset_type,
+            &asset_identifiers.scope_address,
+        )
+        .to_some()
+    } else {
+        None
+    };
+    new_asset_attribute.os_gateway_grant_id = os_gateway_grant_id.to_owned();
+    new_asset_attribute.requestor_metadata = msg.metadata;
 
     // check to see if the attribute already exists, and determine if this is a fresh onboard or a subsequent one
+    let mut timed_out_verification: Option<(AssetScopeAttribute, FeePaymentDetail)> = None;
+    let mut previous_retry_count = 0;
+    let mut previous_initiated_at_block = 0;
+    let mut previous_requestor_metadata: Option<String> = None;
     let is_retry = if let Some(existing_attribute) =
         repository.try_get_asset_by_asset_type(&asset_identifiers.scope_address, &msg.asset_type)?
     {
+        previous_retry_count = existing_attribute.retry_count;
+        previous_initiated_at_block = existing_attribute.classification_initiated_at_block;
+        previous_requestor_metadata = existing_attribute.requestor_metadata.to_owned();
         match existing_attribute.onboarding_status {
             // If the attribute indicates that the asset is approved, then it's already fully onboarded and verified
             AssetOnboardingStatus::Approved => {
@@ -232,22 +358,199 @@ where
             }
             // If the attribute indicates that the asset is pending, then it's currently waiting for verification
             AssetOnboardingStatus::Pending => {
-                return ContractError::AssetPendingVerification {
-                    scope_address: existing_attribute.scope_address,
+                // A verification that has been pending for longer than the contract's timeout is
+                // automatically denied, allowing this request to proceed as a retry
+                match may_load_timed_out_fee_payment_detail(
+                    &repository,
+                    &env,
+                    state.verification_timeout_seconds,
+                    &asset_identifiers.scope_address,
+                    &msg.asset_type,
+                ) {
+                    Some(fee_payment_detail) => {
+                        timed_out_verification = (existing_attribute, fee_payment_detail).to_some();
+                        true
+                    }
+                    None => {
+                        return ContractError::AssetPendingVerification {
+                            scope_address: existing_attribute.scope_address,
+                            asset_type: msg.asset_type,
+                            verifier_address: existing_attribute.verifier_address.to_string(),
+                        }
+                        .to_err()
+                    }
+                }
+            }
+            // If the attribute indicates that the asset is appealing, then its denial is awaiting the admin's resolution
+            AssetOnboardingStatus::Appealing => {
+                return ContractError::AssetAppealPending {
+                    scope_address: asset_identifiers.scope_address,
                     asset_type: msg.asset_type,
-                    verifier_address: existing_attribute.verifier_address.to_string(),
                 }
-                .to_err()
+                .to_err();
             }
             // If the attribute indicates that the asset is pending, then it's been denied by a verifier, and this is a secondary
             // attempt to onboard the asset
-            AssetOnboardingStatus::Denied => true,
+            AssetOnboardingStatus::Denied => {
+                // Reject the retry if the verifier requires a waiting period after denials that has not yet elapsed
+                if let Some(cooldown_seconds) = verifier_config.cooldown_seconds {
+                    if let Some(denied_at_seconds) = repository.use_deps(|deps| {
+                        may_load_denial_timestamp(
+                            deps.storage,
+                            &asset_identifiers.scope_address,
+                            &msg.asset_type,
+                        )
+                    })? {
+                        let elapsed_seconds =
+                            env.block.time.seconds().saturating_sub(denied_at_seconds);
+                        if elapsed_seconds < cooldown_seconds {
+                            return ContractError::OnboardingCooldownActive {
+                                seconds_remaining: cooldown_seconds - elapsed_seconds,
+                            }
+                            .to_err();
+                        }
+                    }
+                }
+                true
+            }
         }
     } else {
         // If no scope attribute exists, it's safe to simply add the attribute to the scope
         false
     };
 
+    if is_retry {
+        // Reject the retry if the verifier limits the number of retries and the limit has been hit
+        if let Some(max_retries) = verifier_config.max_retries {
+            if previous_retry_count >= max_retries {
+                return ContractError::MaxRetriesExceeded {
+                    scope_address: asset_identifiers.scope_address,
+                    asset_type: msg.asset_type,
+                    max_retries,
+                }
+                .to_err();
+            }
+        }
+        new_asset_attribute.retry_count = previous_retry_count.saturating_add(1);
+        // A retry continues the original classification process.  Attributes onboarded before the
+        // initiating block was tracked have no value to retain, so they use the retry's block
+        if previous_initiated_at_block > 0 {
+            new_asset_attribute.classification_initiated_at_block = previous_initiated_at_block;
+        }
+        // The contract never clears requestor metadata, so a retry without new metadata keeps the
+        // value provided by a previous onboard
+        if new_asset_attribute.requestor_metadata.is_none() {
+            new_asset_attribute.requestor_metadata = previous_requestor_metadata;
+        }
+    }
+
+    // A timed out verifier loses its object store gateway access, unless this onboarding grants the
+    // same verifier access under the same grant id, in which case the existing grant stays in use.
+    // The gateway reads a single action from the response's attributes, so a revoke cannot be
+    // emitted alongside a different grant
+    let timed_out_access_revoke = match &timed_out_verification {
+        Some((timed_out_attribute, _)) if timed_out_attribute.os_gateway_permission_granted => {
+            let timed_out_grant_id = timed_out_attribute
+                .get_os_gateway_grant_id(state.os_gateway_grant_prefix.as_deref());
+            if timed_out_attribute.verifier_address.as_str() == msg.verifier_address
+                && os_gateway_grant_id.as_ref() == Some(&timed_out_grant_id)
+            {
+                vec![]
+            } else if os_gateway_grant_id.is_some() {
+                return ContractError::InvalidMessageFields {
+                    message_type: "ExecuteMsg::OnboardAsset".to_string(),
+                    invalid_fields: vec![format!(
+                        "add_os_gateway_permission: access for timed out verifier [{}] must be revoked before access can be granted with grant id [{}]",
+                        timed_out_attribute.verifier_address,
+                        os_gateway_grant_id.unwrap_or_default(),
+                    )],
+                }
+                .to_err();
+            } else {
+                repository.use_deps(|deps| {
+                    generate_os_gateway_access_revoke(deps.storage, timed_out_attribute)
+                })?
+            }
+        }
+        _ => vec![],
+    };
+
+    // Reject the request if the verifier limits the number of requests it receives per block and
+    // the limit has been hit.  Counts are keyed on the block height, so no cleanup is required
+    if let Some(block_limit) = verifier_config.max_classifications_per_block {
+        let block_count = repository.use_deps(|deps| {
+            load_verifier_block_classification_count(
+                deps.storage,
+                &msg.asset_type,
+                &msg.verifier_address,
+                env.block.height,
+            )
+        })?;
+        if block_count >= block_limit {
+            return ContractError::VerifierRateLimitExceeded {
+                asset_type: msg.asset_type,
+                verifier_address: msg.verifier_address,
+                block_limit,
+            }
+            .to_err();
+        }
+        repository.use_deps(|deps| {
+            increment_verifier_block_classification_count(
+                deps.storage,
+                &msg.asset_type,
+                &msg.verifier_address,
+                env.block.height,
+            )
+        })?;
+    }
+
+    // verify that the fee charged matches the requestor's expectation exactly, if one was provided
+    if let Some(expected_fee) = msg.expected_fee {
+        let actual_fee = Uint128::new(
+            repository
+                .calculate_fee_payment_detail(
+                    &env,
+                    &new_asset_attribute,
+                    &verifier_config,
+                    is_retry,
+                )?
+                .sum_costs(),
+        );
+        if expected_fee != actual_fee {
+            return ContractError::FeeSlippage {
+                expected: expected_fee,
+                actual: actual_fee,
+            }
+            .to_err();
+        }
+    }
+
+    // The verifier never received the fees for a timed out verification, so they are refunded to
+    // the original requestor before the new fee payment detail replaces the stale one
+    if let Some((timed_out_attribute, fee_payment_detail)) = &timed_out_verification {
+        repository.use_deps(|deps| {
+            delete_fee_payment_detail(
+                deps.storage,
+                &asset_identifiers.scope_address,
+                &msg.asset_type,
+            )
+        })?;
+        // The timed out verifier is no longer responsible for the asset, even if the same verifier
+        // is selected again, as the retry counts as a new pending verification
+        repository.use_deps(|deps| {
+            decrement_verifier_pending(
+                deps.storage,
+                &msg.asset_type,
+                timed_out_attribute.verifier_address.as_str(),
+            )
+        })?;
+        if let Some(refund_msg) =
+            fee_payment_detail.to_refund_msg(&timed_out_attribute.requestor_address)
+        {
+            repository.add_message(refund_msg);
+        }
+    }
+
     // store asset metadata in contract storage, with assigned verifier and provided fee (in case fee changes between onboarding and verification)
     repository.onboard_asset(&env, &new_asset_attribute, &verifier_config, is_retry)?;
 
@@ -260,19 +563,48 @@ where
             )
             .set_verifier(&msg.verifier_address)
             .set_scope_owner(info.sender)
-            .set_new_asset_onboarding_status(&new_asset_attribute.onboarding_status),
+            .set_new_asset_onboarding_status(&new_asset_attribute.onboarding_status)
+            .set_onboarding_memo(msg.memo.as_deref()),
         )
+        .add_attributes(timed_out_access_revoke)
         .add_messages(repository.get_messages());
-    let response = if msg.add_os_gateway_permission {
+    let response = if let Some((timed_out_attribute, _)) = timed_out_verification {
+        response.add_event(
+            Event::new(EventType::VerificationTimeout.event_name()).add_attributes(
+                EventAttributes::for_asset_event(
+                    EventType::VerificationTimeout,
+                    &msg.asset_type,
+                    &asset_identifiers.scope_address,
+                )
+                .set_verifier(timed_out_attribute.verifier_address)
+                .set_new_asset_onboarding_status(&AssetOnboardingStatus::Denied),
+            ),
+        )
+    } else {
+        response
+    };
+    // Deprecated asset types still accept onboarding, but requestors are signaled to migrate
+    let response = if asset_definition.deprecated {
+        response.add_event(
+            Event::new(EventType::DeprecatedTypeUsed.event_name()).add_attributes(
+                EventAttributes::for_asset_event(
+                    EventType::DeprecatedTypeUsed,
+                    &msg.asset_type,
+                    &asset_identifiers.scope_address,
+                )
+                .set_replacement_asset_type(asset_definition.replacement_asset_type.as_deref()),
+            ),
+        )
+    } else {
+        response
+    };
+    let response = if let Some(grant_id) = os_gateway_grant_id {
         response.add_attributes(
             OsGatewayAttributeGenerator::access_grant(
                 &asset_identifiers.scope_address,
                 msg.verifier_address,
             )
-            .with_access_grant_id(generate_os_gateway_grant_id(
-                msg.asset_type,
-                asset_identifiers.scope_address,
-            )),
+            .with_access_grant_id(grant_id),
         )
     } else {
         response
@@ -280,26 +612,60 @@ where
     response.to_ok()
 }
 
+/// Fetches the [FeePaymentDetail](crate::core::types::fee_payment_detail::FeePaymentDetail) for a
+/// pending verification, but only if the verification has been pending for longer than the given
+/// timeout.  No value is returned when no timeout is configured.
+///
+/// # Parameters
+///
+/// * `repository` A helper collection of traits that allows access to the contract's storage.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `verification_timeout_seconds` The contract's [verification timeout](crate::core::state::StateV2::verification_timeout_seconds).
+/// * `scope_address` The bech32 address of the scope awaiting verification.
+/// * `asset_type` The asset type for which the scope is awaiting verification.
+fn may_load_timed_out_fee_payment_detail<'a, T>(
+    repository: &T,
+    env: &Env,
+    verification_timeout_seconds: Option<u64>,
+    scope_address: &str,
+    asset_type: &str,
+) -> Option<FeePaymentDetail>
+where
+    T: DepsManager<'a>,
+{
+    let timeout_seconds = verification_timeout_seconds?;
+    repository
+        .use_deps(|deps| may_load_fee_payment_detail(deps.storage, scope_address, asset_type))
+        .filter(|detail| {
+            env.block.time.seconds().saturating_sub(detail.created_at) > timeout_seconds
+        })
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::testutil::attribute_utilities::{
+        mock_attribute_response_for_scope, mock_named_attribute_response_for_scope,
+    };
     use cosmwasm_std::testing::{mock_env, MOCK_CONTRACT_ADDR};
-    use cosmwasm_std::{coins, from_json, Response, Uint128};
+    use cosmwasm_std::{coins, from_json, BankMsg, CosmosMsg, Response, Uint128};
     use os_gateway_contract_attributes::{OS_GATEWAY_EVENT_TYPES, OS_GATEWAY_KEYS};
     use provwasm_mocks::mock_provenance_dependencies;
     use provwasm_std::types::provenance::attribute::v1::{
-        AttributeType, MsgAddAttributeRequest, MsgUpdateAttributeRequest, QueryAttributeRequest,
-        QueryAttributeResponse, QueryAttributesRequest, QueryAttributesResponse,
+        AttributeType, MsgAddAttributeRequest, MsgUpdateAttributeRequest,
     };
-    use provwasm_std::types::provenance::metadata::v1::process::ProcessId;
     use provwasm_std::types::provenance::metadata::v1::{
-        Process, Record, RecordWrapper, RecordsRequest, RecordsResponse, ScopeRequest,
-        ScopeResponse, ScopeWrapper,
+        Party, PartyType, RecordsRequest, ScopeRequest, ScopeResponse,
     };
     use provwasm_std::types::provenance::msgfees::v1::MsgAssessCustomMsgFeeRequest;
 
     use crate::contract::execute;
     use crate::core::msg::ExecuteMsg::OnboardAsset;
-    use crate::core::state::{load_asset_definition_by_type_v3, load_fee_payment_detail};
+    use crate::core::state::{
+        increment_verifier_block_classification_count, load_asset_definition_by_type_v3,
+        load_fee_payment_detail, load_verifier_block_classification_count, load_verifier_stats,
+        may_load_fee_payment_detail, STATE_V2,
+    };
     use crate::core::types::asset_definition::{AssetDefinitionInputV3, AssetDefinitionV3};
     use crate::core::types::fee_destination::FeeDestinationV2;
     use crate::core::types::fee_payment_detail::FeePaymentDetail;
@@ -311,18 +677,25 @@ mod tests {
     use crate::testutil::msg_utilities::{
         test_aggregate_msg_fees_are_charged, test_no_money_moved_in_response,
     };
+    use crate::testutil::scope_utilities::{
+        mock_missing_scope_response, mock_record, mock_records_response, mock_scope_response,
+        mock_scope_response_with_spec_address, mock_scope_with_owner,
+    };
     use crate::testutil::test_constants::{
-        DEFAULT_ONBOARDING_COST, DEFAULT_RETRY_COST, DEFAULT_SECONDARY_ASSET_TYPE,
+        DEFAULT_ONBOARDING_COST, DEFAULT_RETRY_COST, DEFAULT_SCOPE_SPEC_ADDRESS,
+        DEFAULT_SECONDARY_ASSET_TYPE,
     };
     use crate::testutil::test_utilities::{
-        assert_single_item, build_attribute, get_default_asset_definition_input,
-        get_default_verifier_detail, mock_single_scope_attribute, setup_no_attribute_response,
-        single_attribute_for_key,
+        assert_response_attributes_contain, assert_response_messages_count, assert_single_item,
+        build_attribute, get_default_asset_definition_input, get_default_verifier_detail,
+        mock_single_scope_attribute, setup_no_attribute_response,
     };
-    use crate::util::constants::{NEW_ASSET_ONBOARDING_STATUS_KEY, NHASH};
+    use crate::util::aliases::{AssetResult, EntryPointResponse};
+    use crate::util::constants::{format_nhash, NEW_ASSET_ONBOARDING_STATUS_KEY, NHASH};
+    use crate::util::event_attributes::EventType;
     use crate::util::functions::{
-        generate_os_gateway_grant_id, try_into_add_attribute_request, try_into_custom_fee_request,
-        try_into_update_attribute_request,
+        assert_msg_type, generate_os_gateway_grant_id, try_into_add_attribute_request,
+        try_into_custom_fee_request, try_into_update_attribute_request,
     };
     use crate::util::traits::OptionExtensions;
     use crate::{
@@ -330,6 +703,7 @@ mod tests {
             error::ContractError,
             types::{
                 access_definition::{AccessDefinition, AccessDefinitionType},
+                access_route::AccessRoute,
                 asset_identifier::AssetIdentifier,
                 asset_onboarding_status::AssetOnboardingStatus,
                 asset_scope_attribute::AssetScopeAttribute,
@@ -341,23 +715,27 @@ mod tests {
             message_gathering_service::MessageGatheringService,
         },
         testutil::{
+            execute_utilities::{
+                execute_add_asset_definition, execute_deprecate_asset_definition,
+                execute_onboard_asset,
+            },
             onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset},
             test_constants::{
                 DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_CONTRACT_BASE_NAME,
-                DEFAULT_RECORD_SPEC_ADDRESS, DEFAULT_SCOPE_ADDRESS, DEFAULT_SENDER_ADDRESS,
-                DEFAULT_SESSION_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+                DEFAULT_SCOPE_ADDRESS, DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
             },
             test_utilities::{
                 empty_mock_info, get_default_access_routes, get_default_scope,
-                mock_info_with_funds, mock_info_with_nhash, setup_test_suite,
-                test_instantiate_success, InstArgs,
+                intercept_add_or_update_attribute, mock_info_with_funds, mock_info_with_nhash,
+                setup_test_suite, test_instantiate_success, InstArgs, MockOwnedDeps,
             },
             verify_asset_helpers::{test_verify_asset, TestVerifyAsset},
         },
         util::{
             constants::{
-                ASSET_EVENT_TYPE_KEY, ASSET_SCOPE_ADDRESS_KEY, ASSET_TYPE_KEY, SCOPE_OWNER_KEY,
-                VERIFIER_ADDRESS_KEY,
+                ASSET_EVENT_TYPE_KEY, ASSET_SCOPE_ADDRESS_KEY, ASSET_TYPE_KEY,
+                MAX_ONBOARDING_MEMO_LENGTH, MAX_REQUESTOR_METADATA_LENGTH, ONBOARDING_MEMO_KEY,
+                REPLACEMENT_ASSET_TYPE_KEY, SCOPE_OWNER_KEY, VERIFIER_ADDRESS_KEY,
             },
             functions::generate_asset_attribute_name,
         },
@@ -365,6 +743,8 @@ mod tests {
 
     use super::{onboard_asset, OnboardAssetV1};
 
+    const DEFAULT_REQUESTOR_METADATA: &str = "{\"workflow_id\":\"wf-1\"}";
+
     #[test]
     fn test_onboard_asset_errors_on_unsupported_asset_type() {
         let mut deps = mock_provenance_dependencies();
@@ -380,6 +760,9 @@ mod tests {
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.into(),
                 access_routes: vec![],
                 add_os_gateway_permission: false,
+                expected_fee: None,
+                memo: None,
+                metadata: None,
             },
         )
         .unwrap_err();
@@ -418,6 +801,9 @@ mod tests {
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.into(),
                 access_routes: vec![],
                 add_os_gateway_permission: false,
+                expected_fee: None,
+                memo: None,
+                metadata: None,
             },
         )
         .unwrap_err();
@@ -443,6 +829,9 @@ mod tests {
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string() + "bogus".into(),
                 access_routes: vec![],
                 add_os_gateway_permission: false,
+                expected_fee: None,
+                memo: None,
+                metadata: None,
             },
         )
         .unwrap_err();
@@ -484,6 +873,9 @@ mod tests {
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
                 access_routes: vec![],
                 add_os_gateway_permission: false,
+                expected_fee: None,
+                memo: None,
+                metadata: None,
             },
         )
         .unwrap_err();
@@ -510,15 +902,7 @@ mod tests {
 
         // Some random scope address unrelated to the default scope address, which is mocked during setup_test_suite
         let bogus_scope_address = "scope1qp9szrgvvpy5ph5fmxrzs2euyltssfc3lu";
-        ScopeRequest::mock_response(
-            &mut deps.querier,
-            ScopeResponse {
-                scope: None,
-                sessions: vec![],
-                records: vec![],
-                request: None,
-            },
-        );
+        ScopeRequest::mock_response(&mut deps.querier, mock_missing_scope_response());
 
         let err = onboard_asset(
             AssetMetaService::new(deps.as_mut()),
@@ -530,6 +914,9 @@ mod tests {
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
                 access_routes: vec![],
                 add_os_gateway_permission: false,
+                expected_fee: None,
+                memo: None,
+                metadata: None,
             },
         )
         .unwrap_err();
@@ -566,6 +953,9 @@ mod tests {
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
                 access_routes: vec![],
                 add_os_gateway_permission: false,
+                expected_fee: None,
+                memo: None,
+                metadata: None,
             },
         )
         .unwrap_err();
@@ -642,28 +1032,8 @@ mod tests {
         let mut deps = mock_provenance_dependencies();
         test_instantiate_success(deps.as_mut(), &InstArgs::default());
         // Setup the default scope as the result value of a scope query, but don't establish any records
-        ScopeRequest::mock_response(
-            &mut deps.querier,
-            ScopeResponse {
-                scope: Some(ScopeWrapper {
-                    scope: Some(get_default_scope()),
-                    scope_id_info: None,
-                    scope_spec_id_info: None,
-                }),
-                sessions: vec![],
-                records: vec![],
-                request: None,
-            },
-        );
-        RecordsRequest::mock_response(
-            &mut deps.querier,
-            RecordsResponse {
-                scope: None,
-                sessions: vec![],
-                records: vec![],
-                request: None,
-            },
-        );
+        ScopeRequest::mock_response(&mut deps.querier, mock_scope_response(get_default_scope()));
+        RecordsRequest::mock_response(&mut deps.querier, mock_records_response(vec![]));
         let err = onboard_asset(
             AssetMetaService::new(deps.as_mut()),
             mock_env(),
@@ -674,6 +1044,9 @@ mod tests {
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
                 access_routes: vec![],
                 add_os_gateway_permission: false,
+                expected_fee: None,
+                memo: None,
+                metadata: None,
             },
         )
         .unwrap_err();
@@ -691,27 +1064,9 @@ mod tests {
     #[test]
     fn test_onboard_asset_succeeds_on_no_records_in_test_mode() {
         let mut deps = mock_provenance_dependencies();
-        test_instantiate_success(
-            deps.as_mut(),
-            &InstArgs {
-                is_test: true,
-                ..Default::default()
-            },
-        );
+        test_instantiate_success(deps.as_mut(), &InstArgs::with_test_mode(true));
         // Setup the default scope as the result value of a scope query, but don't establish any records
-        ScopeRequest::mock_response(
-            &mut deps.querier,
-            ScopeResponse {
-                scope: Some(ScopeWrapper {
-                    scope: Some(get_default_scope()),
-                    scope_id_info: None,
-                    scope_spec_id_info: None,
-                }),
-                sessions: vec![],
-                records: vec![],
-                request: None,
-            },
-        );
+        ScopeRequest::mock_response(&mut deps.querier, mock_scope_response(get_default_scope()));
         setup_no_attribute_response(&mut deps, None);
         onboard_asset(
             AssetMetaService::new(deps.as_mut()),
@@ -723,6 +1078,9 @@ mod tests {
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
                 access_routes: vec![],
                 add_os_gateway_permission: false,
+                expected_fee: None,
+                memo: None,
+                metadata: None,
             },
         )
         .expect("onboarding should succeed due to test mode being enabled");
@@ -734,44 +1092,15 @@ mod tests {
         test_instantiate_success(deps.as_mut(), &InstArgs::default());
         // Setup the default scope and add a record, but make sure the record is not formed properly
         let scope = get_default_scope();
-        let malformed_record = vec![RecordWrapper {
-            record: Some(Record {
-                name: "record-name".to_string(),
-                session_id: DEFAULT_SESSION_ADDRESS.to_string().into(),
-                specification_id: DEFAULT_RECORD_SPEC_ADDRESS.to_string().into(),
-                process: Some(Process {
-                    process_id: Some(ProcessId::Address(String::new())),
-                    method: String::new(),
-                    name: String::new(),
-                }),
-                inputs: vec![],
-                outputs: vec![],
-            }),
-            record_id_info: None,
-            record_spec_id_info: None,
-        }];
+        let malformed_record = vec![mock_record("record-name", false)];
         ScopeRequest::mock_response(
             &mut deps.querier,
             ScopeResponse {
-                scope: Some(ScopeWrapper {
-                    scope: Some(scope.clone()),
-                    scope_id_info: None,
-                    scope_spec_id_info: None,
-                }),
-                sessions: vec![],
                 records: malformed_record.to_owned(),
-                request: None,
-            },
-        );
-        RecordsRequest::mock_response(
-            &mut deps.querier,
-            RecordsResponse {
-                scope: None,
-                sessions: vec![],
-                records: malformed_record,
-                request: None,
+                ..mock_scope_response(scope)
             },
         );
+        RecordsRequest::mock_response(&mut deps.querier, mock_records_response(malformed_record));
         let err = onboard_asset(
             AssetMetaService::new(deps.as_mut()),
             mock_env(),
@@ -782,6 +1111,9 @@ mod tests {
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
                 access_routes: vec![],
                 add_os_gateway_permission: false,
+                expected_fee: None,
+                memo: None,
+                metadata: None,
             },
         )
         .unwrap_err();
@@ -795,41 +1127,14 @@ mod tests {
     #[test]
     fn test_onboard_asset_succeeds_for_empty_records_in_test_mode() {
         let mut deps = mock_provenance_dependencies();
-        test_instantiate_success(
-            deps.as_mut(),
-            &InstArgs {
-                is_test: true,
-                ..Default::default()
-            },
-        );
+        test_instantiate_success(deps.as_mut(), &InstArgs::with_test_mode(true));
         // Setup the default scope and add a record, but make sure the record is not formed properly
         let scope = get_default_scope();
         ScopeRequest::mock_response(
             &mut deps.querier,
             ScopeResponse {
-                scope: Some(ScopeWrapper {
-                    scope: Some(scope.clone()),
-                    scope_id_info: None,
-                    scope_spec_id_info: None,
-                }),
-                sessions: vec![],
-                records: vec![RecordWrapper {
-                    record: Some(Record {
-                        name: "record-name".to_string(),
-                        session_id: DEFAULT_SESSION_ADDRESS.to_string().into(),
-                        specification_id: DEFAULT_RECORD_SPEC_ADDRESS.to_string().into(),
-                        process: Some(Process {
-                            process_id: Some(ProcessId::Address(String::new())),
-                            method: String::new(),
-                            name: String::new(),
-                        }),
-                        inputs: vec![],
-                        outputs: vec![],
-                    }),
-                    record_id_info: None,
-                    record_spec_id_info: None,
-                }],
-                request: None,
+                records: vec![mock_record("record-name", false)],
+                ..mock_scope_response(scope)
             },
         );
         setup_no_attribute_response(&mut deps, None);
@@ -843,11 +1148,57 @@ mod tests {
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
                 access_routes: vec![],
                 add_os_gateway_permission: false,
+                expected_fee: None,
+                memo: None,
+                metadata: None,
             },
         )
         .expect("onboarding should succeed due to test mode being enabled");
     }
 
+    #[test]
+    fn test_onboard_asset_errors_on_access_routes_exceeding_limit() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(
+            &mut deps,
+            &InstArgs {
+                max_access_routes_per_owner: 1.to_some(),
+                ..InstArgs::default()
+            },
+        );
+        setup_no_attribute_response(&mut deps, None);
+        let onboard_with_route_count = |deps: &mut MockOwnedDeps, count: usize| {
+            test_onboard_asset(
+                deps,
+                TestOnboardAsset {
+                    onboard_asset: OnboardAssetV1 {
+                        access_routes: (0..count)
+                            .map(|index| {
+                                AccessRoute::route_only(format!("grpcs://route{}.fake:1234", index))
+                            })
+                            .collect(),
+                        ..TestOnboardAsset::default_onboard_asset()
+                    },
+                    ..TestOnboardAsset::default()
+                },
+            )
+        };
+        let err = onboard_with_route_count(&mut deps, 2)
+            .expect_err("onboarding with more access routes than the limit should fail");
+        match err {
+            ContractError::AccessRouteLimitExceeded { owner, limit } => {
+                assert_eq!(
+                    DEFAULT_SENDER_ADDRESS, owner,
+                    "the error should reference the requestor as the owner of the routes",
+                );
+                assert_eq!(1, limit, "the error should reference the configured limit");
+            }
+            _ => panic!("unexpected error encountered: {:?}", err),
+        }
+        onboard_with_route_count(&mut deps, 1)
+            .expect("onboarding with access routes within the limit should succeed");
+    }
+
     #[test]
     fn test_onboard_asset_success() {
         let mut deps = mock_provenance_dependencies();
@@ -864,6 +1215,9 @@ mod tests {
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
                 access_routes: get_default_access_routes(),
                 add_os_gateway_permission: false,
+                expected_fee: None,
+                memo: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -878,10 +1232,10 @@ mod tests {
             panic!("fee payment detail should be stored for onboarded asset")
         }
 
-        assert_eq!(
+        assert_response_messages_count(
+            &result,
             2,
-            result.messages.len(),
-            "Onboarding should produce the correct number of messages"
+            "Onboarding should produce the correct number of messages",
         );
         result.messages.iter().for_each(|msg| {
             if let Some(add_attribute_request) = try_into_add_attribute_request(&msg.msg) {
@@ -931,7 +1285,8 @@ mod tests {
                 assert_eq!(
                     DEFAULT_ONBOARDING_COST,
                     amount.expect("fee should have amount defined").amount.parse().expect("amount should be parseable"),
-                    "double the default verifier cost should be included in the fee msg to account for the provenance cut",
+                    "double the default verifier cost ({}) should be included in the fee msg to account for the provenance cut",
+                    format_nhash(DEFAULT_ONBOARDING_COST),
                 );
                 assert_ne!(
                     name,
@@ -956,99 +1311,476 @@ mod tests {
     }
 
     #[test]
-    fn test_onboarding_asset_with_free_onboarding_cost() {
+    fn test_onboard_asset_populates_scope_spec_address() {
         let mut deps = mock_provenance_dependencies();
-        // Set up the contract as normal, but make onboarding free
-        setup_test_suite(
-            &mut deps,
-            &InstArgs {
-                asset_definitions: vec![AssetDefinitionInputV3 {
-                    verifiers: vec![VerifierDetailV2 {
-                        onboarding_cost: Uint128::zero(),
-                        ..get_default_verifier_detail()
-                    }],
-                    ..get_default_asset_definition_input()
-                }],
-                ..InstArgs::default()
-            },
-        );
+        setup_test_suite(&mut deps, &InstArgs::default());
         setup_no_attribute_response(&mut deps, None);
-        let response = test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
-        test_no_money_moved_in_response(
-            &response,
-            "no funds should be sent when onboarding with a free onboarding cost",
+        ScopeRequest::mock_response(
+            &mut deps.querier,
+            mock_scope_response_with_spec_address(get_default_scope(), DEFAULT_SCOPE_SPEC_ADDRESS),
+        );
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the asset onboard to succeed");
+        let attribute = AssetMetaService::new(deps.as_mut())
+            .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+            .expect("the scope attribute should be present after onboarding");
+        assert_eq!(
+            DEFAULT_SCOPE_SPEC_ADDRESS,
+            attribute
+                .scope_spec_address
+                .expect("the scope spec address should be populated"),
+            "the scope spec address should be derived from the scope's id info",
         );
     }
 
     #[test]
-    fn test_onboard_asset_retry_success() {
+    fn test_onboard_asset_succeeds_for_scope_matching_bound_scope_spec() {
         let mut deps = mock_provenance_dependencies();
-        let instantiate_args = InstArgs::default();
-        setup_test_suite(&mut deps, &instantiate_args);
+        setup_test_suite(&mut deps, &get_scope_spec_bound_inst_args());
         setup_no_attribute_response(&mut deps, None);
-        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
-        let payment_detail_before_retry = load_fee_payment_detail(
-            deps.as_ref().storage,
-            DEFAULT_SCOPE_ADDRESS,
-            DEFAULT_ASSET_TYPE,
-        )
-        .expect("a fee payment detail should be stored for the asset after onboarding");
-        test_verify_asset(
-            &mut deps,
-            &instantiate_args.env,
-            TestVerifyAsset::default_with_success(false),
-        )
-        .unwrap();
-        load_fee_payment_detail(
-            deps.as_ref().storage,
-            DEFAULT_SCOPE_ADDRESS,
-            DEFAULT_ASSET_TYPE,
-        )
-        .expect_err("no fee payment detail should be present after success=false verification");
-        let attribute = AssetMetaService::new(deps.as_mut())
-            .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
-            .expect("the default scope address should have an attribute attached to it");
-        assert_eq!(
-            attribute.onboarding_status,
-            AssetOnboardingStatus::Denied,
-            "sanity check: the onboarding status should be set to denied after the verifier marks the asset as success = false",
-        );
-        QueryAttributesRequest::mock_response(
+        ScopeRequest::mock_response(
             &mut deps.querier,
-            QueryAttributesResponse {
-                account: DEFAULT_SCOPE_ADDRESS.to_string(),
-                attributes: vec![build_attribute(DEFAULT_SCOPE_ADDRESS, &attribute)],
-                pagination: None,
-            },
-        );
-        let response = test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
-        assert_eq!(
-            2,
-            response.messages.len(),
-            "two messages should be emitted in the retry. One for an attribute update and one for a message fee",
+            mock_scope_response_with_spec_address(get_default_scope(), DEFAULT_SCOPE_SPEC_ADDRESS),
         );
-        assert!(
-            try_into_update_attribute_request(&response.messages[0].msg).is_some(),
-            "the first emitted message should update the attribute",
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding a scope created from the bound scope spec should succeed");
+    }
+
+    #[test]
+    fn test_onboard_asset_fails_for_scope_not_matching_bound_scope_spec() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &get_scope_spec_bound_inst_args());
+        setup_no_attribute_response(&mut deps, None);
+        let other_scope_spec_address = "scopespec1qs30c9axgrw5669ft0kffe6h9gysfe58v3";
+        ScopeRequest::mock_response(
+            &mut deps.querier,
+            mock_scope_response_with_spec_address(get_default_scope(), other_scope_spec_address),
         );
+        let err = test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect_err("onboarding a scope created from a different scope spec should fail");
+        match err {
+            ContractError::InvalidScope { explanation } => assert_eq!(
+                format!(
+                    "cannot onboard scope [{}] as asset type [{}]. expected scope specification [{}], but the scope uses [{}]",
+                    DEFAULT_SCOPE_ADDRESS,
+                    DEFAULT_ASSET_TYPE,
+                    DEFAULT_SCOPE_SPEC_ADDRESS,
+                    other_scope_spec_address,
+                ),
+                explanation,
+                "the error should describe the scope spec mismatch",
+            ),
+            _ => panic!("unexpected error encountered: {:?}", err),
+        };
+        // A scope without any scope spec id info cannot be proven to conform to the bound spec
+        ScopeRequest::mock_response(&mut deps.querier, mock_scope_response(get_default_scope()));
+        let err = test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect_err("onboarding a scope without a scope spec should fail");
         assert!(
-            try_into_custom_fee_request(&response.messages[1].msg).is_some(),
-            "the second emitted message should be the message fee"
-        );
-        test_aggregate_msg_fees_are_charged(
-            &response,
-            DEFAULT_RETRY_COST,
-            "the retry amount should be used because the same verifier was used",
+            matches!(err, ContractError::InvalidScope { ref explanation } if explanation.ends_with("but the scope uses [none]")),
+            "expected an invalid scope error for a scope without a scope spec, but got: {:?}",
+            err,
         );
-        let attribute = AssetMetaService::new(deps.as_mut())
-            .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
-            .expect("the default scope address should still contain an attribute after onboarding for a second time");
+    }
+
+    #[test]
+    fn test_onboard_asset_succeeds_for_matching_expected_fee() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(
+            &mut deps,
+            get_expected_fee_onboard_asset(Uint128::new(DEFAULT_ONBOARDING_COST).to_some()),
+        )
+        .expect("onboarding should succeed when the expected fee matches the actual fee");
         assert_eq!(
-            attribute.onboarding_status,
-            AssetOnboardingStatus::Pending,
-            "the onboarding status should now be set to pending after retrying the onboard process",
+            DEFAULT_ONBOARDING_COST,
+            load_fee_payment_detail(
+                deps.as_ref().storage,
+                DEFAULT_SCOPE_ADDRESS,
+                DEFAULT_ASSET_TYPE
+            )
+            .expect("a fee payment detail should be stored after onboarding")
+            .sum_costs(),
+            "the stored fee payment detail should charge the expected fee",
         );
-        let payment_detail_after_retry = load_fee_payment_detail(
+    }
+
+    #[test]
+    fn test_onboard_asset_fails_for_mismatched_expected_fee() {
+        for expected_fee in [DEFAULT_ONBOARDING_COST - 1, DEFAULT_ONBOARDING_COST + 1] {
+            let mut deps = mock_provenance_dependencies();
+            setup_test_suite(&mut deps, &InstArgs::default());
+            setup_no_attribute_response(&mut deps, None);
+            let err = test_onboard_asset(
+                &mut deps,
+                get_expected_fee_onboard_asset(Uint128::new(expected_fee).to_some()),
+            )
+            .expect_err("onboarding should fail when the expected fee differs from the actual fee");
+            match err {
+                ContractError::FeeSlippage { expected, actual } => {
+                    assert_eq!(
+                        expected_fee,
+                        expected.u128(),
+                        "the error should include the expected fee",
+                    );
+                    assert_eq!(
+                        DEFAULT_ONBOARDING_COST,
+                        actual.u128(),
+                        "the error should include the actual fee",
+                    );
+                }
+                _ => panic!("unexpected error encountered: {:?}", err),
+            };
+            assert!(
+                may_load_fee_payment_detail(
+                    deps.as_ref().storage,
+                    DEFAULT_SCOPE_ADDRESS,
+                    DEFAULT_ASSET_TYPE,
+                )
+                .is_none(),
+                "no fee payment detail should be stored when the expected fee differs",
+            );
+        }
+    }
+
+    #[test]
+    fn test_onboard_asset_without_expected_fee_is_unchanged() {
+        let mut responses = vec![];
+        for expected_fee in [None, Uint128::new(DEFAULT_ONBOARDING_COST).to_some()] {
+            let mut deps = mock_provenance_dependencies();
+            setup_test_suite(&mut deps, &InstArgs::default());
+            setup_no_attribute_response(&mut deps, None);
+            responses.push(
+                test_onboard_asset(&mut deps, get_expected_fee_onboard_asset(expected_fee))
+                    .expect("onboarding should succeed"),
+            );
+        }
+        assert_eq!(
+            responses[0], responses[1],
+            "omitting the expected fee should produce the same response as providing the correct fee",
+        );
+    }
+
+    #[test]
+    fn test_onboard_asset_emits_provided_memo() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        let response = test_onboard_asset(
+            &mut deps,
+            get_memo_onboard_asset("loan tape batch 42".to_string().to_some()),
+        )
+        .expect("onboarding should succeed when a memo is provided");
+        assert_response_attributes_contain(&response, ONBOARDING_MEMO_KEY, "loan tape batch 42");
+    }
+
+    #[test]
+    fn test_onboard_asset_omits_missing_or_blank_memo() {
+        for memo in [None, "".to_string().to_some(), "   ".to_string().to_some()] {
+            let mut deps = mock_provenance_dependencies();
+            setup_test_suite(&mut deps, &InstArgs::default());
+            setup_no_attribute_response(&mut deps, None);
+            let response = test_onboard_asset(&mut deps, get_memo_onboard_asset(memo.clone()))
+                .expect("onboarding should succeed without a memo");
+            assert!(
+                !response
+                    .attributes
+                    .iter()
+                    .any(|attribute| attribute.key == ONBOARDING_MEMO_KEY),
+                "no memo attribute should be emitted for memo {:?}",
+                memo,
+            );
+            assert_onboard_response_attributes_are_correct(&response, true);
+        }
+    }
+
+    #[test]
+    fn test_onboard_asset_rejects_over_length_memo() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        let err = execute_onboard_asset(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+            get_memo_onboard_asset("a".repeat(MAX_ONBOARDING_MEMO_LENGTH + 1).to_some())
+                .onboard_asset,
+        )
+        .expect_err("onboarding should fail when the memo is too long");
+        match err {
+            ContractError::InvalidMessageFields { invalid_fields, .. } => assert_eq!(
+                vec!["memo: must not exceed 256 characters".to_string()],
+                invalid_fields,
+                "the over-length memo should be the only invalid field",
+            ),
+            _ => panic!("unexpected error encountered: {:?}", err),
+        };
+    }
+
+    #[test]
+    fn test_onboard_asset_for_deprecated_asset_type_emits_event() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        let response = test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding a current asset type should succeed");
+        assert!(
+            response.events.is_empty(),
+            "no deprecation event should be emitted for an asset type that is not deprecated",
+        );
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        execute_add_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            AssetDefinitionInputV3 {
+                asset_type: DEFAULT_SECONDARY_ASSET_TYPE.to_string(),
+                bind_name: false.to_some(),
+                ..get_default_asset_definition_input()
+            },
+        )
+        .expect("adding the replacement asset definition should succeed");
+        execute_deprecate_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            DEFAULT_SECONDARY_ASSET_TYPE.to_some(),
+        )
+        .expect("deprecating the default asset type should succeed");
+        let response = test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding a deprecated asset type should still succeed");
+        assert_onboard_response_attributes_are_correct(&response, true);
+        let deprecated_event = assert_single_item(
+            &response.events,
+            "a single deprecation event should be emitted",
+        );
+        assert_eq!(
+            EventType::DeprecatedTypeUsed.event_name(),
+            deprecated_event.ty,
+            "the event should have the deprecated type used type",
+        );
+        let deprecated_attribute = |key: &str| {
+            deprecated_event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == key)
+                .map(|attr| attr.value.to_owned())
+                .unwrap_or_default()
+        };
+        assert_eq!(
+            EventType::DeprecatedTypeUsed.event_name(),
+            deprecated_attribute(ASSET_EVENT_TYPE_KEY),
+            "the deprecation event should include the correct event type",
+        );
+        assert_eq!(
+            DEFAULT_ASSET_TYPE,
+            deprecated_attribute(ASSET_TYPE_KEY),
+            "the deprecation event should include the deprecated asset type",
+        );
+        assert_eq!(
+            DEFAULT_SCOPE_ADDRESS,
+            deprecated_attribute(ASSET_SCOPE_ADDRESS_KEY),
+            "the deprecation event should include the onboarded scope address",
+        );
+        assert_eq!(
+            DEFAULT_SECONDARY_ASSET_TYPE,
+            deprecated_attribute(REPLACEMENT_ASSET_TYPE_KEY),
+            "the deprecation event should include the replacement asset type",
+        );
+    }
+
+    #[test]
+    fn test_onboard_asset_succeeds_without_allowed_requestors() {
+        for allowed_requestors in [None, vec![].to_some()] {
+            let mut deps = mock_provenance_dependencies();
+            setup_test_suite(
+                &mut deps,
+                &get_allowed_requestors_inst_args(allowed_requestors.clone()),
+            );
+            setup_no_attribute_response(&mut deps, None);
+            test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap_or_else(|e| {
+                panic!(
+                    "onboarding should be open to all requestors when allowed requestors are {:?}, but got: {:?}",
+                    allowed_requestors, e,
+                )
+            });
+        }
+    }
+
+    #[test]
+    fn test_onboard_asset_fails_for_requestor_not_in_allowed_list() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(
+            &mut deps,
+            &get_allowed_requestors_inst_args(vec![DEFAULT_ADMIN_ADDRESS.to_string()].to_some()),
+        );
+        setup_no_attribute_response(&mut deps, None);
+        let err = test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect_err("onboarding by a requestor that is not in the allowed list should fail");
+        match err {
+            ContractError::Unauthorized { explanation } => assert_eq!(
+                "requestor not in allowed list", explanation,
+                "the error should describe the requestor restriction",
+            ),
+            _ => panic!("unexpected error encountered: {:?}", err),
+        };
+    }
+
+    #[test]
+    fn test_onboard_asset_succeeds_for_allowed_requestor() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(
+            &mut deps,
+            &get_allowed_requestors_inst_args(
+                vec![
+                    DEFAULT_ADMIN_ADDRESS.to_string(),
+                    DEFAULT_SENDER_ADDRESS.to_string(),
+                ]
+                .to_some(),
+            ),
+        );
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding by a requestor in the allowed list should succeed");
+    }
+
+    #[test]
+    fn test_onboard_asset_fails_for_proxy_owner_when_scope_owner_must_match_requestor() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &get_scope_owner_must_match_requestor_inst_args());
+        setup_no_attribute_response(&mut deps, None);
+        // The sender is a scope owner, but not the primary owner
+        let mut proxy_scope = mock_scope_with_owner(DEFAULT_ADMIN_ADDRESS);
+        proxy_scope.owners.push(Party {
+            address: DEFAULT_SENDER_ADDRESS.to_string(),
+            role: PartyType::Owner.into(),
+            optional: false,
+        });
+        ScopeRequest::mock_response(&mut deps.querier, mock_scope_response(proxy_scope));
+        assert_scope_owner_must_match_requestor_error(
+            test_onboard_asset(&mut deps, TestOnboardAsset::default())
+                .expect_err("onboarding by a secondary scope owner should fail"),
+        );
+        // The sender is the primary owner, but the value ownership has been delegated elsewhere
+        let mut delegated_scope = get_default_scope();
+        delegated_scope.value_owner_address = DEFAULT_ADMIN_ADDRESS.to_string();
+        ScopeRequest::mock_response(&mut deps.querier, mock_scope_response(delegated_scope));
+        assert_scope_owner_must_match_requestor_error(
+            test_onboard_asset(&mut deps, TestOnboardAsset::default())
+                .expect_err("onboarding a scope with a delegated value owner should fail"),
+        );
+    }
+
+    #[test]
+    fn test_onboard_asset_succeeds_for_primary_owner_when_scope_owner_must_match_requestor() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &get_scope_owner_must_match_requestor_inst_args());
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding by the primary scope owner should succeed");
+    }
+
+    #[test]
+    fn test_onboard_asset_allows_proxy_owner_by_default() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        let mut proxy_scope = mock_scope_with_owner(DEFAULT_ADMIN_ADDRESS);
+        proxy_scope.owners.push(Party {
+            address: DEFAULT_SENDER_ADDRESS.to_string(),
+            role: PartyType::Owner.into(),
+            optional: false,
+        });
+        ScopeRequest::mock_response(&mut deps.querier, mock_scope_response(proxy_scope));
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding by a secondary scope owner should succeed without the restriction");
+    }
+
+    #[test]
+    fn test_onboarding_asset_with_free_onboarding_cost() {
+        let mut deps = mock_provenance_dependencies();
+        // Set up the contract as normal, but make onboarding free
+        setup_test_suite(
+            &mut deps,
+            &InstArgs::with_single_verifier(VerifierDetailV2 {
+                onboarding_cost: OnboardingCost::new(0, &[]),
+                ..get_default_verifier_detail()
+            }),
+        );
+        setup_no_attribute_response(&mut deps, None);
+        let response = test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        test_no_money_moved_in_response(
+            &response,
+            "no funds should be sent when onboarding with a free onboarding cost",
+        );
+    }
+
+    #[test]
+    fn test_onboard_asset_retry_success() {
+        let mut deps = mock_provenance_dependencies();
+        let instantiate_args = InstArgs::default();
+        setup_test_suite(&mut deps, &instantiate_args);
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        let payment_detail_before_retry = load_fee_payment_detail(
+            deps.as_ref().storage,
+            DEFAULT_SCOPE_ADDRESS,
+            DEFAULT_ASSET_TYPE,
+        )
+        .expect("a fee payment detail should be stored for the asset after onboarding");
+        test_verify_asset(
+            &mut deps,
+            &instantiate_args.env,
+            TestVerifyAsset::default_with_success(false),
+        )
+        .unwrap();
+        load_fee_payment_detail(
+            deps.as_ref().storage,
+            DEFAULT_SCOPE_ADDRESS,
+            DEFAULT_ASSET_TYPE,
+        )
+        .expect_err("no fee payment detail should be present after success=false verification");
+        let attribute = AssetMetaService::new(deps.as_mut())
+            .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+            .expect("the default scope address should have an attribute attached to it");
+        assert_eq!(
+            attribute.onboarding_status,
+            AssetOnboardingStatus::Denied,
+            "sanity check: the onboarding status should be set to denied after the verifier marks the asset as success = false",
+        );
+        mock_attribute_response_for_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![build_attribute(DEFAULT_SCOPE_ADDRESS, &attribute)],
+        );
+        let response = test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        assert_response_messages_count(&response, 2, "two messages should be emitted in the retry. One for an attribute update and one for a message fee");
+        assert!(
+            try_into_update_attribute_request(&response.messages[0].msg).is_some(),
+            "the first emitted message should update the attribute",
+        );
+        assert!(
+            try_into_custom_fee_request(&response.messages[1].msg).is_some(),
+            "the second emitted message should be the message fee"
+        );
+        test_aggregate_msg_fees_are_charged(
+            &response,
+            DEFAULT_RETRY_COST,
+            "the retry amount should be used because the same verifier was used",
+        );
+        let attribute = AssetMetaService::new(deps.as_mut())
+            .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+            .expect("the default scope address should still contain an attribute after onboarding for a second time");
+        assert_eq!(
+            attribute.onboarding_status,
+            AssetOnboardingStatus::Pending,
+            "the onboarding status should now be set to pending after retrying the onboard process",
+        );
+        let payment_detail_after_retry = load_fee_payment_detail(
             deps.as_ref().storage,
             DEFAULT_SCOPE_ADDRESS,
             DEFAULT_ASSET_TYPE,
@@ -1072,6 +1804,8 @@ mod tests {
             true,
             DEFAULT_ASSET_TYPE,
             &[attribute],
+            mock_env().block.time.seconds(),
+            mock_env().block.height,
         )
         .expect("Payment detail should be generated without issue");
         assert_eq!(
@@ -1116,140 +1850,791 @@ mod tests {
                 verifier: other_verifier.clone(),
             },
         )
-        .expect("adding the second verifier should succeed without error");
+        .expect("adding the second verifier should succeed without error");
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        let payment_detail_before = load_fee_payment_detail(
+            deps.as_ref().storage,
+            DEFAULT_SCOPE_ADDRESS,
+            DEFAULT_ASSET_TYPE,
+        )
+        .expect("a fee payment detail should be stored for the asset after onboarding");
+        test_verify_asset(
+            &mut deps,
+            &instantiate_args.env,
+            TestVerifyAsset::default_with_success(false),
+        )
+        .unwrap();
+        load_fee_payment_detail(
+            deps.as_ref().storage,
+            DEFAULT_SCOPE_ADDRESS,
+            DEFAULT_ASSET_TYPE,
+        )
+        .expect_err("no fee payment detail should be present after success=false verification");
+        let attribute = AssetMetaService::new(deps.as_mut())
+            .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+            .expect("the default scope address should have an attribute attached to it");
+        assert_eq!(
+            attribute.onboarding_status,
+            AssetOnboardingStatus::Denied,
+            "sanity check: the onboarding status should be set to denied after the verifier marks the asset as success = false",
+        );
+        let response = test_onboard_asset(
+            &mut deps,
+            TestOnboardAsset {
+                onboard_asset: OnboardAssetV1 {
+                    verifier_address: other_verifier.address.clone(),
+                    ..TestOnboardAsset::default_onboard_asset()
+                },
+                ..TestOnboardAsset::default()
+            },
+        )
+        .unwrap();
+        assert_response_messages_count(&response, 2, "two messages should be emitted in the retry. One for an attribute update and one for a message fee");
+        assert!(
+            try_into_update_attribute_request(&response.messages[0].msg).is_some(),
+            "the first emitted message should update the attribute",
+        );
+        assert!(
+            try_into_custom_fee_request(&response.messages[1].msg).is_some(),
+            "the second emitted message should be the message fee"
+        );
+        test_aggregate_msg_fees_are_charged(
+            &response,
+            300,
+            "the retry amount should not be used because a different verifier was used",
+        );
+        let attribute = AssetMetaService::new(deps.as_mut())
+            .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+            .expect("the default scope address should still contain an attribute after onboarding for a second time");
+        assert_eq!(
+            attribute.onboarding_status,
+            AssetOnboardingStatus::Pending,
+            "the onboarding status should now be set to pending after retrying the onboard process",
+        );
+        assert_eq!(
+            attribute.verifier_address.as_str(),
+            other_verifier.address,
+            "the attribute should be updated to the other verifier's address",
+        );
+        let payment_detail_after = load_fee_payment_detail(
+            deps.as_ref().storage,
+            DEFAULT_SCOPE_ADDRESS,
+            DEFAULT_ASSET_TYPE,
+        )
+        .expect("a fee payment detail should be stored for the asset");
+        assert_ne!(
+            payment_detail_before, payment_detail_after,
+            "the payment details should not match due to changing verifiers",
+        );
+        assert_eq!(
+            // Proves that this subsequent retry using a different verifier will not load the
+            // retry fees, because retries should only execute when using the same verifier
+            FeePaymentDetail::new(DEFAULT_SCOPE_ADDRESS, &other_verifier, false, DEFAULT_ASSET_TYPE, &[], mock_env().block.time.seconds(), mock_env().block.height)
+                .expect("the other verifier should be successfully converted to a fee payment detail"),
+            payment_detail_after,
+            "the fee payment detail after the retry should equate to the new verifier's fee definitions",
+        );
+    }
+
+    #[test]
+    fn test_onboarding_asset_retry_success_with_free_retries() {
+        let mut deps = mock_provenance_dependencies();
+        // Set up the contract as normal, but make retries free
+        let instantiate_args = InstArgs::with_single_verifier(VerifierDetailV2 {
+            retry_cost: OnboardingCost::new(0, &[]).to_some(),
+            ..get_default_verifier_detail()
+        });
+        setup_test_suite(&mut deps, &instantiate_args);
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        test_verify_asset(
+            &mut deps,
+            &instantiate_args.env,
+            TestVerifyAsset::default_with_success(false),
+        )
+        .unwrap();
+        let attribute = AssetMetaService::new(deps.as_mut())
+            .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+            .expect("the default scope address should have an attribute attached to it");
+        assert_eq!(
+            attribute.onboarding_status,
+            AssetOnboardingStatus::Denied,
+            "sanity check: the onboarding status should be set to denied after the verifier marks the asset as success = false",
+        );
+        mock_attribute_response_for_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![build_attribute(DEFAULT_SCOPE_ADDRESS, &attribute)],
+        );
+        let response = test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        test_no_money_moved_in_response(
+            &response,
+            "no funds should be sent when onboarding as a retry with no retry cost",
+        );
+    }
+
+    #[test]
+    fn test_onboarding_asset_retry_rejected_during_cooldown() {
+        let mut deps = mock_provenance_dependencies();
+        let response = deny_and_retry_with_cooldown(&mut deps, 60, 20)
+            .expect_err("a retry before the cooldown elapses should be rejected");
+        match response {
+            ContractError::OnboardingCooldownActive { seconds_remaining } => {
+                assert_eq!(
+                    40, seconds_remaining,
+                    "the remaining cooldown should be the cooldown minus the time since denial",
+                );
+            }
+            _ => panic!("unexpected error encountered: {:?}", response),
+        };
+    }
+
+    #[test]
+    fn test_onboarding_asset_retry_allowed_after_cooldown() {
+        let mut deps = mock_provenance_dependencies();
+        deny_and_retry_with_cooldown(&mut deps, 60, 60)
+            .expect("a retry after the cooldown elapses should succeed");
+    }
+
+    #[test]
+    fn test_onboarding_asset_retry_allowed_without_cooldown() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        test_verify_asset(
+            &mut deps,
+            &mock_env(),
+            TestVerifyAsset::default_with_success(false),
+        )
+        .unwrap();
+        mock_denied_attribute(&mut deps);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("a retry should succeed immediately when the verifier has no cooldown");
+    }
+
+    #[test]
+    fn test_onboard_asset_retry_increments_retry_count() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        assert_eq!(
+            0,
+            get_default_attribute(&mut deps).retry_count,
+            "the retry count should start at zero after the initial onboard",
+        );
+        for expected_retry_count in 1..=2 {
+            deny_and_retry(&mut deps).expect("the retry should succeed");
+            assert_eq!(
+                expected_retry_count,
+                get_default_attribute(&mut deps).retry_count,
+                "the retry count should be incremented by each retry",
+            );
+        }
+        test_verify_asset(
+            &mut deps,
+            &mock_env(),
+            TestVerifyAsset::default_with_success(true),
+        )
+        .unwrap();
+        let approved_attribute = get_default_attribute(&mut deps);
+        assert_eq!(
+            AssetOnboardingStatus::Approved,
+            approved_attribute.onboarding_status,
+            "the asset should be approved after a successful verification",
+        );
+        assert_eq!(
+            2, approved_attribute.retry_count,
+            "the retry count should be preserved when the asset is verified",
+        );
+    }
+
+    #[test]
+    fn test_onboard_asset_tracks_classification_blocks() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        let onboard_height = mock_env().block.height;
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        let onboarded_attribute = get_default_attribute(&mut deps);
+        assert_eq!(
+            onboard_height, onboarded_attribute.classification_initiated_at_block,
+            "the classification should be initiated at the onboarding block",
+        );
+        assert_eq!(
+            onboard_height, onboarded_attribute.last_modified_block,
+            "the attribute should be last modified at the onboarding block",
+        );
+        let mut verify_env = mock_env();
+        verify_env.block.height += 10;
+        test_verify_asset(
+            &mut deps,
+            &verify_env,
+            TestVerifyAsset::default_with_success(false),
+        )
+        .unwrap();
+        let denied_attribute = get_default_attribute(&mut deps);
+        assert_eq!(
+            onboard_height, denied_attribute.classification_initiated_at_block,
+            "the initiating block should be unchanged by verification",
+        );
+        assert_eq!(
+            verify_env.block.height, denied_attribute.last_modified_block,
+            "the attribute should be last modified at the verification block",
+        );
+        mock_denied_attribute(&mut deps);
+        let mut retry_env = mock_env();
+        retry_env.block.height += 20;
+        onboard_asset(
+            AssetMetaService::new(deps.as_mut()),
+            retry_env.to_owned(),
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+            TestOnboardAsset::default_onboard_asset(),
+        )
+        .and_then(|response| {
+            intercept_add_or_update_attribute(&mut deps, response, "the retry should succeed")
+        })
+        .unwrap();
+        let retried_attribute = get_default_attribute(&mut deps);
+        assert_eq!(
+            onboard_height, retried_attribute.classification_initiated_at_block,
+            "the initiating block should be retained by a retry",
+        );
+        assert_eq!(
+            retry_env.block.height, retried_attribute.last_modified_block,
+            "the attribute should be last modified at the retry block",
+        );
+    }
+
+    #[test]
+    fn test_onboard_asset_stores_requestor_metadata() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(
+            &mut deps,
+            get_metadata_onboard_asset(DEFAULT_REQUESTOR_METADATA.to_string().to_some()),
+        )
+        .expect("onboarding should succeed when metadata is provided");
+        assert_eq!(
+            DEFAULT_REQUESTOR_METADATA.to_string().to_some(),
+            get_default_attribute(&mut deps).requestor_metadata,
+            "the metadata should be stored on the attribute during the first onboard",
+        );
+        test_verify_asset(
+            &mut deps,
+            &mock_env(),
+            TestVerifyAsset::default_with_success(true),
+        )
+        .expect("the verification should succeed");
+        assert_eq!(
+            DEFAULT_REQUESTOR_METADATA.to_string().to_some(),
+            get_default_attribute(&mut deps).requestor_metadata,
+            "the metadata should be preserved when the asset is verified",
+        );
+    }
+
+    #[test]
+    fn test_onboard_asset_retry_retains_requestor_metadata() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(
+            &mut deps,
+            get_metadata_onboard_asset(DEFAULT_REQUESTOR_METADATA.to_string().to_some()),
+        )
+        .unwrap();
+        deny_and_retry(&mut deps).expect("the retry without metadata should succeed");
+        assert_eq!(
+            DEFAULT_REQUESTOR_METADATA.to_string().to_some(),
+            get_default_attribute(&mut deps).requestor_metadata,
+            "a retry without metadata should not clear the previously-stored metadata",
+        );
+        test_verify_asset(
+            &mut deps,
+            &mock_env(),
+            TestVerifyAsset::default_with_success(false),
+        )
+        .unwrap();
+        mock_denied_attribute(&mut deps);
+        test_onboard_asset(
+            &mut deps,
+            get_metadata_onboard_asset("{\"workflow_id\":\"wf-2\"}".to_string().to_some()),
+        )
+        .expect("the retry with new metadata should succeed");
+        assert_eq!(
+            "{\"workflow_id\":\"wf-2\"}".to_string().to_some(),
+            get_default_attribute(&mut deps).requestor_metadata,
+            "a retry with new metadata should replace the previously-stored metadata",
+        );
+    }
+
+    #[test]
+    fn test_onboard_asset_rejects_over_length_requestor_metadata() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        let err = execute_onboard_asset(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+            get_metadata_onboard_asset("a".repeat(MAX_REQUESTOR_METADATA_LENGTH + 1).to_some())
+                .onboard_asset,
+        )
+        .expect_err("onboarding should fail when the metadata is too long");
+        match err {
+            ContractError::InvalidMessageFields { invalid_fields, .. } => assert_eq!(
+                vec!["metadata: must not exceed 1024 bytes".to_string()],
+                invalid_fields,
+                "the over-length metadata should be the only invalid field",
+            ),
+            _ => panic!("unexpected error encountered: {:?}", err),
+        };
+    }
+
+    #[test]
+    fn test_onboard_asset_retry_rejected_after_max_retries() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(
+            &mut deps,
+            &InstArgs::with_single_verifier(VerifierDetailV2 {
+                max_retries: 1.to_some(),
+                ..get_default_verifier_detail()
+            }),
+        );
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        deny_and_retry(&mut deps).expect("the first retry should be allowed");
+        let err = deny_and_retry(&mut deps)
+            .expect_err("a retry beyond the verifier's maximum should be rejected");
+        match err {
+            ContractError::MaxRetriesExceeded {
+                scope_address,
+                asset_type,
+                max_retries,
+            } => {
+                assert_eq!(
+                    DEFAULT_SCOPE_ADDRESS, scope_address,
+                    "the error should reference the onboarded scope",
+                );
+                assert_eq!(
+                    DEFAULT_ASSET_TYPE, asset_type,
+                    "the error should reference the onboarded asset type",
+                );
+                assert_eq!(
+                    1, max_retries,
+                    "the error should reference the verifier's maximum retries",
+                );
+            }
+            _ => panic!("unexpected error encountered: {:?}", err),
+        };
+    }
+
+    #[test]
+    fn test_onboard_asset_rejected_when_verifier_block_limit_reached() {
+        let mut deps = mock_provenance_dependencies();
+        setup_rate_limited_verifier(&mut deps, 1);
+        increment_verifier_block_classification_count(
+            deps.as_mut().storage,
+            DEFAULT_ASSET_TYPE,
+            DEFAULT_VERIFIER_ADDRESS,
+            mock_env().block.height,
+        )
+        .expect("the block classification count should be incremented");
+        let err = test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect_err("an onboard beyond the verifier's per-block limit should be rejected");
+        match err {
+            ContractError::VerifierRateLimitExceeded {
+                asset_type,
+                verifier_address,
+                block_limit,
+            } => {
+                assert_eq!(
+                    DEFAULT_ASSET_TYPE, asset_type,
+                    "the error should reference the onboarded asset type",
+                );
+                assert_eq!(
+                    DEFAULT_VERIFIER_ADDRESS, verifier_address,
+                    "the error should reference the selected verifier",
+                );
+                assert_eq!(
+                    1, block_limit,
+                    "the error should reference the verifier's per-block limit",
+                );
+            }
+            _ => panic!("unexpected error encountered: {:?}", err),
+        };
+        assert_eq!(
+            1,
+            load_block_classification_count(&deps, mock_env().block.height),
+            "a rejected onboard should not increment the block classification count",
+        );
+    }
+
+    #[test]
+    fn test_onboard_asset_verifier_block_limit_resets_in_subsequent_block() {
+        let mut deps = mock_provenance_dependencies();
+        setup_rate_limited_verifier(&mut deps, 1);
+        let current_height = mock_env().block.height;
+        increment_verifier_block_classification_count(
+            deps.as_mut().storage,
+            DEFAULT_ASSET_TYPE,
+            DEFAULT_VERIFIER_ADDRESS,
+            current_height - 1,
+        )
+        .expect("the block classification count should be incremented");
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("classifications in a previous block should not count against the limit");
+        assert_eq!(
+            1,
+            load_block_classification_count(&deps, current_height),
+            "the successful onboard should be counted against the current block",
+        );
+        assert_eq!(
+            1,
+            load_block_classification_count(&deps, current_height - 1),
+            "the previous block's count should be unchanged",
+        );
+    }
+
+    #[test]
+    fn test_onboard_asset_without_verifier_block_limit_is_unlimited() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
         setup_no_attribute_response(&mut deps, None);
-        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
-        let payment_detail_before = load_fee_payment_detail(
+        for _ in 0..10 {
+            increment_verifier_block_classification_count(
+                deps.as_mut().storage,
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS,
+                mock_env().block.height,
+            )
+            .expect("the block classification count should be incremented");
+        }
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("a verifier without a per-block limit should never be rate limited");
+        assert_eq!(
+            10,
+            load_block_classification_count(&deps, mock_env().block.height),
+            "classifications should not be counted for a verifier without a per-block limit",
+        );
+    }
+
+    fn setup_rate_limited_verifier(deps: &mut MockOwnedDeps, max_classifications_per_block: u32) {
+        setup_test_suite(
+            deps,
+            &InstArgs::with_single_verifier(VerifierDetailV2 {
+                max_classifications_per_block: max_classifications_per_block.to_some(),
+                ..get_default_verifier_detail()
+            }),
+        );
+        setup_no_attribute_response(deps, None);
+    }
+
+    fn load_block_classification_count(deps: &MockOwnedDeps, block_height: u64) -> u32 {
+        load_verifier_block_classification_count(
             deps.as_ref().storage,
-            DEFAULT_SCOPE_ADDRESS,
             DEFAULT_ASSET_TYPE,
+            DEFAULT_VERIFIER_ADDRESS,
+            block_height,
         )
-        .expect("a fee payment detail should be stored for the asset after onboarding");
+        .expect("the block classification count should load")
+    }
+
+    fn deny_and_retry(deps: &mut MockOwnedDeps) -> EntryPointResponse {
         test_verify_asset(
-            &mut deps,
-            &instantiate_args.env,
+            deps,
+            &mock_env(),
             TestVerifyAsset::default_with_success(false),
         )
         .unwrap();
-        load_fee_payment_detail(
-            deps.as_ref().storage,
-            DEFAULT_SCOPE_ADDRESS,
-            DEFAULT_ASSET_TYPE,
-        )
-        .expect_err("no fee payment detail should be present after success=false verification");
-        let attribute = AssetMetaService::new(deps.as_mut())
+        mock_denied_attribute(deps);
+        test_onboard_asset(deps, TestOnboardAsset::default())
+    }
+
+    fn get_default_attribute(deps: &mut MockOwnedDeps) -> AssetScopeAttribute {
+        AssetMetaService::new(deps.as_mut())
             .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
-            .expect("the default scope address should have an attribute attached to it");
+            .expect("the default scope address should have an attribute attached to it")
+    }
+
+    #[test]
+    fn test_onboard_asset_retry_after_verification_timeout() {
+        let mut deps = mock_provenance_dependencies();
+        let response = onboard_and_retry_with_verification_timeout(&mut deps, 3600, 3601)
+            .expect("a retry after the verification timeout elapses should succeed");
+        let timeout_event = response
+            .events
+            .iter()
+            .find(|event| event.ty == EventType::VerificationTimeout.event_name())
+            .expect("a verification timeout event should be emitted");
+        let timeout_attribute = |key: &str| {
+            timeout_event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == key)
+                .map(|attr| attr.value.to_owned())
+        };
         assert_eq!(
-            attribute.onboarding_status,
-            AssetOnboardingStatus::Denied,
-            "sanity check: the onboarding status should be set to denied after the verifier marks the asset as success = false",
+            EventType::VerificationTimeout.event_name(),
+            timeout_attribute(ASSET_EVENT_TYPE_KEY).unwrap_or_default(),
+            "the timeout event should include the correct event type",
         );
-        let response = test_onboard_asset(
-            &mut deps,
-            TestOnboardAsset {
-                onboard_asset: OnboardAssetV1 {
-                    verifier_address: other_verifier.address.clone(),
-                    ..TestOnboardAsset::default_onboard_asset()
-                },
-                ..TestOnboardAsset::default()
-            },
-        )
-        .unwrap();
         assert_eq!(
-            2,
-            response.messages.len(),
-            "two messages should be emitted in the retry. One for an attribute update and one for a message fee",
+            DEFAULT_SCOPE_ADDRESS,
+            timeout_attribute(ASSET_SCOPE_ADDRESS_KEY).unwrap_or_default(),
+            "the timeout event should include the timed out scope address",
         );
-        assert!(
-            try_into_update_attribute_request(&response.messages[0].msg).is_some(),
-            "the first emitted message should update the attribute",
+        assert_eq!(
+            DEFAULT_VERIFIER_ADDRESS,
+            timeout_attribute(VERIFIER_ADDRESS_KEY).unwrap_or_default(),
+            "the timeout event should include the verifier that failed to respond",
         );
-        assert!(
-            try_into_custom_fee_request(&response.messages[1].msg).is_some(),
-            "the second emitted message should be the message fee"
+        assert_eq!(
+            AssetOnboardingStatus::Denied.to_string(),
+            timeout_attribute(NEW_ASSET_ONBOARDING_STATUS_KEY).unwrap_or_default(),
+            "the timeout event should indicate that the pending verification was denied",
         );
-        test_aggregate_msg_fees_are_charged(
+        assert_response_attributes_contain(
             &response,
-            300,
-            "the retry amount should not be used because a different verifier was used",
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::OnboardAsset.event_name(),
         );
-        let attribute = AssetMetaService::new(deps.as_mut())
-            .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
-            .expect("the default scope address should still contain an attribute after onboarding for a second time");
-        assert_eq!(
-            attribute.onboarding_status,
-            AssetOnboardingStatus::Pending,
-            "the onboarding status should now be set to pending after retrying the onboard process",
+        assert_response_messages_count(
+            &response,
+            3,
+            "a refund, an attribute update and a message fee should be emitted",
         );
-        assert_eq!(
-            attribute.verifier_address.as_str(),
-            other_verifier.address,
-            "the attribute should be updated to the other verifier's address",
+        assert_msg_type(&response.messages[0].msg, "bank_send");
+        if let CosmosMsg::Bank(BankMsg::Send { to_address, amount }) = &response.messages[0].msg {
+            assert_eq!(
+                DEFAULT_SENDER_ADDRESS, to_address,
+                "the original requestor should be refunded",
+            );
+            assert_eq!(
+                coins(DEFAULT_ONBOARDING_COST, NHASH),
+                *amount,
+                "the requestor should be refunded the original onboarding fees",
+            );
+        }
+        assert!(
+            try_into_update_attribute_request(&response.messages[1].msg).is_some(),
+            "the attribute should be updated after the refund",
         );
-        let payment_detail_after = load_fee_payment_detail(
+        let payment_detail = load_fee_payment_detail(
             deps.as_ref().storage,
             DEFAULT_SCOPE_ADDRESS,
             DEFAULT_ASSET_TYPE,
         )
-        .expect("a fee payment detail should be stored for the asset");
-        assert_ne!(
-            payment_detail_before, payment_detail_after,
-            "the payment details should not match due to changing verifiers",
+        .expect("a new fee payment detail should be stored for the retry");
+        assert_eq!(
+            mock_env().block.time.plus_seconds(3601).seconds(),
+            payment_detail.created_at,
+            "the new fee payment detail should be created at the time of the retry",
         );
         assert_eq!(
-            // Proves that this subsequent retry using a different verifier will not load the
-            // retry fees, because retries should only execute when using the same verifier
-            FeePaymentDetail::new(DEFAULT_SCOPE_ADDRESS, &other_verifier, false, DEFAULT_ASSET_TYPE, &[])
-                .expect("the other verifier should be successfully converted to a fee payment detail"),
-            payment_detail_after,
-            "the fee payment detail after the retry should equate to the new verifier's fee definitions",
+            DEFAULT_RETRY_COST,
+            payment_detail.sum_costs(),
+            "the retry should use the retry cost because the same verifier was used",
+        );
+        assert_eq!(
+            1,
+            load_verifier_stats(
+                deps.as_ref().storage,
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS,
+            )
+            .expect("the verifier stats should load")
+            .total_pending,
+            "the timed out verification should no longer be counted as pending, leaving only the retry",
         );
     }
 
     #[test]
-    fn test_onboarding_asset_retry_success_with_free_retries() {
+    fn test_onboard_asset_retry_after_verification_timeout_retains_matching_gateway_grant() {
         let mut deps = mock_provenance_dependencies();
-        // Set up the contract as normal, but make retries free
-        let instantiate_args = InstArgs {
-            asset_definitions: vec![AssetDefinitionInputV3 {
-                verifiers: vec![VerifierDetailV2 {
-                    retry_cost: OnboardingCost::new(0, &[]).to_some(),
-                    ..get_default_verifier_detail()
-                }],
-                ..get_default_asset_definition_input()
-            }],
-            ..InstArgs::default()
-        };
-        setup_test_suite(&mut deps, &instantiate_args);
-        setup_no_attribute_response(&mut deps, None);
-        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
-        test_verify_asset(
+        let response = onboard_and_retry_with_verification_timeout(&mut deps, 3600, 3601)
+            .expect("a retry after the verification timeout elapses should succeed");
+        assert_response_attributes_contain(
+            &response,
+            OS_GATEWAY_KEYS.event_type,
+            OS_GATEWAY_EVENT_TYPES.access_grant,
+        );
+        assert!(
+            !response
+                .attributes
+                .iter()
+                .any(|attr| attr.value == OS_GATEWAY_EVENT_TYPES.access_revoke),
+            "the grant should be reused rather than revoked when the same verifier is granted access under the same grant id",
+        );
+    }
+
+    #[test]
+    fn test_onboard_asset_retry_after_verification_timeout_revokes_gateway_access() {
+        let mut deps = mock_provenance_dependencies();
+        onboard_with_verification_timeout(&mut deps, 3600);
+        let response = retry_without_verifier_response(
             &mut deps,
-            &instantiate_args.env,
-            TestVerifyAsset::default_with_success(false),
+            3601,
+            OnboardAssetV1 {
+                add_os_gateway_permission: false,
+                ..TestOnboardAsset::default_onboard_asset()
+            },
         )
-        .unwrap();
+        .expect("a retry after the verification timeout elapses should succeed");
+        assert_response_attributes_contain(
+            &response,
+            OS_GATEWAY_KEYS.event_type,
+            OS_GATEWAY_EVENT_TYPES.access_revoke,
+        );
+        assert_response_attributes_contain(
+            &response,
+            OS_GATEWAY_KEYS.target_account,
+            DEFAULT_VERIFIER_ADDRESS,
+        );
+        assert_response_attributes_contain(
+            &response,
+            OS_GATEWAY_KEYS.access_grant_id,
+            &generate_os_gateway_grant_id(None, DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS),
+        );
+    }
+
+    #[test]
+    fn test_onboard_asset_retry_after_verification_timeout_rejects_conflicting_gateway_grant() {
+        let mut deps = mock_provenance_dependencies();
+        onboard_with_verification_timeout(&mut deps, 3600);
+        STATE_V2
+            .update(deps.as_mut().storage, |mut state| -> AssetResult<_> {
+                state.os_gateway_grant_prefix = "testnet".to_string().to_some();
+                Ok(state)
+            })
+            .expect("the grant prefix should be stored");
+        let error = retry_without_verifier_response(
+            &mut deps,
+            3601,
+            TestOnboardAsset::default_onboard_asset(),
+        )
+        .expect_err("a new grant that cannot replace the timed out grant should be rejected");
+        assert!(
+            error.is_invalid_message_fields(),
+            "expected an invalid message fields error, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_onboard_asset_pending_before_verification_timeout() {
+        let mut deps = mock_provenance_dependencies();
+        let error = onboard_and_retry_with_verification_timeout(&mut deps, 3600, 3600)
+            .expect_err("a retry before the verification timeout elapses should be rejected");
+        assert!(
+            matches!(error, ContractError::AssetPendingVerification { .. }),
+            "the asset should still be pending verification, but got: {:?}",
+            error,
+        );
+        load_fee_payment_detail(
+            deps.as_ref().storage,
+            DEFAULT_SCOPE_ADDRESS,
+            DEFAULT_ASSET_TYPE,
+        )
+        .expect("the original fee payment detail should remain in storage");
+    }
+
+    /// Onboards the default asset with the given verification timeout configured in the contract,
+    /// and then attempts to onboard it again the given number of seconds later without a response
+    /// from the verifier.
+    fn onboard_and_retry_with_verification_timeout(
+        deps: &mut MockOwnedDeps,
+        verification_timeout_seconds: u64,
+        seconds_since_onboard: u64,
+    ) -> EntryPointResponse {
+        onboard_with_verification_timeout(deps, verification_timeout_seconds);
+        retry_without_verifier_response(
+            deps,
+            seconds_since_onboard,
+            TestOnboardAsset::default_onboard_asset(),
+        )
+    }
+
+    /// Onboards the default asset with the given verification timeout configured in the contract.
+    fn onboard_with_verification_timeout(
+        deps: &mut MockOwnedDeps,
+        verification_timeout_seconds: u64,
+    ) {
+        setup_test_suite(deps, &InstArgs::default());
+        STATE_V2
+            .update(deps.as_mut().storage, |mut state| -> AssetResult<_> {
+                state.verification_timeout_seconds = verification_timeout_seconds.to_some();
+                Ok(state)
+            })
+            .expect("the verification timeout should be stored");
+        setup_no_attribute_response(deps, None);
+        test_onboard_asset(deps, TestOnboardAsset::default()).unwrap();
+    }
+
+    /// Attempts to onboard the default asset again with the given message, the given number of
+    /// seconds after its pending onboarding without a response from the verifier.
+    fn retry_without_verifier_response(
+        deps: &mut MockOwnedDeps,
+        seconds_since_onboard: u64,
+        retry_msg: OnboardAssetV1,
+    ) -> EntryPointResponse {
         let attribute = AssetMetaService::new(deps.as_mut())
             .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
             .expect("the default scope address should have an attribute attached to it");
-        assert_eq!(
-            attribute.onboarding_status,
-            AssetOnboardingStatus::Denied,
-            "sanity check: the onboarding status should be set to denied after the verifier marks the asset as success = false",
+        mock_attribute_response_for_scope(
+            deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![build_attribute(DEFAULT_SCOPE_ADDRESS, &attribute)],
         );
-        QueryAttributesRequest::mock_response(
-            &mut deps.querier,
-            QueryAttributesResponse {
-                account: DEFAULT_SCOPE_ADDRESS.to_string(),
-                attributes: vec![build_attribute(DEFAULT_SCOPE_ADDRESS, &attribute)],
-                pagination: None,
-            },
+        let mut retry_env = mock_env();
+        retry_env.block.time = retry_env.block.time.plus_seconds(seconds_since_onboard);
+        onboard_asset(
+            AssetMetaService::new(deps.as_mut()),
+            retry_env,
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+            retry_msg,
+        )
+    }
+
+    /// Onboards and denies the default asset with a verifier that has the given cooldown, with the
+    /// denial occurring the given number of seconds before the retry is attempted.
+    fn deny_and_retry_with_cooldown(
+        deps: &mut MockOwnedDeps,
+        cooldown_seconds: u64,
+        seconds_since_denial: u64,
+    ) -> EntryPointResponse {
+        setup_test_suite(
+            deps,
+            &InstArgs::with_single_verifier(VerifierDetailV2 {
+                cooldown_seconds: cooldown_seconds.to_some(),
+                ..get_default_verifier_detail()
+            }),
         );
-        let response = test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
-        test_no_money_moved_in_response(
-            &response,
-            "no funds should be sent when onboarding as a retry with no retry cost",
+        setup_no_attribute_response(deps, None);
+        test_onboard_asset(deps, TestOnboardAsset::default()).unwrap();
+        let mut denial_env = mock_env();
+        denial_env.block.time = denial_env.block.time.minus_seconds(seconds_since_denial);
+        test_verify_asset(
+            deps,
+            &denial_env,
+            TestVerifyAsset::default_with_success(false),
+        )
+        .unwrap();
+        mock_denied_attribute(deps);
+        test_onboard_asset(deps, TestOnboardAsset::default())
+    }
+
+    fn mock_denied_attribute(deps: &mut MockOwnedDeps) {
+        let attribute = AssetMetaService::new(deps.as_mut())
+            .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+            .expect("the default scope address should have an attribute attached to it");
+        mock_attribute_response_for_scope(
+            deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![build_attribute(DEFAULT_SCOPE_ADDRESS, &attribute)],
         );
     }
 
@@ -1273,11 +2658,12 @@ mod tests {
             )
             .to_some(),
         );
-        let secondary_asset_definition = AssetDefinitionV3::new(
-            DEFAULT_SECONDARY_ASSET_TYPE,
-            Some("secondary asset"),
-            vec![secondary_verifier.clone()],
-        );
+        let secondary_asset_definition = AssetDefinitionV3::builder()
+            .asset_type(DEFAULT_SECONDARY_ASSET_TYPE)
+            .display_name(Some("secondary asset"))
+            .add_verifier(secondary_verifier.clone())
+            .build()
+            .expect("the secondary asset definition should be built");
         add_asset_definition(
             deps.as_mut(),
             mock_env(),
@@ -1295,14 +2681,7 @@ mod tests {
             .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
             .expect("the existing asset type should have an asset scope attribute");
         // We expect to find no match when querying for an existing attribute with the same name as the yet-to-be-added second attribute
-        QueryAttributeRequest::mock_response(
-            &mut deps.querier,
-            QueryAttributeResponse {
-                account: DEFAULT_SCOPE_ADDRESS.to_string(),
-                attributes: vec![],
-                pagination: None,
-            },
-        );
+        mock_named_attribute_response_for_scope(&mut deps, DEFAULT_SCOPE_ADDRESS, vec![]);
         // We expect to find a single match when querying for any existing attributes: the first attribute that was already added
         mock_single_scope_attribute(&mut deps, &existing_scope_attribute, DEFAULT_SCOPE_ADDRESS);
         let subsequent_response = test_onboard_asset(
@@ -1338,6 +2717,8 @@ mod tests {
             false,
             DEFAULT_SECONDARY_ASSET_TYPE,
             &[existing_scope_attribute],
+            mock_env().block.time.seconds(),
+            mock_env().block.height,
         )
         .expect("fee payment detail generation using the correct values should succeed");
         assert_eq!(
@@ -1368,11 +2749,12 @@ mod tests {
             )
             .to_some(),
         );
-        let secondary_asset_definition = AssetDefinitionV3::new(
-            DEFAULT_SECONDARY_ASSET_TYPE,
-            Some("secondary asset"),
-            vec![secondary_verifier],
-        );
+        let secondary_asset_definition = AssetDefinitionV3::builder()
+            .asset_type(DEFAULT_SECONDARY_ASSET_TYPE)
+            .display_name(Some("secondary asset"))
+            .add_verifier(secondary_verifier)
+            .build()
+            .expect("the secondary asset definition should be built");
         add_asset_definition(
             deps.as_mut(),
             mock_env(),
@@ -1413,7 +2795,8 @@ mod tests {
         assert_eq!(
             300,
             payment_detail.amount.amount.u128(),
-            "the payment amount should be 300, which is the entirety of the onboarding cost",
+            "the payment amount should be {}, which is the entirety of the onboarding cost",
+            format_nhash(300),
         );
         assert_eq!(
             DEFAULT_VERIFIER_ADDRESS,
@@ -1444,11 +2827,12 @@ mod tests {
             )
             .to_some(),
         );
-        let secondary_asset_definition = AssetDefinitionV3::new(
-            DEFAULT_SECONDARY_ASSET_TYPE,
-            Some("secondary asset"),
-            vec![secondary_verifier.clone()],
-        );
+        let secondary_asset_definition = AssetDefinitionV3::builder()
+            .asset_type(DEFAULT_SECONDARY_ASSET_TYPE)
+            .display_name(Some("secondary asset"))
+            .add_verifier(secondary_verifier.clone())
+            .build()
+            .expect("the secondary asset definition should be built");
         add_asset_definition(
             deps.as_mut(),
             mock_env(),
@@ -1466,23 +2850,13 @@ mod tests {
             .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
             .expect("the default scope address should have an attribute attached to it");
         // Ensure a query for all attributes returns the initially onboarded attribute
-        QueryAttributesRequest::mock_response(
-            &mut deps.querier,
-            QueryAttributesResponse {
-                account: DEFAULT_SCOPE_ADDRESS.to_string(),
-                attributes: vec![build_attribute(DEFAULT_SCOPE_ADDRESS, &initial_attribute)],
-                pagination: None,
-            },
+        mock_attribute_response_for_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![build_attribute(DEFAULT_SCOPE_ADDRESS, &initial_attribute)],
         );
         // Ensure the query for the attribute yet to be onboarded returns no results
-        QueryAttributeRequest::mock_response(
-            &mut deps.querier,
-            QueryAttributeResponse {
-                account: DEFAULT_SCOPE_ADDRESS.to_string(),
-                attributes: vec![],
-                pagination: None,
-            },
-        );
+        mock_named_attribute_response_for_scope(&mut deps, DEFAULT_SCOPE_ADDRESS, vec![]);
         let response = test_onboard_asset(
             &mut deps,
             TestOnboardAsset {
@@ -1523,12 +2897,12 @@ mod tests {
         service
             .update_attribute(&instantiate_args.env, &updated_attribute)
             .expect("update attribute should work as intended");
-        let generated_messages = service.get_messages();
         assert_eq!(
             1,
-            generated_messages.len(),
+            service.message_count(),
             "the service should generate one message when updating an attribute"
         );
+        let generated_messages = service.get_messages();
         let target_attribute_name =
             generate_asset_attribute_name(DEFAULT_ASSET_TYPE, DEFAULT_CONTRACT_BASE_NAME);
         let first_message = &generated_messages[0];
@@ -1597,6 +2971,9 @@ mod tests {
                     verifier_address: DEFAULT_VERIFIER_ADDRESS.into(),
                     access_routes: get_default_access_routes().to_some(),
                     add_os_gateway_permission: permission_spec,
+                    expected_fee: None,
+                    memo: None,
+                    metadata: None,
                 },
             )
         };
@@ -1623,6 +3000,67 @@ mod tests {
         assert_onboard_response_attributes_are_correct(&explicit_false_response, false);
     }
 
+    fn get_scope_spec_bound_inst_args() -> InstArgs {
+        InstArgs::with_asset_definitions(vec![AssetDefinitionInputV3 {
+            scope_spec_address: DEFAULT_SCOPE_SPEC_ADDRESS.to_string().to_some(),
+            ..get_default_asset_definition_input()
+        }])
+    }
+
+    fn get_expected_fee_onboard_asset(expected_fee: Option<Uint128>) -> TestOnboardAsset {
+        TestOnboardAsset {
+            onboard_asset: OnboardAssetV1 {
+                expected_fee,
+                ..TestOnboardAsset::default_onboard_asset()
+            },
+            ..TestOnboardAsset::default()
+        }
+    }
+
+    fn get_memo_onboard_asset(memo: Option<String>) -> TestOnboardAsset {
+        TestOnboardAsset {
+            onboard_asset: OnboardAssetV1 {
+                memo,
+                ..TestOnboardAsset::default_onboard_asset()
+            },
+            ..TestOnboardAsset::default()
+        }
+    }
+
+    fn get_metadata_onboard_asset(metadata: Option<String>) -> TestOnboardAsset {
+        TestOnboardAsset {
+            onboard_asset: OnboardAssetV1 {
+                metadata,
+                ..TestOnboardAsset::default_onboard_asset()
+            },
+            ..TestOnboardAsset::default()
+        }
+    }
+
+    fn get_allowed_requestors_inst_args(allowed_requestors: Option<Vec<String>>) -> InstArgs {
+        InstArgs::with_asset_definitions(vec![AssetDefinitionInputV3 {
+            allowed_requestors,
+            ..get_default_asset_definition_input()
+        }])
+    }
+
+    fn get_scope_owner_must_match_requestor_inst_args() -> InstArgs {
+        InstArgs::with_asset_definitions(vec![AssetDefinitionInputV3 {
+            scope_owner_must_match_requestor: true.to_some(),
+            ..get_default_asset_definition_input()
+        }])
+    }
+
+    fn assert_scope_owner_must_match_requestor_error(err: ContractError) {
+        match err {
+            ContractError::Unauthorized { explanation } => assert_eq!(
+                "requestor must be the scope owner", explanation,
+                "the error should describe the scope owner restriction",
+            ),
+            _ => panic!("unexpected error encountered: {:?}", err),
+        };
+    }
+
     fn assert_onboard_response_attributes_are_correct(
         response: &Response,
         expect_os_gateway_values: bool,
@@ -1632,58 +3070,46 @@ mod tests {
             response.attributes.len(),
             "the correct number of response attributes should be emitted",
         );
-        assert_eq!(
-            "onboard_asset",
-            single_attribute_for_key(response, ASSET_EVENT_TYPE_KEY),
-            "the correct event type attribute should be emitted",
-        );
-        assert_eq!(
-            DEFAULT_ASSET_TYPE,
-            single_attribute_for_key(response, ASSET_TYPE_KEY),
-            "the correct asset type attribute should be emitted",
-        );
-        assert_eq!(
+        assert_response_attributes_contain(response, ASSET_EVENT_TYPE_KEY, "onboard_asset");
+        assert_response_attributes_contain(response, ASSET_TYPE_KEY, DEFAULT_ASSET_TYPE);
+        assert_response_attributes_contain(
+            response,
+            ASSET_SCOPE_ADDRESS_KEY,
             DEFAULT_SCOPE_ADDRESS,
-            single_attribute_for_key(response, ASSET_SCOPE_ADDRESS_KEY),
-            "the correct scope address attribute should be emitted",
         );
-        assert_eq!(
+        assert_response_attributes_contain(
+            response,
+            VERIFIER_ADDRESS_KEY,
             DEFAULT_VERIFIER_ADDRESS,
-            single_attribute_for_key(response, VERIFIER_ADDRESS_KEY),
-            "the correct verifier address attribute should be emitted",
-        );
-        assert_eq!(
-            DEFAULT_SENDER_ADDRESS,
-            single_attribute_for_key(response, SCOPE_OWNER_KEY),
-            "the correct scope owner address attribute should be emitted",
         );
-        assert_eq!(
-            AssetOnboardingStatus::Pending.to_string(),
-            single_attribute_for_key(response, NEW_ASSET_ONBOARDING_STATUS_KEY),
-            "the new onboarding status after a successful onboard should always be pending",
+        assert_response_attributes_contain(response, SCOPE_OWNER_KEY, DEFAULT_SENDER_ADDRESS);
+        assert_response_attributes_contain(
+            response,
+            NEW_ASSET_ONBOARDING_STATUS_KEY,
+            &AssetOnboardingStatus::Pending.to_string(),
         );
         if !expect_os_gateway_values {
             return;
         }
-        assert_eq!(
+        assert_response_attributes_contain(
+            response,
+            OS_GATEWAY_KEYS.event_type,
             OS_GATEWAY_EVENT_TYPES.access_grant,
-            single_attribute_for_key(response, OS_GATEWAY_KEYS.event_type),
-            "the correct object store gateway event type attribute should be emitted",
         );
-        assert_eq!(
+        assert_response_attributes_contain(
+            response,
+            OS_GATEWAY_KEYS.scope_address,
             DEFAULT_SCOPE_ADDRESS,
-            single_attribute_for_key(response, OS_GATEWAY_KEYS.scope_address),
-            "the correct object store gateway scope address attribute should be emitted",
         );
-        assert_eq!(
+        assert_response_attributes_contain(
+            response,
+            OS_GATEWAY_KEYS.target_account,
             DEFAULT_VERIFIER_ADDRESS,
-            single_attribute_for_key(response, OS_GATEWAY_KEYS.target_account),
-            "the correct object store gateway target account address attribute should be emitted",
         );
-        assert_eq!(
-            generate_os_gateway_grant_id(DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS),
-            single_attribute_for_key(response, OS_GATEWAY_KEYS.access_grant_id),
-            "the correct object store gateway access grant id attribute should be emitted",
+        assert_response_attributes_contain(
+            response,
+            OS_GATEWAY_KEYS.access_grant_id,
+            &generate_os_gateway_grant_id(None, DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS),
         );
     }
 }
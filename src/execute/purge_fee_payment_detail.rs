@@ -0,0 +1,412 @@
+use crate::core::error::ContractError;
+use crate::core::msg::ExecuteMsg;
+use crate::core::state::{
+    decrement_verifier_pending, delete_fee_payment_detail, load_fee_payment_detail,
+    update_verifier_status_index,
+};
+use crate::core::types::asset_identifier::AssetIdentifier;
+use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+use crate::core::types::asset_verification_result::AssetVerificationResult;
+use crate::service::asset_meta_repository::AssetMetaRepository;
+use crate::service::deps_manager::DepsManager;
+use crate::service::message_gathering_service::MessageGatheringService;
+use crate::util::aliases::{AssetResult, EntryPointResponse};
+use crate::util::constants::MAX_FEE_PAYMENT_DETAIL_SECONDS;
+use crate::util::contract_helpers::{
+    check_admin_only, check_funds_are_empty, generate_os_gateway_access_revoke,
+};
+use crate::util::event_attributes::{EventAttributes, EventType};
+
+use cosmwasm_std::{MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// A transformation of [ExecuteMsg::PurgeFeePaymentDetail](crate::core::msg::ExecuteMsg::PurgeFeePaymentDetail)
+/// for ease of use in the underlying [purge_fee_payment_detail](self::purge_fee_payment_detail) function.
+///
+/// # Parameters
+///
+/// * `identifier` An instance of the asset identifier enum that helps the contract identify which
+/// scope the expired [FeePaymentDetail](crate::core::types::fee_payment_detail::FeePaymentDetail)
+/// belongs to.
+/// * `asset_type` The asset type for which the expired fee payment detail was created.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PurgeFeePaymentDetailV1 {
+    pub identifier: AssetIdentifier,
+    pub asset_type: String,
+}
+impl PurgeFeePaymentDetailV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `identifier` An instance of the asset identifier enum that helps the contract identify which
+    /// scope the expired [FeePaymentDetail](crate::core::types::fee_payment_detail::FeePaymentDetail)
+    /// belongs to.
+    /// * `asset_type` The asset type for which the expired fee payment detail was created.
+    pub fn new<S: Into<String>>(identifier: AssetIdentifier, asset_type: S) -> Self {
+        PurgeFeePaymentDetailV1 {
+            identifier,
+            asset_type: asset_type.into(),
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [PurgeFeePaymentDetail](crate::core::msg::ExecuteMsg::PurgeFeePaymentDetail)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<PurgeFeePaymentDetailV1> {
+        match msg {
+            ExecuteMsg::PurgeFeePaymentDetail {
+                identifier,
+                asset_type,
+            } => Self::new(identifier.to_asset_identifier()?, asset_type).to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::PurgeFeePaymentDetail".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+impl TryFrom<ExecuteMsg> for PurgeFeePaymentDetailV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        PurgeFeePaymentDetailV1::from_execute_msg(msg)
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::PurgeFeePaymentDetail](crate::core::msg::ExecuteMsg::PurgeFeePaymentDetail)
+/// message is provided.  Removes a [FeePaymentDetail](crate::core::types::fee_payment_detail::FeePaymentDetail)
+/// that is older than [MAX_FEE_PAYMENT_DETAIL_SECONDS](crate::util::constants::MAX_FEE_PAYMENT_DETAIL_SECONDS),
+/// and refunds the fees collected from the requestor during onboarding, which could otherwise never
+/// be disbursed.  If the asset is still pending verification, it is denied, as it can no longer be
+/// verified without its fee payment detail, and any object store gateway access granted to the
+/// verifier during onboarding is revoked.
+///
+/// # Parameters
+///
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `repository` A helper collection of traits that allows complex lookups of scope values and
+/// emits messages to construct the process of purging as a collection of messages to produce in
+/// the function's result.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the purge fee payment detail v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn purge_fee_payment_detail<'a, T>(
+    env: &cosmwasm_std::Env,
+    repository: T,
+    info: MessageInfo,
+    msg: PurgeFeePaymentDetailV1,
+) -> EntryPointResponse
+where
+    T: AssetMetaRepository + MessageGatheringService + DepsManager<'a>,
+{
+    repository.use_deps(|deps| check_admin_only(&deps.as_ref(), &info))?;
+    check_funds_are_empty(&info)?;
+    let scope_address = msg.identifier.get_scope_address()?;
+    let fee_payment_detail = repository
+        .use_deps(|deps| load_fee_payment_detail(deps.storage, &scope_address, &msg.asset_type))?;
+    if !fee_payment_detail.is_expired(env.block.time.seconds()) {
+        return ContractError::UnexpectedState {
+            explanation: format!(
+                "fee payment detail for asset [{}] with asset type [{}] has not been stored for more than [{}] seconds and cannot be purged",
+                scope_address, msg.asset_type, MAX_FEE_PAYMENT_DETAIL_SECONDS,
+            ),
+        }
+        .to_err();
+    }
+    let scope_attribute = repository.get_asset_by_asset_type(&scope_address, &msg.asset_type)?;
+    let mut access_revoke = vec![];
+    if scope_attribute.onboarding_status == AssetOnboardingStatus::Pending {
+        let mut denied_attribute = scope_attribute.clone();
+        denied_attribute.onboarding_status = AssetOnboardingStatus::Denied;
+        denied_attribute.latest_verification_result = AssetVerificationResult {
+            message: "fee payment detail expired".to_string(),
+            success: false,
+        }
+        .into();
+        denied_attribute.pending_expiry_block = None;
+        repository.update_attribute(env, &denied_attribute)?;
+        repository.use_deps(|deps| {
+            update_verifier_status_index(deps.storage, Some(&scope_attribute), &denied_attribute)
+        })?;
+        repository.use_deps(|deps| {
+            decrement_verifier_pending(
+                deps.storage,
+                &msg.asset_type,
+                scope_attribute.verifier_address.as_str(),
+            )
+        })?;
+        access_revoke = repository
+            .use_deps(|deps| generate_os_gateway_access_revoke(deps.storage, &scope_attribute))?;
+    }
+    repository.use_deps(|deps| {
+        delete_fee_payment_detail(deps.storage, &scope_address, &msg.asset_type)
+    })?;
+    if let Some(refund_msg) = fee_payment_detail.to_refund_msg(&scope_attribute.requestor_address) {
+        repository.add_message(refund_msg);
+    }
+    Response::new()
+        .add_attributes(EventAttributes::for_asset_event(
+            EventType::PurgeFeePaymentDetail,
+            &msg.asset_type,
+            &scope_address,
+        ))
+        .add_attributes(access_revoke)
+        .add_messages(repository.get_messages())
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{coin, BankMsg, CosmosMsg, Env};
+    use os_gateway_contract_attributes::{OS_GATEWAY_EVENT_TYPES, OS_GATEWAY_KEYS};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::state::{
+        count_assets_by_verifier_and_status, load_fee_payment_detail, load_verifier_stats,
+        may_load_fee_payment_detail,
+    };
+    use crate::core::types::asset_identifier::AssetIdentifier;
+    use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+    use crate::service::asset_meta_repository::AssetMetaRepository;
+    use crate::service::asset_meta_service::AssetMetaService;
+    use crate::testutil::execute_utilities::execute_purge_fee_payment_detail;
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS, DEFAULT_SENDER_ADDRESS,
+        DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, assert_single_item_by, empty_mock_info,
+        intercept_add_or_update_attribute, mock_info_with_nhash, setup_no_attribute_response,
+        setup_test_suite, InstArgs, MockOwnedDeps,
+    };
+    use crate::util::aliases::EntryPointResponse;
+    use crate::util::constants::{
+        ASSET_EVENT_TYPE_KEY, ASSET_SCOPE_ADDRESS_KEY, ASSET_TYPE_KEY,
+        MAX_FEE_PAYMENT_DETAIL_SECONDS,
+    };
+    use crate::util::event_attributes::EventType;
+    use crate::util::functions::assert_msg_type;
+
+    use super::{purge_fee_payment_detail, PurgeFeePaymentDetailV1};
+
+    #[test]
+    fn test_purge_expired_fee_payment_detail_refunds_requestor() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps);
+        let detail =
+            load_fee_payment_detail(&deps.storage, DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+                .expect("the fee payment detail should be stored after onboarding");
+        let response = purge(&mut deps, &expired_env(), DEFAULT_ADMIN_ADDRESS)
+            .expect("purging an expired fee payment detail should succeed");
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::PurgeFeePaymentDetail.event_name(),
+        );
+        assert_response_attributes_contain(&response, ASSET_TYPE_KEY, DEFAULT_ASSET_TYPE);
+        assert_response_attributes_contain(
+            &response,
+            ASSET_SCOPE_ADDRESS_KEY,
+            DEFAULT_SCOPE_ADDRESS,
+        );
+        assert_response_attributes_contain(
+            &response,
+            OS_GATEWAY_KEYS.event_type,
+            OS_GATEWAY_EVENT_TYPES.access_revoke,
+        );
+        assert_response_attributes_contain(
+            &response,
+            OS_GATEWAY_KEYS.target_account,
+            DEFAULT_VERIFIER_ADDRESS,
+        );
+        assert_eq!(
+            2,
+            response.messages.len(),
+            "an attribute update and a refund should be emitted",
+        );
+        let refund_msg = &assert_single_item_by(
+            &response.messages,
+            "a single refund message should be emitted",
+            |msg| matches!(msg.msg, CosmosMsg::Bank(..)),
+        )
+        .msg;
+        assert_msg_type(refund_msg, "bank_send");
+        if let CosmosMsg::Bank(BankMsg::Send { to_address, amount }) = refund_msg {
+            assert_eq!(
+                DEFAULT_SENDER_ADDRESS, to_address,
+                "the refund should be sent to the requestor of the asset",
+            );
+            assert_eq!(
+                &vec![coin(
+                    detail.sum_costs(),
+                    &detail.payments.first().unwrap().amount.denom
+                )],
+                amount,
+                "the refund should include the total of all fee payments",
+            );
+        }
+        assert!(
+            may_load_fee_payment_detail(&deps.storage, DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+                .is_none(),
+            "the fee payment detail should be removed from storage",
+        );
+        intercept_add_or_update_attribute(
+            &mut deps,
+            response,
+            "purging should update the attribute",
+        )
+        .expect("the attribute update should be intercepted");
+        let attribute = AssetMetaService::new(deps.as_mut())
+            .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+            .expect("the scope attribute should exist");
+        assert_eq!(
+            AssetOnboardingStatus::Denied,
+            attribute.onboarding_status,
+            "the pending asset should be denied once its fee payment detail is purged",
+        );
+        assert_eq!(
+            0,
+            load_verifier_stats(&deps.storage, DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS)
+                .expect("the verifier stats should load")
+                .total_pending,
+            "the verifier should no longer be counted as having a pending verification",
+        );
+        assert_eq!(
+            0,
+            count_assets_by_verifier_and_status(
+                &deps.storage,
+                DEFAULT_VERIFIER_ADDRESS,
+                &AssetOnboardingStatus::Pending,
+                None,
+            )
+            .expect("the verifier status index should be readable"),
+            "the asset should be removed from the pending status index",
+        );
+    }
+
+    #[test]
+    fn test_purge_from_execute_route() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps);
+        execute_purge_fee_payment_detail(
+            deps.as_mut(),
+            expired_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+        )
+        .expect("purging through the execute route should succeed");
+        assert!(
+            may_load_fee_payment_detail(&deps.storage, DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+                .is_none(),
+            "the fee payment detail should be removed from storage",
+        );
+    }
+
+    #[test]
+    fn test_purge_rejected_for_unexpired_fee_payment_detail() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps);
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(MAX_FEE_PAYMENT_DETAIL_SECONDS);
+        let err = purge(&mut deps, &env, DEFAULT_ADMIN_ADDRESS)
+            .expect_err("purging a fee payment detail that has not expired should fail");
+        assert!(
+            matches!(err, ContractError::UnexpectedState { .. }),
+            "an unexpected state error should be emitted, but got: {:?}",
+            err,
+        );
+        assert!(
+            may_load_fee_payment_detail(&deps.storage, DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+                .is_some(),
+            "the fee payment detail should remain in storage",
+        );
+    }
+
+    #[test]
+    fn test_purge_rejected_for_non_admin_sender() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps);
+        let err = purge(&mut deps, &expired_env(), DEFAULT_SENDER_ADDRESS)
+            .expect_err("purging as a non-admin address should fail");
+        assert!(
+            err.is_unauthorized(),
+            "an unauthorized error should be emitted, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_purge_rejected_for_provided_funds() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps);
+        let err = purge_fee_payment_detail(
+            &expired_env(),
+            AssetMetaService::new(deps.as_mut()),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 100),
+            default_purge(),
+        )
+        .expect_err("purging with funds provided should fail");
+        assert!(
+            matches!(err, ContractError::InvalidFunds(..)),
+            "an invalid funds error should be emitted, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_purge_rejected_for_missing_fee_payment_detail() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        let err = purge(&mut deps, &expired_env(), DEFAULT_ADMIN_ADDRESS)
+            .expect_err("purging a fee payment detail that does not exist should fail");
+        assert!(
+            err.is_not_found(),
+            "a not found error should be emitted, but got: {:?}",
+            err,
+        );
+    }
+
+    fn setup_onboarded_asset(deps: &mut MockOwnedDeps) {
+        setup_test_suite(deps, &InstArgs::default());
+        setup_no_attribute_response(deps, None);
+        test_onboard_asset(deps, TestOnboardAsset::default())
+            .expect("the asset should be onboarded successfully");
+    }
+
+    fn expired_env() -> Env {
+        let mut env = mock_env();
+        env.block.time = env
+            .block
+            .time
+            .plus_seconds(MAX_FEE_PAYMENT_DETAIL_SECONDS + 1);
+        env
+    }
+
+    fn default_purge() -> PurgeFeePaymentDetailV1 {
+        PurgeFeePaymentDetailV1::new(
+            AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+        )
+    }
+
+    fn purge(deps: &mut MockOwnedDeps, env: &Env, sender: &str) -> EntryPointResponse {
+        purge_fee_payment_detail(
+            env,
+            AssetMetaService::new(deps.as_mut()),
+            empty_mock_info(sender),
+            default_purge(),
+        )
+    }
+}
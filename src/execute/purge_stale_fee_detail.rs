@@ -0,0 +1,460 @@
+use crate::core::error::ContractError;
+use crate::core::msg::ExecuteMsg;
+use crate::core::state::{delete_fee_payment_detail, load_fee_payment_detail, STATE_V2};
+use crate::core::types::asset_identifier::AssetIdentifier;
+use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+use crate::service::asset_meta_repository::AssetMetaRepository;
+use crate::service::deps_manager::DepsManager;
+use crate::service::message_gathering_service::MessageGatheringService;
+use crate::util::aliases::{AssetResult, EntryPointResponse};
+use crate::util::contract_helpers::{check_admin_only, check_funds_are_empty};
+use crate::util::event_attributes::{EventAttributes, EventType};
+
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// A transformation of [ExecuteMsg::PurgeStaleFeeDetail](crate::core::msg::ExecuteMsg::PurgeStaleFeeDetail)
+/// for ease of use in the underlying [purge_stale_fee_detail](self::purge_stale_fee_detail) function.
+///
+/// # Parameters
+///
+/// * `identifier` An instance of the asset identifier enum that helps the contract identify which
+/// scope the stale [FeePaymentDetail](crate::core::types::fee_payment_detail::FeePaymentDetail)
+/// belongs to.
+/// * `asset_type` The asset type for which the stale fee payment detail was created.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PurgeStaleFeeDetailV1 {
+    pub identifier: AssetIdentifier,
+    pub asset_type: String,
+}
+impl PurgeStaleFeeDetailV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `identifier` An instance of the asset identifier enum that helps the contract identify which
+    /// scope the stale [FeePaymentDetail](crate::core::types::fee_payment_detail::FeePaymentDetail)
+    /// belongs to.
+    /// * `asset_type` The asset type for which the stale fee payment detail was created.
+    pub fn new<S: Into<String>>(identifier: AssetIdentifier, asset_type: S) -> Self {
+        PurgeStaleFeeDetailV1 {
+            identifier,
+            asset_type: asset_type.into(),
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [PurgeStaleFeeDetail](crate::core::msg::ExecuteMsg::PurgeStaleFeeDetail)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<PurgeStaleFeeDetailV1> {
+        match msg {
+            ExecuteMsg::PurgeStaleFeeDetail {
+                identifier,
+                asset_type,
+            } => Self::new(identifier.to_asset_identifier()?, asset_type).to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::PurgeStaleFeeDetail".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+impl TryFrom<ExecuteMsg> for PurgeStaleFeeDetailV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        PurgeStaleFeeDetailV1::from_execute_msg(msg)
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::SetFeeDetailMaxAge](crate::core::msg::ExecuteMsg::SetFeeDetailMaxAge)
+/// message is provided.  Replaces the contract's [fee_detail_max_age_blocks](crate::core::state::StateV2::fee_detail_max_age_blocks).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `max_age_blocks` The new maximum age, in blocks.  A None value prevents stale fee payment
+/// details from being purged.
+pub fn set_fee_detail_max_age(
+    deps: DepsMut,
+    info: MessageInfo,
+    max_age_blocks: Option<u64>,
+) -> EntryPointResponse {
+    check_admin_only(&deps.as_ref(), &info)?;
+    check_funds_are_empty(&info)?;
+    let mut state = STATE_V2.load(deps.storage)?;
+    state.fee_detail_max_age_blocks = max_age_blocks;
+    STATE_V2.save(deps.storage, &state)?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::SetFeeDetailMaxAge).set_new_value(
+                max_age_blocks.map_or_else(|| "none".to_string(), |blocks| blocks.to_string()),
+            ),
+        )
+        .to_ok()
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::PurgeStaleFeeDetail](crate::core::msg::ExecuteMsg::PurgeStaleFeeDetail)
+/// message is provided.  Removes a [FeePaymentDetail](crate::core::types::fee_payment_detail::FeePaymentDetail)
+/// that has been stored for more than the contract's [fee_detail_max_age_blocks](crate::core::state::StateV2::fee_detail_max_age_blocks)
+/// for an asset that has been [Denied](crate::core::types::asset_onboarding_status::AssetOnboardingStatus::Denied).
+/// No funds are moved.
+///
+/// # Parameters
+///
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `repository` A helper collection of traits that allows complex lookups of scope values and
+/// emits messages to construct the process of purging as a collection of messages to produce in
+/// the function's result.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the purge stale fee detail v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn purge_stale_fee_detail<'a, T>(
+    env: &Env,
+    repository: T,
+    info: MessageInfo,
+    msg: PurgeStaleFeeDetailV1,
+) -> EntryPointResponse
+where
+    T: AssetMetaRepository + MessageGatheringService + DepsManager<'a>,
+{
+    check_funds_are_empty(&info)?;
+    let scope_address = msg.identifier.get_scope_address()?;
+    let max_age_blocks = match repository
+        .use_deps(|deps| STATE_V2.load(deps.storage))?
+        .fee_detail_max_age_blocks
+    {
+        Some(max_age_blocks) => max_age_blocks,
+        None => {
+            return ContractError::UnexpectedState {
+                explanation: "no fee detail max age is configured, so stale fee payment details cannot be purged".to_string(),
+            }
+            .to_err();
+        }
+    };
+    let scope_attribute = repository.get_asset_by_asset_type(&scope_address, &msg.asset_type)?;
+    if scope_attribute.onboarding_status != AssetOnboardingStatus::Denied {
+        return ContractError::UnexpectedState {
+            explanation: format!(
+                "asset [{}] with asset type [{}] has onboarding status [{}], and only the fee payment details of denied assets can be purged",
+                scope_address, msg.asset_type, scope_attribute.onboarding_status,
+            ),
+        }
+        .to_err();
+    }
+    let fee_payment_detail = repository
+        .use_deps(|deps| load_fee_payment_detail(deps.storage, &scope_address, &msg.asset_type))?;
+    if env
+        .block
+        .height
+        .saturating_sub(fee_payment_detail.created_at_block)
+        <= max_age_blocks
+    {
+        return ContractError::UnexpectedState {
+            explanation: format!(
+                "fee payment detail for asset [{}] with asset type [{}] has not been stored for more than [{}] blocks and cannot be purged",
+                scope_address, msg.asset_type, max_age_blocks,
+            ),
+        }
+        .to_err();
+    }
+    repository.use_deps(|deps| {
+        delete_fee_payment_detail(deps.storage, &scope_address, &msg.asset_type)
+    })?;
+    Response::new()
+        .add_attributes(EventAttributes::for_asset_event(
+            EventType::PurgeStaleFeeDetail,
+            &msg.asset_type,
+            &scope_address,
+        ))
+        .add_messages(repository.get_messages())
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::Env;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::state::{may_load_fee_payment_detail, STATE_V2};
+    use crate::core::types::asset_identifier::AssetIdentifier;
+    use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+    use crate::service::asset_meta_service::AssetMetaService;
+    use crate::testutil::attribute_utilities::{
+        build_scope_attribute, mock_named_attribute_response_for_scope,
+    };
+    use crate::testutil::execute_utilities::{
+        execute_purge_stale_fee_detail, execute_set_fee_detail_max_age,
+    };
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS, DEFAULT_SENDER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, empty_mock_info, mock_info_with_nhash,
+        setup_no_attribute_response, setup_test_suite, InstArgs, MockOwnedDeps,
+    };
+    use crate::util::aliases::EntryPointResponse;
+    use crate::util::constants::{
+        ASSET_EVENT_TYPE_KEY, ASSET_SCOPE_ADDRESS_KEY, ASSET_TYPE_KEY, NEW_VALUE_KEY,
+    };
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{purge_stale_fee_detail, PurgeStaleFeeDetailV1};
+
+    const MAX_AGE_BLOCKS: u64 = 100;
+    // Anyone may purge a stale fee detail, so an account unrelated to the asset is used
+    const UNRELATED_SENDER_ADDRESS: &str = "tp1hsqtppgy7mgd64q0uvk7q39qa7h8hp30urqs6n";
+
+    #[test]
+    fn test_onboarding_records_created_at_block() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps, AssetOnboardingStatus::Denied);
+        assert_eq!(
+            mock_env().block.height,
+            may_load_fee_payment_detail(&deps.storage, DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+                .expect("the fee payment detail should be stored after onboarding")
+                .created_at_block,
+            "the fee payment detail should record the block height at which it was created",
+        );
+    }
+
+    #[test]
+    fn test_set_fee_detail_max_age() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        let response = set_max_age(&mut deps, DEFAULT_ADMIN_ADDRESS, MAX_AGE_BLOCKS.to_some())
+            .expect("the admin should be able to set the fee detail max age");
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::SetFeeDetailMaxAge.event_name(),
+        );
+        assert_response_attributes_contain(&response, NEW_VALUE_KEY, "100");
+        assert_eq!(
+            MAX_AGE_BLOCKS.to_some(),
+            load_max_age(&deps),
+            "the max age should be stored in the contract's state",
+        );
+        set_max_age(&mut deps, DEFAULT_ADMIN_ADDRESS, None)
+            .expect("the admin should be able to clear the fee detail max age");
+        assert_eq!(
+            None,
+            load_max_age(&deps),
+            "the max age should be removed from the contract's state",
+        );
+    }
+
+    #[test]
+    fn test_set_fee_detail_max_age_by_non_admin_fails() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        let err = set_max_age(&mut deps, DEFAULT_SENDER_ADDRESS, MAX_AGE_BLOCKS.to_some())
+            .expect_err("a non-admin should not be able to set the fee detail max age");
+        assert!(
+            matches!(err, ContractError::Unauthorized { .. }),
+            "expected an unauthorized error, but got: {:?}",
+            err,
+        );
+        assert_eq!(None, load_max_age(&deps), "the max age should remain unset",);
+    }
+
+    #[test]
+    fn test_purge_stale_fee_detail_before_max_age_fails() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps, AssetOnboardingStatus::Denied);
+        let err = purge(&mut deps, &env_at_offset(MAX_AGE_BLOCKS))
+            .expect_err("purging a fee detail that has not exceeded the max age should fail");
+        match err {
+            ContractError::UnexpectedState { explanation } => {
+                assert_eq!(
+                    format!(
+                        "fee payment detail for asset [{}] with asset type [{}] has not been stored for more than [{}] blocks and cannot be purged",
+                        DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE, MAX_AGE_BLOCKS,
+                    ),
+                    explanation,
+                    "the error should describe the max age",
+                );
+            }
+            _ => panic!("unexpected error encountered: {:?}", err),
+        };
+        assert_fee_detail_exists(&deps, true);
+    }
+
+    #[test]
+    fn test_purge_stale_fee_detail_after_max_age_succeeds() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps, AssetOnboardingStatus::Denied);
+        let response = purge(&mut deps, &env_at_offset(MAX_AGE_BLOCKS + 1))
+            .expect("purging a fee detail that has exceeded the max age should succeed");
+        assert!(
+            response.messages.is_empty(),
+            "purging a stale fee detail should not move any funds",
+        );
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::PurgeStaleFeeDetail.event_name(),
+        );
+        assert_response_attributes_contain(&response, ASSET_TYPE_KEY, DEFAULT_ASSET_TYPE);
+        assert_response_attributes_contain(
+            &response,
+            ASSET_SCOPE_ADDRESS_KEY,
+            DEFAULT_SCOPE_ADDRESS,
+        );
+        assert_fee_detail_exists(&deps, false);
+    }
+
+    #[test]
+    fn test_purge_stale_fee_detail_for_approved_asset_fails() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps, AssetOnboardingStatus::Approved);
+        let err = purge(&mut deps, &env_at_offset(MAX_AGE_BLOCKS + 1))
+            .expect_err("purging the fee detail of an approved asset should fail");
+        match err {
+            ContractError::UnexpectedState { explanation } => {
+                assert_eq!(
+                    format!(
+                        "asset [{}] with asset type [{}] has onboarding status [approved], and only the fee payment details of denied assets can be purged",
+                        DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE,
+                    ),
+                    explanation,
+                    "the error should describe the asset's onboarding status",
+                );
+            }
+            _ => panic!("unexpected error encountered: {:?}", err),
+        };
+        assert_fee_detail_exists(&deps, true);
+    }
+
+    #[test]
+    fn test_purge_stale_fee_detail_for_pending_asset_fails() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps, AssetOnboardingStatus::Pending);
+        let err = purge(&mut deps, &env_at_offset(MAX_AGE_BLOCKS + 1))
+            .expect_err("purging the fee detail of a pending asset should fail");
+        assert!(
+            matches!(err, ContractError::UnexpectedState { .. }),
+            "expected an unexpected state error, but got: {:?}",
+            err,
+        );
+        assert_fee_detail_exists(&deps, true);
+    }
+
+    #[test]
+    fn test_purge_stale_fee_detail_without_max_age_fails() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps, AssetOnboardingStatus::Denied);
+        set_max_age(&mut deps, DEFAULT_ADMIN_ADDRESS, None)
+            .expect("the admin should be able to clear the fee detail max age");
+        let err = purge(&mut deps, &env_at_offset(u32::MAX as u64))
+            .expect_err("purging a fee detail without a configured max age should fail");
+        assert!(
+            matches!(err, ContractError::UnexpectedState { .. }),
+            "expected an unexpected state error, but got: {:?}",
+            err,
+        );
+        assert_fee_detail_exists(&deps, true);
+    }
+
+    #[test]
+    fn test_purge_stale_fee_detail_fails_for_provided_funds() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps, AssetOnboardingStatus::Denied);
+        let err = purge_stale_fee_detail(
+            &env_at_offset(MAX_AGE_BLOCKS + 1),
+            AssetMetaService::new(deps.as_mut()),
+            mock_info_with_nhash(UNRELATED_SENDER_ADDRESS, 150),
+            PurgeStaleFeeDetailV1::new(
+                AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
+                DEFAULT_ASSET_TYPE,
+            ),
+        )
+        .expect_err("purging a fee detail with funds should fail");
+        assert!(
+            matches!(err, ContractError::InvalidFunds(_)),
+            "expected an invalid funds error, but got: {:?}",
+            err,
+        );
+        assert_fee_detail_exists(&deps, true);
+    }
+
+    // Onboards the default asset, leaving its fee payment detail in storage, and then mocks the
+    // scope attribute with the provided status
+    fn setup_onboarded_asset(deps: &mut MockOwnedDeps, status: AssetOnboardingStatus) {
+        setup_test_suite(deps, &InstArgs::default());
+        setup_no_attribute_response(deps, None);
+        test_onboard_asset(deps, TestOnboardAsset::default())
+            .expect("expected the asset onboard to succeed");
+        set_max_age(deps, DEFAULT_ADMIN_ADDRESS, MAX_AGE_BLOCKS.to_some())
+            .expect("the admin should be able to set the fee detail max age");
+        mock_named_attribute_response_for_scope(
+            deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![build_scope_attribute(
+                DEFAULT_SCOPE_ADDRESS,
+                DEFAULT_ASSET_TYPE,
+                status,
+            )],
+        );
+    }
+
+    fn env_at_offset(blocks: u64) -> Env {
+        let mut env = mock_env();
+        env.block.height += blocks;
+        env
+    }
+
+    fn set_max_age(
+        deps: &mut MockOwnedDeps,
+        sender: &str,
+        max_age_blocks: Option<u64>,
+    ) -> EntryPointResponse {
+        execute_set_fee_detail_max_age(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(sender),
+            max_age_blocks,
+        )
+    }
+
+    fn purge(deps: &mut MockOwnedDeps, env: &Env) -> EntryPointResponse {
+        execute_purge_stale_fee_detail(
+            deps.as_mut(),
+            env.to_owned(),
+            empty_mock_info(UNRELATED_SENDER_ADDRESS),
+            AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+        )
+    }
+
+    fn load_max_age(deps: &MockOwnedDeps) -> Option<u64> {
+        STATE_V2
+            .load(&deps.storage)
+            .expect("the contract state should load")
+            .fee_detail_max_age_blocks
+    }
+
+    fn assert_fee_detail_exists(deps: &MockOwnedDeps, expected: bool) {
+        assert_eq!(
+            expected,
+            may_load_fee_payment_detail(&deps.storage, DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+                .is_some(),
+            "the fee payment detail should {}be stored",
+            if expected { "" } else { "no longer " },
+        );
+    }
+}
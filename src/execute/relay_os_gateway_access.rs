@@ -0,0 +1,194 @@
+use crate::core::error::ContractError;
+use crate::core::msg::ExecuteMsg;
+use crate::core::types::os_gateway_access::OsGatewayAccess;
+use crate::util::aliases::{AssetResult, EntryPointResponse};
+use crate::util::contract_helpers::check_funds_are_empty;
+use crate::util::event_attributes::{EventAttributes, EventType};
+
+use cosmwasm_std::{Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// A transformation of [ExecuteMsg::RelayOsGatewayAccess](crate::core::msg::ExecuteMsg::RelayOsGatewayAccess)
+/// for ease of use in the underlying [relay_os_gateway_access](self::relay_os_gateway_access) function.
+///
+/// # Parameters
+///
+/// * `access` The object store gateway access grant or revocation to emit.
+#[derive(Clone, PartialEq, Eq)]
+pub struct RelayOsGatewayAccessV1 {
+    pub access: OsGatewayAccess,
+}
+impl RelayOsGatewayAccessV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `access` The object store gateway access grant or revocation to emit.
+    pub fn new(access: OsGatewayAccess) -> Self {
+        RelayOsGatewayAccessV1 { access }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [RelayOsGatewayAccess](crate::core::msg::ExecuteMsg::RelayOsGatewayAccess)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<RelayOsGatewayAccessV1> {
+        match msg {
+            ExecuteMsg::RelayOsGatewayAccess { access } => {
+                RelayOsGatewayAccessV1::new(access).to_ok()
+            }
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::RelayOsGatewayAccess".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+impl TryFrom<ExecuteMsg> for RelayOsGatewayAccessV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        RelayOsGatewayAccessV1::from_execute_msg(msg)
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::RelayOsGatewayAccess](crate::core::msg::ExecuteMsg::RelayOsGatewayAccess)
+/// message is provided.  Emits a single object store gateway access grant or revocation in the
+/// attributes of the response, which the gateway reads from the route's `wasm` event.  Only the
+/// contract itself may invoke this route, as it relays the gateway changes that its other routes
+/// cannot fit into their own responses.
+///
+/// # Parameters
+///
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the relay os gateway access v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn relay_os_gateway_access(
+    env: &Env,
+    info: MessageInfo,
+    msg: RelayOsGatewayAccessV1,
+) -> EntryPointResponse {
+    if info.sender != env.contract.address {
+        return ContractError::Unauthorized {
+            explanation: "object store gateway access can only be relayed by the contract itself"
+                .to_string(),
+        }
+        .to_err();
+    }
+    check_funds_are_empty(&info)?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::RelayOsGatewayAccess)
+                .set_scope_address(&msg.access.scope_address),
+        )
+        .add_attributes(msg.access.to_attributes())
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use os_gateway_contract_attributes::{OS_GATEWAY_EVENT_TYPES, OS_GATEWAY_KEYS};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::types::os_gateway_access::OsGatewayAccess;
+    use crate::testutil::execute_utilities::execute_relay_os_gateway_access;
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_SCOPE_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, empty_mock_info, mock_info_with_nhash,
+        test_instantiate_success, InstArgs,
+    };
+    use crate::util::constants::{ASSET_EVENT_TYPE_KEY, ASSET_SCOPE_ADDRESS_KEY};
+    use crate::util::event_attributes::EventType;
+
+    #[test]
+    fn test_relay_os_gateway_access_emits_access_attributes() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let env = mock_env();
+        let response = execute_relay_os_gateway_access(
+            deps.as_mut(),
+            env.clone(),
+            empty_mock_info(env.contract.address.as_str()),
+            OsGatewayAccess::revoke(DEFAULT_SCOPE_ADDRESS, DEFAULT_VERIFIER_ADDRESS, "grant-id"),
+        )
+        .expect("the contract should be able to relay gateway access");
+        assert!(
+            response.messages.is_empty(),
+            "relaying gateway access should not produce messages",
+        );
+        assert_eq!(
+            6,
+            response.attributes.len(),
+            "relaying gateway access should produce the correct number of attributes",
+        );
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::RelayOsGatewayAccess.event_name(),
+        );
+        assert_response_attributes_contain(
+            &response,
+            ASSET_SCOPE_ADDRESS_KEY,
+            DEFAULT_SCOPE_ADDRESS,
+        );
+        assert_response_attributes_contain(
+            &response,
+            OS_GATEWAY_KEYS.event_type,
+            OS_GATEWAY_EVENT_TYPES.access_revoke,
+        );
+        assert_response_attributes_contain(
+            &response,
+            OS_GATEWAY_KEYS.target_account,
+            DEFAULT_VERIFIER_ADDRESS,
+        );
+        assert_response_attributes_contain(&response, OS_GATEWAY_KEYS.access_grant_id, "grant-id");
+    }
+
+    #[test]
+    fn test_relay_os_gateway_access_rejects_other_senders() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        for sender in [DEFAULT_ADMIN_ADDRESS, DEFAULT_VERIFIER_ADDRESS] {
+            let err = execute_relay_os_gateway_access(
+                deps.as_mut(),
+                mock_env(),
+                empty_mock_info(sender),
+                OsGatewayAccess::grant(DEFAULT_SCOPE_ADDRESS, sender, "grant-id"),
+            )
+            .expect_err("only the contract should be able to relay gateway access");
+            assert!(
+                matches!(err, ContractError::Unauthorized { .. }),
+                "unexpected error type when [{sender}] relays gateway access: {err:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_relay_os_gateway_access_rejects_funds() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let env = mock_env();
+        let err = execute_relay_os_gateway_access(
+            deps.as_mut(),
+            env.clone(),
+            mock_info_with_nhash(env.contract.address.as_str(), 100),
+            OsGatewayAccess::grant(DEFAULT_SCOPE_ADDRESS, DEFAULT_VERIFIER_ADDRESS, "grant-id"),
+        )
+        .expect_err("funds should not be accepted when relaying gateway access");
+        assert!(
+            matches!(err, ContractError::InvalidFunds(..)),
+            "unexpected error type when funds are provided: {err:?}",
+        );
+    }
+}
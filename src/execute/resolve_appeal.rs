@@ -0,0 +1,438 @@
+use crate::core::error::ContractError;
+use crate::core::msg::ExecuteMsg;
+use crate::core::state::{
+    delete_denial_timestamp, record_overturned_denial, update_verifier_status_index,
+};
+use crate::core::types::asset_identifier::AssetIdentifier;
+use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+use crate::core::types::asset_verification_result::AssetVerificationResult;
+use crate::service::asset_meta_repository::AssetMetaRepository;
+use crate::service::deps_manager::DepsManager;
+use crate::service::message_gathering_service::MessageGatheringService;
+use crate::util::aliases::{AssetResult, EntryPointResponse};
+use crate::util::contract_helpers::{check_admin_only, check_funds_are_empty};
+use crate::util::event_attributes::{EventAttributes, EventType};
+
+use cosmwasm_std::{Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// A transformation of [ExecuteMsg::ResolveAppeal](crate::core::msg::ExecuteMsg::ResolveAppeal)
+/// for ease of use in the underlying [resolve_appeal](self::resolve_appeal) function.
+///
+/// # Parameters
+///
+/// * `identifier` An instance of the asset identifier enum that helps the contract identify which
+/// scope was appealed.
+/// * `asset_type` The asset type of the appealed verification.
+/// * `approved` Whether or not the appeal overturns the verifier's denial.
+/// * `resolution_message` The admin's explanation of the resolution.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ResolveAppealV1 {
+    pub identifier: AssetIdentifier,
+    pub asset_type: String,
+    pub approved: bool,
+    pub resolution_message: String,
+}
+impl ResolveAppealV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `identifier` An instance of the asset identifier enum that helps the contract identify which
+    /// scope was appealed.
+    /// * `asset_type` The asset type of the appealed verification.
+    /// * `approved` Whether or not the appeal overturns the verifier's denial.
+    /// * `resolution_message` The admin's explanation of the resolution.
+    pub fn new<S1: Into<String>, S2: Into<String>>(
+        identifier: AssetIdentifier,
+        asset_type: S1,
+        approved: bool,
+        resolution_message: S2,
+    ) -> Self {
+        ResolveAppealV1 {
+            identifier,
+            asset_type: asset_type.into(),
+            approved,
+            resolution_message: resolution_message.into(),
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [ResolveAppeal](crate::core::msg::ExecuteMsg::ResolveAppeal) variant,
+    /// then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType) error
+    /// will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<ResolveAppealV1> {
+        match msg {
+            ExecuteMsg::ResolveAppeal {
+                identifier,
+                asset_type,
+                approved,
+                resolution_message,
+            } => Self::new(
+                identifier.to_asset_identifier()?,
+                asset_type,
+                approved,
+                resolution_message,
+            )
+            .to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::ResolveAppeal".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+impl TryFrom<ExecuteMsg> for ResolveAppealV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        ResolveAppealV1::from_execute_msg(msg)
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::ResolveAppeal](crate::core::msg::ExecuteMsg::ResolveAppeal)
+/// message is provided.  Moves an [Appealing](crate::core::types::asset_onboarding_status::AssetOnboardingStatus::Appealing)
+/// asset to [Approved](crate::core::types::asset_onboarding_status::AssetOnboardingStatus::Approved)
+/// when the appeal is upheld, or back to [Denied](crate::core::types::asset_onboarding_status::AssetOnboardingStatus::Denied)
+/// when it is rejected.  The resolution message replaces the asset's latest verification result.
+/// The verifier was paid when it denied the asset, so no funds are held for the appeal and no funds
+/// are moved by its resolution.
+///
+/// # Parameters
+///
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `repository` A helper collection of traits that allows complex lookups of scope values and
+/// emits messages to construct the process of resolution as a collection of messages to produce in
+/// the function's result.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the resolve appeal v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn resolve_appeal<'a, T>(
+    env: &Env,
+    repository: T,
+    info: MessageInfo,
+    msg: ResolveAppealV1,
+) -> EntryPointResponse
+where
+    T: AssetMetaRepository + MessageGatheringService + DepsManager<'a>,
+{
+    repository.use_deps(|deps| check_admin_only(&deps.as_ref(), &info))?;
+    check_funds_are_empty(&info)?;
+    let scope_address = msg.identifier.get_scope_address()?;
+    let scope_attribute = repository.get_asset_by_asset_type(&scope_address, &msg.asset_type)?;
+    if scope_attribute.onboarding_status != AssetOnboardingStatus::Appealing {
+        return ContractError::UnexpectedState {
+            explanation: format!(
+                "asset [{}] with asset type [{}] has status [{}], but only appealing assets can have their appeals resolved",
+                scope_address, msg.asset_type, scope_attribute.onboarding_status,
+            ),
+        }
+        .to_err();
+    }
+    let mut resolved_attribute = scope_attribute.clone();
+    resolved_attribute.onboarding_status = if msg.approved {
+        AssetOnboardingStatus::Approved
+    } else {
+        AssetOnboardingStatus::Denied
+    };
+    resolved_attribute.latest_verification_result = AssetVerificationResult {
+        message: msg.resolution_message.to_owned(),
+        success: msg.approved,
+    }
+    .into();
+    repository.update_attribute(env, &resolved_attribute)?;
+    repository.use_deps(|deps| {
+        update_verifier_status_index(deps.storage, Some(&scope_attribute), &resolved_attribute)
+    })?;
+    // An approved asset can never be retried, so the denial no longer needs to be tracked for
+    // cooldown purposes.  The verifier's denial was overturned, so statistics must reflect the
+    // approval instead
+    if msg.approved {
+        repository.use_deps(|deps| {
+            delete_denial_timestamp(deps.storage, &scope_address, &msg.asset_type)
+        });
+        repository.use_deps(|deps| {
+            record_overturned_denial(
+                deps.storage,
+                &msg.asset_type,
+                scope_attribute.verifier_address.as_str(),
+            )
+        })?;
+    }
+    Response::new()
+        .add_attributes(
+            EventAttributes::for_asset_event(
+                EventType::AppealResolved,
+                &msg.asset_type,
+                &scope_address,
+            )
+            .set_verifier(&scope_attribute.verifier_address)
+            .set_new_asset_onboarding_status(&resolved_attribute.onboarding_status)
+            .set_new_value(&msg.resolution_message),
+        )
+        .add_messages(repository.get_messages())
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::state::{load_verifier_stats, may_load_denial_timestamp, STATE_V2};
+    use crate::core::types::asset_identifier::AssetIdentifier;
+    use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+    use crate::core::types::asset_scope_attribute::AssetScopeAttribute;
+    use crate::core::types::asset_verification_result::AssetVerificationResult;
+    use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::service::asset_meta_repository::AssetMetaRepository;
+    use crate::service::asset_meta_service::AssetMetaService;
+    use crate::testutil::execute_utilities::{execute_appeal_verification, execute_resolve_appeal};
+    use crate::testutil::msg_utilities::test_no_money_moved_in_response;
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS, DEFAULT_SENDER_ADDRESS,
+        DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, empty_mock_info, get_default_verifier_detail,
+        intercept_add_or_update_attribute, setup_no_attribute_response, setup_test_suite, InstArgs,
+        MockOwnedDeps,
+    };
+    use crate::testutil::verify_asset_helpers::{test_verify_asset, TestVerifyAsset};
+    use crate::util::aliases::EntryPointResponse;
+    use crate::util::constants::{
+        ASSET_EVENT_TYPE_KEY, NEW_ASSET_ONBOARDING_STATUS_KEY, NEW_VALUE_KEY,
+    };
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    const RESOLUTION_MESSAGE: &str = "the appeal was reviewed by the admin";
+
+    #[test]
+    fn test_approved_appeal_approves_asset() {
+        let mut deps = mock_provenance_dependencies();
+        setup_appealing_asset(&mut deps);
+        let response = resolve(&mut deps, DEFAULT_ADMIN_ADDRESS, true)
+            .expect("the admin should be able to approve an appeal");
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::AppealResolved.event_name(),
+        );
+        assert_response_attributes_contain(
+            &response,
+            NEW_ASSET_ONBOARDING_STATUS_KEY,
+            AssetOnboardingStatus::Approved.to_string().as_str(),
+        );
+        assert_response_attributes_contain(&response, NEW_VALUE_KEY, RESOLUTION_MESSAGE);
+        test_no_money_moved_in_response(&response, "resolving an appeal should not move any funds");
+        intercept_add_or_update_attribute(
+            &mut deps,
+            response,
+            "the resolution should update the attribute",
+        )
+        .expect("the attribute update should be intercepted");
+        let attribute = get_default_attribute(&mut deps);
+        assert_eq!(
+            AssetOnboardingStatus::Approved,
+            attribute.onboarding_status,
+            "the asset should be approved after its appeal is upheld",
+        );
+        assert_eq!(
+            AssetVerificationResult {
+                message: RESOLUTION_MESSAGE.to_string(),
+                success: true,
+            }
+            .to_some(),
+            attribute.latest_verification_result,
+            "the resolution should replace the latest verification result",
+        );
+        assert!(
+            may_load_denial_timestamp(&deps.storage, DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+                .expect("loading the denial timestamp should succeed")
+                .is_none(),
+            "the denial timestamp should be removed once the asset is approved",
+        );
+        let stats =
+            load_verifier_stats(&deps.storage, DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS)
+                .expect("the verifier stats should load");
+        assert_eq!(
+            (1, 0, 0),
+            (
+                stats.total_approved,
+                stats.total_denied,
+                stats.total_pending
+            ),
+            "the overturned denial should be counted as an approval for the verifier",
+        );
+        let metrics = STATE_V2
+            .load(&deps.storage)
+            .expect("the contract state should load")
+            .metrics;
+        assert_eq!(
+            1, metrics.total_verified_ever,
+            "the asset should be counted as verified once its appeal is upheld",
+        );
+        assert_eq!(
+            1, metrics.total_verifications,
+            "resolving an appeal should not count as another verification",
+        );
+    }
+
+    #[test]
+    fn test_rejected_appeal_returns_asset_to_denied() {
+        let mut deps = mock_provenance_dependencies();
+        setup_appealing_asset(&mut deps);
+        let response = resolve(&mut deps, DEFAULT_ADMIN_ADDRESS, false)
+            .expect("the admin should be able to reject an appeal");
+        assert_response_attributes_contain(
+            &response,
+            NEW_ASSET_ONBOARDING_STATUS_KEY,
+            AssetOnboardingStatus::Denied.to_string().as_str(),
+        );
+        test_no_money_moved_in_response(&response, "resolving an appeal should not move any funds");
+        intercept_add_or_update_attribute(
+            &mut deps,
+            response,
+            "the resolution should update the attribute",
+        )
+        .expect("the attribute update should be intercepted");
+        let attribute = get_default_attribute(&mut deps);
+        assert_eq!(
+            AssetOnboardingStatus::Denied,
+            attribute.onboarding_status,
+            "the asset should be denied after its appeal is rejected",
+        );
+        assert_eq!(
+            AssetVerificationResult {
+                message: RESOLUTION_MESSAGE.to_string(),
+                success: false,
+            }
+            .to_some(),
+            attribute.latest_verification_result,
+            "the resolution should replace the latest verification result",
+        );
+        let stats =
+            load_verifier_stats(&deps.storage, DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS)
+                .expect("the verifier stats should load");
+        assert_eq!(
+            (0, 1, 0),
+            (
+                stats.total_approved,
+                stats.total_denied,
+                stats.total_pending
+            ),
+            "the verifier's denial should still be counted after the appeal is rejected",
+        );
+        let err = appeal(&mut deps).expect_err("a denial should only be appealed once");
+        assert!(
+            matches!(err, ContractError::UnexpectedState { .. }),
+            "expected an unexpected state error, but got: {:?}",
+            err,
+        );
+        // The asset is free to be onboarded again as a retry
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding should succeed after an appeal is rejected");
+    }
+
+    #[test]
+    fn test_resolve_appeal_by_non_admin_fails() {
+        for sender in [DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS] {
+            let mut deps = mock_provenance_dependencies();
+            setup_appealing_asset(&mut deps);
+            let err = resolve(&mut deps, sender, true)
+                .expect_err("only the admin should be able to resolve an appeal");
+            assert!(
+                matches!(err, ContractError::Unauthorized { .. }),
+                "expected an unauthorized error when [{}] resolves an appeal, but got: {:?}",
+                sender,
+                err,
+            );
+            assert_eq!(
+                AssetOnboardingStatus::Appealing,
+                get_default_attribute(&mut deps).onboarding_status,
+                "the asset should remain appealing",
+            );
+        }
+    }
+
+    #[test]
+    fn test_resolve_appeal_for_asset_without_appeal_fails() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding should succeed");
+        test_verify_asset(
+            &mut deps,
+            &mock_env(),
+            TestVerifyAsset::default_with_success(false),
+        )
+        .expect("denying the asset should succeed");
+        let err = resolve(&mut deps, DEFAULT_ADMIN_ADDRESS, true)
+            .expect_err("resolving an asset that was never appealed should fail");
+        assert!(
+            matches!(err, ContractError::UnexpectedState { .. }),
+            "expected an unexpected state error, but got: {:?}",
+            err,
+        );
+    }
+
+    fn setup_appealing_asset(deps: &mut MockOwnedDeps) {
+        setup_test_suite(
+            deps,
+            &InstArgs::with_single_verifier(VerifierDetailV2 {
+                appeal_window_blocks: 50.to_some(),
+                ..get_default_verifier_detail()
+            }),
+        );
+        setup_no_attribute_response(deps, None);
+        test_onboard_asset(deps, TestOnboardAsset::default()).expect("onboarding should succeed");
+        test_verify_asset(
+            deps,
+            &mock_env(),
+            TestVerifyAsset::default_with_success(false),
+        )
+        .expect("denying the asset should succeed");
+        let response = appeal(deps).expect("appealing the denial should succeed");
+        intercept_add_or_update_attribute(deps, response, "the appeal should update the attribute")
+            .expect("the attribute update should be intercepted");
+    }
+
+    fn appeal(deps: &mut MockOwnedDeps) -> EntryPointResponse {
+        execute_appeal_verification(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+            AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            "the verifier reviewed the wrong records",
+        )
+    }
+
+    fn resolve(deps: &mut MockOwnedDeps, sender: &str, approved: bool) -> EntryPointResponse {
+        execute_resolve_appeal(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(sender),
+            AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            approved,
+            RESOLUTION_MESSAGE,
+        )
+    }
+
+    fn get_default_attribute(deps: &mut MockOwnedDeps) -> AssetScopeAttribute {
+        AssetMetaService::new(deps.as_mut())
+            .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+            .expect("the scope attribute should exist")
+    }
+}
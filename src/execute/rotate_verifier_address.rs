@@ -0,0 +1,629 @@
+use crate::core::error::ContractError;
+use crate::core::msg::ExecuteMsg;
+use crate::core::state::{
+    decrement_verifier_pending, delete_fee_payment_detail, increment_verifier_pending,
+    insert_fee_payment_detail, list_pending_verifier_status_index_page,
+    load_asset_definition_by_type_v3, may_load_fee_payment_detail, replace_asset_definition_v3,
+    update_verifier_status_index,
+};
+use crate::core::types::os_gateway_access::OsGatewayAccess;
+use crate::service::asset_meta_repository::AssetMetaRepository;
+use crate::service::deps_manager::DepsManager;
+use crate::service::message_gathering_service::MessageGatheringService;
+use crate::util::aliases::{AssetResult, EntryPointResponse};
+use crate::util::contract_helpers::{
+    check_admin_only, check_funds_are_empty, get_os_gateway_access_revoke,
+};
+use crate::util::event_attributes::{EventAttributes, EventType};
+use crate::util::traits::OptionExtensions;
+
+use cosmwasm_std::{Addr, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// A transformation of [ExecuteMsg::RotateVerifierAddress](crate::core::msg::ExecuteMsg::RotateVerifierAddress)
+/// for ease of use in the underlying [rotate_verifier_address](self::rotate_verifier_address) function.
+///
+/// # Parameters
+///
+/// * `asset_type` The asset type of the [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
+/// that contains the target verifier.
+/// * `old_address` The bech32 address currently used by the verifier.
+/// * `new_address` The bech32 address that will replace the verifier's current address.
+#[derive(Clone, PartialEq, Eq)]
+pub struct RotateVerifierAddressV1 {
+    pub asset_type: String,
+    pub old_address: String,
+    pub new_address: String,
+}
+impl RotateVerifierAddressV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_type` The asset type of the [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
+    /// that contains the target verifier.
+    /// * `old_address` The bech32 address currently used by the verifier.
+    /// * `new_address` The bech32 address that will replace the verifier's current address.
+    pub fn new<S1: Into<String>, S2: Into<String>, S3: Into<String>>(
+        asset_type: S1,
+        old_address: S2,
+        new_address: S3,
+    ) -> Self {
+        RotateVerifierAddressV1 {
+            asset_type: asset_type.into(),
+            old_address: old_address.into(),
+            new_address: new_address.into(),
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [RotateVerifierAddress](crate::core::msg::ExecuteMsg::RotateVerifierAddress)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<RotateVerifierAddressV1> {
+        match msg {
+            ExecuteMsg::RotateVerifierAddress {
+                asset_type,
+                old_address,
+                new_address,
+            } => RotateVerifierAddressV1::new(asset_type, old_address, new_address).to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::RotateVerifierAddress".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+impl TryFrom<ExecuteMsg> for RotateVerifierAddressV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        RotateVerifierAddressV1::from_execute_msg(msg)
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::RotateVerifierAddress](crate::core::msg::ExecuteMsg::RotateVerifierAddress)
+/// message is provided.  Replaces the [address](crate::core::types::verifier_detail::VerifierDetailV2::address)
+/// of a verifier on an asset definition, and moves every pending [AssetScopeAttribute](crate::core::types::asset_scope_attribute::AssetScopeAttribute)
+/// of the asset type that awaits the old address over to the new address.  Any payment in the
+/// [FeePaymentDetail](crate::core::types::fee_payment_detail::FeePaymentDetail) of a migrated
+/// attribute that targets the old address is redirected to the new address.  When a migrated
+/// attribute was granted object store gateway access during onboarding, the old address's grant is
+/// revoked and the new address is granted access under the same grant id, each via its own
+/// [RelayOsGatewayAccess](crate::core::msg::ExecuteMsg::RelayOsGatewayAccess) execution.
+/// Attributes that have already been approved or denied are left untouched, as they record the
+/// verifier that performed the verification.
+///
+/// # Parameters
+///
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `repository` A helper collection of traits that allows complex lookups of scope values and
+/// emits messages to construct the process of rotation as a collection of messages to produce
+/// in the function's result.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the rotate verifier address v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn rotate_verifier_address<'a, T>(
+    env: &Env,
+    repository: T,
+    info: MessageInfo,
+    msg: RotateVerifierAddressV1,
+) -> EntryPointResponse
+where
+    T: AssetMetaRepository + MessageGatheringService + DepsManager<'a>,
+{
+    repository.use_deps(|deps| check_admin_only(&deps.as_ref(), &info))?;
+    check_funds_are_empty(&info)?;
+    let mut asset_definition = repository
+        .use_deps(|deps| load_asset_definition_by_type_v3(deps.storage, &msg.asset_type))?;
+    asset_definition.check_not_frozen()?;
+    if asset_definition
+        .get_verifier_detail_opt(&msg.new_address)
+        .is_some()
+    {
+        return ContractError::DuplicateVerifierProvided.to_err();
+    }
+    let verifier = match asset_definition
+        .verifiers
+        .iter_mut()
+        .find(|verifier| verifier.address == msg.old_address)
+    {
+        Some(verifier) => verifier,
+        None => {
+            return ContractError::NotFound {
+                explanation: format!(
+                    "verifier with address {} not found for asset definition for type {}",
+                    msg.old_address, msg.asset_type
+                ),
+            }
+            .to_err();
+        }
+    };
+    verifier.address = msg.new_address.to_owned();
+    repository.use_deps(|deps| replace_asset_definition_v3(deps.storage, &asset_definition))?;
+    let pending_assets = repository.use_deps(|deps| {
+        list_pending_verifier_status_index_page(
+            deps.storage,
+            &msg.old_address,
+            Some(&msg.asset_type),
+            None,
+            u32::MAX,
+        )
+    })?;
+    let mut access_relay_messages = vec![];
+    for (scope_address, asset_type) in &pending_assets {
+        let scope_attribute = repository.get_asset_by_asset_type(scope_address, asset_type)?;
+        let mut rotated_attribute = scope_attribute.clone();
+        rotated_attribute.verifier_address = Addr::unchecked(&msg.new_address);
+        // The old address loses its object store gateway grant, and the new address receives a
+        // grant under the same id so that verification revokes it as usual.  Each change is
+        // relayed in its own event, as the gateway only reads one change per event
+        if let Some(access_revoke) = repository
+            .use_deps(|deps| get_os_gateway_access_revoke(deps.storage, &scope_attribute))?
+        {
+            let access_grant = OsGatewayAccess::grant(
+                scope_address,
+                &msg.new_address,
+                &access_revoke.access_grant_id,
+            );
+            rotated_attribute.os_gateway_grant_id =
+                access_grant.access_grant_id.to_owned().to_some();
+            access_relay_messages.push(access_revoke.to_relay_msg(env)?);
+            access_relay_messages.push(access_grant.to_relay_msg(env)?);
+        }
+        repository.update_attribute(env, &rotated_attribute)?;
+        repository.use_deps(|deps| {
+            update_verifier_status_index(deps.storage, Some(&scope_attribute), &rotated_attribute)
+        })?;
+        // Only pending onboardings follow the verifier to its new address.  Historical approval
+        // and denial counts remain with the old address
+        repository.use_deps(|deps| {
+            decrement_verifier_pending(deps.storage, asset_type, &msg.old_address)?;
+            increment_verifier_pending(deps.storage, asset_type, &msg.new_address)
+        })?;
+        repository.use_deps(|deps| {
+            match may_load_fee_payment_detail(deps.storage, scope_address, asset_type) {
+                Some(mut fee_payment_detail) => {
+                    for payment in fee_payment_detail.payments.iter_mut() {
+                        if payment.recipient.as_str() == msg.old_address {
+                            payment.recipient = Addr::unchecked(&msg.new_address);
+                        }
+                    }
+                    delete_fee_payment_detail(deps.storage, scope_address, asset_type)?;
+                    insert_fee_payment_detail(deps.storage, &fee_payment_detail, asset_type)
+                }
+                None => ().to_ok(),
+            }
+        })?;
+    }
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::VerifierRotated)
+                .set_asset_type(&msg.asset_type)
+                .set_verifier(&msg.old_address)
+                .set_new_value(&msg.new_address),
+        )
+        .add_messages(repository.get_messages())
+        .add_messages(access_relay_messages)
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{message_info, mock_env};
+    use cosmwasm_std::Addr;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::state::{
+        count_assets_by_verifier_and_status, load_asset_definition_by_type_v3,
+        load_fee_payment_detail, load_verifier_stats,
+    };
+    use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+    use crate::core::types::asset_scope_attribute::AssetScopeAttribute;
+    use crate::core::types::os_gateway_access::OsGatewayAccess;
+    use crate::execute::onboard_asset::OnboardAssetV1;
+    use crate::service::asset_meta_repository::AssetMetaRepository;
+    use crate::service::asset_meta_service::AssetMetaService;
+    use crate::testutil::execute_utilities::{
+        execute_add_asset_verifier, execute_freeze_asset_definition,
+        execute_rotate_verifier_address,
+    };
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_FEE_ADDRESS, DEFAULT_SCOPE_ADDRESS,
+        DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, empty_mock_info, get_default_verifier_detail,
+        get_relayed_os_gateway_access, intercept_add_or_update_attribute, mock_info_with_nhash,
+        setup_no_attribute_response, setup_test_suite, InstArgs, MockOwnedDeps,
+    };
+    use crate::testutil::verify_asset_helpers::{test_verify_asset, TestVerifyAsset};
+    use crate::util::aliases::EntryPointResponse;
+    use crate::util::constants::{
+        ASSET_EVENT_TYPE_KEY, ASSET_TYPE_KEY, NEW_VALUE_KEY, VERIFIER_ADDRESS_KEY,
+    };
+    use crate::util::event_attributes::EventType;
+
+    use super::{rotate_verifier_address, RotateVerifierAddressV1};
+
+    const NEW_VERIFIER_ADDRESS: &str = "tp1hsqtppgy7mgd64q0uvk7q39qa7h8hp30urqs6n";
+
+    #[test]
+    fn test_rotate_verifier_address_migrates_pending_onboarding() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps);
+        let response = rotate(&mut deps, DEFAULT_ADMIN_ADDRESS)
+            .expect("the admin should be able to rotate a verifier's address");
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::VerifierRotated.event_name(),
+        );
+        assert_response_attributes_contain(&response, ASSET_TYPE_KEY, DEFAULT_ASSET_TYPE);
+        assert_response_attributes_contain(
+            &response,
+            VERIFIER_ADDRESS_KEY,
+            DEFAULT_VERIFIER_ADDRESS,
+        );
+        assert_response_attributes_contain(&response, NEW_VALUE_KEY, NEW_VERIFIER_ADDRESS);
+        let asset_definition = load_asset_definition_by_type_v3(&deps.storage, DEFAULT_ASSET_TYPE)
+            .expect("the asset definition should still exist");
+        assert!(
+            asset_definition
+                .get_verifier_detail_opt(DEFAULT_VERIFIER_ADDRESS)
+                .is_none(),
+            "the old verifier address should no longer be present on the asset definition",
+        );
+        assert_eq!(
+            get_default_verifier_detail().onboarding_cost,
+            asset_definition
+                .get_verifier_detail_opt(NEW_VERIFIER_ADDRESS)
+                .expect("the new verifier address should be present on the asset definition")
+                .onboarding_cost,
+            "the remaining verifier values should be unchanged by the rotation",
+        );
+        intercept_add_or_update_attribute(
+            &mut deps,
+            response,
+            "rotation should update the pending attribute",
+        )
+        .expect("the attribute update should be intercepted");
+        let attribute = get_default_attribute(&mut deps);
+        assert_eq!(
+            NEW_VERIFIER_ADDRESS,
+            attribute.verifier_address.as_str(),
+            "the pending attribute should be moved to the new verifier address",
+        );
+        assert_eq!(
+            AssetOnboardingStatus::Pending,
+            attribute.onboarding_status,
+            "the migrated attribute should remain pending",
+        );
+        assert_eq!(
+            0,
+            count_pending(&deps, DEFAULT_VERIFIER_ADDRESS),
+            "no pending assets should remain indexed for the old verifier address",
+        );
+        assert_eq!(
+            1,
+            count_pending(&deps, NEW_VERIFIER_ADDRESS),
+            "the pending asset should be indexed for the new verifier address",
+        );
+        assert_eq!(
+            0,
+            load_verifier_stats(&deps.storage, DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS)
+                .expect("the old verifier's statistics should load")
+                .total_pending,
+            "the pending count should be removed from the old verifier address's statistics",
+        );
+        assert_eq!(
+            1,
+            load_verifier_stats(&deps.storage, DEFAULT_ASSET_TYPE, NEW_VERIFIER_ADDRESS)
+                .expect("the new verifier's statistics should load")
+                .total_pending,
+            "the pending count should be moved to the new verifier address's statistics",
+        );
+        assert_eq!(
+            vec![Addr::unchecked(NEW_VERIFIER_ADDRESS)],
+            load_fee_payment_detail(&deps.storage, DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+                .expect("the fee payment detail should remain stored")
+                .payments
+                .into_iter()
+                .map(|payment| payment.recipient)
+                .collect::<Vec<Addr>>(),
+            "the verifier fee should be redirected to the new verifier address",
+        );
+        test_verify_asset(
+            &mut deps,
+            &mock_env(),
+            TestVerifyAsset {
+                info: message_info(&Addr::unchecked(NEW_VERIFIER_ADDRESS), &[]),
+                ..TestVerifyAsset::default()
+            },
+        )
+        .expect("the new verifier address should be able to verify the migrated asset");
+    }
+
+    #[test]
+    fn test_rotate_verifier_address_relays_gateway_access_to_new_address() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps);
+        let grant_id = get_default_attribute(&mut deps)
+            .os_gateway_grant_id
+            .expect("onboarding should record the gateway grant id");
+        let response = rotate(&mut deps, DEFAULT_ADMIN_ADDRESS)
+            .expect("the admin should be able to rotate a verifier's address");
+        assert!(
+            response
+                .attributes
+                .iter()
+                .all(|attribute| !OsGatewayAccess::is_gateway_key(&attribute.key)),
+            "gateway access should not be changed in the rotation's own attributes",
+        );
+        assert_eq!(
+            vec![
+                OsGatewayAccess::revoke(DEFAULT_SCOPE_ADDRESS, DEFAULT_VERIFIER_ADDRESS, &grant_id),
+                OsGatewayAccess::grant(DEFAULT_SCOPE_ADDRESS, NEW_VERIFIER_ADDRESS, &grant_id),
+            ],
+            get_relayed_os_gateway_access(&response),
+            "the old address's grant should be revoked and the new address should be granted access",
+        );
+        intercept_add_or_update_attribute(
+            &mut deps,
+            response,
+            "rotation should update the pending attribute",
+        )
+        .expect("the attribute update should be intercepted");
+        let verify_response = test_verify_asset(
+            &mut deps,
+            &mock_env(),
+            TestVerifyAsset {
+                info: message_info(&Addr::unchecked(NEW_VERIFIER_ADDRESS), &[]),
+                ..TestVerifyAsset::default()
+            },
+        )
+        .expect("the new verifier address should be able to verify the migrated asset");
+        assert_eq!(
+            Some(OsGatewayAccess::revoke(
+                DEFAULT_SCOPE_ADDRESS,
+                NEW_VERIFIER_ADDRESS,
+                &grant_id,
+            )),
+            OsGatewayAccess::from_attributes(&verify_response.attributes),
+            "verification should revoke the grant relayed to the new verifier address",
+        );
+    }
+
+    #[test]
+    fn test_rotate_verifier_address_relays_no_access_without_gateway_permission() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(
+            &mut deps,
+            TestOnboardAsset {
+                onboard_asset: OnboardAssetV1 {
+                    add_os_gateway_permission: false,
+                    ..TestOnboardAsset::default_onboard_asset()
+                },
+                ..TestOnboardAsset::default()
+            },
+        )
+        .expect("onboarding should succeed");
+        let response = rotate(&mut deps, DEFAULT_ADMIN_ADDRESS)
+            .expect("the admin should be able to rotate a verifier's address");
+        assert!(
+            get_relayed_os_gateway_access(&response).is_empty(),
+            "no gateway access should be relayed when none was granted during onboarding",
+        );
+    }
+
+    #[test]
+    fn test_rotate_verifier_address_does_not_modify_verified_attributes() {
+        for success in [true, false] {
+            let mut deps = mock_provenance_dependencies();
+            setup_onboarded_asset(&mut deps);
+            test_verify_asset(
+                &mut deps,
+                &mock_env(),
+                TestVerifyAsset::default_with_success(success),
+            )
+            .expect("verification should succeed");
+            let response = rotate(&mut deps, DEFAULT_ADMIN_ADDRESS)
+                .expect("the admin should be able to rotate a verifier's address");
+            assert!(
+                response.messages.is_empty(),
+                "no attribute updates should be emitted when no onboardings are pending (success = {}), but got: {:?}",
+                success,
+                response.messages,
+            );
+            assert_eq!(
+                DEFAULT_VERIFIER_ADDRESS,
+                get_default_attribute(&mut deps).verifier_address.as_str(),
+                "a verified attribute should retain the verifier that verified it (success = {})",
+                success,
+            );
+            assert!(
+                load_asset_definition_by_type_v3(&deps.storage, DEFAULT_ASSET_TYPE)
+                    .expect("the asset definition should still exist")
+                    .get_verifier_detail_opt(NEW_VERIFIER_ADDRESS)
+                    .is_some(),
+                "the asset definition should still be updated (success = {})",
+                success,
+            );
+        }
+    }
+
+    #[test]
+    fn test_rotate_verifier_address_by_non_admin_fails() {
+        for sender in [DEFAULT_VERIFIER_ADDRESS, DEFAULT_SENDER_ADDRESS] {
+            let mut deps = mock_provenance_dependencies();
+            setup_onboarded_asset(&mut deps);
+            let err = rotate(&mut deps, sender)
+                .expect_err("only the admin should be able to rotate a verifier's address");
+            assert!(
+                matches!(err, ContractError::Unauthorized { .. }),
+                "expected an unauthorized error when [{}] rotates a verifier, but got: {:?}",
+                sender,
+                err,
+            );
+            assert_verifier_not_rotated(&mut deps);
+        }
+    }
+
+    #[test]
+    fn test_rotate_verifier_address_fails_for_unknown_verifier() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps);
+        let err = execute_rotate_verifier_address(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            DEFAULT_FEE_ADDRESS,
+            NEW_VERIFIER_ADDRESS,
+        )
+        .expect_err("rotating a verifier that does not exist should fail");
+        assert!(
+            matches!(err, ContractError::NotFound { .. }),
+            "expected a not found error for an unknown verifier, but got: {:?}",
+            err,
+        );
+        assert_verifier_not_rotated(&mut deps);
+    }
+
+    #[test]
+    fn test_rotate_verifier_address_fails_for_existing_new_address() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps);
+        let mut other_verifier = get_default_verifier_detail();
+        other_verifier.address = NEW_VERIFIER_ADDRESS.to_string();
+        execute_add_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            other_verifier,
+        )
+        .expect("adding a second verifier should succeed");
+        let err = rotate(&mut deps, DEFAULT_ADMIN_ADDRESS)
+            .expect_err("rotating to an address used by another verifier should fail");
+        assert!(
+            matches!(err, ContractError::DuplicateVerifierProvided),
+            "expected a duplicate verifier error, but got: {:?}",
+            err,
+        );
+        assert_verifier_not_rotated(&mut deps);
+    }
+
+    #[test]
+    fn test_rotate_verifier_address_fails_for_provided_funds() {
+        let mut deps = mock_provenance_dependencies();
+        setup_onboarded_asset(&mut deps);
+        let err = rotate_verifier_address(
+            &mock_env(),
+            AssetMetaService::new(deps.as_mut()),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            RotateVerifierAddressV1::new(
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS,
+                NEW_VERIFIER_ADDRESS,
+            ),
+        )
+        .expect_err("rotating a verifier's address with funds should fail");
+        assert!(
+            matches!(err, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when the sender provides funds, but got: {:?}",
+            err,
+        );
+        assert_verifier_not_rotated(&mut deps);
+    }
+
+    #[test]
+    fn test_rotate_verifier_address_fails_for_frozen_definition() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        execute_freeze_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+        )
+        .expect("expected the asset definition to be frozen");
+        let error = execute_rotate_verifier_address(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            DEFAULT_VERIFIER_ADDRESS,
+            NEW_VERIFIER_ADDRESS,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::AssetDefinitionFrozen { ref asset_type } if asset_type == DEFAULT_ASSET_TYPE),
+            "expected the frozen error to be returned when rotating a verifier on a frozen definition, but got: {:?}",
+            error,
+        );
+    }
+
+    fn setup_onboarded_asset(deps: &mut MockOwnedDeps) {
+        setup_test_suite(deps, &InstArgs::default());
+        setup_no_attribute_response(deps, None);
+        test_onboard_asset(deps, TestOnboardAsset::default()).expect("onboarding should succeed");
+    }
+
+    fn rotate(deps: &mut MockOwnedDeps, sender: &str) -> EntryPointResponse {
+        execute_rotate_verifier_address(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(sender),
+            DEFAULT_ASSET_TYPE,
+            DEFAULT_VERIFIER_ADDRESS,
+            NEW_VERIFIER_ADDRESS,
+        )
+    }
+
+    fn get_default_attribute(deps: &mut MockOwnedDeps) -> AssetScopeAttribute {
+        AssetMetaService::new(deps.as_mut())
+            .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+            .expect("the scope attribute should exist")
+    }
+
+    fn count_pending(deps: &MockOwnedDeps, verifier_address: &str) -> u64 {
+        count_assets_by_verifier_and_status(
+            &deps.storage,
+            verifier_address,
+            &AssetOnboardingStatus::Pending,
+            DEFAULT_ASSET_TYPE.into(),
+        )
+        .expect("the pending assets should be counted")
+    }
+
+    fn assert_verifier_not_rotated(deps: &mut MockOwnedDeps) {
+        assert!(
+            load_asset_definition_by_type_v3(&deps.storage, DEFAULT_ASSET_TYPE)
+                .expect("the asset definition should still exist")
+                .get_verifier_detail_opt(DEFAULT_VERIFIER_ADDRESS)
+                .is_some(),
+            "the verifier should retain its original address",
+        );
+        assert_eq!(
+            DEFAULT_VERIFIER_ADDRESS,
+            get_default_attribute(deps).verifier_address.as_str(),
+            "the pending attribute should retain the original verifier address",
+        );
+        assert_eq!(
+            1,
+            count_pending(deps, DEFAULT_VERIFIER_ADDRESS),
+            "the pending asset should remain indexed for the original verifier address",
+        );
+    }
+}
@@ -68,6 +68,13 @@ impl ToggleAssetDefinitionV1 {
         }
     }
 }
+impl TryFrom<ExecuteMsg> for ToggleAssetDefinitionV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        ToggleAssetDefinitionV1::from_execute_msg(msg)
+    }
+}
 
 /// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::ToggleAssetDefinition](crate::core::msg::ExecuteMsg::ToggleAssetDefinition)
 /// message is provided.  Attempts to swap the [enabled](crate::core::types::asset_definition::AssetDefinitionV3::enabled)
@@ -89,6 +96,7 @@ pub fn toggle_asset_definition(
     check_admin_only(&deps.as_ref(), &info)?;
     check_funds_are_empty(&info)?;
     let mut asset_definition = load_asset_definition_by_type_v3(deps.storage, &msg.asset_type)?;
+    asset_definition.check_not_frozen()?;
     // Never toggle the state if the caller didn't expect the target result
     // If current state == expected result, then the requestor wants to change TO the current state. So this is a no-op.
     if asset_definition.enabled == msg.expected_result {
@@ -120,12 +128,12 @@ mod tests {
 
     use crate::core::state::load_asset_definition_by_type_v3;
     use crate::{
-        contract::execute,
-        core::{error::ContractError, msg::ExecuteMsg},
+        core::error::ContractError,
         testutil::{
+            execute_utilities::{execute_freeze_asset_definition, execute_toggle_asset_definition},
             test_constants::{DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE},
             test_utilities::{
-                empty_mock_info, mock_info_with_nhash, single_attribute_for_key,
+                assert_response_attributes_contain, empty_mock_info, mock_info_with_nhash,
                 test_instantiate_success, InstArgs,
             },
         },
@@ -141,14 +149,12 @@ mod tests {
     fn test_valid_toggle_asset_definition_via_execute() {
         let mut deps = mock_provenance_dependencies();
         test_instantiate_success(deps.as_mut(), &InstArgs::default());
-        let response = execute(
+        let response = execute_toggle_asset_definition(
             deps.as_mut(),
             mock_env(),
             empty_mock_info(DEFAULT_ADMIN_ADDRESS),
-            ExecuteMsg::ToggleAssetDefinition {
-                asset_type: DEFAULT_ASSET_TYPE.to_string(),
-                expected_result: false,
-            },
+            DEFAULT_ASSET_TYPE,
+            false,
         )
         .expect("the toggle should work correctly");
         assert!(
@@ -160,21 +166,13 @@ mod tests {
             response.attributes.len(),
             "toggling an asset definition should produce the correct number of attributes",
         );
-        assert_eq!(
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
             EventType::ToggleAssetDefinition.event_name().as_str(),
-            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
-            "the proper event type should be emitted",
-        );
-        assert_eq!(
-            DEFAULT_ASSET_TYPE,
-            single_attribute_for_key(&response, ASSET_TYPE_KEY),
-            "the proper asset type should be emitted",
-        );
-        assert_eq!(
-            "false",
-            single_attribute_for_key(&response, NEW_VALUE_KEY),
-            "the new value key should indicate that the asset definition has been set to enabled = false",
         );
+        assert_response_attributes_contain(&response, ASSET_TYPE_KEY, DEFAULT_ASSET_TYPE);
+        assert_response_attributes_contain(&response, NEW_VALUE_KEY, "false");
         test_toggle_has_successfully_occurred(&deps.as_ref(), false);
     }
 
@@ -202,18 +200,16 @@ mod tests {
     fn test_invalid_toggle_asset_definition_for_invalid_msg() {
         let mut deps = mock_provenance_dependencies();
         test_instantiate_success(deps.as_mut(), &InstArgs::default());
-        let error = execute(
+        let error = execute_toggle_asset_definition(
             deps.as_mut(),
             mock_env(),
             empty_mock_info(DEFAULT_ADMIN_ADDRESS),
-            ExecuteMsg::ToggleAssetDefinition {
-                asset_type: String::new(),
-                expected_result: false,
-            },
+            String::new(),
+            false,
         )
         .unwrap_err();
         assert!(
-            matches!(error, ContractError::InvalidMessageFields { .. }),
+            error.is_invalid_message_fields(),
             "expected the invalid message fields error to be returned when the message is malformatted, but got: {:?}",
             error,
         );
@@ -230,7 +226,7 @@ mod tests {
         )
         .unwrap_err();
         assert!(
-            matches!(error, ContractError::Unauthorized { .. }),
+            error.is_unauthorized(),
             "expected the unauthorized error to be returned when the sender is not the admin, but got: {:?}",
             error,
         );
@@ -317,6 +313,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_invalid_toggle_asset_definition_for_frozen_definition() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        execute_freeze_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+        )
+        .expect("expected the asset definition to be frozen");
+        let error = execute_toggle_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            false,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::AssetDefinitionFrozen { ref asset_type } if asset_type == DEFAULT_ASSET_TYPE),
+            "expected the frozen error to be returned when toggling a frozen definition, but got: {:?}",
+            error,
+        );
+    }
+
     fn test_toggle_has_successfully_occurred(deps: &Deps, expected_enabled_value: bool) {
         let asset_def = load_asset_definition_by_type_v3(deps.storage, DEFAULT_ASSET_TYPE)
             .expect("the default asset definition should exist in storage");
@@ -0,0 +1,417 @@
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+use crate::core::state::{load_asset_definition_by_type_v3, replace_asset_definition_v3};
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, EntryPointResponse},
+        contract_helpers::{check_admin_only, check_funds_are_empty},
+        event_attributes::{EventAttributes, EventType},
+    },
+};
+
+/// A transformation of [ExecuteMsg::ToggleAssetVerifier](crate::core::msg::ExecuteMsg::ToggleAssetVerifier)
+/// for ease of use in the underlying [toggle_asset_verifier](self::toggle_asset_verifier) function.
+///
+/// # Parameters
+///
+/// * `asset_type` The unique identifier for the [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
+/// that contains the target verifier.
+/// * `verifier_address` The bech32 address of the target [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2).
+/// * `expected_result` The value of [enabled](crate::core::types::verifier_detail::VerifierDetailV2::enabled)
+/// after the toggle takes place.  This value is required to ensure that multiple toggles executed
+/// in succession will only be honored if the verifier is in the intended state during the execution
+/// of the route.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ToggleAssetVerifierV1 {
+    pub asset_type: String,
+    pub verifier_address: String,
+    pub expected_result: bool,
+}
+impl ToggleAssetVerifierV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_type` The unique identifier for the [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
+    /// that contains the target verifier.
+    /// * `verifier_address` The bech32 address of the target [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2).
+    /// * `expected_result` The value of [enabled](crate::core::types::verifier_detail::VerifierDetailV2::enabled)
+    /// after the toggle takes place.
+    pub fn new<S1: Into<String>, S2: Into<String>>(
+        asset_type: S1,
+        verifier_address: S2,
+        expected_result: bool,
+    ) -> Self {
+        ToggleAssetVerifierV1 {
+            asset_type: asset_type.into(),
+            verifier_address: verifier_address.into(),
+            expected_result,
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [ToggleAssetVerifier](crate::core::msg::ExecuteMsg::ToggleAssetVerifier)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<ToggleAssetVerifierV1> {
+        match msg {
+            ExecuteMsg::ToggleAssetVerifier {
+                asset_type,
+                verifier_address,
+                expected_result,
+            } => ToggleAssetVerifierV1::new(asset_type, verifier_address, expected_result).to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::ToggleAssetVerifier".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+impl TryFrom<ExecuteMsg> for ToggleAssetVerifierV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        ToggleAssetVerifierV1::from_execute_msg(msg)
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::ToggleAssetVerifier](crate::core::msg::ExecuteMsg::ToggleAssetVerifier)
+/// message is provided.  Attempts to swap the [enabled](crate::core::types::verifier_detail::VerifierDetailV2::enabled)
+/// property of a single verifier from true to false, or false to true, without affecting the other
+/// verifiers on the same asset definition.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the toggle asset verifier v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn toggle_asset_verifier(
+    deps: DepsMut,
+    info: MessageInfo,
+    msg: ToggleAssetVerifierV1,
+) -> EntryPointResponse {
+    check_admin_only(&deps.as_ref(), &info)?;
+    check_funds_are_empty(&info)?;
+    let mut asset_definition = load_asset_definition_by_type_v3(deps.storage, &msg.asset_type)?;
+    asset_definition.check_not_frozen()?;
+    let verifier = match asset_definition
+        .verifiers
+        .iter_mut()
+        .find(|verifier| verifier.address == msg.verifier_address)
+    {
+        Some(verifier) => verifier,
+        None => {
+            return ContractError::NotFound {
+                explanation: format!(
+                    "verifier with address {} not found for asset definition for type {}",
+                    msg.verifier_address, msg.asset_type
+                ),
+            }
+            .to_err();
+        }
+    };
+    // Never toggle the state if the caller didn't expect the target result
+    if verifier.enabled == msg.expected_result {
+        return ContractError::UnexpectedState {
+            explanation: format!(
+                "expected to toggle to [enabled = {}], but toggle would set value to [enabled = {}]",
+                msg.expected_result, !verifier.enabled
+            ),
+        }
+        .to_err();
+    }
+    verifier.enabled = !verifier.enabled;
+    let enabled = verifier.enabled;
+    replace_asset_definition_v3(deps.storage, &asset_definition)?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::ToggleAssetVerifier)
+                .set_asset_type(&msg.asset_type)
+                .set_verifier(&msg.verifier_address)
+                .set_new_value(enabled),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{message_info, mock_env};
+    use cosmwasm_std::{Addr, Deps, DepsMut};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::state::load_asset_definition_by_type_v3;
+    use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::execute::onboard_asset::OnboardAssetV1;
+    use crate::testutil::execute_utilities::{
+        execute_freeze_asset_definition, execute_toggle_asset_verifier,
+    };
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, empty_mock_info, get_default_verifier_detail,
+        mock_info_with_nhash, setup_no_attribute_response, setup_test_suite,
+        test_instantiate_success, InstArgs,
+    };
+    use crate::util::constants::{
+        ASSET_EVENT_TYPE_KEY, ASSET_TYPE_KEY, NEW_VALUE_KEY, VERIFIER_ADDRESS_KEY,
+    };
+    use crate::util::event_attributes::EventType;
+
+    use super::{toggle_asset_verifier, ToggleAssetVerifierV1};
+
+    const SECOND_VERIFIER_ADDRESS: &str = "tp1hsqtppgy7mgd64q0uvk7q39qa7h8hp30urqs6n";
+
+    #[test]
+    fn test_valid_toggle_asset_verifier_via_execute() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let response = execute_toggle_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            DEFAULT_VERIFIER_ADDRESS,
+            false,
+        )
+        .expect("the toggle should work correctly");
+        assert!(
+            response.messages.is_empty(),
+            "toggling an asset verifier should not require messages",
+        );
+        assert_eq!(
+            4,
+            response.attributes.len(),
+            "toggling an asset verifier should produce the correct number of attributes",
+        );
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::ToggleAssetVerifier.event_name(),
+        );
+        assert_response_attributes_contain(&response, ASSET_TYPE_KEY, DEFAULT_ASSET_TYPE);
+        assert_response_attributes_contain(
+            &response,
+            VERIFIER_ADDRESS_KEY,
+            DEFAULT_VERIFIER_ADDRESS,
+        );
+        assert_response_attributes_contain(&response, NEW_VALUE_KEY, "false");
+        assert_verifier_enabled(&deps.as_ref(), DEFAULT_VERIFIER_ADDRESS, false);
+    }
+
+    #[test]
+    fn test_toggle_asset_verifier_off_and_back_on() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        toggle_default_verifier(deps.as_mut(), false);
+        assert_verifier_enabled(&deps.as_ref(), DEFAULT_VERIFIER_ADDRESS, false);
+        toggle_default_verifier(deps.as_mut(), true);
+        assert_verifier_enabled(&deps.as_ref(), DEFAULT_VERIFIER_ADDRESS, true);
+    }
+
+    #[test]
+    fn test_disabled_verifier_blocks_onboarding_without_affecting_other_verifiers() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(
+            &mut deps,
+            &InstArgs::add_verifier_to_default(VerifierDetailV2 {
+                address: SECOND_VERIFIER_ADDRESS.to_string(),
+                ..get_default_verifier_detail()
+            }),
+        );
+        setup_no_attribute_response(&mut deps, None);
+        toggle_default_verifier(deps.as_mut(), false);
+        let err = test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect_err("expected onboarding with a disabled verifier to fail");
+        match err {
+            ContractError::VerifierDisabled {
+                asset_type,
+                verifier_address,
+            } => {
+                assert_eq!(
+                    DEFAULT_ASSET_TYPE, asset_type,
+                    "the error should include the asset type",
+                );
+                assert_eq!(
+                    DEFAULT_VERIFIER_ADDRESS, verifier_address,
+                    "the error should include the disabled verifier's address",
+                );
+            }
+            _ => panic!("unexpected error encountered: {:?}", err),
+        };
+        assert_verifier_enabled(&deps.as_ref(), SECOND_VERIFIER_ADDRESS, true);
+        test_onboard_asset(
+            &mut deps,
+            TestOnboardAsset {
+                onboard_asset: OnboardAssetV1 {
+                    verifier_address: SECOND_VERIFIER_ADDRESS.to_string(),
+                    ..TestOnboardAsset::default_onboard_asset()
+                },
+                ..TestOnboardAsset::default()
+            },
+        )
+        .expect("expected onboarding with an enabled verifier on the same asset type to succeed");
+    }
+
+    #[test]
+    fn test_invalid_toggle_asset_verifier_for_invalid_msg() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = execute_toggle_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            String::new(),
+            false,
+        )
+        .unwrap_err();
+        assert!(
+            error.is_invalid_message_fields(),
+            "expected the invalid message fields error to be returned when the message is malformatted, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_toggle_asset_verifier_for_invalid_sender() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        // Verifiers must not be able to re-enable themselves
+        let error = toggle_asset_verifier(
+            deps.as_mut(),
+            message_info(&Addr::unchecked(DEFAULT_VERIFIER_ADDRESS), &[]),
+            ToggleAssetVerifierV1::new(DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS, false),
+        )
+        .unwrap_err();
+        assert!(
+            error.is_unauthorized(),
+            "expected the unauthorized error to be returned when the sender is not the admin, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_toggle_asset_verifier_for_provided_funds() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = toggle_asset_verifier(
+            deps.as_mut(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            ToggleAssetVerifierV1::new(DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS, false),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when the sender provides funds, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_toggle_asset_verifier_for_missing_verifier() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = toggle_asset_verifier(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ToggleAssetVerifierV1::new(DEFAULT_ASSET_TYPE, SECOND_VERIFIER_ADDRESS, false),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotFound { .. }),
+            "expected the not found error to be returned, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_toggle_asset_verifier_to_incorrect_expected_state_fails() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = toggle_asset_verifier(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ToggleAssetVerifierV1::new(DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS, true),
+        )
+        .unwrap_err();
+        match error {
+            ContractError::UnexpectedState { explanation } => {
+                assert_eq!(
+                    "expected to toggle to [enabled = true], but toggle would set value to [enabled = false]",
+                    explanation.as_str(),
+                    "incorrect error message encountered on invalid toggle",
+                );
+            }
+            _ => panic!(
+                "unexpected error encountered on invalid toggle: {:?}",
+                error
+            ),
+        };
+        assert_verifier_enabled(&deps.as_ref(), DEFAULT_VERIFIER_ADDRESS, true);
+    }
+
+    #[test]
+    fn test_invalid_toggle_asset_verifier_for_frozen_definition() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        execute_freeze_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+        )
+        .expect("expected the asset definition to be frozen");
+        let error = execute_toggle_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            DEFAULT_VERIFIER_ADDRESS,
+            false,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::AssetDefinitionFrozen { ref asset_type } if asset_type == DEFAULT_ASSET_TYPE),
+            "expected the frozen error to be returned when toggling a verifier on a frozen definition, but got: {:?}",
+            error,
+        );
+    }
+
+    fn assert_verifier_enabled(deps: &Deps, verifier_address: &str, expected_enabled: bool) {
+        let asset_definition = load_asset_definition_by_type_v3(deps.storage, DEFAULT_ASSET_TYPE)
+            .expect("the default asset definition should exist in storage");
+        assert_eq!(
+            expected_enabled,
+            asset_definition
+                .get_verifier_detail(verifier_address)
+                .expect("the verifier should exist on the asset definition")
+                .enabled,
+            "the verifier enabled value was not the expected value",
+        );
+    }
+
+    fn toggle_default_verifier(deps: DepsMut, expected_result: bool) {
+        toggle_asset_verifier(
+            deps,
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ToggleAssetVerifierV1::new(
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS,
+                expected_result,
+            ),
+        )
+        .expect("toggle should execute without fail");
+    }
+}
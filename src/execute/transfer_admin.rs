@@ -0,0 +1,387 @@
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+use crate::core::state::STATE_V2;
+use crate::util::scope_address_utils::bech32_string_to_addr;
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, EntryPointResponse},
+        contract_helpers::{check_admin_only, check_funds_are_empty},
+        event_attributes::{EventAttributes, EventType},
+    },
+};
+
+/// A transformation of [ExecuteMsg::TransferAdmin](crate::core::msg::ExecuteMsg::TransferAdmin)
+/// for ease of use in the underlying [transfer_admin](self::transfer_admin) function.
+///
+/// # Parameters
+///
+/// * `new_admin_address` The bech32 address of the account nominated to become the contract's
+/// [admin](crate::core::state::StateV2::admin).
+#[derive(Clone, PartialEq, Eq)]
+pub struct TransferAdminV1 {
+    pub new_admin_address: String,
+}
+impl TransferAdminV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `new_admin_address` The bech32 address of the account nominated to become the contract's
+    /// [admin](crate::core::state::StateV2::admin).
+    pub fn new<S: Into<String>>(new_admin_address: S) -> Self {
+        TransferAdminV1 {
+            new_admin_address: new_admin_address.into(),
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [TransferAdmin](crate::core::msg::ExecuteMsg::TransferAdmin)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<TransferAdminV1> {
+        match msg {
+            ExecuteMsg::TransferAdmin { new_admin_address } => {
+                TransferAdminV1::new(new_admin_address).to_ok()
+            }
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::TransferAdmin".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+impl TryFrom<ExecuteMsg> for TransferAdminV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        TransferAdminV1::from_execute_msg(msg)
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::TransferAdmin](crate::core::msg::ExecuteMsg::TransferAdmin)
+/// message is provided.  Stores the nominated address as the contract's [pending admin](crate::core::state::StateV2::pending_admin).
+/// The current admin retains control of the contract until the nominated account accepts the
+/// transfer via [accept_admin_transfer](self::accept_admin_transfer).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the transfer admin v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn transfer_admin(
+    deps: DepsMut,
+    info: MessageInfo,
+    msg: TransferAdminV1,
+) -> EntryPointResponse {
+    check_admin_only(&deps.as_ref(), &info)?;
+    check_funds_are_empty(&info)?;
+    let mut state = STATE_V2.load(deps.storage)?;
+    state.pending_admin = Some(bech32_string_to_addr(&msg.new_admin_address)?);
+    STATE_V2.save(deps.storage, &state)?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::TransferAdmin).set_new_value(&msg.new_admin_address),
+        )
+        .to_ok()
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::AcceptAdminTransfer](crate::core::msg::ExecuteMsg::AcceptAdminTransfer)
+/// message is provided.  Replaces the contract's [admin](crate::core::state::StateV2::admin) with
+/// the sender, provided that the sender is the [pending admin](crate::core::state::StateV2::pending_admin)
+/// nominated by [transfer_admin](self::transfer_admin).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+pub fn accept_admin_transfer(deps: DepsMut, info: MessageInfo) -> EntryPointResponse {
+    check_funds_are_empty(&info)?;
+    let mut state = STATE_V2.load(deps.storage)?;
+    if state.pending_admin.as_ref() != Some(&info.sender) {
+        return ContractError::Unauthorized {
+            explanation: "only the pending admin may accept an admin transfer".to_string(),
+        }
+        .to_err();
+    }
+    state.admin = info.sender;
+    state.pending_admin = None;
+    STATE_V2.save(deps.storage, &state)?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::AcceptAdminTransfer).set_new_value(&state.admin),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::Deps;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::state::STATE_V2;
+    use crate::testutil::execute_utilities::{
+        execute_accept_admin_transfer, execute_toggle_asset_definition, execute_transfer_admin,
+    };
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_SENDER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, empty_mock_info, mock_info_with_nhash,
+        test_instantiate_success, InstArgs,
+    };
+    use crate::util::constants::{ASSET_EVENT_TYPE_KEY, NEW_VALUE_KEY};
+    use crate::util::event_attributes::EventType;
+
+    use super::{accept_admin_transfer, transfer_admin, TransferAdminV1};
+
+    #[test]
+    fn test_transfer_admin_success() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let response = execute_transfer_admin(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_SENDER_ADDRESS,
+        )
+        .expect("expected the admin transfer to succeed");
+        assert!(
+            response.messages.is_empty(),
+            "transferring the admin should not require messages",
+        );
+        assert_eq!(
+            2,
+            response.attributes.len(),
+            "transferring the admin should produce the correct number of attributes",
+        );
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::TransferAdmin.event_name(),
+        );
+        assert_response_attributes_contain(&response, NEW_VALUE_KEY, DEFAULT_SENDER_ADDRESS);
+        assert_admin_state(
+            &deps.as_ref(),
+            DEFAULT_ADMIN_ADDRESS,
+            Some(DEFAULT_SENDER_ADDRESS),
+        );
+    }
+
+    #[test]
+    fn test_transfer_admin_replaces_existing_pending_admin() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        transfer_admin(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            TransferAdminV1::new(DEFAULT_SENDER_ADDRESS),
+        )
+        .expect("expected the first admin transfer to succeed");
+        let replacement_address = "tp1hsqtppgy7mgd64q0uvk7q39qa7h8hp30urqs6n";
+        transfer_admin(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            TransferAdminV1::new(replacement_address),
+        )
+        .expect("expected the second admin transfer to succeed");
+        assert_admin_state(
+            &deps.as_ref(),
+            DEFAULT_ADMIN_ADDRESS,
+            Some(replacement_address),
+        );
+        let err = accept_admin_transfer(deps.as_mut(), empty_mock_info(DEFAULT_SENDER_ADDRESS))
+            .expect_err("expected the replaced nominee to be unable to accept the transfer");
+        assert!(
+            err.is_unauthorized(),
+            "expected the unauthorized error to be returned for a replaced nominee, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_transfer_admin_failure_for_non_admin_sender() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let err = transfer_admin(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+            TransferAdminV1::new(DEFAULT_SENDER_ADDRESS),
+        )
+        .expect_err("expected a non-admin sender to be rejected");
+        assert!(
+            err.is_unauthorized(),
+            "expected the unauthorized error to be returned when the sender is not the admin, but got: {:?}",
+            err,
+        );
+        assert_admin_state(&deps.as_ref(), DEFAULT_ADMIN_ADDRESS, None);
+    }
+
+    #[test]
+    fn test_transfer_admin_failure_for_provided_funds() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let err = transfer_admin(
+            deps.as_mut(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            TransferAdminV1::new(DEFAULT_SENDER_ADDRESS),
+        )
+        .expect_err("expected a request with funds to be rejected");
+        assert!(
+            matches!(err, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when the sender provides funds, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_transfer_admin_failure_for_invalid_address() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let err = execute_transfer_admin(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            "not-a-bech32-address",
+        )
+        .expect_err("expected an error to occur when an invalid address is provided");
+        assert!(
+            matches!(err, ContractError::Bech32Error(..)),
+            "expected a bech32 error to be emitted, but got: {:?}",
+            err,
+        );
+        assert_admin_state(&deps.as_ref(), DEFAULT_ADMIN_ADDRESS, None);
+    }
+
+    #[test]
+    fn test_transfer_admin_failure_for_blank_address() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let err = execute_transfer_admin(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            "",
+        )
+        .expect_err("expected an error to occur when a blank address is provided");
+        assert!(
+            err.is_invalid_message_fields(),
+            "expected the invalid message fields error to be returned when the message is malformatted, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_accept_admin_transfer_success() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        execute_transfer_admin(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_SENDER_ADDRESS,
+        )
+        .expect("expected the admin transfer to succeed");
+        let response = execute_accept_admin_transfer(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+        )
+        .expect("expected the pending admin to be able to accept the transfer");
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::AcceptAdminTransfer.event_name(),
+        );
+        assert_response_attributes_contain(&response, NEW_VALUE_KEY, DEFAULT_SENDER_ADDRESS);
+        assert_admin_state(&deps.as_ref(), DEFAULT_SENDER_ADDRESS, None);
+        // The previous admin should no longer have access to administrative routes
+        let err = execute_toggle_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            false,
+        )
+        .expect_err("expected the previous admin to be rejected from admin routes");
+        assert!(
+            err.is_unauthorized(),
+            "expected the unauthorized error to be returned for the previous admin, but got: {:?}",
+            err,
+        );
+        execute_toggle_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            false,
+        )
+        .expect("expected the new admin to have access to admin routes");
+    }
+
+    #[test]
+    fn test_accept_admin_transfer_failure_for_no_pending_admin() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let err = accept_admin_transfer(deps.as_mut(), empty_mock_info(DEFAULT_ADMIN_ADDRESS))
+            .expect_err("expected acceptance to fail when no transfer is pending");
+        assert!(
+            err.is_unauthorized(),
+            "expected the unauthorized error to be returned when no transfer is pending, but got: {:?}",
+            err,
+        );
+        assert_admin_state(&deps.as_ref(), DEFAULT_ADMIN_ADDRESS, None);
+    }
+
+    #[test]
+    fn test_accept_admin_transfer_failure_for_incorrect_sender() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        transfer_admin(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            TransferAdminV1::new(DEFAULT_SENDER_ADDRESS),
+        )
+        .expect("expected the admin transfer to succeed");
+        let err = accept_admin_transfer(deps.as_mut(), empty_mock_info(DEFAULT_ADMIN_ADDRESS))
+            .expect_err("expected acceptance from a non-nominated sender to fail");
+        assert!(
+            err.is_unauthorized(),
+            "expected the unauthorized error to be returned for a non-nominated sender, but got: {:?}",
+            err,
+        );
+        assert_admin_state(
+            &deps.as_ref(),
+            DEFAULT_ADMIN_ADDRESS,
+            Some(DEFAULT_SENDER_ADDRESS),
+        );
+    }
+
+    fn assert_admin_state(deps: &Deps, expected_admin: &str, expected_pending_admin: Option<&str>) {
+        let state = STATE_V2
+            .load(deps.storage)
+            .expect("expected the contract state to load");
+        assert_eq!(
+            expected_admin,
+            state.admin.as_str(),
+            "expected the admin to be the correct value",
+        );
+        assert_eq!(
+            expected_pending_admin,
+            state.pending_admin.as_ref().map(|addr| addr.as_str()),
+            "expected the pending admin to be the correct value",
+        );
+    }
+}
@@ -1,18 +1,18 @@
 use crate::core::error::ContractError;
 use crate::core::msg::ExecuteMsg;
 use crate::core::state::STATE_V2;
-use crate::core::types::access_definition::AccessDefinition;
+use crate::core::types::access_definition::{AccessDefinition, AccessDefinitionType};
 use crate::core::types::access_route::AccessRoute;
 use crate::core::types::asset_identifier::AssetIdentifier;
 use crate::service::asset_meta_repository::AssetMetaRepository;
 use crate::service::deps_manager::DepsManager;
 use crate::service::message_gathering_service::MessageGatheringService;
 use crate::util::aliases::{AssetResult, EntryPointResponse};
-use crate::util::contract_helpers::check_funds_are_empty;
+use crate::util::contract_helpers::{check_admin_only, check_funds_are_empty};
 use crate::util::event_attributes::{EventAttributes, EventType};
 use crate::util::functions::filter_valid_access_routes;
 
-use cosmwasm_std::{MessageInfo, Response};
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
 use result_extensions::ResultExtensions;
 
 /// A transformation of [ExecuteMsg::UpdateAccessRoutes](crate::core::msg::ExecuteMsg::UpdateAccessRoutes)
@@ -25,7 +25,8 @@ use result_extensions::ResultExtensions;
 /// sender is referring to in the request.
 /// * `owner_address` The Provenance Blockchain bech32 address that owns the scope referred to by
 /// the [identifier](self::UpdateAccessRoutesV1::identifier).  This must either match the sender, or
-/// the sender must be the [contract admin](crate::core::state::StateV2::admin).
+/// the sender must be the [contract admin](crate::core::state::StateV2::admin) or the verifier of a
+/// verifier access definition.
 /// * `access_routes` A vector of [AccessRoute](crate::core::types::access_route::AccessRoute) to be used
 /// instead of the existing routes.  If other existing routes need to be maintained and the updated
 /// is intended to simply add a new route, then the existing routes need to be included in the
@@ -47,7 +48,8 @@ impl UpdateAccessRoutesV1 {
     /// sender is referring to in the request.
     /// * `owner_address` The Provenance Blockchain bech32 address that owns the scope referred to by
     /// the [identifier](self::UpdateAccessRoutesV1::identifier).  This must either match the sender, or
-    /// the sender must be the [contract admin](crate::core::state::StateV2::admin).
+    /// the sender must be the [contract admin](crate::core::state::StateV2::admin) or the verifier of a
+    /// verifier access definition.
     /// * `access_routes` A vector of [AccessRoute](crate::core::types::access_route::AccessRoute) to be used
     /// instead of the existing routes.  If other existing routes need to be maintained and the updated
     /// is intended to simply add a new route, then the existing routes need to be included in the
@@ -95,6 +97,13 @@ impl UpdateAccessRoutesV1 {
         }
     }
 }
+impl TryFrom<ExecuteMsg> for UpdateAccessRoutesV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        UpdateAccessRoutesV1::from_execute_msg(msg)
+    }
+}
 
 /// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::UpdateAccessRoutes](crate::core::msg::ExecuteMsg::UpdateAccessRoutes)
 /// message is provided.  Attempts to change the [AccessRoutes](crate::core::types::access_route::AccessRoute)
@@ -122,27 +131,26 @@ where
     T: AssetMetaRepository + MessageGatheringService + DepsManager<'a>,
 {
     check_funds_are_empty(&info)?;
-    // If the sender is not the specified owner address and the sender is not the admin, they are
-    // not authorized to change access routes
-    if info.sender.as_str() != msg.owner_address
-        && info.sender
-            != repository
-                .use_deps(|deps| STATE_V2.load(deps.storage))?
-                .admin
-    {
-        return ContractError::Unauthorized {
-            explanation:
-                "only the admin or owner of the given access routes can make modifications to them"
-                    .to_string(),
-        }
-        .to_err();
+    let scope_address = msg.identifier.get_scope_address()?;
+    let state = repository.use_deps(|deps| STATE_V2.load(deps.storage))?;
+    // If the sender is not the specified owner address and the sender is not the admin, they must
+    // be the asset type's verifier to change access routes
+    if info.sender.as_str() != msg.owner_address && info.sender != state.admin {
+        check_verifier_authorization(&repository, &info, &msg, &scope_address)?;
     }
     let mut access_routes = filter_valid_access_routes(msg.access_routes.clone());
     if msg.access_routes.len() != access_routes.len() {
         // The filtration function will trim duplicate routes, as well as invalid routes
         return ContractError::generic("invalid or duplicate access routes were provided").to_err();
     }
-    let scope_address = msg.identifier.get_scope_address()?;
+    // The request replaces the owner's routes entirely, so the limit applies to the full list
+    if access_routes.len() > state.max_access_routes_per_owner as usize {
+        return ContractError::AccessRouteLimitExceeded {
+            owner: msg.owner_address,
+            limit: state.max_access_routes_per_owner,
+        }
+        .to_err();
+    }
     let mut scope_attribute = repository.get_asset_by_asset_type(&scope_address, msg.asset_type)?;
     if let Some(mut target_access_definition) = scope_attribute
         .access_definitions
@@ -185,25 +193,104 @@ where
         .to_ok()
 }
 
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::SetMaxAccessRoutesPerOwner](crate::core::msg::ExecuteMsg::SetMaxAccessRoutesPerOwner)
+/// message is provided.  Replaces the contract's [max_access_routes_per_owner](crate::core::state::StateV2::max_access_routes_per_owner).
+/// Existing access routes are never removed, even if an owner holds more than the new limit.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `limit` The new maximum number of access routes per owner.
+pub fn set_max_access_routes_per_owner(
+    deps: DepsMut,
+    info: MessageInfo,
+    limit: u32,
+) -> EntryPointResponse {
+    check_admin_only(&deps.as_ref(), &info)?;
+    check_funds_are_empty(&info)?;
+    let mut state = STATE_V2.load(deps.storage)?;
+    state.max_access_routes_per_owner = limit;
+    STATE_V2.save(deps.storage, &state)?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::SetMaxAccessRoutesPerOwner).set_new_value(limit),
+        )
+        .to_ok()
+}
+
+/// Ensures that a sender that is neither the owner of the target access definition nor the admin
+/// is the verifier for the scope attribute, and that the target access definition was created by a
+/// verifier.  Verifiers are not permitted to modify requestor access definitions.
+///
+/// # Parameters
+///
+/// * `repository` A helper collection of traits that allows complex lookups of scope values.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the update access routes message.
+/// * `msg` The update access routes request being processed.
+/// * `scope_address` The bech32 address of the scope targeted by the request.
+fn check_verifier_authorization<T: AssetMetaRepository>(
+    repository: &T,
+    info: &MessageInfo,
+    msg: &UpdateAccessRoutesV1,
+    scope_address: &str,
+) -> AssetResult<()> {
+    let scope_attribute = match repository
+        .try_get_asset_by_asset_type(scope_address, &msg.asset_type)?
+    {
+        Some(attribute) if attribute.verifier_address == info.sender => attribute,
+        _ => {
+            return ContractError::Unauthorized {
+                explanation:
+                    "only the admin or owner of the given access routes can make modifications to them"
+                        .to_string(),
+            }
+            .to_err();
+        }
+    };
+    let targets_requestor_definition = scope_attribute.access_definitions.iter().any(|def| {
+        def.owner_address == msg.owner_address
+            && def.definition_type != AccessDefinitionType::Verifier
+    });
+    if targets_requestor_definition {
+        return ContractError::Unauthorized {
+            explanation: "verifiers may only update their own access definition entries"
+                .to_string(),
+        }
+        .to_err();
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::contract::execute;
     use crate::service::asset_meta_service::AssetMetaService;
+    use crate::testutil::attribute_utilities::mock_named_attribute_response_for_scope;
+    use crate::testutil::execute_utilities::{
+        execute_set_max_access_routes_per_owner, execute_update_access_routes,
+    };
     use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
     use crate::testutil::test_constants::{
         DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_CONTRACT_BASE_NAME,
         DEFAULT_SCOPE_ADDRESS, DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
     };
     use crate::testutil::test_utilities::{
-        assert_single_item, empty_mock_info, setup_no_attribute_response, setup_test_suite,
-        single_attribute_for_key, InstArgs,
+        assert_response_attributes_contain, assert_response_messages_count, assert_single_item,
+        build_attribute, empty_mock_info, setup_no_attribute_response, setup_test_suite, InstArgs,
+        MockOwnedDeps,
     };
     use crate::testutil::update_access_routes_helpers::{
         test_update_access_routes, TestUpdateAccessRoutes,
     };
     use crate::testutil::verify_asset_helpers::{test_verify_asset, TestVerifyAsset};
-    use crate::util::constants::{ASSET_EVENT_TYPE_KEY, ASSET_SCOPE_ADDRESS_KEY, ASSET_TYPE_KEY};
+    use crate::util::constants::{
+        ASSET_EVENT_TYPE_KEY, ASSET_SCOPE_ADDRESS_KEY, ASSET_TYPE_KEY,
+        DEFAULT_MAX_ACCESS_ROUTES_PER_OWNER, NEW_VALUE_KEY,
+    };
     use crate::util::functions::{
         generate_asset_attribute_name, try_into_update_attribute_request,
     };
@@ -383,10 +470,10 @@ mod tests {
             },
         )
         .expect("expected the update to complete successfully");
-        assert_eq!(
+        assert_response_messages_count(
+            &response,
             1,
-            response.messages.len(),
-            "expected the update to emit the correct number of messages"
+            "expected the update to emit the correct number of messages",
         );
         let expected_attribute_name =
             generate_asset_attribute_name(DEFAULT_ASSET_TYPE, DEFAULT_CONTRACT_BASE_NAME);
@@ -445,20 +532,16 @@ mod tests {
             response.attributes.len(),
             "expected the correct number of attributes to be emitted"
         );
-        assert_eq!(
-            EventType::UpdateAccessRoutes.event_name(),
-            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
-            "expected the correct event type to be emitted",
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::UpdateAccessRoutes.event_name(),
         );
-        assert_eq!(
-            DEFAULT_ASSET_TYPE,
-            single_attribute_for_key(&response, ASSET_TYPE_KEY),
-            "expected the correct asset type to be emitted",
-        );
-        assert_eq!(
+        assert_response_attributes_contain(&response, ASSET_TYPE_KEY, DEFAULT_ASSET_TYPE);
+        assert_response_attributes_contain(
+            &response,
+            ASSET_SCOPE_ADDRESS_KEY,
             DEFAULT_SCOPE_ADDRESS,
-            single_attribute_for_key(&response, ASSET_SCOPE_ADDRESS_KEY),
-            "expected the correct scope address to be emitted",
         );
         assert_eq!(
             attribute_before_update.access_definitions.len(),
@@ -619,21 +702,330 @@ mod tests {
         setup_no_attribute_response(&mut deps, None);
         test_onboard_asset(&mut deps, TestOnboardAsset::default())
             .expect("expected the default asset onboarding to succeed");
-        execute(
+        execute_update_access_routes(
             deps.as_mut(),
             mock_env(),
             empty_mock_info(DEFAULT_SENDER_ADDRESS),
-            ExecuteMsg::UpdateAccessRoutes {
-                identifier: AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS)
-                    .to_serialized_enum(),
-                asset_type: DEFAULT_ASSET_TYPE.into(),
-                owner_address: DEFAULT_SENDER_ADDRESS.to_string(),
-                access_routes: vec![AccessRoute::new("grpcs://no.u:4433", "some_name".to_some())],
-            },
+            UpdateAccessRoutesV1::new(
+                AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_SENDER_ADDRESS,
+                vec![AccessRoute::new("grpcs://no.u:4433", "some_name".to_some())],
+            ),
         )
         .expect("expected an update through the execute function to complete successfully");
     }
 
+    #[test]
+    fn test_requestor_can_update_requestor_access_definition() {
+        let (mut deps, env) = setup_verified_asset();
+        update_routes_for_owner(
+            &mut deps,
+            &env,
+            DEFAULT_SENDER_ADDRESS,
+            DEFAULT_SENDER_ADDRESS,
+        )
+        .expect("expected the requestor to be able to update its own access definition");
+        assert_routes_updated_for_owner(&mut deps, DEFAULT_SENDER_ADDRESS);
+    }
+
+    #[test]
+    fn test_requestor_cannot_update_verifier_access_definition() {
+        let (mut deps, env) = setup_verified_asset();
+        let err = update_routes_for_owner(
+            &mut deps,
+            &env,
+            DEFAULT_SENDER_ADDRESS,
+            DEFAULT_VERIFIER_ADDRESS,
+        )
+        .expect_err(
+            "expected the requestor to be unable to update the verifier's access definition",
+        );
+        assert_unauthorized(
+            err,
+            "only the admin or owner of the given access routes can make modifications to them",
+        );
+    }
+
+    #[test]
+    fn test_verifier_can_update_verifier_access_definition() {
+        let (mut deps, env) = setup_verified_asset();
+        update_routes_for_owner(
+            &mut deps,
+            &env,
+            DEFAULT_VERIFIER_ADDRESS,
+            DEFAULT_VERIFIER_ADDRESS,
+        )
+        .expect("expected the verifier to be able to update its own access definition");
+        assert_routes_updated_for_owner(&mut deps, DEFAULT_VERIFIER_ADDRESS);
+    }
+
+    #[test]
+    fn test_verifier_cannot_update_requestor_access_definition() {
+        let (mut deps, env) = setup_verified_asset();
+        let err = update_routes_for_owner(
+            &mut deps,
+            &env,
+            DEFAULT_VERIFIER_ADDRESS,
+            DEFAULT_SENDER_ADDRESS,
+        )
+        .expect_err(
+            "expected the verifier to be unable to update the requestor's access definition",
+        );
+        assert_unauthorized(
+            err,
+            "verifiers may only update their own access definition entries",
+        );
+    }
+
+    #[test]
+    fn test_verifier_can_update_verifier_access_definition_owned_by_another_address() {
+        let (mut deps, env) = setup_verified_asset();
+        let mut attribute = AssetMetaService::new(deps.as_mut())
+            .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+            .expect("expected a scope attribute to be available after verification");
+        attribute
+            .access_definitions
+            .iter_mut()
+            .filter(|def| def.definition_type == AccessDefinitionType::Verifier)
+            .for_each(|def| def.owner_address = "previous-verifier-address".to_string());
+        mock_named_attribute_response_for_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![build_attribute(DEFAULT_SCOPE_ADDRESS, &attribute)],
+        );
+        update_routes_for_owner(
+            &mut deps,
+            &env,
+            DEFAULT_VERIFIER_ADDRESS,
+            "previous-verifier-address",
+        )
+        .expect("expected the verifier to be able to update any verifier access definition");
+        assert_routes_updated_for_owner(&mut deps, "previous-verifier-address");
+    }
+
+    #[test]
+    fn test_set_max_access_routes_per_owner() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        assert_eq!(
+            DEFAULT_MAX_ACCESS_ROUTES_PER_OWNER,
+            load_max_access_routes(&deps),
+            "the default limit should be used when none is provided at instantiation",
+        );
+        let response = execute_set_max_access_routes_per_owner(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            3,
+        )
+        .expect("the admin should be able to set the maximum access routes per owner");
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::SetMaxAccessRoutesPerOwner.event_name(),
+        );
+        assert_response_attributes_contain(&response, NEW_VALUE_KEY, "3");
+        assert_eq!(
+            3,
+            load_max_access_routes(&deps),
+            "the new limit should be stored in the contract's state",
+        );
+    }
+
+    #[test]
+    fn test_set_max_access_routes_per_owner_by_non_admin_fails() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        let err = execute_set_max_access_routes_per_owner(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+            3,
+        )
+        .expect_err("a non-admin should not be able to set the maximum access routes per owner");
+        assert!(
+            matches!(err, ContractError::Unauthorized { .. }),
+            "expected an unauthorized error, but got: {:?}",
+            err,
+        );
+        assert_eq!(
+            DEFAULT_MAX_ACCESS_ROUTES_PER_OWNER,
+            load_max_access_routes(&deps),
+            "the limit should remain unchanged",
+        );
+    }
+
+    #[test]
+    fn test_error_for_access_routes_exceeding_limit() {
+        let mut deps = mock_provenance_dependencies();
+        let instantiate_args = InstArgs {
+            max_access_routes_per_owner: 1.to_some(),
+            ..InstArgs::default()
+        };
+        setup_test_suite(&mut deps, &instantiate_args);
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the default asset onboarding to succeed");
+        let err = update_routes_for_sender(&mut deps, &instantiate_args.env, get_routes(2))
+            .expect_err("expected an update exceeding the access route limit to fail");
+        assert_access_route_limit_exceeded(err, 1);
+    }
+
+    #[test]
+    fn test_lowering_limit_does_not_truncate_existing_routes() {
+        let mut deps = mock_provenance_dependencies();
+        let instantiate_args = InstArgs::default();
+        setup_test_suite(&mut deps, &instantiate_args);
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the default asset onboarding to succeed");
+        update_routes_for_sender(&mut deps, &instantiate_args.env, get_routes(3))
+            .expect("expected an update within the access route limit to succeed");
+        execute_set_max_access_routes_per_owner(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            2,
+        )
+        .expect("the admin should be able to lower the maximum access routes per owner");
+        assert_eq!(
+            get_routes(3),
+            get_sender_access_routes(&mut deps),
+            "lowering the limit should not remove any existing access routes",
+        );
+        let err = update_routes_for_sender(&mut deps, &instantiate_args.env, get_routes(3))
+            .expect_err("expected an update exceeding the lowered limit to fail");
+        assert_access_route_limit_exceeded(err, 2);
+        update_routes_for_sender(&mut deps, &instantiate_args.env, get_routes(2))
+            .expect("expected an update within the lowered limit to succeed");
+        assert_eq!(
+            get_routes(2),
+            get_sender_access_routes(&mut deps),
+            "the owner should be able to reduce its routes to fit within the lowered limit",
+        );
+    }
+
+    fn setup_verified_asset() -> (MockOwnedDeps, cosmwasm_std::Env) {
+        let mut deps = mock_provenance_dependencies();
+        let instantiate_args = InstArgs::default();
+        setup_test_suite(&mut deps, &instantiate_args);
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the default asset onboarding to succeed");
+        test_verify_asset(&mut deps, &instantiate_args.env, TestVerifyAsset::default())
+            .expect("expected the default asset verification to succeed");
+        (deps, instantiate_args.env)
+    }
+
+    fn update_routes_for_owner(
+        deps: &mut MockOwnedDeps,
+        env: &cosmwasm_std::Env,
+        sender: &str,
+        owner_address: &str,
+    ) -> EntryPointResponse {
+        test_update_access_routes(
+            deps,
+            env,
+            TestUpdateAccessRoutes {
+                info: empty_mock_info(sender),
+                update_access_routes: UpdateAccessRoutesV1 {
+                    owner_address: owner_address.to_string(),
+                    ..get_valid_update_routes_v1()
+                },
+            },
+        )
+    }
+
+    fn assert_routes_updated_for_owner(deps: &mut MockOwnedDeps, owner_address: &str) {
+        let attribute = AssetMetaService::new(deps.as_mut())
+            .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+            .expect("expected the scope attribute to be available after the update");
+        let access_definition = attribute
+            .access_definitions
+            .iter()
+            .find(|def| def.owner_address == owner_address)
+            .expect("expected an access definition to exist for the owner");
+        assert_eq!(
+            get_valid_update_routes_v1().access_routes,
+            access_definition.access_routes,
+            "expected the owner's access routes to be replaced by the update",
+        );
+    }
+
+    fn update_routes_for_sender(
+        deps: &mut MockOwnedDeps,
+        env: &cosmwasm_std::Env,
+        access_routes: Vec<AccessRoute>,
+    ) -> EntryPointResponse {
+        test_update_access_routes(
+            deps,
+            env,
+            TestUpdateAccessRoutes {
+                info: empty_mock_info(DEFAULT_SENDER_ADDRESS),
+                update_access_routes: UpdateAccessRoutesV1 {
+                    access_routes,
+                    ..get_valid_update_routes_v1()
+                },
+            },
+        )
+    }
+
+    fn get_sender_access_routes(deps: &mut MockOwnedDeps) -> Vec<AccessRoute> {
+        let mut access_routes = AssetMetaService::new(deps.as_mut())
+            .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+            .expect("expected the scope attribute to be available")
+            .access_definitions
+            .into_iter()
+            .find(|def| def.owner_address == DEFAULT_SENDER_ADDRESS)
+            .expect("expected an access definition to exist for the sender")
+            .access_routes;
+        // routes are not guaranteed to retain their requested order after filtering
+        access_routes.sort();
+        access_routes
+    }
+
+    fn get_routes(count: usize) -> Vec<AccessRoute> {
+        (0..count)
+            .map(|index| AccessRoute::route_only(format!("grpcs://route{}.fake:1234", index)))
+            .collect()
+    }
+
+    fn load_max_access_routes(deps: &MockOwnedDeps) -> u32 {
+        STATE_V2
+            .load(&deps.storage)
+            .expect("the contract state should load")
+            .max_access_routes_per_owner
+    }
+
+    fn assert_access_route_limit_exceeded(err: ContractError, expected_limit: u32) {
+        match err {
+            ContractError::AccessRouteLimitExceeded { owner, limit } => {
+                assert_eq!(
+                    DEFAULT_SENDER_ADDRESS, owner,
+                    "the error should include the owner of the routes",
+                );
+                assert_eq!(
+                    expected_limit, limit,
+                    "the error should include the current limit",
+                );
+            }
+            _ => panic!("unexpected error encountered: {:?}", err),
+        }
+    }
+
+    fn assert_unauthorized(err: ContractError, expected_explanation: &str) {
+        match err {
+            ContractError::Unauthorized { explanation } => {
+                assert_eq!(
+                    expected_explanation, explanation,
+                    "unexpected Unauthorized error message encountered",
+                );
+            }
+            _ => panic!("unexpected error encountered: {:?}", err),
+        }
+    }
+
     fn get_valid_update_routes_v1() -> UpdateAccessRoutesV1 {
         UpdateAccessRoutesV1::new(
             AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
@@ -0,0 +1,337 @@
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+use crate::core::state::{load_asset_definition_by_type_v3, replace_asset_definition_v3};
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, EntryPointResponse},
+        contract_helpers::{check_admin_only, check_funds_are_empty},
+        event_attributes::{EventAttributes, EventType},
+    },
+};
+
+/// A transformation of [ExecuteMsg::UpdateAllowedRequestors](crate::core::msg::ExecuteMsg::UpdateAllowedRequestors)
+/// for ease of use in the underlying [update_allowed_requestors](self::update_allowed_requestors) function.
+///
+/// # Parameters
+///
+/// * `asset_type` The unique identifier for the [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
+/// to update.
+/// * `allowed_requestors` The new value of the definition's [allowed_requestors](crate::core::types::asset_definition::AssetDefinitionV3::allowed_requestors).
+/// A None value removes the restriction, allowing any requestor to onboard the asset type.
+#[derive(Clone, PartialEq, Eq)]
+pub struct UpdateAllowedRequestorsV1 {
+    pub asset_type: String,
+    pub allowed_requestors: Option<Vec<String>>,
+}
+impl UpdateAllowedRequestorsV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_type` The unique identifier for the [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
+    /// to update.
+    /// * `allowed_requestors` The new value of the definition's [allowed_requestors](crate::core::types::asset_definition::AssetDefinitionV3::allowed_requestors).
+    pub fn new<S: Into<String>>(asset_type: S, allowed_requestors: Option<Vec<String>>) -> Self {
+        UpdateAllowedRequestorsV1 {
+            asset_type: asset_type.into(),
+            allowed_requestors,
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [UpdateAllowedRequestors](crate::core::msg::ExecuteMsg::UpdateAllowedRequestors)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<UpdateAllowedRequestorsV1> {
+        match msg {
+            ExecuteMsg::UpdateAllowedRequestors {
+                asset_type,
+                allowed_requestors,
+            } => UpdateAllowedRequestorsV1::new(asset_type, allowed_requestors).to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::UpdateAllowedRequestors".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+impl TryFrom<ExecuteMsg> for UpdateAllowedRequestorsV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        UpdateAllowedRequestorsV1::from_execute_msg(msg)
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::UpdateAllowedRequestors](crate::core::msg::ExecuteMsg::UpdateAllowedRequestors)
+/// message is provided.  Replaces the [allowed_requestors](crate::core::types::asset_definition::AssetDefinitionV3::allowed_requestors)
+/// of an asset definition without modifying any of its other values.  Assets that were already
+/// onboarded are unaffected by the change.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the update allowed requestors v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn update_allowed_requestors(
+    deps: DepsMut,
+    info: MessageInfo,
+    msg: UpdateAllowedRequestorsV1,
+) -> EntryPointResponse {
+    check_admin_only(&deps.as_ref(), &info)?;
+    check_funds_are_empty(&info)?;
+    let mut asset_definition = load_asset_definition_by_type_v3(deps.storage, &msg.asset_type)?;
+    asset_definition.check_not_frozen()?;
+    asset_definition.allowed_requestors = msg.allowed_requestors;
+    replace_asset_definition_v3(deps.storage, &asset_definition)?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::UpdateAllowedRequestors)
+                .set_asset_type(&asset_definition.asset_type),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{message_info, mock_env};
+    use cosmwasm_std::{Addr, Deps};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::state::load_asset_definition_by_type_v3;
+    use crate::core::types::asset_definition::AssetDefinitionV3;
+    use crate::testutil::execute_utilities::{
+        execute_freeze_asset_definition, execute_update_allowed_requestors,
+    };
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_SENDER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, empty_mock_info, get_default_asset_definition,
+        mock_info_with_nhash, setup_no_attribute_response, setup_test_suite,
+        test_instantiate_success, InstArgs, MockOwnedDeps,
+    };
+    use crate::util::constants::{ASSET_EVENT_TYPE_KEY, ASSET_TYPE_KEY};
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{update_allowed_requestors, UpdateAllowedRequestorsV1};
+
+    #[test]
+    fn test_valid_update_allowed_requestors_via_execute() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let allowed_requestors = vec![DEFAULT_SENDER_ADDRESS.to_string()];
+        let response = execute_update_allowed_requestors(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            allowed_requestors.clone().to_some(),
+        )
+        .expect("the allowed requestors update should work correctly");
+        assert!(
+            response.messages.is_empty(),
+            "updating allowed requestors should not require messages",
+        );
+        assert_eq!(
+            2,
+            response.attributes.len(),
+            "updating allowed requestors should produce the correct number of attributes",
+        );
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::UpdateAllowedRequestors.event_name(),
+        );
+        assert_response_attributes_contain(&response, ASSET_TYPE_KEY, DEFAULT_ASSET_TYPE);
+        assert_eq!(
+            AssetDefinitionV3 {
+                allowed_requestors: allowed_requestors.to_some(),
+                ..get_default_asset_definition()
+            },
+            load_definition(&deps.as_ref()),
+            "only the allowed requestors should be changed on the asset definition",
+        );
+    }
+
+    #[test]
+    fn test_update_allowed_requestors_clears_value_with_none() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        for allowed_requestors in [vec![DEFAULT_SENDER_ADDRESS.to_string()].to_some(), None] {
+            update_allowed_requestors(
+                deps.as_mut(),
+                empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+                UpdateAllowedRequestorsV1::new(DEFAULT_ASSET_TYPE, allowed_requestors.clone()),
+            )
+            .expect("the allowed requestors update should work correctly");
+            assert_eq!(
+                allowed_requestors,
+                load_definition(&deps.as_ref()).allowed_requestors,
+                "the allowed requestors should reflect the most recent update",
+            );
+        }
+    }
+
+    #[test]
+    fn test_update_allowed_requestors_controls_onboarding() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        update(&mut deps, vec![DEFAULT_ADMIN_ADDRESS.to_string()].to_some());
+        let error = test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect_err("a requestor outside of the allowed list should not be able to onboard");
+        assert!(
+            error.is_unauthorized(),
+            "expected the unauthorized error to be returned for a blocked requestor, but got: {:?}",
+            error,
+        );
+        update(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("any requestor should be able to onboard after the allowed list is cleared");
+    }
+
+    #[test]
+    fn test_invalid_update_allowed_requestors_for_invalid_msg() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        for allowed_requestors in [
+            vec!["not-an-address".to_string()],
+            vec![
+                DEFAULT_SENDER_ADDRESS.to_string(),
+                DEFAULT_SENDER_ADDRESS.to_string(),
+            ],
+        ] {
+            let error = execute_update_allowed_requestors(
+                deps.as_mut(),
+                mock_env(),
+                empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+                DEFAULT_ASSET_TYPE,
+                allowed_requestors.clone().to_some(),
+            )
+            .unwrap_err();
+            assert!(
+                error.is_invalid_message_fields(),
+                "expected the invalid message fields error to be returned for {:?}, but got: {:?}",
+                allowed_requestors,
+                error,
+            );
+        }
+        assert_eq!(
+            None,
+            load_definition(&deps.as_ref()).allowed_requestors,
+            "the allowed requestors should be unchanged after invalid updates",
+        );
+    }
+
+    #[test]
+    fn test_invalid_update_allowed_requestors_for_invalid_sender() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = update_allowed_requestors(
+            deps.as_mut(),
+            message_info(&Addr::unchecked(DEFAULT_SENDER_ADDRESS), &[]),
+            UpdateAllowedRequestorsV1::new(
+                DEFAULT_ASSET_TYPE,
+                vec![DEFAULT_SENDER_ADDRESS.to_string()].to_some(),
+            ),
+        )
+        .unwrap_err();
+        assert!(
+            error.is_unauthorized(),
+            "expected the unauthorized error to be returned when the sender is not the admin, but got: {:?}",
+            error,
+        );
+        assert_eq!(
+            None,
+            load_definition(&deps.as_ref()).allowed_requestors,
+            "the allowed requestors should be unchanged after an unauthorized update",
+        );
+    }
+
+    #[test]
+    fn test_invalid_update_allowed_requestors_for_provided_funds() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = update_allowed_requestors(
+            deps.as_mut(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            UpdateAllowedRequestorsV1::new(DEFAULT_ASSET_TYPE, None),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when the sender provides funds, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_update_allowed_requestors_for_missing_asset_type() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = update_allowed_requestors(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateAllowedRequestorsV1::new("not-a-type", None),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::RecordNotFound { .. }),
+            "expected the record not found error to be returned, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_update_allowed_requestors_for_frozen_definition() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        execute_freeze_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+        )
+        .expect("expected the asset definition to be frozen");
+        let error = execute_update_allowed_requestors(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            Some(vec![DEFAULT_SENDER_ADDRESS.to_string()]),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::AssetDefinitionFrozen { ref asset_type } if asset_type == DEFAULT_ASSET_TYPE),
+            "expected the frozen error to be returned when updating the allowed requestors of a frozen definition, but got: {:?}",
+            error,
+        );
+    }
+
+    fn update(deps: &mut MockOwnedDeps, allowed_requestors: Option<Vec<String>>) {
+        update_allowed_requestors(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateAllowedRequestorsV1::new(DEFAULT_ASSET_TYPE, allowed_requestors),
+        )
+        .expect("the allowed requestors update should work correctly");
+    }
+
+    fn load_definition(deps: &Deps) -> AssetDefinitionV3 {
+        load_asset_definition_by_type_v3(deps.storage, DEFAULT_ASSET_TYPE)
+            .expect("the default asset definition should exist in storage")
+    }
+}
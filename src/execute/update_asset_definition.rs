@@ -1,10 +1,11 @@
 use crate::core::error::ContractError;
 use crate::core::msg::ExecuteMsg;
-use crate::core::state::replace_asset_definition_v3;
-use crate::core::types::asset_definition::AssetDefinitionV3;
+use crate::core::state::{load_asset_definition_by_type_v3, replace_asset_definition_v3, STATE_V2};
+use crate::core::types::asset_definition::AssetDefinitionInputV3;
 use crate::util::aliases::{AssetResult, EntryPointResponse};
 use crate::util::contract_helpers::{check_admin_only, check_funds_are_empty};
 use crate::util::event_attributes::{EventAttributes, EventType};
+use crate::validation::validate_init_msg::{validate_asset_definition, validate_verifier};
 
 use cosmwasm_std::{DepsMut, MessageInfo, Response};
 use result_extensions::ResultExtensions;
@@ -14,21 +15,42 @@ use result_extensions::ResultExtensions;
 ///
 /// # Parameters
 ///
-/// * `asset_definition` The asset definition instance to update.  Must have an [asset_type](crate::core::types::asset_definition::AssetDefinitionV3::asset_type)
+/// * `asset_definition` The asset definition input to update.  Must have an [asset_type](crate::core::types::asset_definition::AssetDefinitionInputV3::asset_type)
 /// property that matches an existing asset definition in contract storage.
+/// * `partial` Whether or not only the provided values in the input should overwrite the existing
+/// definition's values.
 #[derive(Clone, PartialEq, Eq)]
 pub struct UpdateAssetDefinitionV1 {
-    pub asset_definition: AssetDefinitionV3,
+    pub asset_definition: AssetDefinitionInputV3,
+    pub partial: bool,
 }
 impl UpdateAssetDefinitionV1 {
-    /// Constructs a new instance of this struct.
+    /// Constructs a new instance of this struct that will fully replace the existing definition.
     ///
     /// # Parameters
     ///
-    /// * `asset_definition` The asset definition instance to update.  Must have an [asset_type](crate::core::types::asset_definition::AssetDefinitionV3::asset_type)
+    /// * `asset_definition` The asset definition input to update.  Must have an [asset_type](crate::core::types::asset_definition::AssetDefinitionInputV3::asset_type)
     /// property that matches an existing asset definition in contract storage.
-    pub fn new(asset_definition: AssetDefinitionV3) -> Self {
-        UpdateAssetDefinitionV1 { asset_definition }
+    pub fn new(asset_definition: AssetDefinitionInputV3) -> Self {
+        UpdateAssetDefinitionV1 {
+            asset_definition,
+            partial: false,
+        }
+    }
+
+    /// Constructs a new instance of this struct that will only overwrite the provided values of the
+    /// existing definition.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_definition` The asset definition input to overlay onto the existing definition.
+    /// Must have an [asset_type](crate::core::types::asset_definition::AssetDefinitionInputV3::asset_type)
+    /// property that matches an existing asset definition in contract storage.
+    pub fn new_partial(asset_definition: AssetDefinitionInputV3) -> Self {
+        UpdateAssetDefinitionV1 {
+            asset_definition,
+            partial: true,
+        }
     }
 
     /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
@@ -41,8 +63,12 @@ impl UpdateAssetDefinitionV1 {
     /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
     pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<UpdateAssetDefinitionV1> {
         match msg {
-            ExecuteMsg::UpdateAssetDefinition { asset_definition } => Self {
-                asset_definition: asset_definition.into_asset_definition(),
+            ExecuteMsg::UpdateAssetDefinition {
+                asset_definition,
+                partial,
+            } => Self {
+                asset_definition,
+                partial: partial.unwrap_or(false),
             }
             .to_ok(),
             _ => ContractError::InvalidMessageType {
@@ -52,11 +78,20 @@ impl UpdateAssetDefinitionV1 {
         }
     }
 }
+impl TryFrom<ExecuteMsg> for UpdateAssetDefinitionV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        UpdateAssetDefinitionV1::from_execute_msg(msg)
+    }
+}
 
 /// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::UpdateAssetDefinition](crate::core::msg::ExecuteMsg::UpdateAssetDefinition)
 /// message is provided.  Attempts to replace an existing [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
 /// value based on a matching [asset_type](crate::core::types::asset_definition::AssetDefinitionV3::asset_type)
-/// property.  If no matching type is present, the request will be rejected.
+/// property.  If no matching type is present, the request will be rejected.  When a partial update
+/// is requested, the provided values are overlaid onto the existing definition via [merge_into](crate::core::types::asset_definition::AssetDefinitionInputV3::merge_into)
+/// and the result is validated before being stored.
 ///
 /// # Parameters
 ///
@@ -73,21 +108,55 @@ pub fn update_asset_definition(
 ) -> EntryPointResponse {
     check_admin_only(&deps.as_ref(), &info)?;
     check_funds_are_empty(&info)?;
+    let existing_definition =
+        load_asset_definition_by_type_v3(deps.storage, &msg.asset_definition.asset_type)?;
+    existing_definition.check_not_frozen()?;
+    let state = STATE_V2.load(deps.storage)?;
+    let asset_definition = if msg.partial {
+        let merged_definition = msg.asset_definition.merge_into(&existing_definition);
+        // Partial inputs skip full validation in the execute entrypoint, so the merged result must
+        // be verified before it is stored
+        validate_asset_definition(&merged_definition, state.allow_all_bech32_prefixes, false)?;
+        merged_definition
+    } else {
+        // An omitted scope owner restriction is inherited rather than silently lifted
+        let scope_owner_must_match_requestor = msg
+            .asset_definition
+            .scope_owner_must_match_requestor
+            .unwrap_or(existing_definition.scope_owner_must_match_requestor);
+        let mut asset_definition = msg.asset_definition.into_asset_definition();
+        // Deprecation is only managed by the DeprecateAssetDefinition route, so it survives a full
+        // replacement
+        asset_definition.deprecated = existing_definition.deprecated;
+        asset_definition.replacement_asset_type = existing_definition.replacement_asset_type;
+        asset_definition.scope_owner_must_match_requestor = scope_owner_must_match_requestor;
+        asset_definition
+    };
+    // Entity details are only required of the verifiers that the update adds, so verifiers that
+    // predate the requirement can remain on the definition
+    if state.require_entity_detail_for_verifiers {
+        for verifier in asset_definition.verifiers.iter().filter(|verifier| {
+            !existing_definition
+                .verifiers
+                .iter()
+                .any(|existing_verifier| existing_verifier.address == verifier.address)
+        }) {
+            validate_verifier(verifier, state.allow_all_bech32_prefixes, true)?;
+        }
+    }
     // Overwrite the existing asset definition with the new one
-    replace_asset_definition_v3(deps.storage, &msg.asset_definition)?;
+    replace_asset_definition_v3(deps.storage, &asset_definition)?;
     Response::new()
         .add_attributes(
             EventAttributes::new(EventType::UpdateAssetDefinition)
-                .set_asset_type(&msg.asset_definition.asset_type),
+                .set_asset_type(&asset_definition.asset_type),
         )
         .to_ok()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::contract::execute;
     use crate::core::error::ContractError;
-    use crate::core::msg::ExecuteMsg;
     use crate::core::state::load_asset_definition_by_type_v3;
     use crate::core::types::asset_definition::{AssetDefinitionInputV3, AssetDefinitionV3};
     use crate::core::types::fee_destination::FeeDestinationV2;
@@ -95,12 +164,17 @@ mod tests {
     use crate::execute::update_asset_definition::{
         update_asset_definition, UpdateAssetDefinitionV1,
     };
+    use crate::testutil::execute_utilities::{
+        execute_deprecate_asset_definition, execute_freeze_asset_definition,
+        execute_update_asset_definition,
+    };
     use crate::testutil::test_constants::{
         DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_ASSET_TYPE_DISPLAY_NAME,
         DEFAULT_SENDER_ADDRESS,
     };
     use crate::testutil::test_utilities::{
-        empty_mock_info, get_default_entity_detail, single_attribute_for_key,
+        assert_response_attributes_contain, empty_mock_info, get_default_asset_definition,
+        get_default_asset_definition_input, get_default_entity_detail, get_default_verifier_detail,
         test_instantiate_success, InstArgs,
     };
     use crate::util::constants::{ASSET_EVENT_TYPE_KEY, ASSET_TYPE_KEY, NHASH};
@@ -116,13 +190,12 @@ mod tests {
         let mut deps = mock_provenance_dependencies();
         test_instantiate_success(deps.as_mut(), &InstArgs::default());
         let asset_definition = get_update_asset_definition();
-        let response = execute(
+        let response = execute_update_asset_definition(
             deps.as_mut(),
             mock_env(),
             empty_mock_info(DEFAULT_ADMIN_ADDRESS),
-            ExecuteMsg::UpdateAssetDefinition {
-                asset_definition: asset_definition.clone(),
-            },
+            asset_definition.clone(),
+            None,
         )
         .expect("expected the update asset checks to work correctly");
         assert!(
@@ -134,16 +207,12 @@ mod tests {
             response.attributes.len(),
             "updating an asset definition should produce the correct number of attributes",
         );
-        assert_eq!(
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
             EventType::UpdateAssetDefinition.event_name().as_str(),
-            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
-            "the correct event type should be emitted",
-        );
-        assert_eq!(
-            DEFAULT_ASSET_TYPE,
-            single_attribute_for_key(&response, ASSET_TYPE_KEY),
-            "the asset type attribute should be added correctly",
         );
+        assert_response_attributes_contain(&response, ASSET_TYPE_KEY, DEFAULT_ASSET_TYPE);
         test_asset_definition_was_updated_for_input(&asset_definition, &deps.as_ref());
     }
 
@@ -158,31 +227,29 @@ mod tests {
             msg.clone(),
         )
         .expect("expected the update asset definition function to return properly");
-        test_asset_definition_was_updated(&msg.asset_definition, &deps.as_ref());
+        test_asset_definition_was_updated_for_input(&msg.asset_definition, &deps.as_ref());
     }
 
     #[test]
     fn test_invalid_update_asset_definition_for_invalid_msg() {
         let mut deps = mock_provenance_dependencies();
         test_instantiate_success(deps.as_mut(), &InstArgs::default());
-        let msg = ExecuteMsg::UpdateAssetDefinition {
-            asset_definition: AssetDefinitionInputV3::new(
+        let error = execute_update_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN_ADDRESS), &[]),
+            AssetDefinitionInputV3::new(
                 DEFAULT_ASSET_TYPE,
                 DEFAULT_ASSET_TYPE_DISPLAY_NAME,
                 vec![],
                 None,
                 None,
             ),
-        };
-        let error = execute(
-            deps.as_mut(),
-            mock_env(),
-            message_info(&Addr::unchecked(DEFAULT_ADMIN_ADDRESS), &[]),
-            msg,
+            None,
         )
         .unwrap_err();
         assert!(
-            matches!(error, ContractError::InvalidMessageFields { .. }),
+            error.is_invalid_message_fields(),
             "expected an invalid asset definition to cause an InvalidMessageFields error, but got {:?}",
             error,
         );
@@ -200,7 +267,7 @@ mod tests {
         )
         .unwrap_err();
         assert!(
-            matches!(error, ContractError::Unauthorized { .. }),
+            error.is_unauthorized(),
             "expected the unauthorized response to be returned when a different address than the admin is the sender, but got error: {:?}",
             error,
         );
@@ -230,7 +297,7 @@ mod tests {
     fn test_invalid_update_asset_definition_for_missing_loan_type() {
         let mut deps = mock_provenance_dependencies();
         test_instantiate_success(deps.as_mut(), &InstArgs::default());
-        let missing_asset_definition = AssetDefinitionV3::new(
+        let missing_asset_definition = AssetDefinitionInputV3::new(
             "nonexistent-type",
             "WHOAMI".to_some(),
             vec![VerifierDetailV2::new(
@@ -242,6 +309,8 @@ mod tests {
                 None,
                 None,
             )],
+            None,
+            None,
         );
         let error = update_asset_definition(
             deps.as_mut(),
@@ -256,6 +325,297 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_valid_partial_update_display_name_only() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        execute_update_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            AssetDefinitionInputV3::new(
+                DEFAULT_ASSET_TYPE,
+                "Renamed Asset".to_some(),
+                vec![],
+                None,
+                None,
+            ),
+            true.to_some(),
+        )
+        .expect("expected a partial update of only the display name to succeed");
+        test_asset_definition_was_updated(
+            &AssetDefinitionV3::builder()
+                .asset_type(DEFAULT_ASSET_TYPE)
+                .display_name("Renamed Asset".to_some())
+                .add_verifier(get_default_verifier_detail())
+                .build()
+                .expect("the expected definition should be built"),
+            &deps.as_ref(),
+        );
+    }
+
+    #[test]
+    fn test_valid_partial_update_verifiers_only() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let new_verifiers = get_update_asset_definition().verifiers;
+        update_asset_definition(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateAssetDefinitionV1::new_partial(AssetDefinitionInputV3::new(
+                DEFAULT_ASSET_TYPE,
+                None::<String>,
+                new_verifiers.clone(),
+                None,
+                None,
+            )),
+        )
+        .expect("expected a partial update of only the verifiers to succeed");
+        test_asset_definition_was_updated(
+            &AssetDefinitionV3 {
+                verifiers: new_verifiers,
+                ..get_default_asset_definition()
+            },
+            &deps.as_ref(),
+        );
+    }
+
+    #[test]
+    fn test_valid_partial_update_enabled_only() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        update_asset_definition(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateAssetDefinitionV1::new_partial(AssetDefinitionInputV3::new(
+                DEFAULT_ASSET_TYPE,
+                None::<String>,
+                vec![],
+                false.to_some(),
+                None,
+            )),
+        )
+        .expect("expected a partial update of only the enabled flag to succeed");
+        test_asset_definition_was_updated(
+            &AssetDefinitionV3::builder()
+                .asset_type(DEFAULT_ASSET_TYPE)
+                .display_name(DEFAULT_ASSET_TYPE_DISPLAY_NAME)
+                .add_verifier(get_default_verifier_detail())
+                .enabled(false)
+                .build()
+                .expect("the expected definition should be built"),
+            &deps.as_ref(),
+        );
+    }
+
+    #[test]
+    fn test_invalid_partial_update_for_invalid_merged_verifiers() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let mut invalid_verifier = get_default_verifier_detail();
+        invalid_verifier.address = String::new();
+        let error = update_asset_definition(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateAssetDefinitionV1::new_partial(AssetDefinitionInputV3::new(
+                DEFAULT_ASSET_TYPE,
+                None::<String>,
+                vec![invalid_verifier],
+                None,
+                None,
+            )),
+        )
+        .unwrap_err();
+        assert!(
+            error.is_invalid_message_fields(),
+            "expected an invalid merged definition to cause an InvalidMessageFields error, but got {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_partial_update_cannot_lower_max_verifiers_below_verifier_count() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = update_asset_definition(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateAssetDefinitionV1::new_partial(AssetDefinitionInputV3 {
+                max_verifiers: 0.to_some(),
+                ..AssetDefinitionInputV3::new(
+                    DEFAULT_ASSET_TYPE,
+                    None::<String>,
+                    vec![],
+                    None,
+                    None,
+                )
+            }),
+        )
+        .unwrap_err();
+        assert!(
+            error.is_invalid_message_fields(),
+            "expected a maximum below the existing verifier count to cause an InvalidMessageFields error, but got {:?}",
+            error,
+        );
+        test_asset_definition_was_updated(&get_default_asset_definition(), &deps.as_ref());
+        update_asset_definition(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateAssetDefinitionV1::new_partial(AssetDefinitionInputV3 {
+                max_verifiers: 1.to_some(),
+                ..AssetDefinitionInputV3::new(
+                    DEFAULT_ASSET_TYPE,
+                    None::<String>,
+                    vec![],
+                    None,
+                    None,
+                )
+            }),
+        )
+        .expect("expected a maximum equal to the existing verifier count to be accepted");
+        test_asset_definition_was_updated(
+            &AssetDefinitionV3 {
+                max_verifiers: 1.to_some(),
+                ..get_default_asset_definition()
+            },
+            &deps.as_ref(),
+        );
+    }
+
+    #[test]
+    fn test_invalid_partial_update_for_missing_asset_type() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = update_asset_definition(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateAssetDefinitionV1::new_partial(AssetDefinitionInputV3::new(
+                "nonexistent-type",
+                "WHOAMI".to_some(),
+                vec![],
+                None,
+                None,
+            )),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::RecordNotFound { .. }),
+            "expected the not found response to be returned when a partial update is attempted for a definition that does not exist, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_update_asset_definition_for_frozen_definition() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        execute_freeze_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+        )
+        .expect("expected the asset definition to be frozen");
+        let error = execute_update_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            get_default_asset_definition_input(),
+            None,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::AssetDefinitionFrozen { ref asset_type } if asset_type == DEFAULT_ASSET_TYPE),
+            "expected the frozen error to be returned when updating a frozen definition, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_update_asset_definition_retains_deprecation() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        execute_deprecate_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            None::<String>,
+        )
+        .expect("expected the asset definition to be deprecated");
+        for partial in [None, true.to_some()] {
+            execute_update_asset_definition(
+                deps.as_mut(),
+                mock_env(),
+                empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+                get_default_asset_definition_input(),
+                partial,
+            )
+            .expect("expected the update to succeed");
+            assert!(
+                load_asset_definition_by_type_v3(deps.as_ref().storage, DEFAULT_ASSET_TYPE)
+                    .expect("expected the asset definition to exist")
+                    .deprecated,
+                "the asset definition should remain deprecated after an update with partial {:?}",
+                partial,
+            );
+        }
+    }
+
+    #[test]
+    fn test_update_asset_definition_inherits_scope_owner_must_match_requestor() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(
+            deps.as_mut(),
+            &InstArgs::with_asset_definitions(vec![AssetDefinitionInputV3 {
+                scope_owner_must_match_requestor: true.to_some(),
+                ..get_default_asset_definition_input()
+            }]),
+        );
+        for partial in [None, true.to_some()] {
+            execute_update_asset_definition(
+                deps.as_mut(),
+                mock_env(),
+                empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+                get_default_asset_definition_input(),
+                partial,
+            )
+            .expect("expected the update to succeed");
+            assert!(
+                load_scope_owner_must_match_requestor(&deps.as_ref()),
+                "the omitted flag should be inherited after an update with partial {:?}",
+                partial,
+            );
+        }
+        for partial in [None, true.to_some()] {
+            for required in [false, true] {
+                execute_update_asset_definition(
+                    deps.as_mut(),
+                    mock_env(),
+                    empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+                    AssetDefinitionInputV3 {
+                        scope_owner_must_match_requestor: required.to_some(),
+                        ..get_default_asset_definition_input()
+                    },
+                    partial,
+                )
+                .expect("expected the update to succeed");
+                assert_eq!(
+                    required,
+                    load_scope_owner_must_match_requestor(&deps.as_ref()),
+                    "the provided flag should overwrite the existing value after an update with partial {:?}",
+                    partial,
+                );
+            }
+        }
+    }
+
+    fn load_scope_owner_must_match_requestor(deps: &Deps) -> bool {
+        load_asset_definition_by_type_v3(deps.storage, DEFAULT_ASSET_TYPE)
+            .expect("expected the asset definition to exist")
+            .scope_owner_must_match_requestor
+    }
+
     fn test_asset_definition_was_updated_for_input(input: &AssetDefinitionInputV3, deps: &Deps) {
         test_asset_definition_was_updated(&input.as_asset_definition(), deps)
     }
@@ -292,13 +652,12 @@ mod tests {
             None,
             None,
         );
-        validate_asset_definition_input(&def).expect("expected the asset definition to be valid");
+        validate_asset_definition_input(&def, false)
+            .expect("expected the asset definition to be valid");
         def
     }
 
     fn get_valid_update_asset_definition() -> UpdateAssetDefinitionV1 {
-        UpdateAssetDefinitionV1 {
-            asset_definition: get_update_asset_definition().into_asset_definition(),
-        }
+        UpdateAssetDefinitionV1::new(get_update_asset_definition())
     }
 }
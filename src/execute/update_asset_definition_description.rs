@@ -0,0 +1,242 @@
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+use crate::core::state::{load_asset_definition_by_type_v3, replace_asset_definition_v3};
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, EntryPointResponse},
+        contract_helpers::{check_admin_only, check_funds_are_empty},
+        event_attributes::{EventAttributes, EventType},
+    },
+};
+
+/// A transformation of [ExecuteMsg::UpdateAssetDefinitionDescription](crate::core::msg::ExecuteMsg::UpdateAssetDefinitionDescription)
+/// for ease of use in the underlying [update_asset_definition_description](self::update_asset_definition_description) function.
+///
+/// # Parameters
+///
+/// * `asset_type` The unique identifier for the target [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3),
+/// keyed on its [asset_type](crate::core::types::asset_definition::AssetDefinitionV3::asset_type)
+/// property.
+/// * `display_name` The new [display_name](crate::core::types::asset_definition::AssetDefinitionV3::display_name)
+/// of the asset definition.  A None value clears the existing display name.
+#[derive(Clone, PartialEq, Eq)]
+pub struct UpdateAssetDefinitionDescriptionV1 {
+    pub asset_type: String,
+    pub display_name: Option<String>,
+}
+impl UpdateAssetDefinitionDescriptionV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_type` The unique identifier for the target [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3),
+    /// keyed on its [asset_type](crate::core::types::asset_definition::AssetDefinitionV3::asset_type)
+    /// property.
+    /// * `display_name` The new [display_name](crate::core::types::asset_definition::AssetDefinitionV3::display_name)
+    /// of the asset definition.  A None value clears the existing display name.
+    pub fn new<S: Into<String>>(asset_type: S, display_name: Option<String>) -> Self {
+        UpdateAssetDefinitionDescriptionV1 {
+            asset_type: asset_type.into(),
+            display_name,
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [UpdateAssetDefinitionDescription](crate::core::msg::ExecuteMsg::UpdateAssetDefinitionDescription)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<UpdateAssetDefinitionDescriptionV1> {
+        match msg {
+            ExecuteMsg::UpdateAssetDefinitionDescription {
+                asset_type,
+                display_name,
+            } => UpdateAssetDefinitionDescriptionV1::new(asset_type, display_name).to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::UpdateAssetDefinitionDescription".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+impl TryFrom<ExecuteMsg> for UpdateAssetDefinitionDescriptionV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        UpdateAssetDefinitionDescriptionV1::from_execute_msg(msg)
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::UpdateAssetDefinitionDescription](crate::core::msg::ExecuteMsg::UpdateAssetDefinitionDescription)
+/// message is provided.  Replaces the [display_name](crate::core::types::asset_definition::AssetDefinitionV3::display_name)
+/// of an existing asset definition, leaving all of its other values, including its verifiers,
+/// untouched.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the update asset definition description v1 struct, provided by conversion
+/// from an [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn update_asset_definition_description(
+    deps: DepsMut,
+    info: MessageInfo,
+    msg: UpdateAssetDefinitionDescriptionV1,
+) -> EntryPointResponse {
+    check_admin_only(&deps.as_ref(), &info)?;
+    check_funds_are_empty(&info)?;
+    let mut asset_definition = load_asset_definition_by_type_v3(deps.storage, &msg.asset_type)?;
+    asset_definition.check_not_frozen()?;
+    asset_definition.display_name = msg.display_name;
+    replace_asset_definition_v3(deps.storage, &asset_definition)?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::UpdateAssetDefinitionDescription)
+                .set_asset_type(&msg.asset_type)
+                .set_new_value(
+                    asset_definition
+                        .display_name
+                        .unwrap_or_else(|| "none".to_string()),
+                ),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::state::load_asset_definition_by_type_v3;
+    use crate::core::types::asset_definition::AssetDefinitionV3;
+    use crate::testutil::execute_utilities::execute_update_asset_definition_description;
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_SENDER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, empty_mock_info, test_instantiate_success, InstArgs,
+        MockOwnedDeps,
+    };
+    use crate::util::constants::{ASSET_EVENT_TYPE_KEY, ASSET_TYPE_KEY, NEW_VALUE_KEY};
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    #[test]
+    fn test_update_asset_definition_description_renames_definition() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let original_definition = load_default_definition(&deps);
+        let response = execute_update_asset_definition_description(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            "Renamed Asset".to_some(),
+        )
+        .expect("the admin should be able to rename an asset definition");
+        assert!(
+            response.messages.is_empty(),
+            "renaming an asset definition should not require messages",
+        );
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::UpdateAssetDefinitionDescription.event_name(),
+        );
+        assert_response_attributes_contain(&response, ASSET_TYPE_KEY, DEFAULT_ASSET_TYPE);
+        assert_response_attributes_contain(&response, NEW_VALUE_KEY, "Renamed Asset");
+        assert_eq!(
+            AssetDefinitionV3 {
+                display_name: "Renamed Asset".to_string().to_some(),
+                ..original_definition
+            },
+            load_default_definition(&deps),
+            "only the display name of the asset definition should be changed",
+        );
+    }
+
+    #[test]
+    fn test_update_asset_definition_description_clears_display_name() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let original_definition = load_default_definition(&deps);
+        assert!(
+            original_definition.display_name.is_some(),
+            "sanity check: the default asset definition should have a display name",
+        );
+        let response = execute_update_asset_definition_description(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            None,
+        )
+        .expect("the admin should be able to clear an asset definition's display name");
+        assert_response_attributes_contain(&response, NEW_VALUE_KEY, "none");
+        let updated_definition = load_default_definition(&deps);
+        assert_eq!(
+            None, updated_definition.display_name,
+            "the display name should be removed from the asset definition",
+        );
+        assert_eq!(
+            original_definition.verifiers, updated_definition.verifiers,
+            "the verifiers should be unmodified after clearing the display name",
+        );
+    }
+
+    #[test]
+    fn test_update_asset_definition_description_by_non_admin_fails() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let original_definition = load_default_definition(&deps);
+        let error = execute_update_asset_definition_description(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            "Renamed Asset".to_some(),
+        )
+        .expect_err("a non-admin should not be able to rename an asset definition");
+        assert!(
+            error.is_unauthorized(),
+            "expected the unauthorized error to be returned when the sender is not the admin, but got: {:?}",
+            error,
+        );
+        assert_eq!(
+            original_definition,
+            load_default_definition(&deps),
+            "the asset definition should be unchanged after a rejected rename",
+        );
+    }
+
+    #[test]
+    fn test_update_asset_definition_description_rejects_blank_display_name() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = execute_update_asset_definition_description(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            "  ".to_some(),
+        )
+        .expect_err("a blank display name should be rejected");
+        assert!(
+            error.is_invalid_message_fields(),
+            "expected the invalid message fields error to be returned for a blank display name, but got: {:?}",
+            error,
+        );
+    }
+
+    fn load_default_definition(deps: &MockOwnedDeps) -> AssetDefinitionV3 {
+        load_asset_definition_by_type_v3(deps.as_ref().storage, DEFAULT_ASSET_TYPE)
+            .expect("the default asset definition should be available")
+    }
+}
@@ -65,6 +65,13 @@ impl UpdateAssetVerifierV1 {
         }
     }
 }
+impl TryFrom<ExecuteMsg> for UpdateAssetVerifierV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        UpdateAssetVerifierV1::from_execute_msg(msg)
+    }
+}
 
 /// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::UpdateAssetVerifier](crate::core::msg::ExecuteMsg::UpdateAssetVerifier)
 /// message is provided.  Replaces an existing [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2)
@@ -92,31 +99,35 @@ pub fn update_asset_verifier(
         }
         .to_err();
     }
+    asset_definition.check_not_frozen()?;
     let verifier_address = msg.verifier.address.clone();
     // If a single verifier for the given address cannot be found, data is either corrupt, or the
     // verifier does not exist.  Given validation upfront prevents multiple verifiers with the
     // same address from existing on an asset definition, this generally will indicate that the
     // verifier is outright missing
-    if !asset_definition
-        .verifiers
-        .iter()
-        .any(|v| v.address == verifier_address)
-    {
-        return ContractError::NotFound {
-            explanation: format!(
-                "verifier with address {} not found for asset definition for type {}. Trying adding this verifier instead",
-                msg.verifier.address, asset_definition.asset_type
-            ),
+    let existing_enabled = match asset_definition.get_verifier_detail_opt(&verifier_address) {
+        Some(existing_verifier) => existing_verifier.enabled,
+        None => {
+            return ContractError::NotFound {
+                explanation: format!(
+                    "verifier with address {} not found for asset definition for type {}. Trying adding this verifier instead",
+                    msg.verifier.address, asset_definition.asset_type
+                ),
+            }
+            .to_err();
         }
-        .to_err();
-    }
+    };
     // Declare the attributes up-front before values are moved
     let attributes = EventAttributes::new(EventType::UpdateAssetVerifier)
         .set_asset_type(&asset_definition.asset_type)
         .set_verifier(&msg.verifier.address);
+    // Verifiers may update their own details, so the enabled flag is only ever changed by the
+    // admin via the ToggleAssetVerifier route
+    let mut verifier = msg.verifier;
+    verifier.enabled = existing_enabled;
     // Replace the existing verifier and save the result to the state
     asset_definition.verifiers =
-        replace_single_matching_vec_element(asset_definition.verifiers, msg.verifier, |v| {
+        replace_single_matching_vec_element(asset_definition.verifiers, verifier, |v| {
             v.address == verifier_address
         })?;
     replace_asset_definition_v3(deps.storage, &asset_definition)?;
@@ -126,19 +137,21 @@ pub fn update_asset_verifier(
 
 #[cfg(test)]
 mod tests {
-    use crate::contract::execute;
     use crate::core::error::ContractError;
-    use crate::core::msg::ExecuteMsg;
     use crate::core::state::load_asset_definition_by_type_v3;
     use crate::core::types::fee_destination::FeeDestinationV2;
     use crate::core::types::verifier_detail::VerifierDetailV2;
     use crate::execute::update_asset_verifier::{update_asset_verifier, UpdateAssetVerifierV1};
+    use crate::testutil::execute_utilities::{
+        execute_freeze_asset_definition, execute_toggle_asset_verifier,
+        execute_update_asset_verifier,
+    };
     use crate::testutil::test_constants::{
         DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS,
     };
     use crate::testutil::test_utilities::{
-        empty_mock_info, get_default_entity_detail, single_attribute_for_key,
-        test_instantiate_success, InstArgs,
+        assert_response_attributes_contain, empty_mock_info, get_default_entity_detail,
+        get_default_verifier_detail, test_instantiate_success, InstArgs,
     };
     use crate::util::constants::{
         ASSET_EVENT_TYPE_KEY, ASSET_TYPE_KEY, NHASH, VERIFIER_ADDRESS_KEY,
@@ -157,14 +170,12 @@ mod tests {
             let mut deps = mock_provenance_dependencies();
             test_instantiate_success(deps.as_mut(), &InstArgs::default());
             let verifier = get_valid_update_verifier();
-            let response = execute(
+            let response = execute_update_asset_verifier(
                 deps.as_mut(),
                 mock_env(),
                 empty_mock_info(sender_address),
-                ExecuteMsg::UpdateAssetVerifier {
-                    asset_type: DEFAULT_ASSET_TYPE.to_string(),
-                    verifier: verifier.clone(),
-                },
+                DEFAULT_ASSET_TYPE,
+                verifier.clone(),
             )
             .expect("expected the update verifier checks to work correctly");
             assert!(
@@ -176,21 +187,13 @@ mod tests {
                 response.attributes.len(),
                 "the correct number of attributes should be produced",
             );
-            assert_eq!(
+            assert_response_attributes_contain(
+                &response,
+                ASSET_EVENT_TYPE_KEY,
                 EventType::UpdateAssetVerifier.event_name().as_str(),
-                single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
-                "expected the proper event type to be emitted",
-            );
-            assert_eq!(
-                DEFAULT_ASSET_TYPE,
-                single_attribute_for_key(&response, ASSET_TYPE_KEY),
-                "expected the update asset verifier main key to include the asset type",
-            );
-            assert_eq!(
-                &verifier.address,
-                single_attribute_for_key(&response, VERIFIER_ADDRESS_KEY),
-                "expected the verifier's address to be the value for the address key",
             );
+            assert_response_attributes_contain(&response, ASSET_TYPE_KEY, DEFAULT_ASSET_TYPE);
+            assert_response_attributes_contain(&response, VERIFIER_ADDRESS_KEY, &verifier.address);
             test_default_verifier_was_updated(&verifier, &deps.as_ref());
         }
     }
@@ -212,19 +215,17 @@ mod tests {
     fn test_invalid_update_asset_verifier_for_invalid_asset_type() {
         let mut deps = mock_provenance_dependencies();
         test_instantiate_success(deps.as_mut(), &InstArgs::default());
-        let error = execute(
+        let error = execute_update_asset_verifier(
             deps.as_mut(),
             mock_env(),
             message_info(&Addr::unchecked(DEFAULT_ADMIN_ADDRESS), &[]),
-            ExecuteMsg::UpdateAssetVerifier {
-                // Invalid because the asset type is missing
-                asset_type: String::new(),
-                verifier: get_valid_update_verifier(),
-            },
+            // Invalid because the asset type is missing
+            String::new(),
+            get_valid_update_verifier(),
         )
         .unwrap_err();
         assert!(
-            matches!(error, ContractError::InvalidMessageFields { .. }),
+            error.is_invalid_message_fields(),
             "when an invalid asset type is provided to execute, the invalid message fields error should be returned, but got: {:?}",
             error,
         );
@@ -234,27 +235,25 @@ mod tests {
     fn test_invalid_update_asset_verifier_for_invalid_msg() {
         let mut deps = mock_provenance_dependencies();
         test_instantiate_success(deps.as_mut(), &InstArgs::default());
-        let error = execute(
+        let error = execute_update_asset_verifier(
             deps.as_mut(),
             mock_env(),
             message_info(&Addr::unchecked(DEFAULT_ADMIN_ADDRESS), &[]),
-            ExecuteMsg::UpdateAssetVerifier {
-                asset_type: DEFAULT_ASSET_TYPE.to_string(),
-                verifier: VerifierDetailV2::new(
-                    // Invalid because the address is blank
-                    "",
-                    Uint128::zero(),
-                    NHASH,
-                    vec![],
-                    None,
-                    None,
-                    None,
-                ),
-            },
+            DEFAULT_ASSET_TYPE,
+            VerifierDetailV2::new(
+                // Invalid because the address is blank
+                "",
+                Uint128::zero(),
+                NHASH,
+                vec![],
+                None,
+                None,
+                None,
+            ),
         )
         .unwrap_err();
         assert!(
-            matches!(error, ContractError::InvalidMessageFields { .. }),
+            error.is_invalid_message_fields(),
             "when an invalid verifier is provided to execute, the invalid message fields error should be returned, but got: {:?}",
             error,
         );
@@ -271,7 +270,7 @@ mod tests {
         )
         .unwrap_err();
         assert!(
-            matches!(error, ContractError::Unauthorized { .. }),
+            error.is_unauthorized(),
             "expected the unauthorized response to be returned when a different address than the admin is the sender, but got: {:?}",
             error,
         );
@@ -319,12 +318,72 @@ mod tests {
         )
         .unwrap_err();
         assert!(
-            matches!(error, ContractError::NotFound { .. }),
+            error.is_not_found(),
             "the not found error should be returned when the provided update verifier cannot be located in the asset definition, but got: {:?}",
             error,
         );
     }
 
+    #[test]
+    fn test_update_asset_verifier_does_not_change_enabled_value() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        execute_toggle_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            DEFAULT_VERIFIER_ADDRESS,
+            false,
+        )
+        .expect("expected the verifier to be disabled");
+        // A disabled verifier should not be able to re-enable itself by updating its own details
+        let msg = get_valid_update_verifier_msg();
+        assert!(
+            msg.verifier.enabled,
+            "sanity check: the update should request an enabled verifier",
+        );
+        update_asset_verifier(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            msg.clone(),
+        )
+        .expect("expected the update verifier function to return properly");
+        test_default_verifier_was_updated(
+            &VerifierDetailV2 {
+                enabled: false,
+                ..msg.verifier
+            },
+            &deps.as_ref(),
+        );
+    }
+
+    #[test]
+    fn test_invalid_update_asset_verifier_for_frozen_definition() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        execute_freeze_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+        )
+        .expect("expected the asset definition to be frozen");
+        let error = execute_update_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            get_default_verifier_detail(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::AssetDefinitionFrozen { ref asset_type } if asset_type == DEFAULT_ASSET_TYPE),
+            "expected the frozen error to be returned when updating a verifier on a frozen definition, but got: {:?}",
+            error,
+        );
+    }
+
     fn test_default_verifier_was_updated(verifier: &VerifierDetailV2, deps: &Deps) {
         let state_def = load_asset_definition_by_type_v3(deps.storage, DEFAULT_ASSET_TYPE)
             .expect("expected the default asset type to be stored in the state");
@@ -351,7 +410,8 @@ mod tests {
             None,
             None,
         );
-        validate_verifier(&verifier).expect("expected the verifier to pass validation");
+        validate_verifier(&verifier, false, false)
+            .expect("expected the verifier to pass validation");
         verifier
     }
 
@@ -0,0 +1,198 @@
+use crate::core::state::STATE_V2;
+use crate::util::aliases::EntryPointResponse;
+use crate::util::contract_helpers::{check_admin_only, check_funds_are_empty};
+use crate::util::event_attributes::{EventAttributes, EventType};
+
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::UpdateOsGatewayGrantPrefix](crate::core::msg::ExecuteMsg::UpdateOsGatewayGrantPrefix)
+/// message is provided.  Replaces the contract's [os_gateway_grant_prefix](crate::core::state::StateV2::os_gateway_grant_prefix).
+/// Grants emitted before the change retain their original ids, and are revoked with those same ids
+/// when their verifications complete.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `prefix` The new grant id prefix.  A None value restores the default grant id format.
+pub fn update_os_gateway_grant_prefix(
+    deps: DepsMut,
+    info: MessageInfo,
+    prefix: Option<String>,
+) -> EntryPointResponse {
+    check_admin_only(&deps.as_ref(), &info)?;
+    check_funds_are_empty(&info)?;
+    let mut state = STATE_V2.load(deps.storage)?;
+    state.os_gateway_grant_prefix = prefix;
+    STATE_V2.save(deps.storage, &state)?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::UpdateOsGatewayGrantPrefix).set_new_value(
+                state
+                    .os_gateway_grant_prefix
+                    .unwrap_or_else(|| "none".to_string()),
+            ),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use os_gateway_contract_attributes::OS_GATEWAY_KEYS;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::state::STATE_V2;
+    use crate::service::asset_meta_repository::AssetMetaRepository;
+    use crate::service::asset_meta_service::AssetMetaService;
+    use crate::testutil::execute_utilities::execute_update_os_gateway_grant_prefix;
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS, DEFAULT_SENDER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, empty_mock_info, setup_no_attribute_response,
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps,
+    };
+    use crate::testutil::verify_asset_helpers::{test_verify_asset, TestVerifyAsset};
+    use crate::util::aliases::EntryPointResponse;
+    use crate::util::constants::{ASSET_EVENT_TYPE_KEY, NEW_VALUE_KEY};
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    #[test]
+    fn test_update_os_gateway_grant_prefix() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        assert_eq!(
+            None,
+            load_prefix(&deps),
+            "no prefix should be set by default",
+        );
+        let response = update_prefix(&mut deps, DEFAULT_ADMIN_ADDRESS, "testnet".to_some())
+            .expect("the admin should be able to set the grant prefix");
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::UpdateOsGatewayGrantPrefix.event_name(),
+        );
+        assert_response_attributes_contain(&response, NEW_VALUE_KEY, "testnet");
+        assert_eq!(
+            "testnet".to_string().to_some(),
+            load_prefix(&deps),
+            "the prefix should be stored in the contract's state",
+        );
+        let response = update_prefix(&mut deps, DEFAULT_ADMIN_ADDRESS, None)
+            .expect("the admin should be able to clear the grant prefix");
+        assert_response_attributes_contain(&response, NEW_VALUE_KEY, "none");
+        assert_eq!(
+            None,
+            load_prefix(&deps),
+            "the prefix should be removed from the contract's state",
+        );
+    }
+
+    #[test]
+    fn test_update_os_gateway_grant_prefix_by_non_admin_fails() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        let err = update_prefix(&mut deps, DEFAULT_SENDER_ADDRESS, "testnet".to_some())
+            .expect_err("a non-admin should not be able to set the grant prefix");
+        assert!(
+            matches!(err, ContractError::Unauthorized { .. }),
+            "expected an unauthorized error, but got: {:?}",
+            err,
+        );
+        assert_eq!(None, load_prefix(&deps), "the prefix should remain unset");
+    }
+
+    #[test]
+    fn test_update_os_gateway_grant_prefix_rejects_blank_prefix() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        let err = update_prefix(&mut deps, DEFAULT_ADMIN_ADDRESS, "  ".to_some())
+            .expect_err("a blank prefix should be rejected");
+        assert!(
+            matches!(err, ContractError::InvalidMessageFields { .. }),
+            "expected an invalid message fields error, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_grant_prefix_is_used_in_onboarding_grant_ids() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(
+            &mut deps,
+            &InstArgs {
+                os_gateway_grant_prefix: "mainnet".to_string().to_some(),
+                ..InstArgs::default()
+            },
+        );
+        setup_no_attribute_response(&mut deps, None);
+        let response = test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the asset onboard to succeed");
+        assert_eq!(
+            format!("mainnet-{}-{}", DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS),
+            single_attribute_for_key(&response, OS_GATEWAY_KEYS.access_grant_id),
+            "the instantiated prefix should be prepended to the grant id",
+        );
+    }
+
+    #[test]
+    fn test_grant_prefix_change_does_not_affect_revocation_of_existing_grants() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(
+            &mut deps,
+            &InstArgs {
+                os_gateway_grant_prefix: "mainnet".to_string().to_some(),
+                ..InstArgs::default()
+            },
+        );
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the asset onboard to succeed");
+        let original_grant_id = format!("mainnet-{}-{}", DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS);
+        assert_eq!(
+            original_grant_id.to_owned().to_some(),
+            AssetMetaService::new(deps.as_mut())
+                .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+                .expect("the scope attribute should exist after onboarding")
+                .os_gateway_grant_id,
+            "the grant id should be recorded on the scope attribute",
+        );
+        update_prefix(&mut deps, DEFAULT_ADMIN_ADDRESS, "testnet".to_some())
+            .expect("the admin should be able to change the grant prefix");
+        let response = test_verify_asset(&mut deps, &mock_env(), TestVerifyAsset::default())
+            .expect("expected the asset verification to succeed");
+        assert_eq!(
+            original_grant_id,
+            single_attribute_for_key(&response, OS_GATEWAY_KEYS.access_grant_id),
+            "the access should be revoked with the grant id issued during onboarding",
+        );
+    }
+
+    fn update_prefix(
+        deps: &mut MockOwnedDeps,
+        sender: &str,
+        prefix: Option<&str>,
+    ) -> EntryPointResponse {
+        execute_update_os_gateway_grant_prefix(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(sender),
+            prefix,
+        )
+    }
+
+    fn load_prefix(deps: &MockOwnedDeps) -> Option<String> {
+        STATE_V2
+            .load(deps.as_ref().storage)
+            .expect("the contract state should exist")
+            .os_gateway_grant_prefix
+    }
+}
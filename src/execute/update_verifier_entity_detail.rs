@@ -0,0 +1,670 @@
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+use crate::core::state::{load_asset_definition_by_type_v3, replace_asset_definition_v3, STATE_V2};
+use crate::core::types::entity_detail::EntityDetail;
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, EntryPointResponse},
+        contract_helpers::{check_admin_only, check_funds_are_empty},
+        event_attributes::{EventAttributes, EventType},
+    },
+};
+
+/// A transformation of [ExecuteMsg::UpdateVerifierEntityDetail](crate::core::msg::ExecuteMsg::UpdateVerifierEntityDetail)
+/// for ease of use in the underlying [update_verifier_entity_detail](self::update_verifier_entity_detail) function.
+///
+/// # Parameters
+///
+/// * `asset_type` The unique identifier for the [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
+/// that contains the target verifier.
+/// * `verifier_address` The bech32 address of the target [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2).
+/// * `entity_detail` The new value of the verifier's [entity_detail](crate::core::types::verifier_detail::VerifierDetailV2::entity_detail).
+/// A None value removes the existing entity detail.
+#[derive(Clone, PartialEq, Eq)]
+pub struct UpdateVerifierEntityDetailV1 {
+    pub asset_type: String,
+    pub verifier_address: String,
+    pub entity_detail: Option<EntityDetail>,
+}
+impl UpdateVerifierEntityDetailV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_type` The unique identifier for the [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
+    /// that contains the target verifier.
+    /// * `verifier_address` The bech32 address of the target [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2).
+    /// * `entity_detail` The new value of the verifier's [entity_detail](crate::core::types::verifier_detail::VerifierDetailV2::entity_detail).
+    pub fn new<S1: Into<String>, S2: Into<String>>(
+        asset_type: S1,
+        verifier_address: S2,
+        entity_detail: Option<EntityDetail>,
+    ) -> Self {
+        UpdateVerifierEntityDetailV1 {
+            asset_type: asset_type.into(),
+            verifier_address: verifier_address.into(),
+            entity_detail,
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [UpdateVerifierEntityDetail](crate::core::msg::ExecuteMsg::UpdateVerifierEntityDetail)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<UpdateVerifierEntityDetailV1> {
+        match msg {
+            ExecuteMsg::UpdateVerifierEntityDetail {
+                asset_type,
+                verifier_address,
+                entity_detail,
+            } => UpdateVerifierEntityDetailV1::new(asset_type, verifier_address, entity_detail)
+                .to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::UpdateVerifierEntityDetail".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+impl TryFrom<ExecuteMsg> for UpdateVerifierEntityDetailV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        UpdateVerifierEntityDetailV1::from_execute_msg(msg)
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::UpdateVerifierEntityDetail](crate::core::msg::ExecuteMsg::UpdateVerifierEntityDetail)
+/// message is provided.  Replaces the [entity_detail](crate::core::types::verifier_detail::VerifierDetailV2::entity_detail)
+/// of a single verifier without modifying any of its other values.  Unlike [update_asset_verifier](crate::execute::update_asset_verifier),
+/// the verifier's fee configuration is neither required nor re-validated.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the update verifier entity detail v1 struct, provided by conversion from
+/// an [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn update_verifier_entity_detail(
+    deps: DepsMut,
+    info: MessageInfo,
+    msg: UpdateVerifierEntityDetailV1,
+) -> EntryPointResponse {
+    check_admin_only(&deps.as_ref(), &info)?;
+    check_funds_are_empty(&info)?;
+    let mut asset_definition = load_asset_definition_by_type_v3(deps.storage, &msg.asset_type)?;
+    asset_definition.check_not_frozen()?;
+    let verifier = match asset_definition
+        .verifiers
+        .iter_mut()
+        .find(|verifier| verifier.address == msg.verifier_address)
+    {
+        Some(verifier) => verifier,
+        None => {
+            return ContractError::NotFound {
+                explanation: format!(
+                    "verifier with address {} not found for asset definition for type {}",
+                    msg.verifier_address, msg.asset_type
+                ),
+            }
+            .to_err();
+        }
+    };
+    verifier.entity_detail = msg.entity_detail;
+    replace_asset_definition_v3(deps.storage, &asset_definition)?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::UpdateVerifierEntityDetail)
+                .set_asset_type(&msg.asset_type)
+                .set_verifier(&msg.verifier_address),
+        )
+        .to_ok()
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::SetRequireEntityDetail](crate::core::msg::ExecuteMsg::SetRequireEntityDetail)
+/// message is provided.  Replaces the contract's [require_entity_detail_for_verifiers](crate::core::state::StateV2::require_entity_detail_for_verifiers)
+/// value.  Verifiers that are already stored are never re-validated, even if they lack an entity
+/// detail.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `required` Whether or not verifiers must include an entity detail with a name.
+pub fn set_require_entity_detail(
+    deps: DepsMut,
+    info: MessageInfo,
+    required: bool,
+) -> EntryPointResponse {
+    check_admin_only(&deps.as_ref(), &info)?;
+    check_funds_are_empty(&info)?;
+    let mut state = STATE_V2.load(deps.storage)?;
+    state.require_entity_detail_for_verifiers = required;
+    STATE_V2.save(deps.storage, &state)?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::SetRequireEntityDetail).set_new_value(required),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{message_info, mock_env};
+    use cosmwasm_std::{Addr, Deps};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::state::{load_asset_definition_by_type_v3, STATE_V2};
+    use crate::core::types::asset_definition::AssetDefinitionInputV3;
+    use crate::core::types::entity_detail::EntityDetail;
+    use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::execute::clone_asset_definition::CloneAssetDefinitionV1;
+    use crate::testutil::execute_utilities::{
+        execute_add_asset_definition, execute_add_asset_verifier, execute_clone_asset_definition,
+        execute_freeze_asset_definition, execute_set_require_entity_detail,
+        execute_update_asset_definition, execute_update_asset_verifier,
+        execute_update_verifier_entity_detail,
+    };
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_SECONDARY_ASSET_TYPE,
+        DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, empty_mock_info, get_default_asset_definition_input,
+        get_default_verifier_detail, mock_info_with_nhash, setup_no_attribute_response,
+        setup_test_suite, test_instantiate_success, InstArgs,
+    };
+    use crate::util::aliases::EntryPointResponse;
+    use crate::util::constants::{
+        ASSET_EVENT_TYPE_KEY, ASSET_TYPE_KEY, NEW_VALUE_KEY, VERIFIER_ADDRESS_KEY,
+    };
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{update_verifier_entity_detail, UpdateVerifierEntityDetailV1};
+
+    const SECOND_VERIFIER_ADDRESS: &str = "tp1hsqtppgy7mgd64q0uvk7q39qa7h8hp30urqs6n";
+
+    #[test]
+    fn test_valid_update_verifier_entity_detail_via_execute() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let new_entity_detail = get_new_entity_detail();
+        let response = execute_update_verifier_entity_detail(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            DEFAULT_VERIFIER_ADDRESS,
+            new_entity_detail.clone().to_some(),
+        )
+        .expect("the entity detail update should work correctly");
+        assert!(
+            response.messages.is_empty(),
+            "updating a verifier entity detail should not require messages",
+        );
+        assert_eq!(
+            3,
+            response.attributes.len(),
+            "updating a verifier entity detail should produce the correct number of attributes",
+        );
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::UpdateVerifierEntityDetail.event_name(),
+        );
+        assert_response_attributes_contain(&response, ASSET_TYPE_KEY, DEFAULT_ASSET_TYPE);
+        assert_response_attributes_contain(
+            &response,
+            VERIFIER_ADDRESS_KEY,
+            DEFAULT_VERIFIER_ADDRESS,
+        );
+        assert_eq!(
+            VerifierDetailV2 {
+                entity_detail: new_entity_detail.to_some(),
+                ..get_default_verifier_detail()
+            },
+            load_verifier(&deps.as_ref(), DEFAULT_VERIFIER_ADDRESS),
+            "only the entity detail should be changed, leaving the fee configuration untouched",
+        );
+    }
+
+    #[test]
+    fn test_update_verifier_entity_detail_clears_value_with_none() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        assert!(
+            load_verifier(&deps.as_ref(), DEFAULT_VERIFIER_ADDRESS)
+                .entity_detail
+                .is_some(),
+            "sanity check: the default verifier should have an entity detail",
+        );
+        update_verifier_entity_detail(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateVerifierEntityDetailV1::new(DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS, None),
+        )
+        .expect("clearing the entity detail should work correctly");
+        assert_eq!(
+            VerifierDetailV2 {
+                entity_detail: None,
+                ..get_default_verifier_detail()
+            },
+            load_verifier(&deps.as_ref(), DEFAULT_VERIFIER_ADDRESS),
+            "the entity detail should be removed without affecting any other verifier values",
+        );
+    }
+
+    #[test]
+    fn test_update_verifier_entity_detail_does_not_affect_other_verifiers() {
+        let mut deps = mock_provenance_dependencies();
+        let second_verifier = VerifierDetailV2 {
+            address: SECOND_VERIFIER_ADDRESS.to_string(),
+            ..get_default_verifier_detail()
+        };
+        test_instantiate_success(
+            deps.as_mut(),
+            &InstArgs::add_verifier_to_default(second_verifier.clone()),
+        );
+        update_verifier_entity_detail(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateVerifierEntityDetailV1::new(
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS,
+                get_new_entity_detail().to_some(),
+            ),
+        )
+        .expect("the entity detail update should work correctly");
+        assert_eq!(
+            second_verifier,
+            load_verifier(&deps.as_ref(), SECOND_VERIFIER_ADDRESS),
+            "the other verifier on the asset definition should be unchanged",
+        );
+    }
+
+    #[test]
+    fn test_invalid_update_verifier_entity_detail_for_invalid_msg() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = execute_update_verifier_entity_detail(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            String::new(),
+            None,
+        )
+        .unwrap_err();
+        assert!(
+            error.is_invalid_message_fields(),
+            "expected the invalid message fields error to be returned when the message is malformatted, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_update_verifier_entity_detail_for_invalid_sender() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        // Verifiers must not be able to change their own metadata
+        let error = update_verifier_entity_detail(
+            deps.as_mut(),
+            message_info(&Addr::unchecked(DEFAULT_VERIFIER_ADDRESS), &[]),
+            UpdateVerifierEntityDetailV1::new(
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS,
+                get_new_entity_detail().to_some(),
+            ),
+        )
+        .unwrap_err();
+        assert!(
+            error.is_unauthorized(),
+            "expected the unauthorized error to be returned when the sender is not the admin, but got: {:?}",
+            error,
+        );
+        assert_eq!(
+            get_default_verifier_detail(),
+            load_verifier(&deps.as_ref(), DEFAULT_VERIFIER_ADDRESS),
+            "the verifier should be unchanged after an unauthorized update",
+        );
+    }
+
+    #[test]
+    fn test_invalid_update_verifier_entity_detail_for_provided_funds() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = update_verifier_entity_detail(
+            deps.as_mut(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            UpdateVerifierEntityDetailV1::new(DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS, None),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when the sender provides funds, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_update_verifier_entity_detail_for_missing_verifier() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = update_verifier_entity_detail(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateVerifierEntityDetailV1::new(DEFAULT_ASSET_TYPE, SECOND_VERIFIER_ADDRESS, None),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotFound { .. }),
+            "expected the not found error to be returned, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_update_verifier_entity_detail_for_frozen_definition() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        execute_freeze_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+        )
+        .expect("expected the asset definition to be frozen");
+        let error = execute_update_verifier_entity_detail(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            DEFAULT_VERIFIER_ADDRESS,
+            None,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::AssetDefinitionFrozen { ref asset_type } if asset_type == DEFAULT_ASSET_TYPE),
+            "expected the frozen error to be returned when updating a verifier entity detail on a frozen definition, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_set_require_entity_detail() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        assert!(
+            !load_require_entity_detail(&deps.as_ref()),
+            "entity details should not be required by default",
+        );
+        let response = execute_set_require_entity_detail(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            true,
+        )
+        .expect("the admin should be able to require entity details");
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::SetRequireEntityDetail.event_name(),
+        );
+        assert_response_attributes_contain(&response, NEW_VALUE_KEY, "true");
+        assert!(
+            load_require_entity_detail(&deps.as_ref()),
+            "the requirement should be stored in the contract's state",
+        );
+    }
+
+    #[test]
+    fn test_set_require_entity_detail_by_non_admin_fails() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = execute_set_require_entity_detail(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+            true,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected an unauthorized error, but got: {:?}",
+            error,
+        );
+        assert!(
+            !load_require_entity_detail(&deps.as_ref()),
+            "the requirement should remain unchanged",
+        );
+    }
+
+    #[test]
+    fn test_required_entity_detail_rejects_verifiers_without_details() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let undetailed_verifier = VerifierDetailV2 {
+            address: SECOND_VERIFIER_ADDRESS.to_string(),
+            entity_detail: None,
+            ..get_default_verifier_detail()
+        };
+        execute_add_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            undetailed_verifier.clone(),
+        )
+        .expect("a verifier without an entity detail should be accepted before the requirement");
+        execute_set_require_entity_detail(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            true,
+        )
+        .expect("the admin should be able to require entity details");
+        assert_missing_entity_detail(
+            execute_update_asset_verifier(
+                deps.as_mut(),
+                mock_env(),
+                empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+                DEFAULT_ASSET_TYPE,
+                undetailed_verifier.clone(),
+            ),
+            "verifier:entity_detail: must be provided when entity details are required",
+        );
+        assert_missing_entity_detail(
+            execute_add_asset_verifier(
+                deps.as_mut(),
+                mock_env(),
+                empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+                DEFAULT_ASSET_TYPE,
+                VerifierDetailV2 {
+                    address: "tp1j8vwrj3g8ghemg2ajgpr7d4fdf5xssx8dxq8ye".to_string(),
+                    entity_detail: EntityDetail {
+                        name: "   ".to_string().to_some(),
+                        ..get_new_entity_detail()
+                    }
+                    .to_some(),
+                    ..get_default_verifier_detail()
+                },
+            ),
+            "verifier:entity_detail:name: must not be blank when entity details are required",
+        );
+        assert_missing_entity_detail(
+            execute_add_asset_definition(
+                deps.as_mut(),
+                mock_env(),
+                empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+                AssetDefinitionInputV3 {
+                    asset_type: DEFAULT_SECONDARY_ASSET_TYPE.to_string(),
+                    verifiers: vec![undetailed_verifier.clone()],
+                    ..get_default_asset_definition_input()
+                },
+            ),
+            "verifier:entity_detail: must be provided when entity details are required",
+        );
+        execute_update_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            VerifierDetailV2 {
+                entity_detail: get_new_entity_detail().to_some(),
+                ..undetailed_verifier
+            },
+        )
+        .expect("a verifier with a named entity detail should be accepted");
+    }
+
+    #[test]
+    fn test_required_entity_detail_does_not_invalidate_existing_verifiers() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(
+            &mut deps,
+            &InstArgs::with_single_verifier(VerifierDetailV2 {
+                entity_detail: None,
+                ..get_default_verifier_detail()
+            }),
+        );
+        setup_no_attribute_response(&mut deps, None);
+        execute_set_require_entity_detail(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            true,
+        )
+        .expect("the admin should be able to require entity details");
+        assert!(
+            load_verifier(&deps.as_ref(), DEFAULT_VERIFIER_ADDRESS)
+                .entity_detail
+                .is_none(),
+            "the existing verifier should be left untouched",
+        );
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).expect(
+            "assets should still be onboarded with an existing verifier that has no entity detail",
+        );
+    }
+
+    #[test]
+    fn test_required_entity_detail_applies_to_verifiers_added_by_definition_changes() {
+        let mut deps = mock_provenance_dependencies();
+        let existing_verifier = VerifierDetailV2 {
+            entity_detail: None,
+            ..get_default_verifier_detail()
+        };
+        setup_test_suite(
+            &mut deps,
+            &InstArgs::with_single_verifier(existing_verifier.clone()),
+        );
+        execute_set_require_entity_detail(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            true,
+        )
+        .expect("the admin should be able to require entity details");
+        let added_verifier = VerifierDetailV2 {
+            address: SECOND_VERIFIER_ADDRESS.to_string(),
+            ..existing_verifier.clone()
+        };
+        let input_with_verifiers = |verifiers: Vec<VerifierDetailV2>| AssetDefinitionInputV3 {
+            verifiers,
+            ..get_default_asset_definition_input()
+        };
+        for partial in [None, true.to_some()] {
+            assert_missing_entity_detail(
+                execute_update_asset_definition(
+                    deps.as_mut(),
+                    mock_env(),
+                    empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+                    input_with_verifiers(vec![existing_verifier.clone(), added_verifier.clone()]),
+                    partial,
+                ),
+                "verifier:entity_detail: must be provided when entity details are required",
+            );
+        }
+        assert_missing_entity_detail(
+            execute_clone_asset_definition(
+                deps.as_mut(),
+                mock_env(),
+                empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+                CloneAssetDefinitionV1::new(
+                    DEFAULT_ASSET_TYPE,
+                    DEFAULT_SECONDARY_ASSET_TYPE,
+                    None,
+                    None,
+                ),
+            ),
+            "verifier:entity_detail: must be provided when entity details are required",
+        );
+        execute_update_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            input_with_verifiers(vec![
+                existing_verifier.clone(),
+                VerifierDetailV2 {
+                    entity_detail: get_new_entity_detail().to_some(),
+                    ..added_verifier
+                },
+            ]),
+            None,
+        )
+        .expect("an existing verifier without an entity detail should remain alongside a detailed new verifier");
+        assert!(
+            load_verifier(&deps.as_ref(), DEFAULT_VERIFIER_ADDRESS)
+                .entity_detail
+                .is_none(),
+            "the existing verifier should be left untouched",
+        );
+    }
+
+    fn assert_missing_entity_detail(result: EntryPointResponse, expected_message: &str) {
+        match result.expect_err("a verifier without a named entity detail should be rejected") {
+            ContractError::InvalidMessageFields { invalid_fields, .. } => assert!(
+                invalid_fields.contains(&expected_message.to_string()),
+                "expected the invalid fields to contain [{}], but got: {:?}",
+                expected_message,
+                invalid_fields,
+            ),
+            error => panic!("unexpected error encountered: {:?}", error),
+        }
+    }
+
+    fn load_require_entity_detail(deps: &Deps) -> bool {
+        STATE_V2
+            .load(deps.storage)
+            .expect("the contract state should exist")
+            .require_entity_detail_for_verifiers
+    }
+
+    fn get_new_entity_detail() -> EntityDetail {
+        EntityDetail::new(
+            "Renamed Verifier",
+            "A verifier with an updated description",
+            "https://www.example.com",
+            "https://github.com/example/verifier",
+        )
+    }
+
+    fn load_verifier(deps: &Deps, verifier_address: &str) -> VerifierDetailV2 {
+        load_asset_definition_by_type_v3(deps.storage, DEFAULT_ASSET_TYPE)
+            .expect("the default asset definition should exist in storage")
+            .get_verifier_detail(verifier_address)
+            .expect("the verifier should exist on the asset definition")
+    }
+}
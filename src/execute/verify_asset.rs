@@ -1,5 +1,6 @@
 use crate::core::error::ContractError;
 use crate::core::msg::ExecuteMsg;
+use crate::core::state::{may_load_asset_definition_by_type_v3, may_load_fee_payment_detail};
 use crate::core::types::access_route::AccessRoute;
 use crate::core::types::asset_identifier::AssetIdentifier;
 use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
@@ -7,12 +8,10 @@ use crate::service::asset_meta_repository::AssetMetaRepository;
 use crate::service::deps_manager::DepsManager;
 use crate::service::message_gathering_service::MessageGatheringService;
 use crate::util::aliases::{AssetResult, EntryPointResponse};
-use crate::util::contract_helpers::check_funds_are_empty;
+use crate::util::contract_helpers::{check_funds_are_empty, generate_os_gateway_access_revoke};
 use crate::util::event_attributes::{EventAttributes, EventType};
-use crate::util::functions::generate_os_gateway_grant_id;
 
 use cosmwasm_std::{MessageInfo, Response};
-use os_gateway_contract_attributes::OsGatewayAttributeGenerator;
 use result_extensions::ResultExtensions;
 
 /// A transformation of [ExecuteMsg::VerifyAsset](crate::core::msg::ExecuteMsg::VerifyAsset)
@@ -77,6 +76,13 @@ impl VerifyAssetV1 {
         }
     }
 }
+impl TryFrom<ExecuteMsg> for VerifyAssetV1 {
+    type Error = ContractError;
+
+    fn try_from(msg: ExecuteMsg) -> AssetResult<Self> {
+        VerifyAssetV1::from_execute_msg(msg)
+    }
+}
 
 /// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::OnboardAsset](crate::core::msg::ExecuteMsg::OnboardAsset)
 /// message is provided.  An execution route for use by the asset verifier selected by a requestor
@@ -133,6 +139,66 @@ where
         .to_err();
     }
 
+    // some verifiers must always explain a denial.  the verifier config is only needed for this
+    // check, so it is only loaded for denials.  if the verifier has since been removed from the
+    // asset definition, there is no requirement to enforce
+    if !msg.success {
+        let denial_reason_required = repository
+            .use_deps(|deps| may_load_asset_definition_by_type_v3(deps.storage, &msg.asset_type))?
+            .and_then(|definition| {
+                definition
+                    .get_verifier_detail_opt(info.sender.as_str())
+                    .map(|verifier| verifier.denial_reason_required)
+            })
+            .unwrap_or(false);
+        if denial_reason_required
+            && msg
+                .message
+                .as_ref()
+                .is_none_or(|message| message.trim().is_empty())
+        {
+            return ContractError::InvalidMessageFields {
+                message_type: "ExecuteMsg::VerifyAsset".to_string(),
+                invalid_fields: vec![format!(
+                    "message: verifier [{}] requires a non-blank message when denying an asset",
+                    info.sender,
+                )],
+            }
+            .to_err();
+        }
+    }
+
+    // the fee payment detail is created during onboarding and removed when verification completes.
+    // check for it up front to produce a descriptive error instead of a raw storage lookup failure
+    let fee_payment_detail = match repository.use_deps(|deps| {
+        may_load_fee_payment_detail(
+            deps.storage,
+            &asset_identifiers.scope_address,
+            &msg.asset_type,
+        )
+    }) {
+        Some(detail) => detail,
+        None => {
+            return ContractError::RecordNotFound {
+                explanation: format!(
+                    "no fee payment detail exists for pending asset [{}] with asset type [{}]",
+                    asset_identifiers.scope_address, msg.asset_type,
+                ),
+            }
+            .to_err();
+        }
+    };
+
+    // stale details can never be disbursed and must be purged by the admin instead
+    if fee_payment_detail.is_expired(env.block.time.seconds()) {
+        return ContractError::FeePaymentDetailExpired {
+            scope_address: asset_identifiers.scope_address,
+            asset_type: msg.asset_type,
+            created_at: fee_payment_detail.created_at,
+        }
+        .to_err();
+    }
+
     let updated_attribute = repository.verify_asset(
         env,
         scope_attribute,
@@ -141,6 +207,10 @@ where
         msg.access_routes,
     )?;
 
+    // the verifier's object store gateway access is no longer needed once verification completes,
+    // regardless of its outcome, but there is nothing to revoke if it was never granted
+    let access_revoke = repository
+        .use_deps(|deps| generate_os_gateway_access_revoke(deps.storage, &updated_attribute))?;
     // construct/emit verification attributes
     Response::new()
         .add_attributes(
@@ -152,45 +222,36 @@ where
             .set_verifier(info.sender.as_str())
             .set_new_asset_onboarding_status(&updated_attribute.onboarding_status),
         )
-        .add_attributes(
-            OsGatewayAttributeGenerator::access_revoke(
-                &asset_identifiers.scope_address,
-                info.sender.as_str(),
-            )
-            .with_access_grant_id(generate_os_gateway_grant_id(
-                &updated_attribute.asset_type,
-                asset_identifiers.scope_address,
-            )),
-        )
+        .add_attributes(access_revoke)
         .add_messages(repository.get_messages())
         .to_ok()
 }
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{Response, Uint128};
+    use crate::testutil::attribute_utilities::{
+        mock_attribute_response_for_scope, mock_named_attribute_response_for_scope,
+    };
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::Response;
     use os_gateway_contract_attributes::{OS_GATEWAY_EVENT_TYPES, OS_GATEWAY_KEYS};
     use provwasm_mocks::mock_provenance_dependencies;
-    use provwasm_std::types::provenance::attribute::v1::{
-        QueryAttributeRequest, QueryAttributeResponse, QueryAttributesRequest,
-        QueryAttributesResponse,
-    };
 
-    use crate::core::state::may_load_fee_payment_detail;
-    use crate::core::types::asset_definition::AssetDefinitionInputV3;
+    use crate::core::state::{delete_fee_payment_detail, may_load_fee_payment_detail};
     use crate::core::types::asset_scope_attribute::AssetScopeAttribute;
     use crate::core::types::asset_verification_result::AssetVerificationResult;
+    use crate::core::types::onboarding_cost::OnboardingCost;
     use crate::core::types::verifier_detail::VerifierDetailV2;
     use crate::execute::onboard_asset::OnboardAssetV1;
     use crate::testutil::msg_utilities::test_no_money_moved_in_response;
     use crate::testutil::test_constants::{DEFAULT_ASSET_TYPE, DEFAULT_SECONDARY_ASSET_TYPE};
     use crate::testutil::test_utilities::{
-        build_attribute, get_default_asset_definition_input, get_default_verifier_detail,
-        setup_no_attribute_response, single_attribute_for_key,
+        assert_response_attributes_contain, assert_response_messages_count, build_attribute,
+        get_default_verifier_detail, setup_no_attribute_response, MockOwnedDeps,
     };
     use crate::util::constants::{
         ASSET_EVENT_TYPE_KEY, ASSET_SCOPE_ADDRESS_KEY, ASSET_TYPE_KEY,
-        NEW_ASSET_ONBOARDING_STATUS_KEY, VERIFIER_ADDRESS_KEY,
+        MAX_FEE_PAYMENT_DETAIL_SECONDS, NEW_ASSET_ONBOARDING_STATUS_KEY, VERIFIER_ADDRESS_KEY,
     };
     use crate::util::functions::generate_os_gateway_grant_id;
     use crate::{
@@ -352,11 +413,7 @@ mod tests {
         )
         .unwrap();
 
-        assert_eq!(
-            2,
-            result.messages.len(),
-            "verify asset should produce two messages: update attribute msg to new status and bank send to default verifier"
-        );
+        assert_response_messages_count(&result, 2, "verify asset should produce two messages: update attribute msg to new status and bank send to default verifier");
     }
 
     #[test]
@@ -376,6 +433,11 @@ mod tests {
         )
         .unwrap();
         test_verify_asset(&mut deps, &instantiate_args.env, TestVerifyAsset::default()).unwrap();
+        assert!(
+            may_load_fee_payment_detail(&deps.storage, DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+                .is_none(),
+            "the fee payment detail should be removed after verification",
+        );
         let err = verify_asset(
             &instantiate_args.env,
             AssetMetaService::new(deps.as_mut()),
@@ -410,6 +472,105 @@ mod tests {
         };
     }
 
+    #[test]
+    fn test_verify_errors_on_pending_asset_without_fee_payment_detail() {
+        let mut deps = mock_provenance_dependencies();
+        let instantiate_args = InstArgs::default();
+        setup_test_suite(&mut deps, &instantiate_args);
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        delete_fee_payment_detail(&mut deps.storage, DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+            .expect("the fee payment detail should be deleted");
+        let err = verify_asset(
+            &instantiate_args.env,
+            AssetMetaService::new(deps.as_mut()),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            TestVerifyAsset::default_verify_asset(),
+        )
+        .unwrap_err();
+        match err {
+            ContractError::RecordNotFound { explanation } => {
+                assert_eq!(
+                    format!(
+                        "no fee payment detail exists for pending asset [{}] with asset type [{}]",
+                        DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE,
+                    ),
+                    explanation,
+                    "the error should describe the missing fee payment detail",
+                );
+            }
+            _ => panic!(
+                "unexpected error encountered when verifying without a fee payment detail: {:?}",
+                err
+            ),
+        };
+    }
+
+    #[test]
+    fn test_verify_errors_on_expired_fee_payment_detail() {
+        let mut deps = mock_provenance_dependencies();
+        let instantiate_args = InstArgs::default();
+        setup_test_suite(&mut deps, &instantiate_args);
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        let created_at = mock_env().block.time.seconds();
+        let mut env = mock_env();
+        env.block.time = env
+            .block
+            .time
+            .plus_seconds(MAX_FEE_PAYMENT_DETAIL_SECONDS + 1);
+        let err = verify_asset(
+            &env,
+            AssetMetaService::new(deps.as_mut()),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            TestVerifyAsset::default_verify_asset(),
+        )
+        .unwrap_err();
+        match err {
+            ContractError::FeePaymentDetailExpired {
+                scope_address,
+                asset_type,
+                created_at: error_created_at,
+            } => {
+                assert_eq!(
+                    DEFAULT_SCOPE_ADDRESS, scope_address,
+                    "the error should reference the scope address of the asset",
+                );
+                assert_eq!(
+                    DEFAULT_ASSET_TYPE, asset_type,
+                    "the error should reference the asset type of the asset",
+                );
+                assert_eq!(
+                    created_at, error_created_at,
+                    "the error should reference the time at which the detail was created",
+                );
+            }
+            _ => panic!(
+                "unexpected error encountered when verifying with an expired fee payment detail: {:?}",
+                err
+            ),
+        };
+        assert!(
+            may_load_fee_payment_detail(&deps.storage, DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+                .is_some(),
+            "the expired fee payment detail should remain in storage until it is purged",
+        );
+    }
+
+    #[test]
+    fn test_verify_succeeds_on_fee_payment_detail_at_max_age() {
+        let mut deps = mock_provenance_dependencies();
+        let instantiate_args = InstArgs::default();
+        setup_test_suite(&mut deps, &instantiate_args);
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(MAX_FEE_PAYMENT_DETAIL_SECONDS);
+        test_verify_asset(&mut deps, &env, TestVerifyAsset::default()).expect(
+            "verification should succeed when the fee payment detail is exactly at its max age",
+        );
+    }
+
     #[test]
     fn test_verify_errors_on_already_verified_success_false() {
         let mut deps = mock_provenance_dependencies();
@@ -470,14 +631,7 @@ mod tests {
             .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
             .unwrap();
         // Ensure the lookup for the attribute we are yet to add returns nothing
-        QueryAttributeRequest::mock_response(
-            &mut deps.querier,
-            QueryAttributeResponse {
-                account: DEFAULT_SCOPE_ADDRESS.to_string(),
-                attributes: vec![],
-                pagination: None,
-            },
-        );
+        mock_named_attribute_response_for_scope(&mut deps, DEFAULT_SCOPE_ADDRESS, vec![]);
         // onboard asset for a secondary classification
         test_onboard_asset(
             &mut deps,
@@ -494,46 +648,37 @@ mod tests {
             .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_SECONDARY_ASSET_TYPE)
             .unwrap();
         // Ensure both attributes (with pending statuses) are returned when looking up all attributes
-        QueryAttributesRequest::mock_response(
-            &mut deps.querier,
-            QueryAttributesResponse {
-                account: DEFAULT_SCOPE_ADDRESS.to_string(),
-                attributes: vec![
-                    build_attribute(DEFAULT_SCOPE_ADDRESS, &default_attribute),
-                    build_attribute(DEFAULT_SCOPE_ADDRESS, &default_secondary_attribute),
-                ],
-                pagination: None,
-            },
+        mock_attribute_response_for_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![
+                build_attribute(DEFAULT_SCOPE_ADDRESS, &default_attribute),
+                build_attribute(DEFAULT_SCOPE_ADDRESS, &default_secondary_attribute),
+            ],
         );
 
         // Ensure the primary attribute is returned when looking it up as part of the verification execution
-        QueryAttributeRequest::mock_response(
-            &mut deps.querier,
-            QueryAttributeResponse {
-                account: DEFAULT_SCOPE_ADDRESS.to_string(),
-                attributes: vec![build_attribute(DEFAULT_SCOPE_ADDRESS, &default_attribute)],
-                pagination: None,
-            },
+        mock_named_attribute_response_for_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![build_attribute(DEFAULT_SCOPE_ADDRESS, &default_attribute)],
         );
         test_verify_asset(&mut deps, &instantiate_args.env, TestVerifyAsset::default()).unwrap();
         // Ensure the updated primary attribute is returned when looking it up by its name
-        QueryAttributeRequest::mock_response(
-            &mut deps.querier,
-            QueryAttributeResponse {
-                account: DEFAULT_SCOPE_ADDRESS.to_string(),
-                attributes: vec![build_attribute(
-                    DEFAULT_SCOPE_ADDRESS,
-                    &AssetScopeAttribute {
-                        latest_verification_result: Some(AssetVerificationResult {
-                            success: true,
-                            message: String::from("verification successful"),
-                        }),
-                        onboarding_status: AssetOnboardingStatus::Approved,
-                        ..default_attribute
-                    },
-                )],
-                pagination: None,
-            },
+        mock_named_attribute_response_for_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![build_attribute(
+                DEFAULT_SCOPE_ADDRESS,
+                &AssetScopeAttribute {
+                    latest_verification_result: Some(AssetVerificationResult {
+                        success: true,
+                        message: String::from("verification successful"),
+                    }),
+                    onboarding_status: AssetOnboardingStatus::Approved,
+                    ..default_attribute
+                },
+            )],
         );
         let updated_default_attribute = AssetMetaService::new(deps.as_mut())
             .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
@@ -551,16 +696,13 @@ mod tests {
         may_load_fee_payment_detail(&deps.storage, DEFAULT_SCOPE_ADDRESS, DEFAULT_SECONDARY_ASSET_TYPE).expect("the asset's payment details for an unrelated secondary asset type should be unaffected by onboarding a different type");
 
         // Ensure the secondary attribute is returned when looking it up as part of the verification execution
-        QueryAttributeRequest::mock_response(
-            &mut deps.querier,
-            QueryAttributeResponse {
-                account: DEFAULT_SCOPE_ADDRESS.to_string(),
-                attributes: vec![build_attribute(
-                    DEFAULT_SCOPE_ADDRESS,
-                    &default_secondary_attribute,
-                )],
-                pagination: None,
-            },
+        mock_named_attribute_response_for_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![build_attribute(
+                DEFAULT_SCOPE_ADDRESS,
+                &default_secondary_attribute,
+            )],
         );
         test_verify_asset(
             &mut deps,
@@ -625,6 +767,11 @@ mod tests {
         setup_test_suite(&mut deps, &instantiate_args);
         setup_no_attribute_response(&mut deps, None);
         test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        assert!(
+            may_load_fee_payment_detail(&deps.storage, DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+                .is_some(),
+            "the fee payment detail should be present for the pending asset before verification",
+        );
         let response =
             test_verify_asset(&mut deps, &instantiate_args.env, TestVerifyAsset::default())
                 .unwrap();
@@ -663,20 +810,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_verify_asset_does_not_revoke_os_gateway_access_that_was_never_granted() {
+        for success in [true, false] {
+            let mut deps = mock_provenance_dependencies();
+            let instantiate_args = InstArgs::default();
+            setup_test_suite(&mut deps, &instantiate_args);
+            setup_no_attribute_response(&mut deps, None);
+            test_onboard_asset(
+                &mut deps,
+                TestOnboardAsset {
+                    onboard_asset: OnboardAssetV1 {
+                        add_os_gateway_permission: false,
+                        ..TestOnboardAsset::default_onboard_asset()
+                    },
+                    ..TestOnboardAsset::default()
+                },
+            )
+            .expect("onboarding without os gateway permission should succeed");
+            assert!(
+                !AssetMetaService::new(deps.as_mut())
+                    .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+                    .expect("the scope attribute should be present after onboarding")
+                    .os_gateway_permission_granted,
+                "the scope attribute should record that os gateway permission was not granted",
+            );
+            let response = test_verify_asset(
+                &mut deps,
+                &instantiate_args.env,
+                TestVerifyAsset::default_with_success(success),
+            )
+            .expect("verification should succeed");
+            assert!(
+                response
+                    .attributes
+                    .iter()
+                    .all(|attribute| attribute.key != OS_GATEWAY_KEYS.event_type),
+                "no os gateway revocation should be emitted when access was never granted (success = {})",
+                success,
+            );
+        }
+    }
+
     #[test]
     fn test_verify_asset_does_not_send_funds_when_onboarding_was_free() {
         let mut deps = mock_provenance_dependencies();
         // Setup as normal, but make onboarding free
-        let instantiate_args = InstArgs {
-            asset_definitions: vec![AssetDefinitionInputV3 {
-                verifiers: vec![VerifierDetailV2 {
-                    onboarding_cost: Uint128::zero(),
-                    ..get_default_verifier_detail()
-                }],
-                ..get_default_asset_definition_input()
-            }],
-            ..InstArgs::default()
-        };
+        let instantiate_args = InstArgs::with_single_verifier(VerifierDetailV2 {
+            onboarding_cost: OnboardingCost::new(0, &[]),
+            ..get_default_verifier_detail()
+        });
         setup_test_suite(&mut deps, &instantiate_args);
         setup_no_attribute_response(&mut deps, None);
         test_onboard_asset(&mut deps, TestOnboardAsset::default())
@@ -690,6 +873,124 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_verify_asset_denial_with_message_succeeds_when_denial_reason_required() {
+        let mut deps = mock_provenance_dependencies();
+        let instantiate_args = setup_denial_reason_required_suite(&mut deps);
+        let response = test_verify_asset(
+            &mut deps,
+            &instantiate_args.env,
+            TestVerifyAsset {
+                verify_asset: VerifyAssetV1 {
+                    success: false,
+                    message: "Missing appraisal documents".to_string().to_some(),
+                    ..TestVerifyAsset::default_verify_asset()
+                },
+                ..TestVerifyAsset::default()
+            },
+        )
+        .expect("a denial with a message should succeed when the verifier requires a reason");
+        assert_verify_response_attributes_are_correct(&response, AssetOnboardingStatus::Denied);
+    }
+
+    #[test]
+    fn test_verify_asset_denial_without_message_fails_when_denial_reason_required() {
+        for message in [None, "   ".to_string().to_some()] {
+            let mut deps = mock_provenance_dependencies();
+            let instantiate_args = setup_denial_reason_required_suite(&mut deps);
+            let err = test_verify_asset(
+                &mut deps,
+                &instantiate_args.env,
+                TestVerifyAsset {
+                    verify_asset: VerifyAssetV1 {
+                        success: false,
+                        message: message.clone(),
+                        ..TestVerifyAsset::default_verify_asset()
+                    },
+                    ..TestVerifyAsset::default()
+                },
+            )
+            .unwrap_err();
+            match err {
+                ContractError::InvalidMessageFields {
+                    message_type,
+                    invalid_fields,
+                } => {
+                    assert_eq!(
+                        "ExecuteMsg::VerifyAsset", message_type,
+                        "the error should reference the verify asset message",
+                    );
+                    assert_eq!(
+                        vec![format!(
+                            "message: verifier [{}] requires a non-blank message when denying an asset",
+                            DEFAULT_VERIFIER_ADDRESS,
+                        )],
+                        invalid_fields,
+                        "the error should explain that the verifier requires a denial reason",
+                    );
+                }
+                e => panic!(
+                    "unexpected error when denying without a reason (message = {:?}): {:?}",
+                    message, e,
+                ),
+            };
+            assert_eq!(
+                AssetOnboardingStatus::Pending,
+                AssetMetaService::new(deps.as_mut())
+                    .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+                    .expect("the scope attribute should still be present after a rejected denial")
+                    .onboarding_status,
+                "the asset should remain pending when the denial is rejected",
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_asset_approval_without_message_succeeds_regardless_of_denial_reason_required() {
+        for denial_reason_required in [true, false] {
+            let mut deps = mock_provenance_dependencies();
+            let instantiate_args = InstArgs::with_single_verifier(VerifierDetailV2 {
+                denial_reason_required,
+                ..get_default_verifier_detail()
+            });
+            setup_test_suite(&mut deps, &instantiate_args);
+            setup_no_attribute_response(&mut deps, None);
+            test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+            let response = test_verify_asset(
+                &mut deps,
+                &instantiate_args.env,
+                TestVerifyAsset {
+                    verify_asset: VerifyAssetV1 {
+                        message: None,
+                        ..TestVerifyAsset::default_verify_asset()
+                    },
+                    ..TestVerifyAsset::default()
+                },
+            )
+            .unwrap_or_else(|e| {
+                panic!(
+                    "an approval without a message should succeed (denial_reason_required = {}): {:?}",
+                    denial_reason_required, e,
+                )
+            });
+            assert_verify_response_attributes_are_correct(
+                &response,
+                AssetOnboardingStatus::Approved,
+            );
+        }
+    }
+
+    fn setup_denial_reason_required_suite(deps: &mut MockOwnedDeps) -> InstArgs {
+        let instantiate_args = InstArgs::with_single_verifier(VerifierDetailV2 {
+            denial_reason_required: true,
+            ..get_default_verifier_detail()
+        });
+        setup_test_suite(deps, &instantiate_args);
+        setup_no_attribute_response(deps, None);
+        test_onboard_asset(deps, TestOnboardAsset::default()).unwrap();
+        instantiate_args
+    }
+
     fn assert_verify_response_attributes_are_correct(
         response: &Response,
         expected_onboarding_status: AssetOnboardingStatus,
@@ -699,50 +1000,42 @@ mod tests {
             response.attributes.len(),
             "the correct number of response attributes should be emitted",
         );
-        assert_eq!(
-            "verify_asset",
-            single_attribute_for_key(response, ASSET_EVENT_TYPE_KEY),
-            "the correct event type attribute should be emitted",
-        );
-        assert_eq!(
-            DEFAULT_ASSET_TYPE,
-            single_attribute_for_key(response, ASSET_TYPE_KEY),
-            "the correct asset type attribute should be emitted",
-        );
-        assert_eq!(
+        assert_response_attributes_contain(response, ASSET_EVENT_TYPE_KEY, "verify_asset");
+        assert_response_attributes_contain(response, ASSET_TYPE_KEY, DEFAULT_ASSET_TYPE);
+        assert_response_attributes_contain(
+            response,
+            ASSET_SCOPE_ADDRESS_KEY,
             DEFAULT_SCOPE_ADDRESS,
-            single_attribute_for_key(response, ASSET_SCOPE_ADDRESS_KEY),
-            "the correct scope address attribute should be emitted",
         );
-        assert_eq!(
+        assert_response_attributes_contain(
+            response,
+            VERIFIER_ADDRESS_KEY,
             DEFAULT_VERIFIER_ADDRESS,
-            single_attribute_for_key(response, VERIFIER_ADDRESS_KEY),
-            "the correct verifier address attribute should be emitted",
         );
-        assert_eq!(
-            expected_onboarding_status.to_string(),
-            single_attribute_for_key(response, NEW_ASSET_ONBOARDING_STATUS_KEY),
-            "expected the correct onboarding status to be emitted",
+        assert_response_attributes_contain(
+            response,
+            NEW_ASSET_ONBOARDING_STATUS_KEY,
+            &expected_onboarding_status.to_string(),
         );
-        assert_eq!(
+        assert_response_attributes_contain(
+            response,
+            OS_GATEWAY_KEYS.event_type,
             OS_GATEWAY_EVENT_TYPES.access_revoke,
-            single_attribute_for_key(response, OS_GATEWAY_KEYS.event_type),
-            "the correct object store gateway event type attribute should be emitted",
         );
-        assert_eq!(
+        assert_response_attributes_contain(
+            response,
+            OS_GATEWAY_KEYS.scope_address,
             DEFAULT_SCOPE_ADDRESS,
-            single_attribute_for_key(response, OS_GATEWAY_KEYS.scope_address),
-            "the correct object store gateway scope address attribute should be emitted",
         );
-        assert_eq!(
+        assert_response_attributes_contain(
+            response,
+            OS_GATEWAY_KEYS.target_account,
             DEFAULT_VERIFIER_ADDRESS,
-            single_attribute_for_key(response, OS_GATEWAY_KEYS.target_account),
-            "the correct object store gateway target account address attribute should be emitted",
         );
-        assert_eq!(
-            generate_os_gateway_grant_id(DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS),
-            single_attribute_for_key(response, OS_GATEWAY_KEYS.access_grant_id),
-            "the correct object store gateway access grant id attribute should be emitted",
+        assert_response_attributes_contain(
+            response,
+            OS_GATEWAY_KEYS.access_grant_id,
+            &generate_os_gateway_grant_id(None, DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS),
         );
     }
 }
@@ -1,3 +1,4 @@
+use crate::core::error::ContractError;
 use crate::core::msg::InitMsg;
 use crate::core::state::{insert_asset_definition_v3, StateV2, STATE_V2};
 use crate::migrate::version_info::migrate_version_info;
@@ -7,12 +8,15 @@ use crate::util::event_attributes::{EventAttributes, EventType};
 use crate::util::functions::{generate_asset_attribute_name, msg_bind_name};
 
 use cosmwasm_std::{CosmosMsg, DepsMut, Env, MessageInfo, Response};
+use provwasm_std::types::provenance::name::v1::NameQuerier;
 use result_extensions::ResultExtensions;
 
 /// The main functionality executed when the smart contract is first instantiated.   This creates
 /// the internal contract [StateV2](crate::core::state::StateV2) value, as well as any provided
 /// [AssetDefinitionsV3](crate::core::types::asset_definition::AssetDefinitionV3) provided in the init
-/// msg.
+/// msg.  If the base contract name is requested to be bound, the Provenance Blockchain Name Module
+/// is first checked to determine if the name is already bound.  A name already bound to the
+/// contract's address will skip the bind, but a name bound to any other address will cause an error.
 ///
 /// # Parameters
 ///
@@ -32,15 +36,33 @@ pub fn init_contract(
 ) -> EntryPointResponse {
     check_funds_are_empty(&info)?;
     let mut messages: Vec<CosmosMsg> = vec![];
+    let mut base_name_already_bound = false;
     // If specified true, the contract needs to own its root name to be effective at preventing
     // asset classification "neighbors" that were never intended to be created from being reserved
     // by external callers
     if msg.bind_base_name {
-        messages.push(CosmosMsg::from(msg_bind_name(
-            &msg.base_contract_name,
-            env.contract.address.clone(),
-            true,
-        )?));
+        match NameQuerier::new(&deps.querier).resolve(msg.base_contract_name.clone()) {
+            // A re-instantiation at the same address will already own the name, and attempting to
+            // bind it again would fail the transaction
+            Ok(resolved) if resolved.address == env.contract.address.as_str() => {
+                base_name_already_bound = true;
+            }
+            Ok(resolved) if !resolved.address.is_empty() => {
+                return ContractError::BaseNameAlreadyBound {
+                    name: msg.base_contract_name,
+                    owner: resolved.address,
+                }
+                .to_err();
+            }
+            // A failed resolution indicates that the name has not yet been bound to any address
+            _ => {
+                messages.push(CosmosMsg::from(msg_bind_name(
+                    &msg.base_contract_name,
+                    env.contract.address.clone(),
+                    true,
+                )?));
+            }
+        }
     }
     // Note: This vector can remain empty on instantiation, and future executions by the admin can
     // append new definitions. When no definitions are supplied, this contract will not be able to
@@ -67,9 +89,13 @@ pub fn init_contract(
     STATE_V2.save(deps.storage, &state)?;
     // Set the version info to the default contract values on instantiation
     migrate_version_info(deps.storage)?;
+    let mut attributes = EventAttributes::new(EventType::InstantiateContract);
+    if base_name_already_bound {
+        attributes = attributes.set_base_name_already_bound(true);
+    }
     Response::new()
         .add_messages(messages)
-        .add_attributes(EventAttributes::new(EventType::InstantiateContract))
+        .add_attributes(attributes)
         .to_ok()
 }
 
@@ -89,15 +115,17 @@ mod tests {
         DEFAULT_ONBOARDING_COST, DEFAULT_ONBOARDING_DENOM, DEFAULT_VERIFIER_ADDRESS,
     };
     use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, assert_response_messages_count,
         get_default_asset_definition, get_default_asset_definition_inputs,
-        get_default_entity_detail, get_default_verifier_detail, single_attribute_for_key,
-        test_instantiate, InstArgs,
+        get_default_entity_detail, get_default_verifier_detail, test_instantiate, InstArgs,
     };
-    use crate::util::constants::{ASSET_EVENT_TYPE_KEY, NHASH};
+    use crate::util::constants::{ASSET_EVENT_TYPE_KEY, BASE_NAME_ALREADY_BOUND_KEY, NHASH};
     use crate::util::event_attributes::EventType;
     use crate::util::traits::OptionExtensions;
     use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
     use cosmwasm_std::{coin, Addr, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+    use provwasm_std::types::provenance::name::v1::{QueryResolveRequest, QueryResolveResponse};
 
     #[test]
     fn test_valid_default_init() {
@@ -109,15 +137,15 @@ mod tests {
             response.attributes.len(),
             "a single attribute should be emitted"
         );
-        assert_eq!(
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
             EventType::InstantiateContract.event_name().as_str(),
-            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
-            "the proper event type should be emitted",
         );
-        assert_eq!(
+        assert_response_messages_count(
+            &response,
             2,
-            response.messages.len(),
-            "the correct number of messages should be emitted"
+            "the correct number of messages should be emitted",
         );
         test_for_default_base_name(&response.messages);
         test_message_is_name_bind(&response.messages, DEFAULT_ASSET_TYPE);
@@ -191,10 +219,10 @@ mod tests {
         );
         let response = test_instantiate(
             deps.as_mut(),
-            &InstArgs {
-                asset_definitions: vec![first_asset_def.clone(), second_asset_def.clone()],
-                ..Default::default()
-            },
+            &InstArgs::with_asset_definitions(vec![
+                first_asset_def.clone(),
+                second_asset_def.clone(),
+            ]),
         )
         .expect("instantiation should succeed with multiple asset definitions");
         assert_eq!(
@@ -202,9 +230,9 @@ mod tests {
             response.attributes.len(),
             "only one attribute should be emitted"
         );
-        assert_eq!(
+        assert_response_messages_count(
+            &response,
             3,
-            response.messages.len(),
             "the correct number of messages should be emitted",
         );
         test_for_default_base_name(&response.messages);
@@ -238,15 +266,84 @@ mod tests {
             },
         )
         .expect("instantiation with defaults and bind_base_name = false should succeed");
-        assert_eq!(
+        assert_response_messages_count(
+            &response,
             1,
-            response.messages.len(),
-            "the correct number of messages should be emitted"
+            "the correct number of messages should be emitted",
         );
         // The only message emitted should be a name bind for the default asset type to the base name
         test_message_is_name_bind(&response.messages, DEFAULT_ASSET_TYPE);
     }
 
+    #[test]
+    fn test_valid_init_unbound_base_name_is_bound() {
+        let mut deps = mock_provenance_dependencies();
+        let response = test_instantiate(deps.as_mut(), &InstArgs::default())
+            .expect("instantiation should succeed when the base name is not yet bound");
+        assert_response_messages_count(
+            &response,
+            2,
+            "the correct number of messages should be emitted",
+        );
+        test_for_default_base_name(&response.messages);
+        assert!(
+            response
+                .attributes
+                .iter()
+                .all(|attr| attr.key != BASE_NAME_ALREADY_BOUND_KEY),
+            "the base name already bound attribute should not be emitted when the name is unbound",
+        );
+    }
+
+    #[test]
+    fn test_valid_init_base_name_bound_to_self_skips_base_bind() {
+        let mut deps = mock_provenance_dependencies();
+        let args = InstArgs::default();
+        QueryResolveRequest::mock_response(
+            &mut deps.querier,
+            QueryResolveResponse {
+                address: args.env.contract.address.to_string(),
+                restricted: true,
+            },
+        );
+        let response = test_instantiate(deps.as_mut(), &args).expect(
+            "instantiation should succeed when the base name is already bound to the contract",
+        );
+        assert_response_messages_count(
+            &response,
+            1,
+            "only the asset type name bind should be emitted when the base name is already bound",
+        );
+        test_message_is_name_bind(&response.messages, DEFAULT_ASSET_TYPE);
+        assert_response_attributes_contain(&response, BASE_NAME_ALREADY_BOUND_KEY, "true");
+    }
+
+    #[test]
+    fn test_invalid_init_base_name_bound_to_other_address() {
+        let mut deps = mock_provenance_dependencies();
+        QueryResolveRequest::mock_response(
+            &mut deps.querier,
+            QueryResolveResponse {
+                address: DEFAULT_VERIFIER_ADDRESS.to_string(),
+                restricted: true,
+            },
+        );
+        let error = test_instantiate(deps.as_mut(), &InstArgs::default()).unwrap_err();
+        match error {
+            ContractError::BaseNameAlreadyBound { name, owner } => {
+                assert_eq!(
+                    DEFAULT_CONTRACT_BASE_NAME, name,
+                    "the error should include the base name that was already bound",
+                );
+                assert_eq!(
+                    DEFAULT_VERIFIER_ADDRESS, owner,
+                    "the error should include the address that owns the base name",
+                );
+            }
+            _ => panic!("unexpected error encountered: {:?}", error),
+        };
+    }
+
     #[test]
     fn test_valid_init_no_is_test_flag_supplied_defaults_to_false() {
         let mut deps = mock_dependencies();
@@ -259,6 +356,9 @@ mod tests {
                 bind_base_name: true,
                 asset_definitions: get_default_asset_definition_inputs(),
                 is_test: None,
+                max_access_routes_per_owner: None,
+                allow_all_bech32_prefixes: None,
+                os_gateway_grant_prefix: None,
             },
         )
         .expect("instantiation should complete successfully");
@@ -290,6 +390,9 @@ mod tests {
                     false.to_some(),
                 )],
                 is_test: None,
+                max_access_routes_per_owner: None,
+                allow_all_bech32_prefixes: None,
+                os_gateway_grant_prefix: None,
             },
         )
         .expect("expected instantiation to succeed with no name binding on the added definition");
@@ -324,16 +427,13 @@ mod tests {
 
     #[test]
     fn test_invalid_init_fails_for_invalid_init_msg() {
-        let args = InstArgs {
-            asset_definitions: vec![AssetDefinitionInputV3::new(
-                "",
-                None::<String>,
-                vec![],
-                None,
-                None,
-            )],
-            ..Default::default()
-        };
+        let args = InstArgs::with_asset_definitions(vec![AssetDefinitionInputV3::new(
+            "",
+            None::<String>,
+            vec![],
+            None,
+            None,
+        )]);
         let error = instantiate(
             mock_dependencies().as_mut(),
             args.env,
@@ -343,11 +443,14 @@ mod tests {
                 bind_base_name: true,
                 asset_definitions: args.asset_definitions,
                 is_test: None,
+                max_access_routes_per_owner: None,
+                allow_all_bech32_prefixes: None,
+                os_gateway_grant_prefix: None,
             },
         )
         .unwrap_err();
         assert!(
-            matches!(error, ContractError::InvalidMessageFields { .. }),
+            error.is_invalid_message_fields(),
             "the responding error should indicate that the InitMsg was badly formatted, but got: {:?}",
             error,
         );
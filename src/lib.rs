@@ -13,6 +13,7 @@ pub mod instantiate;
 pub mod migrate;
 pub mod query;
 pub mod service;
+pub mod sudo;
 pub mod util;
 pub mod validation;
 
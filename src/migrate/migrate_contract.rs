@@ -1,11 +1,25 @@
-use cosmwasm_std::{DepsMut, Response, Storage};
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{CosmosMsg, Deps, DepsMut, Env, Response, Storage};
+use provwasm_std::types::cosmos::base::query::v1beta1::PageRequest;
+use provwasm_std::types::provenance::attribute::v1::{AttributeQuerier, AttributeType};
+use provwasm_std::types::provenance::metadata::v1::MetadataQuerier;
 use result_extensions::ResultExtensions;
 use semver::Version;
 
 use crate::core::msg::MigrationOptions;
-use crate::core::state::STATE_V2;
+use crate::core::state::{
+    backfill_fee_payment_detail_creation, iter_asset_definitions_v3,
+    list_asset_definition_entries_v3, list_asset_definitions_v3, rekey_asset_definition_v3,
+    replace_asset_definition_v3, update_access_route_owner_index, update_last_modified_block_index,
+    update_requestor_index, update_verifier_status_index, STATE_V2,
+};
+use crate::core::types::asset_scope_attribute::AssetScopeAttribute;
+use crate::query::query_asset_scope_attribute_by_asset_type::may_query_scope_attribute_by_scope_address_and_asset_type;
 use crate::util::event_attributes::EventAdditionalMetadata;
+use crate::util::functions::{generate_asset_attribute_name, update_attribute};
 use crate::util::scope_address_utils::bech32_string_to_addr;
+use crate::util::traits::OptionExtensions;
 use crate::{
     core::error::ContractError,
     util::{
@@ -18,19 +32,68 @@ use super::version_info::{
     get_version_info, migrate_version_info, CONTRACT_NAME, CONTRACT_VERSION,
 };
 
+/// Contracts migrating from a version lower than this value may have stored asset definitions with
+/// mixed-case asset types, and will have them normalized to lowercase.
+const ASSET_TYPE_CASE_NORMALIZATION_VERSION: &str = "3.4.1";
+
+/// Contracts migrating from a version lower than this value may have stored verifiers with the
+/// legacy root-level onboarding cost fields, and will have them rewritten with a nested
+/// [OnboardingCost](crate::core::types::onboarding_cost::OnboardingCost).
+const VERIFIER_ONBOARDING_COST_MIGRATION_VERSION: &str = "3.4.1";
+
 /// The main entrypoint function for running a code migration.  Referred to in the [contract file](crate::contract).
 ///
 /// # Parameters
 ///
 /// * `deps` A DepsMut provided by cosmwasm in the migrate entrypoint.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
 /// * `options` An optional instance of [MigrationOptions](crate::core::msg::MigrationOptions) that
 /// dictates whether or not to execute optional functionality during the migration.
-pub fn migrate_contract(deps: DepsMut, options: Option<MigrationOptions>) -> EntryPointResponse {
+/// * `populate_scope_specs` Whether or not to backfill the [scope_spec_address](crate::core::types::asset_scope_attribute::AssetScopeAttribute::scope_spec_address)
+/// of all existing scope attributes via [populate_scope_spec_addresses](self::populate_scope_spec_addresses).
+/// * `backfill_status_index` Whether or not to record all existing scope attributes in the
+///   contract's scope attribute indexes via [backfill_scope_attribute_indexes](self::backfill_scope_attribute_indexes).
+pub fn migrate_contract(
+    mut deps: DepsMut,
+    env: &Env,
+    options: Option<MigrationOptions>,
+    populate_scope_specs: bool,
+    backfill_status_index: bool,
+) -> EntryPointResponse {
     // Ensure the migration is not attempting to revert to an old version or something crazier
     check_valid_migration_versioning(deps.storage)?;
+    let previous_version = get_version_info(deps.storage)?.parse_sem_ver()?;
     // Store the new version info
     let new_version_info = migrate_version_info(deps.storage)?;
+    // Older versions did not guarantee that asset types were stored in lowercase.  This must run
+    // first, as the remaining steps look up definitions by their lowercase storage key
+    if previous_version < ASSET_TYPE_CASE_NORMALIZATION_VERSION.parse::<Version>()? {
+        normalize_asset_type_case(deps.storage)?;
+    }
+    // Rewrite any asset definitions that still hold verifiers in the legacy cost format
+    if previous_version < VERIFIER_ONBOARDING_COST_MIGRATION_VERSION.parse::<Version>()? {
+        migrate_verifier_onboarding_costs(deps.storage)?;
+    }
+    // Populate the cached asset definition count for contracts instantiated before it existed
+    migrate_asset_definition_count(deps.storage)?;
+    // Fee payment details stored before creation tracking would otherwise be treated as expired,
+    // so they are given a full window starting from this migration
+    backfill_fee_payment_detail_creation(deps.storage, env.block.time.seconds(), env.block.height)?;
     let mut additional_metadata = EventAdditionalMetadata::new();
+    let mut messages = vec![];
+    if populate_scope_specs {
+        let (updated_count, update_messages) = populate_scope_spec_addresses(&mut deps, env)?;
+        messages = update_messages;
+        additional_metadata.add_metadata("scope_specs_populated", updated_count.to_string());
+    }
+    if backfill_status_index {
+        let indexed_count = backfill_scope_attribute_indexes(&mut deps)?;
+        additional_metadata.add_metadata(
+            "verifier_status_index_backfilled",
+            indexed_count.to_string(),
+        );
+    }
     if let Some(options) = options {
         // Only load and update the state if any options have actually been specified
         if options.has_changes() {
@@ -39,13 +102,33 @@ pub fn migrate_contract(deps: DepsMut, options: Option<MigrationOptions>) -> Ent
             if let Some(new_admin_address) = options.new_admin_address {
                 // Only set a new specified admin if it is a legitimate bech32 Provenance Blockchain address
                 state.admin = bech32_string_to_addr(&new_admin_address)?;
+                // A transfer nominated by the previous admin should not survive the admin change
+                state.pending_admin = None;
                 additional_metadata.add_metadata("new_admin_address", &new_admin_address);
             }
+            if let Some(verification_timeout_seconds) = options.verification_timeout_seconds {
+                // A zero timeout would deny every pending verification immediately, so it is used
+                // to signal that the timeout should be removed instead
+                state.verification_timeout_seconds =
+                    Some(verification_timeout_seconds).filter(|seconds| *seconds > 0);
+                additional_metadata.add_metadata(
+                    "verification_timeout_seconds",
+                    verification_timeout_seconds.to_string(),
+                );
+            }
+            if let Some(allow_all_bech32_prefixes) = options.allow_all_bech32_prefixes {
+                state.allow_all_bech32_prefixes = allow_all_bech32_prefixes;
+                additional_metadata.add_metadata(
+                    "allow_all_bech32_prefixes",
+                    allow_all_bech32_prefixes.to_string(),
+                );
+            }
             // Persist all changes to the state
             state_storage.save(deps.storage, &state)?;
         }
     }
     Response::new()
+        .add_messages(messages)
         .add_attributes(
             EventAttributes::new(EventType::MigrateContract)
                 .set_new_value(&new_version_info.version)
@@ -85,22 +168,272 @@ fn check_valid_migration_versioning(storage: &mut dyn Storage) -> AssetResult<()
     Ok(())
 }
 
+/// Rewrites all stored [AssetDefinitionV3s](crate::core::types::asset_definition::AssetDefinitionV3)
+/// to ensure that their [VerifierDetailV2s](crate::core::types::verifier_detail::VerifierDetailV2)
+/// are persisted with a nested [OnboardingCost](crate::core::types::onboarding_cost::OnboardingCost)
+/// rather than the legacy root-level onboarding cost and fee destinations fields.  Legacy values are
+/// converted automatically when loaded, so re-saving each definition is all that is required.
+/// If any definition cannot be read, the error is returned rather than leaving it in the legacy
+/// format.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract's internal storage for data manipulation.
+fn migrate_verifier_onboarding_costs(storage: &mut dyn Storage) -> AssetResult<()> {
+    let asset_definitions = iter_asset_definitions_v3(storage).collect::<AssetResult<Vec<_>>>()?;
+    for asset_definition in asset_definitions {
+        replace_asset_definition_v3(storage, &asset_definition)?;
+    }
+    Ok(())
+}
+
+/// Recalculates the [asset_definition_count](crate::core::state::StateV2::asset_definition_count)
+/// stored in the contract's state by counting all existing asset definitions.  Contracts that
+/// were instantiated before the count was tracked will have a default value of zero, and this
+/// ensures the cached value is accurate going forward.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract's internal storage for data manipulation.
+fn migrate_asset_definition_count(storage: &mut dyn Storage) -> AssetResult<()> {
+    if let Some(mut state) = STATE_V2.may_load(storage)? {
+        state.asset_definition_count = iter_asset_definitions_v3(storage).count() as u64;
+        STATE_V2.save(storage, &state)?;
+    }
+    Ok(())
+}
+
+/// Records every [AssetScopeAttribute](crate::core::types::asset_scope_attribute::AssetScopeAttribute)
+/// held by an account on the Provenance Blockchain in each of the contract's scope attribute
+/// indexes: the verifier and onboarding status index, the requestor index, the access route owner
+/// index and the last modified block index.  The indexes are only maintained when attributes are
+/// written, so attributes created before they existed are otherwise invisible to the routes that
+/// rely on them.  Each attribute found by [list_asset_type_accounts](self::list_asset_type_accounts)
+/// is re-indexed.  Indexing is idempotent, so running this more than once is harmless.  Returns the
+/// number of attributes indexed.
+///
+/// # Parameters
+///
+/// * `deps` A DepsMut provided by cosmwasm in the migrate entrypoint.
+pub fn backfill_scope_attribute_indexes(deps: &mut DepsMut) -> AssetResult<u64> {
+    let mut indexed_count = 0;
+    for (scope_address, asset_type) in list_asset_type_accounts(&deps.as_ref())? {
+        if let Some(attribute) = may_query_scope_attribute_by_scope_address_and_asset_type(
+            &deps.as_ref(),
+            &scope_address,
+            &asset_type,
+        )? {
+            update_verifier_status_index(deps.storage, None, &attribute)?;
+            update_requestor_index(deps.storage, None, &attribute)?;
+            update_access_route_owner_index(deps.storage, None, &attribute)?;
+            update_last_modified_block_index(deps.storage, None, &attribute)?;
+            indexed_count += 1;
+        }
+    }
+    indexed_count.to_ok()
+}
+
+/// Lists every account holding an attribute for one of the contract's asset definitions, alongside
+/// the asset type of the attribute.  The accounts are paged through the Provenance Blockchain
+/// Attribute Module, so attributes written by any previous version of the contract are included,
+/// regardless of whether the contract's internal indexes were maintained at the time.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.
+fn list_asset_type_accounts(deps: &Deps) -> AssetResult<Vec<(String, String)>> {
+    let contract_base_name = STATE_V2.load(deps.storage)?.base_contract_name;
+    let mut accounts = vec![];
+    for asset_definition in list_asset_definitions_v3(deps.storage) {
+        let attribute_name =
+            generate_asset_attribute_name(&asset_definition.asset_type, &contract_base_name);
+        let mut page_key = vec![];
+        loop {
+            let response = AttributeQuerier::new(&deps.querier).attribute_accounts(
+                attribute_name.to_owned(),
+                PageRequest {
+                    key: page_key,
+                    offset: 0,
+                    limit: 0,
+                    count_total: false,
+                    reverse: false,
+                }
+                .to_some(),
+            )?;
+            accounts.extend(
+                response
+                    .accounts
+                    .into_iter()
+                    .map(|account| (account, asset_definition.asset_type.to_owned())),
+            );
+            page_key = response
+                .pagination
+                .and_then(|pagination| pagination.next_key)
+                .unwrap_or_default();
+            if page_key.is_empty() {
+                break;
+            }
+        }
+    }
+    accounts.to_ok()
+}
+
+/// Backfills the [scope_spec_address](crate::core::types::asset_scope_attribute::AssetScopeAttribute::scope_spec_address)
+/// of every [AssetScopeAttribute](crate::core::types::asset_scope_attribute::AssetScopeAttribute)
+/// found by [list_asset_type_accounts](self::list_asset_type_accounts).  Each attribute's scope is
+/// queried from the Provenance Blockchain Metadata Module, and an attribute update message is
+/// generated with the scope's specification address.  Attributes that already have a value, or
+/// whose scope does not report a specification, are left untouched.  Returns the number of attributes updated, along
+/// with the messages that perform the updates.
+///
+/// # Parameters
+///
+/// * `deps` A DepsMut provided by cosmwasm in the migrate entrypoint.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+pub fn populate_scope_spec_addresses(
+    deps: &mut DepsMut,
+    env: &Env,
+) -> AssetResult<(u64, Vec<CosmosMsg>)> {
+    let contract_base_name = STATE_V2.load(deps.storage)?.base_contract_name;
+    let mut messages = vec![];
+    for (scope_address, asset_type) in list_asset_type_accounts(&deps.as_ref())? {
+        let Some(original_attribute) = may_query_scope_attribute_by_scope_address_and_asset_type(
+            &deps.as_ref(),
+            &scope_address,
+            &asset_type,
+        )?
+        else {
+            continue;
+        };
+        if original_attribute.scope_spec_address.is_some() {
+            continue;
+        }
+        let scope_spec_address = MetadataQuerier::new(&deps.querier)
+            .scope(
+                scope_address.to_owned(),
+                String::from(""),
+                String::from(""),
+                false,
+                false,
+                false,
+                false,
+            )?
+            .scope
+            .and_then(|scope_wrapper| scope_wrapper.scope_spec_id_info)
+            .map(|id_info| id_info.scope_spec_addr)
+            .filter(|scope_spec_addr| !scope_spec_addr.is_empty());
+        if scope_spec_address.is_none() {
+            continue;
+        }
+        let updated_attribute = AssetScopeAttribute {
+            scope_spec_address,
+            ..original_attribute.clone()
+        };
+        let attribute_name = generate_asset_attribute_name(&asset_type, &contract_base_name);
+        messages.push(update_attribute(
+            bech32_string_to_addr(&scope_address)?,
+            env.contract.address.to_owned(),
+            &attribute_name,
+            original_attribute.to_binary(&attribute_name)?,
+            AttributeType::Json,
+            updated_attribute.to_binary(&attribute_name)?,
+            AttributeType::Json,
+        )?);
+    }
+    (messages.len() as u64, messages).to_ok()
+}
+
+/// Lowercases the [asset_type](crate::core::types::asset_definition::AssetDefinitionV3::asset_type)
+/// of every stored [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
+/// and re-saves each definition under its lowercase storage key.  If any two definitions have
+/// asset types that only differ by case, no changes are made and a [MigrationConflict](crate::core::error::ContractError::MigrationConflict)
+/// error is returned, as the definitions would collide after normalization.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract's internal storage for data manipulation.
+fn normalize_asset_type_case(storage: &mut dyn Storage) -> AssetResult<()> {
+    let entries = list_asset_definition_entries_v3(storage)?;
+    let mut asset_types_by_normalized_type: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (_, asset_definition) in entries.iter() {
+        asset_types_by_normalized_type
+            .entry(asset_definition.asset_type.to_lowercase())
+            .or_default()
+            .push(asset_definition.asset_type.to_owned());
+    }
+    let conflicting_types = asset_types_by_normalized_type
+        .into_values()
+        .filter(|asset_types| asset_types.len() > 1)
+        .flatten()
+        .collect::<Vec<String>>();
+    if !conflicting_types.is_empty() {
+        return ContractError::MigrationConflict { conflicting_types }.to_err();
+    }
+    for (key, mut asset_definition) in entries {
+        let normalized_type = asset_definition.asset_type.to_lowercase();
+        if key != normalized_type || asset_definition.asset_type != normalized_type {
+            asset_definition.asset_type = normalized_type;
+            rekey_asset_definition_v3(storage, &key, &asset_definition)?;
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::to_json_vec;
+    use cosmwasm_std::{ContractResult, SystemResult};
     use provwasm_mocks::mock_provenance_dependencies;
+    use provwasm_std::types::provenance::attribute::v1::{
+        QueryAttributeRequest, QueryAttributeResponse,
+    };
+    use provwasm_std::types::provenance::metadata::v1::ScopeRequest;
 
-    use crate::core::state::STATE_V2;
-    use crate::testutil::test_utilities::{test_instantiate_success, InstArgs};
+    use crate::core::state::{
+        count_assets_by_verifier_and_status, list_access_route_owner_index_page,
+        list_requestor_index_page, list_scopes_modified_after_block,
+        load_asset_definition_by_type_v3, RequestorIndexEntry, STATE_V2,
+    };
+    use crate::core::state::{insert_fee_payment_detail, load_fee_payment_detail};
+    use crate::core::types::asset_definition::AssetDefinitionV3;
+    use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+    use crate::core::types::asset_scope_attribute::AssetScopeAttribute;
+    use crate::core::types::fee_destination::FeeDestinationV2;
+    use crate::core::types::fee_payment_detail::FeePaymentDetail;
+    use crate::core::types::onboarding_cost::OnboardingCost;
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::scope_utilities::mock_scope_response_with_spec_address;
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS, DEFAULT_SCOPE_SPEC_ADDRESS,
+        DEFAULT_SECONDARY_ASSET_TYPE, DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        build_attribute, get_default_asset_scope_attribute, get_default_scope,
+        get_duped_fee_payment_detail, intercept_add_or_update_attribute,
+        mock_attribute_accounts_response, setup_no_attribute_response, setup_test_suite,
+        test_instantiate_success, to_query_response_binary, InstArgs, MockOwnedDeps,
+    };
     use crate::util::constants::ADDITIONAL_METADATA_KEY;
+    use crate::util::functions::try_into_update_attribute_request;
     use crate::util::traits::OptionExtensions;
     use crate::{
         migrate::version_info::{set_version_info, VersionInfoV1},
-        testutil::test_utilities::single_attribute_for_key,
+        testutil::test_utilities::{
+            assert_response_attributes_contain, assert_response_messages_count,
+        },
         util::constants::{ASSET_EVENT_TYPE_KEY, NEW_VALUE_KEY},
     };
 
     use super::*;
 
+    type IndexedScopes = (
+        Vec<RequestorIndexEntry>,
+        Vec<(String, Vec<String>)>,
+        Vec<String>,
+    );
+
     #[test]
     fn test_successful_migration() {
         let mut deps = mock_provenance_dependencies();
@@ -112,7 +445,7 @@ mod tests {
             },
         )
         .expect("setting the initial version info should not fail");
-        let response = migrate_contract(deps.as_mut(), None).expect(
+        let response = migrate_contract(deps.as_mut(), &mock_env(), None, false, false).expect(
             "a migration should be successful when the contract is migrating to a new version",
         );
         assert!(
@@ -124,16 +457,12 @@ mod tests {
             response.attributes.len(),
             "the migration should produce the correct number of attributes",
         );
-        assert_eq!(
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
             EventType::MigrateContract.event_name().as_str(),
-            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
-            "the proper event type attribute should be emitted",
-        );
-        assert_eq!(
-            CONTRACT_VERSION,
-            single_attribute_for_key(&response, NEW_VALUE_KEY),
-            "the new value key should equate to the current contract version",
         );
+        assert_response_attributes_contain(&response, NEW_VALUE_KEY, CONTRACT_VERSION);
     }
 
     #[test]
@@ -151,11 +480,16 @@ mod tests {
         let new_admin_address = "tp1hsqtppgy7mgd64q0uvk7q39qa7h8hp30urqs6n";
         let response = migrate_contract(
             deps.as_mut(),
+            &mock_env(),
             MigrationOptions {
                 // Address randomly generated externally
                 new_admin_address: new_admin_address.to_string().to_some(),
+                verification_timeout_seconds: None,
+                allow_all_bech32_prefixes: None,
             }
             .to_some(),
+            false,
+            false,
         )
         .expect("expected a new admin address with correct bech32 specification to succeed");
         assert!(
@@ -167,20 +501,16 @@ mod tests {
             response.attributes.len(),
             "the migration should produce the correct number of attributes",
         );
-        assert_eq!(
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
             EventType::MigrateContract.event_name().as_str(),
-            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
-            "the proper event type attribute should be emitted",
         );
-        assert_eq!(
-            CONTRACT_VERSION,
-            single_attribute_for_key(&response, NEW_VALUE_KEY),
-            "the new value key should equate to the current contract version",
-        );
-        assert_eq!(
-            format!("[new_admin_address={new_admin_address}]"),
-            single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY),
-            "the additional metadata should specify the new admin address",
+        assert_response_attributes_contain(&response, NEW_VALUE_KEY, CONTRACT_VERSION);
+        assert_response_attributes_contain(
+            &response,
+            ADDITIONAL_METADATA_KEY,
+            &format!("[new_admin_address={new_admin_address}]"),
         );
         let state = STATE_V2
             .load(deps.as_ref().storage)
@@ -192,6 +522,515 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_successful_migration_with_verification_timeout_change() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let migrate_with_timeout = |deps: &mut MockOwnedDeps, timeout_seconds: u64| {
+            set_version_info(
+                deps.as_mut().storage,
+                &VersionInfoV1 {
+                    contract: CONTRACT_NAME.to_string(),
+                    version: "0.0.0".to_string(),
+                },
+            )
+            .expect("setting the initial version info should not fail");
+            migrate_contract(
+                deps.as_mut(),
+                &mock_env(),
+                MigrationOptions {
+                    new_admin_address: None,
+                    verification_timeout_seconds: timeout_seconds.to_some(),
+                    allow_all_bech32_prefixes: None,
+                }
+                .to_some(),
+                false,
+                false,
+            )
+            .expect("expected a migration with a verification timeout to succeed")
+        };
+        let response = migrate_with_timeout(&mut deps, 3600);
+        assert_response_attributes_contain(
+            &response,
+            ADDITIONAL_METADATA_KEY,
+            "[verification_timeout_seconds=3600]",
+        );
+        assert_eq!(
+            Some(3600),
+            STATE_V2
+                .load(deps.as_ref().storage)
+                .expect("expected the contract config to load without issue")
+                .verification_timeout_seconds,
+            "expected the verification timeout to be persisted in the contract state",
+        );
+        migrate_with_timeout(&mut deps, 0);
+        assert_eq!(
+            None,
+            STATE_V2
+                .load(deps.as_ref().storage)
+                .expect("expected the contract config to load without issue")
+                .verification_timeout_seconds,
+            "expected a zero verification timeout to remove the timeout from the contract state",
+        );
+    }
+
+    #[test]
+    fn test_successful_migration_rewrites_legacy_verifier_costs() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        set_version_info(
+            deps.as_mut().storage,
+            &VersionInfoV1 {
+                contract: CONTRACT_NAME.to_string(),
+                version: "0.0.0".to_string(),
+            },
+        )
+        .expect("setting the initial version info should not fail");
+        // Directly write an asset definition in the legacy format, keyed the same way as the
+        // asset definitions map: a length-prefixed namespace followed by the asset type
+        let storage_key = [
+            b"\x00\x14asset_definitions_v2".as_slice(),
+            DEFAULT_ASSET_TYPE.as_bytes(),
+        ]
+        .concat();
+        let legacy_definition = format!(
+            r#"{{"asset_type":"{}","display_name":null,"verifiers":[{{"address":"{}","onboarding_cost":"100","onboarding_denom":"nhash","fee_destinations":[{{"address":"{}","fee_amount":"25","entity_detail":null}}],"entity_detail":null,"retry_cost":null,"subsequent_classification_detail":null}}],"enabled":true}}"#,
+            DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+        );
+        deps.as_mut()
+            .storage
+            .set(&storage_key, legacy_definition.as_bytes());
+        migrate_contract(deps.as_mut(), &mock_env(), None, false, false)
+            .expect("the migration should succeed with a legacy asset definition in storage");
+        let stored_definition = String::from_utf8(
+            deps.as_ref()
+                .storage
+                .get(&storage_key)
+                .expect("the asset definition should still exist after the migration"),
+        )
+        .expect("the stored asset definition should be valid utf8");
+        assert!(
+            stored_definition.contains(r#""onboarding_cost":{"cost":"100""#),
+            "the stored asset definition should be rewritten in the new format, but got: {}",
+            stored_definition,
+        );
+        let verifier = load_asset_definition_by_type_v3(deps.as_ref().storage, DEFAULT_ASSET_TYPE)
+            .expect("the migrated asset definition should load successfully")
+            .get_verifier_detail(DEFAULT_VERIFIER_ADDRESS)
+            .expect("the verifier should be present on the migrated asset definition");
+        assert_eq!(
+            OnboardingCost::new(100, &[FeeDestinationV2::new(DEFAULT_VERIFIER_ADDRESS, 25)]),
+            verifier.onboarding_cost,
+            "the legacy cost values should be preserved in the new onboarding cost node",
+        );
+    }
+
+    #[test]
+    fn test_migration_from_current_version_skips_verifier_cost_rewrite() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        set_version_info(
+            deps.as_mut().storage,
+            &VersionInfoV1 {
+                contract: CONTRACT_NAME.to_string(),
+                version: VERIFIER_ONBOARDING_COST_MIGRATION_VERSION.to_string(),
+            },
+        )
+        .expect("setting the initial version info should not fail");
+        let legacy_definition = format!(
+            r#"{{"asset_type":"{}","display_name":null,"verifiers":[{{"address":"{}","onboarding_cost":"100","onboarding_denom":"nhash","fee_destinations":[],"entity_detail":null,"retry_cost":null,"subsequent_classification_detail":null}}],"enabled":true}}"#,
+            DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS,
+        );
+        deps.as_mut().storage.set(
+            &asset_definition_storage_key(DEFAULT_ASSET_TYPE),
+            legacy_definition.as_bytes(),
+        );
+        migrate_contract(deps.as_mut(), &mock_env(), None, false, false)
+            .expect("the migration should succeed");
+        assert_eq!(
+            legacy_definition.as_bytes(),
+            deps.as_ref()
+                .storage
+                .get(&asset_definition_storage_key(DEFAULT_ASSET_TYPE))
+                .expect("the asset definition should still exist after the migration"),
+            "definitions should not be rewritten when migrating from a version that already stores the new cost format",
+        );
+    }
+
+    #[test]
+    fn test_migrate_verifier_onboarding_costs_fails_for_unreadable_definition() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        deps.as_mut().storage.set(
+            &asset_definition_storage_key("corrupt"),
+            br#"{"asset_type":"corrupt"}"#,
+        );
+        let error = migrate_verifier_onboarding_costs(deps.as_mut().storage)
+            .expect_err("an unreadable asset definition should fail the rewrite");
+        assert!(
+            matches!(error, ContractError::Std(_)),
+            "the storage error should be propagated, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_successful_migration_backfills_legacy_fee_payment_detail_creation() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        // Details stored before creation tracking deserialize with zeroed values
+        let legacy_detail = FeePaymentDetail {
+            created_at: 0,
+            created_at_block: 0,
+            ..get_duped_fee_payment_detail(DEFAULT_SCOPE_ADDRESS)
+        };
+        insert_fee_payment_detail(deps.as_mut().storage, &legacy_detail, DEFAULT_ASSET_TYPE)
+            .expect("the legacy detail should be inserted");
+        let recent_detail = FeePaymentDetail {
+            created_at: 100,
+            created_at_block: 10,
+            ..get_duped_fee_payment_detail(DEFAULT_SCOPE_ADDRESS)
+        };
+        insert_fee_payment_detail(
+            deps.as_mut().storage,
+            &recent_detail,
+            DEFAULT_SECONDARY_ASSET_TYPE,
+        )
+        .expect("the recent detail should be inserted");
+        let env = mock_env();
+        assert!(
+            legacy_detail.is_expired(env.block.time.seconds()),
+            "sanity check: the legacy detail should be considered expired before the migration",
+        );
+        migrate_contract(deps.as_mut(), &env, None, false, false)
+            .expect("the migration should succeed");
+        let migrated_detail =
+            load_fee_payment_detail(&deps.storage, DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+                .expect("the legacy detail should still exist after the migration");
+        assert_eq!(
+            env.block.time.seconds(),
+            migrated_detail.created_at,
+            "the legacy detail should be stamped with the migration block time",
+        );
+        assert_eq!(
+            env.block.height, migrated_detail.created_at_block,
+            "the legacy detail should be stamped with the migration block height",
+        );
+        assert!(
+            !migrated_detail.is_expired(env.block.time.seconds()),
+            "the legacy detail should no longer be considered expired",
+        );
+        assert_eq!(
+            recent_detail,
+            load_fee_payment_detail(
+                &deps.storage,
+                DEFAULT_SCOPE_ADDRESS,
+                DEFAULT_SECONDARY_ASSET_TYPE,
+            )
+            .expect("the recent detail should still exist after the migration"),
+            "details that already track their creation should not be modified",
+        );
+    }
+
+    #[test]
+    fn test_successful_migration_backfills_asset_definition_count() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(
+            deps.as_mut(),
+            &InstArgs::default_with_additional_asset_types(vec![DEFAULT_SECONDARY_ASSET_TYPE]),
+        );
+        set_version_info(
+            deps.as_mut().storage,
+            &VersionInfoV1 {
+                contract: CONTRACT_NAME.to_string(),
+                version: "0.0.0".to_string(),
+            },
+        )
+        .expect("setting the initial version info should not fail");
+        // Simulate a state stored before the asset definition count was tracked
+        let mut state = STATE_V2
+            .load(deps.as_ref().storage)
+            .expect("expected the contract state to load");
+        state.asset_definition_count = 0;
+        STATE_V2
+            .save(deps.as_mut().storage, &state)
+            .expect("expected the contract state to save");
+        migrate_contract(deps.as_mut(), &mock_env(), None, false, false)
+            .expect("the migration should succeed");
+        assert_eq!(
+            2,
+            STATE_V2
+                .load(deps.as_ref().storage)
+                .expect("expected the contract state to load after the migration")
+                .asset_definition_count,
+            "the migration should recalculate the asset definition count from storage",
+        );
+    }
+
+    #[test]
+    fn test_successful_migration_populates_scope_spec_addresses() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the asset onboard to succeed");
+        mock_attribute_accounts_response(&mut deps, vec![DEFAULT_SCOPE_ADDRESS.to_string()], 10);
+        ScopeRequest::mock_response(
+            &mut deps.querier,
+            mock_scope_response_with_spec_address(get_default_scope(), DEFAULT_SCOPE_SPEC_ADDRESS),
+        );
+        let response = migrate_contract(deps.as_mut(), &mock_env(), None, true, false)
+            .expect("the migration should succeed when populating scope specs");
+        assert_response_messages_count(
+            &response,
+            1,
+            "a single attribute update should be produced for the onboarded asset",
+        );
+        assert_response_attributes_contain(
+            &response,
+            ADDITIONAL_METADATA_KEY,
+            "[scope_specs_populated=1]",
+        );
+        let update_request = try_into_update_attribute_request(&response.messages[0].msg)
+            .expect("the message should be an update attribute request");
+        let original_attribute =
+            AssetScopeAttribute::from_binary(&update_request.name, &update_request.original_value)
+                .expect("the original value should deserialize to a scope attribute");
+        let updated_attribute =
+            AssetScopeAttribute::from_binary(&update_request.name, &update_request.update_value)
+                .expect("the update value should deserialize to a scope attribute");
+        assert!(
+            original_attribute.scope_spec_address.is_none(),
+            "the original attribute should not have a scope spec address",
+        );
+        assert_eq!(
+            AssetScopeAttribute {
+                scope_spec_address: DEFAULT_SCOPE_SPEC_ADDRESS.to_string().to_some(),
+                ..original_attribute
+            },
+            updated_attribute,
+            "only the scope spec address should be changed on the updated attribute",
+        );
+        // Reflect the update in the mock querier to ensure a second run does not update it again
+        intercept_add_or_update_attribute(
+            &mut deps,
+            response,
+            "failure occurred when intercepting the scope spec update",
+        )
+        .expect("the attribute update should be intercepted");
+        let response = migrate_contract(deps.as_mut(), &mock_env(), None, true, false)
+            .expect("the migration should succeed when running a second time");
+        assert!(
+            response.messages.is_empty(),
+            "attributes with an existing scope spec address should not be updated",
+        );
+        assert_response_attributes_contain(
+            &response,
+            ADDITIONAL_METADATA_KEY,
+            "[scope_specs_populated=0]",
+        );
+    }
+
+    #[test]
+    fn test_populate_scope_spec_addresses_finds_attributes_missing_from_the_index() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        // Simulate an attribute written before the verifier status index existed
+        QueryAttributeRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributeResponse {
+                account: DEFAULT_SCOPE_ADDRESS.to_string(),
+                attributes: vec![build_attribute(
+                    DEFAULT_SCOPE_ADDRESS,
+                    &get_default_asset_scope_attribute(),
+                )],
+                pagination: None,
+            },
+        );
+        mock_attribute_accounts_response(&mut deps, vec![DEFAULT_SCOPE_ADDRESS.to_string()], 10);
+        ScopeRequest::mock_response(
+            &mut deps.querier,
+            mock_scope_response_with_spec_address(get_default_scope(), DEFAULT_SCOPE_SPEC_ADDRESS),
+        );
+        let (updated_count, messages) =
+            populate_scope_spec_addresses(&mut deps.as_mut(), &mock_env())
+                .expect("populating scope specs should succeed");
+        assert_eq!(
+            1, updated_count,
+            "the attribute should be updated even though it was never indexed by the contract",
+        );
+        assert_eq!(
+            1,
+            messages.len(),
+            "a single attribute update should be produced for the attribute",
+        );
+    }
+
+    #[test]
+    fn test_populate_scope_spec_addresses_skips_scopes_without_spec_info() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the asset onboard to succeed");
+        mock_attribute_accounts_response(&mut deps, vec![DEFAULT_SCOPE_ADDRESS.to_string()], 10);
+        // The default scope response produced by setup_test_suite includes no id info
+        let (updated_count, messages) =
+            populate_scope_spec_addresses(&mut deps.as_mut(), &mock_env())
+                .expect("populating scope specs should succeed");
+        assert_eq!(
+            0, updated_count,
+            "no attributes should be updated when the scope has no specification info",
+        );
+        assert!(
+            messages.is_empty(),
+            "no messages should be produced when no attributes are updated",
+        );
+    }
+
+    #[test]
+    fn test_normalize_asset_type_case_leaves_lowercase_definitions_unchanged() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(
+            deps.as_mut(),
+            &InstArgs::default_with_additional_asset_types(vec![DEFAULT_SECONDARY_ASSET_TYPE]),
+        );
+        let definitions_before = list_asset_definitions_v3(deps.as_ref().storage);
+        normalize_asset_type_case(deps.as_mut().storage)
+            .expect("normalization should succeed for all-lowercase asset types");
+        assert_eq!(
+            definitions_before,
+            list_asset_definitions_v3(deps.as_ref().storage),
+            "lowercase asset definitions should not be modified by normalization",
+        );
+    }
+
+    #[test]
+    fn test_normalize_asset_type_case_lowercases_mixed_case_definitions() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        store_raw_asset_definition(&mut deps, "HELOC", "HELOC");
+        normalize_asset_type_case(deps.as_mut().storage)
+            .expect("normalization should succeed when no collisions exist");
+        let asset_types = list_asset_definitions_v3(deps.as_ref().storage)
+            .into_iter()
+            .map(|definition| definition.asset_type)
+            .collect::<Vec<String>>();
+        assert_eq!(
+            vec!["test_asset".to_string(), "heloc".to_string()],
+            asset_types,
+            "the mixed-case asset type should be lowercased and the original key should be removed",
+        );
+        assert!(
+            deps.as_ref()
+                .storage
+                .get(&asset_definition_storage_key("HELOC"))
+                .is_none(),
+            "the asset definition should no longer be stored under its mixed-case key",
+        );
+    }
+
+    #[test]
+    fn test_normalize_asset_type_case_detects_collisions() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        store_raw_asset_definition(&mut deps, "HELOC", "HELOC");
+        store_raw_asset_definition(&mut deps, "heloc", "heloc");
+        let error = normalize_asset_type_case(deps.as_mut().storage)
+            .expect_err("normalization should fail when asset types collide after lowercasing");
+        match error {
+            ContractError::MigrationConflict { conflicting_types } => {
+                assert_eq!(
+                    vec!["HELOC".to_string(), "heloc".to_string()],
+                    conflicting_types,
+                    "both colliding asset types should be reported",
+                );
+            }
+            _ => panic!("unexpected error encountered: {:?}", error),
+        };
+        assert_eq!(
+            3,
+            list_asset_definitions_v3(deps.as_ref().storage).len(),
+            "no asset definitions should be modified when a collision is detected",
+        );
+    }
+
+    #[test]
+    fn test_migration_from_old_version_rejects_asset_type_collisions() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        set_version_info(
+            deps.as_mut().storage,
+            &VersionInfoV1 {
+                contract: CONTRACT_NAME.to_string(),
+                version: "0.0.0".to_string(),
+            },
+        )
+        .expect("setting the initial version info should not fail");
+        store_raw_asset_definition(&mut deps, "HELOC", "HELOC");
+        store_raw_asset_definition(&mut deps, "heloc", "heloc");
+        let error = migrate_contract(deps.as_mut(), &mock_env(), None, false, false)
+            .expect_err("the migration should fail when asset types collide");
+        assert!(
+            matches!(error, ContractError::MigrationConflict { .. }),
+            "expected a migration conflict error, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_migration_from_old_version_normalizes_mixed_case_definition_before_rewriting_costs() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        set_version_info(
+            deps.as_mut().storage,
+            &VersionInfoV1 {
+                contract: CONTRACT_NAME.to_string(),
+                version: "0.0.0".to_string(),
+            },
+        )
+        .expect("setting the initial version info should not fail");
+        // A single mixed-case definition with no lowercase twin cannot be found by its derived
+        // storage key until it has been normalized
+        store_raw_asset_definition(&mut deps, "HELOC", "HELOC");
+        migrate_contract(deps.as_mut(), &mock_env(), None, false, false)
+            .expect("the migration should succeed with a mixed-case asset definition in storage");
+        assert_eq!(
+            "heloc",
+            load_asset_definition_by_type_v3(deps.as_ref().storage, "heloc")
+                .expect("the normalized asset definition should load successfully")
+                .asset_type,
+            "the asset type should be lowercased by the migration",
+        );
+        assert!(
+            deps.as_ref()
+                .storage
+                .get(&asset_definition_storage_key("HELOC"))
+                .is_none(),
+            "the asset definition should no longer be stored under its mixed-case key",
+        );
+        assert_eq!(
+            2,
+            STATE_V2
+                .load(deps.as_ref().storage)
+                .expect("expected the contract state to load after the migration")
+                .asset_definition_count,
+            "the normalized definition should be counted exactly once",
+        );
+    }
+
+    fn asset_definition_storage_key(key: &str) -> Vec<u8> {
+        [b"\x00\x14asset_definitions_v2".as_slice(), key.as_bytes()].concat()
+    }
+
+    fn store_raw_asset_definition(deps: &mut MockOwnedDeps, key: &str, asset_type: &str) {
+        deps.as_mut().storage.set(
+            &asset_definition_storage_key(key),
+            &to_json_vec(&AssetDefinitionV3::new(asset_type, None::<String>, vec![]))
+                .expect("the asset definition should serialize"),
+        );
+    }
+
     #[test]
     fn test_failed_migration_for_incorrect_name() {
         let mut deps = mock_provenance_dependencies();
@@ -203,7 +1042,7 @@ mod tests {
             },
         )
         .unwrap();
-        let error = migrate_contract(deps.as_mut(), None).unwrap_err();
+        let error = migrate_contract(deps.as_mut(), &mock_env(), None, false, false).unwrap_err();
         match error {
             ContractError::InvalidContractName {
                 current_contract,
@@ -235,7 +1074,7 @@ mod tests {
             },
         )
         .unwrap();
-        let error = migrate_contract(deps.as_mut(), None).unwrap_err();
+        let error = migrate_contract(deps.as_mut(), &mock_env(), None, false, false).unwrap_err();
         match error {
             ContractError::InvalidContractVersion {
                 current_version,
@@ -270,10 +1109,15 @@ mod tests {
         .expect("overriding version info should not fail");
         let error = migrate_contract(
             deps.as_mut(),
+            &mock_env(),
             MigrationOptions {
                 new_admin_address: "not a bech32 thing that's for sure".to_string().to_some(),
+                verification_timeout_seconds: None,
+                allow_all_bech32_prefixes: None,
             }
             .to_some(),
+            false,
+            false,
         )
         .expect_err(
             "expected an error to occur when using a non-bech32 address as the new admin address",
@@ -283,4 +1127,177 @@ mod tests {
             "expected a bech32 error to occur when an invalid bech32 address was provided as the new admin",
         );
     }
+
+    #[test]
+    fn test_successful_migration_backfills_verifier_status_index() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        // Simulate an attribute written before the verifier status index existed
+        let legacy_attribute = get_default_asset_scope_attribute();
+        QueryAttributeRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributeResponse {
+                account: DEFAULT_SCOPE_ADDRESS.to_string(),
+                attributes: vec![build_attribute(DEFAULT_SCOPE_ADDRESS, &legacy_attribute)],
+                pagination: None,
+            },
+        );
+        mock_attribute_accounts_response(&mut deps, vec![DEFAULT_SCOPE_ADDRESS.to_string()], 10);
+        assert_eq!(
+            0,
+            count_pending_for_default_verifier(&deps),
+            "the legacy attribute should not be counted before the backfill",
+        );
+        let response = migrate_contract(deps.as_mut(), &mock_env(), None, false, true)
+            .expect("the migration should succeed when backfilling the verifier status index");
+        assert!(
+            response.messages.is_empty(),
+            "backfilling the verifier status index should not produce messages",
+        );
+        assert_response_attributes_contain(
+            &response,
+            ADDITIONAL_METADATA_KEY,
+            "[verifier_status_index_backfilled=1]",
+        );
+        assert_eq!(
+            1,
+            count_pending_for_default_verifier(&deps),
+            "the legacy attribute should be counted after the backfill",
+        );
+        migrate_contract(deps.as_mut(), &mock_env(), None, false, true)
+            .expect("the migration should succeed when backfilling a second time");
+        assert_eq!(
+            1,
+            count_pending_for_default_verifier(&deps),
+            "backfilling a second time should not count the attribute twice",
+        );
+    }
+
+    #[test]
+    fn test_successful_migration_backfills_requestor_access_route_and_modified_block_indexes() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        // Simulate an attribute written before the indexes existed
+        let legacy_attribute = get_default_asset_scope_attribute();
+        QueryAttributeRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributeResponse {
+                account: DEFAULT_SCOPE_ADDRESS.to_string(),
+                attributes: vec![build_attribute(DEFAULT_SCOPE_ADDRESS, &legacy_attribute)],
+                pagination: None,
+            },
+        );
+        mock_attribute_accounts_response(&mut deps, vec![DEFAULT_SCOPE_ADDRESS.to_string()], 10);
+        assert_eq!(
+            (vec![], vec![], vec![]),
+            list_indexed_scopes(&deps),
+            "the legacy attribute should not be indexed before the backfill",
+        );
+        let expected_indexed_scopes = (
+            vec![(
+                DEFAULT_SCOPE_ADDRESS.to_string(),
+                vec![DEFAULT_ASSET_TYPE.to_string()],
+            )],
+            vec![(
+                DEFAULT_SCOPE_ADDRESS.to_string(),
+                vec![DEFAULT_ASSET_TYPE.to_string()],
+            )],
+            vec![DEFAULT_SCOPE_ADDRESS.to_string()],
+        );
+        migrate_contract(deps.as_mut(), &mock_env(), None, false, true)
+            .expect("the migration should succeed when backfilling the scope attribute indexes");
+        assert_eq!(
+            expected_indexed_scopes,
+            list_indexed_scopes(&deps),
+            "the legacy attribute should be found by requestor, access route owner and modified block after the backfill",
+        );
+        migrate_contract(deps.as_mut(), &mock_env(), None, false, true)
+            .expect("the migration should succeed when backfilling a second time");
+        assert_eq!(
+            expected_indexed_scopes,
+            list_indexed_scopes(&deps),
+            "backfilling a second time should not index the attribute twice",
+        );
+    }
+
+    #[test]
+    fn test_backfill_scope_attribute_indexes_pages_through_all_accounts() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        let second_scope_address = "scope1qz9puy0kad8htbj8xfe2lhnlqr5sp8rqms";
+        let attributes = vec![
+            build_attribute(DEFAULT_SCOPE_ADDRESS, &get_default_asset_scope_attribute()),
+            build_attribute(
+                second_scope_address,
+                &AssetScopeAttribute {
+                    scope_address: second_scope_address.to_string(),
+                    ..get_default_asset_scope_attribute()
+                },
+            ),
+        ];
+        deps.querier.registered_custom_queries.insert(
+            "/provenance.attribute.v1.Query/Attribute".to_string(),
+            Box::new(move |data| {
+                let request = QueryAttributeRequest::try_from(data.to_owned())
+                    .expect("the attribute request should decode");
+                let response = QueryAttributeResponse {
+                    account: request.account.to_owned(),
+                    attributes: attributes
+                        .iter()
+                        .filter(|attribute| attribute.address == request.account)
+                        .cloned()
+                        .collect(),
+                    pagination: None,
+                };
+                SystemResult::Ok(ContractResult::Ok(to_query_response_binary(
+                    response.to_proto_bytes(),
+                )))
+            }),
+        );
+        // A page size of one forces a second attribute accounts query to find the second scope
+        mock_attribute_accounts_response(
+            &mut deps,
+            vec![
+                DEFAULT_SCOPE_ADDRESS.to_string(),
+                second_scope_address.to_string(),
+            ],
+            1,
+        );
+        let indexed_count = backfill_scope_attribute_indexes(&mut deps.as_mut())
+            .expect("backfilling the scope attribute indexes should succeed");
+        assert_eq!(
+            2, indexed_count,
+            "the attributes from both pages of accounts should be indexed",
+        );
+        assert_eq!(
+            2,
+            count_pending_for_default_verifier(&deps),
+            "both attributes should be counted after the backfill",
+        );
+    }
+
+    /// Lists the scopes indexed for the default sender as a requestor and as an access route owner,
+    /// alongside every scope in the last modified block index.
+    fn list_indexed_scopes(deps: &MockOwnedDeps) -> IndexedScopes {
+        let storage = deps.as_ref().storage;
+        let (requestor_scopes, _) =
+            list_requestor_index_page(storage, DEFAULT_SENDER_ADDRESS, None, None, 10)
+                .expect("listing scopes by requestor should succeed");
+        let access_route_owner_scopes =
+            list_access_route_owner_index_page(storage, DEFAULT_SENDER_ADDRESS, None, 10)
+                .expect("listing scopes by access route owner should succeed");
+        let modified_scopes = list_scopes_modified_after_block(storage, 0, None)
+            .expect("listing recently modified scopes should succeed");
+        (requestor_scopes, access_route_owner_scopes, modified_scopes)
+    }
+
+    fn count_pending_for_default_verifier(deps: &MockOwnedDeps) -> u64 {
+        count_assets_by_verifier_and_status(
+            deps.as_ref().storage,
+            DEFAULT_VERIFIER_ADDRESS,
+            &AssetOnboardingStatus::Pending,
+            None,
+        )
+        .expect("counting pending assets should succeed")
+    }
 }
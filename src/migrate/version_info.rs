@@ -1,4 +1,4 @@
-use crate::{core::error::ContractError, util::aliases::AssetResult};
+use crate::util::{aliases::AssetResult, traits::IntoContractError};
 use cosmwasm_std::Storage;
 use cw_storage_plus::Item;
 use schemars::JsonSchema;
@@ -46,7 +46,7 @@ pub fn set_version_info(
 ) -> AssetResult<()> {
     VERSION_INFO
         .save(storage, version_info)
-        .map_err(ContractError::Std)
+        .map_err(|e| e.into_contract_error())
 }
 
 /// Fetches, if possible, the current version information for the contract.
@@ -55,7 +55,9 @@ pub fn set_version_info(
 ///
 /// * `storage` A read-only instance of the contract's internal storage.
 pub fn get_version_info(storage: &dyn Storage) -> AssetResult<VersionInfoV1> {
-    VERSION_INFO.load(storage).map_err(ContractError::Std)
+    VERSION_INFO
+        .load(storage)
+        .map_err(|e| e.into_contract_error())
 }
 
 /// Sets the version info for the given contract to the derived values from the Cargo.toml file.
@@ -1,23 +1,87 @@
 //! Contains the functionality used in the [contract file](crate::contract) to perform a contract query.
 
+/// A query that collects every [AccessDefinition](crate::core::types::access_definition::AccessDefinition)
+/// on a Provenance Blockchain Metadata Scope, across all of its asset types.
+pub mod query_access_definitions;
+/// A query that lists every [AccessRoute](crate::core::types::access_route::AccessRoute) owned by
+/// an address, across all scopes and asset types.
+pub mod query_access_routes_by_owner;
+/// A debugging query that returns all of the contract's queryable configuration in a single
+/// response.  Only available when debug queries are enabled in the contract's state.
+pub mod query_all;
 /// A query that fetches a target [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
 /// from the contract's internal storage.
 pub mod query_asset_definition;
+/// A query that returns the total number of [AssetDefinitionV3s](crate::core::types::asset_definition::AssetDefinitionV3)
+/// stored in the contract.
+pub mod query_asset_definition_count;
 /// A query that fetches all [AssetDefinitionV3s](crate::core::types::asset_definition::AssetDefinitionV3)
 /// from the contract's internal storage.
 pub mod query_asset_definitions;
+/// A query that fetches a single page of the [AssetDefinitionV3s](crate::core::types::asset_definition::AssetDefinitionV3)
+/// that carry a specific label, sorted by asset type.
+pub mod query_asset_definitions_by_label;
+/// A query that fetches a single page of [AssetDefinitionV3s](crate::core::types::asset_definition::AssetDefinitionV3)
+/// from the contract's internal storage, sorted by asset type.
+pub mod query_asset_definitions_page;
 /// A query that attempts to find all [AssetScopeAttribute](crate::core::types::asset_scope_attribute::AssetScopeAttribute)s
 /// on a Provenance Blockchain Metadata Scope that was added by this contract.
 pub mod query_asset_scope_attribute;
 /// A query that attempts to find an [AssetScopeAttribute](crate::core::types::asset_scope_attribute::AssetScopeAttribute)
 /// for a specific asset type on a Provenance Blockchain Metadata Scope that was added by this contract.
 pub mod query_asset_scope_attribute_by_asset_type;
+/// A query that attempts to find [AssetScopeAttribute](crate::core::types::asset_scope_attribute::AssetScopeAttribute)s
+/// for many scope and asset type pairs at once, reporting failures for each pair individually.
+pub mod query_asset_scope_attributes_batch;
+/// A query that fetches the [AssetScopeAttribute](crate::core::types::asset_scope_attribute::AssetScopeAttribute)s
+/// of all scopes onboarded by a specific requestor.
+pub mod query_assets_by_requestor;
+/// A query that returns the operational metrics tracked by the contract as assets are onboarded
+/// and verified.
+pub mod query_contract_metrics;
+/// A query that returns aggregate counts and totals describing the contract's asset definitions,
+/// onboarded assets, and stored fee payment details.
+pub mod query_contract_statistics;
+/// A query that fetches every deprecated [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3),
+/// along with the asset type that should be used in its place.
+pub mod query_deprecated_definitions;
+/// A query that calculates the exact [FeePaymentDetail](crate::core::types::fee_payment_detail::FeePaymentDetail)
+/// that onboarding a scope would produce, based on the scope's existing classifications.
+pub mod query_effective_fee;
 /// A query that attempts to find a [FeePaymentDetail](crate::core::types::fee_payment_detail::FeePaymentDetail)
 /// stored for an [AssetScopeAttribute](crate::core::types::asset_scope_attribute::AssetScopeAttribute)
 /// that has not yet finished its asset verification step.
 pub mod query_fee_payments;
+/// A query that attempts to find the most recent [AssetVerificationResult](crate::core::types::asset_verification_result::AssetVerificationResult)
+/// for a specific asset type on a Provenance Blockchain Metadata Scope, without returning the full
+/// [AssetScopeAttribute](crate::core::types::asset_scope_attribute::AssetScopeAttribute).
+pub mod query_latest_verification_result;
+/// A query that calculates the fees that would be charged for onboarding a scope, without
+/// onboarding it.
+pub mod query_onboarding_fee_preview;
+/// A query that fetches the [AssetScopeAttribute](crate::core::types::asset_scope_attribute::AssetScopeAttribute)s
+/// currently awaiting verification by a specific verifier.
+pub mod query_pending_verifications;
+/// A query that fetches the scope addresses of all assets whose [AssetScopeAttribute](crate::core::types::asset_scope_attribute::AssetScopeAttribute)
+/// was modified after a specific block height.
+pub mod query_recently_modified_scopes;
+/// A query that counts the [AssetScopeAttribute](crate::core::types::asset_scope_attribute::AssetScopeAttribute)s
+/// on a Provenance Blockchain Metadata Scope without deserializing their values.
+pub mod query_scope_attribute_count;
+/// A query that finds the asset type of the [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
+/// bound to a specific scope specification.
+pub mod query_scope_spec_binding;
 /// A query that directly returns the contract's stored [StateV2](crate::core::state::StateV2) value.
 pub mod query_state;
+/// A debugging query that counts the keys held in each of the contract's storage maps.  Only
+/// available when debug queries are enabled in the contract's state.
+pub mod query_storage_key_count;
+/// A query that counts the verifiers registered to a specific [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3).
+pub mod query_verifier_count;
+/// A query that counts the assets currently awaiting verification by a specific verifier.
+pub mod query_verifier_pending_count;
+/// Queries that fetch the approved, denied and pending onboarding counts of verifiers.
+pub mod query_verifier_statistics;
 /// A query that directly returns the contract's stored [VersionInfoV1](crate::migrate::version_info::VersionInfoV1)
 /// value.
 pub mod query_version;
@@ -0,0 +1,230 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+use crate::core::types::access_definitions_response::{
+    AccessDefinitionWithType, AccessDefinitionsResponse,
+};
+use crate::query::query_asset_scope_attribute::may_query_scope_attribute_by_scope_address;
+use crate::util::aliases::AssetResult;
+
+/// Fetches every [AssetScopeAttribute](crate::core::types::asset_scope_attribute::AssetScopeAttribute)
+/// on a scope and flattens their [access_definitions](crate::core::types::asset_scope_attribute::AssetScopeAttribute::access_definitions)
+/// into an [AccessDefinitionsResponse](crate::core::types::access_definitions_response::AccessDefinitionsResponse).
+/// Definitions that share an asset type, owner and definition type are merged, and identical
+/// access routes within them are only included once.  A scope without any scope attributes
+/// produces an empty list of definitions.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `scope_address` The bech32 address of the scope for which to retrieve access definitions.
+pub fn query_access_definitions_for_scope<S: Into<String>>(
+    deps: &Deps,
+    scope_address: S,
+) -> AssetResult<Binary> {
+    let scope_address: String = scope_address.into();
+    let mut scope_attributes =
+        may_query_scope_attribute_by_scope_address(deps, &scope_address)?.unwrap_or_default();
+    // Attributes are produced in no particular order, so sort them to keep the response stable
+    scope_attributes.sort_by(|a, b| a.asset_type.cmp(&b.asset_type));
+    let mut definitions: Vec<AccessDefinitionWithType> = vec![];
+    for attribute in scope_attributes {
+        for definition in attribute.access_definitions {
+            let tagged_definition =
+                AccessDefinitionWithType::new(&attribute.asset_type, definition);
+            if let Some(existing_definition) = definitions.iter_mut().find(|existing| {
+                existing.asset_type == tagged_definition.asset_type
+                    && existing.owner_address == tagged_definition.owner_address
+                    && existing.definition_type == tagged_definition.definition_type
+            }) {
+                existing_definition
+                    .access_routes
+                    .extend(tagged_definition.access_routes);
+            } else {
+                definitions.push(tagged_definition);
+            }
+        }
+    }
+    for definition in definitions.iter_mut() {
+        let mut unique_routes = vec![];
+        for route in definition.access_routes.drain(..) {
+            if !unique_routes.contains(&route) {
+                unique_routes.push(route);
+            }
+        }
+        definition.access_routes = unique_routes;
+    }
+    to_json_binary(&AccessDefinitionsResponse {
+        scope_address,
+        definitions,
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::from_json;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::types::access_definition::{AccessDefinition, AccessDefinitionType};
+    use crate::core::types::access_definitions_response::{
+        AccessDefinitionWithType, AccessDefinitionsResponse,
+    };
+    use crate::core::types::access_route::AccessRoute;
+    use crate::core::types::asset_scope_attribute::AssetScopeAttribute;
+    use crate::testutil::attribute_utilities::mock_attribute_response_for_scope;
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS, DEFAULT_SCOPE_SPEC_ADDRESS,
+        DEFAULT_SECONDARY_ASSET_TYPE, DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        build_attribute, get_default_asset_scope_attribute, mock_scope,
+        setup_no_attribute_response, test_instantiate_success, InstArgs, MockOwnedDeps,
+    };
+
+    use super::query_access_definitions_for_scope;
+
+    #[test]
+    fn test_query_aggregates_definitions_across_asset_types() {
+        let mut deps = setup_scope();
+        let primary_attribute = AssetScopeAttribute {
+            access_definitions: vec![
+                requestor_definition(vec![AccessRoute::route_only("primary-route")]),
+                AccessDefinition {
+                    owner_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
+                    access_routes: vec![AccessRoute::route_only("verifier-route")],
+                    definition_type: AccessDefinitionType::Verifier,
+                },
+            ],
+            ..get_default_asset_scope_attribute()
+        };
+        let secondary_attribute = AssetScopeAttribute {
+            asset_type: DEFAULT_SECONDARY_ASSET_TYPE.to_string(),
+            access_definitions: vec![requestor_definition(vec![AccessRoute::route_only(
+                "secondary-route",
+            )])],
+            ..get_default_asset_scope_attribute()
+        };
+        mock_attribute_response_for_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![
+                build_attribute(DEFAULT_SCOPE_ADDRESS, &secondary_attribute),
+                build_attribute(DEFAULT_SCOPE_ADDRESS, &primary_attribute),
+            ],
+        );
+        let response = query_definitions(&deps);
+        assert_eq!(
+            DEFAULT_SCOPE_ADDRESS, response.scope_address,
+            "the queried scope address should be included in the response",
+        );
+        assert_eq!(
+            vec![
+                AccessDefinitionWithType {
+                    asset_type: DEFAULT_ASSET_TYPE.to_string(),
+                    owner_address: DEFAULT_SENDER_ADDRESS.to_string(),
+                    access_routes: vec![AccessRoute::route_only("primary-route")],
+                    definition_type: AccessDefinitionType::Requestor,
+                },
+                AccessDefinitionWithType {
+                    asset_type: DEFAULT_ASSET_TYPE.to_string(),
+                    owner_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
+                    access_routes: vec![AccessRoute::route_only("verifier-route")],
+                    definition_type: AccessDefinitionType::Verifier,
+                },
+                AccessDefinitionWithType {
+                    asset_type: DEFAULT_SECONDARY_ASSET_TYPE.to_string(),
+                    owner_address: DEFAULT_SENDER_ADDRESS.to_string(),
+                    access_routes: vec![AccessRoute::route_only("secondary-route")],
+                    definition_type: AccessDefinitionType::Requestor,
+                },
+            ],
+            response.definitions,
+            "the definitions from every asset type should be included, sorted by asset type",
+        );
+    }
+
+    #[test]
+    fn test_query_deduplicates_identical_routes() {
+        let mut deps = setup_scope();
+        let attribute = AssetScopeAttribute {
+            access_definitions: vec![
+                requestor_definition(vec![
+                    AccessRoute::route_only("route-one"),
+                    AccessRoute::route_only("route-one"),
+                ]),
+                requestor_definition(vec![
+                    AccessRoute::route_only("route-one"),
+                    AccessRoute::route_only("route-two"),
+                ]),
+            ],
+            ..get_default_asset_scope_attribute()
+        };
+        mock_attribute_response_for_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![build_attribute(DEFAULT_SCOPE_ADDRESS, &attribute)],
+        );
+        let response = query_definitions(&deps);
+        assert_eq!(
+            vec![AccessDefinitionWithType {
+                asset_type: DEFAULT_ASSET_TYPE.to_string(),
+                owner_address: DEFAULT_SENDER_ADDRESS.to_string(),
+                access_routes: vec![
+                    AccessRoute::route_only("route-one"),
+                    AccessRoute::route_only("route-two"),
+                ],
+                definition_type: AccessDefinitionType::Requestor,
+            }],
+            response.definitions,
+            "matching definitions should be merged and identical routes should only be included once",
+        );
+    }
+
+    #[test]
+    fn test_query_scope_with_no_attributes() {
+        let mut deps = setup_scope();
+        setup_no_attribute_response(&mut deps, None);
+        let response = query_definitions(&deps);
+        assert_eq!(
+            DEFAULT_SCOPE_ADDRESS, response.scope_address,
+            "the queried scope address should be included in the response",
+        );
+        assert!(
+            response.definitions.is_empty(),
+            "no definitions should be returned for a scope without attributes, but got: {:?}",
+            response.definitions,
+        );
+    }
+
+    fn setup_scope() -> MockOwnedDeps {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(
+            deps.as_mut(),
+            &InstArgs::default_with_additional_asset_types(vec![DEFAULT_SECONDARY_ASSET_TYPE]),
+        );
+        mock_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            DEFAULT_SCOPE_SPEC_ADDRESS,
+            DEFAULT_SENDER_ADDRESS,
+        );
+        deps
+    }
+
+    fn requestor_definition(access_routes: Vec<AccessRoute>) -> AccessDefinition {
+        AccessDefinition {
+            owner_address: DEFAULT_SENDER_ADDRESS.to_string(),
+            access_routes,
+            definition_type: AccessDefinitionType::Requestor,
+        }
+    }
+
+    fn query_definitions(deps: &MockOwnedDeps) -> AccessDefinitionsResponse {
+        let binary = query_access_definitions_for_scope(&deps.as_ref(), DEFAULT_SCOPE_ADDRESS)
+            .expect("expected the query to execute successfully");
+        from_json::<AccessDefinitionsResponse>(&binary)
+            .expect("expected the result to deserialize correctly")
+    }
+}
@@ -0,0 +1,274 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+use crate::core::error::ContractError;
+use crate::core::state::list_access_route_owner_index_page;
+use crate::core::types::access_route_owner_entry::AccessRouteOwnerEntry;
+use crate::query::query_asset_scope_attribute_by_asset_type::may_query_scope_attribute_by_scope_address_and_asset_type;
+use crate::util::aliases::AssetResult;
+use crate::util::constants::MAX_ACCESS_ROUTE_OWNER_SCOPES_PER_PAGE;
+
+/// A query that fetches a single page of the [AccessRoutes](crate::core::types::access_route::AccessRoute)
+/// owned by an address, sorted in ascending order by scope address and grouped by scope and asset
+/// type, and serializes them as a vector of [AccessRouteOwnerEntries](crate::core::types::access_route_owner_entry::AccessRouteOwnerEntry).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `owner_address` The bech32 address of the account that owns the access routes.
+/// * `start_after` The scope address of the final entry in a previous page.  When omitted, the
+/// first page is returned.
+/// * `limit` The maximum number of scopes to include in the page.  Must be greater than zero.
+/// Values greater than [MAX_ACCESS_ROUTE_OWNER_SCOPES_PER_PAGE](crate::util::constants::MAX_ACCESS_ROUTE_OWNER_SCOPES_PER_PAGE)
+/// are reduced to that limit, which is also used when no value is provided.
+pub fn query_access_routes_by_owner(
+    deps: &Deps,
+    owner_address: &str,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> AssetResult<Binary> {
+    let limit = limit.unwrap_or(MAX_ACCESS_ROUTE_OWNER_SCOPES_PER_PAGE);
+    if limit == 0 {
+        return ContractError::InvalidMessageFields {
+            message_type: "QueryMsg::QueryAccessRoutesByOwner".to_string(),
+            invalid_fields: vec!["limit: must be greater than zero".to_string()],
+        }
+        .to_err();
+    }
+    let scopes = list_access_route_owner_index_page(
+        deps.storage,
+        owner_address,
+        start_after,
+        limit.min(MAX_ACCESS_ROUTE_OWNER_SCOPES_PER_PAGE),
+    )?;
+    let mut entries = vec![];
+    for (scope_address, asset_types) in scopes {
+        for asset_type in asset_types {
+            let Some(scope_attribute) = may_query_scope_attribute_by_scope_address_and_asset_type(
+                deps,
+                &scope_address,
+                &asset_type,
+            )?
+            else {
+                continue;
+            };
+            let access_routes = scope_attribute
+                .access_definitions
+                .into_iter()
+                .filter(|definition| definition.owner_address == owner_address)
+                .flat_map(|definition| definition.access_routes)
+                .collect::<Vec<_>>();
+            if !access_routes.is_empty() {
+                entries.push(AccessRouteOwnerEntry::new(
+                    &scope_address,
+                    asset_type,
+                    access_routes,
+                ));
+            }
+        }
+    }
+    to_json_binary(&entries)?.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::from_json;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::state::update_access_route_owner_index;
+    use crate::core::types::access_definition::{AccessDefinition, AccessDefinitionType};
+    use crate::core::types::access_route::AccessRoute;
+    use crate::core::types::access_route_owner_entry::AccessRouteOwnerEntry;
+    use crate::core::types::asset_scope_attribute::AssetScopeAttribute;
+    use crate::testutil::attribute_utilities::mock_named_attribute_responses_by_scope;
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_SPEC_ADDRESS,
+        DEFAULT_SECONDARY_ASSET_TYPE, DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        build_attribute, get_default_asset_scope_attribute, mock_scope, test_instantiate_success,
+        InstArgs, MockOwnedDeps,
+    };
+    use crate::util::traits::OptionExtensions;
+
+    use super::query_access_routes_by_owner;
+
+    #[test]
+    fn test_query_returns_routes_across_scopes_and_asset_types() {
+        let deps = setup_owned_routes();
+        assert_eq!(
+            vec![
+                AccessRouteOwnerEntry::new(
+                    "scope_1",
+                    DEFAULT_ASSET_TYPE,
+                    vec![AccessRoute::route_only("scope_1-test_asset")],
+                ),
+                AccessRouteOwnerEntry::new(
+                    "scope_2",
+                    DEFAULT_ASSET_TYPE,
+                    vec![AccessRoute::route_only("scope_2-test_asset")],
+                ),
+                AccessRouteOwnerEntry::new(
+                    "scope_2",
+                    DEFAULT_SECONDARY_ASSET_TYPE,
+                    vec![AccessRoute::route_only("scope_2-test_asset_2")],
+                ),
+                AccessRouteOwnerEntry::new(
+                    "scope_3",
+                    DEFAULT_SECONDARY_ASSET_TYPE,
+                    vec![AccessRoute::route_only("scope_3-test_asset_2")],
+                ),
+            ],
+            query_page(&deps, DEFAULT_SENDER_ADDRESS, None, None),
+            "expected the owner's routes for every scope and asset type to be returned",
+        );
+    }
+
+    #[test]
+    fn test_query_returns_only_routes_owned_by_the_address() {
+        let deps = setup_owned_routes();
+        assert_eq!(
+            vec![AccessRouteOwnerEntry::new(
+                "scope_2",
+                DEFAULT_ASSET_TYPE,
+                vec![AccessRoute::route_only("verifier-route")],
+            )],
+            query_page(&deps, DEFAULT_VERIFIER_ADDRESS, None, None),
+            "expected only the verifier's routes to be returned",
+        );
+    }
+
+    #[test]
+    fn test_query_returns_empty_for_owner_without_routes() {
+        let deps = setup_owned_routes();
+        assert!(
+            query_page(&deps, DEFAULT_ADMIN_ADDRESS, None, None).is_empty(),
+            "expected no entries to be returned for an address that owns no routes",
+        );
+    }
+
+    #[test]
+    fn test_query_pages_by_scope_address() {
+        let deps = setup_owned_routes();
+        let first_page = query_page(&deps, DEFAULT_SENDER_ADDRESS, None, 2.to_some());
+        assert_eq!(
+            vec!["scope_1", "scope_2", "scope_2"],
+            scope_addresses(&first_page),
+            "expected the first page to contain every entry for the first two scopes",
+        );
+        let second_page = query_page(
+            &deps,
+            DEFAULT_SENDER_ADDRESS,
+            first_page
+                .last()
+                .map(|entry| entry.scope_address.to_owned()),
+            2.to_some(),
+        );
+        assert_eq!(
+            vec!["scope_3"],
+            scope_addresses(&second_page),
+            "expected the second page to start after the final scope of the first page",
+        );
+        assert!(
+            query_page(
+                &deps,
+                DEFAULT_SENDER_ADDRESS,
+                "scope_3".to_string().to_some(),
+                2.to_some()
+            )
+            .is_empty(),
+            "expected no entries to be returned after the final scope",
+        );
+    }
+
+    #[test]
+    fn test_query_rejects_zero_limit() {
+        let deps = setup_owned_routes();
+        let err =
+            query_access_routes_by_owner(&deps.as_ref(), DEFAULT_SENDER_ADDRESS, None, 0.to_some())
+                .expect_err("expected a limit of zero to be rejected");
+        assert!(
+            matches!(err, ContractError::InvalidMessageFields { .. }),
+            "expected an invalid message fields error, but got: {:?}",
+            err,
+        );
+    }
+
+    fn setup_owned_routes() -> MockOwnedDeps {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(
+            deps.as_mut(),
+            &InstArgs::default_with_additional_asset_types(vec![DEFAULT_SECONDARY_ASSET_TYPE]),
+        );
+        mock_scope(
+            &mut deps,
+            "scope_1",
+            DEFAULT_SCOPE_SPEC_ADDRESS,
+            DEFAULT_SENDER_ADDRESS,
+        );
+        let mut verified_attribute = owned_attribute("scope_2", DEFAULT_ASSET_TYPE);
+        verified_attribute
+            .access_definitions
+            .push(AccessDefinition {
+                owner_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
+                access_routes: vec![AccessRoute::route_only("verifier-route")],
+                definition_type: AccessDefinitionType::Verifier,
+            });
+        let attributes = vec![
+            owned_attribute("scope_1", DEFAULT_ASSET_TYPE),
+            verified_attribute,
+            owned_attribute("scope_2", DEFAULT_SECONDARY_ASSET_TYPE),
+            owned_attribute("scope_3", DEFAULT_SECONDARY_ASSET_TYPE),
+        ];
+        for attribute in &attributes {
+            update_access_route_owner_index(deps.as_mut().storage, None, attribute)
+                .expect("indexing the attribute should succeed");
+        }
+        mock_named_attribute_responses_by_scope(
+            &mut deps,
+            attributes
+                .iter()
+                .map(|attribute| build_attribute(&attribute.scope_address, attribute))
+                .collect(),
+        );
+        deps
+    }
+
+    fn owned_attribute(scope_address: &str, asset_type: &str) -> AssetScopeAttribute {
+        AssetScopeAttribute {
+            scope_address: scope_address.to_string(),
+            asset_type: asset_type.to_string(),
+            access_definitions: vec![AccessDefinition {
+                owner_address: DEFAULT_SENDER_ADDRESS.to_string(),
+                access_routes: vec![AccessRoute::route_only(format!(
+                    "{}-{}",
+                    scope_address, asset_type
+                ))],
+                definition_type: AccessDefinitionType::Requestor,
+            }],
+            ..get_default_asset_scope_attribute()
+        }
+    }
+
+    fn scope_addresses(entries: &[AccessRouteOwnerEntry]) -> Vec<&str> {
+        entries
+            .iter()
+            .map(|entry| entry.scope_address.as_str())
+            .collect()
+    }
+
+    fn query_page(
+        deps: &MockOwnedDeps,
+        owner_address: &str,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> Vec<AccessRouteOwnerEntry> {
+        let binary =
+            query_access_routes_by_owner(&deps.as_ref(), owner_address, start_after, limit)
+                .expect("expected the query to succeed");
+        from_json::<Vec<AccessRouteOwnerEntry>>(&binary)
+            .expect("expected the response to deserialize properly")
+    }
+}
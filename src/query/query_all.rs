@@ -0,0 +1,107 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+use crate::core::state::{iter_asset_definitions_v3, load_asset_definition_count, STATE_V2};
+use crate::core::types::asset_definition::AssetDefinitionV3;
+use crate::core::types::query_all_response::QueryAllResponse;
+use crate::migrate::version_info::get_version_info;
+use crate::util::aliases::AssetResult;
+use crate::util::contract_helpers::check_debug_queries_enabled;
+
+/// A debugging query that assembles all of the contract's queryable configuration into a single
+/// [QueryAllResponse](crate::core::types::query_all_response::QueryAllResponse).  To prevent
+/// information leakage in production, this query is rejected with an [Unauthorized](crate::core::error::ContractError::Unauthorized)
+/// error unless [debug_query_enabled](crate::core::state::StateV2::debug_query_enabled) is set in
+/// the contract's state.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+pub fn query_all(deps: &Deps) -> AssetResult<Binary> {
+    let state = STATE_V2.load(deps.storage)?;
+    check_debug_queries_enabled(&state)?;
+    let asset_definitions =
+        iter_asset_definitions_v3(deps.storage).collect::<AssetResult<Vec<AssetDefinitionV3>>>()?;
+    to_json_binary(&QueryAllResponse::new(
+        state,
+        get_version_info(deps.storage)?,
+        asset_definitions,
+        load_asset_definition_count(deps.storage)?,
+    ))?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::from_json;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::state::STATE_V2;
+    use crate::core::types::query_all_response::QueryAllResponse;
+    use crate::migrate::version_info::{CONTRACT_NAME, CONTRACT_VERSION};
+    use crate::testutil::test_constants::{DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE};
+    use crate::testutil::test_utilities::{test_instantiate_success, InstArgs};
+
+    use super::query_all;
+
+    #[test]
+    fn test_query_all_in_test_mode() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(
+            deps.as_mut(),
+            &InstArgs {
+                is_test: true,
+                ..InstArgs::default()
+            },
+        );
+        let response = from_json::<QueryAllResponse>(
+            &query_all(&deps.as_ref()).expect("the query should succeed in test mode"),
+        )
+        .expect("the response should deserialize correctly");
+        assert_eq!(
+            STATE_V2.load(&deps.storage).unwrap(),
+            response.state,
+            "the stored state should be returned",
+        );
+        assert_eq!(
+            DEFAULT_ADMIN_ADDRESS,
+            response.state.admin.as_str(),
+            "the admin should be populated in the returned state",
+        );
+        assert_eq!(
+            CONTRACT_NAME, response.version.contract,
+            "the contract name should be returned in the version info",
+        );
+        assert_eq!(
+            CONTRACT_VERSION, response.version.version,
+            "the contract version should be returned in the version info",
+        );
+        assert_eq!(
+            1,
+            response.asset_definitions.len(),
+            "the default asset definition should be returned",
+        );
+        assert_eq!(
+            DEFAULT_ASSET_TYPE, response.asset_definitions[0].asset_type,
+            "the default asset definition should have the correct asset type",
+        );
+        assert_eq!(
+            1, response.asset_definition_count,
+            "the asset definition count should be returned",
+        );
+    }
+
+    #[test]
+    fn test_query_all_rejected_outside_of_test_mode() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = query_all(&deps.as_ref())
+            .expect_err("the query should be rejected when debug queries are disabled");
+        assert!(
+            error.is_unauthorized(),
+            "an unauthorized error should be returned, but got: {:?}",
+            error,
+        );
+    }
+}
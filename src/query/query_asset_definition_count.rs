@@ -0,0 +1,94 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+use crate::core::state::load_asset_definition_count;
+use crate::core::types::asset_definition_count_response::AssetDefinitionCountResponse;
+use crate::util::aliases::AssetResult;
+
+/// Fetches the cached total count of stored [AssetDefinitionV3s](crate::core::types::asset_definition::AssetDefinitionV3)
+/// and serializes it as an [AssetDefinitionCountResponse](crate::core::types::asset_definition_count_response::AssetDefinitionCountResponse).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+pub fn query_asset_definition_count(deps: &Deps) -> AssetResult<Binary> {
+    to_json_binary(&AssetDefinitionCountResponse::new(
+        load_asset_definition_count(deps.storage)?,
+    ))?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, Deps};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::state::{
+        delete_asset_definition_by_asset_type_v3, insert_asset_definition_v3,
+    };
+    use crate::core::types::asset_definition::AssetDefinitionV3;
+    use crate::core::types::asset_definition_count_response::AssetDefinitionCountResponse;
+    use crate::testutil::test_constants::DEFAULT_ASSET_TYPE;
+    use crate::testutil::test_utilities::{
+        get_default_verifier_detail, test_instantiate_success, InstArgs,
+    };
+    use crate::util::traits::OptionExtensions;
+
+    use super::query_asset_definition_count;
+
+    #[test]
+    fn test_count_reflects_instantiated_definitions() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(
+            deps.as_mut(),
+            &InstArgs::default_with_additional_asset_types(vec!["heloc", "mortgage"]),
+        );
+        assert_eq!(
+            3,
+            query_count(&deps.as_ref()),
+            "expected the count to include the default definition and all additional definitions",
+        );
+    }
+
+    #[test]
+    fn test_count_tracks_added_and_removed_definitions() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        assert_eq!(
+            1,
+            query_count(&deps.as_ref()),
+            "expected the default definition to be counted after instantiation",
+        );
+        insert_asset_definition_v3(
+            deps.as_mut().storage,
+            &AssetDefinitionV3::builder()
+                .asset_type("heloc")
+                .display_name("Home Equity Line of Credit".to_some())
+                .add_verifier(get_default_verifier_detail())
+                .build()
+                .expect("the heloc asset definition should be built"),
+        )
+        .expect("expected the new asset definition to be inserted");
+        assert_eq!(
+            2,
+            query_count(&deps.as_ref()),
+            "expected the count to increase after a definition is added",
+        );
+        delete_asset_definition_by_asset_type_v3(deps.as_mut().storage, DEFAULT_ASSET_TYPE)
+            .expect("expected the default asset definition to be deleted");
+        assert_eq!(
+            1,
+            query_count(&deps.as_ref()),
+            "expected the count to decrease after a definition is removed",
+        );
+    }
+
+    fn query_count(deps: &Deps) -> u64 {
+        let binary =
+            query_asset_definition_count(deps).expect("expected the query to execute successfully");
+        from_json::<AssetDefinitionCountResponse>(&binary)
+            .expect("expected the result to deserialize correctly")
+            .count
+    }
+}
@@ -1,7 +1,8 @@
 use cosmwasm_std::{to_json_binary, Binary, Deps};
 use result_extensions::ResultExtensions;
 
-use crate::core::state::list_asset_definitions_v3;
+use crate::core::state::iter_asset_definitions_v3;
+use crate::core::types::asset_definition::AssetDefinitionV3;
 use crate::util::aliases::AssetResult;
 
 /// A query that fetches all [AssetDefinitionV3s](crate::core::types::asset_definition::AssetDefinitionV3)
@@ -12,7 +13,8 @@ use crate::util::aliases::AssetResult;
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
 /// resources like contract internal storage and a querier to retrieve blockchain objects.
 pub fn query_asset_definitions(deps: &Deps) -> AssetResult<Binary> {
-    let asset_definitions = list_asset_definitions_v3(deps.storage);
+    let asset_definitions =
+        iter_asset_definitions_v3(deps.storage).collect::<AssetResult<Vec<AssetDefinitionV3>>>()?;
     to_json_binary(&asset_definitions)?.to_ok()
 }
 
@@ -93,10 +95,7 @@ mod tests {
             .collect::<Vec<AssetDefinitionInputV3>>();
         test_instantiate_success(
             deps.as_mut(),
-            &InstArgs {
-                asset_definitions: asset_definition_inputs.clone(),
-                ..Default::default()
-            },
+            &InstArgs::with_asset_definitions(asset_definition_inputs.clone()),
         );
         let response_bin = query_asset_definitions(&deps.as_ref())
             .expect("expected the query to execute appropriately");
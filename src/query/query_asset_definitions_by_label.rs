@@ -0,0 +1,283 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+use crate::core::error::ContractError;
+use crate::core::state::list_asset_definitions_by_label_page;
+use crate::util::aliases::AssetResult;
+use crate::util::constants::MAX_DEFINITIONS_PER_PAGE;
+
+/// A query that fetches a single page of the [AssetDefinitionV3s](crate::core::types::asset_definition::AssetDefinitionV3)
+/// that carry a specific [label](crate::core::types::asset_definition::AssetDefinitionV3::labels),
+/// sorted in ascending order by asset type, and serializes them as a vector.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `label` The label that every returned asset definition must carry.
+/// * `start_after` The asset type of the final definition in a previous page.  When omitted, the
+/// first page is returned.
+/// * `limit` The maximum number of asset definitions to include in the page.  Must be greater than
+/// zero.  Values greater than [MAX_DEFINITIONS_PER_PAGE](crate::util::constants::MAX_DEFINITIONS_PER_PAGE)
+/// are reduced to that limit, which is also used when no value is provided.
+pub fn query_asset_definitions_by_label(
+    deps: &Deps,
+    label: &str,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> AssetResult<Binary> {
+    let limit = limit.unwrap_or(MAX_DEFINITIONS_PER_PAGE);
+    if limit == 0 {
+        return ContractError::InvalidMessageFields {
+            message_type: "QueryMsg::QueryAssetDefinitionsByLabel".to_string(),
+            invalid_fields: vec!["limit: must be greater than zero".to_string()],
+        }
+        .to_err();
+    }
+    to_json_binary(&list_asset_definitions_by_label_page(
+        deps.storage,
+        label,
+        start_after,
+        limit.min(MAX_DEFINITIONS_PER_PAGE),
+    )?)?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_json, Deps};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::types::asset_definition::{AssetDefinitionInputV3, AssetDefinitionV3};
+    use crate::testutil::execute_utilities::{
+        execute_add_asset_definition, execute_delete_asset_definition,
+        execute_update_asset_definition,
+    };
+    use crate::testutil::test_constants::{DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE};
+    use crate::testutil::test_utilities::{
+        empty_mock_info, get_default_asset_definition_input, test_instantiate_success, InstArgs,
+        MockOwnedDeps,
+    };
+    use crate::util::traits::OptionExtensions;
+
+    use super::query_asset_definitions_by_label;
+
+    #[test]
+    fn test_only_definitions_with_matching_label_are_returned() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        add_labeled_definition(&mut deps, "heloc", &["mortgage", "residential"]);
+        add_labeled_definition(&mut deps, "pl", &["unsecured"]);
+        add_labeled_definition(&mut deps, "commercial", &["mortgage"]);
+        assert_eq!(
+            vec!["commercial".to_string(), "heloc".to_string()],
+            query_asset_types(&deps.as_ref(), "mortgage", None, None),
+            "only the definitions labeled as mortgages should be returned, sorted by asset type",
+        );
+        assert_eq!(
+            vec!["pl".to_string()],
+            query_asset_types(&deps.as_ref(), "unsecured", None, None),
+            "only the definition labeled as unsecured should be returned",
+        );
+        assert!(
+            query_asset_types(&deps.as_ref(), "unknown", None, None).is_empty(),
+            "no definitions should be returned for a label that has never been assigned",
+        );
+    }
+
+    #[test]
+    fn test_returned_definitions_include_assigned_labels() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        add_labeled_definition(&mut deps, "heloc", &["mortgage", "residential"]);
+        let definitions = query_definitions(&deps.as_ref(), "residential", None, None);
+        assert_eq!(
+            1,
+            definitions.len(),
+            "the single labeled definition should be returned",
+        );
+        assert_eq!(
+            vec!["mortgage".to_string(), "residential".to_string()],
+            definitions.first().unwrap().labels,
+            "every label assigned to the definition should be stored",
+        );
+    }
+
+    #[test]
+    fn test_index_reflects_definition_updates() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        execute_update_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            AssetDefinitionInputV3 {
+                labels: vec!["mortgage".to_string()],
+                ..get_default_asset_definition_input()
+            },
+            None,
+        )
+        .expect("adding a label to the default definition should succeed");
+        assert_eq!(
+            vec![DEFAULT_ASSET_TYPE.to_string()],
+            query_asset_types(&deps.as_ref(), "mortgage", None, None),
+            "the updated definition should be found by its new label",
+        );
+        execute_update_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            AssetDefinitionInputV3 {
+                labels: vec!["residential".to_string()],
+                ..get_default_asset_definition_input()
+            },
+            None,
+        )
+        .expect("replacing the labels of the default definition should succeed");
+        assert!(
+            query_asset_types(&deps.as_ref(), "mortgage", None, None).is_empty(),
+            "the definition should no longer be found by a label that was removed",
+        );
+        assert_eq!(
+            vec![DEFAULT_ASSET_TYPE.to_string()],
+            query_asset_types(&deps.as_ref(), "residential", None, None),
+            "the definition should be found by its replacement label",
+        );
+        execute_update_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            AssetDefinitionInputV3 {
+                display_name: "New Name".to_string().to_some(),
+                ..get_default_asset_definition_input()
+            },
+            true.to_some(),
+        )
+        .expect("a partial update without labels should succeed");
+        assert_eq!(
+            "New Name",
+            query_definitions(&deps.as_ref(), "residential", None, None)
+                .first()
+                .expect("the definition should still be found after a partial update")
+                .display_name_or_type(),
+            "the index should return the latest version of the definition",
+        );
+        execute_delete_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+        )
+        .expect("deleting the default definition should succeed");
+        assert!(
+            query_asset_types(&deps.as_ref(), "residential", None, None).is_empty(),
+            "a deleted definition should no longer be found by its labels",
+        );
+    }
+
+    #[test]
+    fn test_pagination_with_start_after_and_limit() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        for asset_type in ["type_a", "type_b", "type_c"] {
+            add_labeled_definition(&mut deps, asset_type, &["paged"]);
+        }
+        assert_eq!(
+            vec!["type_a".to_string(), "type_b".to_string()],
+            query_asset_types(&deps.as_ref(), "paged", None, 2.to_some()),
+            "the first page should be limited to the requested size",
+        );
+        assert_eq!(
+            vec!["type_c".to_string()],
+            query_asset_types(
+                &deps.as_ref(),
+                "paged",
+                "TYPE_B".to_string().to_some(),
+                2.to_some(),
+            ),
+            "the second page should begin after the provided asset type, regardless of casing",
+        );
+    }
+
+    #[test]
+    fn test_zero_limit_is_rejected() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = query_asset_definitions_by_label(&deps.as_ref(), "mortgage", None, 0.to_some())
+            .expect_err("expected a zero limit to be rejected");
+        assert!(
+            error.is_invalid_message_fields(),
+            "expected an invalid message fields error to be returned, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_duplicate_labels_are_rejected() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = execute_add_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            AssetDefinitionInputV3 {
+                asset_type: "heloc".to_string(),
+                labels: vec!["mortgage".to_string(), "mortgage".to_string()],
+                ..get_default_asset_definition_input()
+            },
+        )
+        .expect_err("a definition with duplicate labels should be rejected");
+        assert!(
+            matches!(error, ContractError::InvalidMessageFields { .. }),
+            "expected an invalid message fields error to be returned, but got: {:?}",
+            error,
+        );
+        assert!(
+            query_asset_types(&deps.as_ref(), "mortgage", None, None).is_empty(),
+            "the rejected definition should not be indexed",
+        );
+    }
+
+    fn add_labeled_definition(deps: &mut MockOwnedDeps, asset_type: &str, labels: &[&str]) {
+        execute_add_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            AssetDefinitionInputV3 {
+                asset_type: asset_type.to_string(),
+                bind_name: false.to_some(),
+                labels: labels.iter().map(|label| label.to_string()).collect(),
+                ..get_default_asset_definition_input()
+            },
+        )
+        .expect("adding a labeled asset definition should succeed");
+    }
+
+    fn query_definitions(
+        deps: &Deps,
+        label: &str,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> Vec<AssetDefinitionV3> {
+        query_asset_definitions_by_label(deps, label, start_after, limit)
+            .map(|binary| {
+                from_json::<Vec<AssetDefinitionV3>>(&binary)
+                    .expect("expected the result to deserialize correctly")
+            })
+            .expect("expected the query to succeed")
+    }
+
+    fn query_asset_types(
+        deps: &Deps,
+        label: &str,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> Vec<String> {
+        query_definitions(deps, label, start_after, limit)
+            .into_iter()
+            .map(|definition| definition.asset_type)
+            .collect()
+    }
+}
@@ -0,0 +1,278 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+use crate::core::error::ContractError;
+use crate::core::state::{list_asset_definitions_page_v3, load_asset_definition_count};
+use crate::core::types::asset_definitions_page_response::AssetDefinitionsPageResponse;
+use crate::util::aliases::AssetResult;
+use crate::util::constants::MAX_DEFINITIONS_PER_PAGE;
+
+/// A query that fetches a single page of [AssetDefinitionV3s](crate::core::types::asset_definition::AssetDefinitionV3)
+/// from the contract's internal storage, sorted in ascending order by asset type, and serializes
+/// it as an [AssetDefinitionsPageResponse](crate::core::types::asset_definitions_page_response::AssetDefinitionsPageResponse).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `page_size` The maximum number of asset definitions to include in the page.  Must be greater
+/// than zero.  Values greater than [MAX_DEFINITIONS_PER_PAGE](crate::util::constants::MAX_DEFINITIONS_PER_PAGE)
+/// are reduced to that limit, which is also used when no value is provided.
+/// * `page_token` The [next_page_token](crate::core::types::asset_definitions_page_response::AssetDefinitionsPageResponse::next_page_token)
+/// from a previous page's response.  When omitted, the first page is returned.
+pub fn query_asset_definitions_page(
+    deps: &Deps,
+    page_size: Option<u32>,
+    page_token: Option<String>,
+) -> AssetResult<Binary> {
+    let page_size = page_size.unwrap_or(MAX_DEFINITIONS_PER_PAGE);
+    if page_size == 0 {
+        return ContractError::InvalidMessageFields {
+            message_type: "QueryMsg::QueryAssetDefinitionsPage".to_string(),
+            invalid_fields: vec!["page_size: must be greater than zero".to_string()],
+        }
+        .to_err();
+    }
+    let (asset_definitions, has_next_page) = list_asset_definitions_page_v3(
+        deps.storage,
+        page_size.min(MAX_DEFINITIONS_PER_PAGE),
+        page_token,
+    )?;
+    to_json_binary(&AssetDefinitionsPageResponse::new(
+        asset_definitions,
+        load_asset_definition_count(deps.storage)?,
+        has_next_page,
+    ))?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::from_json;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::state::{
+        delete_asset_definition_by_asset_type_v3, insert_asset_definition_v3,
+    };
+    use crate::core::types::asset_definition::AssetDefinitionV3;
+    use crate::core::types::asset_definitions_page_response::AssetDefinitionsPageResponse;
+    use crate::testutil::test_constants::DEFAULT_ASSET_TYPE;
+    use crate::testutil::test_utilities::{test_instantiate_success, InstArgs, MockOwnedDeps};
+    use crate::util::constants::MAX_DEFINITIONS_PER_PAGE;
+    use crate::util::traits::OptionExtensions;
+
+    use super::query_asset_definitions_page;
+
+    #[test]
+    fn test_empty_registry_returns_empty_first_page() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::with_no_definitions());
+        let response = query_page(&deps, 10.to_some(), None);
+        assert!(
+            response.asset_definitions.is_empty(),
+            "expected no asset definitions to be returned when none are stored",
+        );
+        assert_eq!(
+            0, response.total_definitions,
+            "expected the total definitions to be zero when none are stored",
+        );
+        assert!(
+            !response.has_next_page,
+            "expected no next page to exist when no definitions are stored",
+        );
+        assert!(
+            response.next_page_token.is_none(),
+            "expected no next page token to be returned when no definitions are stored",
+        );
+    }
+
+    #[test]
+    fn test_iterating_all_pages_returns_every_definition_once() {
+        let mut deps = mock_provenance_dependencies();
+        let additional_types = (0..6)
+            .map(|id| format!("asset_type_{}", id))
+            .collect::<Vec<String>>();
+        test_instantiate_success(
+            deps.as_mut(),
+            &InstArgs::default_with_additional_asset_types(
+                additional_types.iter().map(|s| s.as_str()).collect(),
+            ),
+        );
+        let mut page_token = None;
+        let mut page_count = 0;
+        let mut found_types = vec![];
+        loop {
+            let response = query_page(&deps, 3.to_some(), page_token);
+            page_count += 1;
+            assert_eq!(
+                7, response.total_definitions,
+                "expected the total definitions to reflect every stored definition on each page",
+            );
+            assert!(
+                response.asset_definitions.len() <= 3,
+                "expected no page to exceed the requested page size",
+            );
+            found_types.extend(
+                response
+                    .asset_definitions
+                    .into_iter()
+                    .map(|definition| definition.asset_type),
+            );
+            assert_eq!(
+                response.has_next_page,
+                response.next_page_token.is_some(),
+                "expected a next page token to be present only when a next page exists",
+            );
+            if !response.has_next_page {
+                break;
+            }
+            page_token = response.next_page_token;
+        }
+        assert_eq!(
+            3, page_count,
+            "expected seven definitions to be split across three pages of size three",
+        );
+        let mut expected_types = additional_types;
+        expected_types.push(DEFAULT_ASSET_TYPE.to_string());
+        expected_types.sort();
+        assert_eq!(
+            expected_types, found_types,
+            "expected every definition to be returned exactly once, sorted by asset type",
+        );
+    }
+
+    #[test]
+    fn test_exact_page_size_has_no_next_page() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let response = query_page(&deps, 1.to_some(), None);
+        assert_eq!(
+            1,
+            response.asset_definitions.len(),
+            "expected the only stored definition to be returned",
+        );
+        assert!(
+            !response.has_next_page,
+            "expected no next page when the page size exactly matches the stored definitions",
+        );
+    }
+
+    #[test]
+    fn test_total_definitions_reflects_deletion() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(
+            deps.as_mut(),
+            &InstArgs::default_with_additional_asset_types(vec!["other_type"]),
+        );
+        assert_eq!(
+            2,
+            query_page(&deps, 10.to_some(), None).total_definitions,
+            "expected both instantiated definitions to be counted",
+        );
+        delete_asset_definition_by_asset_type_v3(deps.as_mut().storage, DEFAULT_ASSET_TYPE)
+            .expect("expected the default definition to be deleted");
+        let response = query_page(&deps, 10.to_some(), None);
+        assert_eq!(
+            1, response.total_definitions,
+            "expected the total definitions to be reduced after a deletion",
+        );
+        assert_eq!(
+            1,
+            response.asset_definitions.len(),
+            "expected only the remaining definition to be returned",
+        );
+    }
+
+    #[test]
+    fn test_zero_page_size_is_rejected() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = query_asset_definitions_page(&deps.as_ref(), 0.to_some(), None)
+            .expect_err("expected a zero page size to be rejected");
+        assert!(
+            error.is_invalid_message_fields(),
+            "expected an invalid message fields error to be returned, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_page_token_of_final_definition_returns_empty_page() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(
+            deps.as_mut(),
+            &InstArgs::default_with_additional_asset_types(vec!["other_type"]),
+        );
+        let final_token = query_page(&deps, 10.to_some(), None)
+            .asset_definitions
+            .last()
+            .expect("expected a definition to be returned")
+            .storage_key();
+        let response = query_page(&deps, 10.to_some(), final_token.to_some());
+        assert!(
+            response.asset_definitions.is_empty(),
+            "expected no definitions to be returned after the final definition",
+        );
+        assert!(
+            !response.has_next_page,
+            "expected no next page to exist after the final definition",
+        );
+    }
+
+    #[test]
+    fn test_pages_are_stable_across_repeated_queries() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(
+            deps.as_mut(),
+            &InstArgs::default_with_additional_asset_types(vec!["a_type", "b_type", "c_type"]),
+        );
+        let first_page = query_page(&deps, 2.to_some(), None);
+        assert_eq!(
+            first_page,
+            query_page(&deps, 2.to_some(), None),
+            "expected the first page to be identical across queries",
+        );
+        assert_eq!(
+            query_page(&deps, 2.to_some(), first_page.next_page_token.clone()),
+            query_page(&deps, 2.to_some(), first_page.next_page_token),
+            "expected the second page to be identical across queries",
+        );
+    }
+
+    #[test]
+    fn test_page_size_is_capped_and_defaulted() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::with_no_definitions());
+        for id in 0..=MAX_DEFINITIONS_PER_PAGE {
+            insert_asset_definition_v3(
+                deps.as_mut().storage,
+                &AssetDefinitionV3::new(format!("asset_type_{:03}", id), None::<String>, vec![]),
+            )
+            .expect("expected the asset definition to be inserted");
+        }
+        for page_size in [None, (MAX_DEFINITIONS_PER_PAGE + 1).to_some()] {
+            let response = query_page(&deps, page_size, None);
+            assert_eq!(
+                MAX_DEFINITIONS_PER_PAGE as usize,
+                response.asset_definitions.len(),
+                "expected a page size of {:?} to return the maximum number of definitions",
+                page_size,
+            );
+            assert!(
+                response.has_next_page,
+                "expected the definition beyond the maximum page size to be on a next page",
+            );
+        }
+    }
+
+    fn query_page(
+        deps: &MockOwnedDeps,
+        page_size: Option<u32>,
+        page_token: Option<String>,
+    ) -> AssetDefinitionsPageResponse {
+        let binary = query_asset_definitions_page(&deps.as_ref(), page_size, page_token)
+            .expect("expected the query to execute successfully");
+        from_json::<AssetDefinitionsPageResponse>(&binary)
+            .expect("expected the response to deserialize correctly")
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
-use cosmwasm_std::{from_json, to_json_binary, Binary, Deps};
+use cosmwasm_std::{to_json_binary, Binary, Deps};
 use provwasm_std::types::provenance::{
     attribute::v1::{AttributeQuerier, AttributeType},
     metadata::v1::MetadataQuerier,
@@ -10,7 +10,7 @@ use result_extensions::ResultExtensions;
 use crate::{
     core::{
         error::ContractError,
-        state::{list_asset_definitions_v3, STATE_V2},
+        state::{iter_asset_definitions_v3, STATE_V2},
         types::{asset_identifier::AssetIdentifier, asset_scope_attribute::AssetScopeAttribute},
     },
     util::{aliases::AssetResult, scope_address_utils::asset_uuid_to_scope_address},
@@ -28,7 +28,7 @@ pub fn query_asset_scope_attribute(
     identifier: AssetIdentifier,
 ) -> AssetResult<Binary> {
     let scope_attributes = match identifier {
-        AssetIdentifier::AssetUuid(asset_uuid) => {
+        AssetIdentifier::AssetUuid(asset_uuid) | AssetIdentifier::ScopeUuid(asset_uuid) => {
             may_query_scope_attribute_by_asset_uuid(deps, asset_uuid)
         }
         AssetIdentifier::ScopeAddress(scope_address) => {
@@ -132,10 +132,9 @@ pub fn may_query_scope_attribute_by_scope_address<S: Into<String>>(
 
     // Second, query up all possible asset definition names
     let state = STATE_V2.load(deps.storage)?;
-    let asset_definitions: HashSet<String> = list_asset_definitions_v3(deps.storage)
-        .iter()
-        .map(|def| def.attribute_name_state(&state))
-        .collect();
+    let asset_definitions = iter_asset_definitions_v3(deps.storage)
+        .map(|def| def.map(|def| def.attribute_name_state(&state)))
+        .collect::<AssetResult<HashSet<String>>>()?;
 
     // Third, query up asset scope attributes attached to the scope address under the name attribute.
     // In a proper scenario, there should only ever be one of each type of these
@@ -148,9 +147,8 @@ pub fn may_query_scope_attribute_by_scope_address<S: Into<String>>(
             asset_definitions.contains(&attr.name) && attr.attribute_type() == AttributeType::Json
         })
         .map(|attr| {
-            from_json::<AssetScopeAttribute>(&attr.value)
+            AssetScopeAttribute::from_binary(&attr.name, &attr.value)
                 .map(|v| (attr.name.clone(), v))
-                .map_err(ContractError::Std)
         })
         .collect::<AssetResult<_>>()?;
     let scope_attributes = &mut HashMap::new();
@@ -243,6 +241,15 @@ mod tests {
                     AccessDefinitionType::Requestor,
                 )
                 .unwrap()],
+                scope_spec_address: None,
+                retry_count: 0,
+                pending_expiry_block: None,
+                appeal_deadline_block: None,
+                os_gateway_permission_granted: true,
+                os_gateway_grant_id: None,
+                classification_initiated_at_block: 0,
+                last_modified_block: 0,
+                requestor_metadata: None,
             },
             DEFAULT_SCOPE_ADDRESS,
         );
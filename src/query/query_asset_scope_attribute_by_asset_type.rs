@@ -1,4 +1,4 @@
-use cosmwasm_std::{from_json, to_json_binary, Binary, Deps};
+use cosmwasm_std::{to_json_binary, Binary, Deps};
 use provwasm_std::types::provenance::{
     attribute::v1::AttributeQuerier, metadata::v1::MetadataQuerier,
 };
@@ -27,7 +27,7 @@ pub fn query_asset_scope_attribute_by_asset_type<S: Into<String>>(
     asset_type: S,
 ) -> AssetResult<Binary> {
     let scope_attribute = match identifier {
-        AssetIdentifier::AssetUuid(asset_uuid) => {
+        AssetIdentifier::AssetUuid(asset_uuid) | AssetIdentifier::ScopeUuid(asset_uuid) => {
             may_query_scope_attribute_by_asset_uuid_and_asset_type(deps, asset_uuid, asset_type)
         }
         AssetIdentifier::ScopeAddress(scope_address) => {
@@ -65,7 +65,9 @@ pub fn query_scope_attribute_by_asset_uuid_and_asset_type<S1: Into<String>, S2:
 
 /// Fetches an AssetScopeAttribute by the scope address value directly for a particular asset type.  The most efficient version
 /// of these functions, but still has to do quite a few lookups.  This functionality should only be used
-/// on a once-per-transaction basis, if possible.
+/// on a once-per-transaction basis, if possible.  Returns a [ScopeNotFound](crate::core::error::ContractError::ScopeNotFound)
+/// error if the scope does not exist, or a [NotFound](crate::core::error::ContractError::NotFound)
+/// error if the scope exists but has no attribute for the specified asset type.
 ///
 /// # Parameters
 ///
@@ -129,7 +131,8 @@ pub fn may_query_scope_attribute_by_asset_uuid_and_asset_type<
 /// Fetches an AssetScopeAttribute by the scope address value directly for a particular asset type.  The most efficient version
 /// of these functions, but still has to do a couple of lookups.  This functionality should only be used
 /// on a once-per-transaction basis, if possible. Returns a ContractResult<None> in the case of no attribute
-/// of the specified asset type being associated with the scope.
+/// of the specified asset type being associated with the scope, and a [ScopeNotFound](crate::core::error::ContractError::ScopeNotFound)
+/// error if the scope itself does not exist.
 ///
 /// # Parameters
 ///
@@ -150,15 +153,24 @@ pub fn may_query_scope_attribute_by_scope_address_and_asset_type<
     let scope_address_str: String = scope_address.into();
 
     // First, query up the scope to verify it exists
-    let _scope = metadata_querier.scope(
-        scope_address_str.to_owned(),
-        String::from(""),
-        String::from(""),
-        false,
-        false,
-        false,
-        false,
-    )?;
+    let scope = metadata_querier
+        .scope(
+            scope_address_str.to_owned(),
+            String::from(""),
+            String::from(""),
+            false,
+            false,
+            false,
+            false,
+        )?
+        .scope
+        .and_then(|scope_wrapper| scope_wrapper.scope);
+    if scope.is_none() {
+        return ContractError::ScopeNotFound {
+            scope_address: scope_address_str,
+        }
+        .to_err();
+    }
 
     // Second, query up the asset definition by the asset type
     let asset_definition = load_asset_definition_by_type_v3(deps.storage, &asset_type.into())?;
@@ -170,7 +182,11 @@ pub fn may_query_scope_attribute_by_scope_address_and_asset_type<
     // In a proper scenario, there should only ever be one of these
     let attribute_querier = AttributeQuerier::new(&deps.querier);
     let scope_attributes = attribute_querier
-        .attribute(scope_address_str.to_owned(), attribute_name, None)?
+        .attribute(
+            scope_address_str.to_owned(),
+            attribute_name.to_owned(),
+            None,
+        )?
         .attributes;
     // This is a very bad scenario - this means that the contract messed up and created multiple attributes under
     // the attribute name.  This should only ever happen in error, and would require a horrible cleanup process
@@ -185,28 +201,25 @@ pub fn may_query_scope_attribute_by_scope_address_and_asset_type<
     // Retain ownership of the first and verified only scope attribute
     scope_attributes
         .first()
-        .map(|a| {
-            from_json(&a.value)
-                .map_err(|_err| {
-                    ContractError::generic(format!(
-                        "Could not deserialize the attribute for address [{}]",
-                        scope_address_str
-                    ))
-                })
-                .unwrap()
-        })
-        .to_ok()
+        .map(|a| AssetScopeAttribute::from_binary(&attribute_name, &a.value))
+        .transpose()
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::testutil::attribute_utilities::mock_named_attribute_response_for_scope;
     use cosmwasm_std::{from_json, StdError};
     use provwasm_mocks::mock_provenance_dependencies;
+    use provwasm_std::types::provenance::attribute::v1::{Attribute, AttributeType};
     use provwasm_std::types::provenance::metadata::v1::ScopeRequest;
 
     use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
-    use crate::testutil::test_constants::{DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS};
+    use crate::testutil::scope_utilities::mock_missing_scope_response;
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_CONTRACT_BASE_NAME, DEFAULT_SCOPE_ADDRESS,
+    };
     use crate::testutil::test_utilities::{setup_no_attribute_response, setup_test_suite};
+    use crate::util::functions::generate_asset_attribute_name;
     use crate::{
         core::{
             error::ContractError,
@@ -220,7 +233,10 @@ mod tests {
         },
     };
 
-    use super::query_asset_scope_attribute_by_asset_type;
+    use super::{
+        query_asset_scope_attribute_by_asset_type,
+        query_scope_attribute_by_scope_address_and_asset_type,
+    };
 
     #[test]
     fn test_successful_query_result() {
@@ -343,4 +359,102 @@ mod tests {
             "expected the result from the query to be missing because no scope attribute existed at the scope address",
         );
     }
+
+    #[test]
+    fn test_query_failure_for_malformed_scope_attribute() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        mock_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            DEFAULT_SCOPE_SPEC_ADDRESS,
+            "test-owner",
+        );
+        let attribute_name =
+            generate_asset_attribute_name(DEFAULT_ASSET_TYPE, DEFAULT_CONTRACT_BASE_NAME);
+        mock_named_attribute_response_for_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![Attribute {
+                name: attribute_name.to_owned(),
+                value: b"not a scope attribute".to_vec(),
+                attribute_type: AttributeType::Json.into(),
+                address: DEFAULT_SCOPE_ADDRESS.to_string(),
+                expiration_date: None,
+            }],
+        );
+        let error = query_asset_scope_attribute_by_asset_type(
+            &deps.as_ref(),
+            AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+        )
+        .expect_err("expected the query to fail when the attribute value is malformed");
+        match error {
+            ContractError::AttributeSerializationError {
+                attribute_name: error_attribute_name,
+                ..
+            } => {
+                assert_eq!(
+                    attribute_name, error_attribute_name,
+                    "the error should reference the attribute that could not be deserialized",
+                );
+            }
+            _ => panic!("unexpected error encountered: {:?}", error),
+        };
+    }
+
+    #[test]
+    fn test_query_failure_for_nonexistent_scope() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        ScopeRequest::mock_response(&mut deps.querier, mock_missing_scope_response());
+        let error = query_scope_attribute_by_scope_address_and_asset_type(
+            &deps.as_ref(),
+            DEFAULT_SCOPE_ADDRESS,
+            DEFAULT_ASSET_TYPE,
+        )
+        .unwrap_err();
+        match error {
+            ContractError::ScopeNotFound { scope_address } => {
+                assert_eq!(
+                    DEFAULT_SCOPE_ADDRESS, scope_address,
+                    "the error should reference the scope address that could not be found",
+                );
+            }
+            _ => panic!("unexpected error encountered: {:?}", error),
+        };
+    }
+
+    #[test]
+    fn test_query_failure_for_existing_scope_without_attribute() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let scope_address = "scope-address".to_string();
+        mock_scope(
+            &mut deps,
+            &scope_address,
+            DEFAULT_SCOPE_SPEC_ADDRESS,
+            "test-owner",
+        );
+        setup_no_attribute_response(&mut deps, Some(scope_address.to_owned()));
+        let error = query_scope_attribute_by_scope_address_and_asset_type(
+            &deps.as_ref(),
+            &scope_address,
+            DEFAULT_ASSET_TYPE,
+        )
+        .unwrap_err();
+        match error {
+            ContractError::NotFound { explanation } => {
+                assert_eq!(
+                    format!(
+                        "scope at address [{}] did not include an asset scope attribute for asset type [{}]",
+                        scope_address, DEFAULT_ASSET_TYPE,
+                    ),
+                    explanation,
+                    "incorrect not found message encountered",
+                );
+            }
+            _ => panic!("unexpected error encountered: {:?}", error),
+        };
+    }
 }
@@ -0,0 +1,263 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+use crate::{
+    core::{
+        error::ContractError,
+        types::{
+            asset_identifier::AssetIdentifier,
+            asset_scope_attribute::AssetScopeAttribute,
+            batch_scope_attribute::{
+                BatchScopeAttributeRequest, BatchScopeAttributeResponse, BatchScopeAttributeResult,
+            },
+        },
+    },
+    query::query_asset_scope_attribute_by_asset_type::{
+        may_query_scope_attribute_by_asset_uuid_and_asset_type,
+        may_query_scope_attribute_by_scope_address_and_asset_type,
+    },
+    util::{aliases::AssetResult, constants::MAX_SCOPE_ATTRIBUTE_BATCH_SIZE},
+};
+
+/// Fetches the [AssetScopeAttribute](crate::core::types::asset_scope_attribute::AssetScopeAttribute)
+/// for each provided scope and asset type pair and serializes the results as a
+/// [BatchScopeAttributeResponse](crate::core::types::batch_scope_attribute::BatchScopeAttributeResponse).
+/// Errors encountered while resolving an individual pair are captured in that pair's result
+/// rather than failing the query.  The query itself is rejected if more than
+/// [MAX_SCOPE_ATTRIBUTE_BATCH_SIZE](crate::util::constants::MAX_SCOPE_ATTRIBUTE_BATCH_SIZE)
+/// requests are provided.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `requests` The scope and asset type pairs to resolve.
+pub fn query_asset_scope_attributes_batch(
+    deps: &Deps,
+    requests: Vec<BatchScopeAttributeRequest>,
+) -> AssetResult<Binary> {
+    if requests.len() > MAX_SCOPE_ATTRIBUTE_BATCH_SIZE {
+        return ContractError::InvalidMessageFields {
+            message_type: "QueryMsg::QueryAssetScopeAttributesBatch".to_string(),
+            invalid_fields: vec![format!(
+                "requests: must include no more than {} entries, but {} were provided",
+                MAX_SCOPE_ATTRIBUTE_BATCH_SIZE,
+                requests.len(),
+            )],
+        }
+        .to_err();
+    }
+    let results = requests
+        .into_iter()
+        .map(|request| {
+            let (attribute, error) = match may_query_scope_attribute(deps, &request) {
+                Ok(attribute) => (attribute, None),
+                Err(e) => (None, Some(e.to_string())),
+            };
+            BatchScopeAttributeResult {
+                identifier: request.identifier,
+                asset_type: request.asset_type,
+                attribute,
+                error,
+            }
+        })
+        .collect::<Vec<BatchScopeAttributeResult>>();
+    to_json_binary(&BatchScopeAttributeResponse { results })?.to_ok()
+}
+
+/// Resolves a single batch request, converting its identifier before looking up the attribute.
+fn may_query_scope_attribute(
+    deps: &Deps,
+    request: &BatchScopeAttributeRequest,
+) -> AssetResult<Option<AssetScopeAttribute>> {
+    match request.identifier.to_asset_identifier()? {
+        AssetIdentifier::AssetUuid(asset_uuid) | AssetIdentifier::ScopeUuid(asset_uuid) => {
+            may_query_scope_attribute_by_asset_uuid_and_asset_type(
+                deps,
+                asset_uuid,
+                &request.asset_type,
+            )
+        }
+        AssetIdentifier::ScopeAddress(scope_address) => {
+            may_query_scope_attribute_by_scope_address_and_asset_type(
+                deps,
+                scope_address,
+                &request.asset_type,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::from_json;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::types::asset_identifier::AssetIdentifier;
+    use crate::core::types::batch_scope_attribute::{
+        BatchScopeAttributeRequest, BatchScopeAttributeResponse,
+    };
+    use crate::core::types::serialized_enum::SerializedEnum;
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_ASSET_UUID, DEFAULT_SCOPE_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        setup_no_attribute_response, setup_test_suite, InstArgs, MockOwnedDeps,
+    };
+    use crate::util::constants::MAX_SCOPE_ATTRIBUTE_BATCH_SIZE;
+
+    use super::query_asset_scope_attributes_batch;
+
+    #[test]
+    fn test_batch_with_hits_and_errors() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the asset onboard to succeed");
+        let response = query_batch(
+            &deps,
+            vec![
+                BatchScopeAttributeRequest::new(
+                    AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS).to_serialized_enum(),
+                    DEFAULT_ASSET_TYPE,
+                ),
+                BatchScopeAttributeRequest::new(
+                    AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS).to_serialized_enum(),
+                    "not-a-real-type",
+                ),
+                BatchScopeAttributeRequest::new(
+                    AssetIdentifier::asset_uuid(DEFAULT_ASSET_UUID).to_serialized_enum(),
+                    DEFAULT_ASSET_TYPE,
+                ),
+                BatchScopeAttributeRequest::new(
+                    SerializedEnum::new("bad_type", "value"),
+                    DEFAULT_ASSET_TYPE,
+                ),
+            ],
+        )
+        .expect("expected the batch query to succeed despite individual failures");
+        assert_eq!(
+            4,
+            response.results.len(),
+            "each request should produce a result",
+        );
+        let hit = &response.results[0];
+        assert_eq!(
+            DEFAULT_ASSET_TYPE, hit.asset_type,
+            "the result should echo the requested asset type",
+        );
+        assert!(
+            hit.error.is_none(),
+            "no error should be produced for an onboarded asset, but got: {:?}",
+            hit.error,
+        );
+        assert_eq!(
+            DEFAULT_SCOPE_ADDRESS,
+            hit.attribute
+                .as_ref()
+                .expect("the onboarded asset's attribute should be returned")
+                .scope_address,
+            "the attribute for the requested scope should be returned",
+        );
+        let unknown_type = &response.results[1];
+        assert!(
+            unknown_type.attribute.is_none(),
+            "no attribute should be returned for an unknown asset type",
+        );
+        assert!(
+            unknown_type
+                .error
+                .as_ref()
+                .expect("an error should be produced for an unknown asset type")
+                .contains("not-a-real-type"),
+            "the error should reference the unknown asset type",
+        );
+        assert_eq!(
+            hit.attribute, response.results[2].attribute,
+            "resolving by asset uuid should produce the same attribute as by scope address",
+        );
+        let bad_identifier = &response.results[3];
+        assert_eq!(
+            SerializedEnum::new("bad_type", "value"),
+            bad_identifier.identifier,
+            "the result should echo the requested identifier",
+        );
+        assert!(
+            bad_identifier.attribute.is_none() && bad_identifier.error.is_some(),
+            "an invalid identifier should produce an error result",
+        );
+    }
+
+    #[test]
+    fn test_batch_with_misses() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        let response = query_batch(
+            &deps,
+            vec![
+                BatchScopeAttributeRequest::new(
+                    AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS).to_serialized_enum(),
+                    DEFAULT_ASSET_TYPE,
+                ),
+                BatchScopeAttributeRequest::new(
+                    AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS).to_serialized_enum(),
+                    "not-a-real-type",
+                ),
+            ],
+        )
+        .expect("expected the batch query to succeed");
+        let miss = &response.results[0];
+        assert!(
+            miss.attribute.is_none() && miss.error.is_none(),
+            "a scope without an attribute should produce neither an attribute nor an error",
+        );
+        assert!(
+            response.results[1].error.is_some(),
+            "an unknown asset type should still produce an error alongside a miss",
+        );
+    }
+
+    #[test]
+    fn test_empty_batch_produces_no_results() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        let response = query_batch(&deps, vec![]).expect("expected an empty batch to succeed");
+        assert!(
+            response.results.is_empty(),
+            "no results should be produced for an empty batch",
+        );
+    }
+
+    #[test]
+    fn test_batch_exceeding_max_size_is_rejected() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        let request = BatchScopeAttributeRequest::new(
+            AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS).to_serialized_enum(),
+            DEFAULT_ASSET_TYPE,
+        );
+        query_batch(&deps, vec![request.clone(); MAX_SCOPE_ATTRIBUTE_BATCH_SIZE])
+            .expect("expected a batch at the maximum size to succeed");
+        let error = query_batch(&deps, vec![request; MAX_SCOPE_ATTRIBUTE_BATCH_SIZE + 1])
+            .expect_err("expected a batch over the maximum size to be rejected");
+        assert!(
+            error.is_invalid_message_fields(),
+            "expected an invalid message fields error to be returned, but got: {:?}",
+            error,
+        );
+    }
+
+    fn query_batch(
+        deps: &MockOwnedDeps,
+        requests: Vec<BatchScopeAttributeRequest>,
+    ) -> Result<BatchScopeAttributeResponse, ContractError> {
+        query_asset_scope_attributes_batch(&deps.as_ref(), requests).map(|binary| {
+            from_json::<BatchScopeAttributeResponse>(&binary)
+                .expect("expected the result to deserialize correctly")
+        })
+    }
+}
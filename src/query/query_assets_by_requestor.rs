@@ -0,0 +1,195 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+use crate::core::error::ContractError;
+use crate::core::state::list_requestor_index_page;
+use crate::core::types::assets_by_requestor_response::AssetsByRequestorResponse;
+use crate::query::query_asset_scope_attribute_by_asset_type::may_query_scope_attribute_by_scope_address_and_asset_type;
+use crate::util::aliases::AssetResult;
+use crate::util::constants::MAX_REQUESTOR_ASSETS_PER_PAGE;
+
+/// A query that fetches a single page of [AssetScopeAttributes](crate::core::types::asset_scope_attribute::AssetScopeAttribute)
+/// for the scopes onboarded by a requestor, sorted in ascending order by scope address, and
+/// serializes it as an [AssetsByRequestorResponse](crate::core::types::assets_by_requestor_response::AssetsByRequestorResponse).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `requestor_address` The bech32 address of the account that onboarded the scopes.
+/// * `asset_type` An optional asset type filter.  If provided, only scope attributes of this type
+/// are returned.
+/// * `start_after` The [next_start_after](crate::core::types::assets_by_requestor_response::AssetsByRequestorResponse::next_start_after)
+/// from a previous page's response.  When omitted, the first page is returned.
+/// * `limit` The maximum number of scopes to include in the page.  Must be greater than zero.
+/// Values greater than [MAX_REQUESTOR_ASSETS_PER_PAGE](crate::util::constants::MAX_REQUESTOR_ASSETS_PER_PAGE)
+/// are reduced to that limit, which is also used when no value is provided.
+pub fn query_assets_by_requestor(
+    deps: &Deps,
+    requestor_address: &str,
+    asset_type: Option<&str>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> AssetResult<Binary> {
+    let limit = limit.unwrap_or(MAX_REQUESTOR_ASSETS_PER_PAGE);
+    if limit == 0 {
+        return ContractError::InvalidMessageFields {
+            message_type: "QueryMsg::QueryAssetsByRequestor".to_string(),
+            invalid_fields: vec!["limit: must be greater than zero".to_string()],
+        }
+        .to_err();
+    }
+    let (scopes, has_next_page) = list_requestor_index_page(
+        deps.storage,
+        requestor_address,
+        asset_type,
+        start_after,
+        limit.min(MAX_REQUESTOR_ASSETS_PER_PAGE),
+    )?;
+    let next_start_after = if has_next_page {
+        scopes
+            .last()
+            .map(|(scope_address, _)| scope_address.to_owned())
+    } else {
+        None
+    };
+    let mut scope_attributes = vec![];
+    for (scope_address, asset_types) in scopes {
+        for asset_type in asset_types {
+            if let Some(scope_attribute) =
+                may_query_scope_attribute_by_scope_address_and_asset_type(
+                    deps,
+                    &scope_address,
+                    asset_type,
+                )?
+            {
+                scope_attributes.push(scope_attribute);
+            }
+        }
+    }
+    to_json_binary(&AssetsByRequestorResponse::new(
+        scope_attributes,
+        next_start_after,
+    ))?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::from_json;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::types::assets_by_requestor_response::AssetsByRequestorResponse;
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS,
+        DEFAULT_SECONDARY_ASSET_TYPE, DEFAULT_SENDER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        setup_no_attribute_response, setup_test_suite, InstArgs, MockOwnedDeps,
+    };
+    use crate::util::traits::OptionExtensions;
+
+    use super::query_assets_by_requestor;
+
+    #[test]
+    fn test_query_returns_onboarded_scope_attributes() {
+        let deps = setup_onboarded_asset();
+        let response = query_page(&deps, DEFAULT_SENDER_ADDRESS, None);
+        assert_eq!(
+            1,
+            response.scope_attributes.len(),
+            "expected the onboarded scope's attribute to be returned",
+        );
+        let scope_attribute = response.scope_attributes.first().unwrap();
+        assert_eq!(
+            DEFAULT_SCOPE_ADDRESS, scope_attribute.scope_address,
+            "expected the attribute to be for the onboarded scope",
+        );
+        assert_eq!(
+            DEFAULT_SENDER_ADDRESS,
+            scope_attribute.requestor_address.as_str(),
+            "expected the attribute to be for the requestor",
+        );
+        assert_eq!(
+            None, response.next_start_after,
+            "expected no cursor to be returned when all scopes fit in the page",
+        );
+    }
+
+    #[test]
+    fn test_query_filters_by_asset_type() {
+        let deps = setup_onboarded_asset();
+        assert_eq!(
+            1,
+            query_page(&deps, DEFAULT_SENDER_ADDRESS, DEFAULT_ASSET_TYPE.to_some())
+                .scope_attributes
+                .len(),
+            "expected the attribute to be returned when filtering by its asset type",
+        );
+        assert!(
+            query_page(
+                &deps,
+                DEFAULT_SENDER_ADDRESS,
+                DEFAULT_SECONDARY_ASSET_TYPE.to_some()
+            )
+            .scope_attributes
+            .is_empty(),
+            "expected no attributes to be returned when filtering by a different asset type",
+        );
+    }
+
+    #[test]
+    fn test_query_does_not_return_other_requestors_assets() {
+        let deps = setup_onboarded_asset();
+        let response = query_page(&deps, DEFAULT_ADMIN_ADDRESS, None);
+        assert!(
+            response.scope_attributes.is_empty(),
+            "expected no attributes to be returned for a requestor that onboarded nothing",
+        );
+        assert_eq!(
+            None, response.next_start_after,
+            "expected no cursor to be returned for an empty page",
+        );
+    }
+
+    #[test]
+    fn test_query_rejects_zero_limit() {
+        let deps = setup_onboarded_asset();
+        let err = query_assets_by_requestor(
+            &deps.as_ref(),
+            DEFAULT_SENDER_ADDRESS,
+            None,
+            None,
+            0.to_some(),
+        )
+        .expect_err("expected a limit of zero to be rejected");
+        assert!(
+            matches!(err, ContractError::InvalidMessageFields { .. }),
+            "expected an invalid message fields error, but got: {:?}",
+            err,
+        );
+    }
+
+    fn setup_onboarded_asset() -> MockOwnedDeps {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the asset onboard to succeed");
+        deps
+    }
+
+    fn query_page(
+        deps: &MockOwnedDeps,
+        requestor_address: &str,
+        asset_type: Option<&str>,
+    ) -> AssetsByRequestorResponse {
+        let binary =
+            query_assets_by_requestor(&deps.as_ref(), requestor_address, asset_type, None, None)
+                .expect("expected the query to succeed");
+        from_json::<AssetsByRequestorResponse>(&binary)
+            .expect("expected the response to deserialize properly")
+    }
+}
@@ -0,0 +1,174 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+use std::collections::BTreeMap;
+
+use crate::core::state::{list_asset_type_onboard_counts, STATE_V2};
+use crate::core::types::contract_metrics_response::ContractMetricsResponse;
+use crate::util::aliases::AssetResult;
+
+/// Fetches the operational [metrics](crate::core::state::StateV2::metrics) tracked by the contract,
+/// alongside the onboard count for each asset type, and serializes them as a
+/// [ContractMetricsResponse](crate::core::types::contract_metrics_response::ContractMetricsResponse).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+pub fn query_contract_metrics(deps: &Deps) -> AssetResult<Binary> {
+    let metrics = STATE_V2.load(deps.storage)?.metrics;
+    let asset_type_onboard_counts = list_asset_type_onboard_counts(deps.storage)?
+        .into_iter()
+        .collect::<BTreeMap<String, u64>>();
+    to_json_binary(&ContractMetricsResponse::new(
+        metrics,
+        asset_type_onboard_counts,
+    ))?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_json, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::state::record_onboard_metrics;
+    use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+    use crate::core::types::contract_metrics_response::ContractMetricsResponse;
+    use crate::testutil::attribute_utilities::{
+        build_scope_attribute, mock_attribute_response_for_scope,
+    };
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_ONBOARDING_COST, DEFAULT_RETRY_COST, DEFAULT_SCOPE_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        setup_no_attribute_response, setup_test_suite, test_instantiate_success, InstArgs,
+        MockOwnedDeps,
+    };
+    use crate::testutil::verify_asset_helpers::{test_verify_asset, TestVerifyAsset};
+
+    use super::query_contract_metrics;
+
+    #[test]
+    fn test_query_contract_metrics_before_any_activity() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let response = query_metrics(&deps);
+        assert_eq!(0, response.total_onboards, "no onboards should be counted");
+        assert_eq!(
+            0, response.total_verifications,
+            "no verifications should be counted",
+        );
+        assert_eq!(
+            Uint128::zero(),
+            response.average_fee_paid_nhash,
+            "the average fee should be zero when no assets have been onboarded",
+        );
+        assert!(
+            response.asset_type_onboard_counts.is_empty(),
+            "no asset type counts should be present",
+        );
+        assert_eq!(
+            None, response.most_popular_asset_type,
+            "no asset type should be the most popular when no assets have been onboarded",
+        );
+    }
+
+    #[test]
+    fn test_query_contract_metrics_after_onboard_and_verify_cycles() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("the initial onboard should succeed");
+        test_verify_asset(
+            &mut deps,
+            &mock_env(),
+            TestVerifyAsset::default_with_success(false),
+        )
+        .expect("the denial should succeed");
+        mock_attribute_response_for_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![build_scope_attribute(
+                DEFAULT_SCOPE_ADDRESS,
+                DEFAULT_ASSET_TYPE,
+                AssetOnboardingStatus::Denied,
+            )],
+        );
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("the retry should succeed");
+        test_verify_asset(
+            &mut deps,
+            &mock_env(),
+            TestVerifyAsset::default_with_success(true),
+        )
+        .expect("the approval should succeed");
+        let response = query_metrics(&deps);
+        assert_eq!(
+            2, response.total_onboards,
+            "both the initial onboard and the retry should be counted",
+        );
+        assert_eq!(
+            2, response.total_verifications,
+            "both the denial and the approval should be counted",
+        );
+        assert_eq!(
+            Uint128::new(DEFAULT_ONBOARDING_COST + DEFAULT_RETRY_COST),
+            response.total_fees_collected_nhash,
+            "the onboarding cost and the retry cost should both be collected",
+        );
+        assert_eq!(
+            Uint128::new((DEFAULT_ONBOARDING_COST + DEFAULT_RETRY_COST) / 2),
+            response.average_fee_paid_nhash,
+            "the average fee should be the total fees divided by the number of onboards",
+        );
+        assert_eq!(
+            Some(&2),
+            response.asset_type_onboard_counts.get(DEFAULT_ASSET_TYPE),
+            "both onboards should be counted for the default asset type",
+        );
+        assert_eq!(
+            Some(DEFAULT_ASSET_TYPE.to_string()),
+            response.most_popular_asset_type,
+            "the only onboarded asset type should be the most popular",
+        );
+    }
+
+    #[test]
+    fn test_query_contract_metrics_most_popular_asset_type() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        for asset_type in ["heloc", "mortgage", "mortgage", "payable", "payable"] {
+            record_onboard_metrics(deps.as_mut().storage, asset_type, 100, false)
+                .expect("recording onboard metrics should succeed");
+        }
+        let response = query_metrics(&deps);
+        assert_eq!(5, response.total_onboards, "all onboards should be counted");
+        assert_eq!(
+            3,
+            response.asset_type_onboard_counts.len(),
+            "each onboarded asset type should be counted",
+        );
+        assert_eq!(
+            Some("mortgage".to_string()),
+            response.most_popular_asset_type,
+            "ties for the most onboards should be resolved by the alphabetically-first asset type",
+        );
+        record_onboard_metrics(deps.as_mut().storage, "payable", 100, false)
+            .expect("recording onboard metrics should succeed");
+        assert_eq!(
+            Some("payable".to_string()),
+            query_metrics(&deps).most_popular_asset_type,
+            "the asset type with the most onboards should be the most popular",
+        );
+    }
+
+    fn query_metrics(deps: &MockOwnedDeps) -> ContractMetricsResponse {
+        from_json::<ContractMetricsResponse>(
+            &query_contract_metrics(&deps.as_ref()).expect("the metrics query should succeed"),
+        )
+        .expect("the response should deserialize correctly")
+    }
+}
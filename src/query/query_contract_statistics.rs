@@ -0,0 +1,226 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+use crate::core::state::build_contract_statistics;
+use crate::util::aliases::AssetResult;
+
+/// Fetches aggregate counts and totals describing the contract's contents and serializes them as a
+/// [ContractStatisticsResponse](crate::core::types::contract_statistics_response::ContractStatisticsResponse).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+pub fn query_contract_statistics(deps: &Deps) -> AssetResult<Binary> {
+    to_json_binary(&build_contract_statistics(deps.storage)?)?.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::from_json;
+    use cosmwasm_std::testing::mock_env;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::types::asset_identifier::AssetIdentifier;
+    use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+    use crate::core::types::contract_statistics_response::ContractStatisticsResponse;
+    use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::testutil::attribute_utilities::{
+        build_scope_attribute, mock_attribute_response_for_scope,
+    };
+    use crate::testutil::execute_utilities::execute_appeal_verification;
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS, DEFAULT_SENDER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        empty_mock_info, get_default_asset_definition_inputs, get_default_verifier_detail,
+        intercept_add_or_update_attribute, setup_no_attribute_response, setup_test_suite,
+        test_instantiate_success, InstArgs, MockOwnedDeps,
+    };
+    use crate::testutil::verify_asset_helpers::{test_verify_asset, TestVerifyAsset};
+    use crate::util::traits::OptionExtensions;
+
+    use super::query_contract_statistics;
+
+    #[test]
+    fn test_query_contract_statistics_after_instantiation() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        assert_eq!(
+            ContractStatisticsResponse {
+                total_asset_definitions: get_default_asset_definition_inputs().len() as u64,
+                total_pending_onboardings: 0,
+                total_approved_onboardings: 0,
+                total_denied_onboardings: 0,
+                total_appealing_onboardings: 0,
+                total_fee_payment_details_stored: 0,
+                total_onboarded_ever: 0,
+                total_verified_ever: 0,
+            },
+            query_statistics(&deps),
+            "only the instantiated asset definitions should be counted before any activity",
+        );
+    }
+
+    #[test]
+    fn test_query_contract_statistics_after_onboard_and_verification() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("the onboard should succeed");
+        let response = query_statistics(&deps);
+        assert_eq!(
+            1, response.total_pending_onboardings,
+            "the onboarded asset should be pending",
+        );
+        assert_eq!(
+            1, response.total_fee_payment_details_stored,
+            "a fee payment detail should be stored for the pending asset",
+        );
+        assert_eq!(
+            1, response.total_onboarded_ever,
+            "the onboarded asset should be counted",
+        );
+        assert_eq!(
+            0, response.total_verified_ever,
+            "no assets should be verified before the verifier responds",
+        );
+        test_verify_asset(&mut deps, &mock_env(), TestVerifyAsset::default())
+            .expect("the approval should succeed");
+        let response = query_statistics(&deps);
+        assert_eq!(
+            0, response.total_pending_onboardings,
+            "no assets should be pending after verification",
+        );
+        assert_eq!(
+            1, response.total_approved_onboardings,
+            "the verified asset should be approved",
+        );
+        assert_eq!(
+            0, response.total_fee_payment_details_stored,
+            "the fee payment detail should be removed after verification",
+        );
+        assert_eq!(
+            1, response.total_verified_ever,
+            "the approved asset should be counted as verified",
+        );
+    }
+
+    #[test]
+    fn test_query_contract_statistics_does_not_double_count_retries() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("the initial onboard should succeed");
+        test_verify_asset(
+            &mut deps,
+            &mock_env(),
+            TestVerifyAsset::default_with_success(false),
+        )
+        .expect("the denial should succeed");
+        let response = query_statistics(&deps);
+        assert_eq!(
+            1, response.total_denied_onboardings,
+            "the asset should be denied",
+        );
+        assert_eq!(
+            0, response.total_verified_ever,
+            "a denial should not be counted as a verified asset",
+        );
+        mock_attribute_response_for_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![build_scope_attribute(
+                DEFAULT_SCOPE_ADDRESS,
+                DEFAULT_ASSET_TYPE,
+                AssetOnboardingStatus::Denied,
+            )],
+        );
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("the retry should succeed");
+        let response = query_statistics(&deps);
+        assert_eq!(
+            1, response.total_pending_onboardings,
+            "the retried asset should be pending",
+        );
+        assert_eq!(
+            0, response.total_denied_onboardings,
+            "the retried asset should no longer be counted as denied",
+        );
+        assert_eq!(
+            1, response.total_onboarded_ever,
+            "the retry should not be counted as a new onboarded asset",
+        );
+        test_verify_asset(&mut deps, &mock_env(), TestVerifyAsset::default())
+            .expect("the approval should succeed");
+        let response = query_statistics(&deps);
+        assert_eq!(
+            1, response.total_approved_onboardings,
+            "the retried asset should be approved",
+        );
+        assert_eq!(
+            1, response.total_onboarded_ever,
+            "the asset should still only be counted as onboarded once",
+        );
+        assert_eq!(
+            1, response.total_verified_ever,
+            "the asset should only be counted as verified once",
+        );
+    }
+
+    #[test]
+    fn test_query_contract_statistics_counts_appealing_assets() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(
+            &mut deps,
+            &InstArgs::with_single_verifier(VerifierDetailV2 {
+                appeal_window_blocks: 50.to_some(),
+                ..get_default_verifier_detail()
+            }),
+        );
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("the onboard should succeed");
+        test_verify_asset(
+            &mut deps,
+            &mock_env(),
+            TestVerifyAsset::default_with_success(false),
+        )
+        .expect("the denial should succeed");
+        let response = execute_appeal_verification(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+            AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            "the verifier reviewed the wrong records",
+        )
+        .expect("the appeal should succeed");
+        intercept_add_or_update_attribute(
+            &mut deps,
+            response,
+            "the appeal should update the attribute",
+        )
+        .expect("the attribute update should be intercepted");
+        let response = query_statistics(&deps);
+        assert_eq!(
+            1, response.total_appealing_onboardings,
+            "the appealed asset should be counted as appealing",
+        );
+        assert_eq!(
+            0, response.total_denied_onboardings,
+            "the appealed asset should no longer be counted as denied",
+        );
+    }
+
+    fn query_statistics(deps: &MockOwnedDeps) -> ContractStatisticsResponse {
+        from_json::<ContractStatisticsResponse>(
+            &query_contract_statistics(&deps.as_ref())
+                .expect("the statistics query should succeed"),
+        )
+        .expect("the response should deserialize correctly")
+    }
+}
@@ -0,0 +1,111 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+use crate::core::state::iter_asset_definitions_v3;
+use crate::core::types::deprecated_definition_entry::DeprecatedDefinitionEntry;
+use crate::util::aliases::AssetResult;
+
+/// A query that fetches every [deprecated](crate::core::types::asset_definition::AssetDefinitionV3::deprecated)
+/// [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3), sorted in ascending
+/// order by asset type, and serializes them as a vector of [DeprecatedDefinitionEntry](crate::core::types::deprecated_definition_entry::DeprecatedDefinitionEntry)
+/// values.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+pub fn query_deprecated_definitions(deps: &Deps) -> AssetResult<Binary> {
+    let mut entries: Vec<DeprecatedDefinitionEntry> = vec![];
+    for definition in iter_asset_definitions_v3(deps.storage) {
+        let definition = definition?;
+        if definition.deprecated {
+            entries.push(DeprecatedDefinitionEntry::new(
+                definition.asset_type,
+                definition.replacement_asset_type,
+            ));
+        }
+    }
+    to_json_binary(&entries)?.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_json, Deps};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::types::asset_definition::AssetDefinitionInputV3;
+    use crate::core::types::deprecated_definition_entry::DeprecatedDefinitionEntry;
+    use crate::testutil::execute_utilities::{
+        execute_add_asset_definition, execute_deprecate_asset_definition,
+    };
+    use crate::testutil::test_constants::{DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE};
+    use crate::testutil::test_utilities::{
+        empty_mock_info, get_default_asset_definition_input, test_instantiate_success, InstArgs,
+    };
+    use crate::util::traits::OptionExtensions;
+
+    use super::query_deprecated_definitions;
+
+    #[test]
+    fn test_no_deprecated_definitions() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        assert!(
+            query_entries(&deps.as_ref()).is_empty(),
+            "no entries should be returned when no definitions have been deprecated",
+        );
+    }
+
+    #[test]
+    fn test_only_deprecated_definitions_are_returned() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        for asset_type in ["heloc", "mortgage"] {
+            execute_add_asset_definition(
+                deps.as_mut(),
+                mock_env(),
+                empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+                AssetDefinitionInputV3 {
+                    asset_type: asset_type.to_string(),
+                    bind_name: false.to_some(),
+                    ..get_default_asset_definition_input()
+                },
+            )
+            .expect("adding an asset definition should succeed");
+        }
+        execute_deprecate_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            "mortgage",
+            None::<String>,
+        )
+        .expect("deprecating without a replacement should succeed");
+        execute_deprecate_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            "heloc".to_some(),
+        )
+        .expect("deprecating with a replacement should succeed");
+        assert_eq!(
+            vec![
+                DeprecatedDefinitionEntry::new("mortgage", None),
+                DeprecatedDefinitionEntry::new(DEFAULT_ASSET_TYPE, "heloc".to_string().to_some()),
+            ],
+            query_entries(&deps.as_ref()),
+            "only the deprecated definitions should be returned, sorted by asset type",
+        );
+    }
+
+    fn query_entries(deps: &Deps) -> Vec<DeprecatedDefinitionEntry> {
+        query_deprecated_definitions(deps)
+            .map(|binary| {
+                from_json::<Vec<DeprecatedDefinitionEntry>>(&binary)
+                    .expect("expected the result to deserialize correctly")
+            })
+            .expect("expected the query to succeed")
+    }
+}
@@ -0,0 +1,263 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps, Env};
+use result_extensions::ResultExtensions;
+
+use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+use crate::core::types::fee_payment_detail::FeePaymentDetail;
+use crate::query::query_asset_scope_attribute::may_query_scope_attribute_by_scope_address;
+use crate::query::query_onboarding_fee_preview::load_onboarding_verifier_detail;
+use crate::util::aliases::AssetResult;
+
+/// A query that calculates the exact [FeePaymentDetail](crate::core::types::fee_payment_detail::FeePaymentDetail)
+/// that would be stored if a scope were onboarded as a specific asset type with a specific verifier
+/// in the current block, and serializes it.  The scope's existing [AssetScopeAttributes](crate::core::types::asset_scope_attribute::AssetScopeAttribute)
+/// are inspected to detect retries and subsequent classification discounts, so callers do not need
+/// to know the scope's classification history.  No state is modified.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the query.
+/// * `scope_address` The bech32 address of the scope to onboard.
+/// * `asset_type` The asset type to onboard the scope as.
+/// * `verifier_address` The bech32 address of the verifier that would be selected for onboarding.
+pub fn query_effective_fee(
+    deps: &Deps,
+    env: &Env,
+    scope_address: &str,
+    asset_type: &str,
+    verifier_address: &str,
+) -> AssetResult<Binary> {
+    let verifier_detail =
+        load_onboarding_verifier_detail(deps.storage, asset_type, verifier_address)?;
+    let existing_scope_attributes =
+        may_query_scope_attribute_by_scope_address(deps, scope_address)?.unwrap_or_default();
+    // Retry costs are only charged during onboarding when the denied classification of the same
+    // asset type was performed by the same verifier
+    let is_retry = existing_scope_attributes.iter().any(|attribute| {
+        attribute.asset_type == asset_type
+            && attribute.onboarding_status == AssetOnboardingStatus::Denied
+            && attribute.verifier_address.as_str() == verifier_address
+    });
+    to_json_binary(&FeePaymentDetail::new(
+        scope_address,
+        &verifier_detail,
+        is_retry,
+        asset_type,
+        &existing_scope_attributes,
+        env.block.time.seconds(),
+        env.block.height,
+    )?)?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::from_json;
+    use cosmwasm_std::testing::mock_env;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::types::asset_definition::AssetDefinitionInputV3;
+    use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+    use crate::core::types::fee_payment_detail::FeePaymentDetail;
+    use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::testutil::attribute_utilities::{
+        build_scope_attribute, mock_attribute_response_for_scope,
+    };
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_ONBOARDING_COST, DEFAULT_RETRY_COST, DEFAULT_SCOPE_ADDRESS,
+        DEFAULT_SECONDARY_ASSET_TYPE, DEFAULT_SUBSEQUENT_CLASSIFICATION_COST,
+        DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        get_default_asset_definition_input, get_default_verifier_detail,
+        setup_no_attribute_response, setup_test_suite, InstArgs, MockOwnedDeps,
+    };
+    use crate::util::aliases::AssetResult;
+
+    use super::query_effective_fee;
+
+    const OTHER_VERIFIER_ADDRESS: &str = "tp1rk3qa624qe504mmvh2nv30zkrtdc5y2455uvew";
+
+    #[test]
+    fn test_effective_fee_for_fresh_onboard_uses_onboarding_cost() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        let env = mock_env();
+        let fee_detail = query_fee(&deps, DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS)
+            .expect("expected the effective fee query to succeed");
+        assert_eq!(
+            DEFAULT_ONBOARDING_COST,
+            fee_detail.sum_costs(),
+            "expected the verifier's onboarding cost to be charged for a fresh onboard",
+        );
+        assert_eq!(
+            DEFAULT_SCOPE_ADDRESS, fee_detail.scope_address,
+            "expected the fee detail to reference the queried scope",
+        );
+        assert_eq!(
+            env.block.time.seconds(),
+            fee_detail.created_at,
+            "expected the fee detail to be created at the current block time",
+        );
+        assert_eq!(
+            env.block.height, fee_detail.created_at_block,
+            "expected the fee detail to be created at the current block height",
+        );
+    }
+
+    #[test]
+    fn test_effective_fee_for_retry_uses_retry_cost() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        mock_attribute_response_for_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![build_scope_attribute(
+                DEFAULT_SCOPE_ADDRESS,
+                DEFAULT_ASSET_TYPE,
+                AssetOnboardingStatus::Denied,
+            )],
+        );
+        let fee_detail = query_fee(&deps, DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS)
+            .expect("expected the effective fee query to succeed");
+        assert_eq!(
+            DEFAULT_RETRY_COST,
+            fee_detail.sum_costs(),
+            "expected the verifier's retry cost to be charged after a denial",
+        );
+    }
+
+    #[test]
+    fn test_effective_fee_for_retry_with_different_verifier_uses_onboarding_cost() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(
+            &mut deps,
+            &InstArgs::with_asset_definitions(vec![AssetDefinitionInputV3 {
+                verifiers: vec![
+                    get_default_verifier_detail(),
+                    VerifierDetailV2 {
+                        address: OTHER_VERIFIER_ADDRESS.to_string(),
+                        ..get_default_verifier_detail()
+                    },
+                ],
+                ..get_default_asset_definition_input()
+            }]),
+        );
+        mock_attribute_response_for_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![build_scope_attribute(
+                DEFAULT_SCOPE_ADDRESS,
+                DEFAULT_ASSET_TYPE,
+                AssetOnboardingStatus::Denied,
+            )],
+        );
+        let fee_detail = query_fee(&deps, DEFAULT_ASSET_TYPE, OTHER_VERIFIER_ADDRESS)
+            .expect("expected the effective fee query to succeed");
+        assert_eq!(
+            DEFAULT_ONBOARDING_COST,
+            fee_detail.sum_costs(),
+            "expected the retry cost to be withheld when a different verifier is selected",
+        );
+    }
+
+    #[test]
+    fn test_effective_fee_for_subsequent_classification_uses_subsequent_cost() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(
+            &mut deps,
+            &InstArgs::default_with_additional_asset_types(vec![DEFAULT_SECONDARY_ASSET_TYPE]),
+        );
+        mock_attribute_response_for_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![build_scope_attribute(
+                DEFAULT_SCOPE_ADDRESS,
+                DEFAULT_ASSET_TYPE,
+                AssetOnboardingStatus::Approved,
+            )],
+        );
+        let fee_detail = query_fee(
+            &deps,
+            DEFAULT_SECONDARY_ASSET_TYPE,
+            DEFAULT_VERIFIER_ADDRESS,
+        )
+        .expect("expected the effective fee query to succeed");
+        assert_eq!(
+            DEFAULT_SUBSEQUENT_CLASSIFICATION_COST,
+            fee_detail.sum_costs(),
+            "expected the subsequent classification cost to be charged",
+        );
+    }
+
+    #[test]
+    fn test_effective_fee_for_retry_of_subsequent_classification_uses_retry_cost() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(
+            &mut deps,
+            &InstArgs::default_with_additional_asset_types(vec![DEFAULT_SECONDARY_ASSET_TYPE]),
+        );
+        mock_attribute_response_for_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![
+                build_scope_attribute(
+                    DEFAULT_SCOPE_ADDRESS,
+                    DEFAULT_ASSET_TYPE,
+                    AssetOnboardingStatus::Approved,
+                ),
+                build_scope_attribute(
+                    DEFAULT_SCOPE_ADDRESS,
+                    DEFAULT_SECONDARY_ASSET_TYPE,
+                    AssetOnboardingStatus::Denied,
+                ),
+            ],
+        );
+        let fee_detail = query_fee(
+            &deps,
+            DEFAULT_SECONDARY_ASSET_TYPE,
+            DEFAULT_VERIFIER_ADDRESS,
+        )
+        .expect("expected the effective fee query to succeed");
+        assert_eq!(
+            DEFAULT_RETRY_COST,
+            fee_detail.sum_costs(),
+            "expected the retry cost to take precedence over the subsequent classification cost",
+        );
+    }
+
+    #[test]
+    fn test_effective_fee_rejects_unsupported_asset_type() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        let err = query_fee(&deps, "bogus", DEFAULT_VERIFIER_ADDRESS)
+            .expect_err("expected an unsupported asset type to be rejected");
+        assert!(
+            matches!(err, ContractError::UnsupportedAssetType { ref asset_type } if asset_type == "bogus"),
+            "expected an unsupported asset type error, but got: {:?}",
+            err,
+        );
+    }
+
+    fn query_fee(
+        deps: &MockOwnedDeps,
+        asset_type: &str,
+        verifier_address: &str,
+    ) -> AssetResult<FeePaymentDetail> {
+        query_effective_fee(
+            &deps.as_ref(),
+            &mock_env(),
+            DEFAULT_SCOPE_ADDRESS,
+            asset_type,
+            verifier_address,
+        )
+        .map(|binary| {
+            from_json::<FeePaymentDetail>(&binary)
+                .expect("expected the response to deserialize properly")
+        })
+    }
+}
@@ -0,0 +1,193 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+use crate::{
+    core::types::{
+        asset_identifier::AssetIdentifier,
+        latest_verification_result_response::LatestVerificationResultResponse,
+    },
+    query::query_asset_scope_attribute_by_asset_type::{
+        may_query_scope_attribute_by_asset_uuid_and_asset_type,
+        may_query_scope_attribute_by_scope_address_and_asset_type,
+    },
+    util::aliases::AssetResult,
+};
+
+/// Fetches the verification status of an [AssetScopeAttribute](crate::core::types::asset_scope_attribute::AssetScopeAttribute)
+/// for a particular asset type and serializes it as a [LatestVerificationResultResponse](crate::core::types::latest_verification_result_response::LatestVerificationResultResponse).
+/// When no attribute exists for the asset type, a None Option variant is serialized instead,
+/// effectively representing a null json payload.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `identifier` Helps derive a unique key that can locate an [AssetScopeAttribute](crate::core::types::asset_scope_attribute::AssetScopeAttribute).
+/// * `asset_type` The asset type to query for
+pub fn query_latest_verification_result<S: Into<String>>(
+    deps: &Deps,
+    identifier: AssetIdentifier,
+    asset_type: S,
+) -> AssetResult<Binary> {
+    let scope_attribute = match identifier {
+        AssetIdentifier::AssetUuid(asset_uuid) | AssetIdentifier::ScopeUuid(asset_uuid) => {
+            may_query_scope_attribute_by_asset_uuid_and_asset_type(deps, asset_uuid, asset_type)
+        }
+        AssetIdentifier::ScopeAddress(scope_address) => {
+            may_query_scope_attribute_by_scope_address_and_asset_type(
+                deps,
+                scope_address,
+                asset_type,
+            )
+        }
+    }?;
+    to_json_binary(&scope_attribute.map(LatestVerificationResultResponse::from_scope_attribute))?
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::from_json;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::types::asset_identifier::AssetIdentifier;
+    use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+    use crate::core::types::latest_verification_result_response::LatestVerificationResultResponse;
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_ASSET_UUID, DEFAULT_SCOPE_ADDRESS, DEFAULT_SCOPE_SPEC_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        mock_scope, setup_no_attribute_response, setup_test_suite, test_instantiate_success,
+        InstArgs, MockOwnedDeps,
+    };
+    use crate::testutil::verify_asset_helpers::{test_verify_asset, TestVerifyAsset};
+
+    use super::query_latest_verification_result;
+
+    #[test]
+    fn test_pending_asset_has_no_result() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the asset onboard to succeed");
+        let response = query_and_unwrap(&deps, AssetIdentifier::asset_uuid(DEFAULT_ASSET_UUID));
+        assert_eq!(
+            DEFAULT_SCOPE_ADDRESS, response.scope_address,
+            "the response should include the scope address of the onboarded asset",
+        );
+        assert_eq!(
+            DEFAULT_ASSET_TYPE, response.asset_type,
+            "the response should include the asset type of the onboarded asset",
+        );
+        assert_eq!(
+            AssetOnboardingStatus::Pending,
+            response.onboarding_status,
+            "the onboarding status should be pending before verification occurs",
+        );
+        assert!(
+            response.result.is_none(),
+            "no verification result should be present before verification occurs",
+        );
+    }
+
+    #[test]
+    fn test_approved_asset_includes_result() {
+        let mut deps = mock_provenance_dependencies();
+        let args = InstArgs::default();
+        setup_test_suite(&mut deps, &args);
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the asset onboard to succeed");
+        test_verify_asset(&mut deps, &args.env, TestVerifyAsset::default())
+            .expect("expected the asset verification to succeed");
+        let response =
+            query_and_unwrap(&deps, AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS));
+        assert_eq!(
+            AssetOnboardingStatus::Approved,
+            response.onboarding_status,
+            "the onboarding status should be approved after a successful verification",
+        );
+        let result = response
+            .result
+            .expect("a verification result should be present after verification");
+        assert!(
+            result.success,
+            "the verification result should indicate success",
+        );
+        assert_eq!(
+            TestVerifyAsset::default_verify_asset().message.unwrap(),
+            result.message,
+            "the verification result should include the message provided by the verifier",
+        );
+    }
+
+    #[test]
+    fn test_denied_asset_includes_result() {
+        let mut deps = mock_provenance_dependencies();
+        let args = InstArgs::default();
+        setup_test_suite(&mut deps, &args);
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the asset onboard to succeed");
+        test_verify_asset(
+            &mut deps,
+            &args.env,
+            TestVerifyAsset::default_with_success(false),
+        )
+        .expect("expected the asset verification to succeed");
+        let response =
+            query_and_unwrap(&deps, AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS));
+        assert_eq!(
+            AssetOnboardingStatus::Denied,
+            response.onboarding_status,
+            "the onboarding status should be denied after a failed verification",
+        );
+        assert!(
+            !response
+                .result
+                .expect("a verification result should be present after verification")
+                .success,
+            "the verification result should indicate failure",
+        );
+    }
+
+    #[test]
+    fn test_missing_scope_attribute_produces_none() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let scope_address = "scope-address".to_string();
+        mock_scope(
+            &mut deps,
+            &scope_address,
+            DEFAULT_SCOPE_SPEC_ADDRESS,
+            "test-owner",
+        );
+        setup_no_attribute_response(&mut deps, Some(scope_address.to_owned()));
+        let binary = query_latest_verification_result(
+            &deps.as_ref(),
+            AssetIdentifier::scope_address(&scope_address),
+            DEFAULT_ASSET_TYPE,
+        )
+        .expect("the query should execute without error");
+        let result = from_json::<Option<LatestVerificationResultResponse>>(&binary)
+            .expect("expected the result to deserialize correctly");
+        assert!(
+            result.is_none(),
+            "expected the result to be missing because no scope attribute existed at the scope address",
+        );
+    }
+
+    fn query_and_unwrap(
+        deps: &MockOwnedDeps,
+        identifier: AssetIdentifier,
+    ) -> LatestVerificationResultResponse {
+        let binary =
+            query_latest_verification_result(&deps.as_ref(), identifier, DEFAULT_ASSET_TYPE)
+                .expect("expected the query to execute successfully");
+        from_json::<Option<LatestVerificationResultResponse>>(&binary)
+            .expect("expected the result to deserialize correctly")
+            .expect("expected the result to be present in the resulting Option")
+    }
+}
@@ -0,0 +1,273 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps, Storage, Uint128};
+use result_extensions::ResultExtensions;
+
+use crate::core::error::ContractError;
+use crate::core::state::load_asset_definition_by_type_v3;
+use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+use crate::core::types::fee_payment_detail::FeePaymentDetail;
+use crate::core::types::fee_preview_response::FeePreviewResponse;
+use crate::core::types::verifier_detail::VerifierDetailV2;
+use crate::query::query_asset_scope_attribute::may_query_scope_attribute_by_scope_address;
+use crate::util::aliases::AssetResult;
+
+/// A query that calculates the fees that would be charged if a scope were onboarded as a specific
+/// asset type with a specific verifier, and serializes them as a [FeePreviewResponse](crate::core::types::fee_preview_response::FeePreviewResponse).
+/// The scope's existing [AssetScopeAttributes](crate::core::types::asset_scope_attribute::AssetScopeAttribute)
+/// are inspected to apply retry and subsequent classification costs in the same manner as the
+/// [onboard_asset](crate::execute::onboard_asset::onboard_asset) function.  No state is modified.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `scope_address` The bech32 address of the scope to onboard.
+/// * `asset_type` The asset type to onboard the scope as.
+/// * `verifier_address` The bech32 address of the verifier that would be selected for onboarding.
+pub fn query_onboarding_fee_preview(
+    deps: &Deps,
+    scope_address: &str,
+    asset_type: &str,
+    verifier_address: &str,
+) -> AssetResult<Binary> {
+    let verifier_detail =
+        load_onboarding_verifier_detail(deps.storage, asset_type, verifier_address)?;
+    let existing_scope_attributes =
+        may_query_scope_attribute_by_scope_address(deps, scope_address)?.unwrap_or_default();
+    // Onboarding is only considered a retry when a previous classification of the same asset type
+    // was denied
+    let is_retry = existing_scope_attributes.iter().any(|attribute| {
+        attribute.asset_type == asset_type
+            && attribute.onboarding_status == AssetOnboardingStatus::Denied
+    });
+    let payment_detail = FeePaymentDetail::new(
+        scope_address,
+        &verifier_detail,
+        is_retry,
+        asset_type,
+        &existing_scope_attributes,
+        0,
+        0,
+    )?;
+    to_json_binary(&FeePreviewResponse::new(
+        payment_detail.payments.to_owned(),
+        Uint128::new(payment_detail.sum_costs()),
+        &verifier_detail.onboarding_denom,
+    ))?
+    .to_ok()
+}
+
+/// Loads the [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2) that would
+/// be selected when onboarding a scope, rejecting the request with the same errors as the
+/// [onboard_asset](crate::execute::onboard_asset::onboard_asset) function if the asset type is
+/// unsupported or disabled, or if the verifier is not registered to the asset type or is disabled.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+/// * `asset_type` The asset type to onboard the scope as.
+/// * `verifier_address` The bech32 address of the verifier that would be selected for onboarding.
+pub fn load_onboarding_verifier_detail(
+    storage: &dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+) -> AssetResult<VerifierDetailV2> {
+    let asset_definition = match load_asset_definition_by_type_v3(storage, asset_type) {
+        Ok(asset_definition) => {
+            if !asset_definition.enabled {
+                return ContractError::AssetTypeDisabled {
+                    asset_type: asset_type.to_string(),
+                }
+                .to_err();
+            }
+            asset_definition
+        }
+        Err(_) => {
+            return ContractError::UnsupportedAssetType {
+                asset_type: asset_type.to_string(),
+            }
+            .to_err()
+        }
+    };
+    let verifier_detail = asset_definition.get_verifier_detail(verifier_address)?;
+    if !verifier_detail.enabled {
+        return ContractError::VerifierDisabled {
+            asset_type: asset_type.to_string(),
+            verifier_address: verifier_address.to_string(),
+        }
+        .to_err();
+    }
+    verifier_detail.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::state::may_load_fee_payment_detail;
+    use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+    use crate::core::types::fee_preview_response::FeePreviewResponse;
+    use crate::testutil::attribute_utilities::{
+        build_scope_attribute, mock_attribute_response_for_scope,
+    };
+    use crate::testutil::execute_utilities::{
+        execute_toggle_asset_definition, execute_toggle_asset_verifier,
+    };
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_ONBOARDING_COST,
+        DEFAULT_ONBOARDING_DENOM, DEFAULT_RETRY_COST, DEFAULT_SCOPE_ADDRESS,
+        DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        empty_mock_info, setup_no_attribute_response, setup_test_suite, InstArgs, MockOwnedDeps,
+    };
+    use crate::util::aliases::AssetResult;
+    use cosmwasm_std::testing::mock_env;
+
+    use super::query_onboarding_fee_preview;
+
+    #[test]
+    fn test_preview_for_new_scope_uses_onboarding_cost() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        let response = query_preview(&deps, DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS)
+            .expect("expected the fee preview to succeed");
+        assert_eq!(
+            Uint128::new(DEFAULT_ONBOARDING_COST),
+            response.total_cost,
+            "expected the verifier's onboarding cost to be previewed for a new scope",
+        );
+        assert_eq!(
+            DEFAULT_ONBOARDING_DENOM, response.denom,
+            "expected the verifier's onboarding denom to be returned",
+        );
+        assert_eq!(
+            DEFAULT_ONBOARDING_COST,
+            response
+                .payments
+                .iter()
+                .map(|payment| payment.amount.amount.u128())
+                .sum::<u128>(),
+            "expected the payments to sum to the total cost",
+        );
+        assert!(
+            may_load_fee_payment_detail(
+                deps.as_ref().storage,
+                DEFAULT_SCOPE_ADDRESS,
+                DEFAULT_ASSET_TYPE
+            )
+            .is_none(),
+            "expected no fee payment detail to be stored by the preview",
+        );
+    }
+
+    #[test]
+    fn test_preview_for_denied_scope_uses_retry_cost() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        mock_attribute_response_for_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![build_scope_attribute(
+                DEFAULT_SCOPE_ADDRESS,
+                DEFAULT_ASSET_TYPE,
+                AssetOnboardingStatus::Denied,
+            )],
+        );
+        let response = query_preview(&deps, DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS)
+            .expect("expected the fee preview to succeed");
+        assert_eq!(
+            Uint128::new(DEFAULT_RETRY_COST),
+            response.total_cost,
+            "expected the verifier's retry cost to be previewed for a denied scope",
+        );
+    }
+
+    #[test]
+    fn test_preview_rejects_unsupported_asset_type() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        let err = query_preview(&deps, "bogus", DEFAULT_VERIFIER_ADDRESS)
+            .expect_err("expected an unsupported asset type to be rejected");
+        assert!(
+            matches!(err, ContractError::UnsupportedAssetType { ref asset_type } if asset_type == "bogus"),
+            "expected an unsupported asset type error, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_preview_rejects_disabled_asset_type() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        execute_toggle_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            false,
+        )
+        .expect("expected the asset definition to be disabled");
+        let err = query_preview(&deps, DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS)
+            .expect_err("expected a disabled asset type to be rejected");
+        assert!(
+            matches!(err, ContractError::AssetTypeDisabled { .. }),
+            "expected an asset type disabled error, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_preview_rejects_disabled_verifier() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        execute_toggle_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            DEFAULT_VERIFIER_ADDRESS,
+            false,
+        )
+        .expect("expected the verifier to be disabled");
+        let err = query_preview(&deps, DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS)
+            .expect_err("expected a disabled verifier to be rejected");
+        assert!(
+            matches!(err, ContractError::VerifierDisabled { .. }),
+            "expected a verifier disabled error, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_preview_rejects_unsupported_verifier() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        let err = query_preview(&deps, DEFAULT_ASSET_TYPE, DEFAULT_SENDER_ADDRESS)
+            .expect_err("expected an unregistered verifier to be rejected");
+        assert!(
+            matches!(err, ContractError::UnsupportedVerifier { .. }),
+            "expected an unsupported verifier error, but got: {:?}",
+            err,
+        );
+    }
+
+    fn query_preview(
+        deps: &MockOwnedDeps,
+        asset_type: &str,
+        verifier_address: &str,
+    ) -> AssetResult<FeePreviewResponse> {
+        query_onboarding_fee_preview(
+            &deps.as_ref(),
+            DEFAULT_SCOPE_ADDRESS,
+            asset_type,
+            verifier_address,
+        )
+        .map(|binary| {
+            from_json::<FeePreviewResponse>(&binary)
+                .expect("expected the response to deserialize properly")
+        })
+    }
+}
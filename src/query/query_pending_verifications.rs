@@ -0,0 +1,251 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+use crate::core::error::ContractError;
+use crate::core::state::list_pending_verifier_status_index_page;
+use crate::query::query_asset_scope_attribute_by_asset_type::may_query_scope_attribute_by_scope_address_and_asset_type;
+use crate::util::aliases::AssetResult;
+use crate::util::constants::MAX_PENDING_VERIFICATIONS_PER_PAGE;
+
+/// A query that fetches a single page of [AssetScopeAttributes](crate::core::types::asset_scope_attribute::AssetScopeAttribute)
+/// currently awaiting verification by a specific verifier, sorted in ascending order by scope
+/// address, and serializes them as a vector.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `verifier_address` The bech32 address of the verifier for which to list pending scopes.
+/// * `asset_type` An optional asset type filter.  If provided, only scope attributes of this type
+/// are returned.
+/// * `start_after` The scope address of the final attribute in a previous page.  When omitted, the
+/// first page is returned.
+/// * `limit` The maximum number of scopes to include in the page.  Must be greater than zero.
+/// Values greater than [MAX_PENDING_VERIFICATIONS_PER_PAGE](crate::util::constants::MAX_PENDING_VERIFICATIONS_PER_PAGE)
+/// are reduced to that limit, which is also used when no value is provided.
+pub fn query_pending_verifications(
+    deps: &Deps,
+    verifier_address: &str,
+    asset_type: Option<&str>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> AssetResult<Binary> {
+    let limit = limit.unwrap_or(MAX_PENDING_VERIFICATIONS_PER_PAGE);
+    if limit == 0 {
+        return ContractError::InvalidMessageFields {
+            message_type: "QueryMsg::QueryPendingVerificationsByVerifier".to_string(),
+            invalid_fields: vec!["limit: must be greater than zero".to_string()],
+        }
+        .to_err();
+    }
+    let mut scope_attributes = vec![];
+    for (scope_address, asset_type) in list_pending_verifier_status_index_page(
+        deps.storage,
+        verifier_address,
+        asset_type,
+        start_after,
+        limit.min(MAX_PENDING_VERIFICATIONS_PER_PAGE),
+    )? {
+        if let Some(scope_attribute) = may_query_scope_attribute_by_scope_address_and_asset_type(
+            deps,
+            &scope_address,
+            asset_type,
+        )? {
+            scope_attributes.push(scope_attribute);
+        }
+    }
+    to_json_binary(&scope_attributes)?.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::from_json;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+    use crate::core::types::asset_scope_attribute::AssetScopeAttribute;
+    use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::execute::onboard_asset::OnboardAssetV1;
+    use crate::testutil::attribute_utilities::{
+        build_scope_attribute, mock_attribute_response_for_scope,
+    };
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS, DEFAULT_SECONDARY_ASSET_TYPE,
+        DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        get_default_verifier_detail, setup_no_attribute_response, setup_test_suite, InstArgs,
+        MockOwnedDeps,
+    };
+    use crate::testutil::verify_asset_helpers::{test_verify_asset, TestVerifyAsset};
+    use crate::util::traits::OptionExtensions;
+
+    use super::query_pending_verifications;
+
+    const SECOND_VERIFIER_ADDRESS: &str = "tp1hsqtppgy7mgd64q0uvk7q39qa7h8hp30urqs6n";
+
+    #[test]
+    fn test_query_returns_pending_scope_attributes() {
+        let deps = setup_onboarded_asset(&InstArgs::default());
+        let scope_attributes = query_page(&deps, DEFAULT_VERIFIER_ADDRESS, None);
+        assert_eq!(
+            1,
+            scope_attributes.len(),
+            "expected the onboarded scope's attribute to be returned",
+        );
+        let scope_attribute = scope_attributes.first().unwrap();
+        assert_eq!(
+            DEFAULT_SCOPE_ADDRESS, scope_attribute.scope_address,
+            "expected the attribute to be for the onboarded scope",
+        );
+        assert_eq!(
+            AssetOnboardingStatus::Pending,
+            scope_attribute.onboarding_status,
+            "expected the attribute to be pending verification",
+        );
+    }
+
+    #[test]
+    fn test_query_filters_by_asset_type() {
+        let deps = setup_onboarded_asset(&InstArgs::default());
+        assert_eq!(
+            1,
+            query_page(
+                &deps,
+                DEFAULT_VERIFIER_ADDRESS,
+                DEFAULT_ASSET_TYPE.to_some()
+            )
+            .len(),
+            "expected the attribute to be returned when filtering by its asset type",
+        );
+        assert!(
+            query_page(
+                &deps,
+                DEFAULT_VERIFIER_ADDRESS,
+                DEFAULT_SECONDARY_ASSET_TYPE.to_some()
+            )
+            .is_empty(),
+            "expected no attributes to be returned when filtering by a different asset type",
+        );
+    }
+
+    #[test]
+    fn test_query_excludes_approved_and_denied_scopes() {
+        let args = InstArgs::default();
+        let mut deps = setup_onboarded_asset(&args);
+        test_verify_asset(
+            &mut deps,
+            &args.env,
+            TestVerifyAsset::default_with_success(false),
+        )
+        .expect("expected the asset verification to succeed");
+        assert!(
+            query_page(&deps, DEFAULT_VERIFIER_ADDRESS, None).is_empty(),
+            "expected a denied scope to be excluded",
+        );
+        mock_denied_attribute(&mut deps);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the asset onboard retry to succeed");
+        test_verify_asset(&mut deps, &args.env, TestVerifyAsset::default())
+            .expect("expected the asset verification to succeed");
+        assert!(
+            query_page(&deps, DEFAULT_VERIFIER_ADDRESS, None).is_empty(),
+            "expected an approved scope to be excluded",
+        );
+    }
+
+    #[test]
+    fn test_query_reflects_retry_with_different_verifier() {
+        let args = InstArgs::add_verifier_to_default(VerifierDetailV2 {
+            address: SECOND_VERIFIER_ADDRESS.to_string(),
+            ..get_default_verifier_detail()
+        });
+        let mut deps = setup_onboarded_asset(&args);
+        test_verify_asset(
+            &mut deps,
+            &args.env,
+            TestVerifyAsset::default_with_success(false),
+        )
+        .expect("expected the asset verification to succeed");
+        mock_denied_attribute(&mut deps);
+        test_onboard_asset(
+            &mut deps,
+            TestOnboardAsset {
+                onboard_asset: OnboardAssetV1 {
+                    verifier_address: SECOND_VERIFIER_ADDRESS.to_string(),
+                    ..TestOnboardAsset::default_onboard_asset()
+                },
+                ..TestOnboardAsset::default()
+            },
+        )
+        .expect("expected the asset onboard retry to succeed");
+        assert!(
+            query_page(&deps, DEFAULT_VERIFIER_ADDRESS, None).is_empty(),
+            "expected the retried scope to no longer be pending for the original verifier",
+        );
+        let scope_attributes = query_page(&deps, SECOND_VERIFIER_ADDRESS, None);
+        assert_eq!(
+            1,
+            scope_attributes.len(),
+            "expected the retried scope to be pending for the newly selected verifier",
+        );
+        assert_eq!(
+            SECOND_VERIFIER_ADDRESS,
+            scope_attributes.first().unwrap().verifier_address.as_str(),
+            "expected the returned attribute to reference the newly selected verifier",
+        );
+    }
+
+    #[test]
+    fn test_query_rejects_zero_limit() {
+        let deps = setup_onboarded_asset(&InstArgs::default());
+        let err = query_pending_verifications(
+            &deps.as_ref(),
+            DEFAULT_VERIFIER_ADDRESS,
+            None,
+            None,
+            0.to_some(),
+        )
+        .expect_err("expected a limit of zero to be rejected");
+        assert!(
+            matches!(err, ContractError::InvalidMessageFields { .. }),
+            "expected an invalid message fields error, but got: {:?}",
+            err,
+        );
+    }
+
+    fn setup_onboarded_asset(args: &InstArgs) -> MockOwnedDeps {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, args);
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the asset onboard to succeed");
+        deps
+    }
+
+    fn mock_denied_attribute(deps: &mut MockOwnedDeps) {
+        mock_attribute_response_for_scope(
+            deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![build_scope_attribute(
+                DEFAULT_SCOPE_ADDRESS,
+                DEFAULT_ASSET_TYPE,
+                AssetOnboardingStatus::Denied,
+            )],
+        );
+    }
+
+    fn query_page(
+        deps: &MockOwnedDeps,
+        verifier_address: &str,
+        asset_type: Option<&str>,
+    ) -> Vec<AssetScopeAttribute> {
+        let binary =
+            query_pending_verifications(&deps.as_ref(), verifier_address, asset_type, None, None)
+                .expect("expected the query to succeed");
+        from_json::<Vec<AssetScopeAttribute>>(&binary)
+            .expect("expected the response to deserialize properly")
+    }
+}
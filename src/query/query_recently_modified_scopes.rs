@@ -0,0 +1,158 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+use crate::core::state::list_scopes_modified_after_block;
+use crate::util::aliases::AssetResult;
+
+/// A query that fetches the scope addresses of all assets whose [AssetScopeAttribute](crate::core::types::asset_scope_attribute::AssetScopeAttribute)
+/// was modified after a specific block height, sorted in ascending order by the block of their
+/// most recent modification, and serializes them as a vector of strings.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `after_block` The block height after which scopes must have been modified to be included.
+/// * `asset_type` An optional asset type filter.  If omitted, scopes modified as any asset type
+/// are included.
+pub fn query_recently_modified_scopes(
+    deps: &Deps,
+    after_block: u64,
+    asset_type: Option<&str>,
+) -> AssetResult<Binary> {
+    to_json_binary(&list_scopes_modified_after_block(
+        deps.storage,
+        after_block,
+        asset_type,
+    )?)?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::from_json;
+    use cosmwasm_std::testing::mock_env;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::state::update_last_modified_block_index;
+    use crate::core::types::asset_scope_attribute::AssetScopeAttribute;
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS, DEFAULT_SECONDARY_ASSET_TYPE,
+    };
+    use crate::testutil::test_utilities::{
+        get_default_asset_scope_attribute, setup_no_attribute_response, setup_test_suite, InstArgs,
+        MockOwnedDeps,
+    };
+    use crate::testutil::verify_asset_helpers::{test_verify_asset, TestVerifyAsset};
+
+    use super::query_recently_modified_scopes;
+
+    #[test]
+    fn test_no_modified_scopes() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        assert!(
+            query_scopes(&deps, 0, None).is_empty(),
+            "no scopes should be returned before any onboarding occurs",
+        );
+    }
+
+    #[test]
+    fn test_scopes_are_tracked_across_onboard_and_verify() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        let onboard_height = mock_env().block.height;
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the asset onboard to succeed");
+        assert_eq!(
+            vec![DEFAULT_SCOPE_ADDRESS.to_string()],
+            query_scopes(&deps, onboard_height - 1, None),
+            "the onboarded scope should be returned when querying before its onboarding block",
+        );
+        assert!(
+            query_scopes(&deps, onboard_height, None).is_empty(),
+            "the onboarded scope should not be returned when querying at its onboarding block",
+        );
+        let mut verify_env = mock_env();
+        verify_env.block.height += 10;
+        test_verify_asset(
+            &mut deps,
+            &verify_env,
+            TestVerifyAsset::default_with_success(true),
+        )
+        .expect("expected the asset verification to succeed");
+        assert_eq!(
+            vec![DEFAULT_SCOPE_ADDRESS.to_string()],
+            query_scopes(&deps, onboard_height, DEFAULT_ASSET_TYPE.into()),
+            "the verified scope should be returned when querying after its onboarding block",
+        );
+        assert!(
+            query_scopes(&deps, onboard_height, DEFAULT_SECONDARY_ASSET_TYPE.into()).is_empty(),
+            "the verified scope should not be returned when filtering on a different asset type",
+        );
+        assert!(
+            query_scopes(&deps, verify_env.block.height, None).is_empty(),
+            "the verified scope should not be returned when querying at its verification block",
+        );
+    }
+
+    #[test]
+    fn test_scopes_are_sorted_by_block_and_deduplicated() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        let second_scope_address = "scope1qz9puy0kad8htbj8xfe2lhnlqr5sp8rqms";
+        for (scope_address, asset_type, block) in [
+            (DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE, 30),
+            (second_scope_address, DEFAULT_ASSET_TYPE, 20),
+            (DEFAULT_SCOPE_ADDRESS, DEFAULT_SECONDARY_ASSET_TYPE, 10),
+        ] {
+            update_last_modified_block_index(
+                deps.as_mut().storage,
+                None,
+                &AssetScopeAttribute {
+                    scope_address: scope_address.to_string(),
+                    asset_type: asset_type.to_string(),
+                    last_modified_block: block,
+                    ..get_default_asset_scope_attribute()
+                },
+            )
+            .expect("the index entry should be stored");
+        }
+        assert_eq!(
+            vec![
+                DEFAULT_SCOPE_ADDRESS.to_string(),
+                second_scope_address.to_string(),
+            ],
+            query_scopes(&deps, 0, None),
+            "each scope should be returned once, in order of its earliest matching modification",
+        );
+        assert_eq!(
+            vec![
+                second_scope_address.to_string(),
+                DEFAULT_SCOPE_ADDRESS.to_string(),
+            ],
+            query_scopes(&deps, 0, DEFAULT_ASSET_TYPE.into()),
+            "only modifications of the filtered asset type should determine the order",
+        );
+        assert_eq!(
+            vec![DEFAULT_SCOPE_ADDRESS.to_string()],
+            query_scopes(&deps, 20, None),
+            "only scopes modified after the given block should be returned",
+        );
+    }
+
+    fn query_scopes(
+        deps: &MockOwnedDeps,
+        after_block: u64,
+        asset_type: Option<&str>,
+    ) -> Vec<String> {
+        query_recently_modified_scopes(&deps.as_ref(), after_block, asset_type)
+            .map(|binary| {
+                from_json::<Vec<String>>(&binary)
+                    .expect("expected the result to deserialize correctly")
+            })
+            .expect("expected the query to succeed")
+    }
+}
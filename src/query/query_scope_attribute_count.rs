@@ -0,0 +1,173 @@
+use std::collections::HashSet;
+
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use provwasm_std::types::provenance::attribute::v1::{AttributeQuerier, AttributeType};
+use result_extensions::ResultExtensions;
+
+use crate::core::state::{iter_asset_definitions_v3, load_asset_definition_by_type_v3, STATE_V2};
+use crate::core::types::count_response::CountResponse;
+use crate::util::aliases::AssetResult;
+
+/// A query that counts the [AssetScopeAttributes](crate::core::types::asset_scope_attribute::AssetScopeAttribute)
+/// that this contract has attached to a Provenance Blockchain Metadata Scope, and serializes the
+/// result as a [CountResponse](crate::core::types::count_response::CountResponse).  Attributes are
+/// matched on their generated attribute names, and their values are never deserialized, making
+/// this a cheaper alternative to the full scope attribute queries.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `scope_address` The bech32 address of the scope for which to count attributes.
+/// * `asset_type` An optional asset type filter.  If provided, only the attributes for that asset
+/// type are counted, and the request is rejected if no asset definition exists for it.  If omitted,
+/// attributes for every asset type are counted.
+pub fn query_scope_attribute_count<S: Into<String>>(
+    deps: &Deps,
+    scope_address: S,
+    asset_type: Option<&str>,
+) -> AssetResult<Binary> {
+    let scope_address = scope_address.into();
+    let attribute_querier = AttributeQuerier::new(&deps.querier);
+    let count = if let Some(asset_type) = asset_type {
+        let attribute_name =
+            load_asset_definition_by_type_v3(deps.storage, asset_type)?.attribute_name(deps)?;
+        attribute_querier
+            .attribute(scope_address, attribute_name, None)?
+            .attributes
+            .len()
+    } else {
+        let state = STATE_V2.load(deps.storage)?;
+        let attribute_names = iter_asset_definitions_v3(deps.storage)
+            .map(|def| def.map(|def| def.attribute_name_state(&state)))
+            .collect::<AssetResult<HashSet<String>>>()?;
+        attribute_querier
+            .attributes(scope_address, None)?
+            .attributes
+            .iter()
+            .filter(|attr| {
+                attribute_names.contains(&attr.name) && attr.attribute_type() == AttributeType::Json
+            })
+            .count()
+    };
+    to_json_binary(&CountResponse::new(count as u64))?.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::from_json;
+    use provwasm_mocks::mock_provenance_dependencies;
+    use provwasm_std::types::provenance::attribute::v1::{Attribute, AttributeType};
+
+    use crate::core::error::ContractError;
+    use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+    use crate::core::types::count_response::CountResponse;
+    use crate::testutil::attribute_utilities::{
+        build_scope_attribute, mock_attribute_response_for_scope,
+    };
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS, DEFAULT_SECONDARY_ASSET_TYPE,
+    };
+    use crate::testutil::test_utilities::{
+        setup_no_attribute_response, setup_test_suite, InstArgs, MockOwnedDeps,
+    };
+
+    use super::query_scope_attribute_count;
+
+    #[test]
+    fn test_count_is_zero_for_scope_without_attributes() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        assert_eq!(
+            0,
+            query_count(&deps, None),
+            "no attributes should be counted for a scope that has never been onboarded",
+        );
+        assert_eq!(
+            0,
+            query_count(&deps, DEFAULT_ASSET_TYPE.into()),
+            "no attributes should be counted for a specific asset type on a scope that has never been onboarded",
+        );
+    }
+
+    #[test]
+    fn test_count_by_asset_type_after_onboarding() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the asset onboard to succeed");
+        assert_eq!(
+            1,
+            query_count(&deps, DEFAULT_ASSET_TYPE.into()),
+            "the onboarded attribute should be counted for its asset type",
+        );
+    }
+
+    #[test]
+    fn test_count_across_all_asset_types_ignores_unrelated_attributes() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(
+            &mut deps,
+            &InstArgs::default_with_additional_asset_types(vec![DEFAULT_SECONDARY_ASSET_TYPE]),
+        );
+        mock_attribute_response_for_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![
+                build_scope_attribute(
+                    DEFAULT_SCOPE_ADDRESS,
+                    DEFAULT_ASSET_TYPE,
+                    AssetOnboardingStatus::Pending,
+                ),
+                build_scope_attribute(
+                    DEFAULT_SCOPE_ADDRESS,
+                    DEFAULT_SECONDARY_ASSET_TYPE,
+                    AssetOnboardingStatus::Approved,
+                ),
+                Attribute {
+                    name: "unrelated.attribute".to_string(),
+                    value: b"unrelated".to_vec(),
+                    attribute_type: AttributeType::String.into(),
+                    address: DEFAULT_SCOPE_ADDRESS.to_string(),
+                    expiration_date: None,
+                },
+            ],
+        );
+        assert_eq!(
+            2,
+            query_count(&deps, None),
+            "only the attributes created by the contract should be counted",
+        );
+    }
+
+    #[test]
+    fn test_count_for_unknown_asset_type_fails() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        let error = query_scope_attribute_count(
+            &deps.as_ref(),
+            DEFAULT_SCOPE_ADDRESS,
+            "not-a-real-type".into(),
+        )
+        .expect_err("expected the query to fail for an unknown asset type");
+        assert!(
+            matches!(error, ContractError::RecordNotFound { .. }),
+            "unexpected error type encountered: {:?}",
+            error,
+        );
+    }
+
+    fn query_count(deps: &MockOwnedDeps, asset_type: Option<&str>) -> u64 {
+        query_scope_attribute_count(&deps.as_ref(), DEFAULT_SCOPE_ADDRESS, asset_type)
+            .map(|binary| {
+                from_json::<CountResponse>(&binary)
+                    .expect("expected the result to deserialize correctly")
+                    .count
+            })
+            .expect("expected the query to succeed")
+    }
+}
@@ -0,0 +1,166 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+use crate::core::state::may_load_asset_type_by_scope_spec_address;
+use crate::core::types::scope_spec_binding_response::ScopeSpecBindingResponse;
+use crate::util::aliases::AssetResult;
+
+/// Finds the asset type of the [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
+/// bound to a scope specification and serializes the result as a [ScopeSpecBindingResponse](crate::core::types::scope_spec_binding_response::ScopeSpecBindingResponse).
+/// The response contains no asset type if no definition is bound to the scope specification.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `scope_spec_address` The bech32 address of the scope specification to look up.
+pub fn query_scope_spec_binding(deps: &Deps, scope_spec_address: &str) -> AssetResult<Binary> {
+    let asset_type = may_load_asset_type_by_scope_spec_address(deps.storage, scope_spec_address)?;
+    to_json_binary(&ScopeSpecBindingResponse::new(
+        scope_spec_address,
+        asset_type,
+    ))?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_json, Deps};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::types::asset_definition::AssetDefinitionInputV3;
+    use crate::core::types::scope_spec_binding_response::ScopeSpecBindingResponse;
+    use crate::execute::add_asset_definition::{add_asset_definition, AddAssetDefinitionV1};
+    use crate::execute::delete_asset_definition::{
+        delete_asset_definition, DeleteAssetDefinitionV1,
+    };
+    use crate::execute::update_asset_definition::{
+        update_asset_definition, UpdateAssetDefinitionV1,
+    };
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_SPEC_ADDRESS,
+        DEFAULT_SECONDARY_ASSET_TYPE,
+    };
+    use crate::testutil::test_utilities::{
+        empty_mock_info, get_default_asset_definition_input, test_instantiate_success, InstArgs,
+    };
+    use crate::util::traits::OptionExtensions;
+
+    use super::query_scope_spec_binding;
+
+    const SECONDARY_SCOPE_SPEC_ADDRESS: &str = "scopespec1qs30c9axgrw5669ft0kffe6h9gysfe58v3";
+
+    #[test]
+    fn test_absent_binding() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        assert_eq!(
+            ScopeSpecBindingResponse::new(DEFAULT_SCOPE_SPEC_ADDRESS, None),
+            query_binding(&deps.as_ref(), DEFAULT_SCOPE_SPEC_ADDRESS),
+            "no asset type should be returned for an unbound scope spec",
+        );
+    }
+
+    #[test]
+    fn test_binding_lifecycle() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        add_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            get_add_asset_definition(DEFAULT_SCOPE_SPEC_ADDRESS),
+        )
+        .expect("adding an asset definition with a scope spec should succeed");
+        assert_eq!(
+            DEFAULT_SECONDARY_ASSET_TYPE.to_string().to_some(),
+            query_binding(&deps.as_ref(), DEFAULT_SCOPE_SPEC_ADDRESS).asset_type,
+            "the scope spec should be bound to the added asset type",
+        );
+        update_asset_definition(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateAssetDefinitionV1::new(AssetDefinitionInputV3 {
+                asset_type: DEFAULT_SECONDARY_ASSET_TYPE.to_string(),
+                scope_spec_address: SECONDARY_SCOPE_SPEC_ADDRESS.to_string().to_some(),
+                ..get_default_asset_definition_input()
+            }),
+        )
+        .expect("updating the scope spec of an asset definition should succeed");
+        assert_eq!(
+            None,
+            query_binding(&deps.as_ref(), DEFAULT_SCOPE_SPEC_ADDRESS).asset_type,
+            "the previous scope spec should no longer be bound after the update",
+        );
+        assert_eq!(
+            DEFAULT_SECONDARY_ASSET_TYPE.to_string().to_some(),
+            query_binding(&deps.as_ref(), SECONDARY_SCOPE_SPEC_ADDRESS).asset_type,
+            "the new scope spec should be bound to the updated asset type",
+        );
+        delete_asset_definition(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DeleteAssetDefinitionV1::new(DEFAULT_SECONDARY_ASSET_TYPE),
+        )
+        .expect("deleting the asset definition should succeed");
+        assert_eq!(
+            None,
+            query_binding(&deps.as_ref(), SECONDARY_SCOPE_SPEC_ADDRESS).asset_type,
+            "the scope spec should no longer be bound after the definition is deleted",
+        );
+    }
+
+    #[test]
+    fn test_scope_spec_cannot_be_bound_to_multiple_asset_types() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        update_asset_definition(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateAssetDefinitionV1::new(AssetDefinitionInputV3 {
+                scope_spec_address: DEFAULT_SCOPE_SPEC_ADDRESS.to_string().to_some(),
+                ..get_default_asset_definition_input()
+            }),
+        )
+        .expect("binding a scope spec to the default asset type should succeed");
+        let error = add_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            get_add_asset_definition(DEFAULT_SCOPE_SPEC_ADDRESS),
+        )
+        .expect_err("binding an already-bound scope spec to another asset type should fail");
+        assert!(
+            error.to_string().contains(DEFAULT_SCOPE_SPEC_ADDRESS),
+            "the error should reference the already-bound scope spec, but got: {:?}",
+            error,
+        );
+        assert_eq!(
+            DEFAULT_ASSET_TYPE.to_string().to_some(),
+            query_binding(&deps.as_ref(), DEFAULT_SCOPE_SPEC_ADDRESS).asset_type,
+            "the original binding should be unaffected by the rejected definition",
+        );
+    }
+
+    fn get_add_asset_definition(scope_spec_address: &str) -> AddAssetDefinitionV1 {
+        AddAssetDefinitionV1 {
+            asset_definition: AssetDefinitionInputV3 {
+                asset_type: DEFAULT_SECONDARY_ASSET_TYPE.to_string(),
+                scope_spec_address: scope_spec_address.to_string().to_some(),
+                ..get_default_asset_definition_input()
+            }
+            .into_asset_definition(),
+            bind_name: false.to_some(),
+        }
+    }
+
+    fn query_binding(deps: &Deps, scope_spec_address: &str) -> ScopeSpecBindingResponse {
+        query_scope_spec_binding(deps, scope_spec_address)
+            .map(|binary| {
+                from_json::<ScopeSpecBindingResponse>(&binary)
+                    .expect("expected the result to deserialize correctly")
+            })
+            .expect("expected the query to succeed")
+    }
+}
@@ -0,0 +1,145 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+use crate::core::state::{count_storage_keys, STATE_V2};
+use crate::util::aliases::AssetResult;
+use crate::util::contract_helpers::check_debug_queries_enabled;
+
+/// A debugging query that counts the keys held in each of the contract's storage maps and
+/// serializes them as a [StorageKeyCountResponse](crate::core::types::storage_key_count_response::StorageKeyCountResponse).
+/// This query is rejected with an [Unauthorized](crate::core::error::ContractError::Unauthorized)
+/// error unless [debug_query_enabled](crate::core::state::StateV2::debug_query_enabled) is set in
+/// the contract's state.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+pub fn query_storage_key_count(deps: &Deps) -> AssetResult<Binary> {
+    check_debug_queries_enabled(&STATE_V2.load(deps.storage)?)?;
+    to_json_binary(&count_storage_keys(deps.storage))?.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::from_json;
+    use provwasm_mocks::mock_provenance_dependencies;
+    use provwasm_std::types::provenance::metadata::v1::ScopeRequest;
+
+    use crate::core::error::ContractError;
+    use crate::core::types::asset_definition::AssetDefinitionInputV3;
+    use crate::core::types::storage_key_count_response::StorageKeyCountResponse;
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::scope_utilities::mock_scope_response_with_spec_address;
+    use crate::testutil::test_constants::{
+        DEFAULT_SCOPE_SPEC_ADDRESS, DEFAULT_SECONDARY_ASSET_TYPE,
+    };
+    use crate::testutil::test_utilities::{
+        get_default_asset_definition_input, get_default_scope, setup_no_attribute_response,
+        setup_test_suite, test_instantiate_success, InstArgs, MockOwnedDeps,
+    };
+    use crate::testutil::verify_asset_helpers::{test_verify_asset, TestVerifyAsset};
+    use crate::util::traits::OptionExtensions;
+
+    use super::query_storage_key_count;
+
+    #[test]
+    fn test_query_rejected_when_debug_queries_disabled() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = query_storage_key_count(&deps.as_ref())
+            .expect_err("the query should be rejected when debug queries are disabled");
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected an unauthorized error, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_query_counts_instantiated_keys() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &get_debug_inst_args());
+        assert_eq!(
+            StorageKeyCountResponse {
+                definitions: 2,
+                fee_payment_details: 0,
+                indexes: 1,
+                denial_timestamps: 0,
+                stats: 0,
+                total: 3,
+            },
+            query_counts(&deps),
+            "only the asset definitions and the scope spec binding should be counted after instantiation",
+        );
+    }
+
+    #[test]
+    fn test_query_counts_keys_after_onboarding_and_verification() {
+        let mut deps = mock_provenance_dependencies();
+        let args = get_debug_inst_args();
+        setup_test_suite(&mut deps, &args);
+        setup_no_attribute_response(&mut deps, None);
+        // The default asset definition is bound to a scope specification, so the scope must use it
+        ScopeRequest::mock_response(
+            &mut deps.querier,
+            mock_scope_response_with_spec_address(get_default_scope(), DEFAULT_SCOPE_SPEC_ADDRESS),
+        );
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the asset onboard to succeed");
+        assert_eq!(
+            StorageKeyCountResponse {
+                definitions: 2,
+                fee_payment_details: 1,
+                indexes: 5,
+                denial_timestamps: 0,
+                stats: 2,
+                total: 10,
+            },
+            query_counts(&deps),
+            "the fee payment detail, verifier status, requestor, access route owner and last modified block index entries, onboard count and verifier statistics should be counted after onboarding",
+        );
+        test_verify_asset(
+            &mut deps,
+            &args.env,
+            TestVerifyAsset::default_with_success(false),
+        )
+        .expect("expected the asset verification to succeed");
+        assert_eq!(
+            StorageKeyCountResponse {
+                definitions: 2,
+                fee_payment_details: 0,
+                indexes: 6,
+                denial_timestamps: 1,
+                stats: 2,
+                total: 11,
+            },
+            query_counts(&deps),
+            "the fee payment detail should be replaced by a denial timestamp, and the verifier's access routes indexed, after the asset is denied",
+        );
+    }
+
+    fn get_debug_inst_args() -> InstArgs {
+        InstArgs {
+            is_test: true,
+            asset_definitions: vec![
+                AssetDefinitionInputV3 {
+                    scope_spec_address: DEFAULT_SCOPE_SPEC_ADDRESS.to_string().to_some(),
+                    ..get_default_asset_definition_input()
+                },
+                AssetDefinitionInputV3 {
+                    asset_type: DEFAULT_SECONDARY_ASSET_TYPE.to_string(),
+                    ..get_default_asset_definition_input()
+                },
+            ],
+            ..InstArgs::default()
+        }
+    }
+
+    fn query_counts(deps: &MockOwnedDeps) -> StorageKeyCountResponse {
+        let binary = query_storage_key_count(&deps.as_ref())
+            .expect("expected the query to execute successfully");
+        from_json::<StorageKeyCountResponse>(&binary)
+            .expect("expected the result to deserialize correctly")
+    }
+}
@@ -0,0 +1,94 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+use crate::core::state::load_asset_definition_by_type_v3;
+use crate::core::types::verifier_count_response::VerifierCountResponse;
+use crate::util::aliases::AssetResult;
+
+/// Counts the [VerifierDetailV2s](crate::core::types::verifier_detail::VerifierDetailV2) registered
+/// to an asset definition and serializes the result as a [VerifierCountResponse](crate::core::types::verifier_count_response::VerifierCountResponse).
+/// Returns an error if no asset definition exists for the given asset type.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `asset_type` The asset type of the [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
+/// for which to count verifiers.
+pub fn query_verifier_count(deps: &Deps, asset_type: &str) -> AssetResult<Binary> {
+    let asset_definition = load_asset_definition_by_type_v3(deps.storage, asset_type)?;
+    to_json_binary(&VerifierCountResponse::new(
+        asset_definition.asset_type,
+        asset_definition.verifiers.len() as u32,
+    ))?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, Deps};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::error::ContractError;
+    use crate::core::types::verifier_count_response::VerifierCountResponse;
+    use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::execute::add_asset_verifier::{add_asset_verifier, AddAssetVerifierV1};
+    use crate::testutil::test_constants::{DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE};
+    use crate::testutil::test_utilities::{
+        empty_mock_info, get_default_verifier_detail, test_instantiate_success, InstArgs,
+    };
+
+    use super::query_verifier_count;
+
+    #[test]
+    fn test_count_tracks_added_verifiers() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let response = query_count(&deps.as_ref(), DEFAULT_ASSET_TYPE)
+            .expect("expected the query to succeed for the default asset type");
+        assert_eq!(
+            VerifierCountResponse::new(DEFAULT_ASSET_TYPE, 1),
+            response,
+            "expected the default verifier to be counted after instantiation",
+        );
+        add_asset_verifier(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            AddAssetVerifierV1::new(
+                DEFAULT_ASSET_TYPE,
+                VerifierDetailV2 {
+                    address: "tp1r2x0kz8ntxtgckfm7zxmwqt9ft03a4gnk7nw8z".to_string(),
+                    ..get_default_verifier_detail()
+                },
+            ),
+        )
+        .expect("expected the new verifier to be added");
+        assert_eq!(
+            2,
+            query_count(&deps.as_ref(), DEFAULT_ASSET_TYPE)
+                .expect("expected the query to succeed after adding a verifier")
+                .count,
+            "expected the count to increase after a verifier is added",
+        );
+    }
+
+    #[test]
+    fn test_count_for_missing_asset_type_is_rejected() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let error = query_count(&deps.as_ref(), "not-a-real-type")
+            .expect_err("expected the query to fail for an unknown asset type");
+        assert!(
+            error.is_not_found(),
+            "unexpected error encountered: {:?}",
+            error,
+        );
+    }
+
+    fn query_count(deps: &Deps, asset_type: &str) -> Result<VerifierCountResponse, ContractError> {
+        query_verifier_count(deps, asset_type).map(|binary| {
+            from_json::<VerifierCountResponse>(&binary)
+                .expect("expected the result to deserialize correctly")
+        })
+    }
+}
@@ -0,0 +1,153 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+use crate::{
+    core::{
+        state::count_assets_by_verifier_and_status,
+        types::{
+            asset_onboarding_status::AssetOnboardingStatus,
+            verifier_pending_count_response::VerifierPendingCountResponse,
+        },
+    },
+    util::aliases::AssetResult,
+};
+
+/// Counts the assets currently awaiting verification by a specific verifier and serializes the
+/// result as a [VerifierPendingCountResponse](crate::core::types::verifier_pending_count_response::VerifierPendingCountResponse).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `verifier_address` The bech32 address of the verifier for which to count pending assets.
+/// * `asset_type` An optional asset type filter.  If omitted, pending assets of all types are
+/// counted.
+pub fn query_verifier_pending_count(
+    deps: &Deps,
+    verifier_address: &str,
+    asset_type: Option<&str>,
+) -> AssetResult<Binary> {
+    let count = count_assets_by_verifier_and_status(
+        deps.storage,
+        verifier_address,
+        &AssetOnboardingStatus::Pending,
+        asset_type,
+    )?;
+    to_json_binary(&VerifierPendingCountResponse::new(count))?.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::from_json;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+    use crate::core::types::verifier_pending_count_response::VerifierPendingCountResponse;
+    use crate::testutil::attribute_utilities::{
+        build_scope_attribute, mock_attribute_response_for_scope,
+    };
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS, DEFAULT_SECONDARY_ASSET_TYPE,
+        DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        setup_no_attribute_response, setup_test_suite, InstArgs, MockOwnedDeps,
+    };
+    use crate::testutil::verify_asset_helpers::{test_verify_asset, TestVerifyAsset};
+
+    use super::query_verifier_pending_count;
+
+    #[test]
+    fn test_no_pending_assets_produces_zero_count() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        assert_eq!(
+            0,
+            query_count(&deps, None),
+            "no assets should be pending before any onboarding occurs",
+        );
+    }
+
+    #[test]
+    fn test_pending_count_filters_by_asset_type() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the asset onboard to succeed");
+        assert_eq!(
+            1,
+            query_count(&deps, None),
+            "the onboarded asset should be counted when no asset type filter is provided",
+        );
+        assert_eq!(
+            1,
+            query_count(&deps, Some(DEFAULT_ASSET_TYPE)),
+            "the onboarded asset should be counted when its asset type is used as a filter",
+        );
+        assert_eq!(
+            0,
+            query_count(&deps, Some(DEFAULT_SECONDARY_ASSET_TYPE)),
+            "the onboarded asset should not be counted when a different asset type is used as a filter",
+        );
+    }
+
+    #[test]
+    fn test_pending_count_across_onboard_and_verify_cycles() {
+        let mut deps = mock_provenance_dependencies();
+        let args = InstArgs::default();
+        setup_test_suite(&mut deps, &args);
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the asset onboard to succeed");
+        assert_eq!(
+            1,
+            query_count(&deps, None),
+            "the asset should be pending after onboarding",
+        );
+        test_verify_asset(
+            &mut deps,
+            &args.env,
+            TestVerifyAsset::default_with_success(false),
+        )
+        .expect("expected the asset verification to succeed");
+        assert_eq!(
+            0,
+            query_count(&deps, None),
+            "the asset should no longer be pending after it is denied",
+        );
+        mock_attribute_response_for_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![build_scope_attribute(
+                DEFAULT_SCOPE_ADDRESS,
+                DEFAULT_ASSET_TYPE,
+                AssetOnboardingStatus::Denied,
+            )],
+        );
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the asset onboard retry to succeed");
+        assert_eq!(
+            1,
+            query_count(&deps, None),
+            "the asset should be pending again after an onboarding retry",
+        );
+        test_verify_asset(&mut deps, &args.env, TestVerifyAsset::default())
+            .expect("expected the asset verification to succeed");
+        assert_eq!(
+            0,
+            query_count(&deps, None),
+            "the asset should no longer be pending after it is approved",
+        );
+    }
+
+    fn query_count(deps: &MockOwnedDeps, asset_type: Option<&str>) -> u64 {
+        let binary =
+            query_verifier_pending_count(&deps.as_ref(), DEFAULT_VERIFIER_ADDRESS, asset_type)
+                .expect("expected the query to execute successfully");
+        from_json::<VerifierPendingCountResponse>(&binary)
+            .expect("expected the result to deserialize correctly")
+            .count
+    }
+}
@@ -0,0 +1,289 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+use crate::core::error::ContractError;
+use crate::core::state::{list_verifier_stats_page, load_verifier_stats};
+use crate::util::aliases::AssetResult;
+use crate::util::constants::MAX_VERIFIER_STATISTICS_PER_PAGE;
+
+/// A query that fetches the [VerifierStats](crate::core::types::verifier_stats::VerifierStats) of a
+/// single verifier for a single asset type.  A verifier that has never been assigned an onboarding
+/// of the asset type produces statistics with every count set to zero.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `asset_type` The asset type for which to fetch the verifier's statistics.
+/// * `verifier_address` The bech32 address of the verifier for which to fetch statistics.
+pub fn query_verifier_statistics(
+    deps: &Deps,
+    asset_type: &str,
+    verifier_address: &str,
+) -> AssetResult<Binary> {
+    to_json_binary(&load_verifier_stats(
+        deps.storage,
+        asset_type,
+        verifier_address,
+    )?)?
+    .to_ok()
+}
+
+/// A query that fetches a single page of [VerifierStats](crate::core::types::verifier_stats::VerifierStats),
+/// sorted in ascending order by asset type and then by verifier address, and serializes them as a
+/// vector.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `asset_type` An optional asset type filter.  If provided, only statistics for this asset type
+/// are returned.
+/// * `start_after` The asset type and verifier address of the final statistics in a previous page.
+/// When omitted, the first page is returned.
+/// * `limit` The maximum number of statistics to include in the page.  Must be greater than zero.
+/// Values greater than [MAX_VERIFIER_STATISTICS_PER_PAGE](crate::util::constants::MAX_VERIFIER_STATISTICS_PER_PAGE)
+/// are reduced to that limit, which is also used when no value is provided.
+pub fn query_all_verifier_statistics(
+    deps: &Deps,
+    asset_type: Option<&str>,
+    start_after: Option<(String, String)>,
+    limit: Option<u32>,
+) -> AssetResult<Binary> {
+    let limit = limit.unwrap_or(MAX_VERIFIER_STATISTICS_PER_PAGE);
+    if limit == 0 {
+        return ContractError::InvalidMessageFields {
+            message_type: "QueryMsg::QueryAllVerifierStatistics".to_string(),
+            invalid_fields: vec!["limit: must be greater than zero".to_string()],
+        }
+        .to_err();
+    }
+    to_json_binary(&list_verifier_stats_page(
+        deps.storage,
+        asset_type,
+        start_after,
+        limit.min(MAX_VERIFIER_STATISTICS_PER_PAGE),
+    )?)?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::from_json;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::core::state::increment_verifier_pending;
+    use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+    use crate::core::types::verifier_stats::VerifierStats;
+    use crate::testutil::attribute_utilities::{
+        build_scope_attribute, mock_attribute_response_for_scope,
+    };
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_ADDRESS, DEFAULT_SECONDARY_ASSET_TYPE,
+        DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        setup_no_attribute_response, setup_test_suite, InstArgs, MockOwnedDeps,
+    };
+    use crate::testutil::verify_asset_helpers::{test_verify_asset, TestVerifyAsset};
+    use crate::util::traits::OptionExtensions;
+
+    use super::{query_all_verifier_statistics, query_verifier_statistics};
+
+    #[test]
+    fn test_unknown_verifier_produces_zeroed_statistics() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        assert_eq!(
+            VerifierStats::new(DEFAULT_VERIFIER_ADDRESS, DEFAULT_ASSET_TYPE),
+            query_stats(&deps),
+            "a verifier without any onboardings should produce zeroed statistics",
+        );
+    }
+
+    #[test]
+    fn test_statistics_across_multiple_classification_cycles() {
+        let mut deps = mock_provenance_dependencies();
+        let args = InstArgs::default();
+        setup_test_suite(&mut deps, &args);
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the asset onboard to succeed");
+        assert_counts(&deps, 0, 0, 1, "after the first onboard");
+        test_verify_asset(
+            &mut deps,
+            &args.env,
+            TestVerifyAsset::default_with_success(false),
+        )
+        .expect("expected the asset denial to succeed");
+        assert_counts(&deps, 0, 1, 0, "after the asset is denied");
+        mock_attribute_response_for_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![build_scope_attribute(
+                DEFAULT_SCOPE_ADDRESS,
+                DEFAULT_ASSET_TYPE,
+                AssetOnboardingStatus::Denied,
+            )],
+        );
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the asset onboard retry to succeed");
+        assert_counts(&deps, 0, 1, 1, "after the onboarding retry");
+        test_verify_asset(&mut deps, &args.env, TestVerifyAsset::default())
+            .expect("expected the asset approval to succeed");
+        assert_counts(&deps, 1, 1, 0, "after the asset is approved");
+        assert_eq!(
+            vec![query_stats(&deps)],
+            query_all(&deps, None, None, None),
+            "the verifier's statistics should be the only listed entry",
+        );
+    }
+
+    #[test]
+    fn test_all_statistics_filter_by_asset_type() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        add_pending(&mut deps, DEFAULT_ASSET_TYPE, "verifier_a");
+        add_pending(&mut deps, DEFAULT_SECONDARY_ASSET_TYPE, "verifier_a");
+        add_pending(&mut deps, DEFAULT_SECONDARY_ASSET_TYPE, "verifier_b");
+        assert_eq!(
+            vec![
+                (
+                    DEFAULT_SECONDARY_ASSET_TYPE.to_string(),
+                    "verifier_a".to_string()
+                ),
+                (
+                    DEFAULT_SECONDARY_ASSET_TYPE.to_string(),
+                    "verifier_b".to_string()
+                ),
+            ],
+            query_all_keys(&deps, DEFAULT_SECONDARY_ASSET_TYPE.to_some(), None, None),
+            "only the statistics for the filtered asset type should be returned",
+        );
+        assert_eq!(
+            3,
+            query_all(&deps, None, None, None).len(),
+            "statistics for every asset type should be returned without a filter",
+        );
+    }
+
+    #[test]
+    fn test_all_statistics_pagination() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        add_pending(&mut deps, DEFAULT_ASSET_TYPE, "verifier_a");
+        add_pending(&mut deps, DEFAULT_ASSET_TYPE, "verifier_b");
+        add_pending(&mut deps, DEFAULT_SECONDARY_ASSET_TYPE, "verifier_a");
+        let first_page = query_all_keys(&deps, None, None, 2.to_some());
+        assert_eq!(
+            vec![
+                (DEFAULT_ASSET_TYPE.to_string(), "verifier_a".to_string()),
+                (DEFAULT_ASSET_TYPE.to_string(), "verifier_b".to_string()),
+            ],
+            first_page,
+            "the first page should be limited to the requested size",
+        );
+        assert_eq!(
+            vec![(
+                DEFAULT_SECONDARY_ASSET_TYPE.to_string(),
+                "verifier_a".to_string()
+            )],
+            query_all_keys(&deps, None, first_page.last().cloned(), 2.to_some()),
+            "the second page should begin after the final entry of the first page",
+        );
+        assert_eq!(
+            vec![(DEFAULT_ASSET_TYPE.to_string(), "verifier_b".to_string())],
+            query_all_keys(
+                &deps,
+                DEFAULT_ASSET_TYPE.to_some(),
+                (DEFAULT_ASSET_TYPE.to_string(), "verifier_a".to_string()).to_some(),
+                None,
+            ),
+            "a filtered page should begin after the provided verifier address",
+        );
+        assert!(
+            query_all_keys(
+                &deps,
+                DEFAULT_ASSET_TYPE.to_some(),
+                (
+                    DEFAULT_SECONDARY_ASSET_TYPE.to_string(),
+                    "verifier_a".to_string()
+                )
+                    .to_some(),
+                None,
+            )
+            .is_empty(),
+            "a filtered page should be empty when starting after a later asset type",
+        );
+    }
+
+    #[test]
+    fn test_all_statistics_zero_limit_is_rejected() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        let error = query_all_verifier_statistics(&deps.as_ref(), None, None, 0.to_some())
+            .expect_err("expected a zero limit to be rejected");
+        assert!(
+            error.is_invalid_message_fields(),
+            "expected an invalid message fields error to be returned, but got: {:?}",
+            error,
+        );
+    }
+
+    fn add_pending(deps: &mut MockOwnedDeps, asset_type: &str, verifier_address: &str) {
+        increment_verifier_pending(deps.as_mut().storage, asset_type, verifier_address)
+            .expect("recording a pending onboarding should succeed");
+    }
+
+    fn assert_counts(
+        deps: &MockOwnedDeps,
+        total_approved: u64,
+        total_denied: u64,
+        total_pending: u64,
+        stage: &str,
+    ) {
+        assert_eq!(
+            VerifierStats {
+                total_approved,
+                total_denied,
+                total_pending,
+                ..VerifierStats::new(DEFAULT_VERIFIER_ADDRESS, DEFAULT_ASSET_TYPE)
+            },
+            query_stats(deps),
+            "the verifier's statistics should be correct {}",
+            stage,
+        );
+    }
+
+    fn query_stats(deps: &MockOwnedDeps) -> VerifierStats {
+        let binary =
+            query_verifier_statistics(&deps.as_ref(), DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS)
+                .expect("expected the query to execute successfully");
+        from_json::<VerifierStats>(&binary).expect("expected the result to deserialize correctly")
+    }
+
+    fn query_all(
+        deps: &MockOwnedDeps,
+        asset_type: Option<&str>,
+        start_after: Option<(String, String)>,
+        limit: Option<u32>,
+    ) -> Vec<VerifierStats> {
+        let binary = query_all_verifier_statistics(&deps.as_ref(), asset_type, start_after, limit)
+            .expect("expected the query to execute successfully");
+        from_json::<Vec<VerifierStats>>(&binary)
+            .expect("expected the result to deserialize correctly")
+    }
+
+    fn query_all_keys(
+        deps: &MockOwnedDeps,
+        asset_type: Option<&str>,
+        start_after: Option<(String, String)>,
+        limit: Option<u32>,
+    ) -> Vec<(String, String)> {
+        query_all(deps, asset_type, start_after, limit)
+            .into_iter()
+            .map(|stats| (stats.asset_type, stats.verifier_address))
+            .collect()
+    }
+}
@@ -1,4 +1,8 @@
+use crate::core::state::count_assets_by_verifier_and_status;
+use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+use crate::core::types::fee_payment_detail::FeePaymentDetail;
 use crate::core::types::verifier_detail::VerifierDetailV2;
+use crate::service::deps_manager::DepsManager;
 use crate::{
     core::types::{access_route::AccessRoute, asset_scope_attribute::AssetScopeAttribute},
     util::aliases::AssetResult,
@@ -39,6 +43,27 @@ pub trait AssetMetaRepository {
         is_retry: bool,
     ) -> AssetResult<()>;
 
+    /// Calculates the [FeePaymentDetail](crate::core::types::fee_payment_detail::FeePaymentDetail)
+    /// that [onboarding](self::AssetMetaRepository::onboard_asset) the asset with the given values
+    /// would produce, without storing it or generating any messages.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+    /// details, as well as blockchain information at the time of the transaction.
+    /// * `attribute` The scope attribute to be appended to the Provenance Metadata Scope as a result
+    /// of a successful onboarding process.
+    /// * `verifier_detail` The verifier chosen by the onboarding account.
+    /// * `is_retry` Indicates that this onboarding action was attempted before, and the scope has
+    /// an existing scope attribute with a failed verification on it.
+    fn calculate_fee_payment_detail(
+        &self,
+        env: &Env,
+        attribute: &AssetScopeAttribute,
+        verifier_detail: &VerifierDetailV2,
+        is_retry: bool,
+    ) -> AssetResult<FeePaymentDetail>;
+
     /// Alters the internal values of the [AssetScopeAttribute](crate::core::types::asset_scope_attribute::AssetScopeAttribute)
     /// currently attached to a Provenance Metadata Scope with the provided values.  This function
     /// will fail if no attribute exists on the target scope.
@@ -130,4 +155,31 @@ pub trait AssetMetaRepository {
         verification_message: Option<S>,
         access_routes: Vec<AccessRoute>,
     ) -> AssetResult<AssetScopeAttribute>;
+
+    /// Counts the assets currently awaiting verification by a specific verifier, using the
+    /// contract's internal verifier and onboarding status index.  The default implementation reads
+    /// the index directly from storage, so any repository that can provide dependencies supports it.
+    ///
+    /// # Parameters
+    ///
+    /// * `verifier_address` The bech32 address of the verifier for which to count pending assets.
+    /// * `asset_type` An optional asset type filter.  If omitted, pending assets of all types are
+    /// counted.
+    fn count_pending_for_verifier<'a>(
+        &self,
+        verifier_address: &str,
+        asset_type: Option<&str>,
+    ) -> AssetResult<u64>
+    where
+        Self: DepsManager<'a>,
+    {
+        self.try_use_deps(|deps| {
+            count_assets_by_verifier_and_status(
+                deps.storage,
+                verifier_address,
+                &AssetOnboardingStatus::Pending,
+                asset_type,
+            )
+        })?
+    }
 }
@@ -1,11 +1,16 @@
 use std::collections::HashSet;
 
-use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, DepsMut, Env, Uint128};
+use cosmwasm_std::{Coin, CosmosMsg, DepsMut, Env, Uint128};
 use provwasm_std::types::provenance::attribute::v1::AttributeType;
 use result_extensions::ResultExtensions;
 
+use crate::core::error::ContractError;
 use crate::core::state::{
-    delete_fee_payment_detail, insert_fee_payment_detail, load_fee_payment_detail, STATE_V2,
+    delete_denial_timestamp, delete_fee_payment_detail, increment_verifier_pending,
+    insert_fee_payment_detail, load_fee_payment_detail, may_load_asset_definition_by_type_v3,
+    record_onboard_metrics, record_verification_metrics, record_verifier_decision,
+    set_denial_timestamp, update_access_route_owner_index, update_last_modified_block_index,
+    update_requestor_index, update_verifier_status_index, STATE_V2,
 };
 use crate::core::types::fee_payment_detail::FeePaymentDetail;
 use crate::core::types::verifier_detail::VerifierDetailV2;
@@ -13,6 +18,7 @@ use crate::query::query_asset_scope_attribute_by_asset_type::{
     may_query_scope_attribute_by_scope_address_and_asset_type,
     query_scope_attribute_by_scope_address_and_asset_type,
 };
+use crate::util::constants::NHASH;
 use crate::util::contract_helpers::assess_custom_fee;
 use crate::util::functions::update_attribute;
 use crate::{
@@ -63,6 +69,30 @@ impl<'a> AssetMetaService<'a> {
             messages: VecContainer::new(),
         }
     }
+
+    /// Constructs a new instance of this struct with its messages pre-populated.  Useful for
+    /// continuing to gather messages after they were produced by a different service.
+    ///
+    /// # Parameters
+    ///
+    /// * `deps` The cosmwasm deps that will be moved into a [DepsContainer](crate::util::deps_container::DepsContainer)
+    /// for future access.
+    /// * `initial_messages` Messages that will be held by the service before any others are added.
+    pub fn with_messages(deps: DepsMut<'a>, mut initial_messages: Vec<CosmosMsg>) -> Self {
+        let service = Self::new(deps);
+        service.messages.append(&mut initial_messages);
+        service
+    }
+
+    /// Appends all messages held by another service to the end of this service's messages.  The
+    /// other service's messages are left unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` The service from which to copy messages.
+    pub fn merge_messages_from(&self, other: &impl MessageGatheringService) {
+        self.append_messages(&other.get_messages());
+    }
 }
 impl<'a> AssetMetaRepository for AssetMetaService<'a> {
     fn has_asset<S1: Into<String>, S2: Into<String>>(
@@ -73,13 +103,13 @@ impl<'a> AssetMetaRepository for AssetMetaService<'a> {
         let scope_address: String = scope_address.into();
         let asset_type = asset_type.into();
         // check for asset attribute existence
-        self.use_deps(|d| {
+        self.try_use_deps(|d| {
             may_query_scope_attribute_by_scope_address_and_asset_type(
                 &d.as_ref(),
                 &scope_address,
                 &asset_type,
             )
-        })?
+        })??
         .is_some()
         .to_ok()
     }
@@ -95,9 +125,9 @@ impl<'a> AssetMetaRepository for AssetMetaService<'a> {
         // may change if an existing scope attribute on this asset has used a different asset type
         // from the same verifier address.
         let existing_scope_attributes = self
-            .use_deps(|deps| {
+            .try_use_deps(|deps| {
                 may_query_scope_attribute_by_scope_address(&deps.as_ref(), &attribute.scope_address)
-            })?
+            })??
             .unwrap_or_default();
         // generate attribute -> scope bind messages
         // On a retry, update the existing attribute with the given values
@@ -107,7 +137,7 @@ impl<'a> AssetMetaRepository for AssetMetaService<'a> {
             // On a first time execution, simply add the attribute to the scope - it's already been
             // verified that the attribute does not yet exist
             let contract_base_name = self
-                .use_deps(|d| STATE_V2.load(d.storage))?
+                .try_use_deps(|d| STATE_V2.load(d.storage))??
                 .base_contract_name;
             self.add_message(get_add_attribute_to_scope_msg(
                 attribute,
@@ -116,24 +146,11 @@ impl<'a> AssetMetaRepository for AssetMetaService<'a> {
             )?);
         }
 
-        // Retry fees should only be used when an asset is classified as a specific asset type with
-        // a specific verifier and rejected.  After rejection, the retry fee amount should be used
-        // in place of normal onboarding costs ONLY if the asset is onboarded as the same type of
-        // asset with the same verifier.  Without this check, an asset could fail onboarding with
-        // one verifier, and then take advantage of a retry fee reduction by using a wholly
-        // different verifier.
-        let calculate_retry_fees = is_retry
-            && existing_scope_attributes
-                .iter()
-                .find(|attr| attr.asset_type == attribute.asset_type)
-                .map(|attr| attr.verifier_address.as_str() == attribute.verifier_address.as_str())
-                .unwrap_or(false);
-
-        let payment_detail = FeePaymentDetail::new(
-            &attribute.scope_address,
+        let payment_detail = build_fee_payment_detail(
+            env,
+            attribute,
             verifier_detail,
-            calculate_retry_fees,
-            &attribute.asset_type,
+            is_retry,
             &existing_scope_attributes,
         )?;
         // No need to assess a fee from the onboarding user if there is no requested fee
@@ -153,37 +170,123 @@ impl<'a> AssetMetaRepository for AssetMetaService<'a> {
                 Some(env.contract.address.to_owned()),
             )?]);
         }
-        self.use_deps(|deps| {
+        self.try_use_deps(|deps| {
             insert_fee_payment_detail(deps.storage, &payment_detail, &attribute.asset_type)
-        })?;
+        })??;
+        // Only fees charged in nhash contribute to the collected fee metrics
+        let fees_collected_nhash = if verifier_detail.onboarding_denom == NHASH {
+            payment_detail.sum_costs()
+        } else {
+            0
+        };
+        self.try_use_deps(|deps| {
+            record_onboard_metrics(
+                deps.storage,
+                &attribute.asset_type,
+                fees_collected_nhash,
+                is_retry,
+            )
+        })??;
+        // Retries are only permitted after a denial, so every onboarding adds a new pending asset
+        // for the verifier
+        self.try_use_deps(|deps| {
+            increment_verifier_pending(
+                deps.storage,
+                &attribute.asset_type,
+                attribute.verifier_address.as_str(),
+            )
+        })??;
+        // On a retry, the previously-denied attribute's index entry must be replaced with the new
+        // pending entry
+        let previous_attribute = existing_scope_attributes
+            .iter()
+            .find(|attr| is_retry && attr.asset_type == attribute.asset_type);
+        self.try_use_deps(|deps| {
+            update_verifier_status_index(deps.storage, previous_attribute, attribute)
+        })??;
+        self.try_use_deps(|deps| {
+            update_requestor_index(deps.storage, previous_attribute, attribute)
+        })??;
+        self.try_use_deps(|deps| {
+            update_access_route_owner_index(deps.storage, previous_attribute, attribute)
+        })??;
+        self.try_use_deps(|deps| {
+            update_last_modified_block_index(deps.storage, previous_attribute, attribute)
+        })??;
         Ok(())
     }
 
+    fn calculate_fee_payment_detail(
+        &self,
+        env: &Env,
+        attribute: &AssetScopeAttribute,
+        verifier_detail: &VerifierDetailV2,
+        is_retry: bool,
+    ) -> AssetResult<FeePaymentDetail> {
+        // Fetch any existing scope attributes for use in calculating the onboarding cost, which
+        // may change if an existing scope attribute on this asset has used a different asset type
+        // from the same verifier address.
+        let existing_scope_attributes = self
+            .try_use_deps(|deps| {
+                may_query_scope_attribute_by_scope_address(&deps.as_ref(), &attribute.scope_address)
+            })??
+            .unwrap_or_default();
+        build_fee_payment_detail(
+            env,
+            attribute,
+            verifier_detail,
+            is_retry,
+            &existing_scope_attributes,
+        )
+    }
+
     fn update_attribute(
         &self,
         env: &Env,
         updated_attribute: &AssetScopeAttribute,
     ) -> AssetResult<()> {
         let contract_base_name = self
-            .use_deps(|d| STATE_V2.load(d.storage))?
+            .try_use_deps(|d| STATE_V2.load(d.storage))??
             .base_contract_name;
         let original_attribute = self.get_asset_by_asset_type(
             &updated_attribute.scope_address,
             &updated_attribute.asset_type,
         )?;
+        // Every update made by the contract marks the attribute as modified in the current block
+        let updated_attribute = &AssetScopeAttribute {
+            last_modified_block: env.block.height,
+            ..updated_attribute.to_owned()
+        };
+        self.try_use_deps(|deps| {
+            update_last_modified_block_index(
+                deps.storage,
+                Some(&original_attribute),
+                updated_attribute,
+            )
+        })??;
+        // Keep the access route owner index in sync with any access definition changes
+        self.try_use_deps(|deps| {
+            update_access_route_owner_index(
+                deps.storage,
+                Some(&original_attribute),
+                updated_attribute,
+            )
+        })??;
+        let attribute_name =
+            generate_asset_attribute_name(&original_attribute.asset_type, &contract_base_name);
         self.add_message(update_attribute(
             // address: Target address - the scope with the attribute on it
             bech32_string_to_addr(&original_attribute.scope_address)?,
             // contract address
             env.contract.address.to_owned(),
             // name: Attribute name - use the same value as before
-            generate_asset_attribute_name(&original_attribute.asset_type, &contract_base_name),
+            &attribute_name,
             // original_value: The unmodified original attribute
-            to_json_binary(&original_attribute)?,
+            original_attribute.to_binary(&attribute_name)?,
             // original_value_type
             AttributeType::Json,
             // update_value: The attribute with changes
-            to_json_binary(&updated_attribute)?,
+            updated_attribute.to_binary(&attribute_name)?,
             // update_value_type: Maintain Json typing. it's awesome that this can change between updates,
             // but this code doesn't want that
             AttributeType::Json,
@@ -197,9 +300,9 @@ impl<'a> AssetMetaRepository for AssetMetaService<'a> {
     ) -> AssetResult<Vec<AssetScopeAttribute>> {
         let scope_address_string: String = scope_address.into();
         // try to fetch asset from attribute meta, if found also fetch scope attribute and reconstruct AssetMeta from relevant pieces
-        self.use_deps(|d| {
+        self.try_use_deps(|d| {
             query_scope_attribute_by_scope_address(&d.as_ref(), &scope_address_string)
-        })
+        })?
     }
 
     fn try_get_asset<S1: Into<String>>(
@@ -207,9 +310,9 @@ impl<'a> AssetMetaRepository for AssetMetaService<'a> {
         scope_address: S1,
     ) -> AssetResult<Option<Vec<AssetScopeAttribute>>> {
         let scope_address_string: String = scope_address.into();
-        self.use_deps(|d| {
+        self.try_use_deps(|d| {
             may_query_scope_attribute_by_scope_address(&d.as_ref(), &scope_address_string)
-        })
+        })?
     }
 
     fn get_asset_by_asset_type<S1: Into<String>, S2: Into<String>>(
@@ -220,13 +323,13 @@ impl<'a> AssetMetaRepository for AssetMetaService<'a> {
         let scope_address = scope_address.into();
         let asset_type = asset_type.into();
         // try to fetch asset from attribute meta, if found also fetch scope attribute and reconstruct AssetMeta from relevant pieces
-        self.use_deps(|d| {
+        self.try_use_deps(|d| {
             query_scope_attribute_by_scope_address_and_asset_type(
                 &d.as_ref(),
                 &scope_address,
                 &asset_type,
             )
-        })
+        })?
     }
 
     fn try_get_asset_by_asset_type<S1: Into<String>, S2: Into<String>>(
@@ -236,13 +339,13 @@ impl<'a> AssetMetaRepository for AssetMetaService<'a> {
     ) -> AssetResult<Option<AssetScopeAttribute>> {
         let scope_address_string: String = scope_address.into();
         let asset_type_string: String = asset_type.into();
-        self.use_deps(|d| {
+        self.try_use_deps(|d| {
             may_query_scope_attribute_by_scope_address_and_asset_type(
                 &d.as_ref(),
                 &scope_address_string,
                 &asset_type_string,
             )
-        })
+        })?
     }
 
     fn verify_asset<S: Into<String>>(
@@ -253,6 +356,7 @@ impl<'a> AssetMetaRepository for AssetMetaService<'a> {
         verification_message: Option<S>,
         access_routes: Vec<AccessRoute>,
     ) -> AssetResult<AssetScopeAttribute> {
+        let previous_attribute = scope_attribute.clone();
         let message = verification_message.map(|m| m.into()).unwrap_or_else(|| {
             match success {
                 true => "verification successful",
@@ -269,10 +373,35 @@ impl<'a> AssetMetaRepository for AssetMetaService<'a> {
             true => AssetOnboardingStatus::Approved,
             false => AssetOnboardingStatus::Denied,
         };
+        // the verification is no longer pending, so it can no longer expire
+        scope_attribute.pending_expiry_block = None;
+        // denials by verifiers with an appeal window can be disputed by the requestor until the
+        // window closes.  a verifier that has since been removed from the asset definition allows
+        // no appeal
+        scope_attribute.appeal_deadline_block = if success {
+            None
+        } else {
+            self.try_use_deps(|deps| {
+                may_load_asset_definition_by_type_v3(deps.storage, &scope_attribute.asset_type)
+            })??
+            .and_then(|definition| {
+                definition
+                    .get_verifier_detail_opt(scope_attribute.verifier_address.as_str())
+                    .and_then(|verifier| verifier.appeal_window_blocks)
+            })
+            .map(|window_blocks| env.block.height.saturating_add(window_blocks))
+        };
 
         let verifier_address = scope_attribute.verifier_address.as_str();
 
         let filtered_access_routes = filter_valid_access_routes(access_routes);
+        let max_access_routes = self
+            .try_use_deps(|d| STATE_V2.load(d.storage))??
+            .max_access_routes_per_owner;
+        let access_route_limit_error = || ContractError::AccessRouteLimitExceeded {
+            owner: verifier_address.to_string(),
+            limit: max_access_routes,
+        };
 
         // check for existing verifier-linked access route collection
         if let Some(access_definition) = scope_attribute
@@ -291,6 +420,13 @@ impl<'a> AssetMetaRepository for AssetMetaService<'a> {
             .cloned()
             .collect::<Vec<AccessRoute>>();
             distinct_routes.sort();
+            // a lowered limit never truncates the verifier's existing routes, but new routes can
+            // only be merged in while the result stays within the limit
+            if distinct_routes.len() > access_definition.access_routes.len()
+                && distinct_routes.len() > max_access_routes as usize
+            {
+                return access_route_limit_error().to_err();
+            }
 
             let mut new_access_definitions = scope_attribute
                 .access_definitions
@@ -306,24 +442,28 @@ impl<'a> AssetMetaRepository for AssetMetaService<'a> {
 
             scope_attribute.access_definitions = new_access_definitions;
         } else if !filtered_access_routes.is_empty() {
+            if filtered_access_routes.len() > max_access_routes as usize {
+                return access_route_limit_error().to_err();
+            }
             scope_attribute.access_definitions.push(AccessDefinition {
                 owner_address: verifier_address.to_string(),
                 access_routes: filtered_access_routes,
                 definition_type: AccessDefinitionType::Verifier,
             });
         }
+        scope_attribute.last_modified_block = env.block.height;
         // Remove the old scope attribute and append a new one that overwrites existing data
         // with the changes made to the attribute
         self.update_attribute(env, &scope_attribute)?;
 
         // Retrieve fee breakdown and use it to emit message fees
-        let payment_detail = self.use_deps(|deps| {
+        let payment_detail = self.try_use_deps(|deps| {
             load_fee_payment_detail(
                 deps.storage,
                 &scope_attribute.scope_address,
                 &scope_attribute.asset_type,
             )
-        })?;
+        })??;
         // Pay the verifier detail fees after verification has successfully been completed
         let send_msgs = &payment_detail.to_bank_send_msgs()?;
         if !send_msgs.is_empty() {
@@ -333,13 +473,47 @@ impl<'a> AssetMetaRepository for AssetMetaService<'a> {
         // Remove the fee payment detail after it has been used for verification.
         // Stored fee payment amounts are no longer needed after the custom bank send messages have been
         // used, as it can easily become outdated in the future
-        self.use_deps(|deps| {
+        self.try_use_deps(|deps| {
             delete_fee_payment_detail(
                 deps.storage,
                 &scope_attribute.scope_address,
                 &scope_attribute.asset_type,
             )
-        })?;
+        })??;
+
+        self.try_use_deps(|deps| {
+            update_verifier_status_index(deps.storage, Some(&previous_attribute), &scope_attribute)
+        })??;
+
+        self.try_use_deps(|deps| record_verification_metrics(deps.storage, success))??;
+        self.try_use_deps(|deps| {
+            record_verifier_decision(
+                deps.storage,
+                &scope_attribute.asset_type,
+                scope_attribute.verifier_address.as_str(),
+                success,
+            )
+        })??;
+
+        // Track the time of denials to allow verifiers to enforce a cooldown before the asset can
+        // be onboarded again
+        self.try_use_deps(|deps| {
+            if success {
+                delete_denial_timestamp(
+                    deps.storage,
+                    &scope_attribute.scope_address,
+                    &scope_attribute.asset_type,
+                );
+                Ok(())
+            } else {
+                set_denial_timestamp(
+                    deps.storage,
+                    &scope_attribute.scope_address,
+                    &scope_attribute.asset_type,
+                    env.block.time.seconds(),
+                )
+            }
+        })??;
 
         scope_attribute.to_ok()
     }
@@ -352,6 +526,13 @@ impl<'a> DepsManager<'a> for AssetMetaService<'a> {
         self.deps.use_deps(deps_fn)
     }
 
+    fn try_use_deps<T, F>(&self, deps_fn: F) -> AssetResult<T>
+    where
+        F: FnMut(&mut DepsMut) -> T,
+    {
+        self.deps.try_use_deps(deps_fn)
+    }
+
     fn into_deps(self) -> DepsMut<'a> {
         self.deps.get()
     }
@@ -372,6 +553,44 @@ impl<'a> MessageGatheringService for AssetMetaService<'a> {
     fn clear_messages(&self) {
         self.messages.clear();
     }
+
+    fn message_count(&self) -> usize {
+        self.messages.len()
+    }
+}
+
+/// Calculates the [FeePaymentDetail](crate::core::types::fee_payment_detail::FeePaymentDetail)
+/// charged for onboarding an asset, using the scope attributes that are already attached to the
+/// asset's scope to determine whether retry fees apply.
+fn build_fee_payment_detail(
+    env: &Env,
+    attribute: &AssetScopeAttribute,
+    verifier_detail: &VerifierDetailV2,
+    is_retry: bool,
+    existing_scope_attributes: &[AssetScopeAttribute],
+) -> AssetResult<FeePaymentDetail> {
+    // Retry fees should only be used when an asset is classified as a specific asset type with
+    // a specific verifier and rejected.  After rejection, the retry fee amount should be used
+    // in place of normal onboarding costs ONLY if the asset is onboarded as the same type of
+    // asset with the same verifier.  Without this check, an asset could fail onboarding with
+    // one verifier, and then take advantage of a retry fee reduction by using a wholly
+    // different verifier.
+    let calculate_retry_fees = is_retry
+        && existing_scope_attributes
+            .iter()
+            .find(|attr| attr.asset_type == attribute.asset_type)
+            .map(|attr| attr.verifier_address.as_str() == attribute.verifier_address.as_str())
+            .unwrap_or(false);
+
+    FeePaymentDetail::new(
+        &attribute.scope_address,
+        verifier_detail,
+        calculate_retry_fees,
+        &attribute.asset_type,
+        existing_scope_attributes,
+        env.block.time.seconds(),
+        env.block.height,
+    )
 }
 
 #[cfg(test)]
@@ -380,6 +599,7 @@ mod tests {
     use crate::execute::update_asset_definition::{
         update_asset_definition, UpdateAssetDefinitionV1,
     };
+    use crate::testutil::attribute_utilities::mock_named_attribute_response_for_scope;
     use crate::testutil::test_constants::{
         DEFAULT_ADMIN_ADDRESS, DEFAULT_ONBOARDING_DENOM, DEFAULT_SECONDARY_ASSET_TYPE,
     };
@@ -387,8 +607,9 @@ mod tests {
         empty_mock_info, get_default_asset_definition, get_duped_fee_payment_detail,
         setup_no_attribute_response,
     };
+    use crate::util::constants::NHASH;
     use crate::util::functions::{
-        try_into_add_attribute_request, try_into_custom_fee_request,
+        assert_msg_type, bank_send, try_into_add_attribute_request, try_into_custom_fee_request,
         try_into_update_attribute_request,
     };
     use crate::{
@@ -397,6 +618,7 @@ mod tests {
             types::{
                 access_definition::{AccessDefinition, AccessDefinitionType},
                 access_route::AccessRoute,
+                asset_definition::AssetDefinitionInputV3,
                 asset_identifier::AssetIdentifier,
                 asset_onboarding_status::AssetOnboardingStatus,
                 asset_scope_attribute::AssetScopeAttribute,
@@ -421,14 +643,16 @@ mod tests {
             },
             verify_asset_helpers::{test_verify_asset, TestVerifyAsset},
         },
-        util::{functions::generate_asset_attribute_name, traits::OptionExtensions},
+        util::{
+            aliases::AssetResult, constants::format_nhash,
+            functions::generate_asset_attribute_name, traits::OptionExtensions,
+        },
     };
     use cosmwasm_std::testing::{mock_env, MOCK_CONTRACT_ADDR};
-    use cosmwasm_std::{from_json, to_json_vec, Addr, BankMsg, Coin, CosmosMsg, StdError};
+    use cosmwasm_std::{from_json, to_json_vec, Addr, BankMsg, Coin, CosmosMsg};
     use provwasm_mocks::mock_provenance_dependencies;
     use provwasm_std::types::provenance::attribute::v1::{
         Attribute, AttributeType, MsgAddAttributeRequest, MsgUpdateAttributeRequest,
-        QueryAttributeRequest, QueryAttributeResponse,
     };
     use provwasm_std::types::provenance::msgfees::v1::MsgAssessCustomMsgFeeRequest;
 
@@ -506,13 +730,12 @@ mod tests {
             )
             .unwrap();
 
-        let messages = repository.get_messages();
-
         assert_eq!(
             2,
-            messages.len(),
+            repository.message_count(),
             "add_asset should generate the correct number of messages"
         );
+        let messages = repository.get_messages();
         messages.iter().for_each(|msg| {
             if let Some(add_attribute_request) = try_into_add_attribute_request(msg) {
                 let MsgAddAttributeRequest {
@@ -526,7 +749,11 @@ mod tests {
                     "attribute name should match what is expected"
                 );
                 let deserialized: AssetScopeAttribute = from_json(value).unwrap();
-                let expected = get_default_asset_scope_attribute();
+                // The grant id is assigned by the onboarding route, not the repository
+                let expected = AssetScopeAttribute {
+                    os_gateway_grant_id: None,
+                    ..get_default_asset_scope_attribute()
+                };
                 assert_eq!(
                     expected, deserialized,
                     "attribute should contain proper values"
@@ -547,7 +774,8 @@ mod tests {
                 assert_eq!(
                     DEFAULT_ONBOARDING_COST.to_string(),
                     amount.expect("fee should have amount defined").amount,
-                    "double the onboarding cost should be charged to account for provenance fees",
+                    "double the onboarding cost ({}) should be charged to account for provenance fees",
+                    format_nhash(DEFAULT_ONBOARDING_COST),
                 );
                 assert_ne!(name, String::from(""), "a fee name should be provided");
                 assert_eq!(
@@ -675,6 +903,43 @@ mod tests {
         test_verification_result(None, false);
     }
 
+    #[test]
+    fn count_pending_for_verifier_reflects_onboard_and_verify() {
+        let mut deps = mock_provenance_dependencies();
+        let instantiate_args = InstArgs::default();
+        setup_test_suite(&mut deps, &instantiate_args);
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        let repository = AssetMetaService::new(deps.as_mut());
+        assert_eq!(
+            1,
+            repository
+                .count_pending_for_verifier(DEFAULT_VERIFIER_ADDRESS, None)
+                .expect("counting pending assets should succeed"),
+            "the onboarded asset should be counted as pending for its verifier",
+        );
+        assert_eq!(
+            0,
+            repository
+                .count_pending_for_verifier(DEFAULT_VERIFIER_ADDRESS, Some("other_type"))
+                .expect("counting pending assets should succeed"),
+            "no assets should be counted for an asset type that was not onboarded",
+        );
+        let attribute = repository
+            .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+            .expect("the attribute should load after onboarding");
+        repository
+            .verify_asset::<&str>(&instantiate_args.env, attribute, true, None, vec![])
+            .expect("verification should succeed");
+        assert_eq!(
+            0,
+            repository
+                .count_pending_for_verifier(DEFAULT_VERIFIER_ADDRESS, None)
+                .expect("counting pending assets should succeed"),
+            "the verified asset should no longer be counted as pending",
+        );
+    }
+
     #[test]
     fn test_into_deps() {
         let mut mock_deps = mock_provenance_dependencies();
@@ -690,43 +955,46 @@ mod tests {
     fn test_existing_verifier_detail_access_routes_merged() {
         let mut deps = mock_provenance_dependencies();
         // set up existing attribute with pre-existing access routes
-        QueryAttributeRequest::mock_response(
-            &mut deps.querier,
-            QueryAttributeResponse {
-                account: DEFAULT_SCOPE_ADDRESS.to_string(),
-                attributes: vec![Attribute {
-                    name: generate_asset_attribute_name(
-                        DEFAULT_ASSET_TYPE,
-                        DEFAULT_CONTRACT_BASE_NAME,
-                    ),
-                    value: to_json_vec(&AssetScopeAttribute {
-                        asset_uuid: DEFAULT_ASSET_UUID.to_string(),
-                        scope_address: DEFAULT_SCOPE_ADDRESS.to_string(),
-                        asset_type: DEFAULT_ASSET_TYPE.to_string(),
-                        requestor_address: Addr::unchecked(DEFAULT_SENDER_ADDRESS),
-                        verifier_address: Addr::unchecked(DEFAULT_VERIFIER_ADDRESS),
-                        onboarding_status: AssetOnboardingStatus::Pending,
-                        latest_verification_result: None,
-                        access_definitions: vec![
-                            AccessDefinition {
-                                owner_address: DEFAULT_SENDER_ADDRESS.to_string(),
-                                access_routes: vec![AccessRoute::route_only("ownerroute1")],
-                                definition_type: AccessDefinitionType::Requestor,
-                            },
-                            AccessDefinition {
-                                owner_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
-                                access_routes: vec![AccessRoute::route_only("existingroute")],
-                                definition_type: AccessDefinitionType::Verifier,
-                            },
-                        ],
-                    })
-                    .unwrap(),
-                    attribute_type: AttributeType::Json.into(),
-                    address: DEFAULT_SCOPE_ADDRESS.to_string(),
-                    expiration_date: None,
-                }],
-                pagination: None,
-            },
+        mock_named_attribute_response_for_scope(
+            &mut deps,
+            DEFAULT_SCOPE_ADDRESS,
+            vec![Attribute {
+                name: generate_asset_attribute_name(DEFAULT_ASSET_TYPE, DEFAULT_CONTRACT_BASE_NAME),
+                value: to_json_vec(&AssetScopeAttribute {
+                    asset_uuid: DEFAULT_ASSET_UUID.to_string(),
+                    scope_address: DEFAULT_SCOPE_ADDRESS.to_string(),
+                    asset_type: DEFAULT_ASSET_TYPE.to_string(),
+                    requestor_address: Addr::unchecked(DEFAULT_SENDER_ADDRESS),
+                    verifier_address: Addr::unchecked(DEFAULT_VERIFIER_ADDRESS),
+                    onboarding_status: AssetOnboardingStatus::Pending,
+                    latest_verification_result: None,
+                    access_definitions: vec![
+                        AccessDefinition {
+                            owner_address: DEFAULT_SENDER_ADDRESS.to_string(),
+                            access_routes: vec![AccessRoute::route_only("ownerroute1")],
+                            definition_type: AccessDefinitionType::Requestor,
+                        },
+                        AccessDefinition {
+                            owner_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
+                            access_routes: vec![AccessRoute::route_only("existingroute")],
+                            definition_type: AccessDefinitionType::Verifier,
+                        },
+                    ],
+                    scope_spec_address: None,
+                    retry_count: 0,
+                    pending_expiry_block: None,
+                    appeal_deadline_block: None,
+                    os_gateway_permission_granted: true,
+                    os_gateway_grant_id: None,
+                    classification_initiated_at_block: 0,
+                    last_modified_block: 0,
+                    requestor_metadata: None,
+                })
+                .unwrap(),
+                attribute_type: AttributeType::Json.into(),
+                address: DEFAULT_SCOPE_ADDRESS.to_string(),
+                expiration_date: None,
+            }],
         );
 
         let instantiate_args = InstArgs::default();
@@ -752,13 +1020,12 @@ mod tests {
             )
             .unwrap();
 
-        let messages = repository.messages.get();
-
         assert_eq!(
             2,
-            messages.len(),
+            repository.message_count(),
             "verify asset should produce 2 messages (update attribute msg and 1 bank send msg)"
         );
+        let messages = repository.messages.get();
 
         let first_message = &messages[0];
         if let Some(MsgUpdateAttributeRequest { update_value, .. }) =
@@ -806,33 +1073,29 @@ mod tests {
             )
         }
         let second_message = &messages[1];
-        match second_message {
-            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
-                assert_eq!(
-                    to_address,
-                    &DEFAULT_VERIFIER_ADDRESS.to_string(),
-                    "fee message should go to default verifier address"
-                );
-                assert_eq!(
-                    1,
-                    amount.len(),
-                    "exactly one coin type should be present on bank send fee message"
-                );
-                assert_eq!(
-                    amount.first().unwrap().amount.u128(),
-                    DEFAULT_ONBOARDING_COST,
-                    "bank send fee message should be the default onboarding cost"
-                );
-                assert_eq!(
-                    amount.first().unwrap().denom,
-                    DEFAULT_ONBOARDING_DENOM.to_string(),
-                    "bank send fee message should use the default onboarding denom"
-                );
-            }
-            _ => panic!(
-                "Unexpected second message type for verify_asset: {:?}",
-                second_message
-            ),
+        assert_msg_type(second_message, "bank_send");
+        if let CosmosMsg::Bank(BankMsg::Send { to_address, amount }) = second_message {
+            assert_eq!(
+                to_address,
+                &DEFAULT_VERIFIER_ADDRESS.to_string(),
+                "fee message should go to default verifier address"
+            );
+            assert_eq!(
+                1,
+                amount.len(),
+                "exactly one coin type should be present on bank send fee message"
+            );
+            assert_eq!(
+                amount.first().unwrap().amount.u128(),
+                DEFAULT_ONBOARDING_COST,
+                "bank send fee message should be the default onboarding cost of {}",
+                format_nhash(DEFAULT_ONBOARDING_COST)
+            );
+            assert_eq!(
+                amount.first().unwrap().denom,
+                DEFAULT_ONBOARDING_DENOM.to_string(),
+                "bank send fee message should use the default onboarding denom"
+            );
         }
     }
 
@@ -1159,6 +1422,101 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_verify_rejects_verifier_routes_exceeding_limit() {
+        let mut deps = mock_provenance_dependencies();
+        let instantiate_args = InstArgs {
+            max_access_routes_per_owner: 1.to_some(),
+            ..InstArgs::default()
+        };
+        setup_test_suite(&mut deps, &instantiate_args);
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        let repository = AssetMetaService::new(deps.as_mut());
+        let attribute = repository
+            .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+            .expect("the scope attribute should be fetched");
+        let err = repository
+            .verify_asset::<&str>(
+                &instantiate_args.env,
+                attribute,
+                true,
+                None,
+                vec![
+                    AccessRoute::route_only("route-one"),
+                    AccessRoute::route_only("route-two"),
+                ],
+            )
+            .expect_err("verification should fail when the verifier's routes exceed the limit");
+        assert_access_route_limit_exceeded(err, 1);
+        assert_eq!(
+            0,
+            repository.message_count(),
+            "no messages should be produced when the access route limit is exceeded",
+        );
+    }
+
+    #[test]
+    fn test_verify_does_not_truncate_verifier_routes_above_lowered_limit() {
+        let mut deps = mock_provenance_dependencies();
+        let instantiate_args = InstArgs::default();
+        setup_test_suite(&mut deps, &instantiate_args);
+        setup_no_attribute_response(&mut deps, None);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        let existing_routes = vec![
+            AccessRoute::route_only("route-one"),
+            AccessRoute::route_only("route-two"),
+        ];
+        STATE_V2
+            .update(deps.as_mut().storage, |mut state| -> AssetResult<_> {
+                state.max_access_routes_per_owner = 1;
+                Ok(state)
+            })
+            .expect("the lowered access route limit should be stored");
+        let repository = AssetMetaService::new(deps.as_mut());
+        let mut attribute = repository
+            .get_asset_by_asset_type(DEFAULT_SCOPE_ADDRESS, DEFAULT_ASSET_TYPE)
+            .expect("the scope attribute should be fetched");
+        attribute.access_definitions.push(AccessDefinition {
+            owner_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
+            access_routes: existing_routes.clone(),
+            definition_type: AccessDefinitionType::Verifier,
+        });
+        let err = repository
+            .verify_asset::<&str>(
+                &instantiate_args.env,
+                attribute.clone(),
+                true,
+                None,
+                vec![AccessRoute::route_only("route-three")],
+            )
+            .expect_err("verification should fail when it would add routes beyond the limit");
+        assert_access_route_limit_exceeded(err, 1);
+        repository
+            .verify_asset::<&str>(
+                &instantiate_args.env,
+                attribute,
+                true,
+                None,
+                vec![AccessRoute::route_only("route-one")],
+            )
+            .expect("verification should succeed when it adds no routes beyond the limit");
+        let update_value = try_into_update_attribute_request(&repository.get_messages()[0])
+            .expect("the first message should update the scope attribute")
+            .update_value;
+        let updated_attribute: AssetScopeAttribute = from_json(update_value).unwrap();
+        assert_eq!(
+            existing_routes,
+            updated_attribute
+                .access_definitions
+                .into_iter()
+                .find(|def| def.owner_address == DEFAULT_VERIFIER_ADDRESS)
+                .expect("the verifier's access definition should be retained")
+                .access_routes,
+            "the verifier's existing routes should not be truncated to fit the lowered limit",
+        );
+    }
+
     #[test]
     fn test_finalize_classification_success_with_retained_verifier() {
         assert_verify_classification_success(false);
@@ -1191,13 +1549,12 @@ mod tests {
             )
             .unwrap();
 
-        let messages = repository.get_messages();
-
         assert_eq!(
             2,
-            messages.len(),
+            repository.message_count(),
             "verify asset should produce two message (update attribute msg and one bank send message)"
         );
+        let messages = repository.get_messages();
         let first_message = &messages[0];
         if let Some(update_attribute_request) = try_into_update_attribute_request(first_message) {
             let mut value = original_attribute_value.clone();
@@ -1242,23 +1599,35 @@ mod tests {
             )
         }
         let second_message = &messages[1];
-        match second_message {
-            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+        assert_msg_type(second_message, "bank_send");
+        if let CosmosMsg::Bank(BankMsg::Send { to_address, amount }) = second_message {
+            assert_eq!(
+                &DEFAULT_VERIFIER_ADDRESS.to_string(),
+                to_address,
+                "fee message should go to the verifier"
+            );
+            assert_eq!(
+                &vec![Coin::new(DEFAULT_ONBOARDING_COST, DEFAULT_ONBOARDING_DENOM)],
+                amount,
+                "fee message should be of the proper amount: {}",
+                format_nhash(DEFAULT_ONBOARDING_COST)
+            );
+        }
+    }
+
+    fn assert_access_route_limit_exceeded(err: ContractError, expected_limit: u32) {
+        match err {
+            ContractError::AccessRouteLimitExceeded { owner, limit } => {
                 assert_eq!(
-                    &DEFAULT_VERIFIER_ADDRESS.to_string(),
-                    to_address,
-                    "fee message should go to the verifier"
+                    DEFAULT_VERIFIER_ADDRESS, owner,
+                    "the error should include the verifier as the owner of the routes",
                 );
                 assert_eq!(
-                    &vec![Coin::new(DEFAULT_ONBOARDING_COST, DEFAULT_ONBOARDING_DENOM)],
-                    amount,
-                    "fee message should be of the proper amount"
+                    expected_limit, limit,
+                    "the error should include the current limit",
                 );
             }
-            _ => panic!(
-                "Unexpected second message type for verify_asset: {:?}",
-                second_message
-            ),
+            _ => panic!("unexpected error encountered: {:?}", err),
         }
     }
 
@@ -1270,6 +1639,7 @@ mod tests {
             DEFAULT_VERIFIER_ADDRESS,
             AssetOnboardingStatus::Pending.to_some(),
             get_default_access_routes(),
+            mock_env().block.height,
         )
         .expect("failed to instantiate default asset scope attribute")
     }
@@ -1301,7 +1671,13 @@ mod tests {
             update_asset_definition(
                 deps.as_mut(),
                 empty_mock_info(DEFAULT_ADMIN_ADDRESS),
-                UpdateAssetDefinitionV1::new(definition),
+                UpdateAssetDefinitionV1::new(AssetDefinitionInputV3::new(
+                    definition.asset_type,
+                    definition.display_name,
+                    definition.verifiers,
+                    None,
+                    None,
+                )),
             )
             .expect("updating the asset definition to remove the verifier should succeed");
         }
@@ -1323,12 +1699,12 @@ mod tests {
                 get_default_access_routes(),
             )
             .expect("finalize classification should succeed");
-        let messages = service.get_messages();
         assert_eq!(
             2,
-            messages.len(),
+            service.message_count(),
             "the correct number of messages should be generated",
         );
+        let messages = service.get_messages();
         let first_message = &messages[0];
         if let Some(update_attribute_request) = try_into_update_attribute_request(first_message) {
             let MsgUpdateAttributeRequest {
@@ -1378,34 +1754,30 @@ mod tests {
             )
         }
         let fee_payment_msg = &messages[1];
-        match fee_payment_msg {
-            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
-                assert_eq!(
-                    1,
-                    amount.len(),
-                    "exactly one coin amount should be present on bank send message"
-                );
-                assert_eq!(
-                    DEFAULT_ONBOARDING_COST,
-                    amount.first().unwrap().amount.u128(),
-                    "the fee amount should equate to the onboarding cost",
-                );
-                assert_eq!(
-                    DEFAULT_ONBOARDING_DENOM,
-                    amount.first().unwrap().denom,
-                    "the fee should use the correct denom",
-                );
-                assert_eq!(
-                    DEFAULT_VERIFIER_ADDRESS,
-                    to_address.as_str(),
-                    "the recipient of the fee should be the verifier",
-                );
-            }
-            msg => panic!(
-                "the second message generated should be a bank send msg, but got: {:?}",
-                msg,
-            ),
-        };
+        assert_msg_type(fee_payment_msg, "bank_send");
+        if let CosmosMsg::Bank(BankMsg::Send { to_address, amount }) = fee_payment_msg {
+            assert_eq!(
+                1,
+                amount.len(),
+                "exactly one coin amount should be present on bank send message"
+            );
+            assert_eq!(
+                DEFAULT_ONBOARDING_COST,
+                amount.first().unwrap().amount.u128(),
+                "the fee amount should equate to the onboarding cost of {}",
+                format_nhash(DEFAULT_ONBOARDING_COST),
+            );
+            assert_eq!(
+                DEFAULT_ONBOARDING_DENOM,
+                amount.first().unwrap().denom,
+                "the fee should use the correct denom",
+            );
+            assert_eq!(
+                DEFAULT_VERIFIER_ADDRESS,
+                to_address.as_str(),
+                "the recipient of the fee should be the verifier",
+            );
+        }
         let err = service
             .use_deps(|deps| {
                 load_fee_payment_detail(
@@ -1418,9 +1790,108 @@ mod tests {
                 "an error should occur when trying to fetch payment detail after finalization",
             );
         assert!(
-            matches!(err, ContractError::Std(StdError::NotFound { .. })),
+            err.is_not_found(),
             "a not found error should occur for the fee payment detail after finalization completes, but got: {:?}",
             err,
         );
     }
+
+    #[test]
+    fn test_with_messages_places_initial_messages_first() {
+        let mut deps = mock_provenance_dependencies();
+        let service = AssetMetaService::with_messages(
+            deps.as_mut(),
+            vec![bank_send(DEFAULT_SENDER_ADDRESS, 100, NHASH)],
+        );
+        service.add_message(bank_send(DEFAULT_VERIFIER_ADDRESS, 200, NHASH));
+        assert_eq!(
+            vec![
+                bank_send(DEFAULT_SENDER_ADDRESS, 100, NHASH),
+                bank_send(DEFAULT_VERIFIER_ADDRESS, 200, NHASH),
+            ],
+            service.get_messages(),
+            "the initial messages should precede any messages added afterward",
+        );
+    }
+
+    #[test]
+    fn test_merge_messages_from_appends_in_order() {
+        let mut deps = mock_provenance_dependencies();
+        let mut other_deps = mock_provenance_dependencies();
+        let service = AssetMetaService::with_messages(
+            deps.as_mut(),
+            vec![bank_send(DEFAULT_SENDER_ADDRESS, 100, NHASH)],
+        );
+        let other_service = AssetMetaService::new(other_deps.as_mut());
+        other_service.add_message(bank_send(DEFAULT_VERIFIER_ADDRESS, 200, NHASH));
+        other_service.add_message(bank_send(DEFAULT_ADMIN_ADDRESS, 300, NHASH));
+        service.merge_messages_from(&other_service);
+        assert_eq!(
+            vec![
+                bank_send(DEFAULT_SENDER_ADDRESS, 100, NHASH),
+                bank_send(DEFAULT_VERIFIER_ADDRESS, 200, NHASH),
+                bank_send(DEFAULT_ADMIN_ADDRESS, 300, NHASH),
+            ],
+            service.get_messages(),
+            "the merged messages should be appended after the existing messages in order",
+        );
+        assert_eq!(
+            vec![
+                bank_send(DEFAULT_VERIFIER_ADDRESS, 200, NHASH),
+                bank_send(DEFAULT_ADMIN_ADDRESS, 300, NHASH),
+            ],
+            other_service.get_messages(),
+            "the other service's messages should be unaffected by the merge",
+        );
+    }
+
+    #[test]
+    fn test_message_count_matches_messages() {
+        let mut deps = mock_provenance_dependencies();
+        let service = AssetMetaService::new(deps.as_mut());
+        assert_eq!(
+            0,
+            service.message_count(),
+            "a new service should not contain any messages",
+        );
+        assert!(
+            !service.has_messages(),
+            "a new service should not report that it has messages",
+        );
+        service.add_message(bank_send(DEFAULT_SENDER_ADDRESS, 100, NHASH));
+        service.append_messages(&[
+            bank_send(DEFAULT_VERIFIER_ADDRESS, 200, NHASH),
+            bank_send(DEFAULT_ADMIN_ADDRESS, 300, NHASH),
+        ]);
+        assert_eq!(
+            service.get_messages().len(),
+            service.message_count(),
+            "the message count should agree with the number of held messages",
+        );
+        assert!(
+            service.has_messages(),
+            "the service should report that it has messages after they are added",
+        );
+        service.clear_messages();
+        assert_eq!(
+            0,
+            service.message_count(),
+            "the message count should be zero after the messages are cleared",
+        );
+    }
+
+    #[test]
+    fn test_re_entrant_deps_usage_produces_error() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_suite(&mut deps, &InstArgs::default());
+        let service = AssetMetaService::new(deps.as_mut());
+        let error = service
+            .use_deps(|_| service.get_asset(DEFAULT_SCOPE_ADDRESS))
+            .expect_err("fetching an asset while the deps are in use should produce an error");
+        assert!(
+            matches!(error, ContractError::InternalError { .. }),
+            "expected an internal error to be produced, but got: {:?}",
+            error,
+        );
+    }
 }
@@ -1,5 +1,7 @@
 use cosmwasm_std::DepsMut;
 
+use crate::util::aliases::AssetResult;
+
 /// Allows dynamic delegation of a cosmwasm [DepsMut] to prevent
 /// common issues that arise when the struct is moved.
 pub trait DepsManager<'a> {
@@ -12,6 +14,16 @@ pub trait DepsManager<'a> {
     where
         F: FnMut(&mut DepsMut) -> T;
 
+    /// Functionally retrieves the result of a usage of the held [DepsMut] value, producing an
+    /// error instead of panicking if the value is already in use.
+    ///
+    /// # Parameters
+    ///
+    /// * `deps_fn` A closure that receives the held [DepsMut].
+    fn try_use_deps<T, F>(&self, deps_fn: F) -> AssetResult<T>
+    where
+        F: FnMut(&mut DepsMut) -> T;
+
     /// Moves the held [DepsMut] back to the caller.
     fn into_deps(self) -> DepsMut<'a>;
 }
@@ -14,4 +14,15 @@ pub trait MessageGatheringService {
 
     /// Deletes all held messages from the service's internal values.
     fn clear_messages(&self);
+
+    /// Counts the messages that have been appended to the service.  Implementations should
+    /// override this when the count can be determined without cloning all held messages.
+    fn message_count(&self) -> usize {
+        self.get_messages().len()
+    }
+
+    /// Determines if any messages have been appended to the service.
+    fn has_messages(&self) -> bool {
+        self.message_count() > 0
+    }
 }
@@ -0,0 +1,89 @@
+use cosmwasm_std::{DepsMut, Response};
+use result_extensions::ResultExtensions;
+
+use crate::core::state::delete_asset_definition_by_asset_type_v3;
+use crate::util::aliases::EntryPointResponse;
+use crate::util::event_attributes::{EventAttributes, EventType};
+
+/// Route implementation for [SudoMsg::ForceDeleteAssetDefinition](crate::core::msg::SudoMsg::ForceDeleteAssetDefinition).
+/// This function behaves identically to [delete_asset_definition](crate::execute::delete_asset_definition::delete_asset_definition),
+/// but does not require the contract's admin address, allowing governance to remove a definition
+/// when the admin is unavailable.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `asset_type` The asset type of the definition to delete.
+pub fn force_delete_asset_definition(deps: DepsMut, asset_type: String) -> EntryPointResponse {
+    let deleted_asset_type = delete_asset_definition_by_asset_type_v3(deps.storage, &asset_type)?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::ForceDeleteAssetDefinition)
+                .set_asset_type(deleted_asset_type),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::contract::sudo;
+    use crate::core::error::ContractError;
+    use crate::core::msg::SudoMsg;
+    use crate::core::state::load_asset_definition_by_type_v3;
+    use crate::testutil::test_constants::DEFAULT_ASSET_TYPE;
+    use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, test_instantiate_success, InstArgs,
+    };
+    use crate::util::constants::{ASSET_EVENT_TYPE_KEY, ASSET_TYPE_KEY};
+    use crate::util::event_attributes::EventType;
+
+    #[test]
+    fn test_force_delete_asset_definition_success() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let response = sudo(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::ForceDeleteAssetDefinition {
+                asset_type: DEFAULT_ASSET_TYPE.to_string(),
+            },
+        )
+        .expect("expected the forced deletion to succeed");
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::ForceDeleteAssetDefinition.event_name(),
+        );
+        assert_response_attributes_contain(&response, ASSET_TYPE_KEY, DEFAULT_ASSET_TYPE);
+        let err = load_asset_definition_by_type_v3(deps.as_ref().storage, DEFAULT_ASSET_TYPE)
+            .expect_err("expected an error to occur when loading the deleted asset definition");
+        assert!(
+            matches!(err, ContractError::RecordNotFound { .. }),
+            "expected the record not found error to occur after deletion, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_force_delete_asset_definition_failure_for_missing_definition() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let err = sudo(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::ForceDeleteAssetDefinition {
+                asset_type: "not real asset type".to_string(),
+            },
+        )
+        .expect_err("expected an error to occur when an invalid asset type is provided");
+        assert!(
+            matches!(err, ContractError::RecordNotFound { .. }),
+            "expected a record not found error to be emitted, but got: {:?}",
+            err,
+        );
+    }
+}
@@ -0,0 +1,15 @@
+//! Contains the functionality used in the [contract file](crate::contract) to process governance
+//! actions received via the sudo entrypoint.
+
+/// Contains the functionality used by the [ForceDeleteAssetDefinition](crate::core::msg::SudoMsg::ForceDeleteAssetDefinition)
+/// [SudoMsg](crate::core::msg::SudoMsg) variant when invoked via the [sudo](crate::contract::sudo)
+/// function.
+pub mod force_delete_asset_definition;
+/// Contains the functionality used by the [OverrideAdmin](crate::core::msg::SudoMsg::OverrideAdmin)
+/// [SudoMsg](crate::core::msg::SudoMsg) variant when invoked via the [sudo](crate::contract::sudo)
+/// function.
+pub mod override_admin;
+/// Contains the functionality used by the [Pause](crate::core::msg::SudoMsg::Pause) and
+/// [Unpause](crate::core::msg::SudoMsg::Unpause) [SudoMsg](crate::core::msg::SudoMsg) variants
+/// when invoked via the [sudo](crate::contract::sudo) function.
+pub mod pause_contract;
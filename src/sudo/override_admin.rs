@@ -0,0 +1,93 @@
+use cosmwasm_std::{DepsMut, Response};
+use result_extensions::ResultExtensions;
+
+use crate::core::state::STATE_V2;
+use crate::util::aliases::EntryPointResponse;
+use crate::util::event_attributes::{EventAttributes, EventType};
+use crate::util::scope_address_utils::bech32_string_to_addr;
+
+/// Route implementation for [SudoMsg::OverrideAdmin](crate::core::msg::SudoMsg::OverrideAdmin).
+/// Replaces the [admin](crate::core::state::StateV2::admin) stored in the contract's state
+/// without requiring a code migration.  Any [pending admin](crate::core::state::StateV2::pending_admin)
+/// transfer is discarded.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `new_admin` The bech32 address of the account that will become the contract's new admin.
+pub fn override_admin(deps: DepsMut, new_admin: String) -> EntryPointResponse {
+    let mut state = STATE_V2.load(deps.storage)?;
+    state.admin = bech32_string_to_addr(&new_admin)?;
+    state.pending_admin = None;
+    STATE_V2.save(deps.storage, &state)?;
+    Response::new()
+        .add_attributes(EventAttributes::new(EventType::OverrideAdmin).set_new_value(new_admin))
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::contract::sudo;
+    use crate::core::error::ContractError;
+    use crate::core::msg::SudoMsg;
+    use crate::core::state::STATE_V2;
+    use crate::testutil::test_constants::{DEFAULT_ADMIN_ADDRESS, DEFAULT_SENDER_ADDRESS};
+    use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, test_instantiate_success, InstArgs,
+    };
+    use crate::util::constants::{ASSET_EVENT_TYPE_KEY, NEW_VALUE_KEY};
+    use crate::util::event_attributes::EventType;
+
+    #[test]
+    fn test_override_admin_success() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let response = sudo(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::OverrideAdmin {
+                new_admin: DEFAULT_SENDER_ADDRESS.to_string(),
+            },
+        )
+        .expect("expected the admin override to succeed");
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::OverrideAdmin.event_name(),
+        );
+        assert_response_attributes_contain(&response, NEW_VALUE_KEY, DEFAULT_SENDER_ADDRESS);
+        assert_eq!(
+            DEFAULT_SENDER_ADDRESS,
+            STATE_V2.load(deps.as_ref().storage).unwrap().admin.as_str(),
+            "expected the admin to be changed in the contract's state",
+        );
+    }
+
+    #[test]
+    fn test_override_admin_failure_for_invalid_address() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let err = sudo(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::OverrideAdmin {
+                new_admin: "not-a-bech32-address".to_string(),
+            },
+        )
+        .expect_err("expected an error to occur when an invalid address is provided");
+        assert!(
+            matches!(err, ContractError::Bech32Error(..)),
+            "expected a bech32 error to be emitted, but got: {:?}",
+            err,
+        );
+        assert_eq!(
+            DEFAULT_ADMIN_ADDRESS,
+            STATE_V2.load(deps.as_ref().storage).unwrap().admin.as_str(),
+            "expected the admin to remain unchanged after a failed override",
+        );
+    }
+}
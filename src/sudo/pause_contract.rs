@@ -0,0 +1,111 @@
+use cosmwasm_std::{DepsMut, Response};
+use result_extensions::ResultExtensions;
+
+use crate::core::state::set_contract_paused;
+use crate::util::aliases::EntryPointResponse;
+use crate::util::event_attributes::{EventAttributes, EventType};
+
+/// Route implementation for [SudoMsg::Pause](crate::core::msg::SudoMsg::Pause) and
+/// [SudoMsg::Unpause](crate::core::msg::SudoMsg::Unpause).  While paused, all execution routes
+/// will be rejected.  Queries remain available regardless of the paused status.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `paused` If true, the contract will be paused.  If false, the contract will be unpaused.
+pub fn pause_contract(deps: DepsMut, paused: bool) -> EntryPointResponse {
+    set_contract_paused(deps.storage, paused)?;
+    Response::new()
+        .add_attributes(EventAttributes::new(if paused {
+            EventType::PauseContract
+        } else {
+            EventType::UnpauseContract
+        }))
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    use crate::contract::sudo;
+    use crate::core::error::ContractError;
+    use crate::core::msg::SudoMsg;
+    use crate::core::state::is_contract_paused;
+    use crate::testutil::execute_utilities::execute_toggle_asset_definition;
+    use crate::testutil::test_constants::{DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE};
+    use crate::testutil::test_utilities::{
+        assert_response_attributes_contain, empty_mock_info, test_instantiate_success, InstArgs,
+    };
+    use crate::util::constants::ASSET_EVENT_TYPE_KEY;
+    use crate::util::event_attributes::EventType;
+
+    #[test]
+    fn test_pause_and_unpause_contract() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        assert!(
+            !is_contract_paused(deps.as_ref().storage).unwrap(),
+            "the contract should not be paused after instantiation",
+        );
+        let response = sudo(deps.as_mut(), mock_env(), SudoMsg::Pause {})
+            .expect("pausing the contract should succeed");
+        assert!(
+            response.messages.is_empty(),
+            "pausing the contract should not emit messages",
+        );
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::PauseContract.event_name(),
+        );
+        assert!(
+            is_contract_paused(deps.as_ref().storage).unwrap(),
+            "the contract should be paused after the pause route is invoked",
+        );
+        let response = sudo(deps.as_mut(), mock_env(), SudoMsg::Unpause {})
+            .expect("unpausing the contract should succeed");
+        assert_response_attributes_contain(
+            &response,
+            ASSET_EVENT_TYPE_KEY,
+            &EventType::UnpauseContract.event_name(),
+        );
+        assert!(
+            !is_contract_paused(deps.as_ref().storage).unwrap(),
+            "the contract should not be paused after the unpause route is invoked",
+        );
+    }
+
+    #[test]
+    fn test_execute_is_rejected_while_paused() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        sudo(deps.as_mut(), mock_env(), SudoMsg::Pause {})
+            .expect("pausing the contract should succeed");
+        let err = execute_toggle_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            false,
+        )
+        .expect_err("expected execution to be rejected while the contract is paused");
+        assert!(
+            matches!(err, ContractError::UnexpectedState { .. }),
+            "expected an unexpected state error to be emitted, but got: {:?}",
+            err,
+        );
+        sudo(deps.as_mut(), mock_env(), SudoMsg::Unpause {})
+            .expect("unpausing the contract should succeed");
+        execute_toggle_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DEFAULT_ASSET_TYPE,
+            false,
+        )
+        .expect("expected execution to succeed after the contract is unpaused");
+    }
+}
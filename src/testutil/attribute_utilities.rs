@@ -0,0 +1,155 @@
+use cosmwasm_std::{from_json, ContractResult, SystemResult};
+use provwasm_std::types::provenance::attribute::v1::{
+    Attribute, QueryAttributeRequest, QueryAttributeResponse, QueryAttributesRequest,
+    QueryAttributesResponse,
+};
+
+use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+use crate::core::types::asset_scope_attribute::AssetScopeAttribute;
+use crate::testutil::test_constants::DEFAULT_CONTRACT_BASE_NAME;
+use crate::testutil::test_utilities::{
+    build_attribute, get_default_asset_scope_attribute, to_query_response_binary, MockOwnedDeps,
+};
+use crate::util::functions::generate_asset_attribute_name;
+use crate::util::scope_address_utils::scope_address_to_asset_uuid;
+
+/// Sets up mock queries such that querying for all attributes on the given scope returns the
+/// provided attributes.
+pub fn mock_attribute_response_for_scope<S: Into<String>>(
+    deps: &mut MockOwnedDeps,
+    scope_address: S,
+    attributes: Vec<Attribute>,
+) {
+    QueryAttributesRequest::mock_response(
+        &mut deps.querier,
+        QueryAttributesResponse {
+            account: scope_address.into(),
+            attributes,
+            pagination: None,
+        },
+    );
+}
+
+/// Sets up mock queries such that querying for an attribute by name on the given scope returns
+/// the provided attributes.
+pub fn mock_named_attribute_response_for_scope<S: Into<String>>(
+    deps: &mut MockOwnedDeps,
+    scope_address: S,
+    attributes: Vec<Attribute>,
+) {
+    QueryAttributeRequest::mock_response(
+        &mut deps.querier,
+        QueryAttributeResponse {
+            account: scope_address.into(),
+            attributes,
+            pagination: None,
+        },
+    );
+}
+
+/// Sets up mock queries such that querying for an attribute by name on the given scope returns only
+/// the provided attributes with the requested name.  Unlike [mock_named_attribute_response_for_scope](self::mock_named_attribute_response_for_scope),
+/// this allows the attributes for multiple asset types to be looked up independently within a
+/// single execution.
+pub fn mock_named_attribute_responses_by_name<S: Into<String>>(
+    deps: &mut MockOwnedDeps,
+    scope_address: S,
+    attributes: Vec<Attribute>,
+) {
+    let scope_address: String = scope_address.into();
+    deps.querier.registered_custom_queries.insert(
+        "/provenance.attribute.v1.Query/Attribute".to_string(),
+        Box::new(move |data| {
+            let request = QueryAttributeRequest::try_from(data.to_owned())
+                .expect("the attribute request should decode");
+            let response = QueryAttributeResponse {
+                account: scope_address.to_owned(),
+                attributes: attributes
+                    .iter()
+                    .filter(|attribute| attribute.name == request.name)
+                    .cloned()
+                    .collect(),
+                pagination: None,
+            };
+            SystemResult::Ok(ContractResult::Ok(to_query_response_binary(
+                response.to_proto_bytes(),
+            )))
+        }),
+    );
+}
+
+/// Sets up mock queries such that querying for an attribute by name on any scope returns only the
+/// provided attributes with the requested name whose address matches the queried scope.  Unlike
+/// [mock_named_attribute_responses_by_name](self::mock_named_attribute_responses_by_name), this
+/// allows the attributes of multiple scopes to be looked up independently within a single query.
+pub fn mock_named_attribute_responses_by_scope(
+    deps: &mut MockOwnedDeps,
+    attributes: Vec<Attribute>,
+) {
+    deps.querier.registered_custom_queries.insert(
+        "/provenance.attribute.v1.Query/Attribute".to_string(),
+        Box::new(move |data| {
+            let request = QueryAttributeRequest::try_from(data.to_owned())
+                .expect("the attribute request should decode");
+            let response = QueryAttributeResponse {
+                account: request.account.to_owned(),
+                attributes: attributes
+                    .iter()
+                    .filter(|attribute| {
+                        attribute.address == request.account && attribute.name == request.name
+                    })
+                    .cloned()
+                    .collect(),
+                pagination: None,
+            };
+            SystemResult::Ok(ContractResult::Ok(to_query_response_binary(
+                response.to_proto_bytes(),
+            )))
+        }),
+    );
+}
+
+/// Sets up mock queries such that the given scope has no attributes, whether they are queried by
+/// name or all at once.
+pub fn mock_empty_attribute_response<S: Into<String>>(deps: &mut MockOwnedDeps, scope_address: S) {
+    let scope_address: String = scope_address.into();
+    mock_named_attribute_response_for_scope(deps, &scope_address, vec![]);
+    mock_attribute_response_for_scope(deps, scope_address, vec![]);
+}
+
+/// Builds an attribute containing the default scope attribute, adjusted to the given scope address,
+/// asset type and onboarding status.
+pub fn build_scope_attribute<S1: Into<String>, S2: Into<String>>(
+    scope_address: S1,
+    asset_type: S2,
+    onboarding_status: AssetOnboardingStatus,
+) -> Attribute {
+    let scope_address: String = scope_address.into();
+    build_attribute(
+        &scope_address,
+        &AssetScopeAttribute {
+            asset_uuid: scope_address_to_asset_uuid(&scope_address)
+                .expect("the scope address should be convertible to an asset uuid"),
+            scope_address: scope_address.to_owned(),
+            asset_type: asset_type.into(),
+            onboarding_status,
+            ..get_default_asset_scope_attribute()
+        },
+    )
+}
+
+/// Asserts that the attribute is named for the expected scope attribute's asset type and that its
+/// value deserializes to the expected scope attribute.
+pub fn assert_attribute_value_matches(attribute: &Attribute, expected: &AssetScopeAttribute) {
+    assert_eq!(
+        generate_asset_attribute_name(&expected.asset_type, DEFAULT_CONTRACT_BASE_NAME),
+        attribute.name,
+        "the attribute should be named for the expected asset type",
+    );
+    assert_eq!(
+        expected,
+        &from_json::<AssetScopeAttribute>(&attribute.value)
+            .expect("the attribute value should deserialize to a scope attribute"),
+        "the attribute value should match the expected scope attribute",
+    );
+}
@@ -0,0 +1,607 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo};
+
+use crate::contract::execute;
+use crate::core::msg::ExecuteMsg;
+use crate::core::types::asset_definition::AssetDefinitionInputV3;
+use crate::core::types::asset_identifier::AssetIdentifier;
+use crate::core::types::batch_onboard_asset::BatchOnboardAssetEntry;
+use crate::core::types::batch_verify_asset::BatchVerifyAssetEntry;
+use crate::core::types::entity_detail::EntityDetail;
+use crate::core::types::os_gateway_access::OsGatewayAccess;
+use crate::core::types::verifier_detail::VerifierDetailV2;
+use crate::execute::clone_asset_definition::CloneAssetDefinitionV1;
+use crate::execute::onboard_asset::OnboardAssetV1;
+use crate::execute::update_access_routes::UpdateAccessRoutesV1;
+use crate::execute::verify_asset::VerifyAssetV1;
+use crate::util::aliases::EntryPointResponse;
+use crate::util::traits::OptionExtensions;
+
+// Each helper in this file builds the corresponding ExecuteMsg variant and routes it through
+// contract::execute, ensuring that tests exercise the same entrypoint that a real transaction
+// would.  The result is returned untouched so that callers can assert on both successes and
+// failures.
+
+// Executes an OnboardAsset message built from the provided OnboardAssetV1
+pub fn execute_onboard_asset(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: OnboardAssetV1,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::OnboardAsset {
+            identifier: msg.identifier.to_serialized_enum(),
+            asset_type: msg.asset_type,
+            verifier_address: msg.verifier_address,
+            access_routes: msg.access_routes.to_some(),
+            add_os_gateway_permission: msg.add_os_gateway_permission.to_some(),
+            expected_fee: msg.expected_fee,
+            memo: msg.memo,
+            metadata: msg.metadata,
+        },
+    )
+}
+
+// Executes a BatchOnboardAsset message for the provided entries
+pub fn execute_batch_onboard_asset(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    entries: Vec<BatchOnboardAssetEntry>,
+) -> EntryPointResponse {
+    execute(deps, env, info, ExecuteMsg::BatchOnboardAsset { entries })
+}
+
+// Executes a VerifyAsset message built from the provided VerifyAssetV1
+pub fn execute_verify_asset(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: VerifyAssetV1,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::VerifyAsset {
+            identifier: msg.identifier.to_serialized_enum(),
+            asset_type: msg.asset_type,
+            success: msg.success,
+            message: msg.message,
+            access_routes: msg.access_routes.to_some(),
+        },
+    )
+}
+
+// Executes a BatchVerifyAsset message for the provided entries
+pub fn execute_batch_verify_asset(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    entries: Vec<BatchVerifyAssetEntry>,
+) -> EntryPointResponse {
+    execute(deps, env, info, ExecuteMsg::BatchVerifyAsset { entries })
+}
+
+// Executes an AddAssetDefinition message for the provided input
+pub fn execute_add_asset_definition(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset_definition: AssetDefinitionInputV3,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::AddAssetDefinition { asset_definition },
+    )
+}
+
+// Executes a CloneAssetDefinition message built from the provided CloneAssetDefinitionV1
+pub fn execute_clone_asset_definition(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: CloneAssetDefinitionV1,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::CloneAssetDefinition {
+            source_asset_type: msg.source_asset_type,
+            new_asset_type: msg.new_asset_type,
+            new_display_name: msg.new_display_name,
+            bind_name: msg.bind_name,
+        },
+    )
+}
+
+// Executes an UpdateAssetDefinition message for the provided input
+pub fn execute_update_asset_definition(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset_definition: AssetDefinitionInputV3,
+    partial: Option<bool>,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::UpdateAssetDefinition {
+            asset_definition,
+            partial,
+        },
+    )
+}
+
+// Executes an UpdateAssetDefinitionDescription message for the provided asset type
+pub fn execute_update_asset_definition_description<S: Into<String>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset_type: S,
+    display_name: Option<&str>,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::UpdateAssetDefinitionDescription {
+            asset_type: asset_type.into(),
+            display_name: display_name.map(|name| name.to_string()),
+        },
+    )
+}
+
+// Executes a ToggleAssetDefinition message for the provided asset type
+pub fn execute_toggle_asset_definition<S: Into<String>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset_type: S,
+    expected_result: bool,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::ToggleAssetDefinition {
+            asset_type: asset_type.into(),
+            expected_result,
+        },
+    )
+}
+
+// Executes a ToggleAssetVerifier message for the provided verifier on the provided asset type
+pub fn execute_toggle_asset_verifier<S1: Into<String>, S2: Into<String>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset_type: S1,
+    verifier_address: S2,
+    expected_result: bool,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::ToggleAssetVerifier {
+            asset_type: asset_type.into(),
+            verifier_address: verifier_address.into(),
+            expected_result,
+        },
+    )
+}
+
+// Executes an UpdateVerifierEntityDetail message, replacing the entity detail of the provided verifier
+pub fn execute_update_verifier_entity_detail<S1: Into<String>, S2: Into<String>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset_type: S1,
+    verifier_address: S2,
+    entity_detail: Option<EntityDetail>,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::UpdateVerifierEntityDetail {
+            asset_type: asset_type.into(),
+            verifier_address: verifier_address.into(),
+            entity_detail,
+        },
+    )
+}
+
+// Executes a RotateVerifierAddress message, replacing the address of the provided verifier
+pub fn execute_rotate_verifier_address<S1: Into<String>, S2: Into<String>, S3: Into<String>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset_type: S1,
+    old_address: S2,
+    new_address: S3,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::RotateVerifierAddress {
+            asset_type: asset_type.into(),
+            old_address: old_address.into(),
+            new_address: new_address.into(),
+        },
+    )
+}
+
+// Executes an UpdateAllowedRequestors message, replacing the allowed requestors of the provided asset type
+pub fn execute_update_allowed_requestors<S: Into<String>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset_type: S,
+    allowed_requestors: Option<Vec<String>>,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::UpdateAllowedRequestors {
+            asset_type: asset_type.into(),
+            allowed_requestors,
+        },
+    )
+}
+
+// Executes an AddAssetVerifier message, adding the verifier to the provided asset type
+pub fn execute_add_asset_verifier<S: Into<String>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset_type: S,
+    verifier: VerifierDetailV2,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::AddAssetVerifier {
+            asset_type: asset_type.into(),
+            verifier,
+        },
+    )
+}
+
+// Executes an UpdateAssetVerifier message, replacing the verifier on the provided asset type
+pub fn execute_update_asset_verifier<S: Into<String>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset_type: S,
+    verifier: VerifierDetailV2,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::UpdateAssetVerifier {
+            asset_type: asset_type.into(),
+            verifier,
+        },
+    )
+}
+
+// Executes an UpdateAccessRoutes message built from the provided UpdateAccessRoutesV1
+pub fn execute_update_access_routes(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: UpdateAccessRoutesV1,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::UpdateAccessRoutes {
+            identifier: msg.identifier.to_serialized_enum(),
+            asset_type: msg.asset_type,
+            owner_address: msg.owner_address,
+            access_routes: msg.access_routes,
+        },
+    )
+}
+
+// Executes a BulkDeleteAssetDefinitions message for the provided asset types
+pub fn execute_bulk_delete_asset_definitions<S: Into<String>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset_types: Vec<S>,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::BulkDeleteAssetDefinitions {
+            asset_types: asset_types.into_iter().map(|t| t.into()).collect(),
+        },
+    )
+}
+
+// Executes a DeleteAssetDefinition message for the provided asset type
+pub fn execute_delete_asset_definition<S: Into<String>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset_type: S,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::DeleteAssetDefinition {
+            asset_type: asset_type.into(),
+        },
+    )
+}
+
+// Executes a DeprecateAssetDefinition message for the provided asset type and optional replacement
+pub fn execute_deprecate_asset_definition<S1: Into<String>, S2: Into<String>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset_type: S1,
+    replacement_asset_type: Option<S2>,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::DeprecateAssetDefinition {
+            asset_type: asset_type.into(),
+            replacement_asset_type: replacement_asset_type.map(|asset_type| asset_type.into()),
+        },
+    )
+}
+
+// Executes a FreezeAssetDefinition message for the provided asset type
+pub fn execute_freeze_asset_definition<S: Into<String>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset_type: S,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::FreezeAssetDefinition {
+            asset_type: asset_type.into(),
+        },
+    )
+}
+
+// Executes a PurgeFeePaymentDetail message for the provided asset
+pub fn execute_purge_fee_payment_detail<S: Into<String>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    identifier: AssetIdentifier,
+    asset_type: S,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::PurgeFeePaymentDetail {
+            identifier: identifier.to_serialized_enum(),
+            asset_type: asset_type.into(),
+        },
+    )
+}
+
+// Executes a PurgeStaleFeeDetail message for the provided asset
+pub fn execute_purge_stale_fee_detail<S: Into<String>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    identifier: AssetIdentifier,
+    asset_type: S,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::PurgeStaleFeeDetail {
+            identifier: identifier.to_serialized_enum(),
+            asset_type: asset_type.into(),
+        },
+    )
+}
+
+// Executes a SetFeeDetailMaxAge message, replacing the contract's fee detail max age
+pub fn execute_set_fee_detail_max_age(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    max_age_blocks: Option<u64>,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::SetFeeDetailMaxAge { max_age_blocks },
+    )
+}
+
+// Executes a SetMaxAccessRoutesPerOwner message, replacing the contract's access route limit
+pub fn execute_set_max_access_routes_per_owner(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    limit: u32,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::SetMaxAccessRoutesPerOwner { limit },
+    )
+}
+
+// Executes an UpdateOsGatewayGrantPrefix message, replacing the prefix used for grant ids
+pub fn execute_update_os_gateway_grant_prefix<S: Into<String>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    prefix: Option<S>,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::UpdateOsGatewayGrantPrefix {
+            prefix: prefix.map(|p| p.into()),
+        },
+    )
+}
+
+// Executes a SetRequireEntityDetail message, toggling whether or not verifiers need entity details
+pub fn execute_set_require_entity_detail(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    required: bool,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::SetRequireEntityDetail { required },
+    )
+}
+
+// Executes a TransferAdmin message, nominating the provided address as the contract's next admin
+pub fn execute_transfer_admin<S: Into<String>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    new_admin_address: S,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::TransferAdmin {
+            new_admin_address: new_admin_address.into(),
+        },
+    )
+}
+
+// Executes an AcceptAdminTransfer message on behalf of the sender
+pub fn execute_accept_admin_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> EntryPointResponse {
+    execute(deps, env, info, ExecuteMsg::AcceptAdminTransfer {})
+}
+
+// Executes an ExpireStaleVerification message for the provided asset
+pub fn execute_expire_stale_verification<S: Into<String>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    identifier: AssetIdentifier,
+    asset_type: S,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::ExpireStaleVerification {
+            identifier: identifier.to_serialized_enum(),
+            asset_type: asset_type.into(),
+        },
+    )
+}
+
+// Executes a CancelPendingOnboarding message for the provided asset
+pub fn execute_cancel_pending_onboarding<S: Into<String>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    identifier: AssetIdentifier,
+    asset_type: S,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::CancelPendingOnboarding {
+            identifier: identifier.to_serialized_enum(),
+            asset_type: asset_type.into(),
+        },
+    )
+}
+
+// Executes a RelayOsGatewayAccess message for the provided access
+pub fn execute_relay_os_gateway_access(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    access: OsGatewayAccess,
+) -> EntryPointResponse {
+    execute(deps, env, info, ExecuteMsg::RelayOsGatewayAccess { access })
+}
+
+// Executes an AppealVerification message for the provided asset
+pub fn execute_appeal_verification<S1: Into<String>, S2: Into<String>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    identifier: AssetIdentifier,
+    asset_type: S1,
+    appeal_reason: S2,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::AppealVerification {
+            identifier: identifier.to_serialized_enum(),
+            asset_type: asset_type.into(),
+            appeal_reason: appeal_reason.into(),
+        },
+    )
+}
+
+// Executes a ResolveAppeal message for the provided asset
+pub fn execute_resolve_appeal<S1: Into<String>, S2: Into<String>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    identifier: AssetIdentifier,
+    asset_type: S1,
+    approved: bool,
+    resolution_message: S2,
+) -> EntryPointResponse {
+    execute(
+        deps,
+        env,
+        info,
+        ExecuteMsg::ResolveAppeal {
+            identifier: identifier.to_serialized_enum(),
+            asset_type: asset_type.into(),
+            approved,
+            resolution_message: resolution_message.into(),
+        },
+    )
+}
@@ -0,0 +1,45 @@
+use crate::core::types::asset_definition::AssetDefinitionInputV3;
+use crate::core::types::verifier_detail::VerifierDetailV2;
+use crate::testutil::test_utilities::{get_default_asset_definition_input, InstArgs};
+
+impl InstArgs {
+    /// Instantiates with the default asset definition, using the provided verifier as its only
+    /// verifier.
+    pub fn with_single_verifier(verifier: VerifierDetailV2) -> Self {
+        Self::with_asset_definitions(vec![AssetDefinitionInputV3 {
+            verifiers: vec![verifier],
+            ..get_default_asset_definition_input()
+        }])
+    }
+
+    /// Instantiates without any asset definitions.
+    pub fn with_no_definitions() -> Self {
+        Self::with_asset_definitions(vec![])
+    }
+
+    /// Instantiates with the default asset definitions and the provided test mode value.
+    pub fn with_test_mode(is_test: bool) -> Self {
+        Self {
+            is_test,
+            ..Self::default()
+        }
+    }
+
+    /// Instantiates with exactly the provided asset definitions.
+    pub fn with_asset_definitions(asset_definitions: Vec<AssetDefinitionInputV3>) -> Self {
+        Self {
+            asset_definitions,
+            ..Self::default()
+        }
+    }
+
+    /// Instantiates with the default asset definition, appending the provided verifier after the
+    /// default verifier.
+    pub fn add_verifier_to_default(verifier: VerifierDetailV2) -> Self {
+        let default_input = get_default_asset_definition_input();
+        Self::with_asset_definitions(vec![AssetDefinitionInputV3 {
+            verifiers: [default_input.verifiers.clone(), vec![verifier]].concat(),
+            ..default_input
+        }])
+    }
+}
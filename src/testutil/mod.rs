@@ -1,5 +1,9 @@
+pub mod attribute_utilities;
+pub mod execute_utilities;
+pub mod instantiate_utilities;
 pub mod msg_utilities;
 pub mod onboard_asset_helpers;
+pub mod scope_utilities;
 pub mod test_constants;
 pub mod test_utilities;
 pub mod update_access_routes_helpers;
@@ -4,6 +4,7 @@ use provwasm_std::types::provenance::{
     name::v1::{MsgBindNameRequest, NameRecord},
 };
 
+use crate::util::constants::format_nhash;
 use crate::util::functions::{try_into_bind_name_request, try_into_custom_fee_request};
 
 use super::test_constants::DEFAULT_CONTRACT_BASE_NAME;
@@ -62,8 +63,10 @@ pub fn test_aggregate_msg_fees_are_charged<S: Into<String>>(
     assert_eq!(
         expected_fee_amount,
         total_fees,
-        "{}",
+        "{}: expected {} but got {}",
         assertion_message.into(),
+        format_nhash(expected_fee_amount),
+        format_nhash(total_fees),
     );
 }
 
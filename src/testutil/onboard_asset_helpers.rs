@@ -25,6 +25,9 @@ impl TestOnboardAsset {
             verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
             access_routes: get_default_access_routes(),
             add_os_gateway_permission: true,
+            expected_fee: None,
+            memo: None,
+            metadata: None,
         }
     }
 
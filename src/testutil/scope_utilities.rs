@@ -0,0 +1,128 @@
+use provwasm_std::types::provenance::metadata::v1::{
+    process::ProcessId, record_input::Source, Process, Record, RecordInput, RecordInputStatus,
+    RecordOutput, RecordWrapper, RecordsResponse, ResultStatus, Scope, ScopeResponse,
+    ScopeSpecIdInfo, ScopeWrapper,
+};
+
+use crate::testutil::test_constants::{
+    DEFAULT_PROCESS_ADDRESS, DEFAULT_PROCESS_METHOD, DEFAULT_PROCESS_NAME,
+    DEFAULT_RECORD_INPUT_NAME, DEFAULT_RECORD_INPUT_SOURCE_ADDRESS, DEFAULT_RECORD_OUTPUT_HASH,
+    DEFAULT_RECORD_SPEC_ADDRESS, DEFAULT_SCOPE_ADDRESS, DEFAULT_SCOPE_SPEC_ADDRESS,
+    DEFAULT_SESSION_ADDRESS,
+};
+use crate::testutil::test_utilities::get_duped_scope;
+
+/// Wraps the scope without any of the optional id info values.
+pub fn mock_scope_wrapper(scope: Scope) -> ScopeWrapper {
+    ScopeWrapper {
+        scope: Some(scope),
+        scope_id_info: None,
+        scope_spec_id_info: None,
+    }
+}
+
+/// A scope query response that contains the scope, and no sessions or records.
+pub fn mock_scope_response(scope: Scope) -> ScopeResponse {
+    ScopeResponse {
+        scope: Some(mock_scope_wrapper(scope)),
+        ..mock_missing_scope_response()
+    }
+}
+
+/// A scope query response that contains the scope, with id info indicating that it was created
+/// from the provided scope specification.
+pub fn mock_scope_response_with_spec_address(
+    scope: Scope,
+    scope_spec_address: &str,
+) -> ScopeResponse {
+    ScopeResponse {
+        scope: Some(ScopeWrapper {
+            scope_spec_id_info: Some(ScopeSpecIdInfo {
+                scope_spec_addr: scope_spec_address.to_string(),
+                ..ScopeSpecIdInfo::default()
+            }),
+            ..mock_scope_wrapper(scope)
+        }),
+        ..mock_missing_scope_response()
+    }
+}
+
+/// A scope query response indicating that the requested scope does not exist.
+pub fn mock_missing_scope_response() -> ScopeResponse {
+    ScopeResponse {
+        scope: None,
+        sessions: vec![],
+        records: vec![],
+        request: None,
+    }
+}
+
+/// A records query response that contains the provided records and no scope or sessions.
+pub fn mock_records_response(records: Vec<RecordWrapper>) -> RecordsResponse {
+    RecordsResponse {
+        scope: None,
+        sessions: vec![],
+        records,
+        request: None,
+    }
+}
+
+/// A record with the given name, linked to the default session and record specification.  When
+/// `has_outputs` is false, the record is considered empty during onboarding.
+pub fn mock_record(name: &str, has_outputs: bool) -> RecordWrapper {
+    mock_record_with_ids(
+        name,
+        DEFAULT_SESSION_ADDRESS,
+        DEFAULT_RECORD_SPEC_ADDRESS,
+        has_outputs,
+    )
+}
+
+/// A record with the given name, linked to the provided session and record specification.
+pub fn mock_record_with_ids<S1, S2, S3>(
+    name: S1,
+    session_address: S2,
+    record_spec_address: S3,
+    has_outputs: bool,
+) -> RecordWrapper
+where
+    S1: Into<String>,
+    S2: Into<String>,
+    S3: Into<String>,
+{
+    RecordWrapper {
+        record: Some(Record {
+            name: name.into(),
+            session_id: session_address.into().into(),
+            specification_id: record_spec_address.into().into(),
+            process: Some(Process {
+                process_id: Some(ProcessId::Address(DEFAULT_PROCESS_ADDRESS.to_string())),
+                method: DEFAULT_PROCESS_METHOD.to_string(),
+                name: DEFAULT_PROCESS_NAME.to_string(),
+            }),
+            inputs: vec![RecordInput {
+                name: DEFAULT_RECORD_INPUT_NAME.to_string(),
+                type_name: "string".to_string(),
+                source: Some(Source::Hash(
+                    DEFAULT_RECORD_INPUT_SOURCE_ADDRESS.to_string(),
+                )),
+                status: RecordInputStatus::Record.into(),
+            }],
+            outputs: if has_outputs {
+                vec![RecordOutput {
+                    hash: DEFAULT_RECORD_OUTPUT_HASH.to_string(),
+                    status: ResultStatus::Pass.into(),
+                }]
+            } else {
+                vec![]
+            },
+        }),
+        record_id_info: None,
+        record_spec_id_info: None,
+    }
+}
+
+/// The default scope, owned by the provided address.
+pub fn mock_scope_with_owner(owner: &str) -> Scope {
+    get_duped_scope(DEFAULT_SCOPE_ADDRESS, DEFAULT_SCOPE_SPEC_ADDRESS, owner)
+}
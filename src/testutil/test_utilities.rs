@@ -1,38 +1,51 @@
+use std::fmt::Debug;
+
 use cosmwasm_std::{
-    coin,
+    coin, from_json,
     testing::{message_info, mock_env},
-    to_json_vec, Addr, Coin, DepsMut, Env, MessageInfo, OwnedDeps, Response, Uint128,
+    to_json_vec, Addr, Binary, Coin, ContractResult, CosmosMsg, DepsMut, Env, MessageInfo,
+    OwnedDeps, Response, SystemResult, Uint128, WasmMsg,
 };
+use provwasm_std::types::cosmos::base::query::v1beta1::PageResponse;
 use provwasm_std::types::provenance::{
     attribute::v1::{
         Attribute, AttributeType, MsgAddAttributeRequest, MsgUpdateAttributeRequest,
-        QueryAttributeRequest, QueryAttributeResponse, QueryAttributesRequest,
-        QueryAttributesResponse,
+        QueryAttributeAccountsRequest, QueryAttributeAccountsResponse,
     },
     metadata::v1::{
-        process::ProcessId, record_input::Source, Party, PartyType, Process, Record, RecordInput,
-        RecordInputStatus, RecordOutput, RecordWrapper, RecordsRequest, RecordsResponse,
-        ResultStatus, Scope, ScopeRequest, ScopeResponse, ScopeWrapper,
+        Party, PartyType, RecordsRequest, RecordsResponse, Scope, ScopeRequest, ScopeWrapper,
     },
 };
 
+use provwasm_std::types::tendermint::abci::ResponseQuery;
+
 use crate::core::types::subsequent_classification_detail::SubsequentClassificationDetail;
 use crate::core::types::verifier_detail::VerifierDetailV2;
 use crate::core::{
     error::ContractError,
     types::asset_definition::{AssetDefinitionInputV3, AssetDefinitionV3},
 };
+use crate::testutil::attribute_utilities::{
+    mock_attribute_response_for_scope, mock_empty_attribute_response,
+    mock_named_attribute_response_for_scope,
+};
+use crate::testutil::scope_utilities::{
+    mock_record_with_ids, mock_records_response, mock_scope_response, mock_scope_with_owner,
+    mock_scope_wrapper,
+};
 use crate::testutil::test_constants::{DEFAULT_RETRY_COST, DEFAULT_SUBSEQUENT_CLASSIFICATION_COST};
 use crate::util::constants::NHASH;
+use crate::util::functions::generate_os_gateway_grant_id;
 use crate::{
     contract::instantiate,
     core::{
-        msg::InitMsg,
+        msg::{ExecuteMsg, InitMsg},
         types::{
             access_definition::{AccessDefinition, AccessDefinitionType},
             asset_onboarding_status::AssetOnboardingStatus,
             asset_scope_attribute::AssetScopeAttribute,
             entity_detail::EntityDetail,
+            os_gateway_access::OsGatewayAccess,
         },
     },
     util::{functions::generate_asset_attribute_name, traits::OptionExtensions},
@@ -52,11 +65,9 @@ use super::test_constants::{
     DEFAULT_ASSET_TYPE, DEFAULT_ASSET_TYPE_DISPLAY_NAME, DEFAULT_ASSET_UUID,
     DEFAULT_CONTRACT_BASE_NAME, DEFAULT_ENTITY_DETAIL_DESCRIPTION, DEFAULT_ENTITY_DETAIL_HOME_URL,
     DEFAULT_ENTITY_DETAIL_NAME, DEFAULT_ENTITY_DETAIL_SOURCE_URL, DEFAULT_ONBOARDING_COST,
-    DEFAULT_ONBOARDING_DENOM, DEFAULT_PROCESS_ADDRESS, DEFAULT_PROCESS_METHOD,
-    DEFAULT_PROCESS_NAME, DEFAULT_RECORD_INPUT_NAME, DEFAULT_RECORD_INPUT_SOURCE_ADDRESS,
-    DEFAULT_RECORD_NAME, DEFAULT_RECORD_OUTPUT_HASH, DEFAULT_RECORD_SPEC_ADDRESS,
-    DEFAULT_SCOPE_ADDRESS, DEFAULT_SCOPE_SPEC_ADDRESS, DEFAULT_SENDER_ADDRESS,
-    DEFAULT_SESSION_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    DEFAULT_ONBOARDING_DENOM, DEFAULT_RECORD_NAME, DEFAULT_RECORD_SPEC_ADDRESS,
+    DEFAULT_SCOPE_ADDRESS, DEFAULT_SENDER_ADDRESS, DEFAULT_SESSION_ADDRESS,
+    DEFAULT_VERIFIER_ADDRESS,
 };
 
 pub type MockOwnedDeps = OwnedDeps<
@@ -75,6 +86,11 @@ pub fn get_default_asset_definition_input() -> AssetDefinitionInputV3 {
         enabled: None,
         // Specifying None will cause the underlying code to always choose bind_name: true
         bind_name: None,
+        labels: vec![],
+        allowed_requestors: None,
+        max_verifiers: None,
+        scope_spec_address: None,
+        scope_owner_must_match_requestor: None,
     }
 }
 
@@ -104,17 +120,28 @@ pub fn get_default_subsequent_classification_detail() -> SubsequentClassificatio
 pub fn get_default_verifier_detail() -> VerifierDetailV2 {
     VerifierDetailV2 {
         address: DEFAULT_VERIFIER_ADDRESS.into(),
-        onboarding_cost: Uint128::from(DEFAULT_ONBOARDING_COST),
+        onboarding_cost: OnboardingCost::new(DEFAULT_ONBOARDING_COST, &[]),
         onboarding_denom: DEFAULT_ONBOARDING_DENOM.into(),
-        fee_destinations: vec![],
         entity_detail: get_default_entity_detail().to_some(),
         retry_cost: get_default_retry_cost().to_some(),
         subsequent_classification_detail: get_default_subsequent_classification_detail().to_some(),
+        cooldown_seconds: None,
+        max_retries: None,
+        verification_timeout_blocks: None,
+        appeal_window_blocks: None,
+        max_classifications_per_block: None,
+        denial_reason_required: false,
+        enabled: true,
     }
 }
 
 pub fn get_default_asset_definition() -> AssetDefinitionV3 {
-    get_default_asset_definition_input().into_asset_definition()
+    AssetDefinitionV3::builder()
+        .asset_type(DEFAULT_ASSET_TYPE)
+        .display_name(DEFAULT_ASSET_TYPE_DISPLAY_NAME)
+        .add_verifier(get_default_verifier_detail())
+        .build()
+        .expect("the default asset definition should be built")
 }
 
 pub fn get_default_asset_definition_inputs() -> Vec<AssetDefinitionInputV3> {
@@ -150,6 +177,20 @@ pub fn get_default_asset_scope_attribute() -> AssetScopeAttribute {
             access_routes: get_default_access_routes(),
             definition_type: AccessDefinitionType::Requestor,
         }],
+        scope_spec_address: None,
+        retry_count: 0,
+        pending_expiry_block: None,
+        appeal_deadline_block: None,
+        os_gateway_permission_granted: true,
+        os_gateway_grant_id: generate_os_gateway_grant_id(
+            None,
+            DEFAULT_ASSET_TYPE,
+            DEFAULT_SCOPE_ADDRESS,
+        )
+        .to_some(),
+        classification_initiated_at_block: mock_env().block.height,
+        last_modified_block: mock_env().block.height,
+        requestor_metadata: None,
     }
 }
 
@@ -160,6 +201,9 @@ pub struct InstArgs {
     pub bind_base_name: bool,
     pub is_test: bool,
     pub asset_definitions: Vec<AssetDefinitionInputV3>,
+    pub max_access_routes_per_owner: Option<u32>,
+    pub allow_all_bech32_prefixes: bool,
+    pub os_gateway_grant_prefix: Option<String>,
 }
 impl Default for InstArgs {
     fn default() -> Self {
@@ -173,6 +217,9 @@ impl Default for InstArgs {
             // realistic scenarios
             is_test: false,
             asset_definitions: get_default_asset_definition_inputs(),
+            max_access_routes_per_owner: None,
+            allow_all_bech32_prefixes: false,
+            os_gateway_grant_prefix: None,
         }
     }
 }
@@ -207,26 +254,16 @@ pub fn test_instantiate(deps: DepsMut, args: &InstArgs) -> EntryPointResponse {
             bind_base_name: args.bind_base_name,
             asset_definitions: args.asset_definitions.to_owned(),
             is_test: Some(args.is_test),
+            max_access_routes_per_owner: args.max_access_routes_per_owner,
+            allow_all_bech32_prefixes: Some(args.allow_all_bech32_prefixes),
+            os_gateway_grant_prefix: args.os_gateway_grant_prefix.to_owned(),
         },
     )
 }
 
 pub fn setup_test_suite(deps: &mut MockOwnedDeps, args: &InstArgs) {
     test_instantiate_success(deps.as_mut(), args);
-    let default_scope = get_default_scope();
-    ScopeRequest::mock_response(
-        &mut deps.querier,
-        ScopeResponse {
-            scope: Some(ScopeWrapper {
-                scope: Some(default_scope.clone()),
-                scope_id_info: None,
-                scope_spec_id_info: None,
-            }),
-            sessions: vec![],
-            records: vec![],
-            request: None,
-        },
-    );
+    ScopeRequest::mock_response(&mut deps.querier, mock_scope_response(get_default_scope()));
     RecordsRequest::mock_response(&mut deps.querier, get_default_records());
 }
 
@@ -236,24 +273,7 @@ pub fn setup_test_suite(deps: &mut MockOwnedDeps, args: &InstArgs) {
 /// on an asset that would prevent its onboarding. [intercept_add_or_update_attribute] will then update the mock attribute
 /// query result after onboarding to mark the asset as onboarded.
 pub fn setup_no_attribute_response(deps: &mut MockOwnedDeps, address: Option<String>) {
-    QueryAttributeRequest::mock_response(
-        &mut deps.querier,
-        QueryAttributeResponse {
-            account: address
-                .to_owned()
-                .unwrap_or(DEFAULT_SCOPE_ADDRESS.to_string()),
-            attributes: vec![],
-            pagination: None,
-        },
-    );
-    QueryAttributesRequest::mock_response(
-        &mut deps.querier,
-        QueryAttributesResponse {
-            account: address.unwrap_or(DEFAULT_SCOPE_ADDRESS.to_string()),
-            attributes: vec![],
-            pagination: None,
-        },
-    );
+    mock_empty_attribute_response(deps, address.unwrap_or(DEFAULT_SCOPE_ADDRESS.to_string()));
 }
 
 pub fn test_instantiate_success(deps: DepsMut, args: &InstArgs) -> Response {
@@ -265,11 +285,7 @@ pub fn empty_mock_info<S: Into<String>>(sender: S) -> MessageInfo {
 }
 
 pub fn get_default_scope() -> Scope {
-    get_duped_scope(
-        DEFAULT_SCOPE_ADDRESS,
-        DEFAULT_SCOPE_SPEC_ADDRESS,
-        DEFAULT_SENDER_ADDRESS,
-    )
+    mock_scope_with_owner(DEFAULT_SENDER_ADDRESS)
 }
 
 pub fn get_default_records() -> RecordsResponse {
@@ -305,6 +321,77 @@ pub fn single_attribute_for_key<'a, T>(response: &'a Response<T>, key: &'a str)
         .as_str()
 }
 
+/// Asserts that the response contains exactly the expected number of messages, including each
+/// actual message in the failure output to make mismatches easier to diagnose.
+pub fn assert_response_messages_count<T: Debug>(
+    response: &Response<T>,
+    expected: usize,
+    context: &str,
+) {
+    if response.messages.len() != expected {
+        panic!(
+            "{}: expected {} message(s), but found {}:\n{}",
+            context,
+            expected,
+            response.messages.len(),
+            response
+                .messages
+                .iter()
+                .enumerate()
+                .map(|(index, msg)| format!("  [{}] {:?}", index, msg))
+                .collect::<Vec<String>>()
+                .join("\n"),
+        );
+    }
+}
+
+/// Asserts that the response contains an attribute with the given key and value, including all of
+/// the response's attributes in the failure output when no match is found.
+pub fn assert_response_attributes_contain<T>(
+    response: &Response<T>,
+    key: &str,
+    expected_value: &str,
+) {
+    if !response
+        .attributes
+        .iter()
+        .any(|attr| attr.key == key && attr.value == expected_value)
+    {
+        panic!(
+            "expected an attribute with key [{}] and value [{}], but found:\n{}",
+            key,
+            expected_value,
+            response
+                .attributes
+                .iter()
+                .map(|attr| format!("  {} = {}", attr.key, attr.value))
+                .collect::<Vec<String>>()
+                .join("\n"),
+        );
+    }
+}
+
+/// Collects the object store gateway access changes relayed by the contract executing its own
+/// [RelayOsGatewayAccess](crate::core::msg::ExecuteMsg::RelayOsGatewayAccess) route in the
+/// response's messages, in the order that they are executed.
+pub fn get_relayed_os_gateway_access(response: &Response) -> Vec<OsGatewayAccess> {
+    response
+        .messages
+        .iter()
+        .filter_map(|message| match &message.msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+                match from_json::<ExecuteMsg>(msg)
+                    .expect("the contract execution should deserialize")
+                {
+                    ExecuteMsg::RelayOsGatewayAccess { access } => access.to_some(),
+                    msg => panic!("unexpected contract execution: {msg:?}"),
+                }
+            }
+            _ => None,
+        })
+        .collect()
+}
+
 pub fn get_duped_scope<S1: Into<String>, S2: Into<String>, S3: Into<String>>(
     scope_id: S1,
     spec_id: S2,
@@ -338,34 +425,12 @@ where
 {
     RecordsResponse {
         scope,
-        sessions: vec![],
-        request: None,
-        records: vec![RecordWrapper {
-            record: Some(Record {
-                name: record_name.into(),
-                session_id: session_address.into().into(),
-                specification_id: record_spec_address.into().into(),
-                process: Some(Process {
-                    process_id: Some(ProcessId::Address(DEFAULT_PROCESS_ADDRESS.to_string())),
-                    method: DEFAULT_PROCESS_METHOD.to_string(),
-                    name: DEFAULT_PROCESS_NAME.to_string(),
-                }),
-                inputs: vec![RecordInput {
-                    name: DEFAULT_RECORD_INPUT_NAME.to_string(),
-                    type_name: "string".to_string(),
-                    source: Some(Source::Hash(
-                        DEFAULT_RECORD_INPUT_SOURCE_ADDRESS.to_string(),
-                    )),
-                    status: RecordInputStatus::Record.into(),
-                }],
-                outputs: vec![RecordOutput {
-                    hash: DEFAULT_RECORD_OUTPUT_HASH.to_string(),
-                    status: ResultStatus::Pass.into(),
-                }],
-            }),
-            record_id_info: None,
-            record_spec_id_info: None,
-        }],
+        ..mock_records_response(vec![mock_record_with_ids(
+            record_name,
+            session_address,
+            record_spec_address,
+            true,
+        )])
     }
 }
 
@@ -384,6 +449,8 @@ pub fn get_duped_fee_payment_detail<S: Into<String>>(scope_address: S) -> FeePay
                 recipient: Addr::unchecked(DEFAULT_VERIFIER_ADDRESS),
             },
         ],
+        created_at: mock_env().block.time.seconds(),
+        created_at_block: mock_env().block.height,
     }
 }
 
@@ -395,16 +462,7 @@ pub fn mock_scope<S1: Into<String>, S2: Into<String>, S3: Into<String>>(
 ) {
     ScopeRequest::mock_response(
         &mut deps.querier,
-        ScopeResponse {
-            scope: Some(ScopeWrapper {
-                scope: Some(get_duped_scope(scope_id, spec_id, owner_address)),
-                scope_id_info: None,
-                scope_spec_id_info: None,
-            }),
-            sessions: vec![],
-            records: vec![],
-            request: None,
-        },
+        mock_scope_response(get_duped_scope(scope_id, spec_id, owner_address)),
     );
 }
 
@@ -422,11 +480,7 @@ pub fn mock_record<S1, S2, S3>(
     RecordsRequest::mock_response(
         &mut deps.querier,
         get_duped_records(
-            Some(ScopeWrapper {
-                scope: Some(scope),
-                scope_id_info: None,
-                scope_spec_id_info: None,
-            }),
+            Some(mock_scope_wrapper(scope)),
             record_name,
             session_address,
             scope_spec_address,
@@ -452,16 +506,71 @@ pub fn mock_single_scope_attribute<S: Into<String>>(
     scope_address: S,
 ) {
     let address: String = scope_address.into();
-    QueryAttributesRequest::mock_response(
-        &mut deps.querier,
-        QueryAttributesResponse {
-            account: address.to_owned(),
-            attributes: vec![build_attribute(address, attribute)],
-            pagination: None,
-        },
+    let attributes = vec![build_attribute(&address, attribute)];
+    mock_attribute_response_for_scope(deps, address, attributes);
+}
+
+/// Sets up mock queries such that querying for the accounts holding an attribute returns the
+/// provided accounts, split into pages of the given size.  Each page's key is the index of its first
+/// account, mirroring the opaque keys returned by the Provenance Blockchain Attribute Module.
+pub fn mock_attribute_accounts_response(
+    deps: &mut MockOwnedDeps,
+    accounts: Vec<String>,
+    page_size: usize,
+) {
+    deps.querier.registered_custom_queries.insert(
+        "/provenance.attribute.v1.Query/AttributeAccounts".to_string(),
+        Box::new(move |data| {
+            let request = QueryAttributeAccountsRequest::try_from(data.to_owned())
+                .expect("the attribute accounts request should decode");
+            let start = request
+                .pagination
+                .map(|pagination| pagination.key)
+                .filter(|key| !key.is_empty())
+                .map(|key| {
+                    String::from_utf8(key)
+                        .expect("the page key should be valid utf8")
+                        .parse::<usize>()
+                        .expect("the page key should be a valid index")
+                })
+                .unwrap_or_default();
+            let end = (start + page_size).min(accounts.len());
+            let response = QueryAttributeAccountsResponse {
+                accounts: accounts[start..end].to_vec(),
+                pagination: PageResponse {
+                    next_key: Some(end)
+                        .filter(|end| *end < accounts.len())
+                        .map(|end| end.to_string().into_bytes()),
+                    total: 0,
+                }
+                .to_some(),
+            };
+            SystemResult::Ok(ContractResult::Ok(to_query_response_binary(
+                response.to_proto_bytes(),
+            )))
+        }),
     );
 }
 
+/// Wraps the given proto bytes in the query response envelope expected by the mock querier's
+/// registered custom queries.
+pub fn to_query_response_binary(value: Vec<u8>) -> Binary {
+    Binary::new(
+        ResponseQuery {
+            code: 0,
+            log: String::new(),
+            info: String::new(),
+            index: 0,
+            key: vec![],
+            value,
+            proof_ops: None,
+            height: 0,
+            codespace: String::new(),
+        }
+        .to_proto_bytes(),
+    )
+}
+
 pub fn assert_single_item<T: Clone, S: Into<String>>(slice: &[T], message: S) -> T {
     assert_eq!(1, slice.len(), "{}", message.into());
     slice.first().unwrap().clone()
@@ -530,19 +639,16 @@ pub fn intercept_add_or_update_attribute<S: Into<String>>(
         }) = params
         {
             // inject bound name into provmock querier
-            QueryAttributeRequest::mock_response(
-                &mut deps.querier,
-                QueryAttributeResponse {
-                    account: address.to_string(),
-                    attributes: vec![Attribute {
-                        name: name.to_string(),
-                        value,
-                        attribute_type: AttributeType::Json.into(),
-                        address: address.to_string(),
-                        expiration_date: None,
-                    }],
-                    pagination: None,
-                },
+            mock_named_attribute_response_for_scope(
+                deps,
+                &address,
+                vec![Attribute {
+                    name: name.to_string(),
+                    value,
+                    attribute_type: AttributeType::Json.into(),
+                    address: address.to_string(),
+                    expiration_date: None,
+                }],
             );
             // After finding the an add or update attribute message, exit to avoid panics
             return Ok(response);
@@ -11,6 +11,8 @@ pub const ASSET_REGISTERED_KEY: &str = "asset_registered";
 pub const SCOPE_ID_KEY: &str = "asset_related_scope_id";
 /// Value = The scope owner that sent the onboarding message.
 pub const SCOPE_OWNER_KEY: &str = "asset_scope_owner_address";
+/// Value = An optional memo provided by the requestor when onboarding the asset (String).
+pub const ONBOARDING_MEMO_KEY: &str = "asset_onboarding_memo";
 
 //////////////////////////////
 // Shared output attributes //
@@ -30,6 +32,15 @@ pub const NEW_ASSET_ONBOARDING_STATUS_KEY: &str = "asset_onboarding_status";
 pub const NEW_VALUE_KEY: &str = "asset_new_value";
 /// Value = EventAdditionalMetadata meta string.
 pub const ADDITIONAL_METADATA_KEY: &str = "asset_additional_metadata";
+/// Value = The asset type that should be used in place of a deprecated asset type (String).
+pub const REPLACEMENT_ASSET_TYPE_KEY: &str = "asset_replacement_type";
+
+/////////////////////////////////////
+// Instantiation output attributes //
+/////////////////////////////////////
+
+/// Value = Whether or not the base contract name was already bound to the contract before instantiation (bool).
+pub const BASE_NAME_ALREADY_BOUND_KEY: &str = "base_name_already_bound";
 
 //////////////////////
 // Global Constants //
@@ -37,6 +48,135 @@ pub const ADDITIONAL_METADATA_KEY: &str = "asset_additional_metadata";
 /// A constant declaration to ensure the word "nhash" does not have typos when used throughout the
 /// contract's source.
 pub const NHASH: &str = "nhash";
+/// The number of decimal places between the nhash denomination and its display denomination, hash.
+pub const NHASH_DENOM_DECIMALS: u8 = 9;
+/// The maximum number of requests that can be resolved in a single [QueryAssetScopeAttributesBatch](crate::core::msg::QueryMsg::QueryAssetScopeAttributesBatch)
+/// query.
+pub const MAX_SCOPE_ATTRIBUTE_BATCH_SIZE: usize = 50;
+/// The maximum number of entries that can be onboarded in a single [BatchOnboardAsset](crate::core::msg::ExecuteMsg::BatchOnboardAsset)
+/// request, protecting the transaction from exhausting its gas.
+pub const MAX_BATCH_ONBOARD_SIZE: usize = 25;
+/// The maximum number of characters allowed in the memo of an [OnboardAsset](crate::core::msg::ExecuteMsg::OnboardAsset)
+/// request, keeping the emitted event attribute reasonably sized.
+pub const MAX_ONBOARDING_MEMO_LENGTH: usize = 256;
+/// The maximum number of bytes allowed in the metadata of an [OnboardAsset](crate::core::msg::ExecuteMsg::OnboardAsset)
+/// request, keeping the stored [AssetScopeAttribute](crate::core::types::asset_scope_attribute::AssetScopeAttribute)
+/// reasonably sized.
+pub const MAX_REQUESTOR_METADATA_LENGTH: usize = 1024;
+/// The maximum number of characters allowed in the [display_name](crate::core::types::fee_destination::FeeDestinationV2::display_name)
+/// of a fee destination, keeping the generated fee names reasonably sized.
+pub const MAX_FEE_DESTINATION_DISPLAY_NAME_LENGTH: usize = 100;
+/// The default value of the contract's [max_access_routes_per_owner](crate::core::state::StateV2::max_access_routes_per_owner)
+/// when none is provided at instantiation, as well as for contracts instantiated before the limit
+/// existed.
+pub const DEFAULT_MAX_ACCESS_ROUTES_PER_OWNER: u32 = 10;
+/// The maximum number of bytes allowed in the [route](crate::core::types::access_route::AccessRoute::route)
+/// of an access route.  Longer routes are dropped before being stored.
+pub const MAX_ACCESS_ROUTE_LENGTH: usize = 2048;
+/// The maximum number of bytes allowed in the [name](crate::core::types::access_route::AccessRoute::name)
+/// of an access route.  Routes with longer names are dropped before being stored.
+pub const MAX_ACCESS_ROUTE_NAME_LENGTH: usize = 128;
+/// The maximum number of entries that can be verified in a single [BatchVerifyAsset](crate::core::msg::ExecuteMsg::BatchVerifyAsset)
+/// request, protecting the transaction from exhausting its gas.
+pub const MAX_BATCH_VERIFY_SIZE: usize = 25;
+/// The maximum number of asset types that can be deleted in a single [BulkDeleteAssetDefinitions](crate::core::msg::ExecuteMsg::BulkDeleteAssetDefinitions)
+/// request.
+pub const MAX_BULK_DELETE_SIZE: usize = 10;
+/// The maximum number of asset definitions that can be returned in a single [QueryAssetDefinitionsPage](crate::core::msg::QueryMsg::QueryAssetDefinitionsPage)
+/// query.  Larger requested page sizes are reduced to this value, and it is used as the page size
+/// when none is requested.
+pub const MAX_DEFINITIONS_PER_PAGE: u32 = 100;
+/// The maximum number of scopes that can be returned in a single [QueryAssetsByRequestor](crate::core::msg::QueryMsg::QueryAssetsByRequestor)
+/// query.  Larger requested limits are reduced to this value, and it is used as the limit when none
+/// is requested.
+pub const MAX_REQUESTOR_ASSETS_PER_PAGE: u32 = 30;
+/// The maximum number of scopes that can be returned in a single [QueryPendingVerificationsByVerifier](crate::core::msg::QueryMsg::QueryPendingVerificationsByVerifier)
+/// query.  Larger requested limits are reduced to this value, and it is used as the limit when none
+/// is requested.
+pub const MAX_PENDING_VERIFICATIONS_PER_PAGE: u32 = 30;
+/// The maximum number of verifier statistics that can be returned in a single [QueryAllVerifierStatistics](crate::core::msg::QueryMsg::QueryAllVerifierStatistics)
+/// query.  Larger requested limits are reduced to this value, and it is used as the limit when none
+/// is requested.
+pub const MAX_VERIFIER_STATISTICS_PER_PAGE: u32 = 30;
+/// The maximum number of scopes that can be returned in a single [QueryAccessRoutesByOwner](crate::core::msg::QueryMsg::QueryAccessRoutesByOwner)
+/// query.  Larger requested limits are reduced to this value, and it is used as the limit when none
+/// is requested.
+pub const MAX_ACCESS_ROUTE_OWNER_SCOPES_PER_PAGE: u32 = 30;
+/// The maximum number of characters allowed in the contract's [base_contract_name](crate::core::msg::InitMsg::base_contract_name),
+/// in line with the restrictions of the Provenance Blockchain Name Module.
+pub const MAX_BASE_CONTRACT_NAME_LENGTH: usize = 256;
+/// The maximum number of characters allowed in each dot-separated segment of the contract's
+/// [base_contract_name](crate::core::msg::InitMsg::base_contract_name), in line with the
+/// restrictions of the Provenance Blockchain Name Module.
+pub const MAX_BASE_CONTRACT_NAME_SEGMENT_LENGTH: usize = 64;
+/// The maximum age, in seconds, that a [FeePaymentDetail](crate::core::types::fee_payment_detail::FeePaymentDetail)
+/// can reach before it is considered expired and can no longer be used to verify an asset.  One week.
+pub const MAX_FEE_PAYMENT_DETAIL_SECONDS: u64 = 604800;
 /// All denominations of coin that are valid for a verifier detail to include in its [onboarding_denom](crate::core::types::verifier_detail::VerifierDetailV2::onboarding_denom)
 /// field.
 pub const VALID_VERIFIER_DENOMS: [&str; 1] = [NHASH];
+/// All bech32 prefixes that are valid for a verifier detail's [address](crate::core::types::verifier_detail::VerifierDetailV2::address)
+/// and its fee destinations' [addresses](crate::core::types::fee_destination::FeeDestinationV2::address),
+/// unless the contract [allows all bech32 prefixes](crate::core::state::StateV2::allow_all_bech32_prefixes).
+pub const VALID_VERIFIER_ADDRESS_PREFIXES: [&str; 2] = ["tp", "pb"];
+
+/// Converts an amount of nhash to its equivalent amount of hash.  Intended only for human-readable
+/// display values, like test assertion messages, due to the imprecision of floating point numbers.
+///
+/// # Parameters
+///
+/// * `nhash` The amount of nhash to convert.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn nhash_to_hash(nhash: u128) -> f64 {
+    nhash as f64 / 1_000_000_000.0
+}
+
+/// Formats an amount of nhash as a human-readable amount of hash, including every decimal place.
+/// For instance, an input of `1000000000` will produce `"1.000000000 HASH"`.
+///
+/// # Parameters
+///
+/// * `nhash` The amount of nhash to format.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn format_nhash(nhash: u128) -> String {
+    let nhash_per_hash = 10u128.pow(NHASH_DENOM_DECIMALS as u32);
+    format!(
+        "{}.{:0width$} HASH",
+        nhash / nhash_per_hash,
+        nhash % nhash_per_hash,
+        width = NHASH_DENOM_DECIMALS as usize,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::constants::{format_nhash, nhash_to_hash};
+
+    #[test]
+    fn test_nhash_to_hash() {
+        assert_eq!(
+            1.5,
+            nhash_to_hash(1_500_000_000),
+            "nhash should be converted to hash by shifting nine decimal places",
+        );
+    }
+
+    #[test]
+    fn test_format_nhash() {
+        assert_eq!(
+            "1.000000000 HASH",
+            format_nhash(1_000_000_000),
+            "a single hash should be formatted with all decimal places",
+        );
+        assert_eq!(
+            "0.000000150 HASH",
+            format_nhash(150),
+            "amounts less than a single hash should be formatted with leading zeroes",
+        );
+        assert_eq!(
+            "30.000000000 HASH",
+            format_nhash(30_000_000_000),
+            "amounts greater than a single hash should be formatted correctly",
+        );
+    }
+}
@@ -1,7 +1,12 @@
-use crate::core::{error::ContractError, state::STATE_V2};
+use crate::core::{
+    error::ContractError,
+    state::{is_contract_paused, StateV2, STATE_V2},
+    types::{asset_scope_attribute::AssetScopeAttribute, os_gateway_access::OsGatewayAccess},
+};
 use crate::util::aliases::AssetResult;
+use crate::util::traits::OptionExtensions;
 
-use cosmwasm_std::{Addr, Deps, MessageInfo};
+use cosmwasm_std::{Addr, Attribute, CosmosMsg, Deps, Env, MessageInfo, Storage};
 use provwasm_std::types::provenance::msgfees::v1::MsgAssessCustomMsgFeeRequest;
 use result_extensions::ResultExtensions;
 
@@ -24,9 +29,10 @@ use super::functions::validate_address;
 /// use cosmwasm_std::testing::mock_info;
 /// use provwasm_mocks::mock_provenance_dependencies;
 /// use asset_classification_smart_contract::core::state::{STATE_V2, StateV2};
+/// use asset_classification_smart_contract::core::types::contract_metrics::ContractMetrics;
 ///
 /// let mut deps = mock_provenance_dependencies();
-/// STATE_V2.save(deps.as_mut().storage, &StateV2 { base_contract_name: "contract-name".to_string(), admin: Addr::unchecked("admin-name"), is_test: false })
+/// STATE_V2.save(deps.as_mut().storage, &StateV2 { base_contract_name: "contract-name".to_string(), admin: Addr::unchecked("admin-name"), is_test: false, asset_definition_count: 0, debug_query_enabled: false, verification_timeout_seconds: None, metrics: ContractMetrics::default(), pending_admin: None, fee_detail_max_age_blocks: None, max_access_routes_per_owner: 10, allow_all_bech32_prefixes: false, require_entity_detail_for_verifiers: false, os_gateway_grant_prefix: None })
 ///     .expect("expected state to save successfully");
 /// let info = mock_info("admin-name", &[]);
 /// check_admin_only(&deps.as_ref(), &info).expect("admin-name was used as the admin and should return a success");
@@ -66,6 +72,118 @@ pub fn check_funds_are_empty(info: &MessageInfo) -> AssetResult<()> {
     }
 }
 
+/// Ensures that the contract has not been paused via the [sudo](crate::contract::sudo) entrypoint
+/// before allowing a route to proceed.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+pub fn check_contract_not_paused(deps: &Deps) -> AssetResult<()> {
+    if is_contract_paused(deps.storage)? {
+        ContractError::UnexpectedState {
+            explanation: "contract is paused".to_string(),
+        }
+        .to_err()
+    } else {
+        Ok(())
+    }
+}
+
+/// Ensures that [debug_query_enabled](crate::core::state::StateV2::debug_query_enabled) is set in the
+/// contract's state before allowing a debugging query to proceed.
+///
+/// # Parameters
+///
+/// * `state` The contract's current state.
+pub fn check_debug_queries_enabled(state: &StateV2) -> AssetResult<()> {
+    if state.debug_query_enabled {
+        Ok(())
+    } else {
+        ContractError::Unauthorized {
+            explanation: "debug queries are not enabled for this contract".to_string(),
+        }
+        .to_err()
+    }
+}
+
+/// Produces the [Object Store Gateway](https://github.com/FigureTechnologies/object-store-gateway)
+/// revocation of the verifier's access to the scope of the provided attribute.  `None` is returned
+/// if access was never granted when the scope was onboarded.  The [grant id](crate::core::types::asset_scope_attribute::AssetScopeAttribute::os_gateway_grant_id)
+/// recorded during onboarding is used when present.  Attributes onboarded before it was recorded
+/// fall back to an id derived from the contract's current [os_gateway_grant_prefix](crate::core::state::StateV2::os_gateway_grant_prefix).
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+/// * `attribute` The scope attribute of the verification that no longer requires gateway access.
+pub fn get_os_gateway_access_revoke(
+    storage: &dyn Storage,
+    attribute: &AssetScopeAttribute,
+) -> AssetResult<Option<OsGatewayAccess>> {
+    if !attribute.os_gateway_permission_granted {
+        return None.to_ok();
+    }
+    // The contract's state is only needed when no grant id was recorded during onboarding
+    let fallback_prefix = if attribute.os_gateway_grant_id.is_none() {
+        STATE_V2.load(storage)?.os_gateway_grant_prefix
+    } else {
+        None
+    };
+    OsGatewayAccess::revoke(
+        &attribute.scope_address,
+        attribute.verifier_address.as_str(),
+        attribute.get_os_gateway_grant_id(fallback_prefix.as_deref()),
+    )
+    .to_some()
+    .to_ok()
+}
+
+/// Generates the [Object Store Gateway](https://github.com/FigureTechnologies/object-store-gateway)
+/// attributes that revoke the verifier's access to the scope of the provided attribute, as
+/// produced by [get_os_gateway_access_revoke](self::get_os_gateway_access_revoke).  No attributes
+/// are generated if access was never granted when the scope was onboarded.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+/// * `attribute` The scope attribute of the verification that no longer requires gateway access.
+pub fn generate_os_gateway_access_revoke(
+    storage: &dyn Storage,
+    attribute: &AssetScopeAttribute,
+) -> AssetResult<Vec<Attribute>> {
+    get_os_gateway_access_revoke(storage, attribute)?
+        .map(|access_revoke| access_revoke.to_attributes())
+        .unwrap_or_default()
+        .to_ok()
+}
+
+/// Separates the [Object Store Gateway](https://github.com/FigureTechnologies/object-store-gateway)
+/// grant or revocation from the attributes produced for a single entry of a batch route.  The
+/// gateway only reads one grant or revocation from each `wasm` event, so the entries of a batch
+/// cannot each emit their own in the batch's response.  Instead, the grant or revocation is
+/// returned as a message that executes the [RelayOsGatewayAccess](crate::core::msg::ExecuteMsg::RelayOsGatewayAccess)
+/// route, along with the remaining attributes.
+///
+/// # Parameters
+///
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `attributes` The attributes produced for a single entry of a batch route.
+pub fn relay_os_gateway_attributes(
+    env: &Env,
+    attributes: Vec<Attribute>,
+) -> AssetResult<(Vec<Attribute>, Option<CosmosMsg>)> {
+    let (gateway_attributes, attributes): (Vec<Attribute>, Vec<Attribute>) = attributes
+        .into_iter()
+        .partition(|attribute| OsGatewayAccess::is_gateway_key(&attribute.key));
+    let relay_msg = match OsGatewayAccess::from_attributes(&gateway_attributes) {
+        Some(access) => access.to_relay_msg(env)?.to_some(),
+        None => None,
+    };
+    (attributes, relay_msg).to_ok()
+}
+
 /// Creates a message for charging a custom fee.
 ///
 /// # Parameters
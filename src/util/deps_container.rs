@@ -1,6 +1,10 @@
 use std::cell::RefCell;
 
 use cosmwasm_std::DepsMut;
+use result_extensions::ResultExtensions;
+
+use crate::core::error::ContractError;
+use crate::util::aliases::AssetResult;
 
 /// Holds a ref cell to a DepsMut, which allows it to be passed to sub-objects
 /// relatively easily and then freed when required.
@@ -44,6 +48,26 @@ impl<'a> DepsContainer<'a> {
         deps_fn(&mut self.deps_cell.borrow_mut())
     }
 
+    /// Allows the encapsulated DepsMut value to be used while the service owns it, without
+    /// panicking if the value is already in use.  This can occur when the container is accessed
+    /// from within another usage's closure.
+    ///
+    /// # Parameters
+    ///
+    /// * `deps_fn` A closure that utilizes the internally-held [DepsMut] reference
+    pub fn try_use_deps<T, F>(&self, mut deps_fn: F) -> AssetResult<T>
+    where
+        F: FnMut(&mut DepsMut) -> T,
+    {
+        let mut deps =
+            self.deps_cell
+                .try_borrow_mut()
+                .map_err(|_| ContractError::InternalError {
+                    message: "deps already borrowed".to_string(),
+                })?;
+        deps_fn(&mut deps).to_ok()
+    }
+
     /// Relinquishes the held DepsMut to the caller with a move.
     pub fn get(self) -> DepsMut<'a> {
         self.deps_cell.into_inner()
@@ -56,7 +80,7 @@ mod tests {
     use provwasm_mocks::mock_provenance_dependencies;
 
     use crate::{
-        core::state::STATE_V2,
+        core::{error::ContractError, state::STATE_V2},
         testutil::test_utilities::{test_instantiate_success, InstArgs},
     };
 
@@ -98,4 +122,33 @@ mod tests {
         let container = DepsContainer::new(deps);
         container.get()
     }
+
+    #[test]
+    fn test_try_use_deps_success() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_success(deps.as_mut(), &InstArgs::default());
+        let container = DepsContainer::new(deps.as_mut());
+        container
+            .try_use_deps(|deps_mut| STATE_V2.load(deps_mut.storage))
+            .expect("the deps should be available when they are not already in use")
+            .expect("expected state to load successfully");
+    }
+
+    #[test]
+    fn test_try_use_deps_when_already_borrowed() {
+        let mut deps = mock_provenance_dependencies();
+        let container = DepsContainer::new(deps.as_mut());
+        let error = container
+            .use_deps(|_| container.try_use_deps(|_| ()))
+            .expect_err("a re-entrant usage of the deps should produce an error");
+        match error {
+            ContractError::InternalError { message } => {
+                assert_eq!(
+                    "deps already borrowed", message,
+                    "the error should indicate that the deps were already in use",
+                );
+            }
+            e => panic!("unexpected error encountered: {:?}", e),
+        };
+    }
 }
@@ -1,9 +1,11 @@
 use super::constants::{
-    ASSET_EVENT_TYPE_KEY, ASSET_SCOPE_ADDRESS_KEY, ASSET_TYPE_KEY, NEW_VALUE_KEY, SCOPE_OWNER_KEY,
-    VERIFIER_ADDRESS_KEY,
+    ASSET_EVENT_TYPE_KEY, ASSET_SCOPE_ADDRESS_KEY, ASSET_TYPE_KEY, NEW_VALUE_KEY,
+    ONBOARDING_MEMO_KEY, REPLACEMENT_ASSET_TYPE_KEY, SCOPE_OWNER_KEY, VERIFIER_ADDRESS_KEY,
 };
 use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
-use crate::util::constants::{ADDITIONAL_METADATA_KEY, NEW_ASSET_ONBOARDING_STATUS_KEY};
+use crate::util::constants::{
+    ADDITIONAL_METADATA_KEY, BASE_NAME_ALREADY_BOUND_KEY, NEW_ASSET_ONBOARDING_STATUS_KEY,
+};
 use std::collections::HashMap;
 
 /// An enum that contains all different event types that can occur throughout the [contract's](crate::contract)
@@ -15,22 +17,84 @@ pub enum EventType {
     MigrateContract,
     /// Occurs when the contract is [executed](crate::contract::execute) to [onboard an asset](crate::execute::onboard_asset).
     OnboardAsset,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [onboard many assets](crate::execute::batch_onboard_asset).
+    BatchOnboardAsset,
     /// Occurs when the contract is [executed](crate::contract::execute) to [verify an asset](crate::execute::verify_asset).
     VerifyAsset,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [verify many assets](crate::execute::batch_verify_asset).
+    BatchVerifyAsset,
     /// Occurs when the contract is [executed](crate::contract::execute) to [add an asset definition](crate::execute::add_asset_definition).
     AddAssetDefinition,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [clone an asset definition](crate::execute::clone_asset_definition).
+    CloneAssetDefinition,
     /// Occurs when the contract is [executed](crate::contract::execute) to [update an asset definition](crate::execute::update_asset_definition).
     UpdateAssetDefinition,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [update an asset definition's description](crate::execute::update_asset_definition_description).
+    UpdateAssetDefinitionDescription,
     /// Occurs when the contract is [executed](crate::contract::execute) to [toggle an asset definition](crate::execute::toggle_asset_definition).
     ToggleAssetDefinition,
     /// Occurs when the contract is [executed](crate::contract::execute) to [add an asset verifier detail](crate::execute::add_asset_verifier).
     AddAssetVerifier,
     /// Occurs when the contract is [executed](crate::contract::execute) to [update an asset verifier detail](crate::execute::update_asset_verifier).
     UpdateAssetVerifier,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [toggle an asset verifier detail](crate::execute::toggle_asset_verifier).
+    ToggleAssetVerifier,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [update a verifier's entity detail](crate::execute::update_verifier_entity_detail).
+    UpdateVerifierEntityDetail,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [rotate a verifier's address](crate::execute::rotate_verifier_address).
+    VerifierRotated,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [update the allowed requestors of an asset definition](crate::execute::update_allowed_requestors).
+    UpdateAllowedRequestors,
     /// Occurs when the contract is [executed](crate::contract::execute) to [update access routes](crate::execute::update_access_routes).
     UpdateAccessRoutes,
     /// Occurs when the contract is [executed](crate::contract::execute) to [delete an asset definition](crate::execute::delete_asset_definition).
     DeleteAssetDefinition,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [delete many asset definitions](crate::execute::bulk_delete_asset_definitions).
+    BulkDeleteAssetDefinitions,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [freeze an asset definition](crate::execute::freeze_asset_definition).
+    FreezeAssetDefinition,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [deprecate an asset definition](crate::execute::deprecate_asset_definition).
+    DeprecateAssetDefinition,
+    /// Occurs when an [onboard asset](crate::execute::onboard_asset) request uses an asset type whose
+    /// definition has been [deprecated](crate::core::types::asset_definition::AssetDefinitionV3::deprecated).
+    DeprecatedTypeUsed,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [purge a fee payment detail](crate::execute::purge_fee_payment_detail).
+    PurgeFeePaymentDetail,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [purge a stale fee payment detail](crate::execute::purge_stale_fee_detail::purge_stale_fee_detail).
+    PurgeStaleFeeDetail,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [set the fee detail max age](crate::execute::purge_stale_fee_detail::set_fee_detail_max_age).
+    SetFeeDetailMaxAge,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [set the maximum access routes per owner](crate::execute::update_access_routes::set_max_access_routes_per_owner).
+    SetMaxAccessRoutesPerOwner,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [set whether or not verifiers require entity details](crate::execute::update_verifier_entity_detail::set_require_entity_detail).
+    SetRequireEntityDetail,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [update the Object Store Gateway grant id prefix](crate::execute::update_os_gateway_grant_prefix).
+    UpdateOsGatewayGrantPrefix,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [nominate a new admin](crate::execute::transfer_admin).
+    TransferAdmin,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [accept an admin transfer](crate::execute::transfer_admin).
+    AcceptAdminTransfer,
+    /// Occurs when an [onboard asset](crate::execute::onboard_asset) request automatically denies a
+    /// pending verification that has exceeded the contract's [verification timeout](crate::core::state::StateV2::verification_timeout_seconds).
+    VerificationTimeout,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [expire a stale verification](crate::execute::expire_stale_verification).
+    VerificationExpired,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [appeal a denied verification](crate::execute::appeal_verification).
+    VerificationAppealed,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [resolve an appeal](crate::execute::resolve_appeal).
+    AppealResolved,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [cancel a pending onboarding](crate::execute::cancel_pending_onboarding).
+    CancelledOnboarding,
+    /// Occurs when the contract is [executed](crate::contract::execute) by itself to [relay object store gateway access](crate::execute::relay_os_gateway_access).
+    RelayOsGatewayAccess,
+    /// Occurs when the contract is [sudoed](crate::contract::sudo) to [pause the contract](crate::sudo::pause_contract).
+    PauseContract,
+    /// Occurs when the contract is [sudoed](crate::contract::sudo) to [unpause the contract](crate::sudo::pause_contract).
+    UnpauseContract,
+    /// Occurs when the contract is [sudoed](crate::contract::sudo) to [force delete an asset definition](crate::sudo::force_delete_asset_definition).
+    ForceDeleteAssetDefinition,
+    /// Occurs when the contract is [sudoed](crate::contract::sudo) to [override the admin](crate::sudo::override_admin).
+    OverrideAdmin,
 }
 #[allow(clippy::from_over_into)]
 impl Into<String> for EventType {
@@ -39,14 +103,44 @@ impl Into<String> for EventType {
             EventType::InstantiateContract => "instantiate_contract",
             EventType::MigrateContract => "migrate_contract",
             EventType::OnboardAsset => "onboard_asset",
+            EventType::BatchOnboardAsset => "batch_onboard_asset",
+            EventType::BatchVerifyAsset => "batch_verify_asset",
             EventType::VerifyAsset => "verify_asset",
             EventType::AddAssetDefinition => "add_asset_definition",
+            EventType::CloneAssetDefinition => "clone_asset_definition",
             EventType::UpdateAssetDefinition => "update_asset_definition",
+            EventType::UpdateAssetDefinitionDescription => "update_asset_definition_description",
             EventType::ToggleAssetDefinition => "toggle_asset_definition",
             EventType::AddAssetVerifier => "add_asset_verifier",
             EventType::UpdateAssetVerifier => "update_asset_verifier",
+            EventType::ToggleAssetVerifier => "toggle_asset_verifier",
+            EventType::UpdateVerifierEntityDetail => "update_verifier_entity_detail",
+            EventType::VerifierRotated => "verifier_rotated",
+            EventType::UpdateAllowedRequestors => "update_allowed_requestors",
             EventType::UpdateAccessRoutes => "update_access_routes",
             EventType::DeleteAssetDefinition => "delete_asset_definition",
+            EventType::BulkDeleteAssetDefinitions => "bulk_delete_asset_definitions",
+            EventType::FreezeAssetDefinition => "freeze_asset_definition",
+            EventType::DeprecateAssetDefinition => "deprecate_asset_definition",
+            EventType::DeprecatedTypeUsed => "deprecated_type_used",
+            EventType::PurgeFeePaymentDetail => "purge_fee_payment_detail",
+            EventType::PurgeStaleFeeDetail => "purge_stale_fee_detail",
+            EventType::SetFeeDetailMaxAge => "set_fee_detail_max_age",
+            EventType::SetMaxAccessRoutesPerOwner => "set_max_access_routes_per_owner",
+            EventType::SetRequireEntityDetail => "set_require_entity_detail",
+            EventType::UpdateOsGatewayGrantPrefix => "update_os_gateway_grant_prefix",
+            EventType::TransferAdmin => "transfer_admin",
+            EventType::AcceptAdminTransfer => "accept_admin_transfer",
+            EventType::VerificationTimeout => "verification_timeout",
+            EventType::VerificationExpired => "verification_expired",
+            EventType::VerificationAppealed => "verification_appealed",
+            EventType::AppealResolved => "appeal_resolved",
+            EventType::CancelledOnboarding => "cancelled_onboarding",
+            EventType::RelayOsGatewayAccess => "relay_os_gateway_access",
+            EventType::PauseContract => "pause_contract",
+            EventType::UnpauseContract => "unpause_contract",
+            EventType::ForceDeleteAssetDefinition => "force_delete_asset_definition",
+            EventType::OverrideAdmin => "override_admin",
         }
         .into()
     }
@@ -179,6 +273,56 @@ impl EventAttributes {
         self
     }
 
+    /// Appends an onboarding memo to an existing [EventAttributes](self::EventAttributes) and
+    /// returns the same instance to create a functional chain for further attribute addition.
+    /// Note: If the memo is omitted or blank, this key will be skipped.
+    ///
+    /// # Parameters
+    ///
+    /// * `memo` An optional memo provided by the requestor when onboarding an asset, appended
+    /// with the key [ONBOARDING_MEMO_KEY](super::constants::ONBOARDING_MEMO_KEY).
+    pub fn set_onboarding_memo(mut self, memo: Option<&str>) -> Self {
+        if let Some(memo) = memo.filter(|memo| !memo.trim().is_empty()) {
+            self.attributes
+                .push((ONBOARDING_MEMO_KEY.into(), memo.to_string()));
+        }
+        self
+    }
+
+    /// Appends the replacement for a deprecated asset type to an existing [EventAttributes](self::EventAttributes)
+    /// and returns the same instance to create a functional chain for further attribute addition.
+    /// Note: If no replacement is provided, this key will be skipped.
+    ///
+    /// # Parameters
+    ///
+    /// * `replacement_asset_type` The optional [replacement_asset_type](crate::core::types::asset_definition::AssetDefinitionV3::replacement_asset_type)
+    /// of a deprecated asset definition, appended with the key [REPLACEMENT_ASSET_TYPE_KEY](super::constants::REPLACEMENT_ASSET_TYPE_KEY).
+    pub fn set_replacement_asset_type(mut self, replacement_asset_type: Option<&str>) -> Self {
+        if let Some(replacement_asset_type) = replacement_asset_type {
+            self.attributes.push((
+                REPLACEMENT_ASSET_TYPE_KEY.into(),
+                replacement_asset_type.to_string(),
+            ));
+        }
+        self
+    }
+
+    /// Appends a flag indicating whether or not the contract's base name was already bound to the
+    /// contract's address to an existing [EventAttributes](self::EventAttributes) and returns the
+    /// same instance to create a functional chain for further attribute addition.
+    ///
+    /// # Parameters
+    ///
+    /// * `already_bound` Whether or not the base name was already bound, appended with the key
+    /// [BASE_NAME_ALREADY_BOUND_KEY](super::constants::BASE_NAME_ALREADY_BOUND_KEY).
+    pub fn set_base_name_already_bound(mut self, already_bound: bool) -> Self {
+        self.attributes.push((
+            BASE_NAME_ALREADY_BOUND_KEY.into(),
+            already_bound.to_string(),
+        ));
+        self
+    }
+
     /// Appends a dynamic set of additional metadata to an existing [EventAttributes](self::EventAttributes)
     /// and returns the same instance to create a functional chain for further attribute addition.
     /// Note: If the metadata provided is empty, this key will be skipped to prevent strange value
@@ -270,7 +414,7 @@ mod tests {
 
     use crate::util::event_attributes::EventAdditionalMetadata;
     use crate::{
-        testutil::test_utilities::single_attribute_for_key,
+        testutil::test_utilities::assert_response_attributes_contain,
         util::constants::{
             ASSET_EVENT_TYPE_KEY, ASSET_SCOPE_ADDRESS_KEY, ASSET_TYPE_KEY, NEW_VALUE_KEY,
             VERIFIER_ADDRESS_KEY,
@@ -287,31 +431,11 @@ mod tests {
             .set_verifier("verifier address")
             .set_new_value("new value");
         let response: Response<String> = Response::new().add_attributes(attributes);
-        assert_eq!(
-            "onboard_asset",
-            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
-            "the event type attribute should be added correctly",
-        );
-        assert_eq!(
-            "asset type",
-            single_attribute_for_key(&response, ASSET_TYPE_KEY),
-            "the asset type attribute should be added correctly",
-        );
-        assert_eq!(
-            "scope address",
-            single_attribute_for_key(&response, ASSET_SCOPE_ADDRESS_KEY),
-            "the scope address attribute should be added correctly",
-        );
-        assert_eq!(
-            "verifier address",
-            single_attribute_for_key(&response, VERIFIER_ADDRESS_KEY),
-            "the verifier address attribute should be added correctly",
-        );
-        assert_eq!(
-            "new value",
-            single_attribute_for_key(&response, NEW_VALUE_KEY),
-            "the new value attribute should be added correctly",
-        );
+        assert_response_attributes_contain(&response, ASSET_EVENT_TYPE_KEY, "onboard_asset");
+        assert_response_attributes_contain(&response, ASSET_TYPE_KEY, "asset type");
+        assert_response_attributes_contain(&response, ASSET_SCOPE_ADDRESS_KEY, "scope address");
+        assert_response_attributes_contain(&response, VERIFIER_ADDRESS_KEY, "verifier address");
+        assert_response_attributes_contain(&response, NEW_VALUE_KEY, "new value");
     }
 
     #[test]
@@ -1,10 +1,16 @@
 use crate::core::error::ContractError;
 use crate::core::types::access_route::AccessRoute;
+use crate::core::types::entity_detail::EntityDetail;
 use crate::util::aliases::AssetResult;
+use crate::util::constants::{MAX_ACCESS_ROUTE_LENGTH, MAX_ACCESS_ROUTE_NAME_LENGTH};
 
-use cosmwasm_std::{coin, to_json_binary, Addr, BankMsg, Binary, CosmosMsg, StdError, StdResult};
+#[cfg(not(target_arch = "wasm32"))]
+use cosmwasm_std::WasmMsg;
+use cosmwasm_std::{
+    coin, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, StdError, StdResult,
+};
 use provwasm_std::types::provenance::attribute::v1::{
-    AttributeType, MsgAddAttributeRequest, MsgUpdateAttributeRequest,
+    AttributeType, MsgAddAttributeRequest, MsgDeleteAttributeRequest, MsgUpdateAttributeRequest,
 };
 use provwasm_std::types::provenance::msgfees::v1::MsgAssessCustomMsgFeeRequest;
 use provwasm_std::types::provenance::name::v1::{MsgBindNameRequest, NameRecord};
@@ -73,16 +79,89 @@ pub fn generate_asset_attribute_name<T: Into<String>, U: Into<String>>(
 ///
 /// # Parameters
 ///
-/// * `asset_type` The value to use at the beginning of the grant id.  Should refer to the
-/// [asset_type](crate::core::types::asset_definition::AssetDefinitionV3::asset_type) property of an
-/// [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3).
+/// * `prefix` The contract's [os_gateway_grant_prefix](crate::core::state::StateV2::os_gateway_grant_prefix),
+/// if any.  When provided, it is placed at the beginning of the grant id, ahead of the asset type.
+/// * `asset_type` The value to use at the beginning of the grant id when no prefix is provided.
+/// Should refer to the [asset_type](crate::core::types::asset_definition::AssetDefinitionV3::asset_type)
+/// property of an [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3).
 /// * `scope_address` The bech32 address with a prefix of "scope" that uniquely defines the scope
 /// that is currently in the process of classification.
 pub fn generate_os_gateway_grant_id<T: Into<String>, U: Into<String>>(
+    prefix: Option<&str>,
     asset_type: T,
     scope_address: U,
 ) -> String {
-    format!("{}-{}", asset_type.into(), scope_address.into())
+    match prefix {
+        Some(prefix) => format!("{}-{}-{}", prefix, asset_type.into(), scope_address.into()),
+        None => format!("{}-{}", asset_type.into(), scope_address.into()),
+    }
+}
+
+/// Generates a human-readable name for a fee charged during the onboarding process.  The
+/// [name](crate::core::types::entity_detail::EntityDetail::name) of the provided entity detail is
+/// preferred, falling back to the provided address when no name is available.  When no suffix is
+/// provided, the result is formatted as "Fee for {name}".  Otherwise, the suffix is appended
+/// directly to the name.  If no name is available and the fallback address is empty, the suffix
+/// alone is used.
+///
+/// # Parameters
+///
+/// * `entity_detail` An optional entity detail that may contain a name describing the fee recipient.
+/// * `fallback_address` The value to use in place of the entity detail's name if none is present.
+/// * `suffix` A value to append to the derived name.
+///
+/// # Examples
+/// ```
+/// use asset_classification_smart_contract::util::functions::generate_fee_name;
+///
+/// assert_eq!("Fee for tp1address", generate_fee_name(None, "tp1address", "").as_str());
+/// assert_eq!("Verifier Fee", generate_fee_name(None, "", " Verifier Fee").as_str());
+/// ```
+pub fn generate_fee_name(
+    entity_detail: Option<&EntityDetail>,
+    fallback_address: &str,
+    suffix: &str,
+) -> String {
+    let format_name = |name: &str| {
+        if suffix.is_empty() {
+            format!("Fee for {}", name)
+        } else {
+            format!("{}{}", name, suffix)
+        }
+    };
+    match entity_detail.and_then(|detail| detail.name.as_deref()) {
+        Some(name) => format_name(name),
+        None if fallback_address.is_empty() => suffix.trim_start().to_string(),
+        None => format_name(fallback_address),
+    }
+}
+
+/// Subtracts `b` from `a`, returning an [Underflow](crate::core::error::ContractError::Underflow)
+/// error instead of panicking if the result would be negative.
+///
+/// # Parameters
+///
+/// * `a` The value to subtract from.
+/// * `b` The value to subtract.
+/// * `context` A description of the calculation being performed, included in the error.
+///
+/// # Examples
+/// ```
+/// use asset_classification_smart_contract::util::functions::safe_sub;
+///
+/// assert_eq!(5, safe_sub(10, 5, "example").unwrap());
+/// assert!(safe_sub(5, 10, "example").is_err());
+/// ```
+pub fn safe_sub(a: u128, b: u128, context: &str) -> AssetResult<u128> {
+    match a.checked_sub(b) {
+        Some(difference) => difference.to_ok(),
+        None => ContractError::Underflow {
+            context: context.to_string(),
+            minuend: a,
+            subtrahend: b,
+        }
+        .to_err(),
+    }
 }
 
 /// Takes an existing vector, moves it into this function, swaps out a single existing item for
@@ -140,10 +219,53 @@ pub fn bank_send<R: Into<String>, D: Into<String>>(
     })
 }
 
+/// Creates a single message that sends funds of multiple denominations from the contract to the
+/// recipient address.  An error is returned if no coins are provided.
+///
+/// # Parameters
+///
+/// * `recipient` The bech32 address of the receiver of the sent funds.
+/// * `coins` All coins to send (from the contract's internal funding amount).  Each denomination
+/// should only appear once, which can be ensured with [split_by_denom](self::split_by_denom).
+pub fn bank_send_multi(recipient: &Addr, coins: Vec<Coin>) -> AssetResult<CosmosMsg> {
+    if coins.is_empty() {
+        return ContractError::generic(format!(
+            "cannot send funds to [{recipient}] without specifying any coins"
+        ))
+        .to_err();
+    }
+    CosmosMsg::Bank(BankMsg::Send {
+        to_address: recipient.to_string(),
+        amount: coins,
+    })
+    .to_ok()
+}
+
+/// Splits the provided coins by their denomination, combining all coins of the same denomination
+/// into a single coin.  The output retains the order in which each denomination first appeared.
+///
+/// # Parameters
+///
+/// * `coins` The coins to combine.
+pub fn split_by_denom(coins: &[Coin]) -> Vec<Coin> {
+    coins.iter().fold(vec![], |mut split_coins, coin| {
+        match split_coins
+            .iter_mut()
+            .find(|existing: &&mut Coin| existing.denom == coin.denom)
+        {
+            Some(existing) => existing.amount += coin.amount,
+            None => split_coins.push(coin.to_owned()),
+        }
+        split_coins
+    })
+}
+
 /// Trims down a vector of AccessRoute to ensure that the contained values are valid and unique.
 /// Does the following:
 /// Ensures that access routes have a non-empty route property.
 /// Ensures that access routes either have an unset name, or a non-blank, set name.
+/// Ensures that access routes and names do not exceed [MAX_ACCESS_ROUTE_LENGTH](crate::util::constants::MAX_ACCESS_ROUTE_LENGTH)
+/// and [MAX_ACCESS_ROUTE_NAME_LENGTH](crate::util::constants::MAX_ACCESS_ROUTE_NAME_LENGTH) bytes, respectively.
 /// Ensures that all access routes, after being trimmed of trailing whitespace, are unique. Drops duplicates.
 ///
 /// # Parameters
@@ -163,6 +285,12 @@ pub fn filter_valid_access_routes(routes: Vec<AccessRoute>) -> Vec<AccessRoute>
                     None => true,
                 }
         })
+        // Drop all proposed entries that are too large to reasonably store.  Trimming has already occurred, so surrounding
+        // whitespace does not count against the limits
+        .filter(|r| {
+            r.route.len() <= MAX_ACCESS_ROUTE_LENGTH
+                && r.name.as_ref().map(|n| n.len()).unwrap_or(0) <= MAX_ACCESS_ROUTE_NAME_LENGTH
+        })
         // Temp swap to a HashSet to filter duplicates automagically
         .collect::<HashSet<_>>()
         .into_iter()
@@ -247,6 +375,21 @@ pub fn update_attribute<H: Into<Addr>, S: Into<String>, B: Into<Binary>>(
     .into())
 }
 
+/// A helper to form a message for deleting all attributes with a given name from an account.
+/// Mirrors the validation performed by [add_attribute](self::add_attribute).
+pub fn delete_attribute<H: Into<Addr>, S: Into<String>>(
+    address: H,
+    contract_address: H,
+    name: S,
+) -> StdResult<CosmosMsg> {
+    Ok(MsgDeleteAttributeRequest {
+        name: validate_string(name, "name")?,
+        account: validate_address(address)?.to_string(),
+        owner: validate_address(contract_address)?.to_string(),
+    }
+    .into())
+}
+
 /// A helper that ensures string params are non-empty.
 /// Copied from [provwasm-std](https://github.com/provenance-io/provwasm/blob/83ec2b8ec4339af2ee6a00e5a0318a5306f3438f/contracts/attrs/src/helpers.rs#L159-L168).
 pub fn validate_string<S: Into<String>>(input: S, param_name: &str) -> StdResult<String> {
@@ -361,6 +504,19 @@ pub fn try_into_update_attribute_request(msg: &CosmosMsg) -> Option<MsgUpdateAtt
     }
 }
 
+/// Attempts to convert a [CosmosMsg] into a [MsgDeleteAttributeRequest]
+pub fn try_into_delete_attribute_request(msg: &CosmosMsg) -> Option<MsgDeleteAttributeRequest> {
+    match &msg {
+        CosmosMsg::Any(cosmwasm_std::AnyMsg { type_url: _, value }) => {
+            match MsgDeleteAttributeRequest::try_from(value.to_owned()) {
+                Ok(message) => Some(message),
+                Err(_) => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 /// Attempts to convert a [CosmosMsg] into a [MsgAssessCustomMsgFeeRequest]
 pub fn try_into_custom_fee_request(msg: &CosmosMsg) -> Option<MsgAssessCustomMsgFeeRequest> {
     match &msg {
@@ -387,17 +543,104 @@ pub fn try_into_bind_name_request(msg: &CosmosMsg) -> Option<MsgBindNameRequest>
     }
 }
 
+/// Identifies the type of a [CosmosMsg] with a short, readable name for use in test assertions.
+/// Protobuf-encoded messages, like those produced for the Provenance Blockchain modules, are all
+/// identified as `"stargate"`, regardless of whether they use the legacy stargate variant or the
+/// newer any variant.
+///
+/// # Parameters
+///
+/// * `msg` The message to identify.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn cosmos_msg_type(msg: &CosmosMsg) -> &'static str {
+    match msg {
+        CosmosMsg::Bank(BankMsg::Send { .. }) => "bank_send",
+        CosmosMsg::Bank(BankMsg::Burn { .. }) => "bank_burn",
+        CosmosMsg::Custom(..) => "custom",
+        CosmosMsg::Wasm(WasmMsg::Execute { .. }) => "wasm_execute",
+        CosmosMsg::Wasm(WasmMsg::Instantiate { .. }) => "wasm_instantiate",
+        CosmosMsg::Wasm(WasmMsg::Instantiate2 { .. }) => "wasm_instantiate2",
+        CosmosMsg::Wasm(WasmMsg::Migrate { .. }) => "wasm_migrate",
+        CosmosMsg::Wasm(WasmMsg::UpdateAdmin { .. }) => "wasm_update_admin",
+        CosmosMsg::Wasm(WasmMsg::ClearAdmin { .. }) => "wasm_clear_admin",
+        #[allow(deprecated)]
+        CosmosMsg::Stargate { .. } => "stargate",
+        CosmosMsg::Any(..) => "stargate",
+        CosmosMsg::Ibc(..) => "ibc",
+        CosmosMsg::Gov(..) => "gov",
+        _ => "unknown",
+    }
+}
+
+/// Asserts that a [CosmosMsg] is of the expected type, as identified by [cosmos_msg_type](self::cosmos_msg_type),
+/// panicking with a readable message if it is not.
+///
+/// # Parameters
+///
+/// * `msg` The message to check.
+/// * `expected` The expected type name of the message, like `"bank_send"`.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn assert_msg_type(msg: &CosmosMsg, expected: &str) {
+    let actual = cosmos_msg_type(msg);
+    assert_eq!(
+        expected, actual,
+        "expected a message of type [{}], but got a message of type [{}]: {:?}",
+        expected, actual, msg,
+    );
+}
+
+/// Asserts that a [CosmosMsg] is a [MsgDeleteAttributeRequest] that removes the expected attribute
+/// name from the expected account, panicking with a readable message if it is not.
+///
+/// # Parameters
+///
+/// * `msg` The message to check.
+/// * `expected_account` The bech32 address from which the attribute should be deleted.
+/// * `expected_name` The fully-qualified name of the attribute that should be deleted.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn assert_delete_attribute_targets(
+    msg: &CosmosMsg,
+    expected_account: &str,
+    expected_name: &str,
+) {
+    let request = try_into_delete_attribute_request(msg).unwrap_or_else(|| {
+        panic!(
+            "expected a delete attribute request, but got a message of type [{}]: {:?}",
+            cosmos_msg_type(msg),
+            msg,
+        )
+    });
+    assert_eq!(
+        expected_account, request.account,
+        "expected the attribute to be deleted from account [{}], but it targeted [{}]",
+        expected_account, request.account,
+    );
+    assert_eq!(
+        expected_name, request.name,
+        "expected the attribute named [{}] to be deleted, but it targeted [{}]",
+        expected_name, request.name,
+    );
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::core::{error::ContractError, types::access_route::AccessRoute};
+    use crate::core::{
+        error::ContractError,
+        types::{access_route::AccessRoute, entity_detail::EntityDetail},
+    };
     use crate::testutil::test_utilities::assert_single_item;
+    use crate::util::constants::{MAX_ACCESS_ROUTE_LENGTH, MAX_ACCESS_ROUTE_NAME_LENGTH};
     use crate::util::functions::{
-        filter_valid_access_routes, generate_os_gateway_grant_id, msg_bind_name,
-        replace_single_matching_vec_element,
+        filter_valid_access_routes, generate_fee_name, generate_os_gateway_grant_id, msg_bind_name,
+        replace_single_matching_vec_element, safe_sub,
     };
-    use cosmwasm_std::{BankMsg, CosmosMsg};
+    use cosmwasm_std::{coin, Addr, BankMsg, Binary, CosmosMsg, WasmMsg};
+    use provwasm_std::types::provenance::attribute::v1::AttributeType;
 
-    use super::bank_send;
+    use super::{
+        add_attribute, assert_delete_attribute_targets, assert_msg_type, bank_send,
+        bank_send_multi, cosmos_msg_type, split_by_denom,
+    };
 
     #[derive(Debug, PartialEq)]
     struct TestVal(u32);
@@ -449,40 +692,141 @@ mod tests {
     #[test]
     fn test_bank_send() {
         let msg = bank_send("address", 150, "fakecoin");
-        match msg {
-            CosmosMsg::Bank(bank_msg) => match bank_msg {
-                BankMsg::Send { to_address, amount } => {
-                    assert_eq!(
-                        "address",
-                        to_address.as_str(),
-                        "expected the address to be output correctly",
-                    );
-                    assert_eq!(
-                        1,
-                        amount.len(),
-                        "expected only one coin to be added to the message",
-                    );
-                    let coin = amount.first().unwrap();
-                    assert_eq!(
-                        150,
-                        coin.amount.u128(),
-                        "expected the coin to contain the proper amount",
-                    );
-                    assert_eq!(
-                        "fakecoin",
-                        coin.denom.as_str(),
-                        "expected the coin to contain the proper denom",
-                    );
-                }
-                _ => panic!(
-                    "unexpected bank msg generated by helper function: {:?}",
-                    bank_msg
-                ),
-            },
-            _ => panic!("unexpected message generated by helper function: {:?}", msg),
+        assert_msg_type(&msg, "bank_send");
+        if let CosmosMsg::Bank(BankMsg::Send { to_address, amount }) = msg {
+            assert_eq!(
+                "address",
+                to_address.as_str(),
+                "expected the address to be output correctly",
+            );
+            assert_eq!(
+                1,
+                amount.len(),
+                "expected only one coin to be added to the message",
+            );
+            let coin = amount.first().unwrap();
+            assert_eq!(
+                150,
+                coin.amount.u128(),
+                "expected the coin to contain the proper amount",
+            );
+            assert_eq!(
+                "fakecoin",
+                coin.denom.as_str(),
+                "expected the coin to contain the proper denom",
+            );
+        }
+    }
+
+    #[test]
+    fn test_bank_send_multi() {
+        let msg = bank_send_multi(
+            &Addr::unchecked("address"),
+            vec![coin(150, "fakecoin"), coin(200, "othercoin")],
+        )
+        .expect("a bank send with coins should be created successfully");
+        assert_msg_type(&msg, "bank_send");
+        if let CosmosMsg::Bank(BankMsg::Send { to_address, amount }) = msg {
+            assert_eq!(
+                "address",
+                to_address.as_str(),
+                "expected the address to be output correctly",
+            );
+            assert_eq!(
+                vec![coin(150, "fakecoin"), coin(200, "othercoin")],
+                amount,
+                "expected all coins to be included in the single message",
+            );
         }
     }
 
+    #[test]
+    fn test_bank_send_multi_without_coins() {
+        let error = bank_send_multi(&Addr::unchecked("address"), vec![])
+            .expect_err("a bank send without coins should be rejected");
+        assert!(
+            error.is_generic_error(),
+            "expected a generic error to be emitted, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_split_by_denom() {
+        assert_eq!(
+            vec![coin(400, "fakecoin"), coin(200, "othercoin")],
+            split_by_denom(&[
+                coin(150, "fakecoin"),
+                coin(200, "othercoin"),
+                coin(250, "fakecoin"),
+            ]),
+            "coins of the same denom should be combined in the order they first appeared",
+        );
+        assert!(
+            split_by_denom(&[]).is_empty(),
+            "no coins should be produced for empty input",
+        );
+    }
+
+    #[test]
+    fn test_cosmos_msg_type() {
+        assert_eq!(
+            "bank_send",
+            cosmos_msg_type(&bank_send("address", 150, "fakecoin")),
+            "a bank send should be identified correctly",
+        );
+        assert_eq!(
+            "bank_burn",
+            cosmos_msg_type(&CosmosMsg::Bank(BankMsg::Burn {
+                amount: vec![coin(150, "fakecoin")],
+            })),
+            "a bank burn should be identified correctly",
+        );
+        assert_eq!(
+            "wasm_execute",
+            cosmos_msg_type(&CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "contract".to_string(),
+                msg: Binary::default(),
+                funds: vec![],
+            })),
+            "a wasm execute should be identified correctly",
+        );
+        assert_eq!(
+            "stargate",
+            cosmos_msg_type(
+                &add_attribute(
+                    Addr::unchecked("address"),
+                    Addr::unchecked("contract"),
+                    "name",
+                    Binary::default(),
+                    AttributeType::String,
+                )
+                .unwrap()
+            ),
+            "a provenance message should be identified as a stargate message",
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "expected a message of type [wasm_execute], but got a message of type [bank_send]"
+    )]
+    fn test_assert_msg_type_rejects_mismatched_type() {
+        assert_msg_type(&bank_send("address", 150, "fakecoin"), "wasm_execute");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "expected a delete attribute request, but got a message of type [bank_send]"
+    )]
+    fn test_assert_delete_attribute_targets_rejects_other_messages() {
+        assert_delete_attribute_targets(
+            &bank_send("address", 150, "fakecoin"),
+            "address",
+            "attribute",
+        );
+    }
+
     #[test]
     fn test_valid_access_routes_drops_none_name_blank_route() {
         let routes = vec![AccessRoute::route_only("   ")];
@@ -600,13 +944,108 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_over_length_routes_and_names_are_dropped() {
+        let routes = vec![
+            AccessRoute::route_only("r".repeat(MAX_ACCESS_ROUTE_LENGTH + 1)),
+            AccessRoute::route_and_name("route", "n".repeat(MAX_ACCESS_ROUTE_NAME_LENGTH + 1)),
+        ];
+        assert!(
+            filter_valid_access_routes(routes).is_empty(),
+            "routes and names exceeding the maximum lengths should be dropped",
+        );
+    }
+
+    #[test]
+    fn test_routes_and_names_at_max_length_are_kept() {
+        let routes = vec![AccessRoute::route_and_name(
+            "r".repeat(MAX_ACCESS_ROUTE_LENGTH),
+            "n".repeat(MAX_ACCESS_ROUTE_NAME_LENGTH),
+        )];
+        assert_eq!(
+            routes,
+            filter_valid_access_routes(routes.clone()),
+            "routes and names at exactly the maximum lengths should be kept",
+        );
+    }
+
+    #[test]
+    fn test_length_limits_apply_after_trimming() {
+        let routes = vec![AccessRoute::route_and_name(
+            format!("  {}  ", "r".repeat(MAX_ACCESS_ROUTE_LENGTH)),
+            format!("  {}  ", "n".repeat(MAX_ACCESS_ROUTE_NAME_LENGTH)),
+        )];
+        let result = filter_valid_access_routes(routes);
+        let filtered_route = assert_single_item(
+            &result,
+            "the route should be kept because surrounding whitespace does not count against the limits",
+        );
+        assert_eq!(
+            MAX_ACCESS_ROUTE_LENGTH,
+            filtered_route.route.len(),
+            "the resulting route should be trimmed down to the maximum length",
+        );
+    }
+
     #[test]
     fn test_generate_os_gateway_grant_id() {
         assert_eq!(
             "heloc-scopescopescope",
-            generate_os_gateway_grant_id("heloc", "scopescopescope"),
+            generate_os_gateway_grant_id(None, "heloc", "scopescopescope"),
             "the output value should equate to the asset type concatenated to the scope address with a hyphen",
         );
+        assert_eq!(
+            "mainnet-heloc-scopescopescope",
+            generate_os_gateway_grant_id(Some("mainnet"), "heloc", "scopescopescope"),
+            "the prefix should be prepended to the default grant id with a hyphen",
+        );
+    }
+
+    #[test]
+    fn test_generate_fee_name_without_suffix() {
+        let mut entity_detail = EntityDetail::new("Fee Collector", "", "", "");
+        assert_eq!(
+            "Fee for Fee Collector",
+            generate_fee_name(Some(&entity_detail), "fee-address", ""),
+            "the entity detail name should be used when it is present",
+        );
+        entity_detail.name = None;
+        assert_eq!(
+            "Fee for fee-address",
+            generate_fee_name(Some(&entity_detail), "fee-address", ""),
+            "the fallback address should be used when the entity detail has no name",
+        );
+        assert_eq!(
+            "Fee for fee-address",
+            generate_fee_name(None, "fee-address", ""),
+            "the fallback address should be used when no entity detail is provided",
+        );
+    }
+
+    #[test]
+    fn test_generate_fee_name_with_suffix() {
+        let mut entity_detail = EntityDetail::new("Jeff's Frozen Pizza Emporium", "", "", "");
+        assert_eq!(
+            "Jeff's Frozen Pizza Emporium Verifier Fee",
+            generate_fee_name(Some(&entity_detail), "", " Verifier Fee"),
+            "the entity detail name should be prepended to the suffix when it is present",
+        );
+        entity_detail.name = None;
+        assert_eq!(
+            "Verifier Fee",
+            generate_fee_name(Some(&entity_detail), "", " Verifier Fee"),
+            "the suffix alone should be used when the entity detail has no name and no fallback is provided",
+        );
+        assert_eq!(
+            "Verifier Fee",
+            generate_fee_name(None, "", " Verifier Fee"),
+            "the suffix alone should be used when no entity detail or fallback is provided",
+        );
+        assert_eq!(
+            "verifier-address Verifier Fee",
+            generate_fee_name(None, "verifier-address", " Verifier Fee"),
+            "the fallback address should be prepended to the suffix when no name is present",
+        );
     }
 
     /// Copied from [funding-trading-bridge-smart-contract](https://github.com/FigureTechnologies/funding-trading-bridge-smart-contract/blob/a92bafb4397360ac0a4febfbc8390c7a54080e84/src/util/provenance_utils.rs#L237-L269).
@@ -696,4 +1135,41 @@ mod tests {
             "unexpected error message when specifying a malformed name",
         );
     }
+
+    #[test]
+    fn test_safe_sub_success() {
+        assert_eq!(
+            0,
+            safe_sub(10, 10, "test").expect("subtracting an equal value should succeed"),
+            "subtracting an equal value should produce zero",
+        );
+        assert_eq!(
+            7,
+            safe_sub(10, 3, "test").expect("subtracting a smaller value should succeed"),
+            "subtracting a smaller value should produce the difference",
+        );
+    }
+
+    #[test]
+    fn test_safe_sub_underflow() {
+        let error = safe_sub(3, 10, "verifier cost").expect_err("an underflow should be rejected");
+        match error {
+            ContractError::Underflow {
+                context,
+                minuend,
+                subtrahend,
+            } => {
+                assert_eq!(
+                    "verifier cost", context,
+                    "the provided context should be included in the error",
+                );
+                assert_eq!(3, minuend, "the minuend should be included in the error");
+                assert_eq!(
+                    10, subtrahend,
+                    "the subtrahend should be included in the error",
+                );
+            }
+            _ => panic!("unexpected error encountered: {:?}", error),
+        }
+    }
 }
@@ -1,10 +1,12 @@
 use cosmwasm_std::{Addr, CosmosMsg};
+use provwasm_std::types::provenance::attribute::v1::AttributeType;
 
-use crate::core::{error::ContractError, types::asset_scope_attribute::AssetScopeAttribute};
+use crate::core::types::asset_scope_attribute::AssetScopeAttribute;
 
 use super::{
     aliases::AssetResult,
-    functions::{add_json_attribute, generate_asset_attribute_name},
+    functions::{add_attribute, delete_attribute, generate_asset_attribute_name},
+    traits::IntoContractError,
 };
 
 /// Helper function to generate an "add attribute" message, as the functionality is re-used across
@@ -20,15 +22,88 @@ pub fn get_add_attribute_to_scope_msg(
     base_contract_name: impl Into<String>,
     contract_address: Addr,
 ) -> AssetResult<CosmosMsg> {
-    add_json_attribute(
+    let attribute_name = generate_asset_attribute_name(&attribute.asset_type, base_contract_name);
+    let attribute_value = attribute.to_binary(&attribute_name)?;
+    add_attribute(
         // Until there's a way to parse a scope address as an Addr, we must use Addr::unchecked.
         // It's not the best policy, but contract execution will fail if it's an incorrect address,
         // so it'll just fail later down the line with a less sane error message than if it was
         // being properly checked.
         Addr::unchecked(&attribute.scope_address),
         contract_address,
-        generate_asset_attribute_name(&attribute.asset_type, base_contract_name),
-        attribute,
+        attribute_name,
+        attribute_value,
+        AttributeType::Json,
     )
-    .map_err(ContractError::Std)
+    .map_err(|e| e.into_contract_error())
+}
+
+/// Helper function to generate a "delete attribute" message, removing all attributes with the
+/// given name from the target scope.  Only the contract that owns the attribute name is able to
+/// successfully execute the generated message.
+///
+/// # Parameters
+///
+/// * `scope_address` The address of the Provenance Metadata Scope from which the attribute will be
+/// removed.
+/// * `contract_address` The address of the contract, which must own the attribute name.
+/// * `attribute_name` The fully-qualified name of the attribute to remove, generally produced by
+/// [generate_asset_attribute_name](super::functions::generate_asset_attribute_name).
+pub fn get_revoke_attribute_msg(
+    scope_address: &Addr,
+    contract_address: Addr,
+    attribute_name: &str,
+) -> AssetResult<CosmosMsg> {
+    delete_attribute(scope_address.to_owned(), contract_address, attribute_name)
+        .map_err(|e| e.into_contract_error())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MOCK_CONTRACT_ADDR;
+    use cosmwasm_std::Addr;
+
+    use crate::core::error::ContractError;
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_CONTRACT_BASE_NAME, DEFAULT_SCOPE_ADDRESS,
+    };
+    use crate::util::functions::{
+        assert_delete_attribute_targets, generate_asset_attribute_name,
+        try_into_delete_attribute_request,
+    };
+    use crate::util::provenance_util::get_revoke_attribute_msg;
+
+    #[test]
+    fn test_get_revoke_attribute_msg_populates_fields() {
+        let attribute_name =
+            generate_asset_attribute_name(DEFAULT_ASSET_TYPE, DEFAULT_CONTRACT_BASE_NAME);
+        let msg = get_revoke_attribute_msg(
+            &Addr::unchecked(DEFAULT_SCOPE_ADDRESS),
+            Addr::unchecked(MOCK_CONTRACT_ADDR),
+            &attribute_name,
+        )
+        .expect("a revoke attribute msg should be generated for valid input");
+        assert_delete_attribute_targets(&msg, DEFAULT_SCOPE_ADDRESS, &attribute_name);
+        let request = try_into_delete_attribute_request(&msg)
+            .expect("the generated msg should be a delete attribute request");
+        assert_eq!(
+            MOCK_CONTRACT_ADDR, request.owner,
+            "the contract should be listed as the attribute owner",
+        );
+    }
+
+    #[test]
+    fn test_get_revoke_attribute_msg_rejects_blank_name() {
+        let error = get_revoke_attribute_msg(
+            &Addr::unchecked(DEFAULT_SCOPE_ADDRESS),
+            Addr::unchecked(MOCK_CONTRACT_ADDR),
+            "  ",
+        )
+        .expect_err("a blank attribute name should be rejected");
+        assert!(
+            matches!(error, ContractError::Std(_)),
+            "unexpected error encountered: {:?}",
+            error,
+        );
+    }
 }
@@ -1,6 +1,9 @@
 use std::{convert::TryInto, str::FromStr};
 
-use crate::{core::error::ContractError, util::aliases::AssetResult};
+use crate::{
+    core::error::ContractError,
+    util::{aliases::AssetResult, traits::IntoContractError},
+};
 use bech32::{FromBase32, ToBase32, Variant};
 use cosmwasm_std::Addr;
 use result_extensions::ResultExtensions;
@@ -14,6 +17,8 @@ const MAINNET_HRP: &str = "pb";
 const TESTNET_HRP: &str = "tp";
 // Standard bech32 encoding for scope addresses simply begins with the string "scope"
 const SCOPE_HRP: &str = "scope";
+// Standard bech32 encoding for scope specification addresses simply begins with the string "scopespec"
+const SCOPE_SPEC_HRP: &str = "scopespec";
 // All valid hrps for use in the underlying functions
 const VALID_HRPS: [&str; 3] = [MAINNET_HRP, TESTNET_HRP, SCOPE_HRP];
 
@@ -45,7 +50,7 @@ pub fn scope_address_to_asset_uuid<S: Into<String>>(scope_address: S) -> AssetRe
 pub fn bech32_string_to_addr<S: Into<String>>(address: S) -> AssetResult<Addr> {
     let address_string = address.into();
     // First, try to decode the string as Bech32.  If this fails, then the input is invalid and should not be converted to an Addr
-    let (hrp, _, _) = bech32::decode(&address_string)?;
+    let (hrp, _, _) = bech32::decode(&address_string).map_err(|e| e.into_contract_error())?;
     if !VALID_HRPS.contains(&hrp.as_str()) {
         ContractError::InvalidAddress {
             address: address_string,
@@ -58,6 +63,30 @@ pub fn bech32_string_to_addr<S: Into<String>>(address: S) -> AssetResult<Addr> {
     }
 }
 
+/// Determines whether or not the provided value is a valid bech32 address with one of the provided
+/// hrps.
+///
+/// # Parameters
+///
+/// * `address` The value to check for a bech32 encoding.
+/// * `prefixes` All hrps that the address is allowed to use.
+pub fn has_bech32_prefix<S: Into<String>>(address: S, prefixes: &[&str]) -> bool {
+    bech32::decode(&address.into())
+        .map(|(hrp, _, _)| prefixes.contains(&hrp.as_str()))
+        .unwrap_or(false)
+}
+
+/// Determines whether or not the provided value is a valid bech32 scope specification address.
+///
+/// # Parameters
+///
+/// * `address` The value to check for a bech32 encoding with an hrp of "scopespec".
+pub fn is_scope_spec_address<S: Into<String>>(address: S) -> bool {
+    bech32::decode(&address.into())
+        .map(|(hrp, _, _)| hrp == SCOPE_SPEC_HRP)
+        .unwrap_or(false)
+}
+
 /// Takes a string representation of a UUID and converts it to a scope address by appending its
 /// big-endian bytes to a byte slice that also contains a prefix key (as defined in the provenance source).
 ///
@@ -70,8 +99,13 @@ pub fn bech32_string_to_addr<S: Into<String>>(address: S) -> AssetResult<Addr> {
 /// * `uuid` A valid uuid v4 string.
 fn uuid_to_address<S: Into<String>>(key_byte: u8, hrp: &str, uuid: S) -> AssetResult<String> {
     let mut buffer = vec![key_byte];
-    buffer.append(&mut Uuid::from_str(&uuid.into())?.as_bytes().to_vec());
-    bech32::encode(hrp, buffer.to_base32(), Variant::Bech32)?.to_ok()
+    buffer.append(
+        &mut Uuid::from_str(&uuid.into())
+            .map_err(|e| e.into_contract_error())?
+            .as_bytes()
+            .to_vec(),
+    );
+    bech32::encode(hrp, buffer.to_base32(), Variant::Bech32).map_err(|e| e.into_contract_error())
 }
 
 /// Takes a valid bech32 address with the acknowledged prefix and attempts to convert it to a uuid.
@@ -87,7 +121,7 @@ fn address_to_uuid<S1: Into<String>, S2: Into<String>>(
     expected_hrp: S2,
 ) -> AssetResult<String> {
     let target_address = address.into();
-    let (hrp, base_32, _) = bech32::decode(&target_address)?;
+    let (hrp, base_32, _) = bech32::decode(&target_address).map_err(|e| e.into_contract_error())?;
     let expected_hrp_string = expected_hrp.into();
     // Run a human-readable-prefix match on the output of the decode to verify that the address passed into the
     // function is of the correct type, avoiding unnnecessary and confusing panics
@@ -98,7 +132,8 @@ fn address_to_uuid<S1: Into<String>, S2: Into<String>>(
         }
         .to_err();
     }
-    let uuid_bytes: [u8; 16] = Vec::from_base32(&base_32)?
+    let uuid_bytes: [u8; 16] = Vec::from_base32(&base_32)
+        .map_err(|e| e.into_contract_error())?
         .into_iter()
         // Lop off the first byte - it represents the key prefix byte and is not a portion of the uuid bytes
         .skip(1)
@@ -113,7 +148,9 @@ fn address_to_uuid<S1: Into<String>, S2: Into<String>>(
     // Important: this uses from_slice instead of from_bytes.  from_bytes is fully unchecked and trusts the
     // caller that they are using valid data that can convert to a uuid.  To avoid any weird panics when calling
     // to_string(), we just use the sliced data
-    Uuid::from_slice(&uuid_bytes)?.to_string().to_ok()
+    Uuid::from_slice(&uuid_bytes)
+        .map(|uuid| uuid.to_string())
+        .map_err(|e| e.into_contract_error())
 }
 
 #[cfg(test)]
@@ -122,7 +159,9 @@ mod tests {
         core::error::ContractError, util::scope_address_utils::asset_uuid_to_scope_address,
     };
 
-    use super::{bech32_string_to_addr, scope_address_to_asset_uuid};
+    use crate::testutil::test_constants::{DEFAULT_SCOPE_ADDRESS, DEFAULT_SCOPE_SPEC_ADDRESS};
+
+    use super::{bech32_string_to_addr, is_scope_spec_address, scope_address_to_asset_uuid};
 
     #[test]
     fn test_successful_asset_uuid_to_scope_address() {
@@ -236,4 +275,20 @@ mod tests {
             _ => panic!("unexpected error encountered: {:?}", error),
         }
     }
+
+    #[test]
+    fn test_is_scope_spec_address() {
+        assert!(
+            is_scope_spec_address(DEFAULT_SCOPE_SPEC_ADDRESS),
+            "a scope spec address should be detected",
+        );
+        assert!(
+            !is_scope_spec_address(DEFAULT_SCOPE_ADDRESS),
+            "a scope address should not be detected as a scope spec address",
+        );
+        assert!(
+            !is_scope_spec_address("not an address"),
+            "a non-bech32 value should not be detected as a scope spec address",
+        );
+    }
 }
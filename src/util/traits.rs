@@ -1,3 +1,7 @@
+use cosmwasm_std::StdError;
+
+use crate::core::error::ContractError;
+
 /// Allows any Sized type to functionally move itself into an Option<T>
 pub trait OptionExtensions
 where
@@ -10,9 +14,53 @@ where
 // Implement for EVERYTHING IN THE UNIVERSE
 impl<T> OptionExtensions for T {}
 
+/// Allows error types produced by the contract's dependencies to be converted into a
+/// [ContractError](crate::core::error::ContractError) with a uniform call, rather than naming the
+/// wrapping variant at each call site.
+pub trait IntoContractError {
+    /// Converts this error into the [ContractError](crate::core::error::ContractError) variant
+    /// that intercepts it.
+    fn into_contract_error(self) -> ContractError;
+}
+impl IntoContractError for StdError {
+    fn into_contract_error(self) -> ContractError {
+        ContractError::Std(self)
+    }
+}
+impl IntoContractError for bech32::Error {
+    fn into_contract_error(self) -> ContractError {
+        ContractError::Bech32Error(self)
+    }
+}
+impl IntoContractError for semver::Error {
+    fn into_contract_error(self) -> ContractError {
+        ContractError::SemVer(self)
+    }
+}
+impl IntoContractError for uuid::Error {
+    fn into_contract_error(self) -> ContractError {
+        ContractError::UuidError(self)
+    }
+}
+
+/// Produces a human-readable representation of a value that is safe to include in logs and error
+/// messages.  Unlike a derived [Debug] output, implementations list each displayed field
+/// explicitly, so fields added to a struct in the future are excluded until they are deliberately
+/// opted in.
+pub trait SafeDisplay {
+    /// Formats this value, including only the fields that are safe to expose.
+    fn safe_display(&self) -> String;
+}
+
 #[cfg(test)]
 mod tests {
-    use super::OptionExtensions;
+    use std::str::FromStr;
+
+    use cosmwasm_std::StdError;
+
+    use crate::core::error::ContractError;
+
+    use super::{IntoContractError, OptionExtensions};
 
     #[test]
     fn test_to_option() {
@@ -25,4 +73,50 @@ mod tests {
             "incorrect value contained in wrapped Option",
         );
     }
+
+    #[test]
+    fn test_std_error_into_contract_error() {
+        let error = StdError::generic_err("oops").into_contract_error();
+        assert!(
+            matches!(error, ContractError::Std(StdError::GenericErr { .. })),
+            "a std error should be wrapped in the Std variant, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_bech32_error_into_contract_error() {
+        let error = bech32::decode("not a bech32 address")
+            .expect_err("decoding an invalid address should fail")
+            .into_contract_error();
+        assert!(
+            matches!(error, ContractError::Bech32Error(_)),
+            "a bech32 error should be wrapped in the Bech32Error variant, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_semver_error_into_contract_error() {
+        let error = semver::Version::parse("not a version")
+            .expect_err("parsing an invalid version should fail")
+            .into_contract_error();
+        assert!(
+            matches!(error, ContractError::SemVer(_)),
+            "a semver error should be wrapped in the SemVer variant, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_uuid_error_into_contract_error() {
+        let error = uuid::Uuid::from_str("not a uuid")
+            .expect_err("parsing an invalid uuid should fail")
+            .into_contract_error();
+        assert!(
+            matches!(error, ContractError::UuidError(_)),
+            "a uuid error should be wrapped in the UuidError variant, but got: {:?}",
+            error,
+        );
+    }
 }
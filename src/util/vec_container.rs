@@ -39,6 +39,16 @@ impl<T> VecContainer<T> {
         self.values.borrow_mut().clear();
     }
 
+    /// Counts the values in the inner Vec without cloning or moving them.
+    pub fn len(&self) -> usize {
+        self.values.borrow().len()
+    }
+
+    /// Determines if the inner Vec contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.values.borrow().is_empty()
+    }
+
     /// Fetches the actual value inside the RefCell, moving the internalized value
     /// and disposing of this container in the process.
     pub fn get(self) -> Vec<T> {
@@ -148,4 +158,21 @@ mod tests {
             "the copied vectors should be identical",
         );
     }
+
+    #[test]
+    fn test_container_len() {
+        let container = VecContainer::new();
+        assert!(container.is_empty(), "a new container should be empty");
+        container.append(&mut vec![1, 2, 3]);
+        assert_eq!(3, container.len(), "the container should hold three values");
+        assert!(
+            !container.is_empty(),
+            "the container should not be empty after values are appended",
+        );
+        assert_eq!(
+            container.get_copied().len(),
+            container.len(),
+            "the length should agree with the number of held values",
+        );
+    }
 }
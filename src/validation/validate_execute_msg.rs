@@ -1,15 +1,24 @@
 use crate::core::error::ContractError;
 use crate::core::msg::ExecuteMsg;
 use crate::core::types::asset_identifier::AssetIdentifier;
+use crate::core::types::batch_onboard_asset::BatchOnboardAssetEntry;
+use crate::core::types::batch_verify_asset::BatchVerifyAssetEntry;
+use crate::core::types::os_gateway_access::OsGatewayAccess;
 use crate::core::types::serialized_enum::SerializedEnum;
 use crate::core::types::verifier_detail::VerifierDetailV2;
 use crate::util::aliases::AssetResult;
-use crate::util::scope_address_utils::bech32_string_to_addr;
+use crate::util::constants::{
+    MAX_BATCH_ONBOARD_SIZE, MAX_BATCH_VERIFY_SIZE, MAX_BULK_DELETE_SIZE,
+    MAX_ONBOARDING_MEMO_LENGTH, MAX_REQUESTOR_METADATA_LENGTH, VALID_VERIFIER_ADDRESS_PREFIXES,
+};
+use crate::util::scope_address_utils::{bech32_string_to_addr, has_bech32_prefix};
 use crate::util::traits::OptionExtensions;
 use crate::validation::validate_init_msg::{
-    validate_asset_definition, validate_verifier_with_provided_errors,
+    validate_allowed_requestors_internal, validate_asset_definition,
+    validate_os_gateway_grant_prefix, validate_verifier_with_provided_errors,
 };
 use result_extensions::ResultExtensions;
+use uuid::Uuid;
 
 /// The main branch of validation for an execute msg.  Funnels the intercepted value based on variant
 /// to one of the various sub-functions in this module.
@@ -17,32 +26,111 @@ use result_extensions::ResultExtensions;
 /// # Parameters
 ///
 /// * `msg` An execute msg to process.
-pub fn validate_execute_msg(msg: &ExecuteMsg) -> AssetResult<()> {
+/// * `allow_all_bech32_prefixes` Whether or not verifier and fee destination addresses may use
+/// prefixes other than the [VALID_VERIFIER_ADDRESS_PREFIXES](crate::util::constants::VALID_VERIFIER_ADDRESS_PREFIXES).
+/// Sourced from the contract's [state](crate::core::state::StateV2::allow_all_bech32_prefixes).
+/// * `require_entity_detail` Whether or not newly-added and updated verifiers must include an
+/// [EntityDetail](crate::core::types::entity_detail::EntityDetail) with a name.  Sourced from the
+/// contract's [state](crate::core::state::StateV2::require_entity_detail_for_verifiers).
+pub fn validate_execute_msg(
+    msg: &ExecuteMsg,
+    allow_all_bech32_prefixes: bool,
+    require_entity_detail: bool,
+) -> AssetResult<()> {
     match msg {
         ExecuteMsg::OnboardAsset {
             identifier,
             asset_type,
             verifier_address,
+            memo,
+            metadata,
             ..
-        } => validate_onboard_asset(identifier, asset_type, verifier_address),
+        } => validate_onboard_asset(
+            identifier,
+            asset_type,
+            verifier_address,
+            memo.as_deref(),
+            metadata.as_deref(),
+        ),
+        ExecuteMsg::BatchOnboardAsset { entries } => validate_batch_onboard_asset(entries),
         ExecuteMsg::VerifyAsset { identifier, .. } => validate_verify_asset(identifier),
-        ExecuteMsg::AddAssetDefinition { asset_definition } => {
-            validate_asset_definition(&asset_definition.as_asset_definition())
-        }
-        ExecuteMsg::UpdateAssetDefinition { asset_definition } => {
-            validate_asset_definition(&asset_definition.as_asset_definition())
+        ExecuteMsg::BatchVerifyAsset { entries } => validate_batch_verify_asset(entries),
+        ExecuteMsg::AddAssetDefinition { asset_definition } => validate_asset_definition(
+            &asset_definition.as_asset_definition(),
+            allow_all_bech32_prefixes,
+            require_entity_detail,
+        ),
+        ExecuteMsg::CloneAssetDefinition {
+            source_asset_type,
+            new_asset_type,
+            ..
+        } => validate_clone_asset_definition(source_asset_type, new_asset_type),
+        ExecuteMsg::UpdateAssetDefinition {
+            asset_definition,
+            partial,
+        } => {
+            // Partial updates are validated after being merged with the existing definition
+            if partial.unwrap_or(false) {
+                validate_partial_update_asset_definition(&asset_definition.asset_type)
+            } else {
+                // Entity details are only required of the verifiers that the update adds, which
+                // requires the existing definition and is checked when the update is executed
+                validate_asset_definition(
+                    &asset_definition.as_asset_definition(),
+                    allow_all_bech32_prefixes,
+                    false,
+                )
+            }
         }
+        ExecuteMsg::UpdateAssetDefinitionDescription {
+            asset_type,
+            display_name,
+        } => validate_update_asset_definition_description(asset_type, display_name.as_deref()),
         ExecuteMsg::ToggleAssetDefinition { asset_type, .. } => {
             validate_toggle_asset_definition(asset_type)
         }
         ExecuteMsg::AddAssetVerifier {
             asset_type,
             verifier,
-        } => validate_asset_verifier_msg(asset_type, verifier),
+        } => validate_asset_verifier_msg(
+            asset_type,
+            verifier,
+            allow_all_bech32_prefixes,
+            require_entity_detail,
+        ),
         ExecuteMsg::UpdateAssetVerifier {
             asset_type,
             verifier,
-        } => validate_asset_verifier_msg(asset_type, verifier),
+        } => validate_asset_verifier_msg(
+            asset_type,
+            verifier,
+            allow_all_bech32_prefixes,
+            require_entity_detail,
+        ),
+        ExecuteMsg::ToggleAssetVerifier {
+            asset_type,
+            verifier_address,
+            ..
+        } => validate_toggle_asset_verifier(asset_type, verifier_address),
+        ExecuteMsg::UpdateVerifierEntityDetail {
+            asset_type,
+            verifier_address,
+            ..
+        } => validate_update_verifier_entity_detail(asset_type, verifier_address),
+        ExecuteMsg::RotateVerifierAddress {
+            asset_type,
+            old_address,
+            new_address,
+        } => validate_rotate_verifier_address(
+            asset_type,
+            old_address,
+            new_address,
+            allow_all_bech32_prefixes,
+        ),
+        ExecuteMsg::UpdateAllowedRequestors {
+            asset_type,
+            allowed_requestors,
+        } => validate_update_allowed_requestors(asset_type, allowed_requestors),
         ExecuteMsg::UpdateAccessRoutes {
             identifier,
             owner_address,
@@ -51,6 +139,59 @@ pub fn validate_execute_msg(msg: &ExecuteMsg) -> AssetResult<()> {
         ExecuteMsg::DeleteAssetDefinition { asset_type } => {
             validate_delete_asset_definition(asset_type)
         }
+        ExecuteMsg::BulkDeleteAssetDefinitions { asset_types } => {
+            validate_bulk_delete_asset_definitions(asset_types)
+        }
+        ExecuteMsg::FreezeAssetDefinition { asset_type } => {
+            validate_freeze_asset_definition(asset_type)
+        }
+        ExecuteMsg::DeprecateAssetDefinition {
+            asset_type,
+            replacement_asset_type,
+        } => validate_deprecate_asset_definition(asset_type, replacement_asset_type.as_deref()),
+        ExecuteMsg::PurgeFeePaymentDetail {
+            identifier,
+            asset_type,
+        } => validate_purge_fee_payment_detail(identifier, asset_type),
+        ExecuteMsg::PurgeStaleFeeDetail {
+            identifier,
+            asset_type,
+        } => validate_purge_stale_fee_detail(identifier, asset_type),
+        ExecuteMsg::SetFeeDetailMaxAge { max_age_blocks } => {
+            validate_set_fee_detail_max_age(max_age_blocks)
+        }
+        ExecuteMsg::SetMaxAccessRoutesPerOwner { limit } => {
+            validate_set_max_access_routes_per_owner(*limit)
+        }
+        ExecuteMsg::SetRequireEntityDetail { .. } => ().to_ok(),
+        ExecuteMsg::UpdateOsGatewayGrantPrefix { prefix } => gen_validation_response(
+            "ExecuteMsg::UpdateOsGatewayGrantPrefix",
+            validate_os_gateway_grant_prefix(prefix.as_deref(), "prefix"),
+        ),
+        ExecuteMsg::ExpireStaleVerification {
+            identifier,
+            asset_type,
+        } => validate_expire_stale_verification(identifier, asset_type),
+        ExecuteMsg::CancelPendingOnboarding {
+            identifier,
+            asset_type,
+        } => validate_cancel_pending_onboarding(identifier, asset_type),
+        ExecuteMsg::AppealVerification {
+            identifier,
+            asset_type,
+            appeal_reason,
+        } => validate_appeal_verification(identifier, asset_type, appeal_reason),
+        ExecuteMsg::ResolveAppeal {
+            identifier,
+            asset_type,
+            resolution_message,
+            ..
+        } => validate_resolve_appeal(identifier, asset_type, resolution_message),
+        ExecuteMsg::TransferAdmin { new_admin_address } => {
+            validate_transfer_admin(new_admin_address)
+        }
+        ExecuteMsg::AcceptAdminTransfer {} => ().to_ok(),
+        ExecuteMsg::RelayOsGatewayAccess { access } => validate_relay_os_gateway_access(access),
     }
 }
 
@@ -68,11 +209,94 @@ pub fn validate_execute_msg(msg: &ExecuteMsg) -> AssetResult<()> {
 /// * `verifier_address` The bech32 address of a [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2)
 /// held within the target [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
 /// for onboarding.
+/// * `memo` An optional note from the requestor, which may not exceed [MAX_ONBOARDING_MEMO_LENGTH](crate::util::constants::MAX_ONBOARDING_MEMO_LENGTH)
+/// characters.
+/// * `metadata` Optional free-form data from the requestor, which may not exceed [MAX_REQUESTOR_METADATA_LENGTH](crate::util::constants::MAX_REQUESTOR_METADATA_LENGTH)
+/// bytes.
 fn validate_onboard_asset(
     identifier: &SerializedEnum,
     asset_type: &str,
     verifier_address: &str,
+    memo: Option<&str>,
+    metadata: Option<&str>,
 ) -> AssetResult<()> {
+    let invalid_fields =
+        get_onboard_asset_invalid_fields(identifier, asset_type, verifier_address, memo, metadata);
+    gen_validation_response("ExecuteMsg::OnboardAsset", invalid_fields)
+}
+
+/// Validates the [BatchOnboardAsset](crate::core::msg::ExecuteMsg::BatchOnboardAsset) variant of
+/// the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on success, or an
+/// [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields) error when
+/// invalid fields are found.  Each entry is validated in the same manner as an [OnboardAsset](crate::core::msg::ExecuteMsg::OnboardAsset)
+/// request, and entries that target the same scope and asset type as a previous entry are
+/// rejected.
+///
+/// # Parameters
+///
+/// * `entries` Each scope to onboard in the batch.
+fn validate_batch_onboard_asset(entries: &[BatchOnboardAssetEntry]) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if entries.is_empty() {
+        invalid_fields.push("entries: at least one entry must be provided".to_string());
+    }
+    if entries.len() > MAX_BATCH_ONBOARD_SIZE {
+        invalid_fields.push(format!(
+            "entries: no more than {} entries may be provided",
+            MAX_BATCH_ONBOARD_SIZE,
+        ));
+    }
+    let mut onboarded_scopes: Vec<(String, &str)> = vec![];
+    for (index, entry) in entries.iter().enumerate() {
+        invalid_fields.extend(
+            get_onboard_asset_invalid_fields(
+                &entry.identifier,
+                &entry.asset_type,
+                &entry.verifier_address,
+                entry.memo.as_deref(),
+                entry.metadata.as_deref(),
+            )
+            .into_iter()
+            .map(|field| format!("entries[{}]:{}", index, field)),
+        );
+        if let Ok(identifiers) = entry
+            .identifier
+            .to_asset_identifier()
+            .and_then(|identifier| identifier.to_identifiers())
+        {
+            let scope = (identifiers.scope_address, entry.asset_type.as_str());
+            if onboarded_scopes.contains(&scope) {
+                invalid_fields.push(format!(
+                    "entries[{}]: scope [{}] is already onboarded as asset type [{}] by a previous entry",
+                    index, scope.0, scope.1,
+                ));
+            } else {
+                onboarded_scopes.push(scope);
+            }
+        }
+    }
+    gen_validation_response("ExecuteMsg::BatchOnboardAsset", invalid_fields)
+}
+
+/// Collects the invalid fields for the values used to onboard a single asset.
+///
+/// # Parameters
+///
+/// * `identifier` An [AssetIdentifier](crate::core::types::asset_identifier::AssetIdentifier)
+/// encapsulated within a [SerializedEnum](crate::core::types::serialized_enum::SerializedEnum).
+/// * `asset_type` The type of asset to onboard.
+/// * `verifier_address` The bech32 address of the verifier selected for onboarding.
+/// * `memo` An optional note from the requestor, which may not exceed [MAX_ONBOARDING_MEMO_LENGTH](crate::util::constants::MAX_ONBOARDING_MEMO_LENGTH)
+/// characters.
+/// * `metadata` Optional free-form data from the requestor, which may not exceed [MAX_REQUESTOR_METADATA_LENGTH](crate::util::constants::MAX_REQUESTOR_METADATA_LENGTH)
+/// bytes.
+fn get_onboard_asset_invalid_fields(
+    identifier: &SerializedEnum,
+    asset_type: &str,
+    verifier_address: &str,
+    memo: Option<&str>,
+    metadata: Option<&str>,
+) -> Vec<String> {
     let mut invalid_fields: Vec<String> = vec![];
     if let Some(message) = get_asset_identifier_invalid_message(identifier) {
         invalid_fields.push(message);
@@ -83,7 +307,19 @@ fn validate_onboard_asset(
     if bech32_string_to_addr(verifier_address).is_err() {
         invalid_fields.push("verifier_address: must be valid bech32".to_string());
     }
-    gen_validation_response("ExecuteMsg::OnboardAsset", invalid_fields)
+    if memo.is_some_and(|memo| memo.chars().count() > MAX_ONBOARDING_MEMO_LENGTH) {
+        invalid_fields.push(format!(
+            "memo: must not exceed {} characters",
+            MAX_ONBOARDING_MEMO_LENGTH,
+        ));
+    }
+    if metadata.is_some_and(|metadata| metadata.len() > MAX_REQUESTOR_METADATA_LENGTH) {
+        invalid_fields.push(format!(
+            "metadata: must not exceed {} bytes",
+            MAX_REQUESTOR_METADATA_LENGTH,
+        ));
+    }
+    invalid_fields
 }
 
 /// Validates the [VerifyAsset](crate::core::msg::ExecuteMsg::VerifyAsset) variant of the
@@ -103,6 +339,120 @@ fn validate_verify_asset(identifier: &SerializedEnum) -> AssetResult<()> {
     gen_validation_response("ExecuteMsg::VerifyAsset", invalid_fields)
 }
 
+/// Validates the [BatchVerifyAsset](crate::core::msg::ExecuteMsg::BatchVerifyAsset) variant of
+/// the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on success, or an
+/// [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields) error when
+/// invalid fields are found.
+///
+/// # Parameters
+///
+/// * `entries` Each scope to verify, which must contain between one and [MAX_BATCH_VERIFY_SIZE](crate::util::constants::MAX_BATCH_VERIFY_SIZE)
+/// values, each targeting a distinct scope and asset type.
+fn validate_batch_verify_asset(entries: &[BatchVerifyAssetEntry]) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if entries.is_empty() {
+        invalid_fields.push("entries: at least one entry must be provided".to_string());
+    }
+    if entries.len() > MAX_BATCH_VERIFY_SIZE {
+        invalid_fields.push(format!(
+            "entries: no more than {} entries may be provided",
+            MAX_BATCH_VERIFY_SIZE,
+        ));
+    }
+    let mut verified_scopes: Vec<(String, &str)> = vec![];
+    for (index, entry) in entries.iter().enumerate() {
+        if let Some(message) = get_asset_identifier_invalid_message(&entry.identifier) {
+            invalid_fields.push(format!("entries[{}]:{}", index, message));
+        }
+        if let Ok(identifiers) = entry
+            .identifier
+            .to_asset_identifier()
+            .and_then(|identifier| identifier.to_identifiers())
+        {
+            let scope = (identifiers.scope_address, entry.asset_type.as_str());
+            if verified_scopes.contains(&scope) {
+                invalid_fields.push(format!(
+                    "entries[{}]: scope [{}] is already verified as asset type [{}] by a previous entry",
+                    index, scope.0, scope.1,
+                ));
+            } else {
+                verified_scopes.push(scope);
+            }
+        }
+    }
+    gen_validation_response("ExecuteMsg::BatchVerifyAsset", invalid_fields)
+}
+
+/// Validates the [CloneAssetDefinition](crate::core::msg::ExecuteMsg::CloneAssetDefinition) variant
+/// of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on success, or an
+/// [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields) error when
+/// invalid fields are found.  The cloned definition itself is validated after the source's
+/// verifiers are copied.
+///
+/// # Parameters
+///
+/// * `source_asset_type` The type of the [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
+/// from which the verifiers will be copied.
+/// * `new_asset_type` The type of the new asset definition.
+fn validate_clone_asset_definition(
+    source_asset_type: &str,
+    new_asset_type: &str,
+) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if source_asset_type.is_empty() {
+        invalid_fields.push("source_asset_type: must not be blank".to_string());
+    }
+    if new_asset_type.is_empty() {
+        invalid_fields.push("new_asset_type: must not be blank".to_string());
+    }
+    gen_validation_response("ExecuteMsg::CloneAssetDefinition", invalid_fields)
+}
+
+/// Validates the [UpdateAssetDefinition](crate::core::msg::ExecuteMsg::UpdateAssetDefinition) variant
+/// of the [ExecuteMsg](crate::core::msg::ExecuteMsg) when a partial update is requested.  Returning
+/// an empty response on success, or an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields)
+/// error when invalid fields are found.  The remaining fields are validated after they are merged
+/// with the existing definition.
+///
+/// # Parameters
+///
+/// * `asset_type` The type of asset to update, which should refer to an [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
+/// stored internally in the contract.
+fn validate_partial_update_asset_definition(asset_type: &str) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if asset_type.is_empty() {
+        invalid_fields.push("asset_definition:asset_type: must not be blank".to_string());
+    }
+    gen_validation_response("ExecuteMsg::UpdateAssetDefinition", invalid_fields)
+}
+
+/// Validates the [UpdateAssetDefinitionDescription](crate::core::msg::ExecuteMsg::UpdateAssetDefinitionDescription)
+/// variant of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on success,
+/// or an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields) error when
+/// invalid fields are found.
+///
+/// # Parameters
+///
+/// * `asset_type` The type of asset to update, which should refer to an [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
+/// stored internally in the contract.
+/// * `display_name` The new display name for the asset definition, if any.
+fn validate_update_asset_definition_description(
+    asset_type: &str,
+    display_name: Option<&str>,
+) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if asset_type.is_empty() {
+        invalid_fields.push("asset_type: must not be blank".to_string());
+    }
+    if display_name.is_some_and(|name| name.trim().is_empty()) {
+        invalid_fields.push("display_name: must not be blank if provided".to_string());
+    }
+    gen_validation_response(
+        "ExecuteMsg::UpdateAssetDefinitionDescription",
+        invalid_fields,
+    )
+}
+
 /// Validates the [ToggleAssetDefinition](crate::core::msg::ExecuteMsg::ToggleAssetDefinition) variant of the
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on success, or an
 /// [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields) error when
@@ -120,6 +470,121 @@ fn validate_toggle_asset_definition(asset_type: &str) -> AssetResult<()> {
     gen_validation_response("ExecuteMsg::ToggleAssetDefinition", invalid_fields)
 }
 
+/// Validates the [ToggleAssetVerifier](crate::core::msg::ExecuteMsg::ToggleAssetVerifier) variant of the
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on success, or an
+/// [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields) error when
+/// invalid fields are found.
+///
+/// # Parameters
+///
+/// * `asset_type` The asset type of the [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
+/// that contains the target verifier.
+/// * `verifier_address` The bech32 address of the verifier to toggle.
+fn validate_toggle_asset_verifier(asset_type: &str, verifier_address: &str) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if asset_type.is_empty() {
+        invalid_fields.push("asset_type: must not be blank".to_string());
+    }
+    if verifier_address.is_empty() {
+        invalid_fields.push("verifier_address: must not be blank".to_string());
+    }
+    gen_validation_response("ExecuteMsg::ToggleAssetVerifier", invalid_fields)
+}
+
+/// Validates the [UpdateVerifierEntityDetail](crate::core::msg::ExecuteMsg::UpdateVerifierEntityDetail)
+/// variant of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on
+/// success, or an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields)
+/// error when invalid fields are found.  The entity detail itself is free-form and is not validated.
+///
+/// # Parameters
+///
+/// * `asset_type` The asset type of the [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
+/// that contains the target verifier.
+/// * `verifier_address` The bech32 address of the verifier to update.
+fn validate_update_verifier_entity_detail(
+    asset_type: &str,
+    verifier_address: &str,
+) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if asset_type.is_empty() {
+        invalid_fields.push("asset_type: must not be blank".to_string());
+    }
+    if verifier_address.is_empty() {
+        invalid_fields.push("verifier_address: must not be blank".to_string());
+    }
+    gen_validation_response("ExecuteMsg::UpdateVerifierEntityDetail", invalid_fields)
+}
+
+/// Validates the [RotateVerifierAddress](crate::core::msg::ExecuteMsg::RotateVerifierAddress)
+/// variant of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on
+/// success, or an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields)
+/// error when invalid fields are found.
+///
+/// # Parameters
+///
+/// * `asset_type` The asset type of the [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
+/// that contains the target verifier.
+/// * `old_address` The bech32 address currently used by the verifier.
+/// * `new_address` The bech32 address that will replace the verifier's current address.
+/// * `allow_all_bech32_prefixes` Whether or not the new address may use prefixes other than the
+/// [VALID_VERIFIER_ADDRESS_PREFIXES](crate::util::constants::VALID_VERIFIER_ADDRESS_PREFIXES).
+fn validate_rotate_verifier_address(
+    asset_type: &str,
+    old_address: &str,
+    new_address: &str,
+    allow_all_bech32_prefixes: bool,
+) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if asset_type.is_empty() {
+        invalid_fields.push("asset_type: must not be blank".to_string());
+    }
+    if old_address.is_empty() {
+        invalid_fields.push("old_address: must not be blank".to_string());
+    }
+    if bech32_string_to_addr(new_address).is_err() {
+        invalid_fields.push("new_address: must be valid bech32".to_string());
+    } else if !allow_all_bech32_prefixes
+        && !has_bech32_prefix(new_address, &VALID_VERIFIER_ADDRESS_PREFIXES)
+    {
+        invalid_fields.push(format!(
+            "new_address: must use one of the prefixes [{}]",
+            VALID_VERIFIER_ADDRESS_PREFIXES.join(", "),
+        ));
+    }
+    if old_address == new_address {
+        invalid_fields.push("new_address: must differ from old_address".to_string());
+    }
+    gen_validation_response("ExecuteMsg::RotateVerifierAddress", invalid_fields)
+}
+
+/// Validates the [UpdateAllowedRequestors](crate::core::msg::ExecuteMsg::UpdateAllowedRequestors)
+/// variant of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on
+/// success, or an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields)
+/// error when invalid fields are found.
+///
+/// # Parameters
+///
+/// * `asset_type` The asset type of the [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
+/// to update.
+/// * `allowed_requestors` The bech32 addresses of the only requestors permitted to onboard the
+/// asset type, if any.
+fn validate_update_allowed_requestors(
+    asset_type: &str,
+    allowed_requestors: &Option<Vec<String>>,
+) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if asset_type.is_empty() {
+        invalid_fields.push("asset_type: must not be blank".to_string());
+    }
+    if let Some(allowed_requestors) = allowed_requestors {
+        invalid_fields.append(&mut validate_allowed_requestors_internal(
+            allowed_requestors,
+            "allowed_requestors",
+        ));
+    }
+    gen_validation_response("ExecuteMsg::UpdateAllowedRequestors", invalid_fields)
+}
+
 /// Validates the [AddAssetVerifier](crate::core::msg::ExecuteMsg::AddAssetVerifier) or [UpdateAssetVerifier](crate::core::msg::ExecuteMsg::UpdateAssetVerifier)
 /// variants of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on
 /// success, or an  [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields)
@@ -130,13 +595,27 @@ fn validate_toggle_asset_definition(asset_type: &str) -> AssetResult<()> {
 /// * `asset_type` The type of asset to add or update, which should refer to an [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
 /// stored internally in the contract.
 /// * `verifier` The verifier detail to add or update.
-fn validate_asset_verifier_msg(asset_type: &str, verifier: &VerifierDetailV2) -> AssetResult<()> {
+/// * `allow_all_bech32_prefixes` Whether or not verifier and fee destination addresses may use
+/// prefixes other than the [VALID_VERIFIER_ADDRESS_PREFIXES](crate::util::constants::VALID_VERIFIER_ADDRESS_PREFIXES).
+/// * `require_entity_detail` Whether or not the verifier must include an [EntityDetail](crate::core::types::entity_detail::EntityDetail)
+/// with a name.
+fn validate_asset_verifier_msg(
+    asset_type: &str,
+    verifier: &VerifierDetailV2,
+    allow_all_bech32_prefixes: bool,
+    require_entity_detail: bool,
+) -> AssetResult<()> {
     let errors = if asset_type.is_empty() {
         vec!["asset_type must not be empty".to_string()].to_some()
     } else {
         None
     };
-    validate_verifier_with_provided_errors(verifier, errors)
+    validate_verifier_with_provided_errors(
+        verifier,
+        errors,
+        allow_all_bech32_prefixes,
+        require_entity_detail,
+    )
 }
 
 /// Validates the [UpdateAccessRoutes](crate::core::msg::ExecuteMsg::UpdateAccessRoutes) variant of the
@@ -182,6 +661,311 @@ fn validate_delete_asset_definition(asset_type: &str) -> AssetResult<()> {
     gen_validation_response("ExecuteMsg::DeleteAssetDefinition", invalid_fields)
 }
 
+/// Validates the [BulkDeleteAssetDefinitions](crate::core::msg::ExecuteMsg::BulkDeleteAssetDefinitions)
+/// variant of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on
+/// success, or an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields)
+/// error when invalid fields are found.
+///
+/// # Parameters
+///
+/// * `asset_types` The asset types to delete, which must contain between one and [MAX_BULK_DELETE_SIZE](crate::util::constants::MAX_BULK_DELETE_SIZE)
+/// unique, non-blank values.
+fn validate_bulk_delete_asset_definitions(asset_types: &[String]) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if asset_types.is_empty() {
+        invalid_fields.push("asset_types: at least one asset type must be provided".to_string());
+    }
+    if asset_types.len() > MAX_BULK_DELETE_SIZE {
+        invalid_fields.push(format!(
+            "asset_types: no more than {} asset types may be provided",
+            MAX_BULK_DELETE_SIZE,
+        ));
+    }
+    for (index, asset_type) in asset_types.iter().enumerate() {
+        if asset_type.is_empty() {
+            invalid_fields.push(format!("asset_types[{}]: must not be blank", index));
+        } else if asset_types[..index].contains(asset_type) {
+            invalid_fields.push(format!(
+                "asset_types[{}]: asset type [{}] was already provided",
+                index, asset_type,
+            ));
+        }
+    }
+    gen_validation_response("ExecuteMsg::BulkDeleteAssetDefinitions", invalid_fields)
+}
+
+/// Validates the [FreezeAssetDefinition](crate::core::msg::ExecuteMsg::FreezeAssetDefinition) variant of the
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on success, or an
+/// [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields) error when
+/// invalid fields are found.
+///
+/// # Parameters
+///
+/// * `asset_type` The asset type to identify the [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
+/// to freeze.
+fn validate_freeze_asset_definition(asset_type: &str) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+
+    if asset_type.is_empty() {
+        invalid_fields.push("asset_type: must not be blank".to_string())
+    }
+
+    gen_validation_response("ExecuteMsg::FreezeAssetDefinition", invalid_fields)
+}
+
+/// Validates the [DeprecateAssetDefinition](crate::core::msg::ExecuteMsg::DeprecateAssetDefinition) variant
+/// of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on success, or an
+/// [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields) error when
+/// invalid fields are found.
+///
+/// # Parameters
+///
+/// * `asset_type` The asset type to identify the [AssetDefinitionV3](crate::core::types::asset_definition::AssetDefinitionV3)
+/// to deprecate.
+/// * `replacement_asset_type` The asset type that requestors should migrate to, if any.
+fn validate_deprecate_asset_definition(
+    asset_type: &str,
+    replacement_asset_type: Option<&str>,
+) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if asset_type.is_empty() {
+        invalid_fields.push("asset_type: must not be blank".to_string());
+    }
+    if let Some(replacement_asset_type) = replacement_asset_type {
+        if replacement_asset_type.is_empty() {
+            invalid_fields.push("replacement_asset_type: must not be blank".to_string());
+        } else if replacement_asset_type.eq_ignore_ascii_case(asset_type) {
+            invalid_fields.push("replacement_asset_type: must differ from asset_type".to_string());
+        }
+    }
+    gen_validation_response("ExecuteMsg::DeprecateAssetDefinition", invalid_fields)
+}
+
+/// Validates the [PurgeFeePaymentDetail](crate::core::msg::ExecuteMsg::PurgeFeePaymentDetail) variant
+/// of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on success, or an
+/// [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields) error when
+/// invalid fields are found.
+///
+/// # Parameters
+///
+/// * `identifier` An [AssetIdentifier](crate::core::types::asset_identifier::AssetIdentifier)
+/// encapsulated within a [SerializedEnum](crate::core::types::serialized_enum::SerializedEnum).
+/// * `asset_type` The asset type for which the fee payment detail was created.
+fn validate_purge_fee_payment_detail(
+    identifier: &SerializedEnum,
+    asset_type: &str,
+) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if let Some(message) = get_asset_identifier_invalid_message(identifier) {
+        invalid_fields.push(message);
+    }
+    if asset_type.is_empty() {
+        invalid_fields.push("asset_type: must not be blank".to_string());
+    }
+    gen_validation_response("ExecuteMsg::PurgeFeePaymentDetail", invalid_fields)
+}
+
+/// Validates the [PurgeStaleFeeDetail](crate::core::msg::ExecuteMsg::PurgeStaleFeeDetail)
+/// variant of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on
+/// success, or an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields)
+/// error when invalid fields are found.
+///
+/// # Parameters
+///
+/// * `identifier` An [AssetIdentifier](crate::core::types::asset_identifier::AssetIdentifier)
+/// encapsulated within a [SerializedEnum](crate::core::types::serialized_enum::SerializedEnum).
+/// * `asset_type` The asset type for which the stale fee payment detail was created.
+fn validate_purge_stale_fee_detail(
+    identifier: &SerializedEnum,
+    asset_type: &str,
+) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if let Some(message) = get_asset_identifier_invalid_message(identifier) {
+        invalid_fields.push(message);
+    }
+    if asset_type.is_empty() {
+        invalid_fields.push("asset_type: must not be blank".to_string());
+    }
+    gen_validation_response("ExecuteMsg::PurgeStaleFeeDetail", invalid_fields)
+}
+
+/// Validates the [SetFeeDetailMaxAge](crate::core::msg::ExecuteMsg::SetFeeDetailMaxAge) variant
+/// of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on success, or
+/// an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields) error when
+/// invalid fields are found.
+///
+/// # Parameters
+///
+/// * `max_age_blocks` The number of blocks a fee payment detail must be stored for before it can
+/// be purged, if any.
+fn validate_set_fee_detail_max_age(max_age_blocks: &Option<u64>) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if *max_age_blocks == Some(0) {
+        invalid_fields.push("max_age_blocks: must be greater than zero".to_string());
+    }
+    gen_validation_response("ExecuteMsg::SetFeeDetailMaxAge", invalid_fields)
+}
+
+/// Validates the [SetMaxAccessRoutesPerOwner](crate::core::msg::ExecuteMsg::SetMaxAccessRoutesPerOwner)
+/// variant of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on
+/// success, or an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields)
+/// error when invalid fields are found.
+///
+/// # Parameters
+///
+/// * `limit` The new maximum number of access routes per owner.
+fn validate_set_max_access_routes_per_owner(limit: u32) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if limit == 0 {
+        invalid_fields.push("limit: must be greater than zero".to_string());
+    }
+    gen_validation_response("ExecuteMsg::SetMaxAccessRoutesPerOwner", invalid_fields)
+}
+
+/// Validates the [ExpireStaleVerification](crate::core::msg::ExecuteMsg::ExpireStaleVerification)
+/// variant of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on
+/// success, or an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields)
+/// error when invalid fields are found.
+///
+/// # Parameters
+///
+/// * `identifier` An [AssetIdentifier](crate::core::types::asset_identifier::AssetIdentifier)
+/// encapsulated within a [SerializedEnum](crate::core::types::serialized_enum::SerializedEnum).
+/// * `asset_type` The asset type of the pending verification.
+fn validate_expire_stale_verification(
+    identifier: &SerializedEnum,
+    asset_type: &str,
+) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if let Some(message) = get_asset_identifier_invalid_message(identifier) {
+        invalid_fields.push(message);
+    }
+    if asset_type.is_empty() {
+        invalid_fields.push("asset_type: must not be blank".to_string());
+    }
+    gen_validation_response("ExecuteMsg::ExpireStaleVerification", invalid_fields)
+}
+
+/// Validates the [CancelPendingOnboarding](crate::core::msg::ExecuteMsg::CancelPendingOnboarding)
+/// variant of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on
+/// success, or an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields)
+/// error when invalid fields are found.
+///
+/// # Parameters
+///
+/// * `identifier` An [AssetIdentifier](crate::core::types::asset_identifier::AssetIdentifier)
+/// encapsulated within a [SerializedEnum](crate::core::types::serialized_enum::SerializedEnum).
+/// * `asset_type` The asset type of the pending onboarding.
+fn validate_cancel_pending_onboarding(
+    identifier: &SerializedEnum,
+    asset_type: &str,
+) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if let Some(message) = get_asset_identifier_invalid_message(identifier) {
+        invalid_fields.push(message);
+    }
+    if asset_type.is_empty() {
+        invalid_fields.push("asset_type: must not be blank".to_string());
+    }
+    gen_validation_response("ExecuteMsg::CancelPendingOnboarding", invalid_fields)
+}
+
+/// Validates the [AppealVerification](crate::core::msg::ExecuteMsg::AppealVerification) variant
+/// of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on success, or
+/// an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields) error when
+/// invalid fields are found.
+///
+/// # Parameters
+///
+/// * `identifier` An [AssetIdentifier](crate::core::types::asset_identifier::AssetIdentifier)
+/// encapsulated within a [SerializedEnum](crate::core::types::serialized_enum::SerializedEnum).
+/// * `asset_type` The asset type of the denied verification.
+/// * `appeal_reason` The requestor's explanation of why the denial should be overturned.
+fn validate_appeal_verification(
+    identifier: &SerializedEnum,
+    asset_type: &str,
+    appeal_reason: &str,
+) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if let Some(message) = get_asset_identifier_invalid_message(identifier) {
+        invalid_fields.push(message);
+    }
+    if asset_type.is_empty() {
+        invalid_fields.push("asset_type: must not be blank".to_string());
+    }
+    if appeal_reason.trim().is_empty() {
+        invalid_fields.push("appeal_reason: must not be blank".to_string());
+    }
+    gen_validation_response("ExecuteMsg::AppealVerification", invalid_fields)
+}
+
+/// Validates the [ResolveAppeal](crate::core::msg::ExecuteMsg::ResolveAppeal) variant of the
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on success, or an
+/// [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields) error when
+/// invalid fields are found.
+///
+/// # Parameters
+///
+/// * `identifier` An [AssetIdentifier](crate::core::types::asset_identifier::AssetIdentifier)
+/// encapsulated within a [SerializedEnum](crate::core::types::serialized_enum::SerializedEnum).
+/// * `asset_type` The asset type of the appealed verification.
+/// * `resolution_message` The admin's explanation of the resolution.
+fn validate_resolve_appeal(
+    identifier: &SerializedEnum,
+    asset_type: &str,
+    resolution_message: &str,
+) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if let Some(message) = get_asset_identifier_invalid_message(identifier) {
+        invalid_fields.push(message);
+    }
+    if asset_type.is_empty() {
+        invalid_fields.push("asset_type: must not be blank".to_string());
+    }
+    if resolution_message.trim().is_empty() {
+        invalid_fields.push("resolution_message: must not be blank".to_string());
+    }
+    gen_validation_response("ExecuteMsg::ResolveAppeal", invalid_fields)
+}
+
+/// Validates the [TransferAdmin](crate::core::msg::ExecuteMsg::TransferAdmin) variant of the
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on success, or an
+/// [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields) error when
+/// invalid fields are found.
+///
+/// # Parameters
+///
+/// * `new_admin_address` The bech32 address of the account nominated to become the contract's admin.
+fn validate_transfer_admin(new_admin_address: &str) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if new_admin_address.is_empty() {
+        invalid_fields.push("new_admin_address: must not be blank".to_string());
+    }
+    gen_validation_response("ExecuteMsg::TransferAdmin", invalid_fields)
+}
+
+/// Validates the [RelayOsGatewayAccess](crate::core::msg::ExecuteMsg::RelayOsGatewayAccess)
+/// variant of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on
+/// success, or an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields)
+/// error when invalid fields are found.
+///
+/// # Parameters
+///
+/// * `access` The object store gateway access grant or revocation to emit.
+fn validate_relay_os_gateway_access(access: &OsGatewayAccess) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if access.scope_address.is_empty() {
+        invalid_fields.push("access:scope_address: must not be blank".to_string());
+    }
+    if access.target_account_address.is_empty() {
+        invalid_fields.push("access:target_account_address: must not be blank".to_string());
+    }
+    if access.access_grant_id.is_empty() {
+        invalid_fields.push("access:access_grant_id: must not be blank".to_string());
+    }
+    gen_validation_response("ExecuteMsg::RelayOsGatewayAccess", invalid_fields)
+}
+
 /// Validates a serialized enum to ensure that it can convert to a valid [AssetIdentifier](crate::core::types::asset_identifier::AssetIdentifier),
 /// returning an optional string that is only populated if an error is present.
 ///
@@ -210,6 +994,22 @@ fn get_asset_identifier_invalid_message(identifier: &SerializedEnum) -> Option<S
                     None
                 }
             }
+            AssetIdentifier::ScopeUuid(scope_uuid) => {
+                if scope_uuid.is_empty() {
+                    "identifier:scope_uuid: must not be blank"
+                        .to_string()
+                        .to_some()
+                } else if Uuid::parse_str(&scope_uuid)
+                    .map(|uuid| uuid.get_version_num() != 4)
+                    .unwrap_or(true)
+                {
+                    "identifier:scope_uuid: must be a valid uuid v4"
+                        .to_string()
+                        .to_some()
+                } else {
+                    None
+                }
+            }
         },
         Err(e) => match e {
             ContractError::UnexpectedSerializedEnum {
@@ -251,17 +1051,32 @@ fn gen_validation_response<S: Into<String>>(
 
 #[cfg(test)]
 mod tests {
+    use crate::core::types::os_gateway_access::OsGatewayAccess;
     use crate::core::types::serialized_enum::SerializedEnum;
-    use crate::testutil::test_constants::DEFAULT_VERIFIER_ADDRESS;
+    use crate::testutil::test_constants::{
+        DEFAULT_SCOPE_ADDRESS, DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::util::constants::{
+        MAX_ONBOARDING_MEMO_LENGTH, MAX_REQUESTOR_METADATA_LENGTH, VALID_VERIFIER_ADDRESS_PREFIXES,
+    };
+    use crate::util::traits::OptionExtensions;
     use crate::validation::validate_execute_msg::{
-        validate_delete_asset_definition, validate_update_access_routes,
+        validate_delete_asset_definition, validate_transfer_admin, validate_update_access_routes,
     };
     use crate::{
         core::{error::ContractError, types::asset_identifier::AssetIdentifier},
         util::aliases::AssetResult,
     };
 
-    use super::{validate_onboard_asset, validate_toggle_asset_definition, validate_verify_asset};
+    use super::{
+        validate_appeal_verification, validate_clone_asset_definition,
+        validate_deprecate_asset_definition, validate_freeze_asset_definition,
+        validate_onboard_asset, validate_partial_update_asset_definition,
+        validate_relay_os_gateway_access, validate_resolve_appeal,
+        validate_rotate_verifier_address, validate_set_fee_detail_max_age,
+        validate_set_max_access_routes_per_owner, validate_toggle_asset_definition,
+        validate_toggle_asset_verifier, validate_verify_asset,
+    };
 
     #[test]
     fn test_validate_onboard_asset_success_for_asset_uuid() {
@@ -269,18 +1084,61 @@ mod tests {
             &AssetIdentifier::asset_uuid("asset_uuid").to_serialized_enum(),
             "asset_type",
             DEFAULT_VERIFIER_ADDRESS,
+            None,
+            None,
+        )
+        .expect("expected validation to pass when all arguments are properly supplied");
+    }
+
+    #[test]
+    fn test_validate_onboard_asset_success_for_scope_address() {
+        validate_onboard_asset(
+            &AssetIdentifier::scope_address("scope_address").to_serialized_enum(),
+            "asset_type",
+            DEFAULT_VERIFIER_ADDRESS,
+            None,
+            None,
         )
         .expect("expected validation to pass when all arguments are properly supplied");
     }
 
     #[test]
-    fn test_validate_onboard_asset_success_for_scope_address() {
+    fn test_validate_onboard_asset_success_for_scope_uuid() {
         validate_onboard_asset(
-            &AssetIdentifier::scope_address("scope_address").to_serialized_enum(),
+            &AssetIdentifier::scope_uuid("2d1cf4a4-3f9c-4a4e-9b0e-7c1f5d0a8b3e")
+                .to_serialized_enum(),
             "asset_type",
             DEFAULT_VERIFIER_ADDRESS,
+            None,
+            None,
         )
-        .expect("expected validation to pass when all arguments are properly supplied");
+        .expect("expected validation to pass when a valid scope uuid is supplied");
+    }
+
+    #[test]
+    fn test_validate_onboard_asset_invalid_scope_uuid() {
+        for scope_uuid in ["not-a-uuid", "0c39efb6-9fef-11ec-ab21-6bf5c9fb3f83"] {
+            let result = validate_onboard_asset(
+                &AssetIdentifier::scope_uuid(scope_uuid).to_serialized_enum(),
+                "asset_type",
+                DEFAULT_VERIFIER_ADDRESS,
+                None,
+                None,
+            );
+            test_invalid_message_fields(result, |message_type, invalid_fields| {
+                assert_eq!(
+                    "ExecuteMsg::OnboardAsset",
+                    message_type.as_str(),
+                    "incorrect message type for error"
+                );
+                assert_eq!(
+                    vec!["identifier:scope_uuid: must be a valid uuid v4".to_string()],
+                    invalid_fields,
+                    "expected the scope uuid [{}] to be rejected",
+                    scope_uuid,
+                );
+            });
+        }
     }
 
     #[test]
@@ -289,6 +1147,8 @@ mod tests {
             &AssetIdentifier::asset_uuid("asset_uuid").to_serialized_enum(),
             "",
             DEFAULT_VERIFIER_ADDRESS,
+            None,
+            None,
         );
         test_invalid_message_fields(result, |message_type, invalid_fields| {
             assert_eq!(
@@ -315,6 +1175,8 @@ mod tests {
             &AssetIdentifier::asset_uuid("asset_uuid").to_serialized_enum(),
             "asset_type",
             "not_bech32",
+            None,
+            None,
         );
         test_invalid_message_fields(result, |message_type, invalid_fields| {
             assert_eq!(
@@ -341,6 +1203,48 @@ mod tests {
             &SerializedEnum::new("incorrect_variant", "value"),
             "asset_type",
             DEFAULT_VERIFIER_ADDRESS,
+            None,
+            None,
+        );
+        test_invalid_message_fields(result, |message_type, invalid_fields| {
+            assert_eq!(
+                "ExecuteMsg::OnboardAsset",
+                message_type.as_str(),
+                "incorrect message type for error",
+            );
+            assert_eq!(
+                1,
+                invalid_fields.len(),
+                "expected only a single invalid field to be found",
+            );
+            assert_eq!(
+                "identifier: received type [incorrect_variant]: Invalid AssetIdentifier. Expected one of [asset_uuid, scope_address, scope_uuid]",
+                invalid_fields.first().unwrap().as_str(),
+                "expected the appropriate error message to be returned",
+            );
+        });
+    }
+
+    #[test]
+    fn test_validate_onboard_asset_success_for_memo_at_max_length() {
+        validate_onboard_asset(
+            &AssetIdentifier::scope_address("scope_address").to_serialized_enum(),
+            "asset_type",
+            DEFAULT_VERIFIER_ADDRESS,
+            Some("a".repeat(MAX_ONBOARDING_MEMO_LENGTH).as_str()),
+            None,
+        )
+        .expect("expected validation to pass when the memo is exactly the maximum length");
+    }
+
+    #[test]
+    fn test_validate_onboard_asset_invalid_memo() {
+        let result = validate_onboard_asset(
+            &AssetIdentifier::scope_address("scope_address").to_serialized_enum(),
+            "asset_type",
+            DEFAULT_VERIFIER_ADDRESS,
+            Some("a".repeat(MAX_ONBOARDING_MEMO_LENGTH + 1).as_str()),
+            None,
         );
         test_invalid_message_fields(result, |message_type, invalid_fields| {
             assert_eq!(
@@ -354,13 +1258,49 @@ mod tests {
                 "expected only a single invalid field to be found",
             );
             assert_eq!(
-                "identifier: received type [incorrect_variant]: Invalid AssetIdentifier. Expected one of [asset_uuid, scope_address]",
+                "memo: must not exceed 256 characters",
                 invalid_fields.first().unwrap().as_str(),
                 "expected the appropriate error message to be returned",
             );
         });
     }
 
+    #[test]
+    fn test_validate_onboard_asset_success_for_metadata_at_max_length() {
+        validate_onboard_asset(
+            &AssetIdentifier::scope_address("scope_address").to_serialized_enum(),
+            "asset_type",
+            DEFAULT_VERIFIER_ADDRESS,
+            None,
+            Some("a".repeat(MAX_REQUESTOR_METADATA_LENGTH).as_str()),
+        )
+        .expect("expected validation to pass when the metadata is exactly the maximum length");
+    }
+
+    #[test]
+    fn test_validate_onboard_asset_invalid_metadata() {
+        // Multi-byte characters count against the limit by their encoded length
+        let result = validate_onboard_asset(
+            &AssetIdentifier::scope_address("scope_address").to_serialized_enum(),
+            "asset_type",
+            DEFAULT_VERIFIER_ADDRESS,
+            None,
+            Some("é".repeat(MAX_REQUESTOR_METADATA_LENGTH / 2 + 1).as_str()),
+        );
+        test_invalid_message_fields(result, |message_type, invalid_fields| {
+            assert_eq!(
+                "ExecuteMsg::OnboardAsset",
+                message_type.as_str(),
+                "incorrect message type for error",
+            );
+            assert_eq!(
+                vec!["metadata: must not exceed 1024 bytes".to_string()],
+                invalid_fields,
+                "expected the over-length metadata to be the only invalid field",
+            );
+        });
+    }
+
     #[test]
     fn test_validate_verify_asset_success_for_asset_uuid() {
         validate_verify_asset(
@@ -439,13 +1379,68 @@ mod tests {
                 "expected only a single invalid field to be found",
             );
             assert_eq!(
-                "identifier: received type [incompatible_variant]: Invalid AssetIdentifier. Expected one of [asset_uuid, scope_address]",
+                "identifier: received type [incompatible_variant]: Invalid AssetIdentifier. Expected one of [asset_uuid, scope_address, scope_uuid]",
                 invalid_fields.first().unwrap().as_str(),
                 "expected the appropriate error message to be returned",
             );
         });
     }
 
+    #[test]
+    fn test_validate_clone_asset_definition_success() {
+        validate_clone_asset_definition("heloc", "heloc_clone")
+            .expect("expected the validation to pass when all fields are correctly supplied");
+    }
+
+    #[test]
+    fn test_validate_clone_asset_definition_invalid_fields() {
+        test_invalid_message_fields(
+            validate_clone_asset_definition("", ""),
+            |message_type, invalid_fields| {
+                assert_eq!(
+                    "ExecuteMsg::CloneAssetDefinition", message_type,
+                    "incorrect message type for error",
+                );
+                assert_eq!(
+                    vec![
+                        "source_asset_type: must not be blank".to_string(),
+                        "new_asset_type: must not be blank".to_string(),
+                    ],
+                    invalid_fields,
+                    "expected the appropriate error messages to be returned",
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_validate_partial_update_asset_definition_success() {
+        validate_partial_update_asset_definition("asset_type")
+            .expect("expected the validation to pass when all fields are correctly supplied");
+    }
+
+    #[test]
+    fn test_validate_partial_update_asset_definition_invalid_asset_type() {
+        let result = validate_partial_update_asset_definition("");
+        test_invalid_message_fields(result, |message_type, invalid_fields| {
+            assert_eq!(
+                "ExecuteMsg::UpdateAssetDefinition",
+                message_type.as_str(),
+                "incorrect message type for error"
+            );
+            assert_eq!(
+                1,
+                invalid_fields.len(),
+                "expected only a single invalid field to be found"
+            );
+            assert_eq!(
+                "asset_definition:asset_type: must not be blank",
+                invalid_fields.first().unwrap().as_str(),
+                "expected the appropriate error message to be returned"
+            );
+        });
+    }
+
     #[test]
     fn test_validate_toggle_asset_definition_success() {
         validate_toggle_asset_definition("asset_type")
@@ -474,6 +1469,108 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_validate_toggle_asset_verifier_success() {
+        validate_toggle_asset_verifier("heloc", DEFAULT_VERIFIER_ADDRESS)
+            .expect("expected the validation for asset type and verifier address to pass");
+    }
+
+    #[test]
+    fn test_validate_toggle_asset_verifier_invalid_fields() {
+        let result = validate_toggle_asset_verifier("", "");
+        test_invalid_message_fields(result, |message_type, invalid_fields| {
+            assert_eq!(
+                "ExecuteMsg::ToggleAssetVerifier", message_type,
+                "incorrect message type for error",
+            );
+            assert_eq!(
+                vec![
+                    "asset_type: must not be blank".to_string(),
+                    "verifier_address: must not be blank".to_string(),
+                ],
+                invalid_fields,
+                "expected the appropriate error messages to be returned",
+            );
+        });
+    }
+
+    #[test]
+    fn test_validate_rotate_verifier_address_success() {
+        validate_rotate_verifier_address(
+            "heloc",
+            DEFAULT_VERIFIER_ADDRESS,
+            "tp1hsqtppgy7mgd64q0uvk7q39qa7h8hp30urqs6n",
+            false,
+        )
+        .expect("expected the validation for asset type and verifier addresses to pass");
+    }
+
+    #[test]
+    fn test_validate_rotate_verifier_address_invalid_prefix() {
+        test_invalid_message_fields(
+            validate_rotate_verifier_address(
+                "heloc",
+                DEFAULT_VERIFIER_ADDRESS,
+                DEFAULT_SCOPE_ADDRESS,
+                false,
+            ),
+            |_, invalid_fields| {
+                assert_eq!(
+                    vec![format!(
+                        "new_address: must use one of the prefixes [{}]",
+                        VALID_VERIFIER_ADDRESS_PREFIXES.join(", "),
+                    )],
+                    invalid_fields,
+                    "expected a new address with an unlisted prefix to be rejected",
+                );
+            },
+        );
+        validate_rotate_verifier_address(
+            "heloc",
+            DEFAULT_VERIFIER_ADDRESS,
+            DEFAULT_SCOPE_ADDRESS,
+            true,
+        )
+        .expect("expected the prefix check to be skipped when all prefixes are allowed");
+    }
+
+    #[test]
+    fn test_validate_rotate_verifier_address_invalid_fields() {
+        test_invalid_message_fields(
+            validate_rotate_verifier_address("", "", "not-an-address", false),
+            |message_type, invalid_fields| {
+                assert_eq!(
+                    "ExecuteMsg::RotateVerifierAddress", message_type,
+                    "incorrect message type for error",
+                );
+                assert_eq!(
+                    vec![
+                        "asset_type: must not be blank".to_string(),
+                        "old_address: must not be blank".to_string(),
+                        "new_address: must be valid bech32".to_string(),
+                    ],
+                    invalid_fields,
+                    "expected the appropriate error messages to be returned",
+                );
+            },
+        );
+        test_invalid_message_fields(
+            validate_rotate_verifier_address(
+                "heloc",
+                DEFAULT_VERIFIER_ADDRESS,
+                DEFAULT_VERIFIER_ADDRESS,
+                false,
+            ),
+            |_, invalid_fields| {
+                assert_eq!(
+                    vec!["new_address: must differ from old_address".to_string()],
+                    invalid_fields,
+                    "expected an unchanged address to be rejected",
+                );
+            },
+        );
+    }
+
     #[test]
     fn test_validate_update_access_routes_invalid_identifier_asset_uuid() {
         let result = validate_update_access_routes(
@@ -564,7 +1661,7 @@ mod tests {
                 "expected only a single invalid field to be found",
             );
             assert_eq!(
-                "identifier: received type [weird_variant]: Invalid AssetIdentifier. Expected one of [asset_uuid, scope_address]",
+                "identifier: received type [weird_variant]: Invalid AssetIdentifier. Expected one of [asset_uuid, scope_address, scope_uuid]",
                 invalid_fields.first().unwrap().as_str(),
                 "expected the appropriate error message to be returned",
             );
@@ -598,6 +1695,245 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_validate_deprecate_asset_definition_success() {
+        validate_deprecate_asset_definition("heloc", None)
+            .expect("expected the validation without a replacement to pass");
+        validate_deprecate_asset_definition("heloc", "mortgage".to_some())
+            .expect("expected the validation with a replacement to pass");
+    }
+
+    #[test]
+    fn test_validate_deprecate_asset_definition_invalid_fields() {
+        test_invalid_message_fields(
+            validate_deprecate_asset_definition("", "".to_some()),
+            |message_type, invalid_fields| {
+                assert_eq!(
+                    "ExecuteMsg::DeprecateAssetDefinition", message_type,
+                    "incorrect message type for error",
+                );
+                assert_eq!(
+                    vec![
+                        "asset_type: must not be blank".to_string(),
+                        "replacement_asset_type: must not be blank".to_string(),
+                    ],
+                    invalid_fields,
+                    "expected the appropriate error messages to be returned",
+                );
+            },
+        );
+        test_invalid_message_fields(
+            validate_deprecate_asset_definition("heloc", "HELOC".to_some()),
+            |_, invalid_fields| {
+                assert_eq!(
+                    vec!["replacement_asset_type: must differ from asset_type".to_string()],
+                    invalid_fields,
+                    "expected a deprecated asset type to be rejected as its own replacement",
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_validate_freeze_asset_definition_success() {
+        validate_freeze_asset_definition("heloc")
+            .expect("expected the validation for asset type to pass");
+    }
+
+    #[test]
+    fn test_validate_freeze_asset_definition_invalid_asset_type() {
+        let result = validate_freeze_asset_definition("");
+        test_invalid_message_fields(result, |message_type, invalid_fields| {
+            assert_eq!(
+                "ExecuteMsg::FreezeAssetDefinition", message_type,
+                "incorrect message type for error",
+            );
+            assert_eq!(
+                1,
+                invalid_fields.len(),
+                "expected only a single invalid field to be found",
+            );
+            assert_eq!(
+                "asset_type: must not be blank",
+                invalid_fields.first().unwrap(),
+                "expected the appropriate error message to be returned"
+            );
+        });
+    }
+
+    #[test]
+    fn test_validate_transfer_admin_success() {
+        validate_transfer_admin(DEFAULT_SENDER_ADDRESS)
+            .expect("expected the validation for the new admin address to pass");
+    }
+
+    #[test]
+    fn test_validate_transfer_admin_invalid_new_admin_address() {
+        let result = validate_transfer_admin("");
+        test_invalid_message_fields(result, |message_type, invalid_fields| {
+            assert_eq!(
+                "ExecuteMsg::TransferAdmin", message_type,
+                "incorrect message type for error",
+            );
+            assert_eq!(
+                1,
+                invalid_fields.len(),
+                "expected only a single invalid field to be found",
+            );
+            assert_eq!(
+                "new_admin_address: must not be blank",
+                invalid_fields.first().unwrap(),
+                "expected the appropriate error message to be returned"
+            );
+        });
+    }
+
+    #[test]
+    fn test_validate_relay_os_gateway_access_success() {
+        validate_relay_os_gateway_access(&OsGatewayAccess::grant(
+            DEFAULT_SCOPE_ADDRESS,
+            DEFAULT_VERIFIER_ADDRESS,
+            "grant-id",
+        ))
+        .expect("expected the validation for a complete gateway access to pass");
+    }
+
+    #[test]
+    fn test_validate_relay_os_gateway_access_blank_fields() {
+        let result = validate_relay_os_gateway_access(&OsGatewayAccess::revoke("", "", ""));
+        test_invalid_message_fields(result, |message_type, invalid_fields| {
+            assert_eq!(
+                "ExecuteMsg::RelayOsGatewayAccess", message_type,
+                "incorrect message type for error",
+            );
+            assert_eq!(
+                vec![
+                    "access:scope_address: must not be blank",
+                    "access:target_account_address: must not be blank",
+                    "access:access_grant_id: must not be blank",
+                ],
+                invalid_fields,
+                "expected every blank field to be reported",
+            );
+        });
+    }
+
+    #[test]
+    fn test_validate_set_fee_detail_max_age_success() {
+        validate_set_fee_detail_max_age(&Some(100))
+            .expect("expected the validation for a positive max age to pass");
+        validate_set_fee_detail_max_age(&None)
+            .expect("expected the validation for an omitted max age to pass");
+    }
+
+    #[test]
+    fn test_validate_set_fee_detail_max_age_zero() {
+        let result = validate_set_fee_detail_max_age(&Some(0));
+        test_invalid_message_fields(result, |message_type, invalid_fields| {
+            assert_eq!(
+                "ExecuteMsg::SetFeeDetailMaxAge", message_type,
+                "incorrect message type for error",
+            );
+            assert_eq!(
+                vec!["max_age_blocks: must be greater than zero".to_string()],
+                invalid_fields,
+                "expected the appropriate error message to be returned",
+            );
+        });
+    }
+
+    #[test]
+    fn test_validate_set_max_access_routes_per_owner_success() {
+        validate_set_max_access_routes_per_owner(1)
+            .expect("expected the validation for a positive limit to pass");
+    }
+
+    #[test]
+    fn test_validate_set_max_access_routes_per_owner_zero() {
+        let result = validate_set_max_access_routes_per_owner(0);
+        test_invalid_message_fields(result, |message_type, invalid_fields| {
+            assert_eq!(
+                "ExecuteMsg::SetMaxAccessRoutesPerOwner", message_type,
+                "incorrect message type for error",
+            );
+            assert_eq!(
+                vec!["limit: must be greater than zero".to_string()],
+                invalid_fields,
+                "expected the appropriate error message to be returned",
+            );
+        });
+    }
+
+    #[test]
+    fn test_validate_appeal_verification_success() {
+        validate_appeal_verification(
+            &AssetIdentifier::scope_address("scope_address").to_serialized_enum(),
+            "asset_type",
+            "the verifier reviewed the wrong records",
+        )
+        .expect("expected the validation to pass when all fields are correctly supplied");
+    }
+
+    #[test]
+    fn test_validate_appeal_verification_invalid_fields() {
+        test_invalid_message_fields(
+            validate_appeal_verification(
+                &AssetIdentifier::scope_address("scope_address").to_serialized_enum(),
+                "",
+                "  ",
+            ),
+            |message_type, invalid_fields| {
+                assert_eq!(
+                    "ExecuteMsg::AppealVerification", message_type,
+                    "incorrect message type for error",
+                );
+                assert_eq!(
+                    vec![
+                        "asset_type: must not be blank".to_string(),
+                        "appeal_reason: must not be blank".to_string(),
+                    ],
+                    invalid_fields,
+                    "expected the appropriate error messages to be returned",
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_validate_resolve_appeal_success() {
+        validate_resolve_appeal(
+            &AssetIdentifier::scope_address("scope_address").to_serialized_enum(),
+            "asset_type",
+            "the appeal was reviewed",
+        )
+        .expect("expected the validation to pass when all fields are correctly supplied");
+    }
+
+    #[test]
+    fn test_validate_resolve_appeal_invalid_fields() {
+        test_invalid_message_fields(
+            validate_resolve_appeal(
+                &AssetIdentifier::scope_address("scope_address").to_serialized_enum(),
+                "",
+                "",
+            ),
+            |message_type, invalid_fields| {
+                assert_eq!(
+                    "ExecuteMsg::ResolveAppeal", message_type,
+                    "incorrect message type for error",
+                );
+                assert_eq!(
+                    vec![
+                        "asset_type: must not be blank".to_string(),
+                        "resolution_message: must not be blank".to_string(),
+                    ],
+                    invalid_fields,
+                    "expected the appropriate error messages to be returned",
+                );
+            },
+        );
+    }
+
     // Extracts the InvalidMessageFunds error data from a response from one of the functions
     // in this file, allowing a unit test to target the relevant information without as much
     // boilerplate nonsense.
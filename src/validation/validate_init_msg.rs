@@ -4,12 +4,18 @@ use crate::core::types::asset_definition::{AssetDefinitionInputV3, AssetDefiniti
 use crate::core::types::fee_destination::FeeDestinationV2;
 use crate::core::types::verifier_detail::VerifierDetailV2;
 use crate::util::aliases::AssetResult;
-use crate::util::constants::VALID_VERIFIER_DENOMS;
+use crate::util::constants::{
+    MAX_BASE_CONTRACT_NAME_LENGTH, MAX_BASE_CONTRACT_NAME_SEGMENT_LENGTH,
+    MAX_FEE_DESTINATION_DISPLAY_NAME_LENGTH, VALID_VERIFIER_ADDRESS_PREFIXES,
+    VALID_VERIFIER_DENOMS,
+};
 use crate::util::functions::distinct_count_by_property;
-use crate::util::scope_address_utils::bech32_string_to_addr;
+use crate::util::scope_address_utils::{
+    bech32_string_to_addr, has_bech32_prefix, is_scope_spec_address,
+};
 
 use crate::core::types::onboarding_cost::OnboardingCost;
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Decimal, Uint128};
 use result_extensions::ResultExtensions;
 
 /// Validates the integrity of an intercepted [InitMsg](crate::core::msg::InitMsg) and its
@@ -19,10 +25,7 @@ use result_extensions::ResultExtensions;
 ///
 /// * `msg` The init msg sent during the [instantiation](crate::contract::instantiate) process.
 pub fn validate_init_msg(msg: &InitMsg) -> AssetResult<()> {
-    let mut invalid_fields: Vec<String> = vec![];
-    if msg.base_contract_name.is_empty() {
-        invalid_fields.push("base_contract_name: must not be blank".to_string());
-    }
+    let mut invalid_fields = validate_base_contract_name(&msg.base_contract_name);
     if distinct_count_by_property(&msg.asset_definitions, |def| &def.asset_type)
         != msg.asset_definitions.len()
     {
@@ -30,12 +33,23 @@ pub fn validate_init_msg(msg: &InitMsg) -> AssetResult<()> {
             "asset_definitions: each definition must specify a unique asset type".to_string(),
         );
     }
+    let allow_all_bech32_prefixes = msg.allow_all_bech32_prefixes.unwrap_or(false);
     let mut asset_messages = msg
         .asset_definitions
         .iter()
-        .flat_map(validate_asset_definition_input_internal)
+        .flat_map(|input| {
+            validate_asset_definition_input_internal(input, allow_all_bech32_prefixes)
+        })
         .collect::<Vec<String>>();
     invalid_fields.append(&mut asset_messages);
+    if msg.max_access_routes_per_owner == Some(0) {
+        invalid_fields
+            .push("max_access_routes_per_owner: must be greater than zero if provided".to_string());
+    }
+    invalid_fields.append(&mut validate_os_gateway_grant_prefix(
+        msg.os_gateway_grant_prefix.as_deref(),
+        "os_gateway_grant_prefix",
+    ));
     if !invalid_fields.is_empty() {
         ContractError::InvalidMessageFields {
             message_type: "Instantiate".to_string(),
@@ -53,8 +67,17 @@ pub fn validate_init_msg(msg: &InitMsg) -> AssetResult<()> {
 /// # Parameters
 ///
 /// * `input` The asset definition input value to validate for issues.
-pub fn validate_asset_definition_input(input: &AssetDefinitionInputV3) -> AssetResult<()> {
-    validate_asset_definition(&input.as_asset_definition())
+/// * `allow_all_bech32_prefixes` Whether or not verifier and fee destination addresses may use
+///   prefixes other than the [VALID_VERIFIER_ADDRESS_PREFIXES](crate::util::constants::VALID_VERIFIER_ADDRESS_PREFIXES).
+pub fn validate_asset_definition_input(
+    input: &AssetDefinitionInputV3,
+    allow_all_bech32_prefixes: bool,
+) -> AssetResult<()> {
+    validate_asset_definition(
+        &input.as_asset_definition(),
+        allow_all_bech32_prefixes,
+        false,
+    )
 }
 
 /// Validates that an asset definition value is properly formed, ensuring that all fields are
@@ -63,8 +86,20 @@ pub fn validate_asset_definition_input(input: &AssetDefinitionInputV3) -> AssetR
 /// # Parameters
 ///
 /// * `asset_definition` The asset definition value to validate for issues.
-pub fn validate_asset_definition(asset_definition: &AssetDefinitionV3) -> AssetResult<()> {
-    let invalid_fields = validate_asset_definition_internal(asset_definition);
+/// * `allow_all_bech32_prefixes` Whether or not verifier and fee destination addresses may use
+///   prefixes other than the [VALID_VERIFIER_ADDRESS_PREFIXES](crate::util::constants::VALID_VERIFIER_ADDRESS_PREFIXES).
+/// * `require_entity_detail` Whether or not each verifier must include an [EntityDetail](crate::core::types::entity_detail::EntityDetail)
+///   with a name.  Sourced from the contract's [state](crate::core::state::StateV2::require_entity_detail_for_verifiers).
+pub fn validate_asset_definition(
+    asset_definition: &AssetDefinitionV3,
+    allow_all_bech32_prefixes: bool,
+    require_entity_detail: bool,
+) -> AssetResult<()> {
+    let invalid_fields = validate_asset_definition_internal(
+        asset_definition,
+        allow_all_bech32_prefixes,
+        require_entity_detail,
+    );
     if !invalid_fields.is_empty() {
         ContractError::InvalidMessageFields {
             message_type: "AssetDefinition".to_string(),
@@ -82,8 +117,21 @@ pub fn validate_asset_definition(asset_definition: &AssetDefinitionV3) -> AssetR
 /// # Parameters
 ///
 /// * `verifier` The verifier detail value to validate for issues.
-pub fn validate_verifier(verifier: &VerifierDetailV2) -> AssetResult<()> {
-    validate_verifier_with_provided_errors(verifier, None)
+/// * `allow_all_bech32_prefixes` Whether or not verifier and fee destination addresses may use
+///   prefixes other than the [VALID_VERIFIER_ADDRESS_PREFIXES](crate::util::constants::VALID_VERIFIER_ADDRESS_PREFIXES).
+/// * `require_entity_detail` Whether or not each verifier must include an [EntityDetail](crate::core::types::entity_detail::EntityDetail)
+///   with a name.  Sourced from the contract's [state](crate::core::state::StateV2::require_entity_detail_for_verifiers).
+pub fn validate_verifier(
+    verifier: &VerifierDetailV2,
+    allow_all_bech32_prefixes: bool,
+    require_entity_detail: bool,
+) -> AssetResult<()> {
+    validate_verifier_with_provided_errors(
+        verifier,
+        None,
+        allow_all_bech32_prefixes,
+        require_entity_detail,
+    )
 }
 
 /// Validates that a verifier detail is properly formed, ensuring that all fields are properly set
@@ -94,11 +142,18 @@ pub fn validate_verifier(verifier: &VerifierDetailV2) -> AssetResult<()> {
 ///
 /// * `verifier` The verifier detail value to validate for issues.
 /// * `provided_errors` Any existing errors encountered before validation of the verifier detail.
+/// * `allow_all_bech32_prefixes` Whether or not verifier and fee destination addresses may use
+///   prefixes other than the [VALID_VERIFIER_ADDRESS_PREFIXES](crate::util::constants::VALID_VERIFIER_ADDRESS_PREFIXES).
+/// * `require_entity_detail` Whether or not each verifier must include an [EntityDetail](crate::core::types::entity_detail::EntityDetail)
+///   with a name.  Sourced from the contract's [state](crate::core::state::StateV2::require_entity_detail_for_verifiers).
 pub fn validate_verifier_with_provided_errors(
     verifier: &VerifierDetailV2,
     provided_errors: Option<Vec<String>>,
+    allow_all_bech32_prefixes: bool,
+    require_entity_detail: bool,
 ) -> AssetResult<()> {
-    let mut invalid_fields = validate_verifier_internal(verifier);
+    let mut invalid_fields =
+        validate_verifier_internal(verifier, allow_all_bech32_prefixes, require_entity_detail);
     if let Some(errors) = provided_errors {
         for error in errors {
             invalid_fields.push(error);
@@ -115,11 +170,52 @@ pub fn validate_verifier_with_provided_errors(
     }
 }
 
-fn validate_asset_definition_input_internal(input: &AssetDefinitionInputV3) -> Vec<String> {
-    validate_asset_definition_internal(&input.as_asset_definition())
+fn validate_base_contract_name(name: &str) -> Vec<String> {
+    if name.is_empty() {
+        return vec!["base_contract_name: must not be blank".to_string()];
+    }
+    let mut invalid_fields: Vec<String> = vec![];
+    if name.chars().any(char::is_whitespace) {
+        invalid_fields.push("base_contract_name: must not contain whitespace".to_string());
+    }
+    if name.starts_with('.') || name.ends_with('.') {
+        invalid_fields.push("base_contract_name: must not begin or end with a dot".to_string());
+    }
+    if name.contains("..") {
+        invalid_fields.push("base_contract_name: must not contain consecutive dots".to_string());
+    }
+    if name
+        .split('.')
+        .any(|segment| segment.chars().count() > MAX_BASE_CONTRACT_NAME_SEGMENT_LENGTH)
+    {
+        invalid_fields.push(format!(
+            "base_contract_name: each dot-separated segment must be no more than {MAX_BASE_CONTRACT_NAME_SEGMENT_LENGTH} characters"
+        ));
+    }
+    if name.chars().count() > MAX_BASE_CONTRACT_NAME_LENGTH {
+        invalid_fields.push(format!(
+            "base_contract_name: must be no more than {MAX_BASE_CONTRACT_NAME_LENGTH} characters"
+        ));
+    }
+    invalid_fields
+}
+
+fn validate_asset_definition_input_internal(
+    input: &AssetDefinitionInputV3,
+    allow_all_bech32_prefixes: bool,
+) -> Vec<String> {
+    validate_asset_definition_internal(
+        &input.as_asset_definition(),
+        allow_all_bech32_prefixes,
+        false,
+    )
 }
 
-fn validate_asset_definition_internal(asset_definition: &AssetDefinitionV3) -> Vec<String> {
+fn validate_asset_definition_internal(
+    asset_definition: &AssetDefinitionV3,
+    allow_all_bech32_prefixes: bool,
+    require_entity_detail: bool,
+) -> Vec<String> {
     let mut invalid_fields: Vec<String> = vec![];
     if asset_definition.asset_type.is_empty() {
         invalid_fields.push("asset_definition:asset_type: must not be blank".to_string());
@@ -138,19 +234,152 @@ fn validate_asset_definition_internal(asset_definition: &AssetDefinitionV3) -> V
                 .to_string(),
         );
     }
+    if distinct_count_by_property(&asset_definition.labels, |label| label)
+        != asset_definition.labels.len()
+    {
+        invalid_fields.push("asset_definition:labels: each label must be unique".to_string());
+    }
+    if let Some(allowed_requestors) = &asset_definition.allowed_requestors {
+        invalid_fields.append(&mut validate_allowed_requestors_internal(
+            allowed_requestors,
+            "asset_definition:allowed_requestors",
+        ));
+    }
+    if let Some(max_verifiers) = asset_definition.max_verifiers {
+        if asset_definition.verifiers.len() > max_verifiers as usize {
+            invalid_fields.push(
+                "asset_definition:max_verifiers: must not be less than the number of verifiers"
+                    .to_string(),
+            );
+        }
+    }
+    if let Some(scope_spec_address) = &asset_definition.scope_spec_address {
+        if !is_scope_spec_address(scope_spec_address) {
+            invalid_fields.push(
+                "asset_definition:scope_spec_address: must be a valid scope specification address"
+                    .to_string(),
+            );
+        }
+    }
     let mut verifier_messages = asset_definition
         .verifiers
         .iter()
-        .flat_map(validate_verifier_internal)
+        .flat_map(|verifier| {
+            validate_verifier_internal(verifier, allow_all_bech32_prefixes, require_entity_detail)
+        })
         .collect::<Vec<String>>();
     invalid_fields.append(&mut verifier_messages);
     invalid_fields
 }
 
-fn validate_verifier_internal(verifier: &VerifierDetailV2) -> Vec<String> {
+/// Validates a prefix used when generating Object Store Gateway grant ids, ensuring that a provided
+/// value is not blank.  Returns every issue found, prefixed with the provided source.
+///
+/// # Parameters
+///
+/// * `prefix` The grant id prefix to validate, if any.
+/// * `source` The name of the field that contains the prefix, used to prefix each issue.
+pub fn validate_os_gateway_grant_prefix<S: Into<String>>(
+    prefix: Option<&str>,
+    source: S,
+) -> Vec<String> {
     let mut invalid_fields: Vec<String> = vec![];
-    if bech32_string_to_addr(&verifier.address).is_err() {
-        invalid_fields.push("verifier:address: must be a valid address".to_string());
+    if prefix.is_some_and(|prefix| prefix.trim().is_empty()) {
+        invalid_fields.push(format!("{}: must not be blank if provided", source.into()));
+    }
+    invalid_fields
+}
+
+/// Validates a list of requestors allowed to onboard an asset type, ensuring that each value is a
+/// valid bech32 address and that no address is repeated.  Returns every issue found, prefixed with
+/// the provided source.
+///
+/// # Parameters
+///
+/// * `allowed_requestors` The bech32 addresses to validate.
+/// * `source` The name of the field that contains the requestors, used to prefix each issue.
+pub fn validate_allowed_requestors_internal<S: Into<String>>(
+    allowed_requestors: &[String],
+    source: S,
+) -> Vec<String> {
+    let source = source.into();
+    let mut invalid_fields: Vec<String> = vec![];
+    if allowed_requestors
+        .iter()
+        .any(|requestor| bech32_string_to_addr(requestor).is_err())
+    {
+        invalid_fields.push(format!("{}: each value must be a valid address", source));
+    }
+    if distinct_count_by_property(allowed_requestors, |requestor| requestor)
+        != allowed_requestors.len()
+    {
+        invalid_fields.push(format!("{}: each value must be unique", source));
+    }
+    invalid_fields
+}
+
+/// Validates that an address used by a verifier detail or one of its fee destinations is a valid
+/// bech32 address.  Unless all prefixes are allowed, the address must also use one of the
+/// [VALID_VERIFIER_ADDRESS_PREFIXES](crate::util::constants::VALID_VERIFIER_ADDRESS_PREFIXES).
+/// Returns every issue found, prefixed with the provided source.
+///
+/// # Parameters
+///
+/// * `address` The bech32 address to validate.
+/// * `source` The name of the field that contains the address, used to prefix each issue.
+/// * `allow_all_bech32_prefixes` Whether or not the address may use any valid bech32 prefix.
+fn validate_verifier_address_internal<S: Into<String>>(
+    address: &str,
+    source: S,
+    allow_all_bech32_prefixes: bool,
+) -> Vec<String> {
+    let source = source.into();
+    let mut invalid_fields: Vec<String> = vec![];
+    if bech32_string_to_addr(address).is_err() {
+        invalid_fields.push(format!("{}: must be a valid address", source));
+    } else if !allow_all_bech32_prefixes
+        && !has_bech32_prefix(address, &VALID_VERIFIER_ADDRESS_PREFIXES)
+    {
+        invalid_fields.push(format!(
+            "{}: must use one of the prefixes [{}]",
+            source,
+            VALID_VERIFIER_ADDRESS_PREFIXES.join(", "),
+        ));
+    }
+    invalid_fields
+}
+
+fn validate_verifier_internal(
+    verifier: &VerifierDetailV2,
+    allow_all_bech32_prefixes: bool,
+    require_entity_detail: bool,
+) -> Vec<String> {
+    let mut invalid_fields: Vec<String> = validate_verifier_address_internal(
+        &verifier.address,
+        "verifier:address",
+        allow_all_bech32_prefixes,
+    );
+    // Operators can mandate human-readable metadata for every verifier.  Verifiers that were
+    // stored before the requirement was enabled are left untouched until they are next updated
+    if require_entity_detail {
+        match &verifier.entity_detail {
+            None => invalid_fields.push(
+                "verifier:entity_detail: must be provided when entity details are required"
+                    .to_string(),
+            ),
+            Some(entity_detail) => {
+                if entity_detail
+                    .name
+                    .as_ref()
+                    .is_none_or(|name| name.trim().is_empty())
+                {
+                    invalid_fields.push(
+                        "verifier:entity_detail:name: must not be blank when entity details are required"
+                            .to_string(),
+                    );
+                }
+            }
+        }
     }
     if !VALID_VERIFIER_DENOMS.contains(&verifier.onboarding_denom.as_str()) {
         invalid_fields.push(format!(
@@ -161,6 +390,7 @@ fn validate_verifier_internal(verifier: &VerifierDetailV2) -> Vec<String> {
     invalid_fields.append(&mut validate_onboarding_cost_internal(
         &verifier.get_default_cost(),
         "verifier onboarding costs",
+        allow_all_bech32_prefixes,
     ));
     // Ensure that retry costs follow the same standards as the root onboarding cost, if provided.
     // Retry costs are optional and should not cause validation issues when not provided.
@@ -168,8 +398,37 @@ fn validate_verifier_internal(verifier: &VerifierDetailV2) -> Vec<String> {
         invalid_fields.append(&mut validate_onboarding_cost_internal(
             retry_cost,
             "verifier retry costs",
+            allow_all_bech32_prefixes,
         ));
     }
+    // Retry limits are optional, but a limit of zero is rejected because it would prevent a denied
+    // asset from ever being onboarded again
+    if verifier.max_retries == Some(0) {
+        invalid_fields
+            .push("verifier:max_retries: must be greater than zero if provided".to_string());
+    }
+    // A timeout of zero blocks would allow any pending verification to be expired in the very next
+    // block, giving the verifier no realistic opportunity to respond
+    if verifier.verification_timeout_blocks == Some(0) {
+        invalid_fields.push(
+            "verifier:verification_timeout_blocks: must be greater than zero if provided"
+                .to_string(),
+        );
+    }
+    // A window of zero blocks would close in the same block as the denial, preventing any appeal
+    if verifier.max_classifications_per_block == Some(0) {
+        invalid_fields.push(
+            "verifier:max_classifications_per_block: must be greater than zero if provided"
+                .to_string(),
+        );
+    }
+    if verifier.appeal_window_blocks == Some(0) {
+        invalid_fields.push(
+            "verifier:appeal_window_blocks: must be greater than zero if provided".to_string(),
+        );
+    }
+    // denial_reason_required only affects the VerifyAsset route and is compatible with every other
+    // verifier option, so it requires no validation
     // Check subsequent detail values, only if provided.  Omission of subsequent classification detail
     // will ensure that the root onboarding costs are used, so a missing value is completely fine.
     if let Some(ref subsequent_detail) = verifier.subsequent_classification_detail {
@@ -179,6 +438,7 @@ fn validate_verifier_internal(verifier: &VerifierDetailV2) -> Vec<String> {
             invalid_fields.append(&mut validate_onboarding_cost_internal(
                 cost,
                 "verifier subsequent classification cost",
+                allow_all_bech32_prefixes,
             ));
         }
         // Ensure that if allowed asset types are specified, they are not empty and do not contain
@@ -204,6 +464,7 @@ fn validate_verifier_internal(verifier: &VerifierDetailV2) -> Vec<String> {
 fn validate_onboarding_cost_internal<S: Into<String>>(
     onboarding_cost: &OnboardingCost,
     source: S,
+    allow_all_bech32_prefixes: bool,
 ) -> Vec<String> {
     let source = source.into();
     let mut invalid_fields: Vec<String> = vec![];
@@ -212,6 +473,21 @@ fn validate_onboarding_cost_internal<S: Into<String>>(
     {
         invalid_fields.push(format!("{}: onboarding_cost:fee_destinations:fee_amounts must sum to be less than or equal to the onboarding cost", source));
     }
+    // The Provenance Blockchain halves the collected fee, so percentage-based destinations are held
+    // to the same limit enforced by OnboardingCost::new_proportional
+    let percentage_total = onboarding_cost
+        .fee_destinations
+        .iter()
+        .filter_map(|dest| dest.fee_percentage)
+        .fold(Decimal::zero(), |total, percentage| {
+            total.saturating_add(percentage)
+        });
+    if percentage_total > Decimal::percent(50) {
+        invalid_fields.push(format!(
+            "{}: onboarding_cost:fee_destinations:fee_percentages must sum to at most 50 percent of the onboarding cost",
+            source
+        ));
+    }
     if distinct_count_by_property(&onboarding_cost.fee_destinations, |dest| &dest.address)
         != onboarding_cost.fee_destinations.len()
     {
@@ -223,7 +499,7 @@ fn validate_onboarding_cost_internal<S: Into<String>>(
     let mut fee_destination_messages = onboarding_cost
         .fee_destinations
         .iter()
-        .flat_map(|dest| validate_destination_internal(dest, &source))
+        .flat_map(|dest| validate_destination_internal(dest, &source, allow_all_bech32_prefixes))
         .collect::<Vec<String>>();
     invalid_fields.append(&mut fee_destination_messages);
     invalid_fields
@@ -232,20 +508,51 @@ fn validate_onboarding_cost_internal<S: Into<String>>(
 fn validate_destination_internal<S: Into<String>>(
     destination: &FeeDestinationV2,
     source: S,
+    allow_all_bech32_prefixes: bool,
 ) -> Vec<String> {
     let source = source.into();
-    let mut invalid_fields: Vec<String> = vec![];
-    if bech32_string_to_addr(&destination.address).is_err() {
-        invalid_fields.push(format!(
-            "{}: fee_destination:address: must be a valid address",
-            source
-        ));
+    let mut invalid_fields: Vec<String> = validate_verifier_address_internal(
+        &destination.address,
+        format!("{}: fee_destination:address", source),
+        allow_all_bech32_prefixes,
+    );
+    match destination.fee_percentage {
+        Some(fee_percentage) => {
+            if destination.fee_amount != Uint128::zero() {
+                invalid_fields.push(format!(
+                    "{}: fee_destination: fee_amount and fee_percentage must not both be set",
+                    source
+                ));
+            }
+            if fee_percentage.is_zero() || fee_percentage > Decimal::one() {
+                invalid_fields.push(format!(
+                    "{}: fee_destination:fee_percentage: must be greater than zero and at most one",
+                    source
+                ));
+            }
+        }
+        None => {
+            if destination.fee_amount == Uint128::zero() {
+                invalid_fields.push(format!(
+                    "{}: fee_destination:fee_amount: must not be zero",
+                    source
+                ));
+            }
+        }
     }
-    if destination.fee_amount == Uint128::zero() {
-        invalid_fields.push(format!(
-            "{}: fee_destination:fee_amount: must not be zero",
-            source
-        ));
+    if let Some(ref display_name) = destination.display_name {
+        if display_name.trim().is_empty() {
+            invalid_fields.push(format!(
+                "{}: fee_destination:display_name: must not be blank if provided",
+                source
+            ));
+        }
+        if display_name.chars().count() > MAX_FEE_DESTINATION_DISPLAY_NAME_LENGTH {
+            invalid_fields.push(format!(
+                "{}: fee_destination:display_name: must be at most {} characters",
+                source, MAX_FEE_DESTINATION_DISPLAY_NAME_LENGTH
+            ));
+        }
     }
     invalid_fields
 }
@@ -260,15 +567,22 @@ pub mod tests {
     use crate::core::types::onboarding_cost::OnboardingCost;
     use crate::core::types::subsequent_classification_detail::SubsequentClassificationDetail;
     use crate::core::types::verifier_detail::VerifierDetailV2;
-    use crate::testutil::test_constants::DEFAULT_VERIFIER_ADDRESS;
-    use crate::testutil::test_utilities::get_default_entity_detail;
-    use crate::util::constants::{NHASH, VALID_VERIFIER_DENOMS};
+    use crate::testutil::test_constants::{DEFAULT_SCOPE_ADDRESS, DEFAULT_SCOPE_SPEC_ADDRESS};
+    use crate::testutil::test_constants::{DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS};
+    use crate::testutil::test_utilities::{
+        get_default_asset_definition, get_default_entity_detail, get_default_verifier_detail,
+    };
+    use crate::util::constants::{
+        MAX_BASE_CONTRACT_NAME_SEGMENT_LENGTH, MAX_FEE_DESTINATION_DISPLAY_NAME_LENGTH, NHASH,
+        VALID_VERIFIER_ADDRESS_PREFIXES, VALID_VERIFIER_DENOMS,
+    };
     use crate::util::traits::OptionExtensions;
     use crate::validation::validate_init_msg::{
-        validate_asset_definition_internal, validate_destination_internal, validate_init_msg,
+        validate_asset_definition_internal, validate_base_contract_name,
+        validate_destination_internal, validate_init_msg, validate_onboarding_cost_internal,
         validate_verifier_internal,
     };
-    use cosmwasm_std::Uint128;
+    use cosmwasm_std::{Decimal, Uint128};
 
     #[test]
     fn test_valid_init_msg_no_definitions() {
@@ -277,6 +591,9 @@ pub mod tests {
             bind_base_name: true,
             asset_definitions: vec![],
             is_test: false.to_some(),
+            max_access_routes_per_owner: None,
+            allow_all_bech32_prefixes: None,
+            os_gateway_grant_prefix: None,
         });
     }
 
@@ -286,6 +603,9 @@ pub mod tests {
             base_contract_name: "asset".to_string(),
             bind_base_name: true,
             is_test: false.to_some(),
+            max_access_routes_per_owner: None,
+            allow_all_bech32_prefixes: None,
+            os_gateway_grant_prefix: None,
             asset_definitions: vec![AssetDefinitionInputV3::new(
                 "heloc",
                 "Home Equity Line of Credit".to_some(),
@@ -313,6 +633,9 @@ pub mod tests {
             base_contract_name: "asset".to_string(),
             bind_base_name: true,
             is_test: false.to_some(),
+            max_access_routes_per_owner: None,
+            allow_all_bech32_prefixes: None,
+            os_gateway_grant_prefix: None,
             asset_definitions: vec![
                 AssetDefinitionInputV3::new(
                     "heloc",
@@ -402,6 +725,9 @@ pub mod tests {
                 base_contract_name: String::new(),
                 bind_base_name: true,
                 is_test: false.to_some(),
+                max_access_routes_per_owner: None,
+                allow_all_bech32_prefixes: None,
+                os_gateway_grant_prefix: None,
                 asset_definitions: vec![AssetDefinitionInputV3::new(
                     "heloc",
                     "Home Equity Line of Credit".to_some(),
@@ -422,6 +748,76 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_valid_base_contract_names() {
+        for name in ["asset", "asset.pb", "test.asset.pb"] {
+            assert!(
+                validate_base_contract_name(name).is_empty(),
+                "expected base contract name [{}] to be valid",
+                name,
+            );
+        }
+        test_valid_init_msg(&InitMsg {
+            base_contract_name: "asset.pb".to_string(),
+            bind_base_name: true,
+            is_test: false.to_some(),
+            max_access_routes_per_owner: None,
+            allow_all_bech32_prefixes: None,
+            os_gateway_grant_prefix: None,
+            asset_definitions: vec![],
+        });
+    }
+
+    #[test]
+    fn test_invalid_base_contract_names() {
+        let max_segment = "a".repeat(MAX_BASE_CONTRACT_NAME_SEGMENT_LENGTH);
+        let too_long_name = vec![max_segment.as_str(); 4].join(".");
+        assert!(
+            validate_base_contract_name(&max_segment).is_empty(),
+            "a segment at the maximum length should be valid",
+        );
+        for (name, expected_message) in [
+            (
+                "asset pb",
+                "base_contract_name: must not contain whitespace",
+            ),
+            (
+                "asset..pb",
+                "base_contract_name: must not contain consecutive dots",
+            ),
+            (
+                ".asset.pb",
+                "base_contract_name: must not begin or end with a dot",
+            ),
+            (
+                "asset.pb.",
+                "base_contract_name: must not begin or end with a dot",
+            ),
+            (".", "base_contract_name: must not begin or end with a dot"),
+            (
+                &format!("{max_segment}a.pb"),
+                "base_contract_name: each dot-separated segment must be no more than 64 characters",
+            ),
+            (
+                &too_long_name,
+                "base_contract_name: must be no more than 256 characters",
+            ),
+        ] {
+            test_invalid_init_msg(
+                &InitMsg {
+                    base_contract_name: name.to_string(),
+                    bind_base_name: true,
+                    is_test: false.to_some(),
+                    max_access_routes_per_owner: None,
+                    allow_all_bech32_prefixes: None,
+                    os_gateway_grant_prefix: None,
+                    asset_definitions: vec![],
+                },
+                expected_message,
+            );
+        }
+    }
+
     #[test]
     fn test_invalid_init_msg_duplicate_asset_types() {
         test_invalid_init_msg(
@@ -429,6 +825,9 @@ pub mod tests {
                 base_contract_name: String::new(),
                 bind_base_name: true,
                 is_test: false.to_some(),
+                max_access_routes_per_owner: None,
+                allow_all_bech32_prefixes: None,
+                os_gateway_grant_prefix: None,
                 asset_definitions: vec![
                     AssetDefinitionInputV3::new(
                         "heloc",
@@ -450,6 +849,22 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_invalid_init_msg_blank_os_gateway_grant_prefix() {
+        test_invalid_init_msg(
+            &InitMsg {
+                base_contract_name: "asset".to_string(),
+                bind_base_name: true,
+                is_test: false.to_some(),
+                max_access_routes_per_owner: None,
+                allow_all_bech32_prefixes: None,
+                os_gateway_grant_prefix: " ".to_string().to_some(),
+                asset_definitions: vec![],
+            },
+            "os_gateway_grant_prefix: must not be blank if provided",
+        );
+    }
+
     #[test]
     fn test_invalid_init_msg_picks_up_invalid_asset_definition_scenarios() {
         test_invalid_init_msg(
@@ -457,6 +872,9 @@ pub mod tests {
                 base_contract_name: "asset".to_string(),
                 bind_base_name: true,
                 is_test: false.to_some(),
+                max_access_routes_per_owner: None,
+                allow_all_bech32_prefixes: None,
+                os_gateway_grant_prefix: None,
                 asset_definitions: vec![AssetDefinitionInputV3::new(
                     "",
                     None::<String>,
@@ -469,12 +887,28 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_invalid_init_msg_zero_max_access_routes_per_owner() {
+        test_invalid_init_msg(
+            &InitMsg {
+                base_contract_name: "asset".to_string(),
+                bind_base_name: true,
+                is_test: false.to_some(),
+                max_access_routes_per_owner: 0.to_some(),
+                allow_all_bech32_prefixes: None,
+                os_gateway_grant_prefix: None,
+                asset_definitions: vec![],
+            },
+            "max_access_routes_per_owner: must be greater than zero if provided",
+        );
+    }
+
     #[test]
     fn test_valid_asset_definition() {
-        let definition = AssetDefinitionV3::new(
-            "heloc",
-            "Home Equity Line of Credit".to_some(),
-            vec![VerifierDetailV2::new(
+        let definition = AssetDefinitionV3::builder()
+            .asset_type("heloc")
+            .display_name("Home Equity Line of Credit".to_some())
+            .add_verifier(VerifierDetailV2::new(
                 "tp1x24ueqfehs5ye7akkvhf2d67fmfs2zd55tsy2g",
                 Uint128::new(200),
                 NHASH,
@@ -485,9 +919,10 @@ pub mod tests {
                 get_default_entity_detail().to_some(),
                 None,
                 None,
-            )],
-        );
-        let response = validate_asset_definition_internal(&definition);
+            ))
+            .build()
+            .expect("the heloc asset definition should be built");
+        let response = validate_asset_definition_internal(&definition, false, false);
         assert!(
             response.is_empty(),
             "a valid asset definition should pass validation and return no error messages, but got messages: {:?}",
@@ -524,32 +959,136 @@ pub mod tests {
     }
 
     #[test]
-    fn test_invalid_asset_definition_duplicate_verifier_addresses() {
+    fn test_asset_definition_scope_spec_address() {
+        let valid_definition = AssetDefinitionV3 {
+            scope_spec_address: DEFAULT_SCOPE_SPEC_ADDRESS.to_string().to_some(),
+            ..get_default_asset_definition()
+        };
+        assert!(
+            validate_asset_definition_internal(&valid_definition, false, false).is_empty(),
+            "a definition with a valid scope spec address should pass validation",
+        );
         test_invalid_asset_definition(
-            &AssetDefinitionV3::new(
-                "heloc",
-                "very best heloc ever".to_some(),
-                vec![
-                    VerifierDetailV2::new(
-                        "duplicate",
-                        Uint128::new(100),
-                        NHASH,
-                        vec![FeeDestinationV2::new("fee", 100)],
-                        get_default_entity_detail().to_some(),
-                        None,
-                        None,
-                    ),
-                    VerifierDetailV2::new(
-                        "duplicate",
-                        Uint128::new(100),
-                        NHASH,
-                        vec![FeeDestinationV2::new("fee", 100)],
-                        get_default_entity_detail().to_some(),
-                        None,
-                        None,
-                    ),
+            &AssetDefinitionV3 {
+                scope_spec_address: DEFAULT_SCOPE_ADDRESS.to_string().to_some(),
+                ..get_default_asset_definition()
+            },
+            "asset_definition:scope_spec_address: must be a valid scope specification address",
+        );
+    }
+
+    #[test]
+    fn test_asset_definition_labels() {
+        let valid_definition = AssetDefinitionV3 {
+            labels: vec!["mortgage".to_string(), "residential".to_string()],
+            ..get_default_asset_definition()
+        };
+        assert!(
+            validate_asset_definition_internal(&valid_definition, false, false).is_empty(),
+            "a definition with distinct labels should pass validation",
+        );
+        test_invalid_asset_definition(
+            &AssetDefinitionV3 {
+                labels: vec![
+                    "mortgage".to_string(),
+                    "residential".to_string(),
+                    "mortgage".to_string(),
                 ],
-            ),
+                ..get_default_asset_definition()
+            },
+            "asset_definition:labels: each label must be unique",
+        );
+    }
+
+    #[test]
+    fn test_asset_definition_allowed_requestors() {
+        for allowed_requestors in [
+            None,
+            vec![].to_some(),
+            vec![DEFAULT_SENDER_ADDRESS.to_string()].to_some(),
+        ] {
+            let valid_definition = AssetDefinitionV3 {
+                allowed_requestors: allowed_requestors.clone(),
+                ..get_default_asset_definition()
+            };
+            assert!(
+                validate_asset_definition_internal(&valid_definition, false, false).is_empty(),
+                "a definition with allowed requestors {:?} should pass validation",
+                allowed_requestors,
+            );
+        }
+        test_invalid_asset_definition(
+            &AssetDefinitionV3 {
+                allowed_requestors: vec![
+                    DEFAULT_SENDER_ADDRESS.to_string(),
+                    "not-an-address".to_string(),
+                ]
+                .to_some(),
+                ..get_default_asset_definition()
+            },
+            "asset_definition:allowed_requestors: each value must be a valid address",
+        );
+        test_invalid_asset_definition(
+            &AssetDefinitionV3 {
+                allowed_requestors: vec![
+                    DEFAULT_SENDER_ADDRESS.to_string(),
+                    DEFAULT_SENDER_ADDRESS.to_string(),
+                ]
+                .to_some(),
+                ..get_default_asset_definition()
+            },
+            "asset_definition:allowed_requestors: each value must be unique",
+        );
+    }
+
+    #[test]
+    fn test_asset_definition_max_verifiers() {
+        for max_verifiers in [None, 1.to_some(), 5.to_some()] {
+            let valid_definition = AssetDefinitionV3 {
+                max_verifiers,
+                ..get_default_asset_definition()
+            };
+            assert!(
+                validate_asset_definition_internal(&valid_definition, false, false).is_empty(),
+                "a definition with max verifiers {:?} should pass validation",
+                max_verifiers,
+            );
+        }
+        test_invalid_asset_definition(
+            &AssetDefinitionV3 {
+                max_verifiers: 0.to_some(),
+                ..get_default_asset_definition()
+            },
+            "asset_definition:max_verifiers: must not be less than the number of verifiers",
+        );
+    }
+
+    #[test]
+    fn test_invalid_asset_definition_duplicate_verifier_addresses() {
+        test_invalid_asset_definition(
+            &AssetDefinitionV3::builder()
+                .asset_type("heloc")
+                .display_name("very best heloc ever".to_some())
+                .add_verifier(VerifierDetailV2::new(
+                    "duplicate",
+                    Uint128::new(100),
+                    NHASH,
+                    vec![FeeDestinationV2::new("fee", 100)],
+                    get_default_entity_detail().to_some(),
+                    None,
+                    None,
+                ))
+                .add_verifier(VerifierDetailV2::new(
+                    "duplicate",
+                    Uint128::new(100),
+                    NHASH,
+                    vec![FeeDestinationV2::new("fee", 100)],
+                    get_default_entity_detail().to_some(),
+                    None,
+                    None,
+                ))
+                .build()
+                .expect("the heloc asset definition should be built"),
             "asset_definitions:verifiers: each verifier detail must have a unique address",
         );
     }
@@ -585,7 +1124,7 @@ pub mod tests {
             None,
             None,
         );
-        let response = validate_verifier_internal(&verifier);
+        let response = validate_verifier_internal(&verifier, false, false);
         assert!(
             response.is_empty(),
             "a valid verifier should pass validation and return no error messages, but got messages: {:?}",
@@ -604,7 +1143,7 @@ pub mod tests {
             None,
             None,
         );
-        let response = validate_verifier_internal(&verifier);
+        let response = validate_verifier_internal(&verifier, false, false);
         assert!(
             response.is_empty(),
             "a valid verifier should pass validation and return no error messages, but got messages: ${:?}",
@@ -626,7 +1165,7 @@ pub mod tests {
             None,
             None,
         );
-        let response = validate_verifier_internal(&verifier);
+        let response = validate_verifier_internal(&verifier, false, false);
         assert!(
             response.is_empty(),
             "a valid verifier should pass validation and return no error messages, but got messages: {:?}",
@@ -651,7 +1190,7 @@ pub mod tests {
             None,
             None,
         );
-        let response = validate_verifier_internal(&verifier);
+        let response = validate_verifier_internal(&verifier, false, false);
         assert!(
             response.is_empty(),
             "a valid verifier should pass validation and return no error messages, but got messages: {:?}",
@@ -675,6 +1214,71 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_invalid_verifier_address_prefix() {
+        test_invalid_verifier(
+            &VerifierDetailV2 {
+                address: DEFAULT_SCOPE_ADDRESS.to_string(),
+                ..get_default_verifier_detail()
+            },
+            &format!(
+                "verifier:address: must use one of the prefixes [{}]",
+                VALID_VERIFIER_ADDRESS_PREFIXES.join(", "),
+            ),
+        );
+    }
+
+    #[test]
+    fn test_valid_verifier_address_prefixes() {
+        // Both addresses share the same data, encoded with the testnet and mainnet hrps
+        for address in [
+            "tp1dj50kvzsknr3ydypw3lt8f4dulrrncw4j626vk",
+            "pb1dj50kvzsknr3ydypw3lt8f4dulrrncw4p30kwu",
+        ] {
+            let response = validate_verifier_internal(
+                &VerifierDetailV2 {
+                    address: address.to_string(),
+                    ..get_default_verifier_detail()
+                },
+                false,
+                false,
+            );
+            assert!(
+                response.is_empty(),
+                "a verifier with address {} should pass validation, but got messages: {:?}",
+                address,
+                response,
+            );
+        }
+    }
+
+    #[test]
+    fn test_verifier_address_prefix_ignored_when_all_prefixes_allowed() {
+        let verifier = VerifierDetailV2 {
+            address: DEFAULT_SCOPE_ADDRESS.to_string(),
+            ..get_default_verifier_detail()
+        };
+        let response = validate_verifier_internal(&verifier, true, false);
+        assert!(
+            response.is_empty(),
+            "a verifier with an unlisted prefix should pass validation when all prefixes are allowed, but got messages: {:?}",
+            response,
+        );
+        let response = validate_verifier_internal(
+            &VerifierDetailV2 {
+                address: "not-an-address".to_string(),
+                ..verifier
+            },
+            true,
+            false,
+        );
+        assert_eq!(
+            vec!["verifier:address: must be a valid address".to_string()],
+            response,
+            "an invalid address should still be rejected when all prefixes are allowed",
+        );
+    }
+
     #[test]
     fn test_invalid_verifier_onboarding_denom() {
         let expected_error_text = format!(
@@ -755,7 +1359,7 @@ pub mod tests {
             OnboardingCost::new(0, &[]).to_some(),
             None,
         );
-        let response = validate_verifier_internal(&verifier);
+        let response = validate_verifier_internal(&verifier, false, false);
         assert!(
             response.is_empty(),
             "a verifier with free retries should be considered valid, but got messages: {:?}",
@@ -782,6 +1386,90 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_valid_verifier_max_retries_values() {
+        for max_retries in [None, 1.to_some()] {
+            let response = validate_verifier_internal(
+                &VerifierDetailV2 {
+                    max_retries,
+                    ..get_default_verifier_detail()
+                },
+                false,
+                false,
+            );
+            assert!(
+                response.is_empty(),
+                "a verifier with max retries of {:?} should be considered valid, but got messages: {:?}",
+                max_retries,
+                response,
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_verifier_zero_max_retries() {
+        test_invalid_verifier(
+            &VerifierDetailV2 {
+                max_retries: 0.to_some(),
+                ..get_default_verifier_detail()
+            },
+            "verifier:max_retries: must be greater than zero if provided",
+        );
+    }
+
+    #[test]
+    fn test_invalid_verifier_zero_verification_timeout_blocks() {
+        test_invalid_verifier(
+            &VerifierDetailV2 {
+                verification_timeout_blocks: 0.to_some(),
+                ..get_default_verifier_detail()
+            },
+            "verifier:verification_timeout_blocks: must be greater than zero if provided",
+        );
+    }
+
+    #[test]
+    fn test_invalid_verifier_zero_appeal_window_blocks() {
+        test_invalid_verifier(
+            &VerifierDetailV2 {
+                appeal_window_blocks: 0.to_some(),
+                ..get_default_verifier_detail()
+            },
+            "verifier:appeal_window_blocks: must be greater than zero if provided",
+        );
+    }
+
+    #[test]
+    fn test_invalid_verifier_zero_max_classifications_per_block() {
+        test_invalid_verifier(
+            &VerifierDetailV2 {
+                max_classifications_per_block: 0.to_some(),
+                ..get_default_verifier_detail()
+            },
+            "verifier:max_classifications_per_block: must be greater than zero if provided",
+        );
+    }
+
+    #[test]
+    fn test_valid_verifier_requiring_denial_reasons_alongside_other_options() {
+        let verifier = VerifierDetailV2 {
+            denial_reason_required: true,
+            cooldown_seconds: 86400.to_some(),
+            max_retries: 3.to_some(),
+            verification_timeout_blocks: 100.to_some(),
+            appeal_window_blocks: 50.to_some(),
+            max_classifications_per_block: 10.to_some(),
+            enabled: false,
+            ..get_default_verifier_detail()
+        };
+        let response = validate_verifier_internal(&verifier, false, false);
+        assert!(
+            response.is_empty(),
+            "requiring denial reasons should not conflict with any other verifier options, but got messages: {:?}",
+            response,
+        );
+    }
+
     #[test]
     fn test_valid_verifier_with_zero_cost_subsequent_classifications() {
         let verifier = VerifierDetailV2::new(
@@ -797,7 +1485,7 @@ pub mod tests {
             )
             .to_some(),
         );
-        let response = validate_verifier_internal(&verifier);
+        let response = validate_verifier_internal(&verifier, false, false);
         assert!(
             response.is_empty(),
             "a valid verifier should pass validation with a zero cost subsequent classification detail, but got messages: {:?}",
@@ -886,7 +1574,7 @@ pub mod tests {
     fn test_valid_destination() {
         let destination = FeeDestinationV2::new("tp1362ax9s0gxr5yy636q2p9uuefeg8lhguvu6np5", 100);
         assert!(
-            validate_destination_internal(&destination, "test").is_empty(),
+            validate_destination_internal(&destination, "test", false).is_empty(),
             "a valid fee destination should pass validation and return no error messages",
         );
     }
@@ -899,6 +1587,20 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_invalid_destination_address_prefix() {
+        let expected_message = format!(
+            "test: fee_destination:address: must use one of the prefixes [{}]",
+            VALID_VERIFIER_ADDRESS_PREFIXES.join(", "),
+        );
+        let destination = FeeDestinationV2::new(DEFAULT_SCOPE_ADDRESS, 1);
+        test_invalid_destination(&destination, &expected_message);
+        assert!(
+            !validate_destination_internal(&destination, "test", true).contains(&expected_message),
+            "the prefix should not be checked when all prefixes are allowed",
+        );
+    }
+
     #[test]
     fn test_invalid_destination_fee_amount_too_low() {
         test_invalid_destination(
@@ -907,6 +1609,151 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_valid_percentage_destination() {
+        let destination = FeeDestinationV2::new_percentage(
+            "tp1362ax9s0gxr5yy636q2p9uuefeg8lhguvu6np5",
+            Decimal::percent(25),
+        );
+        assert!(
+            validate_destination_internal(&destination, "test", false).is_empty(),
+            "a valid percentage fee destination should pass validation and return no error messages",
+        );
+    }
+
+    #[test]
+    fn test_invalid_destination_fee_amount_and_percentage_both_set() {
+        test_invalid_destination(
+            &FeeDestinationV2 {
+                fee_amount: Uint128::new(100),
+                ..FeeDestinationV2::new_percentage("good-address", Decimal::percent(25))
+            },
+            "test: fee_destination: fee_amount and fee_percentage must not both be set",
+        );
+    }
+
+    #[test]
+    fn test_invalid_destination_fee_percentage_out_of_range() {
+        test_invalid_destination(
+            &FeeDestinationV2::new_percentage("good-address", Decimal::zero()),
+            "test: fee_destination:fee_percentage: must be greater than zero and at most one",
+        );
+        test_invalid_destination(
+            &FeeDestinationV2::new_percentage("good-address", Decimal::percent(101)),
+            "test: fee_destination:fee_percentage: must be greater than zero and at most one",
+        );
+    }
+
+    #[test]
+    fn test_valid_destination_display_name() {
+        let destination = FeeDestinationV2 {
+            display_name: "d"
+                .repeat(MAX_FEE_DESTINATION_DISPLAY_NAME_LENGTH)
+                .to_some(),
+            ..FeeDestinationV2::new("tp1362ax9s0gxr5yy636q2p9uuefeg8lhguvu6np5", 100)
+        };
+        assert!(
+            validate_destination_internal(&destination, "test", false).is_empty(),
+            "a display name at the maximum length should pass validation",
+        );
+    }
+
+    #[test]
+    fn test_invalid_destination_display_name() {
+        test_invalid_destination(
+            &FeeDestinationV2 {
+                display_name: "  ".to_string().to_some(),
+                ..FeeDestinationV2::new("good-address", 100)
+            },
+            "test: fee_destination:display_name: must not be blank if provided",
+        );
+        test_invalid_destination(
+            &FeeDestinationV2 {
+                display_name: "d"
+                    .repeat(MAX_FEE_DESTINATION_DISPLAY_NAME_LENGTH + 1)
+                    .to_some(),
+                ..FeeDestinationV2::new("good-address", 100)
+            },
+            "test: fee_destination:display_name: must be at most 100 characters",
+        );
+    }
+
+    #[test]
+    fn test_invalid_onboarding_cost_destination_display_name() {
+        let onboarding_cost = OnboardingCost {
+            cost: Uint128::new(100),
+            fee_destinations: vec![FeeDestinationV2 {
+                display_name: "d"
+                    .repeat(MAX_FEE_DESTINATION_DISPLAY_NAME_LENGTH + 1)
+                    .to_some(),
+                ..FeeDestinationV2::new("tp1362ax9s0gxr5yy636q2p9uuefeg8lhguvu6np5", 50)
+            }],
+        };
+        let results = validate_onboarding_cost_internal(&onboarding_cost, "test", false);
+        assert_eq!(
+            vec!["test: fee_destination:display_name: must be at most 100 characters".to_string()],
+            results,
+            "an over-length display name should be reported by the onboarding cost validation",
+        );
+    }
+
+    #[test]
+    fn test_valid_verifier_with_single_percentage_fee_destination() {
+        test_valid_percentage_verifier(&[Decimal::percent(25)]);
+    }
+
+    #[test]
+    fn test_valid_verifier_with_percentage_fee_destinations_summing_to_half() {
+        test_valid_percentage_verifier(&[Decimal::percent(30), Decimal::percent(20)]);
+    }
+
+    #[test]
+    fn test_invalid_verifier_percentage_fees_exceed_half_of_onboarding_cost() {
+        test_invalid_verifier(
+            &VerifierDetailV2::new(
+                DEFAULT_VERIFIER_ADDRESS,
+                Uint128::new(100),
+                NHASH,
+                vec![
+                    FeeDestinationV2::new_percentage(
+                        "tp1362ax9s0gxr5yy636q2p9uuefeg8lhguvu6np5",
+                        Decimal::percent(30),
+                    ),
+                    FeeDestinationV2::new_percentage(
+                        "tp18c94z83e6ng2sc3ylvutzytlx8zqggm554xp5a",
+                        Decimal::percent(21),
+                    ),
+                ],
+                None,
+                None,
+                None,
+            ),
+            "verifier onboarding costs: onboarding_cost:fee_destinations:fee_percentages must sum to at most 50 percent of the onboarding cost",
+        );
+    }
+
+    #[test]
+    fn test_invalid_verifier_percentage_fees_exceed_onboarding_cost() {
+        test_invalid_verifier(
+            &VerifierDetailV2::new(
+                DEFAULT_VERIFIER_ADDRESS,
+                Uint128::new(100),
+                NHASH,
+                vec![
+                    FeeDestinationV2::new_percentage(
+                        "tp1362ax9s0gxr5yy636q2p9uuefeg8lhguvu6np5",
+                        Decimal::percent(60),
+                    ),
+                    FeeDestinationV2::new("tp18c94z83e6ng2sc3ylvutzytlx8zqggm554xp5a", 50),
+                ],
+                None,
+                None,
+                None,
+            ),
+            "verifier onboarding costs: onboarding_cost:fee_destinations:fee_amounts must sum to be less than or equal to the onboarding cost",
+        );
+    }
+
     fn test_valid_init_msg(msg: &InitMsg) {
         match validate_init_msg(&msg) {
             Ok(_) => (),
@@ -949,8 +1796,26 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn test_proportional_onboarding_cost_passes_validation() {
+        let onboarding_cost = OnboardingCost::new_proportional(
+            1000,
+            &[
+                (DEFAULT_VERIFIER_ADDRESS, 10.0),
+                (DEFAULT_SENDER_ADDRESS, 5.0),
+            ],
+        )
+        .expect("valid percentages should produce an onboarding cost");
+        let results = validate_onboarding_cost_internal(&onboarding_cost, "test", false);
+        assert!(
+            results.is_empty(),
+            "expected a proportional onboarding cost to pass validation, but got errors: {:?}",
+            results,
+        );
+    }
+
     fn test_invalid_asset_definition(definition: &AssetDefinitionV3, expected_message: &str) {
-        let results = validate_asset_definition_internal(&definition);
+        let results = validate_asset_definition_internal(&definition, false, false);
         assert!(
             results.contains(&expected_message.to_string()),
             "expected error message `{}` was not contained in the response. Contained messages: {:?}",
@@ -959,8 +1824,34 @@ pub mod tests {
         );
     }
 
+    fn test_valid_percentage_verifier(percentages: &[Decimal]) {
+        let addresses = [
+            "tp1362ax9s0gxr5yy636q2p9uuefeg8lhguvu6np5",
+            "tp18c94z83e6ng2sc3ylvutzytlx8zqggm554xp5a",
+        ];
+        let verifier = VerifierDetailV2::new(
+            DEFAULT_VERIFIER_ADDRESS,
+            Uint128::new(100),
+            NHASH,
+            percentages
+                .iter()
+                .zip(addresses)
+                .map(|(percentage, address)| FeeDestinationV2::new_percentage(address, *percentage))
+                .collect(),
+            None,
+            None,
+            None,
+        );
+        let response = validate_verifier_internal(&verifier, false, false);
+        assert!(
+            response.is_empty(),
+            "a verifier with percentages summing to at most half of the onboarding cost should pass validation, but got messages: {:?}",
+            response,
+        );
+    }
+
     fn test_invalid_verifier(verifier: &VerifierDetailV2, expected_message: &str) {
-        let results = validate_verifier_internal(&verifier);
+        let results = validate_verifier_internal(&verifier, false, false);
         assert!(
             results.contains(&expected_message.to_string()),
             "expected error message `{}` was not contained in the response. Contained messages: {:?}",
@@ -970,7 +1861,7 @@ pub mod tests {
     }
 
     fn test_invalid_destination(destination: &FeeDestinationV2, expected_message: &str) {
-        let results = validate_destination_internal(&destination, "test");
+        let results = validate_destination_internal(&destination, "test", false);
         assert!(
             results.contains(&expected_message.to_string()),
             "expected error message `{}` was not contained in the response. Contained messages: {:?}",
@@ -6,9 +6,11 @@ use asset_classification_smart_contract::core::{
         asset_definition::AssetDefinitionInputV3, asset_onboarding_status::AssetOnboardingStatus,
         asset_scope_attribute::AssetScopeAttribute,
         asset_verification_result::AssetVerificationResult, fee_destination::FeeDestinationV2,
-        serialized_enum::SerializedEnum, verifier_detail::VerifierDetailV2,
+        onboarding_cost::OnboardingCost, serialized_enum::SerializedEnum,
+        verifier_detail::VerifierDetailV2,
     },
 };
+use asset_classification_smart_contract::execute::onboard_asset::OnboardAssetV1;
 use cosmwasm_std::{coin, from_json, Uint128};
 use provwasm_std::{
     metadata_address::MetadataAddress,
@@ -79,21 +81,40 @@ fn happy_path_onboard_and_verify_asset() {
                     display_name: Some(String::from("Mortgage")),
                     verifiers: vec![VerifierDetailV2 {
                         address: verifier.address(),
-                        onboarding_cost: Uint128::new(30000000000),
+                        onboarding_cost: OnboardingCost {
+                            cost: Uint128::new(30000000000),
+                            fee_destinations: vec![FeeDestinationV2 {
+                                address: verifier.address(),
+                                fee_amount: Uint128::new(29999999500),
+                                fee_percentage: None,
+                                entity_detail: None,
+                                display_name: None,
+                            }],
+                        },
                         onboarding_denom: String::from("nhash"),
-                        fee_destinations: vec![FeeDestinationV2 {
-                            address: verifier.address(),
-                            fee_amount: Uint128::new(29999999500),
-                            entity_detail: None,
-                        }],
                         entity_detail: None,
                         retry_cost: None,
                         subsequent_classification_detail: None,
+                        cooldown_seconds: None,
+                        max_retries: None,
+                        verification_timeout_blocks: None,
+                        appeal_window_blocks: None,
+                        max_classifications_per_block: None,
+                        denial_reason_required: false,
+                        enabled: true,
                     }],
                     enabled: Some(true),
                     bind_name: Some(true),
+                    scope_spec_address: None,
+                    labels: vec![],
+                    allowed_requestors: None,
+                    max_verifiers: None,
+                    scope_owner_must_match_requestor: None,
                 }],
                 is_test: Some(true),
+                max_access_routes_per_owner: None,
+                allow_all_bech32_prefixes: None,
+                os_gateway_grant_prefix: None,
             },
             Some(&admin.address()),
             Some("testing"),
@@ -195,22 +216,42 @@ fn happy_path_onboard_and_verify_asset() {
 
     let originator_nhash_balance_before_onboarding_asset = get_nhash_balance(&originator);
 
-    wasm.execute(
-        &contract_addr,
-        &ExecuteMsg::OnboardAsset {
-            identifier: SerializedEnum {
-                r#type: String::from("asset_uuid"),
-                value: scope_uuid.to_string(),
-            },
-            asset_type: String::from("mortgage"),
-            verifier_address: verifier.address(),
-            access_routes: None,
-            add_os_gateway_permission: None,
+    let onboard_asset_msg = ExecuteMsg::OnboardAsset {
+        identifier: SerializedEnum {
+            r#type: String::from("asset_uuid"),
+            value: scope_uuid.to_string(),
         },
-        &[],
-        &originator,
-    )
-    .expect("onboarding an asset should succeed");
+        asset_type: String::from("mortgage"),
+        verifier_address: verifier.address(),
+        access_routes: None,
+        add_os_gateway_permission: None,
+        expected_fee: None,
+        memo: None,
+        metadata: None,
+    };
+
+    let onboard_asset: OnboardAssetV1 = onboard_asset_msg
+        .clone()
+        .try_into()
+        .expect("the onboard asset message should convert to its handler struct");
+    assert_eq!(
+        MetadataAddress::scope(Uuid::from_str(scope_uuid).unwrap())
+            .unwrap()
+            .bech32,
+        onboard_asset
+            .identifier
+            .to_identifiers()
+            .expect("the converted identifier should resolve to a scope address")
+            .scope_address,
+        "The converted onboard asset message should target the created scope"
+    );
+    assert!(
+        onboard_asset.add_os_gateway_permission,
+        "Object store gateway permission should default to true when omitted"
+    );
+
+    wasm.execute(&contract_addr, &onboard_asset_msg, &[], &originator)
+        .expect("onboarding an asset should succeed");
 
     let originator_nhash_balance_after_onboarding_asset = get_nhash_balance(&originator);
 